@@ -1,19 +1,229 @@
-//! `raya bench` — Run benchmarks.
-
-pub fn execute(
-    filter: Option<String>,
-    warmup: usize,
-    iterations: usize,
-    save: Option<String>,
-    compare: Option<String>,
-    json: bool,
-) -> anyhow::Result<()> {
-    if let Some(ref f) = filter {
-        println!("Running benchmarks matching: {}", f);
-    } else {
-        println!("Running all benchmarks...");
+//! `raya bench` — Discover, run, and report benchmarks.
+
+use crate::output::{self, StyledOutput};
+use raya_runtime::bench_runner::{self, BenchFileResult, BenchOptions};
+use raya_runtime::RuntimeOptions;
+use std::path::PathBuf;
+use termcolor::Color;
+
+/// Arguments for the bench command.
+pub struct BenchArgs {
+    pub filter: Option<String>,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub profile: Option<String>,
+    pub profile_out: Option<PathBuf>,
+    pub prof_interval: u64,
+    pub file: Option<String>,
+    pub json: bool,
+    pub color: String,
+}
+
+pub fn execute(args: BenchArgs) -> anyhow::Result<()> {
+    let color_choice = output::resolve_color_choice(Some(&args.color));
+    let mut out = StyledOutput::new(color_choice);
+
+    let bench_files = discover_bench_files(args.file.as_deref())?;
+
+    if bench_files.is_empty() {
+        out.warning("No benchmark files found.");
+        out.newline();
+        out.dim("  Looked for: **/*.bench.raya, **/*_bench.raya");
+        out.newline();
+        return Ok(());
+    }
+
+    let options = RuntimeOptions {
+        prof_interval_us: args.prof_interval,
+        ..Default::default()
+    };
+    let bench_options = BenchOptions {
+        filter: args.filter.clone(),
+        warmup: args.warmup,
+        iterations: args.iterations,
+        profile: args.profile.clone(),
+    };
+
+    let mut any_failure = false;
+    let mut profiled: Option<BenchFileResult> = None;
+
+    for bench_file in &bench_files {
+        match bench_runner::run_bench_file(bench_file, &bench_options, &options) {
+            Ok(file_result) => {
+                if file_result.has_failures() {
+                    any_failure = true;
+                }
+                if args.json {
+                    print_json_results(&file_result);
+                } else {
+                    print_default_results(&mut out, &file_result);
+                }
+                if let Some(ref name) = args.profile {
+                    let ran = file_result.results.results.iter().any(|b| b.name == *name);
+                    if ran && profiled.is_none() {
+                        profiled = Some(file_result);
+                    }
+                }
+            }
+            Err(e) => {
+                any_failure = true;
+                out.newline();
+                out.fail_badge();
+                out.plain(&format!("  {} ", bench_file.display()));
+                out.newline();
+                out.error(&format!("  Compilation/execution error: {}", e));
+                out.newline();
+            }
+        }
+    }
+
+    if let Some(ref name) = args.profile {
+        match profiled.as_ref().and_then(|r| r.profile.as_ref()) {
+            Some(profile) => {
+                let path = args
+                    .profile_out
+                    .clone()
+                    .unwrap_or_else(|| default_profile_path(name));
+                std::fs::write(&path, profile.to_cpuprofile_json())?;
+                eprintln!(
+                    "Profile of \"{}\" ({} samples) written to {}",
+                    name,
+                    profile.samples.len(),
+                    path.display()
+                );
+            }
+            None => {
+                out.stderr_error(&format!("No benchmark named \"{}\" ran.\n", name));
+                any_failure = true;
+            }
+        }
+    }
+
+    if any_failure {
+        std::process::exit(1);
     }
-    let _ = (warmup, iterations, save, compare, json);
-    eprintln!("(Not yet implemented)");
+
     Ok(())
 }
+
+// ── Benchmark Discovery ──────────────────────────────────────────────────
+
+fn discover_bench_files(file_filter: Option<&str>) -> anyhow::Result<Vec<PathBuf>> {
+    let cwd = std::env::current_dir()?;
+    let mut files = Vec::new();
+
+    if let Some(filter) = file_filter {
+        // User-specified glob
+        for path in (glob::glob(filter)?).flatten() {
+            if path.extension().and_then(|e| e.to_str()) == Some("raya") {
+                files.push(path);
+            }
+        }
+    } else {
+        // Default discovery patterns
+        let patterns = ["**/*.bench.raya", "**/*_bench.raya"];
+        let excludes = [
+            "node_modules",
+            ".raya-cache",
+            "dist",
+            "target",
+            ".worktrees",
+        ];
+
+        for pattern in &patterns {
+            let full_pattern = cwd.join(pattern);
+            if let Ok(entries) = glob::glob(full_pattern.to_str().unwrap_or("")) {
+                for path in entries.flatten() {
+                    let path_str = path.to_string_lossy();
+                    if excludes.iter().any(|ex| path_str.contains(ex)) {
+                        continue;
+                    }
+                    if !files.contains(&path) {
+                        files.push(path);
+                    }
+                }
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// `<name>.cpuprofile` in the current directory, with the benchmark name
+/// reduced to filename-safe characters.
+fn default_profile_path(name: &str) -> PathBuf {
+    let stem: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    PathBuf::from(format!("{}.cpuprofile", stem))
+}
+
+// ── Reporters ────────────────────────────────────────────────────────────
+
+fn print_default_results(out: &mut StyledOutput, result: &BenchFileResult) {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let display_path = result
+        .file
+        .strip_prefix(&cwd)
+        .unwrap_or(&result.file)
+        .display();
+
+    if result.results.results.is_empty() && result.execution_error.is_none() {
+        return;
+    }
+
+    out.newline();
+    out.bold(&display_path.to_string());
+    out.newline();
+
+    for bench in &result.results.results {
+        match bench.error_message {
+            Some(ref message) => {
+                out.write_styled("   ✗ ", Some(Color::Red), true, false);
+                out.write_styled(&bench.name, Some(Color::Red), true, false);
+                out.dim(&format!("  {}", message));
+            }
+            None => {
+                out.plain(&format!("   {:<40}", bench.name));
+                out.info(&format!("{:>12.4} ms/iter", bench.mean_ms()));
+                out.dim(&format!("  ({} iterations)", bench.iterations));
+            }
+        }
+        out.newline();
+    }
+
+    if let Some(ref error) = result.execution_error {
+        out.error(&format!("   Execution error: {}", error));
+        out.newline();
+    }
+}
+
+fn print_json_results(result: &BenchFileResult) {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let display_path = result
+        .file
+        .strip_prefix(&cwd)
+        .unwrap_or(&result.file)
+        .display()
+        .to_string();
+
+    for bench in &result.results.results {
+        let record = serde_json::json!({
+            "file": display_path,
+            "name": bench.name,
+            "iterations": bench.iterations,
+            "total_ms": bench.total_ms,
+            "mean_ms": bench.mean_ms(),
+            "error": bench.error_message,
+        });
+        println!("{}", record);
+    }
+}
@@ -1,18 +1,66 @@
 //! `raya build` — Compile Raya source to .ryb bytecode.
+//!
+//! `--emit` selects the artifact: `bytecode` (default, `.ryb`), `ir`
+//! (optimized textual IR, `.rir`) or `disasm` (bytecode disassembly, `.disasm`).
+//!
+//! `--verify <expected.ryb>` rebuilds a single source file and compares the
+//! bytecode byte-for-byte against a published artifact instead of writing it.
 
+use raya_engine::compiler::{disassemble_function, Module};
 use raya_runtime::compile::CompileOptions;
-use raya_runtime::{BuiltinMode, Runtime, RuntimeOptions, TypeMode};
+use raya_runtime::{BuiltinMode, CompiledModule, Runtime, RuntimeOptions, TypeMode};
+use std::fmt::Write;
 use std::path::{Path, PathBuf};
 
+/// Artifact written by `raya build`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmitKind {
+    /// Encoded bytecode module (`.ryb`).
+    #[default]
+    Bytecode,
+    /// Optimized textual IR (`.rir`).
+    Ir,
+    /// Human-readable bytecode disassembly (`.disasm`).
+    Disasm,
+}
+
+impl EmitKind {
+    /// Output file extension for this artifact.
+    fn extension(self) -> &'static str {
+        match self {
+            EmitKind::Bytecode => "ryb",
+            EmitKind::Ir => "rir",
+            EmitKind::Disasm => "disasm",
+        }
+    }
+}
+
+/// Parse the value of `--emit`.
+pub fn parse_emit_kind(emit: Option<&str>) -> anyhow::Result<EmitKind> {
+    match emit.map(|value| value.to_ascii_lowercase()).as_deref() {
+        None | Some("bytecode") => Ok(EmitKind::Bytecode),
+        Some("ir") => Ok(EmitKind::Ir),
+        Some("disasm") => Ok(EmitKind::Disasm),
+        Some(_) => Err(anyhow::anyhow!(
+            "Invalid --emit '{}'. Expected one of: ir, bytecode, disasm.",
+            emit.unwrap_or_default()
+        )),
+    }
+}
+
 pub fn execute(
     files: Vec<String>,
     out_dir: String,
     release: bool,
     watch: bool,
     sourcemap: bool,
+    no_assertions: bool,
     dry_run: bool,
     node_compat: bool,
     type_mode: TypeMode,
+    features: Vec<String>,
+    emit: EmitKind,
+    verify: Option<PathBuf>,
 ) -> anyhow::Result<()> {
     let _ = (release, watch); // TODO: wire these flags
 
@@ -20,6 +68,14 @@ pub fn execute(
         anyhow::bail!("--mode ts/js requires --node-compat");
     }
 
+    // `--feature` flags add to the features listed under [build] in raya.toml
+    let mut features = features;
+    for feature in load_manifest_features() {
+        if !features.contains(&feature) {
+            features.push(feature);
+        }
+    }
+
     let rt = Runtime::with_options(RuntimeOptions {
         builtin_mode: if node_compat {
             BuiltinMode::NodeCompat
@@ -27,12 +83,14 @@ pub fn execute(
             BuiltinMode::RayaStrict
         },
         type_mode: Some(type_mode),
+        features,
         ..Default::default()
     });
     let out_dir = PathBuf::from(&out_dir);
 
     let options = CompileOptions {
         sourcemap,
+        strip_assertions: no_assertions,
         ..Default::default()
     };
 
@@ -43,10 +101,23 @@ pub fn execute(
         anyhow::bail!("No .raya source files found in: {:?}", files);
     }
 
+    if let Some(expected_path) = verify {
+        if emit != EmitKind::Bytecode {
+            anyhow::bail!("--verify only supports --emit=bytecode");
+        }
+        let [src_path] = source_files.as_slice() else {
+            anyhow::bail!(
+                "--verify expects exactly one source file, found {}",
+                source_files.len()
+            );
+        };
+        return verify_build(&rt, src_path, &options, &expected_path);
+    }
+
     println!("Building {} file(s)...", source_files.len());
 
     for src_path in &source_files {
-        let out_path = compute_output_path(src_path, &out_dir);
+        let out_path = compute_output_path(src_path, &out_dir, emit);
 
         if dry_run {
             println!(
@@ -57,21 +128,194 @@ pub fn execute(
             continue;
         }
 
-        let compiled = rt
-            .compile_file_with_options(src_path, &options)
-            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        let output = match emit {
+            EmitKind::Ir => rt
+                .compile_file_to_ir(src_path, &options)
+                .map_err(|e| anyhow::anyhow!("{}", e))?
+                .into_bytes(),
+            EmitKind::Bytecode | EmitKind::Disasm => {
+                let program = rt
+                    .compile_program_file_with_options(src_path, &options)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+                for warning in &program.warnings {
+                    eprintln!("warning: {}", warning);
+                }
+                let compiled = program.entry;
+                if emit == EmitKind::Disasm {
+                    disassemble_module(&compiled).into_bytes()
+                } else {
+                    compiled.encode()
+                }
+            }
+        };
 
         if let Some(parent) = out_path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        std::fs::write(&out_path, compiled.encode())?;
+        std::fs::write(&out_path, output)?;
         println!("  {} → {}", src_path.display(), out_path.display());
     }
 
     Ok(())
 }
 
+/// Rebuild `src_path` and compare the bytecode against `expected_path`.
+fn verify_build(
+    rt: &Runtime,
+    src_path: &Path,
+    options: &CompileOptions,
+    expected_path: &Path,
+) -> anyhow::Result<()> {
+    let expected = std::fs::read(expected_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", expected_path.display(), e))?;
+    let rebuilt = rt
+        .compile_file_with_options(src_path, options)
+        .map_err(|e| anyhow::anyhow!("{}", e))?
+        .encode();
+
+    if rebuilt != expected {
+        anyhow::bail!(
+            "Verification failed: {} does not rebuild to {}\n{}",
+            src_path.display(),
+            expected_path.display(),
+            bytecode_diff_summary(&expected, &rebuilt)
+        );
+    }
+
+    println!(
+        "Verified {} matches {} ({} bytes)",
+        src_path.display(),
+        expected_path.display(),
+        rebuilt.len()
+    );
+    Ok(())
+}
+
+/// Describe how rebuilt bytecode differs from the expected artifact.
+fn bytecode_diff_summary(expected: &[u8], rebuilt: &[u8]) -> String {
+    let mut summary = String::new();
+    writeln!(
+        summary,
+        "  size: expected {} bytes, rebuilt {} bytes",
+        expected.len(),
+        rebuilt.len()
+    )
+    .unwrap();
+
+    let first_difference = expected
+        .iter()
+        .zip(rebuilt)
+        .position(|(a, b)| a != b)
+        .unwrap_or(expected.len().min(rebuilt.len()));
+    let differing = expected.iter().zip(rebuilt).filter(|(a, b)| a != b).count()
+        + expected.len().abs_diff(rebuilt.len());
+    writeln!(
+        summary,
+        "  first difference at byte offset {} ({} bytes differ)",
+        first_difference, differing
+    )
+    .unwrap();
+
+    match (Module::decode(expected), Module::decode(rebuilt)) {
+        (Ok(expected), Ok(rebuilt)) => {
+            for section in module_section_diffs(&expected, &rebuilt) {
+                writeln!(summary, "  {}", section).unwrap();
+            }
+        }
+        (Err(e), _) => {
+            writeln!(summary, "  expected artifact is not valid bytecode: {}", e).unwrap()
+        }
+        (_, Err(e)) => writeln!(summary, "  rebuilt bytecode failed to decode: {}", e).unwrap(),
+    }
+    summary.trim_end().to_string()
+}
+
+/// List the module sections that differ between two decoded modules.
+fn module_section_diffs(expected: &Module, rebuilt: &Module) -> Vec<String> {
+    let mut diffs = Vec::new();
+
+    let floats_match = expected
+        .constants
+        .floats
+        .iter()
+        .map(|f| f.to_bits())
+        .eq(rebuilt.constants.floats.iter().map(|f| f.to_bits()));
+    if expected.constants.strings != rebuilt.constants.strings
+        || expected.constants.integers != rebuilt.constants.integers
+        || !floats_match
+    {
+        diffs.push(format!(
+            "constant pool differs (strings {} → {}, integers {} → {}, floats {} → {})",
+            expected.constants.strings.len(),
+            rebuilt.constants.strings.len(),
+            expected.constants.integers.len(),
+            rebuilt.constants.integers.len(),
+            expected.constants.floats.len(),
+            rebuilt.constants.floats.len()
+        ));
+    }
+
+    if expected.functions.len() != rebuilt.functions.len() {
+        diffs.push(format!(
+            "function count differs ({} → {})",
+            expected.functions.len(),
+            rebuilt.functions.len()
+        ));
+    }
+    for (i, (old, new)) in expected
+        .functions
+        .iter()
+        .zip(&rebuilt.functions)
+        .enumerate()
+    {
+        if old.name != new.name || old.code != new.code {
+            diffs.push(format!("function {} ({}) differs", i, new.name));
+        }
+    }
+
+    if expected.classes.len() != rebuilt.classes.len() {
+        diffs.push(format!(
+            "class count differs ({} → {})",
+            expected.classes.len(),
+            rebuilt.classes.len()
+        ));
+    }
+    if expected.exports.len() != rebuilt.exports.len()
+        || expected.imports.len() != rebuilt.imports.len()
+    {
+        diffs.push("imports or exports differ".to_string());
+    }
+    if expected.native_functions != rebuilt.native_functions {
+        diffs.push("native function table differs".to_string());
+    }
+
+    if diffs.is_empty() {
+        diffs.push("metadata, class bodies, reflection or debug info differ".to_string());
+    }
+    diffs
+}
+
+/// Read `[build].features` from the nearest `raya.toml`, if any.
+fn load_manifest_features() -> Vec<String> {
+    let Ok(mut dir) = std::env::current_dir() else {
+        return Vec::new();
+    };
+    loop {
+        let candidate = dir.join("raya.toml");
+        if candidate.is_file() {
+            return raya_pm::PackageManifest::from_file(&candidate)
+                .ok()
+                .and_then(|manifest| manifest.build)
+                .map(|build| build.features)
+                .unwrap_or_default();
+        }
+        if !dir.pop() {
+            return Vec::new();
+        }
+    }
+}
+
 /// Collect all .raya source files from the given paths (files or directories).
 fn collect_raya_files(paths: &[String]) -> anyhow::Result<Vec<PathBuf>> {
     let mut files = Vec::new();
@@ -118,10 +362,27 @@ fn collect_raya_in_dir(dir: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<(
     Ok(())
 }
 
-/// Compute the output .ryb path for a source file.
+/// Render every function of a compiled module as disassembly.
+fn disassemble_module(compiled: &CompiledModule) -> String {
+    let module = compiled.module();
+    let mut output = String::new();
+    writeln!(output, "; module {}", module.metadata.name).unwrap();
+    for (i, func) in module.functions.iter().enumerate() {
+        writeln!(
+            output,
+            "\nFunction {}: {} (locals: {}, params: {})",
+            i, func.name, func.local_count, func.param_count
+        )
+        .unwrap();
+        output.push_str(&disassemble_function(func));
+    }
+    output
+}
+
+/// Compute the output path for a source file and emitted artifact.
 ///
-/// `src/main.raya` → `dist/src/main.ryb`
-fn compute_output_path(src: &Path, out_dir: &Path) -> PathBuf {
-    let stem = src.with_extension("ryb");
+/// `src/main.raya` → `dist/src/main.ryb` (or `.rir` / `.disasm`)
+fn compute_output_path(src: &Path, out_dir: &Path, emit: EmitKind) -> PathBuf {
+    let stem = src.with_extension(emit.extension());
     out_dir.join(stem.file_name().unwrap_or_default())
 }
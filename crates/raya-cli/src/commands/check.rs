@@ -16,6 +16,8 @@ pub fn execute(
     allow: Vec<String>,
     deny: Vec<String>,
     no_warnings: bool,
+    max_warnings: Option<usize>,
+    strict_unknown: bool,
     node_compat: bool,
     type_mode: TypeMode,
 ) -> anyhow::Result<()> {
@@ -30,7 +32,7 @@ pub fn execute(
         anyhow::bail!("--mode ts/js requires --node-compat");
     }
 
-    let warning_config = build_warning_config(strict, &allow, &deny, no_warnings);
+    let warning_config = build_warning_config(strict, &allow, &deny);
 
     let mut total_errors = 0usize;
     let mut total_warnings = 0usize;
@@ -68,6 +70,7 @@ pub fn execute(
             },
             type_mode: Some(type_mode),
             ts_options,
+            strict_unknown,
             ..Default::default()
         });
 
@@ -104,25 +107,26 @@ pub fn execute(
             }
         }
 
-        // Emit warnings (filtered by config)
-        if !no_warnings {
-            for warn in &diagnostics.warnings {
-                let code = warn.code();
-                if !warning_config.is_enabled(code) {
-                    continue;
-                }
+        // Emit warnings (filtered by config). `--no-warnings` only hides
+        // them; they still count toward `--max-warnings`.
+        for warn in &diagnostics.warnings {
+            let code = warn.code();
+            if !warning_config.is_enabled(code) {
+                continue;
+            }
 
-                if let Some(adjusted) = adjust_warning(warn, offset) {
-                    if warning_config.is_denied(code) {
-                        // Promoted to error — emit as error
+            if let Some(adjusted) = adjust_warning(warn, offset) {
+                if warning_config.is_denied(code) {
+                    // Promoted to error — emit as error
+                    let diag = Diagnostic::from_check_warning(&adjusted, file_id);
+                    emit_diagnostic(&diag, &cs_files, &format);
+                    total_errors += 1;
+                } else {
+                    if !no_warnings {
                         let diag = Diagnostic::from_check_warning(&adjusted, file_id);
                         emit_diagnostic(&diag, &cs_files, &format);
-                        total_errors += 1;
-                    } else {
-                        let diag = Diagnostic::from_check_warning(&adjusted, file_id);
-                        emit_diagnostic(&diag, &cs_files, &format);
-                        total_warnings += 1;
                     }
+                    total_warnings += 1;
                 }
             }
         }
@@ -165,6 +169,16 @@ pub fn execute(
         std::process::exit(1);
     }
 
+    if let Some(max) = max_warnings {
+        if total_warnings > max {
+            eprintln!(
+                "Too many warnings: {} (maximum allowed is {}).",
+                total_warnings, max
+            );
+            std::process::exit(1);
+        }
+    }
+
     Ok(())
 }
 
@@ -181,28 +195,13 @@ fn emit_diagnostic(diag: &Diagnostic, files: &SimpleFiles<String, String>, forma
     }
 }
 
-fn build_warning_config(
-    strict: bool,
-    allow: &[String],
-    deny: &[String],
-    no_warnings: bool,
-) -> WarningConfig {
+fn build_warning_config(strict: bool, allow: &[String], deny: &[String]) -> WarningConfig {
     let mut config = if strict {
         WarningConfig::strict()
     } else {
         WarningConfig::default()
     };
 
-    if no_warnings {
-        // Disable all known warning codes
-        config.disabled.insert(WarningCode::UnusedVariable);
-        config.disabled.insert(WarningCode::UnusedImport);
-        config.disabled.insert(WarningCode::UnusedParameter);
-        config.disabled.insert(WarningCode::UnreachableCode);
-        config.disabled.insert(WarningCode::ShadowedVariable);
-        config.disabled.insert(WarningCode::UnsupportedTsFlag);
-    }
-
     for name in allow {
         if let Some(code) = WarningCode::from_name(name) {
             config.disabled.insert(code);
@@ -260,6 +259,7 @@ fn adjust_check_error_spans(err: &mut CheckError, offset: usize) {
         | CheckError::UndefinedVariable { span, .. }
         | CheckError::NotCallable { span, .. }
         | CheckError::ArgumentCountMismatch { span, .. }
+        | CheckError::NoMatchingOverload { span, .. }
         | CheckError::NonExhaustiveMatch { span, .. }
         | CheckError::PropertyNotFound { span, .. }
         | CheckError::ReturnTypeMismatch { span, .. }
@@ -277,6 +277,8 @@ fn adjust_check_error_spans(err: &mut CheckError, offset: usize) {
         | CheckError::DecoratorSignatureMismatch { span, .. }
         | CheckError::DecoratorReturnMismatch { span, .. }
         | CheckError::ReadonlyAssignment { span, .. }
+        | CheckError::ReadonlyArrayMutation { span, .. }
+        | CheckError::UnsatisfiedType { span, .. }
         | CheckError::ConstReassignment { span, .. }
         | CheckError::NewNonClass { span, .. }
         | CheckError::StrictAnyForbidden { span }
@@ -293,6 +295,14 @@ fn adjust_check_error_spans(err: &mut CheckError, offset: usize) {
                 *span = new_span;
             }
         }
+        CheckError::IncompatibleFieldShadow {
+            span, parent_span, ..
+        } => {
+            if let Some(new_span) = adjust_span(*span, offset) {
+                *span = new_span;
+            }
+            *parent_span = parent_span.and_then(|parent| adjust_span(parent, offset));
+        }
     }
 }
 
@@ -308,6 +318,10 @@ fn adjust_bind_error(err: &BindError, offset: usize) -> Option<BindError> {
             original,
             duplicate,
             ..
+        }
+        | BindError::DuplicateDefaultExport {
+            original,
+            duplicate,
         } => {
             if let Some(s) = adjust_span(*duplicate, offset) {
                 *duplicate = s;
@@ -342,12 +356,14 @@ fn adjust_warning(warn: &CheckWarning, offset: usize) -> Option<CheckWarning> {
 
     let mut adjusted = warn.clone();
     match &mut adjusted {
-        CheckWarning::UnusedVariable { span, .. } => {
+        CheckWarning::UnusedVariable { span, .. } | CheckWarning::UnusedImport { span, .. } => {
             if let Some(s) = adjust_span(*span, offset) {
                 *span = s;
             }
         }
-        CheckWarning::UnsupportedTsFlag { span, .. } => {
+        CheckWarning::UnsupportedTsFlag { span, .. }
+        | CheckWarning::InferredUnknown { span, .. }
+        | CheckWarning::SuspiciousEquality { span, .. } => {
             if let Some(s) = adjust_span(*span, offset) {
                 *span = s;
             }
@@ -367,6 +383,16 @@ fn adjust_warning(warn: &CheckWarning, offset: usize) -> Option<CheckWarning> {
                 *original = s;
             }
         }
+        CheckWarning::Deprecated {
+            span, declaration, ..
+        } => {
+            if let Some(s) = adjust_span(*span, offset) {
+                *span = s;
+            }
+            if let Some(s) = adjust_span(*declaration, offset) {
+                *declaration = s;
+            }
+        }
     }
     Some(adjusted)
 }
@@ -1,16 +1,616 @@
 //! `raya doc` — Generate documentation.
+//!
+//! Every source file becomes one page listing its exported declarations
+//! together with the `/** ... */` comment written above them, and an index
+//! page links the pages together.
+//!
+//! With `--serve` the output directory is served over HTTP. Sources are polled
+//! for changes; only the pages of changed files (and the index) are rebuilt,
+//! after which connected browsers are told to reload through a server-sent
+//! event stream.
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Context;
+use raya_engine::parser::ast::{ExportDecl, Pattern, Statement, VariableKind};
+use raya_engine::parser::{Interner, Parser};
+
+use super::files::collect_raya_files;
+
+/// Endpoint the injected script listens on for reload events.
+const RELOAD_PATH: &str = "/__raya_reload";
+
+/// How often `--serve` checks the sources for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long a reload stream stays silent before sending a keep-alive.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+const LIVE_RELOAD_SCRIPT: &str = "<script>new EventSource(\"/__raya_reload\")\
+.onmessage = () => location.reload();</script>";
 
 pub fn execute(
+    files: Vec<String>,
     out_dir: String,
     serve: bool,
+    port: u16,
     open: bool,
     format: String,
 ) -> anyhow::Result<()> {
-    println!("Generating documentation...");
-    println!("  Output: {}", out_dir);
-    println!("  Format: {}", format);
-    if serve { println!("  Server: enabled"); }
-    if open { println!("  Open in browser: enabled"); }
-    eprintln!("(Not yet implemented)");
+    let format = DocFormat::parse(&format)?;
+    if serve && format != DocFormat::Html {
+        anyhow::bail!("--serve requires --format html");
+    }
+
+    let mut site = DocSite::new(files, PathBuf::from(out_dir), format);
+    site.refresh()?;
+    if site.sources.is_empty() {
+        eprintln!("No .raya files found.");
+        std::process::exit(1);
+    }
+    println!(
+        "Generated documentation for {} file(s) in {}",
+        site.sources.len(),
+        site.out_dir.display()
+    );
+
+    if serve {
+        return serve_docs(site, port, open);
+    }
+    if open {
+        let index = site.out_dir.join(format!("index.{}", format.extension()));
+        open_in_browser(&index.display().to_string());
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Generation
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocFormat {
+    Html,
+    Markdown,
+}
+
+impl DocFormat {
+    fn parse(name: &str) -> anyhow::Result<Self> {
+        match name {
+            "html" => Ok(DocFormat::Html),
+            "markdown" | "md" => Ok(DocFormat::Markdown),
+            other => anyhow::bail!("unknown doc format '{}' (expected html or markdown)", other),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Html => "html",
+            DocFormat::Markdown => "md",
+        }
+    }
+}
+
+/// One documented export.
+#[derive(Debug, Clone, PartialEq)]
+struct DocItem {
+    kind: &'static str,
+    name: String,
+    signature: String,
+    doc: Option<String>,
+}
+
+/// Generated documentation for a set of inputs, tracking what each page was
+/// built from so `refresh` only rebuilds what changed.
+struct DocSite {
+    inputs: Vec<String>,
+    out_dir: PathBuf,
+    format: DocFormat,
+    /// Source file -> (modification time, length) at its last build
+    sources: BTreeMap<PathBuf, (SystemTime, u64)>,
+}
+
+impl DocSite {
+    fn new(inputs: Vec<String>, out_dir: PathBuf, format: DocFormat) -> Self {
+        Self {
+            inputs,
+            out_dir,
+            format,
+            sources: BTreeMap::new(),
+        }
+    }
+
+    /// Rebuild the pages of new or modified sources, drop pages of deleted
+    /// ones, and rewrite the index if anything changed. Returns the sources
+    /// whose pages were rebuilt or removed.
+    fn refresh(&mut self) -> anyhow::Result<Vec<PathBuf>> {
+        std::fs::create_dir_all(&self.out_dir)
+            .with_context(|| format!("creating {}", self.out_dir.display()))?;
+
+        let mut current = BTreeMap::new();
+        for path in collect_raya_files(&self.inputs)? {
+            let meta = std::fs::metadata(&path)?;
+            current.insert(path, (meta.modified()?, meta.len()));
+        }
+
+        let mut changed = Vec::new();
+        for (path, stamp) in &current {
+            if self.sources.get(path) != Some(stamp) {
+                self.write_page(path)?;
+                changed.push(path.clone());
+            }
+        }
+        for path in self.sources.keys() {
+            if !current.contains_key(path) {
+                let _ = std::fs::remove_file(self.page_path(path));
+                changed.push(path.clone());
+            }
+        }
+
+        self.sources = current;
+        if !changed.is_empty() {
+            self.write_index()?;
+        }
+        Ok(changed)
+    }
+
+    /// Page name for a source: its path relative to the input it was found
+    /// under, without the extension, with `.` between components.
+    fn page_stem(&self, source: &Path) -> String {
+        let relative = self
+            .inputs
+            .iter()
+            .map(Path::new)
+            .filter_map(|input| {
+                let root = if input.is_file() {
+                    input.parent()?
+                } else {
+                    input
+                };
+                source.strip_prefix(root).ok()
+            })
+            .min_by_key(|rel| rel.as_os_str().len())
+            .unwrap_or(source);
+        relative
+            .with_extension("")
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => Some(part.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn page_path(&self, source: &Path) -> PathBuf {
+        self.out_dir.join(format!(
+            "{}.{}",
+            self.page_stem(source),
+            self.format.extension()
+        ))
+    }
+
+    fn write_page(&self, source: &Path) -> anyhow::Result<()> {
+        let text = std::fs::read_to_string(source)
+            .with_context(|| format!("reading {}", source.display()))?;
+        let title = self.page_stem(source);
+        let body = match self.format {
+            DocFormat::Html => render_html_page(&title, &extract_items(&text)),
+            DocFormat::Markdown => render_markdown_page(&title, &extract_items(&text)),
+        };
+        let page = self.page_path(source);
+        std::fs::write(&page, body).with_context(|| format!("writing {}", page.display()))
+    }
+
+    fn write_index(&self) -> anyhow::Result<()> {
+        let ext = self.format.extension();
+        let stems: Vec<String> = self.sources.keys().map(|s| self.page_stem(s)).collect();
+        let body = match self.format {
+            DocFormat::Html => {
+                let links: String = stems
+                    .iter()
+                    .map(|stem| {
+                        let stem = escape_html(stem);
+                        format!("<li><a href=\"{stem}.{ext}\">{stem}</a></li>\n")
+                    })
+                    .collect();
+                html_document("Documentation", &format!("<ul>\n{}</ul>", links))
+            }
+            DocFormat::Markdown => {
+                let links: String = stems
+                    .iter()
+                    .map(|stem| format!("- [{stem}]({stem}.{ext})\n"))
+                    .collect();
+                format!("# Documentation\n\n{}", links)
+            }
+        };
+        let index = self.out_dir.join(format!("index.{}", ext));
+        std::fs::write(&index, body).with_context(|| format!("writing {}", index.display()))
+    }
+}
+
+/// Collect the exported declarations of a source file. Sources that fail to
+/// parse yield an error message in place of the items.
+fn extract_items(source: &str) -> Result<Vec<DocItem>, String> {
+    let parser = Parser::new(source).map_err(|errors| match errors.first() {
+        Some(e) => e.to_string(),
+        None => "lex error".to_string(),
+    })?;
+    let (module, interner) = parser.parse().map_err(|errors| match errors.first() {
+        Some(e) => e.to_string(),
+        None => "parse error".to_string(),
+    })?;
+
+    let items = module
+        .statements
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Statement::ExportDecl(ExportDecl::Declaration(decl)) => {
+                describe_declaration(decl, &interner, source)
+            }
+            _ => None,
+        })
+        .collect();
+    Ok(items)
+}
+
+fn describe_declaration(decl: &Statement, interner: &Interner, source: &str) -> Option<DocItem> {
+    let (kind, name) = match decl {
+        Statement::FunctionDecl(f) => ("function", &f.name),
+        Statement::ClassDecl(c) => ("class", &c.name),
+        Statement::TypeAliasDecl(t) => ("type", &t.name),
+        Statement::VariableDecl(v) => match &v.pattern {
+            Pattern::Identifier(id) => match v.kind {
+                VariableKind::Let => ("let", id),
+                _ => ("const", id),
+            },
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let start = decl.span().start;
+    let first_line = source[start..].lines().next().unwrap_or("");
+    let signature = first_line
+        .trim()
+        .trim_end_matches('{')
+        .trim_end()
+        .to_string();
+    Some(DocItem {
+        kind,
+        name: interner.resolve(name.name).to_string(),
+        signature,
+        doc: doc_comment_before(source, start),
+    })
+}
+
+/// The `/** ... */` block directly above the declaration starting at `start`
+/// (an `export` keyword in between is skipped), with its `*` gutters removed.
+fn doc_comment_before(source: &str, start: usize) -> Option<String> {
+    let before = source[..start].trim_end();
+    let before = before.strip_suffix("export").unwrap_or(before).trim_end();
+    let body = before.strip_suffix("*/")?;
+    let open = body.rfind("/**")?;
+    let text = body[open + 3..]
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').map(str::trim_start).unwrap_or(line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn render_html_page(title: &str, items: &Result<Vec<DocItem>, String>) -> String {
+    let mut body = String::from("<p><a href=\"index.html\">Index</a></p>\n");
+    match items {
+        Err(message) => {
+            body.push_str(&format!(
+                "<p class=\"error\">Could not parse this file: {}</p>\n",
+                escape_html(message)
+            ));
+        }
+        Ok(items) if items.is_empty() => body.push_str("<p>No exported declarations.</p>\n"),
+        Ok(items) => {
+            for item in items {
+                body.push_str(&format!(
+                    "<section id=\"{name}\">\n<h2>{kind} {name}</h2>\n\
+                     <pre><code>{sig}</code></pre>\n",
+                    kind = item.kind,
+                    name = escape_html(&item.name),
+                    sig = escape_html(&item.signature),
+                ));
+                if let Some(doc) = &item.doc {
+                    for paragraph in doc.split("\n\n") {
+                        body.push_str(&format!("<p>{}</p>\n", escape_html(paragraph)));
+                    }
+                }
+                body.push_str("</section>\n");
+            }
+        }
+    }
+    html_document(title, &body)
+}
+
+fn render_markdown_page(title: &str, items: &Result<Vec<DocItem>, String>) -> String {
+    let mut out = format!("# {}\n\n[Index](index.md)\n\n", title);
+    match items {
+        Err(message) => out.push_str(&format!("Could not parse this file: {}\n", message)),
+        Ok(items) if items.is_empty() => out.push_str("No exported declarations.\n"),
+        Ok(items) => {
+            for item in items {
+                out.push_str(&format!(
+                    "## {} {}\n\n```raya\n{}\n```\n\n",
+                    item.kind, item.name, item.signature
+                ));
+                if let Some(doc) = &item.doc {
+                    out.push_str(doc);
+                    out.push_str("\n\n");
+                }
+            }
+        }
+    }
+    out
+}
+
+fn html_document(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         </head>\n<body>\n<h1>{title}</h1>\n{body}</body>\n</html>\n",
+        title = escape_html(title),
+        body = body,
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+// ============================================================================
+// Serving
+// ============================================================================
+
+/// Generation counter bumped after every rebuild; reload streams wait on it.
+#[derive(Default)]
+struct ReloadSignal {
+    generation: Mutex<u64>,
+    changed: Condvar,
+}
+
+impl ReloadSignal {
+    fn current(&self) -> u64 {
+        *self.generation.lock().unwrap()
+    }
+
+    fn bump(&self) {
+        *self.generation.lock().unwrap() += 1;
+        self.changed.notify_all();
+    }
+
+    /// Wait until the generation moves past `seen` or `timeout` elapses.
+    fn wait_past(&self, seen: u64, timeout: Duration) -> u64 {
+        let guard = self.generation.lock().unwrap();
+        let (guard, _) = self
+            .changed
+            .wait_timeout_while(guard, timeout, |generation| *generation == seen)
+            .unwrap();
+        *guard
+    }
+}
+
+fn serve_docs(mut site: DocSite, port: u16, open: bool) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .with_context(|| format!("binding 127.0.0.1:{}", port))?;
+    let url = format!("http://127.0.0.1:{}/", listener.local_addr()?.port());
+    println!("Serving documentation at {}", url);
+
+    let reload = Arc::new(ReloadSignal::default());
+    let out_dir = site.out_dir.clone();
+
+    {
+        let reload = Arc::clone(&reload);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(POLL_INTERVAL);
+            match site.refresh() {
+                Ok(changed) if !changed.is_empty() => {
+                    for path in &changed {
+                        println!("  Regenerated {}", path.display());
+                    }
+                    reload.bump();
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Error regenerating documentation: {}", e),
+            }
+        });
+    }
+
+    if open {
+        open_in_browser(&url);
+    }
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        let out_dir = out_dir.clone();
+        let reload = Arc::clone(&reload);
+        std::thread::spawn(move || {
+            let _ = handle_connection(stream, &out_dir, &reload);
+        });
+    }
     Ok(())
 }
+
+fn handle_connection(
+    mut stream: TcpStream,
+    out_dir: &Path,
+    reload: &ReloadSignal,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain headers; nothing in them changes the response.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && header != "\r\n" && header != "\n" {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or(""), parts.next().unwrap_or("/"));
+    let path = target.split('?').next().unwrap_or("/");
+
+    if method != "GET" {
+        return write_response(&mut stream, "405 Method Not Allowed", "text/plain", b"");
+    }
+    if path == RELOAD_PATH {
+        return stream_reloads(stream, reload);
+    }
+
+    match resolve_request_path(out_dir, path)
+        .and_then(|file| std::fs::read(&file).ok().map(|b| (file, b)))
+    {
+        Some((file, mut body)) => {
+            let content_type = content_type_for(&file);
+            if content_type.starts_with("text/html") {
+                body = inject_reload_script(&String::from_utf8_lossy(&body)).into_bytes();
+            }
+            write_response(&mut stream, "200 OK", content_type, &body)
+        }
+        None => write_response(&mut stream, "404 Not Found", "text/plain", b"Not Found"),
+    }
+}
+
+fn stream_reloads(mut stream: TcpStream, reload: &ReloadSignal) -> std::io::Result<()> {
+    stream.write_all(
+        b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\n\
+          Cache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n",
+    )?;
+    let mut seen = reload.current();
+    loop {
+        let now = reload.wait_past(seen, KEEP_ALIVE_INTERVAL);
+        if now != seen {
+            seen = now;
+            stream.write_all(b"data: reload\n\n")?;
+        } else {
+            // Comment line: keeps the connection open and detects closed tabs.
+            stream.write_all(b": ping\n\n")?;
+        }
+        stream.flush()?;
+    }
+}
+
+fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-cache\r\n\
+         Connection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+/// Map a request path onto a file under `out_dir`, refusing anything that
+/// would escape it.
+fn resolve_request_path(out_dir: &Path, path: &str) -> Option<PathBuf> {
+    let relative = path.trim_start_matches('/');
+    let relative = if relative.is_empty() {
+        "index.html"
+    } else {
+        relative
+    };
+    let relative = Path::new(relative);
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)))
+    {
+        return None;
+    }
+    let file = out_dir.join(relative);
+    file.is_file().then_some(file)
+}
+
+fn content_type_for(file: &Path) -> &'static str {
+    match file.extension().and_then(|e| e.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("json") => "application/json",
+        Some("md") => "text/markdown; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        _ => "application/octet-stream",
+    }
+}
+
+fn inject_reload_script(html: &str) -> String {
+    match html.rfind("</body>") {
+        Some(at) => format!("{}{}\n{}", &html[..at], LIVE_RELOAD_SCRIPT, &html[at..]),
+        None => format!("{}{}\n", html, LIVE_RELOAD_SCRIPT),
+    }
+}
+
+fn open_in_browser(target: &str) {
+    let result = if cfg!(target_os = "macos") {
+        std::process::Command::new("open").arg(target).spawn()
+    } else if cfg!(windows) {
+        std::process::Command::new("cmd")
+            .args(["/C", "start", "", target])
+            .spawn()
+    } else {
+        std::process::Command::new("xdg-open").arg(target).spawn()
+    };
+    if let Err(e) = result {
+        eprintln!("Could not open a browser: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_exports_with_doc_comments() {
+        let source = "/**\n * Adds two numbers.\n */\n\
+                      export function add(a: number, b: number): number {\n    return a + b;\n}\n\n\
+                      function hidden(): void {}\n";
+        let items = extract_items(source).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind, "function");
+        assert_eq!(items[0].name, "add");
+        assert_eq!(
+            items[0].signature,
+            "function add(a: number, b: number): number"
+        );
+        assert_eq!(items[0].doc.as_deref(), Some("Adds two numbers."));
+    }
+
+    #[test]
+    fn request_paths_cannot_leave_out_dir() {
+        let dir = std::env::temp_dir();
+        assert!(resolve_request_path(&dir, "/../etc/passwd").is_none());
+        assert!(resolve_request_path(&dir, "/a/../../b").is_none());
+    }
+}
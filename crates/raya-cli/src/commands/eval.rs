@@ -6,6 +6,7 @@ pub fn execute(
     code: String,
     print: bool,
     no_print: bool,
+    json: bool,
     no_jit: bool,
     jit_threshold: u32,
     node_compat: bool,
@@ -40,6 +41,14 @@ pub fn execute(
         .eval(&source)
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
+    if json {
+        let encoded = session
+            .format_value_json(&value)
+            .map_err(|e| anyhow::anyhow!("Cannot print result as JSON: {}", e))?;
+        println!("{}", encoded);
+        return Ok(());
+    }
+
     // Print result unless --no-print, or if --print forces it
     if !no_print && (print || !value.is_null()) {
         println!("{}", session.format_value(&value));
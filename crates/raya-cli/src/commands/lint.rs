@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use crate::output::{resolve_color_choice, StyledOutput};
 use raya_engine::linter::{LintConfig, LintDiagnostic, Linter, Severity};
 use raya_engine::parser::checker::diagnostic::{Diagnostic, ErrorCode, SimpleFiles};
+use raya_pm::LintRuleSetting;
 
 use super::files::collect_raya_files;
 
@@ -304,11 +305,21 @@ fn load_lint_config() -> Option<LintConfig> {
     let lint_manifest = manifest.lint?;
 
     let mut config = LintConfig::new();
-    for (rule_name, severity_str) in &lint_manifest.rules {
-        let severity = match severity_str.as_str() {
-            "off" => Severity::Off,
-            "warn" | "warning" => Severity::Warn,
-            "error" => Severity::Error,
+    for (rule_name, setting) in &lint_manifest.rules {
+        let (enabled, severity) = match setting {
+            LintRuleSetting::Severity(severity) => (None, Some(severity)),
+            LintRuleSetting::Detailed { enabled, severity } => (*enabled, severity.as_ref()),
+        };
+        if let Some(enabled) = enabled {
+            config.set_enabled(rule_name, enabled);
+        }
+        if enabled == Some(false) {
+            continue;
+        }
+        let severity = match severity.map(String::as_str) {
+            Some("off") => Severity::Off,
+            Some("warn" | "warning") => Severity::Warn,
+            Some("error") => Severity::Error,
             _ => continue,
         };
         config.set_severity(rule_name, severity);
@@ -317,7 +328,7 @@ fn load_lint_config() -> Option<LintConfig> {
 }
 
 /// Walk up from CWD to find `raya.toml`.
-fn find_manifest() -> Option<PathBuf> {
+pub(crate) fn find_manifest() -> Option<PathBuf> {
     let mut dir = std::env::current_dir().ok()?;
     loop {
         let candidate = dir.join("raya.toml");
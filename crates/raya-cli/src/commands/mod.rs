@@ -1,9 +1,11 @@
 pub mod add;
+pub mod bench;
 pub mod build;
 pub mod bundle;
 pub mod check;
 pub mod clean;
 pub mod debug;
+pub mod doc;
 pub mod eval;
 pub mod files;
 pub mod init;
@@ -4,6 +4,7 @@ use anyhow::{anyhow, Context};
 use raya_pm::PackageManifest;
 use raya_runtime::{BuiltinMode, Runtime, RuntimeOptions, TypeMode};
 use std::collections::HashMap;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -17,14 +18,23 @@ pub struct RunArgs {
     pub no_cache: bool,
     pub no_jit: bool,
     pub jit_threshold: u32,
+    pub deterministic_float: bool,
     pub threads: usize,
     pub heap_limit: usize,
     pub timeout: u64,
+    pub stack_size: usize,
+    pub max_open_files: usize,
+    pub max_open_sockets: usize,
     pub list: bool,
     pub cpu_prof: Option<std::path::PathBuf>,
     pub prof_interval: u64,
+    pub profile_use: Option<std::path::PathBuf>,
+    pub profile_gen: Option<std::path::PathBuf>,
+    pub heap_snapshot: Option<std::path::PathBuf>,
     pub node_compat: bool,
     pub type_mode: TypeMode,
+    pub features: Vec<String>,
+    pub no_assertions: bool,
 }
 
 impl RunArgs {
@@ -36,10 +46,17 @@ impl RunArgs {
             threads: self.threads,
             heap_limit: self.heap_limit * 1024 * 1024, // MB → bytes
             timeout: self.timeout,
+            stack_size: self.stack_size * 1024, // KB → bytes
+            max_open_files: self.max_open_files,
+            max_open_sockets: self.max_open_sockets,
             no_jit: self.no_jit,
             jit_threshold: self.jit_threshold,
+            deterministic_float: self.deterministic_float,
             cpu_prof: self.cpu_prof.clone(),
             prof_interval_us: self.prof_interval,
+            profile_use: self.profile_use.clone(),
+            profile_gen: self.profile_gen.clone(),
+            heap_snapshot: self.heap_snapshot.clone(),
             builtin_mode: if self.node_compat {
                 BuiltinMode::NodeCompat
             } else {
@@ -47,6 +64,14 @@ impl RunArgs {
             },
             type_mode: Some(self.type_mode),
             ts_options: None,
+            features: self.features.clone(),
+            strip_assertions: self.no_assertions,
+            strict_unknown: false,
+            program_args: self.args.clone(),
+            native_policy: None,
+            lazy_module_init: true,
+            eager_modules: Vec::new(),
+            module_resolver: None,
         })
     }
 }
@@ -69,14 +94,18 @@ pub fn execute(args: RunArgs) -> anyhow::Result<()> {
 
     match &args.target {
         None => run_default(&rt, &args),
+        Some(target) if target == "-" => run_stdin(&rt),
         Some(target) if looks_like_file(target) => run_file(&rt, target),
         Some(script_name) => run_script(script_name, &rt),
     }
 }
 
 /// Called from main.rs for implicit run: `raya ./file.raya`
-pub fn execute_file(path: &str, _extra_args: &[String]) -> anyhow::Result<()> {
-    let rt = Runtime::new();
+pub fn execute_file(path: &str, extra_args: &[String]) -> anyhow::Result<()> {
+    let rt = Runtime::with_options(RuntimeOptions {
+        program_args: extra_args.to_vec(),
+        ..Default::default()
+    });
     run_file(&rt, path)
 }
 
@@ -95,6 +124,26 @@ fn run_file(rt: &Runtime, path: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Run Raya source piped on stdin (`raya run -`).
+fn run_stdin(rt: &Runtime) -> anyhow::Result<()> {
+    let mut source = String::new();
+    std::io::stdin()
+        .read_to_string(&mut source)
+        .context("Failed to read source from stdin")?;
+    if source.trim().is_empty() {
+        eprintln!("No source provided on stdin; nothing to run.");
+        return Ok(());
+    }
+
+    let exit_code = rt.run_source(&source).map_err(|e| anyhow!("{}", e))?;
+
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+
+    Ok(())
+}
+
 fn run_default(rt: &Runtime, _args: &RunArgs) -> anyhow::Result<()> {
     let manifest = load_project_manifest_optional();
 
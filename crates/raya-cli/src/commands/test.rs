@@ -1,9 +1,9 @@
 //! `raya test` — Discover, run, and report tests.
 
 use crate::output::{self, StyledOutput};
-use raya_runtime::test_runner::{self, TestFileResult};
+use raya_runtime::test_runner::{self, FileCoverage, TestFileResult};
 use raya_runtime::RuntimeOptions;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 use termcolor::Color;
 
@@ -13,6 +13,9 @@ pub struct TestArgs {
     pub filter: Option<String>,
     pub watch: bool,
     pub coverage: bool,
+    pub min_coverage: Option<f64>,
+    pub min_line_coverage: Option<f64>,
+    pub min_branch_coverage: Option<f64>,
     pub bail: bool,
     pub timeout: u64,
     pub concurrency: usize,
@@ -43,6 +46,12 @@ pub fn execute(args: TestArgs) -> anyhow::Result<()> {
         ..Default::default()
     };
 
+    // Coverage thresholds on the command line imply --coverage.
+    let coverage = args.coverage
+        || args.min_coverage.is_some()
+        || args.min_line_coverage.is_some()
+        || args.min_branch_coverage.is_some();
+
     let overall_start = Instant::now();
     let mut all_results: Vec<TestFileResult> = Vec::new();
     let mut any_failure = false;
@@ -50,7 +59,11 @@ pub fn execute(args: TestArgs) -> anyhow::Result<()> {
     // Run test files
     // TODO: parallel execution when concurrency > 1
     for test_file in &test_files {
-        let result = test_runner::run_test_file(test_file, &options);
+        let result = if coverage {
+            test_runner::run_test_file_with_coverage(test_file, &options)
+        } else {
+            test_runner::run_test_file(test_file, &options)
+        };
 
         match result {
             Ok(file_result) => {
@@ -112,6 +125,20 @@ pub fn execute(args: TestArgs) -> anyhow::Result<()> {
         ),
     }
 
+    if coverage {
+        if args.reporter != "json" {
+            print_coverage(&mut out, &all_results);
+        }
+        let below = files_below_threshold(&all_results, CoverageThresholds::resolve(&args));
+        if !below.is_empty() {
+            out.stderr_error("Coverage below threshold:\n");
+            for line in &below {
+                out.stderr_error(&format!("  {}\n", line));
+            }
+            any_failure = true;
+        }
+    }
+
     if any_failure {
         std::process::exit(1);
     }
@@ -330,6 +357,130 @@ fn print_failure_details(out: &mut StyledOutput, results: &[TestFileResult]) {
     }
 }
 
+// ── Coverage ─────────────────────────────────────────────────────────────
+
+/// Minimum coverage percentages every test file must meet.
+#[derive(Debug, Clone, Copy, Default)]
+struct CoverageThresholds {
+    line: Option<f64>,
+    branch: Option<f64>,
+}
+
+impl CoverageThresholds {
+    /// Resolve thresholds from the command line, falling back to the `[test]`
+    /// section of raya.toml. Per-metric values override `min_coverage`.
+    fn resolve(args: &TestArgs) -> Self {
+        let config = load_test_config().unwrap_or_default();
+        Self {
+            line: args
+                .min_line_coverage
+                .or(args.min_coverage)
+                .or(config.min_line_coverage)
+                .or(config.min_coverage),
+            branch: args
+                .min_branch_coverage
+                .or(args.min_coverage)
+                .or(config.min_branch_coverage)
+                .or(config.min_coverage),
+        }
+    }
+}
+
+fn load_test_config() -> Option<raya_pm::TestManifestConfig> {
+    let manifest_path = super::lint::find_manifest()?;
+    let manifest = raya_pm::PackageManifest::from_file(&manifest_path).ok()?;
+    manifest.test
+}
+
+/// Describe every file whose line or branch coverage misses its threshold.
+fn files_below_threshold(
+    results: &[TestFileResult],
+    thresholds: CoverageThresholds,
+) -> Vec<String> {
+    let mut below = Vec::new();
+    for coverage in covered_files(results) {
+        let display_path = relative_to_cwd(Path::new(&coverage.path));
+        if let Some(min) = thresholds.line {
+            let actual = coverage.line_percent();
+            if actual < min {
+                below.push(format!(
+                    "{}: lines {:.1}% < {:.1}%",
+                    display_path, actual, min
+                ));
+            }
+        }
+        if let Some(min) = thresholds.branch {
+            let actual = coverage.branch_percent();
+            if actual < min {
+                below.push(format!(
+                    "{}: branches {:.1}% < {:.1}%",
+                    display_path, actual, min
+                ));
+            }
+        }
+    }
+    below
+}
+
+/// Every file covered by the runs: each test file, then the files it imports.
+fn covered_files(results: &[TestFileResult]) -> impl Iterator<Item = &FileCoverage> {
+    results
+        .iter()
+        .filter_map(|result| result.coverage.as_ref())
+        .flat_map(|report| report.files.iter())
+}
+
+fn print_coverage(out: &mut StyledOutput, results: &[TestFileResult]) {
+    out.newline();
+    out.bold("Coverage:");
+    out.newline();
+    out.dim(&format!(
+        "  {:<40} {:>8} {:>9}  Uncovered lines",
+        "File", "Lines", "Branches"
+    ));
+    out.newline();
+
+    for coverage in covered_files(results) {
+        out.plain(&format!(
+            "  {:<40} {:>7.1}% {:>8.1}%  ",
+            relative_to_cwd(Path::new(&coverage.path)),
+            coverage.line_percent(),
+            coverage.branch_percent()
+        ));
+        out.dim(&format_line_ranges(&coverage.uncovered_lines()));
+        out.newline();
+    }
+}
+
+fn relative_to_cwd(path: &Path) -> String {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    path.strip_prefix(&cwd)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Collapse sorted line numbers into ranges: `[3, 4, 5, 9]` → `"3-5, 9"`.
+fn format_line_ranges(lines: &[u32]) -> String {
+    let mut ranges: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let start = lines[i];
+        let mut end = start;
+        while i + 1 < lines.len() && lines[i + 1] == end + 1 {
+            i += 1;
+            end = lines[i];
+        }
+        if start == end {
+            ranges.push(start.to_string());
+        } else {
+            ranges.push(format!("{}-{}", start, end));
+        }
+        i += 1;
+    }
+    ranges.join(", ")
+}
+
 // ── Summary ──────────────────────────────────────────────────────────────
 
 fn print_summary(
@@ -28,7 +28,7 @@ enum Commands {
     /// Run a script or execute a file
     #[command(alias = "r")]
     Run {
-        /// Script name (from scripts in package.json or [scripts] in raya.toml) or file path
+        /// Script name (package.json scripts or raya.toml [scripts]), file path, or `-` for stdin
         target: Option<String>,
         /// Arguments to pass to the program
         #[arg(trailing_var_arg = true)]
@@ -51,6 +51,9 @@ enum Commands {
         /// JIT adaptive compilation call threshold
         #[arg(long, default_value = "1000")]
         jit_threshold: u32,
+        /// Make float results identical between the interpreter and the JIT
+        #[arg(long)]
+        deterministic_float: bool,
         /// Worker thread count (0 = auto)
         #[arg(long, default_value = "0")]
         threads: usize,
@@ -60,6 +63,15 @@ enum Commands {
         /// Maximum execution time in ms (0 = unlimited)
         #[arg(long, default_value = "0")]
         timeout: u64,
+        /// Max stack size per task in KB (0 = default)
+        #[arg(long, default_value = "0")]
+        stack_size: usize,
+        /// Max open file handles (0 = unlimited)
+        #[arg(long, default_value = "0")]
+        max_open_files: usize,
+        /// Max open sockets (0 = unlimited)
+        #[arg(long, default_value = "0")]
+        max_open_sockets: usize,
         /// List available scripts from project manifest
         #[arg(long)]
         list: bool,
@@ -70,12 +82,27 @@ enum Commands {
         /// Profiling sample interval in microseconds (default: 10000 = 100Hz)
         #[arg(long, default_value = "10000")]
         prof_interval: u64,
+        /// JIT-compile the hot functions recorded in this .rayaprof at startup
+        #[arg(long, value_name = "FILE")]
+        profile_use: Option<PathBuf>,
+        /// Record hot functions during the run and write them to this .rayaprof
+        #[arg(long, value_name = "FILE")]
+        profile_gen: Option<PathBuf>,
+        /// Write a Chrome DevTools .heapsnapshot of the heap at exit
+        #[arg(long, value_name = "FILE")]
+        heap_snapshot: Option<PathBuf>,
         /// Enable Node-compatible builtin API surface.
         #[arg(long)]
         node_compat: bool,
         /// Parsing/type mode: raya | ts | js
         #[arg(long)]
         mode: Option<String>,
+        /// Enable a conditional-compilation feature (repeatable)
+        #[arg(long = "feature", value_name = "NAME")]
+        features: Vec<String>,
+        /// Compile calls to the builtin `assert` to nothing
+        #[arg(long)]
+        no_assertions: bool,
     },
 
     /// Debug a Raya script interactively
@@ -112,6 +139,9 @@ enum Commands {
         /// Emit debug source mapping
         #[arg(long)]
         sourcemap: bool,
+        /// Compile calls to the builtin `assert` to nothing
+        #[arg(long)]
+        no_assertions: bool,
         /// Show what would be built without writing
         #[arg(long)]
         dry_run: bool,
@@ -121,6 +151,15 @@ enum Commands {
         /// Parsing/type mode: raya | ts | js
         #[arg(long)]
         mode: Option<String>,
+        /// Enable a conditional-compilation feature (repeatable)
+        #[arg(long = "feature", value_name = "NAME")]
+        features: Vec<String>,
+        /// Artifact to emit: bytecode (default) | ir | disasm
+        #[arg(long, value_name = "KIND")]
+        emit: Option<String>,
+        /// Rebuild and compare byte-for-byte against this .ryb instead of writing output
+        #[arg(long, value_name = "EXPECTED")]
+        verify: Option<PathBuf>,
     },
 
     /// Type-check without building
@@ -144,9 +183,15 @@ enum Commands {
         /// Treat specific warnings as errors (e.g., --deny shadowed-variable)
         #[arg(long = "deny", value_name = "WARNING")]
         deny: Vec<String>,
-        /// Suppress all warnings
+        /// Do not print warnings (they still count toward --max-warnings)
         #[arg(long)]
         no_warnings: bool,
+        /// Fail if more than N warnings remain after --allow/--deny filtering
+        #[arg(long, value_name = "N")]
+        max_warnings: Option<usize>,
+        /// Warn where type inference produces `unknown`/`any` (inferred-unknown)
+        #[arg(long)]
+        strict_unknown: bool,
         /// Enable Node-compatible builtin API surface.
         #[arg(long)]
         node_compat: bool,
@@ -165,6 +210,9 @@ enum Commands {
         /// Don't auto-print the result
         #[arg(long)]
         no_print: bool,
+        /// Print the result serialized as JSON (overrides --print/--no-print)
+        #[arg(long)]
+        json: bool,
         /// Disable JIT for the evaluated code
         #[arg(long)]
         no_jit: bool,
@@ -190,6 +238,15 @@ enum Commands {
         /// Generate coverage report
         #[arg(long)]
         coverage: bool,
+        /// Fail if line or branch coverage of any file is below this percentage
+        #[arg(long, value_name = "PCT")]
+        min_coverage: Option<f64>,
+        /// Fail if line coverage of any file is below this percentage
+        #[arg(long, value_name = "PCT")]
+        min_line_coverage: Option<f64>,
+        /// Fail if branch coverage of any file is below this percentage
+        #[arg(long, value_name = "PCT")]
+        min_branch_coverage: Option<f64>,
         /// Stop after first failure
         #[arg(long)]
         bail: bool,
@@ -213,6 +270,36 @@ enum Commands {
         color: String,
     },
 
+    /// Run benchmarks
+    Bench {
+        /// Benchmark name pattern to match
+        filter: Option<String>,
+        /// Unmeasured warmup iterations per benchmark
+        #[arg(long, default_value = "10")]
+        warmup: usize,
+        /// Measured iterations per benchmark
+        #[arg(long, default_value = "100")]
+        iterations: usize,
+        /// Run only this benchmark under the CPU profiler and write a .cpuprofile
+        #[arg(long, value_name = "NAME")]
+        profile: Option<String>,
+        /// Profile output path (default: <NAME>.cpuprofile)
+        #[arg(long, value_name = "FILE")]
+        profile_out: Option<PathBuf>,
+        /// Profiling sample interval in microseconds (default: 1000 = 1kHz)
+        #[arg(long, default_value = "1000")]
+        prof_interval: u64,
+        /// Filter benchmark files by glob
+        #[arg(long)]
+        file: Option<String>,
+        /// Output results as JSON lines
+        #[arg(long)]
+        json: bool,
+        /// Color output: auto, always, or never
+        #[arg(long, default_value = "auto")]
+        color: String,
+    },
+
     /// Lint source files
     Lint {
         /// Files or directories to lint
@@ -229,6 +316,28 @@ enum Commands {
         watch: bool,
     },
 
+    /// Generate documentation from exported declarations
+    Doc {
+        /// Files or directories to document
+        #[arg(default_value = ".")]
+        files: Vec<String>,
+        /// Output directory
+        #[arg(short, long, default_value = "docs")]
+        out_dir: String,
+        /// Serve the docs over HTTP and rebuild them when sources change
+        #[arg(long)]
+        serve: bool,
+        /// Port for --serve (0 = any free port)
+        #[arg(long, default_value = "8080")]
+        port: u16,
+        /// Open the docs in the default browser
+        #[arg(long)]
+        open: bool,
+        /// Output format: html or markdown
+        #[arg(long, default_value = "html")]
+        format: String,
+    },
+
     /// Start interactive REPL
     Repl {
         /// Disable JIT in REPL
@@ -443,14 +552,23 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             no_cache,
             no_jit,
             jit_threshold,
+            deterministic_float,
             threads,
             heap_limit,
             timeout,
+            stack_size,
+            max_open_files,
+            max_open_sockets,
             list,
             cpu_prof,
             prof_interval,
+            profile_use,
+            profile_gen,
+            heap_snapshot,
             node_compat,
             mode,
+            features,
+            no_assertions,
         } => commands::run::execute(commands::run::RunArgs {
             target,
             args,
@@ -460,14 +578,23 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             no_cache,
             no_jit,
             jit_threshold,
+            deterministic_float,
             threads,
             heap_limit,
             timeout,
+            stack_size,
+            max_open_files,
+            max_open_sockets,
             list,
             cpu_prof,
             prof_interval,
+            profile_use,
+            profile_gen,
+            heap_snapshot,
             node_compat,
             type_mode: resolve_type_mode(mode.as_deref(), node_compat)?,
+            features,
+            no_assertions,
         }),
 
         Commands::Debug {
@@ -483,18 +610,26 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             release,
             watch,
             sourcemap,
+            no_assertions,
             dry_run,
             node_compat,
             mode,
+            features,
+            emit,
+            verify,
         } => commands::build::execute(
             files,
             out_dir,
             release,
             watch,
             sourcemap,
+            no_assertions,
             dry_run,
             node_compat,
             resolve_type_mode(mode.as_deref(), node_compat)?,
+            features,
+            commands::build::parse_emit_kind(emit.as_deref())?,
+            verify,
         ),
 
         Commands::Check {
@@ -505,6 +640,8 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             allow,
             deny,
             no_warnings,
+            max_warnings,
+            strict_unknown,
             node_compat,
             mode,
         } => commands::check::execute(
@@ -515,6 +652,8 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             allow,
             deny,
             no_warnings,
+            max_warnings,
+            strict_unknown,
             node_compat,
             resolve_type_mode(mode.as_deref(), node_compat)?,
         ),
@@ -523,6 +662,7 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             code,
             print,
             no_print,
+            json,
             no_jit,
             jit_threshold,
             node_compat,
@@ -531,6 +671,7 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             code,
             print,
             no_print,
+            json,
             no_jit,
             jit_threshold,
             node_compat,
@@ -541,6 +682,9 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             filter,
             watch,
             coverage,
+            min_coverage,
+            min_line_coverage,
+            min_branch_coverage,
             bail,
             timeout,
             concurrency,
@@ -552,6 +696,9 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             filter,
             watch,
             coverage,
+            min_coverage,
+            min_line_coverage,
+            min_branch_coverage,
             bail,
             timeout,
             concurrency,
@@ -561,6 +708,28 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             color,
         }),
 
+        Commands::Bench {
+            filter,
+            warmup,
+            iterations,
+            profile,
+            profile_out,
+            prof_interval,
+            file,
+            json,
+            color,
+        } => commands::bench::execute(commands::bench::BenchArgs {
+            filter,
+            warmup,
+            iterations,
+            profile,
+            profile_out,
+            prof_interval,
+            file,
+            json,
+            color,
+        }),
+
         Commands::Lint {
             files,
             fix,
@@ -568,6 +737,15 @@ fn dispatch(cmd: Commands) -> anyhow::Result<()> {
             watch,
         } => commands::lint::execute(files, fix, format, watch),
 
+        Commands::Doc {
+            files,
+            out_dir,
+            serve,
+            port,
+            open,
+            format,
+        } => commands::doc::execute(files, out_dir, serve, port, open, format),
+
         Commands::Repl {
             no_jit,
             node_compat,
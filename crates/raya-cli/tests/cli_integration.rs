@@ -599,6 +599,7 @@ fn test_runtime_with_options() {
         builtin_mode: BuiltinMode::RayaStrict,
         type_mode: None,
         ts_options: None,
+        ..Default::default()
     });
 
     let value = rt.eval("return 99;").expect("eval with options failed");
@@ -1120,3 +1121,1271 @@ function main(): void {
         value
     );
 }
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya check --max-warnings`
+// ────────────────────────────────────────────────────────────────────────────
+
+fn run_check_with_max_warnings(max_warnings: &str, extra_args: &[&str]) -> std::process::Output {
+    let path = fixtures_dir().join("warnings/two_unused.raya");
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("check")
+        .arg(&path)
+        .args(["--max-warnings", max_warnings])
+        .args(extra_args)
+        .output()
+        .expect("failed to run raya check")
+}
+
+#[test]
+fn test_check_max_warnings_below_count_fails() {
+    let output = run_check_with_max_warnings("1", &[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !output.status.success(),
+        "expected failure, stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("2 warnings"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_check_max_warnings_at_count_passes() {
+    let output = run_check_with_max_warnings("2", &[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("2 warnings"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_check_no_warnings_still_counts_toward_max_warnings() {
+    let output = run_check_with_max_warnings("0", &["--no-warnings"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !output.status.success(),
+        "expected failure, stderr: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("Too many warnings: 2"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(
+        !stderr.contains("unused"),
+        "warnings were printed: {}",
+        stderr
+    );
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `//@@deprecated` warnings
+// ────────────────────────────────────────────────────────────────────────────
+
+fn run_check_on_deprecated_call(extra_args: &[&str]) -> std::process::Output {
+    let path = fixtures_dir().join("warnings/deprecated_call.raya");
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("check")
+        .arg(&path)
+        .args(extra_args)
+        .output()
+        .expect("failed to run raya check")
+}
+
+#[test]
+fn test_check_warns_on_deprecated_function_call() {
+    let output = run_check_on_deprecated_call(&[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("'oldGreeting' is deprecated: use newGreeting() instead"),
+        "stderr: {}",
+        stderr
+    );
+    // Only the call site warns, not the declaration.
+    assert!(stderr.contains("1 warning"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_check_allow_deprecated_suppresses_warning() {
+    let output = run_check_on_deprecated_call(&["--allow", "deprecated"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+    assert!(!stderr.contains("is deprecated"), "stderr: {}", stderr);
+    assert!(stderr.contains("no errors"), "stderr: {}", stderr);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Suspicious equality
+// ────────────────────────────────────────────────────────────────────────────
+
+fn run_check_on_suspicious_equality(extra_args: &[&str]) -> std::process::Output {
+    let path = fixtures_dir().join("warnings/suspicious_equality.raya");
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("check")
+        .arg(&path)
+        .args(extra_args)
+        .output()
+        .expect("failed to run raya check")
+}
+
+#[test]
+fn test_check_warns_on_disjoint_equality() {
+    let output = run_check_on_suspicious_equality(&[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("This comparison is always false: 'number' and 'string' are never equal"),
+        "stderr: {}",
+        stderr
+    );
+    // `count == 2` compares two numbers and is not flagged.
+    assert!(stderr.contains("1 warning"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_check_allow_suspicious_equality_suppresses_warning() {
+    let output = run_check_on_suspicious_equality(&["--allow", "suspicious-equality"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+    assert!(!stderr.contains("always false"), "stderr: {}", stderr);
+    assert!(stderr.contains("no errors"), "stderr: {}", stderr);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Unused imports
+// ────────────────────────────────────────────────────────────────────────────
+
+const UNUSED_IMPORT_SOURCE: &str =
+    "import { helper } from \"./helpers\";\nimport { shared } from \"./shared\";\nexport { shared };\n";
+
+#[test]
+fn test_check_warns_on_unused_import_and_allow_suppresses() {
+    let dir = unique_temp_dir("unused-import-check");
+    let path = dir.join("main.raya");
+    std::fs::write(&path, UNUSED_IMPORT_SOURCE).unwrap();
+
+    let run = |extra_args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+            .arg("check")
+            .arg(&path)
+            .args(extra_args)
+            .output()
+            .expect("failed to run raya check")
+    };
+
+    let output = run(&[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {}", stderr);
+    assert!(
+        stderr.contains("'helper' is imported but never used"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(!stderr.contains("'shared'"), "stderr: {}", stderr);
+    assert!(stderr.contains("1 warning"), "stderr: {}", stderr);
+
+    let output = run(&["--allow", "unused-import"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {}", stderr);
+    assert!(!stderr.contains("never used"), "stderr: {}", stderr);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_lint_fix_removes_unused_import() {
+    let dir = unique_temp_dir("unused-import-lint");
+    let path = dir.join("main.raya");
+    std::fs::write(&path, UNUSED_IMPORT_SOURCE).unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .current_dir(&dir)
+        .arg("lint")
+        .arg("--fix")
+        .arg(&path)
+        .output()
+        .expect("failed to run raya lint");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "import { shared } from \"./shared\";\nexport { shared };\n"
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya check --strict-unknown`
+// ────────────────────────────────────────────────────────────────────────────
+
+fn run_check_on_inferred_unknown(extra_args: &[&str]) -> std::process::Output {
+    let path = fixtures_dir().join("warnings/inferred_unknown.raya");
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("check")
+        .arg(&path)
+        .args(["--node-compat", "--mode", "js"])
+        .args(extra_args)
+        .output()
+        .expect("failed to run raya check")
+}
+
+#[test]
+fn test_check_strict_unknown_warns_on_unannotated_parameter() {
+    let output = run_check_on_inferred_unknown(&["--strict-unknown"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("return value has inferred type"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("1 warning"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_check_inferred_unknown_is_opt_in_and_filterable() {
+    for extra_args in [
+        &[][..],
+        &["--strict-unknown", "--allow", "inferred-unknown"][..],
+    ] {
+        let output = run_check_on_inferred_unknown(extra_args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            !stderr.contains("inferred type"),
+            "{:?} stderr: {}",
+            extra_args,
+            stderr
+        );
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Default exports
+// ────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_default_exported_class_can_be_imported_and_constructed() {
+    let rt = Runtime::new();
+    let dir = unique_temp_dir("default-export");
+    std::fs::write(
+        dir.join("counter.raya"),
+        r#"
+export default class Counter {
+    value: number;
+    constructor(start: number) {
+        this.value = start;
+    }
+    increment(): void {
+        this.value = this.value + 1;
+    }
+}
+"#,
+    )
+    .unwrap();
+    let main_path = dir.join("main.raya");
+    std::fs::write(
+        &main_path,
+        r#"
+import Counter from "./counter";
+const counter = new Counter(41);
+counter.increment();
+return counter.value;
+"#,
+    )
+    .unwrap();
+
+    let program = rt
+        .compile_program_file(&main_path)
+        .expect("compile program with default import");
+    let value = rt.execute_program(&program).expect("execute program");
+    assert!(
+        value.as_i32() == Some(42) || value.as_f64() == Some(42.0),
+        "Expected 42, got {:?}",
+        value
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_two_default_exports_in_one_module_is_an_error() {
+    let rt = Runtime::new();
+    let dir = unique_temp_dir("duplicate-default-export");
+    let path = dir.join("lib.raya");
+    std::fs::write(
+        &path,
+        r#"
+const a: number = 1;
+const b: number = 2;
+export default a;
+export default b;
+"#,
+    )
+    .unwrap();
+
+    let error = match rt.compile_program_file(&path) {
+        Ok(_) => panic!("expected duplicate default export to be rejected"),
+        Err(error) => error.to_string(),
+    };
+    assert!(
+        error.contains("more than one default export"),
+        "unexpected error: {}",
+        error
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya build --emit`
+// ────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_build_emit_disasm_writes_function_disassembly() {
+    let dir = unique_temp_dir("build-emit-disasm");
+    let src_path = dir.join("main.raya");
+    std::fs::write(
+        &src_path,
+        r#"
+function square(x: number): number { return x * x; }
+function main(): number { return square(11); }
+"#,
+    )
+    .unwrap();
+    let out_dir = dir.join("out");
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("build")
+        .arg(&src_path)
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg("--emit=disasm")
+        .output()
+        .expect("failed to run raya build");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+
+    let disasm_path = out_dir.join("main.disasm");
+    assert!(
+        !out_dir.join("main.ryb").exists(),
+        "--emit=disasm should not write .ryb"
+    );
+    let disasm = std::fs::read_to_string(&disasm_path).expect("read .disasm output");
+
+    let square = disasm
+        .split("\nFunction ")
+        .find(|section| section.contains(": square ("))
+        .unwrap_or_else(|| panic!("missing square disassembly:\n{}", disasm));
+    assert!(square.contains("params: 1"), "{}", square);
+    assert!(
+        square.contains("Fmul") || square.contains("Imul"),
+        "{}",
+        square
+    );
+    assert!(square.contains("Return"), "{}", square);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_build_emit_ir_uses_build_options_and_imports() {
+    let dir = unique_temp_dir("build-emit-ir");
+    std::fs::write(
+        dir.join("util.raya"),
+        "export function scale(x: number): number { return x * 3; }\n",
+    )
+    .unwrap();
+    let src_path = dir.join("main.raya");
+    std::fs::write(
+        &src_path,
+        r#"
+import { scale } from "./util";
+
+//@@if(fast)
+function mode(): number { return 4242; }
+
+//@@if(!fast)
+function mode(): number { return 9191; }
+
+function main(): number {
+    assert(mode() > 0, "mode-marker");
+    return scale(mode());
+}
+"#,
+    )
+    .unwrap();
+    let out_dir = dir.join("out");
+
+    let emit_ir = |extra_args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+            .arg("build")
+            .arg(&src_path)
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .arg("--emit=ir")
+            .args(extra_args)
+            .output()
+            .expect("failed to run raya build");
+        assert!(
+            output.status.success(),
+            "expected success, stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::read_to_string(out_dir.join("main.rir")).expect("read .rir output")
+    };
+
+    let default_ir = emit_ir(&[]);
+    assert!(default_ir.contains("9191"), "{}", default_ir);
+    assert!(!default_ir.contains("4242"), "{}", default_ir);
+    assert!(default_ir.contains("mode-marker"), "{}", default_ir);
+
+    let configured_ir = emit_ir(&["--feature", "fast", "--no-assertions"]);
+    assert!(configured_ir.contains("4242"), "{}", configured_ir);
+    assert!(!configured_ir.contains("9191"), "{}", configured_ir);
+    assert!(!configured_ir.contains("mode-marker"), "{}", configured_ir);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_build_verify_matches_rebuild_and_detects_source_change() {
+    let dir = unique_temp_dir("build-verify");
+    let src_path = dir.join("main.raya");
+    std::fs::write(
+        &src_path,
+        r#"
+export function greet(name: string): string { return "hello " + name; }
+export const answer = 42;
+function main(): number { return greet("raya").length + answer; }
+"#,
+    )
+    .unwrap();
+    let out_dir = dir.join("out");
+
+    let build = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("build")
+        .arg(&src_path)
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to run raya build");
+    assert!(
+        build.status.success(),
+        "build failed, stderr: {}",
+        String::from_utf8_lossy(&build.stderr)
+    );
+    let expected = out_dir.join("main.ryb");
+    assert!(expected.exists(), "build should write main.ryb");
+
+    let verify = |source: &PathBuf| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+            .arg("build")
+            .arg(source)
+            .arg("--verify")
+            .arg(&expected)
+            .output()
+            .expect("failed to run raya build --verify")
+    };
+
+    let matching = verify(&src_path);
+    assert!(
+        matching.status.success(),
+        "rebuild should match, stderr: {}",
+        String::from_utf8_lossy(&matching.stderr)
+    );
+    assert!(String::from_utf8_lossy(&matching.stdout).contains("Verified"));
+
+    std::fs::write(
+        &src_path,
+        r#"
+export function greet(name: string): string { return "hi " + name; }
+export const answer = 42;
+function main(): number { return greet("raya").length + answer; }
+"#,
+    )
+    .unwrap();
+    let changed = verify(&src_path);
+    let stderr = String::from_utf8_lossy(&changed.stderr);
+    assert!(
+        !changed.status.success(),
+        "changed source should fail verification"
+    );
+    assert!(stderr.contains("Verification failed"), "{}", stderr);
+    assert!(
+        stderr.contains("first difference at byte offset"),
+        "{}",
+        stderr
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_build_no_assertions_elides_assert_calls() {
+    let rt = Runtime::new();
+    let dir = unique_temp_dir("build-no-assertions");
+    let src_path = dir.join("main.raya");
+    std::fs::write(
+        &src_path,
+        r#"
+let hits = 0;
+function touch(): boolean { hits = hits + 1; return false; }
+assert(touch(), "boom");
+return hits;
+"#,
+    )
+    .unwrap();
+
+    let checked = rt.compile_file(&src_path).expect("compile with assertions");
+    let err = rt
+        .execute(&checked)
+        .expect_err("failing assert should throw by default");
+    assert!(err.to_string().contains("boom"), "{}", err);
+
+    let stripped = rt
+        .compile_file_with_options(
+            &src_path,
+            &raya_runtime::compile::CompileOptions {
+                strip_assertions: true,
+                ..Default::default()
+            },
+        )
+        .expect("compile without assertions");
+    let value = rt
+        .execute(&stripped)
+        .expect("stripped assert should not throw");
+    assert!(
+        value.as_i32() == Some(0) || value.as_f64() == Some(0.0),
+        "assert arguments should not be evaluated, got {:?}",
+        value
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_run_heap_snapshot_records_retained_array() {
+    let dir = unique_temp_dir("run-heap-snapshot");
+    let src_path = dir.join("main.raya");
+    std::fs::write(
+        &src_path,
+        r#"
+let retained: number[] = [];
+for (let i = 0; i < 100000; i = i + 1) {
+    retained.push(i);
+}
+function keep(): number { return retained.length; }
+keep();
+"#,
+    )
+    .unwrap();
+    let snapshot_path = dir.join("main.heapsnapshot");
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("run")
+        .arg(&src_path)
+        .arg("--heap-snapshot")
+        .arg(&snapshot_path)
+        .output()
+        .expect("failed to run raya run");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+
+    let json = std::fs::read_to_string(&snapshot_path).expect("read heap snapshot");
+    let snapshot: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+    let node_fields = snapshot["snapshot"]["meta"]["node_fields"]
+        .as_array()
+        .unwrap()
+        .len();
+    let nodes = snapshot["nodes"].as_array().unwrap();
+    let edges = snapshot["edges"].as_array().unwrap();
+    let strings = snapshot["strings"].as_array().unwrap();
+    let field = |node: usize, offset: usize| nodes[node + offset].as_u64().unwrap() as usize;
+
+    // Node layout: type, name, id, self_size, edge_count, ...
+    let array_node = (0..nodes.len())
+        .step_by(node_fields)
+        .find(|&node| strings[field(node, 1)] == "Array" && field(node, 3) >= 100_000 * 8)
+        .unwrap_or_else(|| panic!("no retained Array of 100000 elements in snapshot"));
+
+    // The array must be reachable from the synthetic GC roots node.
+    let mut edge_start = vec![0; nodes.len() / node_fields + 1];
+    for (i, node) in (0..nodes.len()).step_by(node_fields).enumerate() {
+        edge_start[i + 1] = edge_start[i] + field(node, 4);
+    }
+    let mut seen = std::collections::HashSet::from([0usize]);
+    let mut stack = vec![0usize];
+    while let Some(node) = stack.pop() {
+        let ordinal = node / node_fields;
+        for edge in edge_start[ordinal]..edge_start[ordinal + 1] {
+            let to = edges[edge * 3 + 2].as_u64().unwrap() as usize;
+            if seen.insert(to) {
+                stack.push(to);
+            }
+        }
+    }
+    assert!(
+        seen.contains(&array_node),
+        "retained array is not reachable from GC roots"
+    );
+}
+
+#[test]
+fn test_session_format_value_json_object() {
+    let mut session = Session::new(&RuntimeOptions::default());
+    let value = session
+        .eval("return { a: 1, tags: [\"x\", \"y\"] };")
+        .expect("eval object failed");
+    let encoded = session
+        .format_value_json(&value)
+        .expect("object should serialize");
+    let parsed: serde_json::Value = serde_json::from_str(&encoded).expect("valid JSON");
+    assert_eq!(parsed, serde_json::json!({ "a": 1, "tags": ["x", "y"] }));
+}
+
+#[test]
+fn test_session_format_value_json_rejects_functions() {
+    let mut session = Session::new(&RuntimeOptions::default());
+    let value = session
+        .eval("function f(): number { return 1; }\nreturn f;")
+        .expect("eval function value failed");
+    let err = session
+        .format_value_json(&value)
+        .expect_err("functions are not serializable");
+    assert!(err.to_string().contains("function"), "{err}");
+}
+
+#[test]
+fn test_session_format_value_json_rejects_nested_functions() {
+    let mut session = Session::new(&RuntimeOptions::default());
+    let value = session
+        .eval("function f(): number { return 1; }\nreturn { name: \"x\", handlers: [f] };")
+        .expect("eval object with function failed");
+    let err = session
+        .format_value_json(&value)
+        .expect_err("nested functions are not serializable");
+    assert!(err.to_string().contains("function"), "{err}");
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya run --feature` / `[build].features`
+// ────────────────────────────────────────────────────────────────────────────
+
+const FEATURE_GATED_SOURCE: &str = r#"
+//@@if(fast)
+function mode(): number { return 1; }
+
+//@@if(!fast)
+function mode(): number { return 2; }
+
+if (mode() != 1) {
+    throw new Error("fast feature disabled");
+}
+"#;
+
+fn run_main_in(dir: &std::path::Path, extra_args: &[&str]) -> std::process::Output {
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .current_dir(dir)
+        .arg("run")
+        .args(extra_args)
+        .arg("main.raya")
+        .output()
+        .expect("failed to run raya run")
+}
+
+#[test]
+fn test_run_feature_flag_selects_gated_declarations() {
+    let dir = unique_temp_dir("run-feature-flag");
+    std::fs::write(dir.join("main.raya"), FEATURE_GATED_SOURCE).unwrap();
+
+    let enabled = run_main_in(&dir, &["--feature", "fast"]);
+    assert!(
+        enabled.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&enabled.stderr)
+    );
+
+    let disabled = run_main_in(&dir, &[]);
+    let stderr = String::from_utf8_lossy(&disabled.stderr);
+    assert!(
+        !disabled.status.success(),
+        "expected failure without --feature"
+    );
+    assert!(
+        stderr.contains("fast feature disabled"),
+        "stderr: {}",
+        stderr
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_run_uses_manifest_build_features() {
+    let dir = unique_temp_dir("run-manifest-features");
+    std::fs::write(dir.join("main.raya"), FEATURE_GATED_SOURCE).unwrap();
+    std::fs::write(
+        dir.join("raya.toml"),
+        r#"[package]
+name = "features"
+version = "0.1.0"
+
+[build]
+features = ["fast"]
+"#,
+    )
+    .unwrap();
+
+    let output = run_main_in(&dir, &[]);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_run_no_assertions_skips_assert_and_its_arguments() {
+    let dir = unique_temp_dir("run-no-assertions");
+    std::fs::write(
+        dir.join("main.raya"),
+        r#"
+let hits = 0;
+function touch(): boolean { hits = hits + 1; return false; }
+assert(touch(), "boom");
+if (hits != 0) {
+    throw new Error("assert arguments were evaluated");
+}
+"#,
+    )
+    .unwrap();
+
+    let checked = run_main_in(&dir, &[]);
+    let stderr = String::from_utf8_lossy(&checked.stderr);
+    assert!(
+        !checked.status.success(),
+        "failing assert should throw by default"
+    );
+    assert!(stderr.contains("boom"), "stderr: {}", stderr);
+
+    let stripped = run_main_in(&dir, &["--no-assertions"]);
+    assert!(
+        stripped.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&stripped.stderr)
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_run_deterministic_float_canonicalizes_nan() {
+    let dir = unique_temp_dir("run-deterministic-float");
+    // Multiply a NaN whose payload is 1; the hardware keeps the payload,
+    // deterministic mode returns the canonical NaN like the JIT does.
+    std::fs::write(
+        dir.join("main.raya"),
+        r#"
+const buf = new Buffer(8);
+buf.setByte(0, 1);
+buf.setByte(6, 0xF8);
+buf.setByte(7, 0x7F);
+buf.setFloat64(0, buf.getFloat64(0) * 1.5);
+if (buf.getByte(0) != 0) {
+    throw new Error("NaN payload survived");
+}
+"#,
+    )
+    .unwrap();
+
+    let deterministic = run_main_in(&dir, &["--deterministic-float"]);
+    assert!(
+        deterministic.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&deterministic.stderr)
+    );
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    {
+        let default = run_main_in(&dir, &[]);
+        let stderr = String::from_utf8_lossy(&default.stderr);
+        assert!(
+            !default.status.success(),
+            "payload should propagate by default"
+        );
+        assert!(
+            stderr.contains("NaN payload survived"),
+            "stderr: {}",
+            stderr
+        );
+    }
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya run -` (source from stdin)
+// ────────────────────────────────────────────────────────────────────────────
+
+fn run_stdin_source(source: &str) -> std::process::Output {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let mut child = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .args(["run", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn raya run -");
+    child
+        .stdin
+        .take()
+        .expect("stdin")
+        .write_all(source.as_bytes())
+        .expect("failed to write stdin");
+    child.wait_with_output().expect("failed to wait for raya")
+}
+
+#[test]
+fn test_run_stdin_executes_piped_program() {
+    let output = run_stdin_source("let answer: number = 40 + 2;\nreturn answer;\n");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_run_stdin_empty_input_is_noop() {
+    let output = run_stdin_source("");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("nothing to run"), "stderr: {}", stderr);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Program arguments (`process.argv()`)
+// ────────────────────────────────────────────────────────────────────────────
+
+fn run_print_args(program_args: &[&str]) -> std::process::Output {
+    let path = fixtures_dir().join("args/print_args.raya");
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("run")
+        .arg(&path)
+        .args(program_args)
+        .output()
+        .expect("failed to run raya run")
+}
+
+#[test]
+fn test_run_passes_program_args_to_process_argv() {
+    let output = run_print_args(&["hello", "world"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("argc=2"), "stdout: {}", stdout);
+    assert!(stdout.contains("first=hello"), "stdout: {}", stdout);
+}
+
+#[test]
+fn test_run_without_program_args_has_empty_argv() {
+    let output = run_print_args(&[]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected success, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("argc=0"), "stdout: {}", stdout);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Signal handling (`process.onSignal`)
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(unix)]
+fn run_signal_fixture(name: &str) -> std::process::Output {
+    let path = fixtures_dir().join("signals").join(name);
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("run")
+        .arg(&path)
+        .output()
+        .expect("failed to run raya run")
+}
+
+#[cfg(unix)]
+#[test]
+fn test_on_signal_handler_runs_and_program_continues() {
+    let output = run_signal_fixture("handled_sigterm.raya");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected success, status: {:?}, stderr: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("received=1"), "stdout: {}", stdout);
+    assert!(stdout.contains("still running"), "stdout: {}", stdout);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_on_signal_listener_rearms_after_each_signal() {
+    let output = run_signal_fixture("repeated_sigterm.raya");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected success, status: {:?}, stderr: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("received=2"), "stdout: {}", stdout);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_unhandled_signal_still_terminates() {
+    use std::os::unix::process::ExitStatusExt;
+
+    let output = run_signal_fixture("unhandled_sigterm.raya");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // SIGTERM keeps its default disposition and kills the process.
+    assert_eq!(
+        output.status.signal(),
+        Some(15),
+        "status: {:?}",
+        output.status
+    );
+    assert!(!stdout.contains("still running"), "stdout: {}", stdout);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_sigterm_runs_exit_hooks() {
+    let output = run_signal_fixture("exit_hooks_on_sigterm.raya");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // With only `onExit` registered, SIGTERM exits through the hooks with 128 + 15.
+    assert_eq!(
+        output.status.code(),
+        Some(143),
+        "status: {:?}, stderr: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("hook ran"), "stdout: {}", stdout);
+    assert!(!stdout.contains("still running"), "stdout: {}", stdout);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_exit_gives_up_on_hung_exit_hook() {
+    let started = std::time::Instant::now();
+    let output = run_signal_fixture("hung_exit_hook.raya");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        output.status.code(),
+        Some(3),
+        "status: {:?}, stderr: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("first hook ran"), "stdout: {}", stdout);
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(30),
+        "exit waited {:?} for a hung hook",
+        started.elapsed()
+    );
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya test --coverage --min-coverage`
+// ────────────────────────────────────────────────────────────────────────────
+
+fn run_test_with_min_coverage(min_coverage: &str) -> std::process::Output {
+    let path = fixtures_dir().join("coverage/partial.test.raya");
+    std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("test")
+        .arg("--coverage")
+        .args(["--min-coverage", min_coverage])
+        .arg("--file")
+        .arg(&path)
+        .args(["--color", "never"])
+        .output()
+        .expect("failed to run raya test")
+}
+
+#[test]
+fn test_coverage_below_threshold_fails() {
+    let output = run_test_with_min_coverage("99");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !output.status.success(),
+        "expected failure, stderr: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("Coverage below threshold"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("partial.test.raya"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_coverage_above_threshold_passes() {
+    let output = run_test_with_min_coverage("10");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected success, stdout: {}, stderr: {}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("Coverage:"), "stdout: {}", stdout);
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya bench --profile`
+// ────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_bench_profile_writes_cpuprofile_for_benchmark_body() {
+    let path = fixtures_dir().join("bench/trivial.bench.raya");
+    let out_dir = unique_temp_dir("bench-profile");
+    let profile_path = out_dir.join("sum.cpuprofile");
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("bench")
+        .arg("--file")
+        .arg(&path)
+        .args(["--profile", "sum", "--iterations", "200"])
+        .args(["--prof-interval", "50"])
+        .arg("--profile-out")
+        .arg(&profile_path)
+        .output()
+        .expect("failed to run raya bench");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected success, stdout: {}, stderr: {}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("sum"), "stdout: {}", stdout);
+    assert!(
+        !stdout.contains("noop"),
+        "only the profiled benchmark runs: {}",
+        stdout
+    );
+
+    let profile = std::fs::read_to_string(&profile_path).expect("profile written");
+    let json: serde_json::Value = serde_json::from_str(&profile).expect("valid cpuprofile");
+    assert!(
+        !json["samples"].as_array().expect("samples").is_empty(),
+        "profile has samples"
+    );
+    assert!(
+        profile.contains("trivial.bench.raya"),
+        "profile: {}",
+        profile
+    );
+    let _ = std::fs::remove_dir_all(&out_dir);
+}
+
+#[test]
+fn test_bench_json_emits_one_record_per_benchmark() {
+    let path = fixtures_dir().join("bench/trivial.bench.raya");
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+        .arg("bench")
+        .arg("--file")
+        .arg(&path)
+        .args(["--iterations", "3", "--json"])
+        .output()
+        .expect("failed to run raya bench");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected success, stdout: {}, stderr: {}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let records: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON record"))
+        .collect();
+    let names: Vec<&str> = records
+        .iter()
+        .map(|record| record["name"].as_str().expect("name"))
+        .collect();
+    assert_eq!(names, vec!["sum", "noop"]);
+    assert!(records.iter().all(|record| record["iterations"] == 3));
+    assert!(records.iter().all(|record| record["error"].is_null()));
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// `raya doc --serve`
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Kills the wrapped server process when the test ends, pass or fail.
+struct ChildGuard(std::process::Child);
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .expect("bind ephemeral port")
+        .local_addr()
+        .expect("local addr")
+        .port()
+}
+
+/// Issue a GET and return (status line, body), or None if nothing is listening.
+fn http_get(port: u16, path: &str) -> Option<(String, String)> {
+    use std::io::{Read, Write};
+
+    let mut stream = std::net::TcpStream::connect(("127.0.0.1", port)).ok()?;
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n",
+        path
+    )
+    .ok()?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    Some((head.lines().next()?.to_string(), body.to_string()))
+}
+
+/// Poll `path` until `ready` accepts the response or `timeout` runs out.
+fn wait_for_page(
+    port: u16,
+    path: &str,
+    timeout: std::time::Duration,
+    ready: impl Fn(&str, &str) -> bool,
+) -> Option<(String, String)> {
+    let deadline = Instant::now() + timeout;
+    let mut last = None;
+    while Instant::now() < deadline {
+        if let Some((status, body)) = http_get(port, path) {
+            if ready(&status, &body) {
+                return Some((status, body));
+            }
+            last = Some((status, body));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    last
+}
+
+#[test]
+fn test_doc_serve_serves_index_and_regenerates_on_change() {
+    let root = unique_temp_dir("doc-serve");
+    let src = root.join("src");
+    std::fs::create_dir_all(&src).expect("create src");
+    let source = src.join("math.raya");
+    std::fs::write(
+        &source,
+        "/** Adds two numbers. */\nexport function add(a: number, b: number): number {\n    \
+         return a + b;\n}\n",
+    )
+    .expect("write source");
+
+    let port = free_port();
+    let _server = ChildGuard(
+        std::process::Command::new(env!("CARGO_BIN_EXE_raya"))
+            .arg("doc")
+            .arg(&src)
+            .arg("--out-dir")
+            .arg(root.join("docs"))
+            .args(["--serve", "--port", &port.to_string()])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .expect("failed to start raya doc --serve"),
+    );
+
+    let timeout = std::time::Duration::from_secs(20);
+    let (status, body) =
+        wait_for_page(port, "/", timeout, |_, _| true).expect("doc server never answered");
+    assert!(status.contains("200"), "status: {}", status);
+    assert!(body.contains("math.html"), "index: {}", body);
+    assert!(
+        body.contains("/__raya_reload"),
+        "live reload script: {}",
+        body
+    );
+
+    let (_, page) = wait_for_page(port, "/math.html", timeout, |_, _| true).expect("math page");
+    assert!(page.contains("Adds two numbers."), "page: {}", page);
+
+    std::fs::write(
+        &source,
+        "/** Adds two numbers. */\nexport function add(a: number, b: number): number {\n    \
+         return a + b;\n}\n\n/** Subtracts b from a. */\n\
+         export function subtract(a: number, b: number): number {\n    return a - b;\n}\n",
+    )
+    .expect("touch source");
+
+    let (_, page) = wait_for_page(port, "/math.html", timeout, |_, body| {
+        body.contains("subtract")
+    })
+    .expect("math page after change");
+    assert!(
+        page.contains("Subtracts b from a."),
+        "page was not regenerated: {}",
+        page
+    );
+
+    let _ = std::fs::remove_dir_all(&root);
+}
@@ -0,0 +1,79 @@
+//! Building a million-element array with and without a capacity hint.
+//!
+//! `grow_from_empty` pushes into `[]`, so the backing store doubles as it
+//! fills; `with_capacity` pushes into `Array.withCapacity(1000000)`, which
+//! never has to grow. A counting allocator reports how many Rust heap
+//! reallocations one run of each program makes.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use raya_runtime::{Runtime, RuntimeOptions};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAlloc;
+
+static REALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn program(init: &str) -> String {
+    format!(
+        r#"
+let xs: number[] = {};
+for (let i = 0; i < 1000000; i++) {{
+    xs.push(i);
+}}
+return xs.length;
+"#,
+        init
+    )
+}
+
+fn bench_array_push(c: &mut Criterion) {
+    let runtime = Runtime::with_options(RuntimeOptions {
+        no_jit: true,
+        ..Default::default()
+    });
+    let grow = runtime
+        .compile(&program("[]"))
+        .expect("growing program compiles");
+    let presized = runtime
+        .compile(&program("Array.withCapacity<number>(1000000)"))
+        .expect("pre-sized program compiles");
+
+    for (name, module) in [("grow_from_empty", &grow), ("with_capacity", &presized)] {
+        let before = REALLOCATIONS.load(Ordering::Relaxed);
+        runtime.execute(module).expect("runs");
+        let reallocations = REALLOCATIONS.load(Ordering::Relaxed) - before;
+        eprintln!("{}: {} heap reallocations per run", name, reallocations);
+    }
+
+    let mut group = c.benchmark_group("array_push");
+    group.sample_size(10);
+    group.bench_function("grow_from_empty", |b| {
+        b.iter(|| runtime.execute(&grow).expect("runs"))
+    });
+    group.bench_function("with_capacity", |b| {
+        b.iter(|| runtime.execute(&presized).expect("runs"))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_array_push);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Structural field access in a tight loop.
+//!
+//! `monomorphic` reads through a `Point` view whose receiver always has the
+//! same layout, so every access after the first is served by the site's
+//! inline cache. `polymorphic` alternates two layouts at the same sites,
+//! so each access misses the cache and resolves through the shape-adapter
+//! table instead.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use raya_runtime::{Runtime, RuntimeOptions};
+
+const READ: &str = r#"
+type Point = {
+    x: number;
+    y: number;
+};
+function norm1(p: Point): number {
+    return p.x + p.y;
+}
+let a: Point = { y: 2, x: 1 };
+let b: Point = { x: 3, y: 4 };
+"#;
+
+const MONOMORPHIC: &str = r#"
+let total = 0;
+for (let i = 0; i < 20000; i++) {
+    total = total + norm1(a);
+}
+return total;
+"#;
+
+const POLYMORPHIC: &str = r#"
+let total = 0;
+for (let i = 0; i < 20000; i++) {
+    total = total + norm1(i % 2 == 0 ? a : b);
+}
+return total;
+"#;
+
+fn bench_field_access(c: &mut Criterion) {
+    let runtime = Runtime::with_options(RuntimeOptions {
+        no_jit: true,
+        ..Default::default()
+    });
+    let monomorphic = runtime
+        .compile(&format!("{}{}", READ, MONOMORPHIC))
+        .expect("monomorphic program compiles");
+    let polymorphic = runtime
+        .compile(&format!("{}{}", READ, POLYMORPHIC))
+        .expect("polymorphic program compiles");
+
+    let mut group = c.benchmark_group("field_access");
+    group.bench_function("monomorphic", |b| {
+        b.iter(|| runtime.execute(&monomorphic).expect("runs"))
+    });
+    group.bench_function("polymorphic", |b| {
+        b.iter(|| runtime.execute(&polymorphic).expect("runs"))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_field_access);
+criterion_main!(benches);
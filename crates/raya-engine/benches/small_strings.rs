@@ -0,0 +1,64 @@
+//! Building many short strings versus many long ones.
+//!
+//! `short_strings` concatenates 10-byte results, which fit inline in a
+//! `RayaString` and are shared between equal strings, so the loop's four
+//! distinct results cost four GC objects. `long_strings` builds 26-byte
+//! results, each of which is a new GC object. The garbage collector's
+//! counters report how much each run allocates and how often it collects.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use raya_runtime::{Runtime, RuntimeOptions};
+
+fn program(parts: [&str; 4]) -> String {
+    format!(
+        r#"
+let parts: string[] = ["{}", "{}", "{}", "{}"];
+let total = 0;
+for (let i = 0; i < 20000; i++) {{
+    let s = parts[i % 4] + parts[(i + 1) % 4];
+    total = total + s.length;
+}}
+return total;
+"#,
+        parts[0], parts[1], parts[2], parts[3]
+    )
+}
+
+fn bench_small_strings(c: &mut Criterion) {
+    let runtime = Runtime::with_options(RuntimeOptions {
+        no_jit: true,
+        ..Default::default()
+    });
+    let short = runtime
+        .compile(&program(["alpha", "bravo", "delta", "gamma"]))
+        .expect("short program compiles");
+    let long = runtime
+        .compile(&program([
+            "alpha-segment",
+            "bravo-segment",
+            "delta-segment",
+            "gamma-segment",
+        ]))
+        .expect("long program compiles");
+
+    for (name, module) in [("short_strings", &short), ("long_strings", &long)] {
+        runtime.execute(module).expect("runs");
+        let stats = runtime.gc_stats().expect("execution records GC stats");
+        eprintln!(
+            "{}: {} bytes allocated by the GC, {} collections per run",
+            name, stats.total_allocated_bytes, stats.collections
+        );
+    }
+
+    let mut group = c.benchmark_group("small_strings");
+    group.bench_function("short_strings", |b| {
+        b.iter(|| runtime.execute(&short).expect("runs"))
+    });
+    group.bench_function("long_strings", |b| {
+        b.iter(|| runtime.execute(&long).expect("runs"))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_small_strings);
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+//! String-keyed dispatch with interned literals versus built strings.
+//!
+//! Both programs run the same `switch` over event tags. `literal_tags` feeds
+//! it constant-pool literals, which are interned at load time, so every case
+//! test is an intern-id compare. `built_tags` concatenates the same tags from
+//! function parameters at run time, so each case test falls back to comparing
+//! bytes.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use raya_runtime::{Runtime, RuntimeOptions};
+
+const DISPATCH: &str = r#"
+function code(tag: string): int {
+    switch (tag) {
+        case "event.user.created": return 1;
+        case "event.user.updated": return 2;
+        case "event.user.deleted": return 3;
+        case "event.team.created": return 4;
+        case "event.team.updated": return 5;
+        case "event.team.deleted": return 6;
+    }
+    return 0;
+}
+"#;
+
+const LITERAL_TAGS: &str = r#"
+let tags: string[] = [
+    "event.user.created", "event.user.updated", "event.user.deleted",
+    "event.team.created", "event.team.updated", "event.team.deleted",
+];
+"#;
+
+const BUILT_TAGS: &str = r#"
+function tag(scope: string, action: string): string {
+    return "event." + scope + "." + action;
+}
+let scopes: string[] = ["user", "team"];
+let actions: string[] = ["created", "updated", "deleted"];
+let tags: string[] = [];
+for (let s = 0; s < scopes.length; s++) {
+    for (let a = 0; a < actions.length; a++) {
+        tags.push(tag(scopes[s], actions[a]));
+    }
+}
+"#;
+
+const LOOP: &str = r#"
+let total = 0;
+for (let i = 0; i < 20000; i++) {
+    total = total + code(tags[i % 6]);
+}
+return total;
+"#;
+
+fn bench_string_switch(c: &mut Criterion) {
+    let runtime = Runtime::with_options(RuntimeOptions {
+        no_jit: true,
+        ..Default::default()
+    });
+    let literal = runtime
+        .compile(&format!("{}{}{}", DISPATCH, LITERAL_TAGS, LOOP))
+        .expect("literal program compiles");
+    let built = runtime
+        .compile(&format!("{}{}{}", DISPATCH, BUILT_TAGS, LOOP))
+        .expect("built program compiles");
+
+    let mut group = c.benchmark_group("string_switch");
+    group.bench_function("literal_tags", |b| {
+        b.iter(|| runtime.execute(&literal).expect("runs"))
+    });
+    group.bench_function("built_tags", |b| {
+        b.iter(|| runtime.execute(&built).expect("runs"))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_string_switch);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+//! Setup cost of binding a module against the builtins.
+//!
+//! `fresh` binds every builtin from scratch, as compiles used to. `cached`
+//! starts from `TypeContext::with_builtins` and `Binder::with_builtins`, which
+//! copy the process-wide context and global scope, so the builtins are bound
+//! once on the first iteration and never again.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use raya_engine::parser::ast::Module;
+use raya_engine::parser::checker::Binder;
+use raya_engine::parser::{Interner, Span, TypeContext};
+
+fn bench_builtin_setup(c: &mut Criterion) {
+    let interner = Interner::new();
+    let module = Module::new(Vec::new(), Span::new(0, 0, 1, 1));
+
+    c.bench_function("type_context/fresh", |b| {
+        b.iter(|| {
+            let mut ctx = TypeContext::new();
+            let mut binder = Binder::new(&mut ctx, &interner);
+            binder.register_builtins(&raya_engine::builtins::to_checker_signatures());
+            binder.bind_module(&module).unwrap()
+        })
+    });
+
+    c.bench_function("type_context/cached", |b| {
+        b.iter(|| {
+            let mut ctx = TypeContext::with_builtins();
+            Binder::with_builtins(&mut ctx, &interner)
+                .bind_module(&module)
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, bench_builtin_setup);
+criterion_main!(benches);
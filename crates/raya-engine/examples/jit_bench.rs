@@ -104,6 +104,7 @@ fn make_module(name: &str, functions: Vec<Function>) -> Module {
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     }
 }
 
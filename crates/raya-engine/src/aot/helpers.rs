@@ -23,7 +23,8 @@ use super::abi;
 use super::frame::{
     AotEntryFn, AotFrame, AotHelperTable, AotTaskContext, SuspendReason, AOT_SUSPEND,
 };
-use crate::vm::abi::{native_to_value, value_to_native, EngineContext};
+use crate::vm::abi::{native_to_value, value_to_native, EngineContext, NativeHeapScope};
+use crate::vm::gc::write_barrier;
 use crate::vm::interpreter::SharedVmState;
 use crate::vm::json::view::{js_classify, JSView};
 use crate::vm::object::{Array, BoundMethod, Object, RayaString};
@@ -314,7 +315,7 @@ unsafe extern "C" fn helper_string_concat(ctx: *mut AotTaskContext, a: u64, b: u
                 float.to_string()
             }
         } else if let Some(ptr) = unsafe { value.as_ptr::<RayaString>() } {
-            unsafe { &*ptr.as_ptr() }.data.clone()
+            unsafe { &*ptr.as_ptr() }.as_str().to_string()
         } else {
             "[object]".to_string()
         }
@@ -490,7 +491,7 @@ fn aot_object_ptr_checked(value: Value) -> Option<std::ptr::NonNull<Object>> {
 fn aot_dyn_key_parts(key_val: Value) -> Result<(Option<String>, Option<usize>), String> {
     match js_classify(key_val) {
         JSView::Str(ptr) => {
-            let key = unsafe { &*ptr }.data.clone();
+            let key = unsafe { &*ptr }.as_str().to_string();
             let index = key.parse::<usize>().ok();
             Ok((Some(key), index))
         }
@@ -774,8 +775,10 @@ unsafe extern "C" fn helper_object_set_shape_field(
             .set_field(slot, Value::from_raw(value_raw))
             .map(|_| 1)
             .unwrap_or(0),
+        StructuralSlotBinding::Dynamic(_) if object_ref.is_frozen() => 0,
         StructuralSlotBinding::Dynamic(key) => {
             object_ref.ensure_dyn_map().insert(key, Value::from_raw(value_raw));
+            write_barrier(Value::from_raw(value_raw));
             1
         }
         StructuralSlotBinding::Method(_) | StructuralSlotBinding::Missing => 0,
@@ -1026,6 +1029,7 @@ unsafe extern "C" fn helper_dyn_set_prop(
                 arr.elements.resize(index + 1, Value::null());
             }
             arr.elements[index] = value;
+            write_barrier(value);
         }
         JSView::Struct { ptr, .. } => {
             let obj = unsafe { &mut *(ptr as *mut Object) };
@@ -1043,6 +1047,7 @@ unsafe extern "C" fn helper_dyn_set_prop(
             } else {
                 let key_id = shared.prop_keys.write().intern(&key_str);
                 obj.ensure_dyn_map().insert(key_id, value);
+                write_barrier(value);
             }
         }
         _ => {}
@@ -1129,7 +1134,11 @@ unsafe extern "C" fn helper_native_call(
             &shared.layouts,
             task_id,
             &shared.class_metadata,
-        );
+        )
+        .with_handle_limits(&shared.handle_limits)
+        .with_deadline(*shared.execution_deadline.lock())
+        .with_program_args(&shared.program_args)
+        .with_exit_hooks(&shared.exit_hooks);
 
         // Convert NaN-boxed args into NativeValue slice.
         let value_args: Vec<Value> = if argc == 0 {
@@ -1156,7 +1165,7 @@ unsafe extern "C" fn helper_native_call(
                 let Some(s) = (unsafe { value_args[0].as_ptr::<RayaString>() }) else {
                     return abi::NULL_VALUE;
                 };
-                let json_str = unsafe { &*s.as_ptr() }.data.clone();
+                let json_str = unsafe { &*s.as_ptr() }.as_str().to_string();
                 let mut gc = shared.gc.lock();
                 let mut prop_keys = shared.prop_keys.write();
                 return match json::parser::parse_with_prop_key_interner(
@@ -1202,6 +1211,7 @@ unsafe extern "C" fn helper_native_call(
             // Context-less helper path used in unit tests / partial runtimes.
             shared.resolved_natives.read().clone()
         };
+        let _heap = NativeHeapScope::enter(&shared.gc);
         match resolved.call(native_id, &engine_ctx, &native_args) {
             NativeCallResult::Value(val) => return native_to_value(val).raw(),
             NativeCallResult::Suspend(io_request) => {
@@ -1221,7 +1231,7 @@ unsafe extern "C" fn helper_native_call(
 
         let native_name = crate::compiler::native_id::native_name(native_id);
         if let Some(handler) = shared.native_registry.read().get(native_name) {
-            match handler(&engine_ctx, &native_args) {
+            match crate::vm::native_registry::call_native(&handler, &engine_ctx, &native_args) {
                 NativeCallResult::Value(val) => return native_to_value(val).raw(),
                 NativeCallResult::Suspend(io_request) => {
                     if let Some(tx) = shared.io_submit_tx.lock().as_ref() {
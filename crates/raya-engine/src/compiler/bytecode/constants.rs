@@ -1,8 +1,14 @@
 //! Constant pool for bytecode modules
 
 use super::encoder::{BytecodeReader, BytecodeWriter, DecodeError};
+use rustc_hash::FxHashMap;
 
 /// Constant pool containing literal values
+///
+/// `intern_string` reuses an existing identical entry; the `add_*` methods
+/// always append (for slots that must stay distinct). Only strings are
+/// interned: numeric literals are inline `ConstI32`/`ConstF64` operands, so
+/// the integer and float sections just hold such distinct slots.
 #[derive(Debug, Clone, Default)]
 pub struct ConstantPool {
     /// String constants
@@ -11,6 +17,11 @@ pub struct ConstantPool {
     pub integers: Vec<i32>,
     /// Float constants
     pub floats: Vec<f64>,
+    /// First index of each string in `strings[..indexed_strings]`, built by
+    /// `intern_string` so pools that are only decoded never pay for it
+    string_index: FxHashMap<String, u32>,
+    /// Number of leading `strings` covered by `string_index`
+    indexed_strings: usize,
 }
 
 impl ConstantPool {
@@ -40,6 +51,47 @@ impl ConstantPool {
         index as u32
     }
 
+    /// Index of the first string constant equal to `s`
+    pub fn find_string(&self, s: &str) -> Option<u32> {
+        if let Some(&index) = self.string_index.get(s) {
+            if self.strings.get(index as usize).map(String::as_str) == Some(s) {
+                return Some(index);
+            }
+        }
+        // Strings appended since the index was last extended
+        let indexed = self.indexed_strings.min(self.strings.len());
+        self.strings[indexed..]
+            .iter()
+            .position(|entry| entry == s)
+            .map(|offset| (indexed + offset) as u32)
+    }
+
+    /// Add a string constant unless an identical one exists; return its index
+    pub fn intern_string(&mut self, s: String) -> u32 {
+        self.index_strings();
+        if let Some(index) = self.find_string(&s) {
+            return index;
+        }
+        let index = self.strings.len() as u32;
+        self.string_index.insert(s.clone(), index);
+        self.strings.push(s);
+        self.indexed_strings = self.strings.len();
+        index
+    }
+
+    /// Extend `string_index` over strings added by `add_string`, `decode` or
+    /// direct pushes since it was last extended
+    fn index_strings(&mut self) {
+        if self.indexed_strings > self.strings.len() {
+            self.string_index.clear();
+            self.indexed_strings = 0;
+        }
+        for (index, s) in self.strings.iter().enumerate().skip(self.indexed_strings) {
+            self.string_index.entry(s.clone()).or_insert(index as u32);
+        }
+        self.indexed_strings = self.strings.len();
+    }
+
     /// Get a string constant by index
     pub fn get_string(&self, index: u32) -> Option<&str> {
         self.strings.get(index as usize).map(|s| s.as_str())
@@ -89,23 +141,23 @@ impl ConstantPool {
     pub fn decode(reader: &mut BytecodeReader<'_>) -> Result<Self, DecodeError> {
         let mut pool = ConstantPool::new();
 
-        // Decode strings
-        let string_count = reader.read_u32()? as usize;
+        // Decode strings (entries are kept as-is, duplicates included, so
+        // indices stay valid)
+        let string_count = reader.read_count()?;
         pool.strings.reserve(string_count);
         for _ in 0..string_count {
-            let s = reader.read_string()?;
-            pool.strings.push(s);
+            pool.strings.push(reader.read_string()?);
         }
 
         // Decode integers
-        let int_count = reader.read_u32()? as usize;
+        let int_count = reader.read_count()?;
         pool.integers.reserve(int_count);
         for _ in 0..int_count {
             pool.integers.push(reader.read_i32()?);
         }
 
         // Decode floats
-        let float_count = reader.read_u32()? as usize;
+        let float_count = reader.read_count()?;
         pool.floats.reserve(float_count);
         for _ in 0..float_count {
             pool.floats.push(reader.read_f64()?);
@@ -155,6 +207,40 @@ mod tests {
         assert_eq!(pool.get_float(float_idx), Some(3.14));
     }
 
+    #[test]
+    fn test_intern_reuses_identical_entries() {
+        let mut pool = ConstantPool::new();
+
+        let first = pool.intern_string("hello".to_string());
+        assert_eq!(pool.intern_string("hello".to_string()), first);
+        assert_eq!(pool.intern_string("hello".to_string()), first);
+        assert_eq!(pool.strings.len(), 1);
+
+        // Strings appended without interning are still found and reused
+        let world = pool.add_string("world".to_string());
+        assert_eq!(pool.find_string("world"), Some(world));
+        assert_eq!(pool.intern_string("world".to_string()), world);
+        assert_eq!(pool.strings.len(), 2);
+    }
+
+    #[test]
+    fn test_interned_pool_roundtrip_preserves_sharing() {
+        let mut pool = ConstantPool::new();
+        let hello = pool.intern_string("hello".to_string());
+        pool.intern_string("world".to_string());
+        pool.intern_string("hello".to_string());
+
+        let mut writer = BytecodeWriter::new();
+        pool.encode(&mut writer);
+        let mut reader = BytecodeReader::new(writer.buffer());
+        let mut decoded = ConstantPool::decode(&mut reader).unwrap();
+
+        assert_eq!(decoded.strings, ["hello", "world"]);
+        // Interning into the decoded pool still finds the shared entries
+        assert_eq!(decoded.intern_string("hello".to_string()), hello);
+        assert_eq!(decoded.strings.len(), 2);
+    }
+
     #[test]
     fn test_constant_pool_encoding() {
         let mut pool = ConstantPool::new();
@@ -19,6 +19,10 @@ pub enum DecodeError {
     /// Invalid opcode
     #[error("Invalid opcode {0} at offset {1}")]
     InvalidOpcode(u8, usize),
+
+    /// Element count larger than the remaining input could possibly hold
+    #[error("Invalid element count {0} at offset {1}")]
+    InvalidCount(u32, usize),
 }
 
 /// Bytecode writer for encoding instructions
@@ -599,148 +603,92 @@ impl<'a> BytecodeReader<'a> {
     }
 
     // ===== Basic Reading =====
+    //
+    // Every read goes through `take`, which never indexes past the buffer, so
+    // truncated or malformed input yields a `DecodeError` instead of a panic.
+
+    /// Consume the next `count` bytes
+    fn take(&mut self, count: usize) -> Result<&'a [u8], DecodeError> {
+        let bytes = self
+            .buffer
+            .get(self.position..)
+            .and_then(|rest| rest.get(..count))
+            .ok_or(DecodeError::UnexpectedEnd(self.position))?;
+        self.position += count;
+        Ok(bytes)
+    }
+
+    /// Read a fixed-size byte array
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
 
     /// Read a single byte
     pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
-        if self.position >= self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let value = self.buffer[self.position];
-        self.position += 1;
-        Ok(value)
+        Ok(self.take(1)?[0])
     }
 
     /// Read a 16-bit unsigned integer (little-endian)
     pub fn read_u16(&mut self) -> Result<u16, DecodeError> {
-        if self.position + 2 > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = [self.buffer[self.position], self.buffer[self.position + 1]];
-        self.position += 2;
-        Ok(u16::from_le_bytes(bytes))
+        Ok(u16::from_le_bytes(self.read_array()?))
     }
 
     /// Read a 32-bit unsigned integer (little-endian)
     pub fn read_u32(&mut self) -> Result<u32, DecodeError> {
-        if self.position + 4 > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = [
-            self.buffer[self.position],
-            self.buffer[self.position + 1],
-            self.buffer[self.position + 2],
-            self.buffer[self.position + 3],
-        ];
-        self.position += 4;
-        Ok(u32::from_le_bytes(bytes))
+        Ok(u32::from_le_bytes(self.read_array()?))
     }
 
     /// Read a 64-bit unsigned integer (little-endian)
     pub fn read_u64(&mut self) -> Result<u64, DecodeError> {
-        if self.position + 8 > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = [
-            self.buffer[self.position],
-            self.buffer[self.position + 1],
-            self.buffer[self.position + 2],
-            self.buffer[self.position + 3],
-            self.buffer[self.position + 4],
-            self.buffer[self.position + 5],
-            self.buffer[self.position + 6],
-            self.buffer[self.position + 7],
-        ];
-        self.position += 8;
-        Ok(u64::from_le_bytes(bytes))
+        Ok(u64::from_le_bytes(self.read_array()?))
     }
 
     /// Read a 32-bit signed integer (little-endian)
     pub fn read_i32(&mut self) -> Result<i32, DecodeError> {
-        if self.position + 4 > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = [
-            self.buffer[self.position],
-            self.buffer[self.position + 1],
-            self.buffer[self.position + 2],
-            self.buffer[self.position + 3],
-        ];
-        self.position += 4;
-        Ok(i32::from_le_bytes(bytes))
+        Ok(i32::from_le_bytes(self.read_array()?))
     }
 
     /// Read a 64-bit signed integer (little-endian)
     pub fn read_i64(&mut self) -> Result<i64, DecodeError> {
-        if self.position + 8 > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = [
-            self.buffer[self.position],
-            self.buffer[self.position + 1],
-            self.buffer[self.position + 2],
-            self.buffer[self.position + 3],
-            self.buffer[self.position + 4],
-            self.buffer[self.position + 5],
-            self.buffer[self.position + 6],
-            self.buffer[self.position + 7],
-        ];
-        self.position += 8;
-        Ok(i64::from_le_bytes(bytes))
+        Ok(i64::from_le_bytes(self.read_array()?))
     }
 
     /// Read a 32-bit float (little-endian)
     pub fn read_f32(&mut self) -> Result<f32, DecodeError> {
-        if self.position + 4 > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = [
-            self.buffer[self.position],
-            self.buffer[self.position + 1],
-            self.buffer[self.position + 2],
-            self.buffer[self.position + 3],
-        ];
-        self.position += 4;
-        Ok(f32::from_le_bytes(bytes))
+        Ok(f32::from_le_bytes(self.read_array()?))
     }
 
     /// Read a 64-bit float (little-endian)
     pub fn read_f64(&mut self) -> Result<f64, DecodeError> {
-        if self.position + 8 > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
+        Ok(f64::from_le_bytes(self.read_array()?))
+    }
+
+    /// Read a u32 element count for a length-prefixed list.
+    ///
+    /// Every encoded element takes at least one byte, so a count larger than
+    /// the remaining input is rejected before it can size an allocation.
+    pub fn read_count(&mut self) -> Result<usize, DecodeError> {
+        let offset = self.position;
+        let count = self.read_u32()?;
+        if count as usize > self.remaining() {
+            return Err(DecodeError::InvalidCount(count, offset));
         }
-        let bytes = [
-            self.buffer[self.position],
-            self.buffer[self.position + 1],
-            self.buffer[self.position + 2],
-            self.buffer[self.position + 3],
-            self.buffer[self.position + 4],
-            self.buffer[self.position + 5],
-            self.buffer[self.position + 6],
-            self.buffer[self.position + 7],
-        ];
-        self.position += 8;
-        Ok(f64::from_le_bytes(bytes))
+        Ok(count as usize)
     }
 
     /// Read a length-prefixed string (u32 length + UTF-8 bytes)
     pub fn read_string(&mut self) -> Result<String, DecodeError> {
         let len = self.read_u32()? as usize;
-        if self.position + len > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = &self.buffer[self.position..self.position + len];
-        self.position += len;
-        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8(self.position - len))
+        let offset = self.position;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8(offset))
     }
 
     /// Read a fixed number of bytes
     pub fn read_bytes(&mut self, count: usize) -> Result<Vec<u8>, DecodeError> {
-        if self.position + count > self.buffer.len() {
-            return Err(DecodeError::UnexpectedEnd(self.position));
-        }
-        let bytes = self.buffer[self.position..self.position + count].to_vec();
-        self.position += count;
-        Ok(bytes)
+        Ok(self.take(count)?.to_vec())
     }
 
     /// Read an opcode
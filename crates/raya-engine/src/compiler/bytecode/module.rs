@@ -2,6 +2,7 @@
 
 use super::constants::ConstantPool;
 use super::encoder::{BytecodeReader, BytecodeWriter, DecodeError};
+use crate::vm::json::{TypeKind, TypeSchema};
 use thiserror::Error;
 
 /// Magic number for Raya bytecode files: "RAYA"
@@ -162,6 +163,9 @@ pub struct Module {
     /// JIT compilation hints (present when HAS_JIT_HINTS flag is set).
     /// Pre-computed heuristic scores for each candidate function.
     pub jit_hints: Vec<JitHint>,
+    /// JSON decode schemas for `//@@json` types (present when HAS_JSON_SCHEMAS flag is set).
+    /// Schema `i` has type ID `i`; class schemas name module-local class indices.
+    pub json_schemas: Vec<TypeSchema>,
 }
 
 /// Module flags
@@ -174,6 +178,8 @@ pub mod flags {
     pub const HAS_NATIVE_FUNCTIONS: u32 = 1 << 2;
     /// Module has JIT compilation hints (pre-computed heuristic scores)
     pub const HAS_JIT_HINTS: u32 = 1 << 3;
+    /// Module has JSON decode schemas for `//@@json` types
+    pub const HAS_JSON_SCHEMAS: u32 = 1 << 4;
 }
 
 /// Reflection data for the entire module
@@ -287,7 +293,7 @@ impl ReflectionData {
 
     /// Decode reflection data from binary
     fn decode(reader: &mut BytecodeReader<'_>) -> Result<Self, DecodeError> {
-        let class_count = reader.read_u32()? as usize;
+        let class_count = reader.read_count()?;
         let mut classes = Vec::with_capacity(class_count);
         for _ in 0..class_count {
             classes.push(ClassReflectionData::decode(reader)?);
@@ -328,21 +334,21 @@ impl ClassReflectionData {
     /// Decode class reflection data from binary
     fn decode(reader: &mut BytecodeReader<'_>) -> Result<Self, DecodeError> {
         // Read fields
-        let field_count = reader.read_u32()? as usize;
+        let field_count = reader.read_count()?;
         let mut fields = Vec::with_capacity(field_count);
         for _ in 0..field_count {
             fields.push(FieldReflectionData::decode(reader)?);
         }
 
         // Read method names
-        let method_count = reader.read_u32()? as usize;
+        let method_count = reader.read_count()?;
         let mut method_names = Vec::with_capacity(method_count);
         for _ in 0..method_count {
             method_names.push(reader.read_string()?);
         }
 
         // Read static field names
-        let static_count = reader.read_u32()? as usize;
+        let static_count = reader.read_count()?;
         let mut static_field_names = Vec::with_capacity(static_count);
         for _ in 0..static_count {
             static_field_names.push(reader.read_string()?);
@@ -454,21 +460,21 @@ impl DebugInfo {
     /// Decode debug info from binary
     pub(crate) fn decode(reader: &mut BytecodeReader<'_>) -> Result<Self, DecodeError> {
         // Read source files
-        let file_count = reader.read_u32()? as usize;
+        let file_count = reader.read_count()?;
         let mut source_files = Vec::with_capacity(file_count);
         for _ in 0..file_count {
             source_files.push(reader.read_string()?);
         }
 
         // Read function debug info
-        let func_count = reader.read_u32()? as usize;
+        let func_count = reader.read_count()?;
         let mut functions = Vec::with_capacity(func_count);
         for _ in 0..func_count {
             functions.push(FunctionDebugInfo::decode(reader)?);
         }
 
         // Read class debug info
-        let class_count = reader.read_u32()? as usize;
+        let class_count = reader.read_count()?;
         let mut classes = Vec::with_capacity(class_count);
         for _ in 0..class_count {
             classes.push(ClassDebugInfo::decode(reader)?);
@@ -557,7 +563,7 @@ impl FunctionDebugInfo {
         let end_line = reader.read_u32()?;
         let end_column = reader.read_u32()?;
 
-        let entry_count = reader.read_u32()? as usize;
+        let entry_count = reader.read_count()?;
         let mut line_table = Vec::with_capacity(entry_count);
         for _ in 0..entry_count {
             let bytecode_offset = reader.read_u32()?;
@@ -735,7 +741,7 @@ impl ClassDef {
         };
 
         // Read methods
-        let method_count = reader.read_u32()? as usize;
+        let method_count = reader.read_count()?;
         let mut methods = Vec::with_capacity(method_count);
         for _ in 0..method_count {
             methods.push(Method::decode(reader)?);
@@ -794,6 +800,112 @@ impl Method {
     }
 }
 
+/// Encode a JSON decode schema to binary
+fn encode_json_schema(schema: &TypeSchema, writer: &mut BytecodeWriter) {
+    writer.emit_u32(schema.type_id as u32);
+    match &schema.kind {
+        TypeKind::Null => writer.emit_u8(0),
+        TypeKind::Bool => writer.emit_u8(1),
+        TypeKind::Number => writer.emit_u8(2),
+        TypeKind::String => writer.emit_u8(3),
+        TypeKind::Any => writer.emit_u8(4),
+        TypeKind::Skipped => writer.emit_u8(5),
+        TypeKind::Interface {
+            nominal_type_id,
+            layout_id,
+            fields,
+        } => {
+            writer.emit_u8(6);
+            // 0xFFFFFFFF means a structural object (type alias)
+            writer.emit_u32(nominal_type_id.map_or(0xFFFFFFFF, |id| id as u32));
+            writer.emit_u32(*layout_id);
+            writer.emit_u32(fields.len() as u32);
+            for (key, type_id) in fields {
+                writer.emit_u32(key.len() as u32);
+                writer.buffer.extend_from_slice(key.as_bytes());
+                writer.emit_u32(*type_id as u32);
+            }
+        }
+        TypeKind::Array { element_type_id } => {
+            writer.emit_u8(7);
+            writer.emit_u32(*element_type_id as u32);
+        }
+        TypeKind::Union {
+            variant_type_ids,
+            discriminant,
+        } => {
+            writer.emit_u8(8);
+            writer.emit_u32(variant_type_ids.len() as u32);
+            for type_id in variant_type_ids {
+                writer.emit_u32(*type_id as u32);
+            }
+            match discriminant {
+                Some(name) => {
+                    writer.emit_u8(1);
+                    writer.emit_u32(name.len() as u32);
+                    writer.buffer.extend_from_slice(name.as_bytes());
+                }
+                None => writer.emit_u8(0),
+            }
+        }
+    }
+}
+
+/// Decode a JSON decode schema from binary
+fn decode_json_schema(reader: &mut BytecodeReader<'_>) -> Result<TypeSchema, DecodeError> {
+    let type_id = reader.read_u32()? as usize;
+    let tag = reader.read_u8()?;
+    let kind = match tag {
+        0 => TypeKind::Null,
+        1 => TypeKind::Bool,
+        2 => TypeKind::Number,
+        3 => TypeKind::String,
+        4 => TypeKind::Any,
+        5 => TypeKind::Skipped,
+        6 => {
+            // 0xFFFFFFFF means a structural object (type alias)
+            let nominal_raw = reader.read_u32()?;
+            let nominal_type_id = if nominal_raw == 0xFFFFFFFF {
+                None
+            } else {
+                Some(nominal_raw as usize)
+            };
+            let layout_id = reader.read_u32()?;
+            let field_count = reader.read_count()?;
+            let mut fields = Vec::with_capacity(field_count);
+            for _ in 0..field_count {
+                fields.push((reader.read_string()?, reader.read_u32()? as usize));
+            }
+            TypeKind::Interface {
+                nominal_type_id,
+                layout_id,
+                fields,
+            }
+        }
+        7 => TypeKind::Array {
+            element_type_id: reader.read_u32()? as usize,
+        },
+        8 => {
+            let count = reader.read_count()?;
+            let mut variant_type_ids = Vec::with_capacity(count);
+            for _ in 0..count {
+                variant_type_ids.push(reader.read_u32()? as usize);
+            }
+            let discriminant = if reader.read_u8()? != 0 {
+                Some(reader.read_string()?)
+            } else {
+                None
+            };
+            TypeKind::Union {
+                variant_type_ids,
+                discriminant,
+            }
+        }
+        _ => return Err(DecodeError::InvalidOpcode(tag, 0)), // Reuse InvalidOpcode for invalid type tag
+    };
+    Ok(TypeSchema { type_id, kind })
+}
+
 /// Module metadata
 #[derive(Debug, Clone, Default)]
 pub struct Metadata {
@@ -908,30 +1020,30 @@ impl Metadata {
             None
         };
 
-        let generic_templates_len = reader.read_u32()? as usize;
+        let generic_templates_len = reader.read_count()?;
         let mut generic_templates = Vec::with_capacity(generic_templates_len);
         for _ in 0..generic_templates_len {
             generic_templates.push(GenericTemplateInfo::decode(reader)?);
         }
 
-        let template_symbol_table_len = reader.read_u32()? as usize;
+        let template_symbol_table_len = reader.read_count()?;
         let mut template_symbol_table = Vec::with_capacity(template_symbol_table_len);
         for _ in 0..template_symbol_table_len {
             template_symbol_table.push(TemplateSymbolEntry::decode(reader)?);
         }
 
-        let mono_debug_map_len = reader.read_u32()? as usize;
+        let mono_debug_map_len = reader.read_count()?;
         let mut mono_debug_map = Vec::with_capacity(mono_debug_map_len);
         for _ in 0..mono_debug_map_len {
             mono_debug_map.push(MonoDebugEntry::decode(reader)?);
         }
 
-        let structural_shape_len = reader.read_u32()? as usize;
+        let structural_shape_len = reader.read_count()?;
         let mut structural_shapes = Vec::with_capacity(structural_shape_len);
         for _ in 0..structural_shape_len {
             structural_shapes.push(StructuralShapeInfo::decode(reader)?);
         }
-        let structural_layout_len = reader.read_u32()? as usize;
+        let structural_layout_len = reader.read_count()?;
         let mut structural_layouts = Vec::with_capacity(structural_layout_len);
         for _ in 0..structural_layout_len {
             structural_layouts.push(StructuralLayoutInfo::decode(reader)?);
@@ -959,7 +1071,7 @@ impl StructuralShapeInfo {
     }
 
     fn decode(reader: &mut BytecodeReader<'_>) -> Result<Self, DecodeError> {
-        let count = reader.read_u32()? as usize;
+        let count = reader.read_count()?;
         let mut member_names = Vec::with_capacity(count);
         for _ in 0..count {
             member_names.push(reader.read_string()?);
@@ -980,7 +1092,7 @@ impl StructuralLayoutInfo {
 
     fn decode(reader: &mut BytecodeReader<'_>) -> Result<Self, DecodeError> {
         let layout_id = reader.read_u32()?;
-        let member_count = reader.read_u32()? as usize;
+        let member_count = reader.read_count()?;
         let mut member_names = Vec::with_capacity(member_count);
         for _ in 0..member_count {
             member_names.push(reader.read_string()?);
@@ -1021,13 +1133,13 @@ impl GenericTemplateInfo {
         let template_id = reader.read_string()?;
         let symbol = reader.read_string()?;
 
-        let type_params_len = reader.read_u32()? as usize;
+        let type_params_len = reader.read_count()?;
         let mut type_params = Vec::with_capacity(type_params_len);
         for _ in 0..type_params_len {
             type_params.push(reader.read_string()?);
         }
 
-        let constraints_len = reader.read_u32()? as usize;
+        let constraints_len = reader.read_count()?;
         let mut constraints = Vec::with_capacity(constraints_len);
         for _ in 0..constraints_len {
             constraints.push(reader.read_string()?);
@@ -1078,7 +1190,7 @@ impl MonoDebugEntry {
     fn decode(reader: &mut BytecodeReader<'_>) -> Result<Self, DecodeError> {
         let specialized_symbol = reader.read_string()?;
         let template_id = reader.read_string()?;
-        let arg_len = reader.read_u32()? as usize;
+        let arg_len = reader.read_count()?;
         let mut concrete_args = Vec::with_capacity(arg_len);
         for _ in 0..arg_len {
             concrete_args.push(reader.read_string()?);
@@ -1408,6 +1520,7 @@ impl Module {
             debug_info: None,
             native_functions: Vec::new(),
             jit_hints: Vec::new(),
+            json_schemas: Vec::new(),
         }
     }
 
@@ -1563,6 +1676,14 @@ impl Module {
             }
         }
 
+        // Encode JSON decode schemas if present
+        if (self.flags & flags::HAS_JSON_SCHEMAS) != 0 {
+            writer.emit_u32(self.json_schemas.len() as u32);
+            for schema in &self.json_schemas {
+                encode_json_schema(schema, &mut writer);
+            }
+        }
+
         // Calculate checksums (of everything after header)
         let payload_start = header_start + 48; // Skip magic + version + flags + crc32 + sha256
         let payload = writer.buffer[payload_start..].to_vec(); // Clone to avoid borrow issues
@@ -1586,8 +1707,7 @@ impl Module {
         let mut reader = BytecodeReader::new(data);
 
         // Read header
-        let magic = reader.read_bytes(4)?;
-        let magic: [u8; 4] = magic.try_into().unwrap();
+        let magic: [u8; 4] = reader.read_array()?;
         if magic != MAGIC {
             return Err(ModuleError::InvalidMagic(magic));
         }
@@ -1601,8 +1721,7 @@ impl Module {
         let stored_crc32 = reader.read_u32()?;
 
         // Read SHA-256 checksum
-        let stored_sha256 = reader.read_bytes(32)?;
-        let checksum: [u8; 32] = stored_sha256.try_into().unwrap();
+        let checksum: [u8; 32] = reader.read_array()?;
 
         // Verify checksums (skip magic + version + flags + crc32 + sha256)
         let payload = &data[48..];
@@ -1629,28 +1748,28 @@ impl Module {
         let constants = ConstantPool::decode(&mut reader)?;
 
         // Decode functions
-        let func_count = reader.read_u32()? as usize;
+        let func_count = reader.read_count()?;
         let mut functions = Vec::with_capacity(func_count);
         for _ in 0..func_count {
             functions.push(Function::decode(&mut reader)?);
         }
 
         // Decode classes
-        let class_count = reader.read_u32()? as usize;
+        let class_count = reader.read_count()?;
         let mut classes = Vec::with_capacity(class_count);
         for _ in 0..class_count {
             classes.push(ClassDef::decode(&mut reader)?);
         }
 
         // Decode exports
-        let export_count = reader.read_u32()? as usize;
+        let export_count = reader.read_count()?;
         let mut exports = Vec::with_capacity(export_count);
         for _ in 0..export_count {
             exports.push(Export::decode(&mut reader)?);
         }
 
         // Decode imports
-        let import_count = reader.read_u32()? as usize;
+        let import_count = reader.read_count()?;
         let mut imports = Vec::with_capacity(import_count);
         for _ in 0..import_count {
             imports.push(Import::decode(&mut reader)?);
@@ -1675,7 +1794,7 @@ impl Module {
 
         // Decode native function table if present
         let native_functions = if (flags & flags::HAS_NATIVE_FUNCTIONS) != 0 {
-            let count = reader.read_u32()? as usize;
+            let count = reader.read_count()?;
             let mut names = Vec::with_capacity(count);
             for _ in 0..count {
                 names.push(reader.read_string()?);
@@ -1687,7 +1806,7 @@ impl Module {
 
         // Decode JIT hints if present
         let jit_hints = if (flags & flags::HAS_JIT_HINTS) != 0 {
-            let count = reader.read_u32()? as usize;
+            let count = reader.read_count()?;
             let mut hints = Vec::with_capacity(count);
             for _ in 0..count {
                 hints.push(JitHint {
@@ -1701,6 +1820,18 @@ impl Module {
             Vec::new()
         };
 
+        // Decode JSON decode schemas if present
+        let json_schemas = if (flags & flags::HAS_JSON_SCHEMAS) != 0 {
+            let count = reader.read_count()?;
+            let mut schemas = Vec::with_capacity(count);
+            for _ in 0..count {
+                schemas.push(decode_json_schema(&mut reader)?);
+            }
+            schemas
+        } else {
+            Vec::new()
+        };
+
         Ok(Self {
             magic,
             version,
@@ -1716,6 +1847,7 @@ impl Module {
             debug_info,
             native_functions,
             jit_hints,
+            json_schemas,
         })
     }
 }
@@ -2137,6 +2269,62 @@ mod tests {
         assert!(!decoded.jit_hints[1].is_cpu_bound);
     }
 
+    #[test]
+    fn test_json_schemas_roundtrip() {
+        let mut module = Module::new("test".to_string());
+        module.json_schemas = vec![
+            TypeSchema {
+                type_id: 0,
+                kind: TypeKind::Interface {
+                    nominal_type_id: Some(0),
+                    layout_id: 7,
+                    fields: vec![("x".to_string(), 2), ("tags".to_string(), 3)],
+                },
+            },
+            TypeSchema {
+                type_id: 1,
+                kind: TypeKind::Interface {
+                    nominal_type_id: None,
+                    layout_id: 8,
+                    fields: vec![("origin".to_string(), 5), ("secret".to_string(), 6)],
+                },
+            },
+            TypeSchema {
+                type_id: 2,
+                kind: TypeKind::Number,
+            },
+            TypeSchema {
+                type_id: 3,
+                kind: TypeKind::Array { element_type_id: 4 },
+            },
+            TypeSchema {
+                type_id: 4,
+                kind: TypeKind::Any,
+            },
+            TypeSchema {
+                type_id: 5,
+                kind: TypeKind::Union {
+                    variant_type_ids: vec![0, 7],
+                    discriminant: Some("kind".to_string()),
+                },
+            },
+            TypeSchema {
+                type_id: 6,
+                kind: TypeKind::Skipped,
+            },
+            TypeSchema {
+                type_id: 7,
+                kind: TypeKind::Null,
+            },
+        ];
+        module.flags |= flags::HAS_JSON_SCHEMAS;
+
+        let bytes = module.encode();
+        let decoded = Module::decode(&bytes).expect("decode should succeed");
+
+        assert_eq!(decoded.json_schemas, module.json_schemas);
+    }
+
     #[test]
     fn test_module_no_jit_hints() {
         let module = Module::new("no_hints".to_string());
@@ -2182,4 +2370,91 @@ mod tests {
         );
         assert_eq!(import.runtime_global_slot, Some(7));
     }
+
+    /// Recompute the header checksums so mutated payloads reach the decoders.
+    fn reseal(bytes: &mut [u8]) {
+        use sha2::{Digest, Sha256};
+
+        if bytes.len() < 48 {
+            return;
+        }
+        let crc32 = crc32fast::hash(&bytes[48..]);
+        bytes[12..16].copy_from_slice(&crc32.to_le_bytes());
+        let sha256 = Sha256::digest(&bytes[48..]);
+        bytes[16..48].copy_from_slice(&sha256);
+    }
+
+    #[test]
+    fn test_decode_never_panics_on_malformed_input() {
+        let mut module = Module::new("fuzz".to_string());
+        module.constants.add_string("hello".to_string());
+        module.constants.add_integer(42);
+        module.constants.add_float(1.5);
+        let mut writer = BytecodeWriter::new();
+        writer.emit_const_i32(7);
+        writer.emit_return();
+        module.functions.push(Function {
+            name: "main".to_string(),
+            param_count: 0,
+            local_count: 1,
+            code: writer.into_bytes(),
+        });
+        module.enable_debug_info();
+        module
+            .debug_info_mut()
+            .add_source_file("main.raya".to_string());
+        let mut func_debug = FunctionDebugInfo::new(0, 1, 1, 3, 1);
+        func_debug.add_line_entry(0, 2, 5);
+        module.debug_info_mut().functions.push(func_debug);
+        module.native_functions = vec!["host.log".to_string()];
+        module.flags |= flags::HAS_NATIVE_FUNCTIONS;
+        module.jit_hints = vec![JitHint {
+            func_index: 0,
+            score: 1.0,
+            is_cpu_bound: true,
+        }];
+        module.flags |= flags::HAS_JIT_HINTS;
+        let bytes = module.encode();
+        assert!(Module::decode(&bytes).is_ok());
+
+        // xorshift64: deterministic, no extra dependencies
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        // Every truncation, both as-is and with valid checksums.
+        for len in 0..bytes.len() {
+            let mut truncated = bytes[..len].to_vec();
+            let _ = Module::decode(&truncated);
+            reseal(&mut truncated);
+            let _ = Module::decode(&truncated);
+        }
+
+        // Random byte mutations inside the payload.
+        for _ in 0..5000 {
+            let mut mutated = bytes.clone();
+            for _ in 0..=next() % 8 {
+                let index = 48 + next() as usize % (mutated.len() - 48);
+                mutated[index] = next() as u8;
+            }
+            reseal(&mut mutated);
+            let _ = Module::decode(&mutated);
+        }
+
+        // Random payloads behind a valid header with every section flag set.
+        for _ in 0..2000 {
+            let len = next() as usize % 256;
+            let mut noise = MAGIC.to_vec();
+            noise.extend_from_slice(&VERSION.to_le_bytes());
+            noise.extend_from_slice(&u32::MAX.to_le_bytes());
+            noise.extend_from_slice(&[0u8; 36]);
+            noise.extend((0..len).map(|_| next() as u8));
+            reseal(&mut noise);
+            let _ = Module::decode(&noise);
+        }
+    }
 }
@@ -779,6 +779,14 @@ impl Opcode {
         )
     }
 
+    /// Check if this opcode is a conditional jump (pops a value, may fall through)
+    pub fn is_conditional_jump(self) -> bool {
+        matches!(
+            self,
+            Self::JmpIfFalse | Self::JmpIfTrue | Self::JmpIfNull | Self::JmpIfNotNull
+        )
+    }
+
     /// Check if this opcode is a call instruction
     pub fn is_call(self) -> bool {
         matches!(
@@ -923,6 +931,13 @@ mod tests {
         assert!(!Opcode::Return.is_jump());
     }
 
+    #[test]
+    fn test_conditional_jump_detection() {
+        assert!(Opcode::JmpIfFalse.is_conditional_jump());
+        assert!(Opcode::JmpIfNotNull.is_conditional_jump());
+        assert!(!Opcode::Jmp.is_conditional_jump());
+    }
+
     #[test]
     fn test_call_detection() {
         assert!(Opcode::Call.is_call());
@@ -3,7 +3,7 @@
 use super::encoder::BytecodeReader;
 use super::module::{Function, Module};
 use super::opcode::Opcode;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Bytecode verification errors
 #[derive(Debug, thiserror::Error)]
@@ -54,6 +54,15 @@ pub enum VerifyError {
         offset: usize,
     },
 
+    /// A basic block is entered with different stack depths
+    #[error("Stack imbalance in function '{func}': inconsistent depth entering offset {offset}")]
+    StackImbalance {
+        /// Name of the function containing the block
+        func: String,
+        /// Offset of the block entry in bytecode
+        offset: usize,
+    },
+
     /// Execution falls off end
     #[error("Execution falls off end of function at offset {0}")]
     FallOffEnd(usize),
@@ -102,7 +111,7 @@ fn verify_function(function: &Function, module: &Module) -> Result<(), VerifyErr
     }
 
     // Verify stack depth consistency
-    verify_stack_depth(&instructions, &jump_targets)?;
+    verify_stack_depth(&function.name, &instructions, &jump_targets)?;
 
     // Verify constant pool references
     verify_constant_refs(&instructions, module)?;
@@ -350,30 +359,43 @@ pub(crate) fn operand_size(opcode: Opcode) -> usize {
     }
 }
 
+/// Absolute target of a jump instruction
+fn jump_target(instr: &Instruction) -> Option<usize> {
+    if !instr.opcode.is_jump() || instr.operands.len() < 4 {
+        return None;
+    }
+    let jump_offset = read_i32_operand(instr, 0);
+
+    // Offsets are relative to the end of the instruction
+    Some((instr.offset as i32 + 1 + 4 + jump_offset) as usize)
+}
+
 /// Collect all jump targets from instructions
 fn collect_jump_targets(instructions: &[Instruction]) -> Result<HashSet<usize>, VerifyError> {
-    let mut targets = HashSet::new();
+    Ok(instructions.iter().filter_map(jump_target).collect())
+}
 
-    for instr in instructions {
-        if instr.opcode.is_jump() && !instr.operands.is_empty() {
-            // Parse jump offset (i32)
-            if instr.operands.len() >= 4 {
-                let offset_bytes: [u8; 4] = [
-                    instr.operands[0],
-                    instr.operands[1],
-                    instr.operands[2],
-                    instr.operands[3],
-                ];
-                let jump_offset = i32::from_le_bytes(offset_bytes);
-
-                // Calculate absolute target
-                let target = (instr.offset as i32 + 1 + 4 + jump_offset) as usize;
-                targets.insert(target);
-            }
-        }
+/// Entry points of the catch and finally handlers installed by a `Try`.
+///
+/// Returns `(offset, depth_delta)` pairs: the VM unwinds to the depth at the
+/// `Try` and pushes the exception before entering a catch handler. A finally
+/// handler is entered with nothing pushed, and is checked even when a catch
+/// handler is present.
+fn try_handler_entries(instr: &Instruction) -> Vec<(usize, i32)> {
+    if instr.opcode != Opcode::Try || instr.operands.len() < 8 {
+        return Vec::new();
     }
+    let catch_rel = read_i32_operand(instr, 0);
+    let finally_rel = read_i32_operand(instr, 4);
 
-    Ok(targets)
+    let mut entries = Vec::with_capacity(2);
+    if catch_rel >= 0 {
+        entries.push(((instr.offset as i32 + 1 + 4 + catch_rel) as usize, 1));
+    }
+    if finally_rel > 0 {
+        entries.push(((instr.offset as i32 + 1 + 8 + finally_rel) as usize, 0));
+    }
+    entries
 }
 
 /// Check if an offset is a valid instruction boundary
@@ -381,35 +403,155 @@ fn is_valid_instruction_boundary(offset: usize, instructions: &[Instruction]) ->
     instructions.iter().any(|instr| instr.offset == offset)
 }
 
-/// Verify stack depth consistency using abstract interpretation
+/// Verify stack depth consistency using abstract interpretation.
+///
+/// Walks the control-flow graph block by block from the function entry. Every
+/// block entry (jump target, fall-through after a conditional jump, or
+/// exception handler) must be reached with the same stack depth from all of
+/// its predecessors. Unreachable code is not checked.
 fn verify_stack_depth(
+    func: &str,
     instructions: &[Instruction],
-    _jump_targets: &HashSet<usize>,
+    jump_targets: &HashSet<usize>,
 ) -> Result<(), VerifyError> {
-    let mut stack_depth = 0i32;
     const MAX_STACK_DEPTH: i32 = 1024;
 
-    for instr in instructions {
-        // Calculate stack effect
-        let (pops, pushes) = get_stack_effect(instr.opcode);
+    let index_of: HashMap<usize, usize> = instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instr)| (instr.offset, index))
+        .collect();
+
+    let mut block_depths: HashMap<usize, i32> = HashMap::new();
+    let mut worklist = vec![0usize];
+    block_depths.insert(0, 0);
+
+    while let Some(block_start) = worklist.pop() {
+        // Jump targets and handler offsets are checked before being queued
+        let start = index_of[&block_start];
+        let mut stack_depth = block_depths[&block_start];
+
+        for (index, instr) in instructions.iter().enumerate().skip(start) {
+            // Falling through into another block ends this one
+            if index != start && jump_targets.contains(&instr.offset) {
+                let entry = instr.offset;
+                enter_block(func, entry, stack_depth, &mut block_depths, &mut worklist)?;
+                break;
+            }
 
-        // Check for underflow
-        if stack_depth < pops {
-            return Err(VerifyError::StackUnderflow(instr.offset));
-        }
+            // Calculate stack effect
+            let (pops, pushes) = instruction_stack_effect(instr);
 
-        stack_depth -= pops;
-        stack_depth += pushes;
+            // Check for underflow
+            if stack_depth < pops {
+                return Err(VerifyError::StackUnderflow(instr.offset));
+            }
 
-        // Check for overflow
-        if stack_depth > MAX_STACK_DEPTH {
-            return Err(VerifyError::StackOverflow(instr.offset, stack_depth));
+            for (handler, delta) in try_handler_entries(instr) {
+                if !index_of.contains_key(&handler) {
+                    return Err(VerifyError::InvalidJumpTarget {
+                        target: handler,
+                        offset: instr.offset,
+                    });
+                }
+                let depth = stack_depth + delta;
+                enter_block(func, handler, depth, &mut block_depths, &mut worklist)?;
+            }
+
+            stack_depth -= pops;
+            stack_depth += pushes;
+
+            // Check for overflow
+            if stack_depth > MAX_STACK_DEPTH {
+                return Err(VerifyError::StackOverflow(instr.offset, stack_depth));
+            }
+
+            if let Some(target) = jump_target(instr) {
+                enter_block(func, target, stack_depth, &mut block_depths, &mut worklist)?;
+            }
+            if instr.opcode.is_terminator() {
+                if instr.opcode.is_conditional_jump() {
+                    if let Some(next) = instructions.get(index + 1) {
+                        let next = next.offset;
+                        enter_block(func, next, stack_depth, &mut block_depths, &mut worklist)?;
+                    }
+                }
+                break;
+            }
         }
     }
 
     Ok(())
 }
 
+/// Record the depth a block is entered with, queueing it on first visit
+fn enter_block(
+    func: &str,
+    offset: usize,
+    depth: i32,
+    block_depths: &mut HashMap<usize, i32>,
+    worklist: &mut Vec<usize>,
+) -> Result<(), VerifyError> {
+    match block_depths.get(&offset) {
+        Some(&existing) if existing != depth => Err(VerifyError::StackImbalance {
+            func: func.to_string(),
+            offset,
+        }),
+        Some(_) => Ok(()),
+        None => {
+            block_depths.insert(offset, depth);
+            worklist.push(offset);
+            Ok(())
+        }
+    }
+}
+
+/// Get the stack effect of a decoded instruction (pops, pushes).
+///
+/// Refines [`get_stack_effect`] for opcodes whose operands carry an argument,
+/// capture, or element count.
+fn instruction_stack_effect(instr: &Instruction) -> (i32, i32) {
+    match instr.opcode {
+        Opcode::Call => {
+            // Closure calls (function index u32::MAX) also pop the closure
+            let is_closure = read_u32_operand(instr, 0) == u32::MAX;
+            (read_u16_operand(instr, 4) + is_closure as i32, 1)
+        }
+        Opcode::CallStatic | Opcode::CallConstructor | Opcode::Spawn | Opcode::MakeClosure => {
+            (read_u16_operand(instr, 4), 1)
+        }
+        Opcode::CallMethodExact | Opcode::OptionalCallMethodExact => {
+            // Arguments plus the receiver
+            (read_u16_operand(instr, 4) + 1, 1)
+        }
+        Opcode::CallMethodShape | Opcode::OptionalCallMethodShape => {
+            (read_u16_operand(instr, 10) + 1, 1)
+        }
+        // Super constructor calls consume the receiver and discard the result
+        Opcode::CallSuper => (read_u16_operand(instr, 4) + 1, 0),
+        Opcode::ConstructType => (instr.operands[2] as i32, 1),
+        Opcode::NativeCall | Opcode::ModuleNativeCall => (instr.operands[2] as i32, 1),
+        Opcode::SpawnClosure => (read_u16_operand(instr, 0) + 1, 1),
+        Opcode::ArrayLiteral => (read_u32_operand(instr, 4) as i32, 1),
+        // Pop the value, leave the object/array being initialized in place
+        Opcode::InitObject | Opcode::InitArray | Opcode::InitTuple => (2, 1),
+        opcode => get_stack_effect(opcode),
+    }
+}
+
+fn read_u16_operand(instr: &Instruction, at: usize) -> i32 {
+    u16::from_le_bytes([instr.operands[at], instr.operands[at + 1]]) as i32
+}
+
+fn read_u32_operand(instr: &Instruction, at: usize) -> u32 {
+    let bytes: [u8; 4] = instr.operands[at..at + 4].try_into().unwrap();
+    u32::from_le_bytes(bytes)
+}
+
+fn read_i32_operand(instr: &Instruction, at: usize) -> i32 {
+    read_u32_operand(instr, at) as i32
+}
+
 /// Get the stack effect of an opcode (pops, pushes)
 fn get_stack_effect(opcode: Opcode) -> (i32, i32) {
     match opcode {
@@ -741,4 +883,112 @@ mod tests {
         let result = verify_module(&module);
         assert!(matches!(result, Err(VerifyError::FallOffEnd(_))));
     }
+
+    #[test]
+    fn test_verify_balanced_branches() {
+        let mut module = Module::new("test".to_string());
+
+        // if (true) { 1 } else { 2 }, both arms leave one value for the join
+        let mut writer = BytecodeWriter::new();
+        writer.emit_const_true(); // 0
+        writer.emit_jmp_if_false(10); // 1 -> 16
+        writer.emit_const_i32(1); // 6
+        writer.emit_jmp(5); // 11 -> 21
+        writer.emit_const_i32(2); // 16
+        writer.emit_return(); // 21
+
+        module.functions.push(Function {
+            name: "balanced".to_string(),
+            param_count: 0,
+            local_count: 0,
+            code: writer.into_bytes(),
+        });
+
+        assert!(verify_module(&module).is_ok());
+    }
+
+    #[test]
+    fn test_verify_stack_imbalance() {
+        let mut module = Module::new("test".to_string());
+
+        // Only the fall-through path pushes a value before the join
+        let mut writer = BytecodeWriter::new();
+        writer.emit_const_true(); // 0
+        writer.emit_jmp_if_false(5); // 1 -> 11
+        writer.emit_const_i32(1); // 6
+        writer.emit_return_void(); // 11
+
+        module.functions.push(Function {
+            name: "unbalanced".to_string(),
+            param_count: 0,
+            local_count: 0,
+            code: writer.into_bytes(),
+        });
+
+        let result = verify_module(&module);
+        match result {
+            Err(VerifyError::StackImbalance { func, offset }) => {
+                assert_eq!(func, "unbalanced");
+                assert_eq!(offset, 11);
+            }
+            other => panic!("expected StackImbalance, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_verify_checks_finally_handler_alongside_catch() {
+        let mut module = Module::new("test".to_string());
+
+        // The finally handler underflows; it must be checked even though a
+        // catch handler is installed too
+        let mut writer = BytecodeWriter::new();
+        writer.emit_opcode(Opcode::Try); // 0, catch -> 15, finally -> 16
+        writer.emit_i32(10);
+        writer.emit_i32(7);
+        writer.emit_const_i32(1); // 9
+        writer.emit_return(); // 14
+        writer.emit_return(); // 15: catch returns the exception
+        writer.emit_pop(); // 16: finally
+        writer.emit_return_void(); // 17
+
+        module.functions.push(Function {
+            name: "handlers".to_string(),
+            param_count: 0,
+            local_count: 0,
+            code: writer.into_bytes(),
+        });
+
+        let result = verify_module(&module);
+        assert!(
+            matches!(result, Err(VerifyError::StackUnderflow(16))),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn test_verify_invalid_handler_reports_try_offset() {
+        let mut module = Module::new("test".to_string());
+
+        let mut writer = BytecodeWriter::new();
+        writer.emit_nop(); // 0
+        writer.emit_opcode(Opcode::Try); // 1, catch -> 6 (inside the Try)
+        writer.emit_i32(0);
+        writer.emit_i32(0);
+        writer.emit_return_void(); // 10
+
+        module.functions.push(Function {
+            name: "bad_handler".to_string(),
+            param_count: 0,
+            local_count: 0,
+            code: writer.into_bytes(),
+        });
+
+        match verify_module(&module) {
+            Err(VerifyError::InvalidJumpTarget { target, offset }) => {
+                assert_eq!(target, 6);
+                assert_eq!(offset, 1);
+            }
+            other => panic!("expected InvalidJumpTarget, got {:?}", other),
+        }
+    }
 }
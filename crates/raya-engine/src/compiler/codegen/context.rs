@@ -281,6 +281,12 @@ impl IrCodeGenerator {
             bytecode_module.native_functions = module.native_functions.clone();
         }
 
+        // Add JSON decode schemas for //@@json types
+        if !module.json_schemas.is_empty() {
+            bytecode_module.flags |= flags::HAS_JSON_SCHEMAS;
+            bytecode_module.json_schemas = module.json_schemas.clone();
+        }
+
         // Add debug info (source map) if collected
         if self.emit_sourcemap && !func_debug_infos.is_empty() {
             let debug_info = DebugInfo {
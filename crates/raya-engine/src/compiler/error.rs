@@ -50,4 +50,10 @@ pub enum CompileError {
 
     #[error("Bytecode verification failed: {message}")]
     Verification { message: String },
+
+    #[error("Invalid IR: {message}")]
+    InvalidIr { message: String },
+
+    #[error("Unresolved native function '{name}' in {function}")]
+    UnresolvedNative { name: String, function: String },
 }
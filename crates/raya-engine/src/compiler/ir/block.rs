@@ -150,6 +150,42 @@ pub enum Terminator {
 }
 
 impl Terminator {
+    /// Get the registers this terminator reads
+    pub fn used_registers(&self) -> Vec<&Register> {
+        let mut used = Vec::new();
+        match self {
+            Terminator::Branch { cond, .. } => {
+                used.push(cond);
+            }
+            Terminator::BranchIfNull { value, .. } => {
+                used.push(value);
+            }
+            Terminator::Return(Some(reg)) => {
+                used.push(reg);
+            }
+            Terminator::Switch { value, .. } => {
+                used.push(value);
+            }
+            Terminator::Throw(reg) => {
+                used.push(reg);
+            }
+            Terminator::Jump(_) | Terminator::Return(None) | Terminator::Unreachable => {}
+        }
+        used
+    }
+
+    /// Get mutable references to the registers this terminator reads
+    pub fn registers_mut(&mut self) -> Vec<&mut Register> {
+        match self {
+            Terminator::Branch { cond: reg, .. }
+            | Terminator::BranchIfNull { value: reg, .. }
+            | Terminator::Return(Some(reg))
+            | Terminator::Switch { value: reg, .. }
+            | Terminator::Throw(reg) => vec![reg],
+            Terminator::Jump(_) | Terminator::Return(None) | Terminator::Unreachable => vec![],
+        }
+    }
+
     /// Get all successor blocks
     pub fn successors(&self) -> Vec<BasicBlockId> {
         match self {
@@ -482,6 +482,252 @@ pub enum IrInstr {
 }
 
 impl IrInstr {
+    /// Get the registers this instruction reads
+    pub fn used_registers(&self) -> Vec<&Register> {
+        let mut used = Vec::new();
+        match self {
+            IrInstr::Assign { value, .. } => {
+                if let IrValue::Register(reg) = value {
+                    used.push(reg);
+                }
+            }
+            IrInstr::BinaryOp { left, right, .. } => {
+                used.push(left);
+                used.push(right);
+            }
+            IrInstr::UnaryOp { operand, .. } => {
+                used.push(operand);
+            }
+            IrInstr::Call { args, .. } => {
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::ConstructType { object, args, .. } => {
+                used.push(object);
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::CallMethodExact { object, args, .. } => {
+                used.push(object);
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::NativeCall { args, .. } | IrInstr::ModuleNativeCall { args, .. } => {
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::IsNominal { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::ImplementsShape { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::CastNominal { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::CastShape { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::CastTupleLen { object, .. }
+            | IrInstr::CastObjectMinFields { object, .. }
+            | IrInstr::CastArrayElemKind { object, .. }
+            | IrInstr::CastKindMask { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::StoreLocal { value, .. } => {
+                used.push(value);
+            }
+            IrInstr::LoadFieldExact { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::LoadFieldShape { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::CallMethodShape { object, args, .. } => {
+                used.push(object);
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::StoreFieldExact { object, value, .. } => {
+                used.push(object);
+                used.push(value);
+            }
+            IrInstr::StoreFieldShape { object, value, .. } => {
+                used.push(object);
+                used.push(value);
+            }
+            IrInstr::DynGetProp { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::DynGetKeyed { object, key, .. } => {
+                used.push(object);
+                used.push(key);
+            }
+            IrInstr::DynSetProp { object, value, .. } => {
+                used.push(object);
+                used.push(value);
+            }
+            IrInstr::DynSetKeyed { object, key, value } => {
+                used.push(object);
+                used.push(key);
+                used.push(value);
+            }
+            IrInstr::LateBoundMember { object, .. } => {
+                used.push(object);
+            }
+            IrInstr::LoadElement { array, index, .. } => {
+                used.push(array);
+                used.push(index);
+            }
+            IrInstr::StoreElement {
+                array,
+                index,
+                value,
+            } => {
+                used.push(array);
+                used.push(index);
+                used.push(value);
+            }
+            IrInstr::NewArray { len, .. } => {
+                used.push(len);
+            }
+            IrInstr::ArrayLiteral { elements, .. } => {
+                for elem in elements {
+                    used.push(elem);
+                }
+            }
+            IrInstr::ObjectLiteral { fields, .. } => {
+                for (_, value) in fields {
+                    used.push(value);
+                }
+            }
+            IrInstr::ArrayLen { array, .. } => {
+                used.push(array);
+            }
+            IrInstr::ArrayPush { array, element } => {
+                used.push(array);
+                used.push(element);
+            }
+            IrInstr::ArrayPop { array, .. } => {
+                used.push(array);
+            }
+            IrInstr::StringLen { string, .. } => {
+                used.push(string);
+            }
+            IrInstr::Typeof { operand, .. } => {
+                used.push(operand);
+            }
+            IrInstr::Phi { sources, .. } => {
+                for (_, reg) in sources {
+                    used.push(reg);
+                }
+            }
+            IrInstr::MakeClosure { captures, .. } => {
+                for capture in captures {
+                    used.push(capture);
+                }
+            }
+            IrInstr::StoreCaptured { value, .. } => {
+                used.push(value);
+            }
+            IrInstr::SetClosureCapture { closure, value, .. } => {
+                used.push(closure);
+                used.push(value);
+            }
+            IrInstr::NewRefCell { initial_value, .. } => {
+                used.push(initial_value);
+            }
+            IrInstr::LoadRefCell { refcell, .. } => {
+                used.push(refcell);
+            }
+            IrInstr::StoreRefCell { refcell, value } => {
+                used.push(refcell);
+                used.push(value);
+            }
+            IrInstr::CallClosure { closure, args, .. } => {
+                used.push(closure);
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::LoadLocal { .. }
+            | IrInstr::LoadArgCount { .. }
+            | IrInstr::NewType { .. }
+            | IrInstr::LoadCaptured { .. }
+            | IrInstr::LoadGlobal { .. } => {
+                // No register uses
+            }
+            IrInstr::LoadArgLocal { index, .. } => {
+                // LoadArgLocal uses the index register
+                used.push(index);
+            }
+            IrInstr::StoreGlobal { value, .. } => {
+                used.push(value);
+            }
+            IrInstr::StringCompare { left, right, .. } => {
+                used.push(left);
+                used.push(right);
+            }
+            IrInstr::ToString { operand, .. } => {
+                used.push(operand);
+            }
+            IrInstr::Spawn { args, .. } => {
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::SpawnClosure { closure, args, .. } => {
+                used.push(closure);
+                for arg in args {
+                    used.push(arg);
+                }
+            }
+            IrInstr::Await { task, .. } => {
+                used.push(task);
+            }
+            IrInstr::AwaitAll { tasks, .. } => {
+                used.push(tasks);
+            }
+            IrInstr::Sleep { duration_ms } => {
+                used.push(duration_ms);
+            }
+            IrInstr::Yield => {
+                // No register uses
+            }
+            IrInstr::Debugger => {
+                // No register uses
+            }
+            IrInstr::NewMutex { .. } => {
+                // Creates a mutex, dest is handled by dest() method
+            }
+            IrInstr::NewChannel { capacity, .. } => {
+                // Uses capacity register
+                used.push(capacity);
+            }
+            IrInstr::MutexLock { mutex } => {
+                used.push(mutex);
+            }
+            IrInstr::MutexUnlock { mutex } => {
+                used.push(mutex);
+            }
+            IrInstr::TaskCancel { task } => {
+                used.push(task);
+            }
+            IrInstr::SetupTry { .. } | IrInstr::EndTry | IrInstr::PopToLocal { .. } => {
+                // No register uses
+            }
+            IrInstr::BindMethod { object, .. } => {
+                used.push(object);
+            }
+        }
+        used
+    }
+
     /// Get the destination register if this instruction produces a value
     pub fn dest(&self) -> Option<&Register> {
         match self {
@@ -558,6 +804,194 @@ impl IrInstr {
         }
     }
 
+    /// Get mutable references to every register this instruction defines or reads
+    pub fn registers_mut(&mut self) -> Vec<&mut Register> {
+        let mut regs: Vec<&mut Register> = Vec::new();
+        match self {
+            IrInstr::Assign { dest, value } => {
+                regs.push(dest);
+                if let IrValue::Register(reg) = value {
+                    regs.push(reg);
+                }
+            }
+            IrInstr::BinaryOp {
+                dest, left, right, ..
+            }
+            | IrInstr::StringCompare {
+                dest, left, right, ..
+            } => {
+                regs.extend([dest, left, right]);
+            }
+            IrInstr::UnaryOp { dest, operand, .. }
+            | IrInstr::Typeof { dest, operand }
+            | IrInstr::ToString { dest, operand } => {
+                regs.extend([dest, operand]);
+            }
+            IrInstr::Call { dest, args, .. }
+            | IrInstr::NativeCall { dest, args, .. }
+            | IrInstr::ModuleNativeCall { dest, args, .. } => {
+                regs.extend(dest.as_mut());
+                regs.extend(args.iter_mut());
+            }
+            IrInstr::ConstructType {
+                dest, object, args, ..
+            } => {
+                regs.extend([dest, object]);
+                regs.extend(args.iter_mut());
+            }
+            IrInstr::CallMethodExact {
+                dest, object, args, ..
+            }
+            | IrInstr::CallMethodShape {
+                dest, object, args, ..
+            } => {
+                regs.extend(dest.as_mut());
+                regs.push(object);
+                regs.extend(args.iter_mut());
+            }
+            IrInstr::CallClosure {
+                dest,
+                closure,
+                args,
+            } => {
+                regs.extend(dest.as_mut());
+                regs.push(closure);
+                regs.extend(args.iter_mut());
+            }
+            IrInstr::BindMethod { dest, object, .. }
+            | IrInstr::IsNominal { dest, object, .. }
+            | IrInstr::ImplementsShape { dest, object, .. }
+            | IrInstr::CastNominal { dest, object, .. }
+            | IrInstr::CastShape { dest, object, .. }
+            | IrInstr::CastTupleLen { dest, object, .. }
+            | IrInstr::CastObjectMinFields { dest, object, .. }
+            | IrInstr::CastArrayElemKind { dest, object, .. }
+            | IrInstr::CastKindMask { dest, object, .. }
+            | IrInstr::LoadFieldExact { dest, object, .. }
+            | IrInstr::LoadFieldShape { dest, object, .. }
+            | IrInstr::DynGetProp { dest, object, .. }
+            | IrInstr::LateBoundMember { dest, object, .. } => {
+                regs.extend([dest, object]);
+            }
+            IrInstr::LoadLocal { dest, .. }
+            | IrInstr::LoadArgCount { dest }
+            | IrInstr::LoadGlobal { dest, .. }
+            | IrInstr::NewType { dest, .. }
+            | IrInstr::LoadCaptured { dest, .. }
+            | IrInstr::NewMutex { dest } => {
+                regs.push(dest);
+            }
+            IrInstr::LoadArgLocal { dest, index } => {
+                regs.extend([dest, index]);
+            }
+            IrInstr::StoreLocal { value, .. }
+            | IrInstr::StoreGlobal { value, .. }
+            | IrInstr::StoreCaptured { value, .. } => {
+                regs.push(value);
+            }
+            IrInstr::StoreFieldExact { object, value, .. }
+            | IrInstr::StoreFieldShape { object, value, .. }
+            | IrInstr::DynSetProp { object, value, .. } => {
+                regs.extend([object, value]);
+            }
+            IrInstr::DynGetKeyed { dest, object, key } => {
+                regs.extend([dest, object, key]);
+            }
+            IrInstr::DynSetKeyed { object, key, value } => {
+                regs.extend([object, key, value]);
+            }
+            IrInstr::LoadElement { dest, array, index } => {
+                regs.extend([dest, array, index]);
+            }
+            IrInstr::StoreElement {
+                array,
+                index,
+                value,
+            } => {
+                regs.extend([array, index, value]);
+            }
+            IrInstr::NewArray { dest, len, .. } => {
+                regs.extend([dest, len]);
+            }
+            IrInstr::ArrayLiteral { dest, elements, .. } => {
+                regs.push(dest);
+                regs.extend(elements.iter_mut());
+            }
+            IrInstr::ObjectLiteral { dest, fields, .. } => {
+                regs.push(dest);
+                regs.extend(fields.iter_mut().map(|(_, value)| value));
+            }
+            IrInstr::ArrayLen { dest, array } | IrInstr::ArrayPop { dest, array } => {
+                regs.extend([dest, array]);
+            }
+            IrInstr::ArrayPush { array, element } => {
+                regs.extend([array, element]);
+            }
+            IrInstr::StringLen { dest, string } => {
+                regs.extend([dest, string]);
+            }
+            IrInstr::Phi { dest, sources } => {
+                regs.push(dest);
+                regs.extend(sources.iter_mut().map(|(_, reg)| reg));
+            }
+            IrInstr::MakeClosure { dest, captures, .. } => {
+                regs.push(dest);
+                regs.extend(captures.iter_mut());
+            }
+            IrInstr::SetClosureCapture { closure, value, .. } => {
+                regs.extend([closure, value]);
+            }
+            IrInstr::NewRefCell {
+                dest,
+                initial_value,
+            } => {
+                regs.extend([dest, initial_value]);
+            }
+            IrInstr::LoadRefCell { dest, refcell } => {
+                regs.extend([dest, refcell]);
+            }
+            IrInstr::StoreRefCell { refcell, value } => {
+                regs.extend([refcell, value]);
+            }
+            IrInstr::Spawn { dest, args, .. } => {
+                regs.push(dest);
+                regs.extend(args.iter_mut());
+            }
+            IrInstr::SpawnClosure {
+                dest,
+                closure,
+                args,
+            } => {
+                regs.extend([dest, closure]);
+                regs.extend(args.iter_mut());
+            }
+            IrInstr::Await { dest, task } => {
+                regs.extend([dest, task]);
+            }
+            IrInstr::AwaitAll { dest, tasks } => {
+                regs.extend([dest, tasks]);
+            }
+            IrInstr::NewChannel { dest, capacity } => {
+                regs.extend([dest, capacity]);
+            }
+            IrInstr::Sleep { duration_ms } => {
+                regs.push(duration_ms);
+            }
+            IrInstr::MutexLock { mutex } | IrInstr::MutexUnlock { mutex } => {
+                regs.push(mutex);
+            }
+            IrInstr::TaskCancel { task } => {
+                regs.push(task);
+            }
+            IrInstr::PopToLocal { .. }
+            | IrInstr::SetupTry { .. }
+            | IrInstr::EndTry
+            | IrInstr::Yield
+            | IrInstr::Debugger => {}
+        }
+        regs
+    }
+
     /// Check if this instruction has side effects
     pub fn has_side_effects(&self) -> bool {
         matches!(
@@ -10,12 +10,14 @@
 //! - `BasicBlock` - A sequence of instructions with a single entry and exit
 //! - `IrInstr` - Three-address code instructions
 //! - `Register` - Virtual registers with type information
+//! - `validate` - Structural checks on lowered IR before codegen
 
 pub mod block;
 pub mod function;
 pub mod instr;
 pub mod module;
 pub mod pretty;
+pub mod validate;
 pub mod value;
 
 pub use block::{BasicBlock, BasicBlockId, Terminator};
@@ -25,4 +27,5 @@ pub use instr::{
 };
 pub use module::{IrClass, IrField, IrModule, IrTypeAlias, IrTypeAliasField};
 pub use pretty::{format_instr_pub, PrettyPrint};
+pub use validate::{validate, IrError, IrErrorKind};
 pub use value::{IrConstant, IrValue, Register, RegisterId, ValueOrigin};
@@ -5,6 +5,7 @@
 use super::function::IrFunction;
 use super::instr::{NominalTypeId, FunctionId, TypeAliasId};
 use crate::parser::TypeId;
+use crate::vm::json::TypeSchema;
 use rustc_hash::FxHashMap;
 
 /// An IR module (compilation unit)
@@ -31,6 +32,8 @@ pub struct IrModule {
     pub structural_shapes: FxHashMap<u64, Vec<String>>,
     /// Physical structural layout metadata referenced by this module.
     pub structural_layouts: FxHashMap<u32, Vec<String>>,
+    /// JSON decode schemas for `//@@json` classes and type aliases.
+    pub json_schemas: Vec<TypeSchema>,
 }
 
 impl IrModule {
@@ -47,6 +50,7 @@ impl IrModule {
             native_functions: Vec::new(),
             structural_shapes: FxHashMap::default(),
             structural_layouts: FxHashMap::default(),
+            json_schemas: Vec::new(),
         }
     }
 
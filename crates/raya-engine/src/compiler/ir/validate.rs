@@ -0,0 +1,388 @@
+//! IR Validation
+//!
+//! Structural checks run on lowered IR before code generation, so lowering
+//! bugs surface as errors naming the offending function and block instead of
+//! as panics or silently miscompiled jumps in codegen.
+
+use super::block::BasicBlockId;
+use super::function::IrFunction;
+use super::instr::IrInstr;
+use super::module::IrModule;
+use super::value::RegisterId;
+use crate::parser::TypeId;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::hash_map::Entry;
+
+/// A validation failure, located at a function and (usually) a block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IrError {
+    /// Name of the function containing the error
+    pub function: String,
+    /// Block containing the error, if it is block-specific
+    pub block: Option<BasicBlockId>,
+    /// What went wrong
+    pub kind: IrErrorKind,
+}
+
+/// Kinds of IR validation failures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IrErrorKind {
+    /// The function's entry block does not exist
+    MissingEntryBlock(BasicBlockId),
+    /// A terminator, phi or try handler references a block that does not exist
+    DanglingBlock(BasicBlockId),
+    /// A reachable block still has the placeholder `Unreachable` terminator
+    MissingTerminator,
+    /// A register is read but never defined in the function
+    UndefinedRegister(RegisterId),
+    /// A register is read before its only definition in the same block
+    UseBeforeDefinition(RegisterId),
+    /// A register is defined more than once with different types
+    TypeMismatch {
+        register: RegisterId,
+        expected: TypeId,
+        found: TypeId,
+    },
+}
+
+impl std::fmt::Display for IrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "function '{}'", self.function)?;
+        if let Some(block) = self.block {
+            write!(f, ", block {}", block)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl std::fmt::Display for IrErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IrErrorKind::MissingEntryBlock(block) => {
+                write!(f, "entry block {} does not exist", block)
+            }
+            IrErrorKind::DanglingBlock(block) => {
+                write!(f, "reference to non-existent block {}", block)
+            }
+            IrErrorKind::MissingTerminator => write!(f, "reachable block has no terminator"),
+            IrErrorKind::UndefinedRegister(reg) => {
+                write!(f, "register {} is used but never defined", reg)
+            }
+            IrErrorKind::UseBeforeDefinition(reg) => {
+                write!(f, "register {} is used before its definition", reg)
+            }
+            IrErrorKind::TypeMismatch {
+                register,
+                expected,
+                found,
+            } => write!(
+                f,
+                "register {} is defined as {} and as {}",
+                register, expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IrError {}
+
+/// Validate every function in a module
+///
+/// Checks that block references resolve, that every reachable block is
+/// terminated, that registers are defined before use, and that all
+/// definitions of a register agree on its type.
+pub fn validate(module: &IrModule) -> Result<(), Vec<IrError>> {
+    let mut errors = Vec::new();
+    for func in module.functions() {
+        validate_function(func, &mut errors);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate a single function, appending any failures to `errors`
+pub fn validate_function(func: &IrFunction, errors: &mut Vec<IrError>) {
+    // Declarations without a body have nothing to check
+    if func.is_empty() {
+        return;
+    }
+
+    let error = |block: Option<BasicBlockId>, kind: IrErrorKind| IrError {
+        function: func.name.clone(),
+        block,
+        kind,
+    };
+
+    if func.get_block(func.entry_block).is_none() {
+        errors.push(error(
+            None,
+            IrErrorKind::MissingEntryBlock(func.entry_block),
+        ));
+        return;
+    }
+
+    // Block references: terminator successors plus phi sources and try handlers
+    for block in func.blocks() {
+        let mut targets = block.successors();
+        for instr in &block.instructions {
+            targets.extend(instr_block_refs(instr));
+        }
+        for target in targets {
+            if func.get_block(target).is_none() {
+                errors.push(error(Some(block.id), IrErrorKind::DanglingBlock(target)));
+            }
+        }
+    }
+
+    // Terminators: only blocks reachable from the entry must be terminated;
+    // dead blocks may keep the placeholder, which codegen emits as a trap
+    let mut reachable = FxHashSet::default();
+    let mut worklist = vec![func.entry_block];
+    while let Some(id) = worklist.pop() {
+        if !reachable.insert(id) {
+            continue;
+        }
+        if let Some(block) = func.get_block(id) {
+            worklist.extend(block.successors());
+            for instr in &block.instructions {
+                if let IrInstr::SetupTry {
+                    catch_block,
+                    finally_block,
+                } = instr
+                {
+                    worklist.push(*catch_block);
+                    worklist.extend(*finally_block);
+                }
+            }
+        }
+    }
+    for block in func.blocks() {
+        if reachable.contains(&block.id) && !block.is_terminated() {
+            errors.push(error(Some(block.id), IrErrorKind::MissingTerminator));
+        }
+    }
+
+    // Definitions: parameters and instruction destinations
+    let mut def_types: FxHashMap<RegisterId, TypeId> = FxHashMap::default();
+    let mut def_blocks: FxHashMap<RegisterId, FxHashSet<BasicBlockId>> = FxHashMap::default();
+    for param in &func.params {
+        def_types.insert(param.id, param.ty);
+    }
+    for block in func.blocks() {
+        for dest in block.instructions.iter().filter_map(IrInstr::dest) {
+            def_blocks.entry(dest.id).or_default().insert(block.id);
+            match def_types.entry(dest.id) {
+                Entry::Vacant(entry) => {
+                    entry.insert(dest.ty);
+                }
+                Entry::Occupied(entry) if *entry.get() != dest.ty => {
+                    errors.push(error(
+                        Some(block.id),
+                        IrErrorKind::TypeMismatch {
+                            register: dest.id,
+                            expected: *entry.get(),
+                            found: dest.ty,
+                        },
+                    ));
+                }
+                Entry::Occupied(_) => {}
+            }
+        }
+    }
+
+    // Uses: every register must be defined somewhere in the function, and a
+    // register defined only in this block must be defined before it is read.
+    // Phi sources flow in from predecessors, so only their existence is checked.
+    let params: FxHashSet<RegisterId> = func.params.iter().map(|p| p.id).collect();
+    for block in func.blocks() {
+        let mut defined_here = FxHashSet::default();
+        let mut reported = FxHashSet::default();
+        let uses = block
+            .instructions
+            .iter()
+            .map(|instr| (instr.used_registers(), Some(instr)))
+            .chain(std::iter::once((block.terminator.used_registers(), None)));
+        for (used, instr) in uses {
+            let is_phi = matches!(instr, Some(IrInstr::Phi { .. }));
+            for reg in used {
+                let kind = if !def_types.contains_key(&reg.id) {
+                    IrErrorKind::UndefinedRegister(reg.id)
+                } else if !is_phi
+                    && !params.contains(&reg.id)
+                    && !defined_here.contains(&reg.id)
+                    && def_blocks
+                        .get(&reg.id)
+                        .is_some_and(|blocks| blocks.len() == 1 && blocks.contains(&block.id))
+                {
+                    IrErrorKind::UseBeforeDefinition(reg.id)
+                } else {
+                    continue;
+                };
+                if reported.insert(reg.id) {
+                    errors.push(error(Some(block.id), kind));
+                }
+            }
+            if let Some(dest) = instr.and_then(IrInstr::dest) {
+                defined_here.insert(dest.id);
+            }
+        }
+    }
+}
+
+/// Blocks referenced from inside an instruction (not via the terminator)
+fn instr_block_refs(instr: &IrInstr) -> Vec<BasicBlockId> {
+    match instr {
+        IrInstr::Phi { sources, .. } => sources.iter().map(|(block, _)| *block).collect(),
+        IrInstr::SetupTry {
+            catch_block,
+            finally_block,
+        } => std::iter::once(*catch_block)
+            .chain(*finally_block)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::ir::block::{BasicBlock, Terminator};
+    use crate::compiler::ir::value::{IrConstant, IrValue, Register};
+
+    fn make_reg(id: u32, ty_id: u32) -> Register {
+        Register::new(RegisterId::new(id), TypeId::new(ty_id))
+    }
+
+    fn module_with(func: IrFunction) -> IrModule {
+        let mut module = IrModule::new("test");
+        module.add_function(func);
+        module
+    }
+
+    #[test]
+    fn test_valid_function_passes() {
+        let mut func = IrFunction::new("ok", vec![make_reg(0, 1)], TypeId::new(1));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.add_instr(IrInstr::Assign {
+            dest: make_reg(1, 1),
+            value: IrValue::Constant(IrConstant::I32(1)),
+        });
+        entry.add_instr(IrInstr::BinaryOp {
+            dest: make_reg(2, 1),
+            op: crate::compiler::ir::BinaryOp::Add,
+            left: make_reg(0, 1),
+            right: make_reg(1, 1),
+        });
+        entry.set_terminator(Terminator::Return(Some(make_reg(2, 1))));
+        func.add_block(entry);
+
+        assert!(validate(&module_with(func)).is_ok());
+    }
+
+    #[test]
+    fn test_dangling_block_reference() {
+        let mut func = IrFunction::new("jumps_nowhere", vec![], TypeId::new(0));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.set_terminator(Terminator::Jump(BasicBlockId(7)));
+        func.add_block(entry);
+
+        let errors = validate(&module_with(func)).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![IrError {
+                function: "jumps_nowhere".to_string(),
+                block: Some(BasicBlockId(0)),
+                kind: IrErrorKind::DanglingBlock(BasicBlockId(7)),
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "function 'jumps_nowhere', block bb0: reference to non-existent block bb7"
+        );
+    }
+
+    #[test]
+    fn test_undefined_register_use() {
+        let mut func = IrFunction::new("reads_garbage", vec![], TypeId::new(1));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.set_terminator(Terminator::Return(Some(make_reg(3, 1))));
+        func.add_block(entry);
+
+        let errors = validate(&module_with(func)).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].function, "reads_garbage");
+        assert_eq!(errors[0].block, Some(BasicBlockId(0)));
+        assert_eq!(
+            errors[0].kind,
+            IrErrorKind::UndefinedRegister(RegisterId::new(3))
+        );
+    }
+
+    #[test]
+    fn test_use_before_definition_in_block() {
+        let mut func = IrFunction::new("out_of_order", vec![], TypeId::new(1));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.add_instr(IrInstr::Assign {
+            dest: make_reg(1, 1),
+            value: IrValue::Register(make_reg(0, 1)),
+        });
+        entry.add_instr(IrInstr::Assign {
+            dest: make_reg(0, 1),
+            value: IrValue::Constant(IrConstant::I32(1)),
+        });
+        entry.set_terminator(Terminator::Return(Some(make_reg(1, 1))));
+        func.add_block(entry);
+
+        let errors = validate(&module_with(func)).unwrap_err();
+        assert_eq!(
+            errors[0].kind,
+            IrErrorKind::UseBeforeDefinition(RegisterId::new(0))
+        );
+    }
+
+    #[test]
+    fn test_reachable_block_without_terminator() {
+        let mut func = IrFunction::new("falls_off", vec![], TypeId::new(0));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.set_terminator(Terminator::Jump(BasicBlockId(1)));
+        func.add_block(entry);
+        func.add_block(BasicBlock::new(BasicBlockId(1)));
+        // Dead blocks may keep the placeholder terminator
+        func.add_block(BasicBlock::new(BasicBlockId(2)));
+
+        let errors = validate(&module_with(func)).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].block, Some(BasicBlockId(1)));
+        assert_eq!(errors[0].kind, IrErrorKind::MissingTerminator);
+    }
+
+    #[test]
+    fn test_conflicting_register_types() {
+        let mut func = IrFunction::new("retyped", vec![], TypeId::new(0));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.add_instr(IrInstr::Assign {
+            dest: make_reg(0, 1),
+            value: IrValue::Constant(IrConstant::I32(1)),
+        });
+        entry.add_instr(IrInstr::Assign {
+            dest: make_reg(0, 2),
+            value: IrValue::Constant(IrConstant::Boolean(true)),
+        });
+        entry.set_terminator(Terminator::Return(None));
+        func.add_block(entry);
+
+        let errors = validate(&module_with(func)).unwrap_err();
+        assert_eq!(
+            errors[0].kind,
+            IrErrorKind::TypeMismatch {
+                register: RegisterId::new(0),
+                expected: TypeId::new(1),
+                found: TypeId::new(2),
+            }
+        );
+    }
+}
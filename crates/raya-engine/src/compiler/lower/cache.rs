@@ -0,0 +1,305 @@
+//! Lowered Function Reuse
+//!
+//! A `LoweringCache` carries the IR of a module's top-level function
+//! declarations from one lowering of the module to the next. With the cache
+//! attached, a function whose declaration text and checker types are
+//! unchanged takes its previous IR (and the closures nested in it) instead of
+//! being lowered again. Classes, type aliases and top-level statements are
+//! always lowered.
+//!
+//! Reuse is conservative:
+//! - the module context must match: the checker's type table, the text of
+//!   every other top-level declaration, every function signature, the
+//!   pre-assigned function ids and the lowering options;
+//! - the lowerer must be in the state it was in when the function was last
+//!   lowered (function ids, arrow numbering, module-level tables);
+//! - functions whose lowering grows a module-level table (natives, shapes,
+//!   globals, classes) are never cached.
+
+use super::Lowerer;
+use crate::compiler::ir::IrFunction;
+use crate::parser::ast::{self, Statement};
+use crate::parser::token::Span;
+use crate::parser::TypeId;
+use rustc_hash::{FxHashMap, FxHasher};
+use std::hash::{Hash, Hasher};
+
+/// Lowered top-level functions of one module, kept for its next lowering
+#[derive(Debug, Clone, Default)]
+pub struct LoweringCache {
+    /// Hash of the module context the cached functions were lowered in
+    context: u64,
+    /// Cached functions keyed by function id
+    functions: FxHashMap<u32, CachedFunction>,
+    /// Entries of the previous lowering, consumed by the current one
+    previous: FxHashMap<u32, CachedFunction>,
+    /// Checker types by expression span, sorted (current lowering only)
+    expr_spans: Vec<(usize, usize, TypeId)>,
+    /// Names of the top-level functions the last lowering lowered
+    lowered: Vec<String>,
+    /// Number of top-level functions the last lowering reused
+    reused: usize,
+}
+
+impl LoweringCache {
+    /// Create an empty cache (every function is lowered on first use)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Names of the top-level functions the last lowering had to lower
+    pub fn lowered_functions(&self) -> &[String] {
+        &self.lowered
+    }
+
+    /// Number of top-level functions the last lowering took from the cache
+    pub fn reused_functions(&self) -> usize {
+        self.reused
+    }
+}
+
+/// A top-level function as it was last lowered
+#[derive(Debug, Clone)]
+struct CachedFunction {
+    /// Hash of the declaration text and the checker types inside it
+    key: u64,
+    /// Declaration span the IR spans belong to
+    span: Span,
+    /// Lowerer state before lowering the function
+    before: LoweringState,
+    /// Lowerer state after lowering the function
+    after: LoweringState,
+    /// The lowered function
+    function: IrFunction,
+    /// Closures lowered inside the function, with their function ids
+    closures: Vec<(u32, IrFunction)>,
+}
+
+/// Module-level lowerer state a function's lowering reads or advances
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LoweringState {
+    next_function_id: u32,
+    arrow_counter: u32,
+    /// Hash of the module-level tables lowering may grow
+    tables: u64,
+}
+
+/// Moves the IR spans of a cached function to the declaration's new position
+struct Relocation {
+    from: Span,
+    to: Span,
+}
+
+impl Relocation {
+    fn span(&self, span: &mut Span) {
+        if *span == Span::default() || span.start < self.from.start || span.end > self.from.end {
+            return;
+        }
+        span.start = span.start - self.from.start + self.to.start;
+        span.end = span.end - self.from.start + self.to.start;
+        span.line = span.line - self.from.line + self.to.line;
+    }
+
+    fn function(&self, func: &mut IrFunction) {
+        self.span(&mut func.source_span);
+        for block in &mut func.blocks {
+            for span in &mut block.instruction_spans {
+                self.span(span);
+            }
+            self.span(&mut block.terminator_span);
+        }
+    }
+}
+
+impl<'a> Lowerer<'a> {
+    /// Reuse functions from `cache` where possible; `source` is the text the
+    /// lowered module was parsed from.
+    pub fn with_lowering_cache(mut self, cache: LoweringCache, source: &'a str) -> Self {
+        self.lowering_cache = Some(cache);
+        self.source_text = Some(source);
+        self
+    }
+
+    /// Take the cache back after `lower_module`, updated with this lowering
+    pub fn take_lowering_cache(&mut self) -> Option<LoweringCache> {
+        self.lowering_cache.take()
+    }
+
+    /// Prepare the cache for a lowering of `module`, dropping every cached
+    /// function if the module context changed.
+    pub(super) fn begin_cached_lowering(&mut self, module: &ast::Module) {
+        let Some(source) = self.source_text else {
+            return;
+        };
+        if self.lowering_cache.is_none() {
+            return;
+        }
+        let context = self.lowering_context_hash(module, source);
+        let mut expr_spans: Vec<_> = self
+            .expr_types_by_span
+            .iter()
+            .map(|(&(start, end), &ty)| (start, end, ty))
+            .collect();
+        expr_spans.sort_by_key(|&(start, end, ty)| (start, end, ty.as_u32()));
+
+        let cache = self.lowering_cache.as_mut().unwrap();
+        cache.previous = std::mem::take(&mut cache.functions);
+        if cache.context != context {
+            cache.previous.clear();
+            cache.context = context;
+        }
+        cache.expr_spans = expr_spans;
+        cache.lowered.clear();
+        cache.reused = 0;
+    }
+
+    /// Drop the per-lowering parts of the cache.
+    pub(super) fn finish_cached_lowering(&mut self) {
+        if let Some(cache) = self.lowering_cache.as_mut() {
+            cache.previous.clear();
+            cache.expr_spans = Vec::new();
+        }
+    }
+
+    /// Lower a top-level function into `pending_arrow_functions`, reusing
+    /// its cached IR when nothing it depends on changed.
+    pub(super) fn lower_top_level_function(&mut self, func: &ast::FunctionDecl, func_id: u32) {
+        let Some(key) = self.function_cache_key(func) else {
+            let ir_func = self.lower_function(func);
+            self.pending_arrow_functions.push((func_id, ir_func));
+            return;
+        };
+        let before = self.lowering_state();
+        let cache = self.lowering_cache.as_mut().unwrap();
+
+        if let Some(mut entry) = cache.previous.remove(&func_id).filter(|entry| {
+            entry.key == key && entry.before == before && entry.span.column == func.span.column
+        }) {
+            let relocation = Relocation {
+                from: entry.span,
+                to: func.span,
+            };
+            relocation.function(&mut entry.function);
+            for (_, closure) in &mut entry.closures {
+                relocation.function(closure);
+            }
+            entry.span = func.span;
+            cache.reused += 1;
+            self.pending_arrow_functions
+                .extend(entry.closures.iter().cloned());
+            self.pending_arrow_functions
+                .push((func_id, entry.function.clone()));
+            self.next_function_id = entry.after.next_function_id;
+            self.arrow_counter = entry.after.arrow_counter;
+            cache.functions.insert(func_id, entry);
+            return;
+        }
+
+        cache
+            .lowered
+            .push(self.interner.resolve(func.name.name).to_string());
+        let first_closure = self.pending_arrow_functions.len();
+        let ir_func = self.lower_function(func);
+        let after = self.lowering_state();
+        if after.tables == before.tables {
+            let entry = CachedFunction {
+                key,
+                span: func.span,
+                before,
+                after,
+                function: ir_func.clone(),
+                closures: self.pending_arrow_functions[first_closure..].to_vec(),
+            };
+            if let Some(cache) = self.lowering_cache.as_mut() {
+                cache.functions.insert(func_id, entry);
+            }
+        }
+        self.pending_arrow_functions.push((func_id, ir_func));
+    }
+
+    /// Hash of the declaration text of `func` and of the checker types of the
+    /// expressions inside it (by offset from the declaration start), or
+    /// `None` when no cache is attached.
+    fn function_cache_key(&self, func: &ast::FunctionDecl) -> Option<u64> {
+        let cache = self.lowering_cache.as_ref()?;
+        let text = self.source_text?.get(func.span.start..func.span.end)?;
+        let mut hasher = FxHasher::default();
+        text.hash(&mut hasher);
+        let first = cache
+            .expr_spans
+            .partition_point(|&(start, _, _)| start < func.span.start);
+        for &(start, end, ty) in cache.expr_spans[first..]
+            .iter()
+            .take_while(|&&(start, _, _)| start < func.span.end)
+        {
+            (start - func.span.start, end - func.span.start, ty.as_u32()).hash(&mut hasher);
+        }
+        Some(hasher.finish())
+    }
+
+    /// Hash of everything outside function bodies that lowering a function
+    /// depends on.
+    fn lowering_context_hash(&self, module: &ast::Module, source: &str) -> u64 {
+        let mut hasher = FxHasher::default();
+        self.type_ctx.fingerprint().hash(&mut hasher);
+        (
+            self.emit_sourcemap,
+            self.strip_assertions,
+            self.js_this_binding_compat,
+            self.allow_unresolved_runtime_fallback,
+        )
+            .hash(&mut hasher);
+        format!("{:?}", self.jsx_options).hash(&mut hasher);
+
+        let mut globals: Vec<_> = self.ambient_builtin_globals.iter().collect();
+        globals.sort();
+        globals.hash(&mut hasher);
+        let mut constants: Vec<_> = self
+            .imported_constants
+            .iter()
+            .map(|(name, value)| format!("{}={:?}", name, value))
+            .collect();
+        constants.sort();
+        constants.hash(&mut hasher);
+
+        let mut decl_ids: Vec<_> = self.function_decl_ids.iter().collect();
+        decl_ids.sort_by_key(|(start, _)| **start);
+        for (_, func_id) in decl_ids {
+            func_id.as_u32().hash(&mut hasher);
+        }
+
+        // Function bodies are keyed per function; everything else is context.
+        for raw_stmt in &module.statements {
+            let span = raw_stmt.span();
+            let end = match Self::unwrap_export(raw_stmt) {
+                Statement::FunctionDecl(func) => func.body.span.start,
+                _ => span.end,
+            };
+            source.get(span.start..end).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    fn lowering_state(&self) -> LoweringState {
+        let mut hasher = FxHasher::default();
+        self.native_function_table.hash(&mut hasher);
+        (
+            self.module_structural_shapes.len(),
+            self.module_structural_layouts.len(),
+            self.next_global_index,
+            self.next_nominal_type_id,
+            self.lowered_classes.len(),
+            self.class_method_cache.len(),
+            self.function_map.len(),
+            self.variable_class_map.len(),
+            self.variable_structural_projection_fields.len(),
+            self.errors.len(),
+        )
+            .hash(&mut hasher);
+        LoweringState {
+            next_function_id: self.next_function_id,
+            arrow_counter: self.arrow_counter,
+            tables: hasher.finish(),
+        }
+    }
+}
@@ -19,6 +19,7 @@ use crate::parser::{TypeContext as TC, TypeId};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 // Re-export VM builtin method IDs (canonical source of truth)
+use crate::vm::builtin::array as builtin_array;
 #[allow(unused_imports)]
 use crate::vm::builtin::number as builtin_number;
 use crate::vm::builtin::regexp as builtin_regexp;
@@ -175,6 +176,7 @@ impl<'a> Lowerer<'a> {
         match expr {
             Expression::IntLiteral(lit) => self.lower_int_literal(lit),
             Expression::FloatLiteral(lit) => self.lower_float_literal(lit),
+            Expression::BigIntLiteral(lit) => self.lower_bigint_literal(lit),
             Expression::StringLiteral(lit) => self.lower_string_literal(lit),
             Expression::BooleanLiteral(lit) => self.lower_bool_literal(lit),
             Expression::NullLiteral(_) => self.lower_null_literal(),
@@ -200,6 +202,10 @@ impl<'a> Lowerer<'a> {
             Expression::AsyncCall(async_call) => self.lower_async_call(async_call),
             Expression::InstanceOf(instanceof) => self.lower_instanceof(instanceof),
             Expression::TypeCast(cast) => self.lower_type_cast(cast),
+            // `satisfies` is compile-time only; the value is the inner expression.
+            Expression::Satisfies(satisfies) => self.lower_expr(&satisfies.object),
+            // `as const` only narrows the static type.
+            Expression::ConstAssertion(assertion) => self.lower_expr(&assertion.object),
             Expression::RegexLiteral(regex) => self.lower_regex_literal(regex),
             Expression::TaggedTemplate(tagged) => self.lower_tagged_template(tagged),
             Expression::DynamicImport(import) => {
@@ -233,6 +239,48 @@ impl<'a> Lowerer<'a> {
         dest
     }
 
+    fn lower_bigint_literal(&mut self, lit: &ast::BigIntLiteral) -> Register {
+        // Same construction path as `new BigInt("...")` against the ambient class;
+        // a local class named BigInt must not capture literals.
+        if !self.ambient_builtin_globals.contains("BigInt") {
+            self.errors.push(CompileError::UnsupportedFeature {
+                feature: "bigint literals without the BigInt builtin".to_string(),
+            });
+            return self.lower_null_literal();
+        }
+
+        let class_name = self.alloc_register(TypeId::new(STRING_TYPE_ID));
+        self.emit(IrInstr::Assign {
+            dest: class_name.clone(),
+            value: IrValue::Constant(IrConstant::String("BigInt".to_string())),
+        });
+        let class_value = self.alloc_register(UNRESOLVED);
+        self.emit(IrInstr::NativeCall {
+            dest: Some(class_value.clone()),
+            native_id: crate::compiler::native_id::OBJECT_GET_AMBIENT_GLOBAL,
+            args: vec![class_name],
+        });
+
+        let digits = self.interner.resolve(lit.digits).to_string();
+        let digits_reg = self.alloc_register(TypeId::new(STRING_TYPE_ID));
+        self.emit(IrInstr::Assign {
+            dest: digits_reg.clone(),
+            value: IrValue::Constant(IrConstant::String(digits)),
+        });
+
+        let bigint_ty = self
+            .type_ctx
+            .lookup_named_type("BigInt")
+            .unwrap_or(UNRESOLVED);
+        let dest = self.alloc_register(bigint_ty);
+        self.emit(IrInstr::NativeCall {
+            dest: Some(dest.clone()),
+            native_id: crate::compiler::native_id::OBJECT_CONSTRUCT_DYNAMIC_CLASS,
+            args: vec![class_value, digits_reg],
+        });
+        dest
+    }
+
     fn lower_string_literal(&mut self, lit: &ast::StringLiteral) -> Register {
         let ty = TypeId::new(STRING_TYPE_ID);
         let dest = self.alloc_register(ty);
@@ -2425,6 +2473,14 @@ impl<'a> Lowerer<'a> {
                     ("Object", "defineProperties") => {
                         Some(crate::compiler::native_id::OBJECT_DEFINE_PROPERTIES)
                     }
+                    ("Object", "keys") => Some(crate::compiler::native_id::OBJECT_KEYS),
+                    ("Object", "values") => Some(crate::compiler::native_id::OBJECT_VALUES),
+                    ("Object", "entries") => Some(crate::compiler::native_id::OBJECT_ENTRIES),
+                    ("Object", "freeze") => Some(crate::compiler::native_id::OBJECT_FREEZE),
+                    ("Object", "isFrozen") => Some(crate::compiler::native_id::OBJECT_IS_FROZEN),
+                    ("Object", "deepFreeze") => {
+                        Some(crate::compiler::native_id::OBJECT_DEEP_FREEZE)
+                    }
                     _ => None,
                 };
                 if let Some(native_id) = static_native_id {
@@ -6685,6 +6741,14 @@ impl<'a> Lowerer<'a> {
         contains_type_id(self, ty_id, NUMBER_TYPE_ID, &mut visited_number)
     }
 
+    fn is_numeric_array_capacity(&self, arg: &Expression) -> bool {
+        if matches!(arg, Expression::IntLiteral(_) | Expression::FloatLiteral(_)) {
+            return true;
+        }
+        let ty = self.get_expr_type(arg).as_u32();
+        ty == NUMBER_TYPE_ID || ty == INT_TYPE_ID
+    }
+
     fn lower_new(&mut self, new_expr: &ast::NewExpression) -> Register {
         // Constructor results are object-like by default. Keep unresolved until a
         // concrete class/type path assigns a precise type.
@@ -6706,6 +6770,23 @@ impl<'a> Lowerer<'a> {
                             elem_ty: TypeId::new(NUMBER_TYPE_ID),
                         });
                     }
+                    // Strict mode: a numeric single arg is a capacity hint, not a length.
+                    1 if !self.allow_unresolved_runtime_fallback
+                        && self.is_numeric_array_capacity(&new_expr.arguments[0]) =>
+                    {
+                        let zero = self.emit_i32_const(0);
+                        self.emit(IrInstr::NewArray {
+                            dest: array_dest.clone(),
+                            len: zero,
+                            elem_ty: TypeId::new(NUMBER_TYPE_ID),
+                        });
+                        let capacity = self.lower_expr(&new_expr.arguments[0]);
+                        self.emit(IrInstr::NativeCall {
+                            dest: None,
+                            native_id: builtin_array::RESERVE,
+                            args: vec![array_dest.clone(), capacity],
+                        });
+                    }
                     1 => match &new_expr.arguments[0] {
                         // JS-compatible pragmatic subset: numeric single arg = length.
                         Expression::IntLiteral(_) | Expression::FloatLiteral(_) => {
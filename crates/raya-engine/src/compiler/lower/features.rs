@@ -0,0 +1,340 @@
+//! `//@@if(feature)` conditional compilation
+//!
+//! The parser records every `//@@if` condition in [`ast::Module::feature_gates`]
+//! against the span of the statement or class member it precedes. Before the
+//! module is bound, [`strip_disabled_features`] removes each gated item whose
+//! condition fails, wherever it is nested, so compiled-out code is never
+//! bound, type-checked or lowered.
+
+use crate::parser::ast::{
+    self, ArrayElement, ArrowBody, BlockStatement, ClassDecl, ClassMember, ExportDecl, Expression,
+    ForInit, ForOfLeft, JsxAttribute, JsxAttributeValue, JsxChild, ObjectProperty, Parameter,
+    PropertyKey, Statement, TemplatePart, VariableDecl,
+};
+use crate::parser::token::Span;
+use rustc_hash::FxHashSet;
+
+/// Remove the statements and class members gated on a feature that is not
+/// among `features` (or on `!feature` for one that is).
+pub fn strip_disabled_features(module: &mut ast::Module, features: &[String]) {
+    let disabled: FxHashSet<Span> = module
+        .feature_gates
+        .iter()
+        .filter(|gate| !gate.is_enabled(features))
+        .map(|gate| gate.target)
+        .collect();
+    if disabled.is_empty() {
+        return;
+    }
+    FeatureStripper { disabled }.statements(&mut module.statements);
+}
+
+struct FeatureStripper {
+    /// Spans of the statements and class members to drop
+    disabled: FxHashSet<Span>,
+}
+
+impl FeatureStripper {
+    fn statements(&self, statements: &mut Vec<Statement>) {
+        statements.retain(|statement| !self.disabled.contains(statement.span()));
+        for statement in statements {
+            self.statement(statement);
+        }
+    }
+
+    /// A statement in a position that needs one (loop or `if` body): a
+    /// disabled one becomes an empty statement
+    fn body(&self, body: &mut Statement) {
+        if self.disabled.contains(body.span()) {
+            *body = Statement::Empty(*body.span());
+        } else {
+            self.statement(body);
+        }
+    }
+
+    fn block(&self, block: &mut BlockStatement) {
+        self.statements(&mut block.statements);
+    }
+
+    fn statement(&self, statement: &mut Statement) {
+        match statement {
+            Statement::VariableDecl(decl) => self.variable_decl(decl),
+            Statement::FunctionDecl(decl) => {
+                self.params(&mut decl.params);
+                self.block(&mut decl.body);
+            }
+            Statement::ClassDecl(decl) => self.class(decl),
+            Statement::ExportDecl(ExportDecl::Declaration(inner)) => self.statement(inner),
+            Statement::ExportDecl(ExportDecl::Default { expression, .. }) => {
+                self.expression(expression)
+            }
+            Statement::Expression(stmt) => self.expression(&mut stmt.expression),
+            Statement::If(stmt) => {
+                self.expression(&mut stmt.condition);
+                self.body(&mut stmt.then_branch);
+                if let Some(else_branch) = &mut stmt.else_branch {
+                    self.body(else_branch);
+                }
+            }
+            Statement::Switch(stmt) => {
+                self.expression(&mut stmt.discriminant);
+                for case in &mut stmt.cases {
+                    if let Some(test) = &mut case.test {
+                        self.expression(test);
+                    }
+                    if let Some(guard) = &mut case.guard {
+                        self.expression(guard);
+                    }
+                    self.statements(&mut case.consequent);
+                }
+            }
+            Statement::While(stmt) => {
+                self.expression(&mut stmt.condition);
+                self.body(&mut stmt.body);
+            }
+            Statement::DoWhile(stmt) => {
+                self.body(&mut stmt.body);
+                self.expression(&mut stmt.condition);
+            }
+            Statement::For(stmt) => {
+                match &mut stmt.init {
+                    Some(ForInit::VariableDecl(decl)) => self.variable_decl(decl),
+                    Some(ForInit::Expression(expr)) => self.expression(expr),
+                    None => {}
+                }
+                if let Some(test) = &mut stmt.test {
+                    self.expression(test);
+                }
+                if let Some(update) = &mut stmt.update {
+                    self.expression(update);
+                }
+                self.body(&mut stmt.body);
+            }
+            Statement::ForOf(stmt) => {
+                if let ForOfLeft::VariableDecl(decl) = &mut stmt.left {
+                    self.variable_decl(decl);
+                }
+                self.expression(&mut stmt.right);
+                self.body(&mut stmt.body);
+            }
+            Statement::ForIn(stmt) => {
+                if let ForOfLeft::VariableDecl(decl) = &mut stmt.left {
+                    self.variable_decl(decl);
+                }
+                self.expression(&mut stmt.right);
+                self.body(&mut stmt.body);
+            }
+            Statement::Return(stmt) => {
+                if let Some(value) = &mut stmt.value {
+                    self.expression(value);
+                }
+            }
+            Statement::Yield(stmt) => {
+                if let Some(value) = &mut stmt.value {
+                    self.expression(value);
+                }
+            }
+            Statement::Throw(stmt) => self.expression(&mut stmt.value),
+            Statement::Try(stmt) => {
+                self.block(&mut stmt.body);
+                if let Some(catch) = &mut stmt.catch_clause {
+                    self.block(&mut catch.body);
+                }
+                if let Some(finally) = &mut stmt.finally_clause {
+                    self.block(finally);
+                }
+            }
+            Statement::Block(block) => self.block(block),
+            Statement::Labeled(stmt) => self.body(&mut stmt.body),
+            Statement::TypeAliasDecl(_)
+            | Statement::ImportDecl(_)
+            | Statement::ExportDecl(_)
+            | Statement::Break(_)
+            | Statement::Continue(_)
+            | Statement::Debugger(_)
+            | Statement::Empty(_) => {}
+        }
+    }
+
+    fn variable_decl(&self, decl: &mut VariableDecl) {
+        if let Some(init) = &mut decl.initializer {
+            self.expression(init);
+        }
+    }
+
+    fn class(&self, class: &mut ClassDecl) {
+        class
+            .members
+            .retain(|member| !self.disabled.contains(member.span()));
+        for member in &mut class.members {
+            match member {
+                ClassMember::Field(field) => {
+                    if let Some(init) = &mut field.initializer {
+                        self.expression(init);
+                    }
+                }
+                ClassMember::Method(method) => {
+                    self.params(&mut method.params);
+                    if let Some(body) = &mut method.body {
+                        self.block(body);
+                    }
+                }
+                ClassMember::Constructor(ctor) => {
+                    self.params(&mut ctor.params);
+                    self.block(&mut ctor.body);
+                }
+                ClassMember::StaticBlock(block) => self.block(block),
+            }
+        }
+    }
+
+    fn params(&self, params: &mut [Parameter]) {
+        for param in params {
+            if let Some(default) = &mut param.default_value {
+                self.expression(default);
+            }
+        }
+    }
+
+    /// Expressions only matter for the arrow function bodies inside them
+    fn expression(&self, expr: &mut Expression) {
+        match expr {
+            Expression::Arrow(arrow) => {
+                self.params(&mut arrow.params);
+                match &mut arrow.body {
+                    ArrowBody::Block(block) => self.block(block),
+                    ArrowBody::Expression(body) => self.expression(body),
+                }
+            }
+            Expression::TemplateLiteral(lit) => self.template_parts(&mut lit.parts),
+            Expression::TaggedTemplate(tagged) => {
+                self.expression(&mut tagged.tag);
+                self.template_parts(&mut tagged.template.parts);
+            }
+            Expression::Array(arr) => {
+                for element in arr.elements.iter_mut().flatten() {
+                    match element {
+                        ArrayElement::Expression(expr) | ArrayElement::Spread(expr) => {
+                            self.expression(expr)
+                        }
+                    }
+                }
+            }
+            Expression::Object(obj) => {
+                for property in &mut obj.properties {
+                    match property {
+                        ObjectProperty::Property(prop) => {
+                            if let PropertyKey::Computed(key) = &mut prop.key {
+                                self.expression(key);
+                            }
+                            self.expression(&mut prop.value);
+                        }
+                        ObjectProperty::Spread(spread) => self.expression(&mut spread.argument),
+                    }
+                }
+            }
+            Expression::Unary(unary) => self.expression(&mut unary.operand),
+            Expression::Binary(binary) => {
+                self.expression(&mut binary.left);
+                self.expression(&mut binary.right);
+            }
+            Expression::Assignment(assign) => {
+                self.expression(&mut assign.left);
+                self.expression(&mut assign.right);
+            }
+            Expression::Logical(logical) => {
+                self.expression(&mut logical.left);
+                self.expression(&mut logical.right);
+            }
+            Expression::Conditional(cond) => {
+                self.expression(&mut cond.test);
+                self.expression(&mut cond.consequent);
+                self.expression(&mut cond.alternate);
+            }
+            Expression::Call(call) => {
+                self.expression(&mut call.callee);
+                self.expressions(&mut call.arguments);
+            }
+            Expression::AsyncCall(call) => {
+                self.expression(&mut call.callee);
+                self.expressions(&mut call.arguments);
+            }
+            Expression::New(new_expr) => {
+                self.expression(&mut new_expr.callee);
+                self.expressions(&mut new_expr.arguments);
+            }
+            Expression::Member(member) => self.expression(&mut member.object),
+            Expression::Index(index) => {
+                self.expression(&mut index.object);
+                self.expression(&mut index.index);
+            }
+            Expression::Await(await_expr) => self.expression(&mut await_expr.argument),
+            Expression::Typeof(typeof_expr) => self.expression(&mut typeof_expr.argument),
+            Expression::Parenthesized(paren) => self.expression(&mut paren.expression),
+            Expression::InstanceOf(instanceof) => self.expression(&mut instanceof.object),
+            Expression::TypeCast(cast) => self.expression(&mut cast.object),
+            Expression::Satisfies(satisfies) => self.expression(&mut satisfies.object),
+            Expression::ConstAssertion(assertion) => self.expression(&mut assertion.object),
+            Expression::DynamicImport(import) => self.expression(&mut import.source),
+            Expression::JsxElement(elem) => self.jsx_element(elem),
+            Expression::JsxFragment(frag) => self.jsx_children(&mut frag.children),
+            Expression::IntLiteral(_)
+            | Expression::FloatLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::RegexLiteral(_)
+            | Expression::Identifier(_)
+            | Expression::This(_)
+            | Expression::Super(_) => {}
+        }
+    }
+
+    fn expressions(&self, exprs: &mut [Expression]) {
+        for expr in exprs {
+            self.expression(expr);
+        }
+    }
+
+    fn template_parts(&self, parts: &mut [TemplatePart]) {
+        for part in parts {
+            if let TemplatePart::Expression(expr) = part {
+                self.expression(expr);
+            }
+        }
+    }
+
+    fn jsx_element(&self, elem: &mut ast::JsxElement) {
+        for attribute in &mut elem.opening.attributes {
+            match attribute {
+                JsxAttribute::Attribute {
+                    value: Some(value), ..
+                } => match value {
+                    JsxAttributeValue::Expression(expr) => self.expression(expr),
+                    JsxAttributeValue::JsxElement(elem) => self.jsx_element(elem),
+                    JsxAttributeValue::JsxFragment(frag) => self.jsx_children(&mut frag.children),
+                    JsxAttributeValue::StringLiteral(_) => {}
+                },
+                JsxAttribute::Attribute { value: None, .. } => {}
+                JsxAttribute::Spread { argument, .. } => self.expression(argument),
+            }
+        }
+        self.jsx_children(&mut elem.children);
+    }
+
+    fn jsx_children(&self, children: &mut [JsxChild]) {
+        for child in children {
+            match child {
+                JsxChild::Element(elem) => self.jsx_element(elem),
+                JsxChild::Fragment(frag) => self.jsx_children(&mut frag.children),
+                JsxChild::Expression(expr) => {
+                    if let Some(expr) = &mut expr.expression {
+                        self.expression(expr);
+                    }
+                }
+                JsxChild::Text(_) => {}
+            }
+        }
+    }
+}
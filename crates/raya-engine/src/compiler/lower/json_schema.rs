@@ -0,0 +1,251 @@
+//! JSON Schema Lowering
+//!
+//! Builds a `TypeSchema` for every top-level class or object type alias
+//! marked `//@@json`. Field types come from the checker's resolved types, so
+//! the VM can validate `json` casts against the real declarations.
+//!
+//! Field keys follow the field annotations: `//@@json name` renames a key,
+//! `//@@json -` leaves the field unread (it decodes as null). Field types
+//! with no JSON schema of their own (`json`, literals, maps, classes not
+//! marked `//@@json`, ...) accept any JSON value.
+
+use super::Lowerer;
+use crate::compiler::ir::IrModule;
+use crate::parser::ast::{self, Statement};
+use crate::parser::types::ty::PrimitiveType;
+use crate::parser::{Type, TypeId};
+use crate::vm::json::{TypeKind, TypeSchema};
+use crate::vm::object::layout_id_from_ordered_names;
+use rustc_hash::FxHashMap;
+
+/// JSON key and resolved type of one field of a `//@@json` declaration
+pub(super) struct JsonFieldInfo {
+    /// Source field name
+    pub name: String,
+    /// JSON object key (`None` for fields marked `//@@json -`)
+    pub key: Option<String>,
+    /// Checker-resolved field type
+    pub ty: TypeId,
+}
+
+/// A top-level declaration marked `//@@json`
+enum JsonDecl<'m> {
+    Class(&'m ast::ClassDecl),
+    Alias(&'m ast::TypeAliasDecl),
+}
+
+/// Schemas of one module, with the schema already built for each type
+struct SchemaTable {
+    schemas: Vec<TypeSchema>,
+    by_type: FxHashMap<TypeId, usize>,
+    skipped: Option<usize>,
+}
+
+impl SchemaTable {
+    fn push(&mut self, kind: TypeKind) -> usize {
+        let type_id = self.schemas.len();
+        self.schemas.push(TypeSchema { type_id, kind });
+        type_id
+    }
+
+    fn skipped(&mut self) -> usize {
+        match self.skipped {
+            Some(type_id) => type_id,
+            None => {
+                let type_id = self.push(TypeKind::Skipped);
+                self.skipped = Some(type_id);
+                type_id
+            }
+        }
+    }
+}
+
+fn is_json_annotated(annotations: &[ast::Annotation]) -> bool {
+    annotations.iter().any(|a| a.tag == "json")
+}
+
+/// JSON key for a field, or `None` when the field is marked `//@@json -`
+fn json_key(name: &str, annotations: &[ast::Annotation]) -> Option<String> {
+    let json = annotations.iter().find(|a| a.tag == "json");
+    match json {
+        Some(a) if a.is_skip() => None,
+        Some(a) => Some(a.json_field_name().unwrap_or(name).to_string()),
+        None => Some(name.to_string()),
+    }
+}
+
+impl<'a> Lowerer<'a> {
+    /// Emit JSON decode schemas for the `//@@json` declarations of `module`.
+    ///
+    /// Schema `i` describes the `i`-th declaration; the schemas of field
+    /// types follow. Must run after classes are added to `ir_module`, since
+    /// class schemas read their field types and layout order from the
+    /// lowered classes, and before the structural tables are moved into it.
+    pub(super) fn lower_json_schemas(&mut self, module: &ast::Module, ir_module: &mut IrModule) {
+        let decls: Vec<JsonDecl<'_>> = module
+            .statements
+            .iter()
+            .filter_map(|stmt| match Self::unwrap_export(stmt) {
+                Statement::ClassDecl(class) if is_json_annotated(&class.annotations) => {
+                    Some(JsonDecl::Class(class))
+                }
+                Statement::TypeAliasDecl(alias)
+                    if is_json_annotated(&alias.annotations)
+                        && matches!(alias.type_annotation.ty, ast::Type::Object(_)) =>
+                {
+                    Some(JsonDecl::Alias(alias))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Reserve a schema per declaration so fields can reference any of them
+        let mut table = SchemaTable {
+            schemas: Vec::with_capacity(decls.len()),
+            by_type: FxHashMap::default(),
+            skipped: None,
+        };
+        for decl in &decls {
+            let name = match decl {
+                JsonDecl::Class(class) => class.name.name,
+                JsonDecl::Alias(alias) => alias.name.name,
+            };
+            let type_id = table.push(TypeKind::Any);
+            if let Some(ty) = self.type_ctx.lookup_named_type(self.interner.resolve(name)) {
+                table.by_type.insert(ty, type_id);
+            }
+        }
+
+        for (type_id, decl) in decls.into_iter().enumerate() {
+            let (nominal_type_id, fields) = match decl {
+                JsonDecl::Class(class) => {
+                    let nominal_type_id = self
+                        .nominal_type_id_for_decl(class)
+                        .map(|id| id.as_u32() as usize);
+                    (nominal_type_id, self.get_json_field_info(class, ir_module))
+                }
+                JsonDecl::Alias(alias) => {
+                    let mut fields = self.get_json_alias_field_info(alias);
+                    // Structural objects keep their fields in sorted name order
+                    fields.sort_by(|a, b| a.name.cmp(&b.name));
+                    fields.dedup_by(|a, b| a.name == b.name);
+                    let names = fields.iter().map(|f| f.name.clone()).collect();
+                    self.emit_structural_registration_for_ordered_names(names);
+                    (None, fields)
+                }
+            };
+
+            let names: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
+            let fields = fields
+                .into_iter()
+                .map(|field| match field.key {
+                    Some(key) => (key, self.json_field_schema(field.ty, &mut table)),
+                    None => (field.name, table.skipped()),
+                })
+                .collect();
+            table.schemas[type_id].kind = TypeKind::Interface {
+                nominal_type_id,
+                layout_id: layout_id_from_ordered_names(&names),
+                fields,
+            };
+        }
+
+        ir_module.json_schemas = table.schemas;
+    }
+
+    /// JSON keys and types of a `//@@json` class's fields, in layout order.
+    ///
+    /// Inherited and constructor-parameter fields use their own names as keys.
+    pub(super) fn get_json_field_info(
+        &self,
+        class: &ast::ClassDecl,
+        ir_module: &IrModule,
+    ) -> Vec<JsonFieldInfo> {
+        let Some(ir_class) = self
+            .nominal_type_id_for_decl(class)
+            .and_then(|id| ir_module.classes.get(id.as_u32() as usize))
+        else {
+            return Vec::new();
+        };
+
+        let mut own_fields: FxHashMap<&str, &[ast::Annotation]> = FxHashMap::default();
+        for member in &class.members {
+            if let ast::ClassMember::Field(field) = member {
+                if !field.is_static {
+                    own_fields.insert(
+                        self.interner.resolve(field.name.name),
+                        field.annotations.as_slice(),
+                    );
+                }
+            }
+        }
+
+        let mut fields: Vec<_> = ir_class.fields.iter().collect();
+        fields.sort_by_key(|field| field.index);
+        fields
+            .into_iter()
+            .map(|field| {
+                let annotations = own_fields.get(field.name.as_str()).copied().unwrap_or(&[]);
+                JsonFieldInfo {
+                    name: field.name.clone(),
+                    key: json_key(&field.name, annotations),
+                    ty: field.ty,
+                }
+            })
+            .collect()
+    }
+
+    /// JSON keys and types of a `//@@json` object type alias's properties.
+    fn get_json_alias_field_info(&self, alias: &ast::TypeAliasDecl) -> Vec<JsonFieldInfo> {
+        let ast::Type::Object(obj_type) = &alias.type_annotation.ty else {
+            return Vec::new();
+        };
+        obj_type
+            .members
+            .iter()
+            .filter_map(|member| {
+                let ast::ObjectTypeMember::Property(prop) = member else {
+                    return None;
+                };
+                let name = self.interner.resolve(prop.name.name);
+                Some(JsonFieldInfo {
+                    name: name.to_string(),
+                    key: json_key(name, &prop.annotations),
+                    ty: self.resolve_type_annotation(&prop.ty),
+                })
+            })
+            .collect()
+    }
+
+    /// Type ID of the schema for a resolved field type, adding it to `table`
+    /// if needed.
+    ///
+    /// Types with no JSON schema of their own (functions, maps, literals,
+    /// classes not marked `//@@json`, ...) get an `Any` schema.
+    fn json_field_schema(&self, ty: TypeId, table: &mut SchemaTable) -> usize {
+        if let Some(&type_id) = table.by_type.get(&ty) {
+            return type_id;
+        }
+        let kind = match self.type_ctx.get(ty) {
+            Some(Type::Primitive(PrimitiveType::Number | PrimitiveType::Int)) => TypeKind::Number,
+            Some(Type::Primitive(PrimitiveType::String)) => TypeKind::String,
+            Some(Type::Primitive(PrimitiveType::Boolean)) => TypeKind::Bool,
+            Some(Type::Primitive(PrimitiveType::Null)) => TypeKind::Null,
+            Some(Type::Array(array)) => TypeKind::Array {
+                element_type_id: self.json_field_schema(array.element, table),
+            },
+            Some(Type::Union(union)) => TypeKind::Union {
+                variant_type_ids: union
+                    .members
+                    .iter()
+                    .map(|&member| self.json_field_schema(member, table))
+                    .collect(),
+                discriminant: union.discriminant.as_ref().map(|d| d.field_name.clone()),
+            },
+            _ => TypeKind::Any,
+        };
+        let type_id = table.push(kind);
+        table.by_type.insert(ty, type_id);
+        type_id
+    }
+}
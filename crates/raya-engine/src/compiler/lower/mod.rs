@@ -2,9 +2,12 @@
 //!
 //! Converts the type-checked AST into the IR representation.
 
+mod cache;
 mod class_methods;
 mod control_flow;
 mod expr;
+mod features;
+mod json_schema;
 mod stmt;
 
 use crate::compiler::ir::{
@@ -20,6 +23,9 @@ use crate::parser::token::Span;
 use crate::parser::{Interner, Symbol, Type, TypeContext, TypeId};
 use rustc_hash::{FxHashMap, FxHashSet};
 
+pub use cache::LoweringCache;
+pub use features::strip_disabled_features;
+
 /// Sentinel TypeId for when the lowerer cannot determine the type.
 /// Distinct from TypeId(0) (Number) and TypeId(6) (Unknown).
 /// Re-exported from type_registry for convenience.
@@ -139,6 +145,21 @@ pub enum ConstantValue {
     Bool(bool),
 }
 
+impl ConstantValue {
+    /// Evaluate a literal expression (no identifier lookup)
+    pub fn from_literal(expr: &Expression, interner: &Interner) -> Option<Self> {
+        match expr {
+            Expression::IntLiteral(lit) => Some(ConstantValue::I64(lit.value)),
+            Expression::FloatLiteral(lit) => Some(ConstantValue::F64(lit.value)),
+            Expression::StringLiteral(lit) => Some(ConstantValue::String(
+                interner.resolve(lit.value).to_string(),
+            )),
+            Expression::BooleanLiteral(lit) => Some(ConstantValue::Bool(lit.value)),
+            _ => None,
+        }
+    }
+}
+
 /// Information about a class field
 #[derive(Clone)]
 struct ClassFieldInfo {
@@ -630,6 +651,14 @@ pub struct Lowerer<'a> {
     js_this_binding_compat: bool,
     /// Whether unresolved member/call paths may lower to runtime late-bound dispatch.
     allow_unresolved_runtime_fallback: bool,
+    /// Drop statement-level calls to the builtin `assert` (`--no-assertions`).
+    strip_assertions: bool,
+    /// Literal values of imported constants, keyed by import-local name.
+    imported_constants: FxHashMap<String, ConstantValue>,
+    /// Previously lowered top-level functions to reuse (see `cache`).
+    lowering_cache: Option<LoweringCache>,
+    /// Source text the module was parsed from (set together with `lowering_cache`).
+    source_text: Option<&'a str>,
     /// Inner type for RefCell-wrapped variables (for preserving type info through loads)
     refcell_inner_types: FxHashMap<u16, TypeId>,
 }
@@ -1148,6 +1177,10 @@ impl<'a> Lowerer<'a> {
             specialized_function_cache: FxHashMap::default(),
             js_this_binding_compat: false,
             allow_unresolved_runtime_fallback: true,
+            strip_assertions: false,
+            imported_constants: FxHashMap::default(),
+            lowering_cache: None,
+            source_text: None,
         }
     }
 
@@ -1190,6 +1223,18 @@ impl<'a> Lowerer<'a> {
         self
     }
 
+    /// Compile statement-level calls to the builtin `assert` to nothing.
+    pub fn with_strip_assertions(mut self, enable: bool) -> Self {
+        self.strip_assertions = enable;
+        self
+    }
+
+    /// Provide link-time values of imported constants so their uses are inlined.
+    pub fn with_imported_constants(mut self, constants: FxHashMap<String, ConstantValue>) -> Self {
+        self.imported_constants = constants;
+        self
+    }
+
     /// Report an unresolved type error at a dispatch point.
     /// Mimics TypeScript's strict type errors — never silently emit incorrect bytecode.
     fn report_unresolved_type(&mut self, context: &str, property: &str) {
@@ -1238,18 +1283,11 @@ impl<'a> Lowerer<'a> {
     /// Returns Some(ConstantValue) if the expression is a literal, None otherwise
     fn try_eval_constant(&self, expr: &Expression) -> Option<ConstantValue> {
         match expr {
-            Expression::IntLiteral(lit) => Some(ConstantValue::I64(lit.value)),
-            Expression::FloatLiteral(lit) => Some(ConstantValue::F64(lit.value)),
-            Expression::StringLiteral(lit) => {
-                let s = self.interner.resolve(lit.value);
-                Some(ConstantValue::String(s.to_string()))
-            }
-            Expression::BooleanLiteral(lit) => Some(ConstantValue::Bool(lit.value)),
             // For identifiers, check if they reference another constant
             Expression::Identifier(ident) => self.constant_map.get(&ident.name).cloned(),
             // Could extend to support simple constant expressions like 0x0300
             // but for now only support direct literals
-            _ => None,
+            _ => ConstantValue::from_literal(expr, self.interner),
         }
     }
 
@@ -1328,6 +1366,32 @@ impl<'a> Lowerer<'a> {
     pub fn lower_module(&mut self, module: &ast::Module) -> IrModule {
         let mut ir_module = IrModule::new("main");
         self.build_expr_type_span_index(module);
+        self.begin_cached_lowering(module);
+
+        // Pre-pass: imported constants resolved at link time fold like local ones.
+        // Their global slots are still reserved below so slot numbering matches
+        // the module compiler's link tables.
+        if !self.imported_constants.is_empty() {
+            for stmt in &module.statements {
+                if let Statement::ImportDecl(import) = stmt {
+                    for specifier in &import.specifiers {
+                        let local_name = match specifier {
+                            ast::ImportSpecifier::Named { name, alias } => {
+                                alias.as_ref().map_or(name.name, |a| a.name)
+                            }
+                            ast::ImportSpecifier::Default(local) => local.name,
+                            ast::ImportSpecifier::Namespace(_) => continue,
+                        };
+                        if let Some(value) = self
+                            .imported_constants
+                            .get(self.interner.resolve(local_name))
+                        {
+                            self.constant_map.insert(local_name, value.clone());
+                        }
+                    }
+                }
+            }
+        }
 
         // Pre-pass: collect module-level const declarations (for constant folding)
         // These need to be processed before classes/functions so they're available
@@ -1887,10 +1951,8 @@ impl<'a> Lowerer<'a> {
                             func.span.start
                         )
                     });
-                    let ir_func = self.lower_function(func);
-                    // Add to pending with pre-assigned ID (will be sorted later)
-                    self.pending_arrow_functions
-                        .push((func_id.as_u32(), ir_func));
+                    // Added to pending with the pre-assigned ID (sorted later)
+                    self.lower_top_level_function(func, func_id.as_u32());
                 }
                 Statement::ClassDecl(class) => {
                     self.lower_class_declaration(class);
@@ -2057,11 +2119,15 @@ impl<'a> Lowerer<'a> {
             ir_module.add_function(func);
         }
 
+        self.lower_json_schemas(module, &mut ir_module);
+
         // Transfer native function table to the IR module
         ir_module.native_functions = self.take_native_function_table();
         ir_module.structural_shapes = self.module_structural_shapes.clone();
         ir_module.structural_layouts = self.module_structural_layouts.clone();
 
+        self.finish_cached_lowering();
+
         ir_module
     }
 
@@ -5493,6 +5559,10 @@ impl<'a> Lowerer<'a> {
                 self.type_annotation_is_callable(&cast.target_type)
                     || self.expression_is_callable_hint(&cast.object)
             }
+            Expression::Satisfies(satisfies) => self.expression_is_callable_hint(&satisfies.object),
+            Expression::ConstAssertion(assertion) => {
+                self.expression_is_callable_hint(&assertion.object)
+            }
             Expression::Parenthesized(inner) => self.expression_is_callable_hint(&inner.expression),
             _ => false,
         }
@@ -267,8 +267,30 @@ impl<'a> Lowerer<'a> {
             Statement::TypeAliasDecl(_) => {
                 // Type-only, no runtime code
             }
-            Statement::ImportDecl(_) => {
-                // Handled at module level
+            Statement::ImportDecl(import) => {
+                // Bindings are handled at module level. Constants inlined at link
+                // time have no import entry, so materialize them into their
+                // reserved slots for re-exports and namespace hydration.
+                for specifier in &import.specifiers {
+                    let local_name = match specifier {
+                        ast::ImportSpecifier::Named { name, alias } => {
+                            alias.as_ref().map_or(name.name, |a| a.name)
+                        }
+                        ast::ImportSpecifier::Default(local) => local.name,
+                        ast::ImportSpecifier::Namespace(_) => continue,
+                    };
+                    let Some(const_val) = self.constant_map.get(&local_name).cloned() else {
+                        continue;
+                    };
+                    if let Some(&global_idx) = self.module_var_globals.get(&local_name) {
+                        let value = self.emit_constant_value(&const_val);
+                        self.global_type_map.insert(global_idx, value.ty);
+                        self.emit(IrInstr::StoreGlobal {
+                            index: global_idx,
+                            value,
+                        });
+                    }
+                }
             }
             Statement::ExportDecl(export) => {
                 match export {
@@ -2556,10 +2578,37 @@ impl<'a> Lowerer<'a> {
     }
 
     fn lower_expr_stmt(&mut self, stmt: &ast::ExpressionStatement) {
+        // With assertions stripped, neither the condition nor the message is evaluated
+        if self.strip_assertions && self.is_builtin_assert_call(&stmt.expression) {
+            return;
+        }
         // Evaluate expression for side effects, discard result
         self.lower_expr(&stmt.expression);
     }
 
+    /// Whether `expr` calls the ambient builtin `assert` rather than a
+    /// user binding of the same name.
+    fn is_builtin_assert_call(&self, expr: &ast::Expression) -> bool {
+        let ast::Expression::Call(call) = expr else {
+            return false;
+        };
+        let ast::Expression::Identifier(ident) = call.callee.as_ref() else {
+            return false;
+        };
+        let name = ident.name;
+        self.interner.resolve(name) == "assert"
+            && self.ambient_builtin_globals.contains("assert")
+            && !self.local_map.contains_key(&name)
+            && !self
+                .ancestor_variables
+                .as_ref()
+                .is_some_and(|vars| vars.contains_key(&name))
+            && !self.function_map.contains_key(&name)
+            && !self.module_var_globals.contains_key(&name)
+            && !self.import_bindings.contains(&name)
+            && !self.class_map.contains_key(&name)
+    }
+
     fn lower_nested_function_decl(&mut self, func_decl: &ast::FunctionDecl) {
         use crate::parser::ast::{ArrowBody, ArrowFunction};
         use crate::parser::token::Span;
@@ -2646,18 +2695,9 @@ impl<'a> Lowerer<'a> {
         let value = ret.value.as_ref().map(|e| self.lower_expr(e));
 
         // Inline finally blocks from innermost to outermost.
-        // Drain the stack to prevent recursive re-inlining: if a finally block
-        // itself contains a return, that nested lower_return sees an empty stack.
-        let entries: Vec<super::TryFinallyEntry> = self.try_finally_stack.drain(..).rev().collect();
-        for entry in &entries {
-            if entry.in_try_body {
-                self.emit(IrInstr::EndTry);
-            }
-            self.lower_block(&entry.finally_body);
-            if self.current_block_is_terminated() {
-                // Finally body contained its own return/throw — it takes precedence
-                return;
-            }
+        if !self.inline_finally_bodies(0) {
+            // Finally body contained its own return/throw — it takes precedence
+            return;
         }
 
         self.set_terminator(Terminator::Return(value));
@@ -3041,19 +3081,9 @@ impl<'a> Lowerer<'a> {
                 .find(|ctx| ctx.label == Some(label_sym))
                 .cloned()
             {
-                let depth = loop_ctx.try_finally_depth;
-                let entries: Vec<super::TryFinallyEntry> =
-                    self.try_finally_stack.drain(depth..).rev().collect();
-                for entry in &entries {
-                    if entry.in_try_body {
-                        self.emit(IrInstr::EndTry);
-                    }
-                    self.lower_block(&entry.finally_body);
-                    if self.current_block_is_terminated() {
-                        return;
-                    }
+                if self.inline_finally_bodies(loop_ctx.try_finally_depth) {
+                    self.set_terminator(Terminator::Jump(loop_ctx.break_target));
                 }
-                self.set_terminator(Terminator::Jump(loop_ctx.break_target));
                 return;
             }
         }
@@ -3064,19 +3094,9 @@ impl<'a> Lowerer<'a> {
             return;
         }
         if let Some(loop_ctx) = self.loop_stack.last().cloned() {
-            let depth = loop_ctx.try_finally_depth;
-            let entries: Vec<super::TryFinallyEntry> =
-                self.try_finally_stack.drain(depth..).rev().collect();
-            for entry in &entries {
-                if entry.in_try_body {
-                    self.emit(IrInstr::EndTry);
-                }
-                self.lower_block(&entry.finally_body);
-                if self.current_block_is_terminated() {
-                    return;
-                }
+            if self.inline_finally_bodies(loop_ctx.try_finally_depth) {
+                self.set_terminator(Terminator::Jump(loop_ctx.break_target));
             }
-            self.set_terminator(Terminator::Jump(loop_ctx.break_target));
         } else {
             self.set_terminator(Terminator::Unreachable);
         }
@@ -3096,24 +3116,40 @@ impl<'a> Lowerer<'a> {
         };
 
         if let Some(loop_ctx) = loop_ctx {
-            let depth = loop_ctx.try_finally_depth;
-            let entries: Vec<super::TryFinallyEntry> =
-                self.try_finally_stack.drain(depth..).rev().collect();
-            for entry in &entries {
-                if entry.in_try_body {
-                    self.emit(IrInstr::EndTry);
-                }
-                self.lower_block(&entry.finally_body);
-                if self.current_block_is_terminated() {
-                    return;
-                }
+            if self.inline_finally_bodies(loop_ctx.try_finally_depth) {
+                self.set_terminator(Terminator::Jump(loop_ctx.continue_target));
             }
-            self.set_terminator(Terminator::Jump(loop_ctx.continue_target));
         } else {
             self.set_terminator(Terminator::Unreachable);
         }
     }
 
+    /// Inline the finally bodies of the try statements above `depth`, innermost
+    /// first, ahead of a jump out of them.
+    ///
+    /// The entries are hidden while their bodies are lowered, so a `return` inside
+    /// a finally body does not inline it again, and restored afterwards so later
+    /// exits from the same try body inline them too. Returns false if a finally
+    /// body terminated the block itself.
+    fn inline_finally_bodies(&mut self, depth: usize) -> bool {
+        let saved = self.try_finally_stack.clone();
+        let entries: Vec<super::TryFinallyEntry> =
+            self.try_finally_stack.drain(depth..).rev().collect();
+        let mut completed = true;
+        for entry in &entries {
+            if entry.in_try_body {
+                self.emit(IrInstr::EndTry);
+            }
+            self.lower_block(&entry.finally_body);
+            if self.current_block_is_terminated() {
+                completed = false;
+                break;
+            }
+        }
+        self.try_finally_stack = saved;
+        completed
+    }
+
     fn lower_throw(&mut self, throw: &ast::ThrowStatement) {
         let value = self.lower_expr(&throw.value);
         self.set_terminator(Terminator::Throw(value));
@@ -3302,7 +3338,8 @@ impl<'a> Lowerer<'a> {
             }
         }
 
-        // Lower case bodies with fall-through support
+        // Lower case bodies with fall-through support. Guarded cases only fall
+        // through on request (or when empty).
         for (i, case) in switch.cases.iter().enumerate() {
             let case_block = case_blocks[i];
             self.current_function_mut()
@@ -3312,8 +3349,10 @@ impl<'a> Lowerer<'a> {
                 self.lower_stmt(stmt);
             }
             if !self.current_block_is_terminated() {
-                // No break: fall through to next case body, or exit if last case
-                let target = case_blocks.get(i + 1).copied().unwrap_or(exit_block);
+                let target = match case_blocks.get(i + 1) {
+                    Some(&next) if case.falls_through() => next,
+                    _ => exit_block,
+                };
                 self.set_terminator(Terminator::Jump(target));
             }
         }
@@ -3322,7 +3361,10 @@ impl<'a> Lowerer<'a> {
 
         let default = default_block.unwrap_or(exit_block);
 
-        if !string_cases.is_empty() {
+        if switch.cases.iter().any(|case| case.guard.is_some()) {
+            self.current_block = entry_block;
+            self.lower_guarded_switch_dispatch(switch, &discriminant, &case_blocks, default);
+        } else if !string_cases.is_empty() {
             // String switch: emit if-else chain of string equality comparisons
             self.current_block = entry_block;
             for (string_val, target_block) in &string_cases {
@@ -3366,6 +3408,68 @@ impl<'a> Lowerer<'a> {
             .add_block(crate::ir::BasicBlock::new(exit_block));
         self.current_block = exit_block;
     }
+
+    /// Dispatch a switch with `when` guards: test each case in source order,
+    /// evaluating a case's guard only once its test has matched.
+    fn lower_guarded_switch_dispatch(
+        &mut self,
+        switch: &ast::SwitchStatement,
+        discriminant: &Register,
+        case_blocks: &[BasicBlockId],
+        default: BasicBlockId,
+    ) {
+        for (case, &case_block) in switch.cases.iter().zip(case_blocks) {
+            let Some(test) = &case.test else {
+                continue;
+            };
+            let test_reg = self.lower_expr(test);
+            let eq_reg = self.alloc_register(TypeId::new(super::BOOLEAN_TYPE_ID));
+            if matches!(test, ast::Expression::StringLiteral(_)) {
+                self.emit(IrInstr::StringCompare {
+                    dest: eq_reg.clone(),
+                    left: discriminant.clone(),
+                    right: test_reg,
+                    mode: StringCompareMode::Full,
+                    negate: false,
+                });
+            } else {
+                self.emit(IrInstr::BinaryOp {
+                    dest: eq_reg.clone(),
+                    op: BinaryOp::Equal,
+                    left: discriminant.clone(),
+                    right: test_reg,
+                });
+            }
+
+            let next_check = self.alloc_block();
+            let matched = match case.guard {
+                Some(_) => self.alloc_block(),
+                None => case_block,
+            };
+            self.set_terminator(Terminator::Branch {
+                cond: eq_reg,
+                then_block: matched,
+                else_block: next_check,
+            });
+
+            if let Some(guard) = &case.guard {
+                self.current_function_mut()
+                    .add_block(crate::ir::BasicBlock::new(matched));
+                self.current_block = matched;
+                let guard_reg = self.lower_expr(guard);
+                self.set_terminator(Terminator::Branch {
+                    cond: guard_reg,
+                    then_block: case_block,
+                    else_block: next_check,
+                });
+            }
+
+            self.current_function_mut()
+                .add_block(crate::ir::BasicBlock::new(next_check));
+            self.current_block = next_check;
+        }
+        self.set_terminator(Terminator::Jump(default));
+    }
 }
 
 #[cfg(test)]
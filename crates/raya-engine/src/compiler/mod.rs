@@ -25,6 +25,7 @@ pub mod module;
 pub mod module_builder;
 #[allow(dead_code)]
 pub mod monomorphize;
+pub mod native_check;
 pub mod native_id;
 #[allow(dead_code)]
 pub mod optimize;
@@ -49,6 +50,7 @@ use crate::parser::Interner;
 use crate::parser::TypeContext;
 use crate::parser::TypeId;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::cell::RefCell;
 use std::collections::HashSet;
 
 /// Monomorphization strategy for compilation.
@@ -81,12 +83,24 @@ pub struct Compiler<'a> {
     emit_generic_templates: bool,
     /// Monomorphization mode for IR pipeline.
     monomorphization_mode: MonomorphizationMode,
+    /// Maximum number of distinct specializations per generic function.
+    instantiation_budget: usize,
     /// Stable module identity used for metadata and symbol ID derivation.
     module_identity: Option<String>,
     /// Original source text for debug dumps (enables source-annotated IR/bytecode output)
     source_text: Option<String>,
     /// Ambient builtin globals available without explicit source declarations/imports.
     ambient_builtin_globals: FxHashSet<String>,
+    /// Drop `assert(...)` statements during lowering.
+    strip_assertions: bool,
+    /// Link-time values of imported constants, keyed by import-local name.
+    imported_constants: FxHashMap<String, lower::ConstantValue>,
+    /// Native function names the target VM registers (None = names unchecked).
+    known_natives: Option<FxHashSet<String>>,
+    /// Non-fatal diagnostics collected during compilation (see `take_warnings`).
+    warnings: RefCell<Vec<String>>,
+    /// Previously lowered functions to reuse (requires `source_text`).
+    lowering_cache: RefCell<Option<lower::LoweringCache>>,
 }
 
 impl<'a> Compiler<'a> {
@@ -102,12 +116,24 @@ impl<'a> Compiler<'a> {
             allow_unresolved_runtime_fallback: true,
             emit_generic_templates: false,
             monomorphization_mode: MonomorphizationMode::ConsumerLink,
+            instantiation_budget: monomorphize::DEFAULT_INSTANTIATION_BUDGET,
             module_identity: None,
             source_text: None,
             ambient_builtin_globals: FxHashSet::default(),
+            strip_assertions: false,
+            imported_constants: FxHashMap::default(),
+            known_natives: None,
+            warnings: RefCell::new(Vec::new()),
+            lowering_cache: RefCell::new(None),
         }
     }
 
+    /// Drain the non-fatal warnings produced by the last compilation
+    /// (e.g. exhausted monomorphization budgets), leaving the caller to report them.
+    pub fn take_warnings(&self) -> Vec<String> {
+        std::mem::take(&mut *self.warnings.borrow_mut())
+    }
+
     /// Attach original source text for annotated debug dumps.
     ///
     /// When set and `RAYA_DEBUG_DUMP_IR` or `RAYA_DEBUG_DUMP_BYTECODE` are active,
@@ -171,6 +197,14 @@ impl<'a> Compiler<'a> {
         self
     }
 
+    /// Limit how many distinct specializations a single generic function may get.
+    ///
+    /// Instantiations past the budget share one type-erased specialization.
+    pub fn with_instantiation_budget(mut self, budget: usize) -> Self {
+        self.instantiation_budget = budget;
+        self
+    }
+
     /// Set stable module identity used in output metadata and symbol ID derivation.
     pub fn with_module_identity(mut self, module_identity: impl Into<String>) -> Self {
         self.module_identity = Some(module_identity.into());
@@ -187,6 +221,44 @@ impl<'a> Compiler<'a> {
         self
     }
 
+    /// Compile calls to the builtin `assert` to nothing (`--no-assertions`).
+    pub fn with_strip_assertions(mut self, enable: bool) -> Self {
+        self.strip_assertions = enable;
+        self
+    }
+
+    /// Inline imported constants whose values were resolved at link time.
+    pub fn with_imported_constants(
+        mut self,
+        constants: FxHashMap<String, lower::ConstantValue>,
+    ) -> Self {
+        self.imported_constants = constants;
+        self
+    }
+
+    /// Reject `__NATIVE_CALL("name", ...)` targets outside these registered names.
+    pub fn with_known_natives<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.known_natives = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Reuse the lowered IR of top-level functions unchanged since `cache` was
+    /// filled. Needs the module's source text (`with_source_text`); without it
+    /// every function is lowered and the cache is handed back untouched.
+    pub fn with_lowering_cache(self, cache: lower::LoweringCache) -> Self {
+        *self.lowering_cache.borrow_mut() = Some(cache);
+        self
+    }
+
+    /// Take the lowering cache back, updated by the last compilation.
+    pub fn take_lowering_cache(&self) -> Option<lower::LoweringCache> {
+        self.lowering_cache.borrow_mut().take()
+    }
+
     /// Compile a module into bytecode
     pub fn compile(&mut self, module: &ast::Module) -> CompileResult<Module> {
         let mut codegen = CodeGenerator::new(&self.type_ctx, self.interner);
@@ -201,6 +273,8 @@ impl<'a> Compiler<'a> {
                 .with_sourcemap(self.emit_sourcemap)
                 .with_js_this_binding_compat(self.js_this_binding_compat)
                 .with_unresolved_runtime_fallback(self.allow_unresolved_runtime_fallback)
+                .with_strip_assertions(self.strip_assertions)
+                .with_imported_constants(self.imported_constants.clone())
                 .with_ambient_builtin_globals(self.ambient_builtin_globals.clone());
         if let Some(ref jsx_opts) = self.jsx_options {
             lowerer = lowerer.with_jsx(jsx_opts.clone());
@@ -239,11 +313,21 @@ impl<'a> Compiler<'a> {
                 .with_sourcemap(need_sourcemap)
                 .with_js_this_binding_compat(self.js_this_binding_compat)
                 .with_unresolved_runtime_fallback(self.allow_unresolved_runtime_fallback)
+                .with_strip_assertions(self.strip_assertions)
+                .with_imported_constants(self.imported_constants.clone())
                 .with_ambient_builtin_globals(self.ambient_builtin_globals.clone());
         if let Some(ref jsx_opts) = self.jsx_options {
             lowerer = lowerer.with_jsx(jsx_opts.clone());
         }
+        if let Some(source) = self.source_text.as_deref() {
+            if let Some(cache) = self.lowering_cache.borrow_mut().take() {
+                lowerer = lowerer.with_lowering_cache(cache, source);
+            }
+        }
         let mut ir_module = lowerer.lower_module(module);
+        if let Some(cache) = lowerer.take_lowering_cache() {
+            *self.lowering_cache.borrow_mut() = Some(cache);
+        }
         if let Some(module_identity) = &self.module_identity {
             ir_module.name = module_identity.clone();
         }
@@ -267,14 +351,21 @@ impl<'a> Compiler<'a> {
                 message: format!("{}", err),
             });
         }
+        validate_lowered_ir(&ir_module)?;
+        native_check::verify_native_calls(&ir_module, self.known_natives.as_ref())?;
 
         // Step 2: Monomorphization
         if matches!(
             self.monomorphization_mode,
             MonomorphizationMode::ConsumerLink
         ) {
-            let _mono_result =
-                monomorphize::monomorphize(&mut ir_module, &self.type_ctx, self.interner);
+            let mono_result = monomorphize::monomorphize(
+                &mut ir_module,
+                &self.type_ctx,
+                self.interner,
+                self.instantiation_budget,
+            );
+            self.warnings.borrow_mut().extend(mono_result.warnings);
         }
 
         // Step 2b: Resolve late-bound member accesses (TypeVar → concrete type dispatch)
@@ -307,7 +398,7 @@ impl<'a> Compiler<'a> {
         }
 
         // Step 3: Optimization passes
-        let optimizer = optimize::Optimizer::basic();
+        let optimizer = optimize::Optimizer::basic().with_print_ir_after_env();
         optimizer.optimize(&mut ir_module);
 
         // Dump annotated IR to stderr when RAYA_DEBUG_DUMP_IR is set
@@ -398,6 +489,8 @@ impl<'a> Compiler<'a> {
                 .with_sourcemap(self.emit_sourcemap)
                 .with_js_this_binding_compat(self.js_this_binding_compat)
                 .with_unresolved_runtime_fallback(self.allow_unresolved_runtime_fallback)
+                .with_strip_assertions(self.strip_assertions)
+                .with_imported_constants(self.imported_constants.clone())
                 .with_ambient_builtin_globals(self.ambient_builtin_globals.clone());
         if let Some(ref jsx_opts) = self.jsx_options {
             lowerer = lowerer.with_jsx(jsx_opts.clone());
@@ -413,6 +506,8 @@ impl<'a> Compiler<'a> {
                 message: format!("{}", err),
             });
         }
+        validate_lowered_ir(&ir_module)?;
+        native_check::verify_native_calls(&ir_module, self.known_natives.as_ref())?;
 
         writeln!(debug, "=== IR Before Optimization ===").unwrap();
         writeln!(debug, "{}", ir_module.pretty_print()).unwrap();
@@ -422,13 +517,14 @@ impl<'a> Compiler<'a> {
             self.monomorphization_mode,
             MonomorphizationMode::ConsumerLink
         ) {
-            monomorphize::monomorphize(&mut ir_module, &self.type_ctx, self.interner)
+            monomorphize::monomorphize(
+                &mut ir_module,
+                &self.type_ctx,
+                self.interner,
+                self.instantiation_budget,
+            )
         } else {
-            monomorphize::MonomorphizationResult {
-                functions_specialized: 0,
-                classes_specialized: 0,
-                call_sites_rewritten: 0,
-            }
+            monomorphize::MonomorphizationResult::default()
         };
         writeln!(debug, "=== Monomorphization Stats ===").unwrap();
         writeln!(
@@ -443,13 +539,22 @@ impl<'a> Compiler<'a> {
             mono_result.classes_specialized
         )
         .unwrap();
+        writeln!(
+            debug,
+            "Type-erased fallbacks: {}",
+            mono_result.functions_erased
+        )
+        .unwrap();
+        for warning in &mono_result.warnings {
+            writeln!(debug, "Warning: {}", warning).unwrap();
+        }
 
         // Step 2b: Resolve late-bound member accesses (TypeVar → concrete type dispatch)
         let type_registry = type_registry::TypeRegistry::new(&self.type_ctx);
         monomorphize::resolve_late_bound_members(&mut ir_module, &type_registry, &self.type_ctx);
 
         // Step 3: Optimization passes
-        let optimizer = optimize::Optimizer::basic();
+        let optimizer = optimize::Optimizer::basic().with_print_ir_after_env();
         optimizer.optimize(&mut ir_module);
 
         writeln!(debug, "\n=== IR After Optimization ===").unwrap();
@@ -487,6 +592,23 @@ impl<'a> Compiler<'a> {
     }
 }
 
+/// Validate freshly lowered IR in debug builds.
+///
+/// Malformed IR is a lowering bug; reporting it here names the function and
+/// block instead of failing obscurely during codegen.
+fn validate_lowered_ir(ir_module: &ir::IrModule) -> CompileResult<()> {
+    if !cfg!(debug_assertions) {
+        return Ok(());
+    }
+    ir::validate(ir_module).map_err(|errors| CompileError::InvalidIr {
+        message: errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; "),
+    })
+}
+
 #[derive(Debug, Clone)]
 struct ExportBinding {
     exported_name: String,
@@ -785,77 +907,116 @@ fn populate_symbol_link_metadata(
 
 /// Disassemble a function's bytecode into human-readable form
 pub fn disassemble_function(func: &Function) -> String {
+    let mut output = String::new();
+    let mut offset = 0;
+
+    while offset < func.code.len() {
+        offset = write_disassembled_instruction(&mut output, &func.code, offset);
+    }
+
+    output
+}
+
+/// Disassemble a function's bytecode, interleaving the source it came from.
+///
+/// Like `objdump -S`: each source line is printed as a `; L{line} | {text}`
+/// comment before the first instruction the line table maps to it. Without
+/// debug info the output matches [`disassemble_function`].
+pub fn disassemble_function_with_source(
+    func: &Function,
+    debug_info: Option<&bytecode::FunctionDebugInfo>,
+    source: &str,
+) -> String {
     use std::fmt::Write;
 
+    let source_lines: Vec<&str> = source.lines().collect();
+    let mut printed = vec![false; source_lines.len()];
     let mut output = String::new();
-    let code = &func.code;
     let mut offset = 0;
 
-    while offset < code.len() {
-        let opcode_byte = code[offset];
-        if let Some(opcode) = Opcode::from_u8(opcode_byte) {
-            write!(output, "    {:04x}: {:?}", offset, opcode).unwrap();
-            offset += 1;
-
-            // Read operands based on opcode
-            let operand_size = codegen::emit::opcode_size(opcode) - 1;
-            if operand_size > 0 && offset + operand_size <= code.len() {
-                match operand_size {
-                    2 => {
-                        let val = u16::from_le_bytes([code[offset], code[offset + 1]]);
-                        write!(output, " {}", val).unwrap();
-                    }
-                    4 => {
-                        let val = i32::from_le_bytes([
-                            code[offset],
-                            code[offset + 1],
-                            code[offset + 2],
-                            code[offset + 3],
-                        ]);
-                        write!(output, " {}", val).unwrap();
-                    }
-                    6 => {
-                        let val1 = u32::from_le_bytes([
-                            code[offset],
-                            code[offset + 1],
-                            code[offset + 2],
-                            code[offset + 3],
-                        ]);
-                        let val2 = u16::from_le_bytes([code[offset + 4], code[offset + 5]]);
-                        write!(output, " {} {}", val1, val2).unwrap();
-                    }
-                    8 => {
-                        let val = f64::from_le_bytes([
-                            code[offset],
-                            code[offset + 1],
-                            code[offset + 2],
-                            code[offset + 3],
-                            code[offset + 4],
-                            code[offset + 5],
-                            code[offset + 6],
-                            code[offset + 7],
-                        ]);
-                        write!(output, " {}", val).unwrap();
-                    }
-                    _ => {}
-                }
-                offset += operand_size;
-            }
-            writeln!(output).unwrap();
-        } else {
+    while offset < func.code.len() {
+        let line = debug_info
+            .and_then(|dbg| dbg.lookup_location(offset as u32))
+            .map_or(0, |entry| entry.line as usize);
+        if line > 0 && line <= source_lines.len() && !printed[line - 1] {
+            printed[line - 1] = true;
             writeln!(
                 output,
-                "    {:04x}: <invalid opcode {:#x}>",
-                offset, opcode_byte
+                "    ; L{} | {}",
+                line,
+                source_lines[line - 1].trim()
             )
             .unwrap();
-            offset += 1;
         }
+        offset = write_disassembled_instruction(&mut output, &func.code, offset);
     }
 
     output
 }
 
+/// Operands of one decoded instruction
+enum Operands {
+    None,
+    U8(u8),
+    U16(u16),
+    I32(i32),
+    U32U16(u32, u16),
+    F64(f64),
+}
+
+/// Decode the instruction at `offset` into its opcode (`None` for an invalid
+/// byte) and operands, and return the offset of the next instruction.
+fn decode_instruction(code: &[u8], offset: usize) -> (Option<Opcode>, Operands, usize) {
+    let Some(opcode) = Opcode::from_u8(code[offset]) else {
+        return (None, Operands::None, offset + 1);
+    };
+    let start = offset + 1;
+    let operand_size = codegen::emit::opcode_size(opcode) - 1;
+    let Some(bytes) = code.get(start..start + operand_size) else {
+        return (Some(opcode), Operands::None, start);
+    };
+    let operands = match operand_size {
+        1 => Operands::U8(bytes[0]),
+        2 => Operands::U16(u16::from_le_bytes([bytes[0], bytes[1]])),
+        4 => Operands::I32(i32::from_le_bytes(bytes.try_into().unwrap())),
+        6 => Operands::U32U16(
+            u32::from_le_bytes(bytes[..4].try_into().unwrap()),
+            u16::from_le_bytes([bytes[4], bytes[5]]),
+        ),
+        8 => Operands::F64(f64::from_le_bytes(bytes.try_into().unwrap())),
+        _ => Operands::None,
+    };
+    (Some(opcode), operands, start + operand_size)
+}
+
+/// Write the instruction at `offset` as one listing line and return the
+/// offset of the next instruction.
+fn write_disassembled_instruction(output: &mut String, code: &[u8], offset: usize) -> usize {
+    use std::fmt::Write;
+
+    let (opcode, operands, next) = decode_instruction(code, offset);
+    let Some(opcode) = opcode else {
+        writeln!(
+            output,
+            "    {:04x}: <invalid opcode {:#x}>",
+            offset, code[offset]
+        )
+        .unwrap();
+        return next;
+    };
+    write!(output, "    {:04x}: {:?}", offset, opcode).unwrap();
+    match operands {
+        Operands::None => {}
+        Operands::U8(val) => write!(output, " {}", val).unwrap(),
+        Operands::U16(val) => write!(output, " {}", val).unwrap(),
+        Operands::I32(val) => write!(output, " {}", val).unwrap(),
+        Operands::U32U16(val1, val2) => write!(output, " {} {}", val1, val2).unwrap(),
+        Operands::F64(val) => write!(output, " {}", val).unwrap(),
+    }
+    writeln!(output).unwrap();
+    next
+}
+
 /// Disassemble a function's bytecode with source-line comments.
 ///
 /// Each opcode line is followed by `; L{line}:{col} | {source_snippet}` when
@@ -872,63 +1033,24 @@ pub fn disassemble_function_annotated(
     let mut offset = 0;
 
     while offset < code.len() {
-        let opcode_byte = code[offset];
-        if let Some(opcode) = Opcode::from_u8(opcode_byte) {
+        let instr_start_offset = offset;
+        let (opcode, operands, next) = decode_instruction(code, offset);
+        offset = next;
+        if let Some(opcode) = opcode {
             write!(
                 output,
                 "    {:04x}: {:<22}",
-                offset,
+                instr_start_offset,
                 format!("{:?}", opcode)
             )
             .unwrap();
-            let instr_start_offset = offset;
-            offset += 1;
-
-            let operand_size = codegen::emit::opcode_size(opcode) - 1;
-            if operand_size > 0 && offset + operand_size <= code.len() {
-                match operand_size {
-                    1 => {
-                        write!(output, " {:3}", code[offset]).unwrap();
-                    }
-                    2 => {
-                        let val = u16::from_le_bytes([code[offset], code[offset + 1]]);
-                        write!(output, " {:5}", val).unwrap();
-                    }
-                    4 => {
-                        let val = i32::from_le_bytes([
-                            code[offset],
-                            code[offset + 1],
-                            code[offset + 2],
-                            code[offset + 3],
-                        ]);
-                        write!(output, " {:10}", val).unwrap();
-                    }
-                    6 => {
-                        let val1 = u32::from_le_bytes([
-                            code[offset],
-                            code[offset + 1],
-                            code[offset + 2],
-                            code[offset + 3],
-                        ]);
-                        let val2 = u16::from_le_bytes([code[offset + 4], code[offset + 5]]);
-                        write!(output, " {} {}", val1, val2).unwrap();
-                    }
-                    8 => {
-                        let val = f64::from_le_bytes([
-                            code[offset],
-                            code[offset + 1],
-                            code[offset + 2],
-                            code[offset + 3],
-                            code[offset + 4],
-                            code[offset + 5],
-                            code[offset + 6],
-                            code[offset + 7],
-                        ]);
-                        write!(output, " {:.6}", val).unwrap();
-                    }
-                    _ => {}
-                }
-                offset += operand_size;
+            match operands {
+                Operands::None => {}
+                Operands::U8(val) => write!(output, " {:3}", val).unwrap(),
+                Operands::U16(val) => write!(output, " {:5}", val).unwrap(),
+                Operands::I32(val) => write!(output, " {:10}", val).unwrap(),
+                Operands::U32U16(val1, val2) => write!(output, " {} {}", val1, val2).unwrap(),
+                Operands::F64(val) => write!(output, " {:.6}", val).unwrap(),
             }
 
             // Append source annotation if debug info is available
@@ -957,10 +1079,9 @@ pub fn disassemble_function_annotated(
             writeln!(
                 output,
                 "    {:04x}: <invalid opcode {:#x}>",
-                offset, opcode_byte
+                instr_start_offset, code[instr_start_offset]
             )
             .unwrap();
-            offset += 1;
         }
     }
 
@@ -1295,4 +1416,106 @@ mod tests {
             "expected strict no-fallback diagnostic, got: {msg}"
         );
     }
+
+    #[test]
+    fn test_compiled_module_shares_repeated_string_constants() {
+        let source = "\
+function greet(): string { return \"hello\"; }
+function shout(): string { return \"hello\" + \"!\"; }
+let greeting = \"hello\";
+return greet() + shout() + greeting + \"!\";
+";
+        let parser = Parser::new(source).expect("lexer failure");
+        let (ast, interner) = parser.parse().expect("parse failure");
+        let module = Compiler::new(TypeContext::new(), &interner)
+            .compile_via_ir(&ast)
+            .expect("compile_via_ir should succeed");
+
+        let strings = &module.constants.strings;
+        let count = |s: &str| strings.iter().filter(|entry| *entry == s).count();
+        assert_eq!(count("hello"), 1, "{strings:?}");
+        assert_eq!(count("!"), 1, "{strings:?}");
+        let unique: std::collections::HashSet<_> = strings.iter().collect();
+        assert_eq!(unique.len(), strings.len(), "{strings:?}");
+    }
+
+    #[test]
+    fn test_module_builder_rejects_strings_past_capacity_without_adding_them() {
+        let mut builder = ModuleBuilder::new("full".to_string());
+        for i in 0..65535 {
+            builder.add_string(i.to_string()).expect("within capacity");
+        }
+        assert!(matches!(
+            builder.add_string("overflow".to_string()),
+            Err(CompileError::TooManyConstants)
+        ));
+        // Existing entries are still found once the pool is full
+        assert_eq!(builder.add_string("7".to_string()).ok(), Some(7));
+
+        let module = builder.build();
+        assert_eq!(module.constants.strings.len(), 65535);
+        assert_eq!(module.constants.find_string("overflow"), None);
+    }
+
+    #[test]
+    fn test_disassemble_function_with_source_interleaves_lines() {
+        let source = "\
+function scale(a: number, b: number): number {
+    let sum = a + b;
+    return sum * 2;
+}
+return scale(1, 2);
+";
+        let parser = Parser::new(source).expect("lexer failure");
+        let (ast, interner) = parser.parse().expect("parse failure");
+        let module = Compiler::new(TypeContext::new(), &interner)
+            .with_sourcemap(true)
+            .compile_via_ir(&ast)
+            .expect("compile_via_ir should succeed");
+
+        let index = module
+            .functions
+            .iter()
+            .position(|func| func.name == "scale")
+            .expect("scale function");
+        let debug_info = module
+            .debug_info
+            .as_ref()
+            .and_then(|dbg| dbg.functions.get(index))
+            .expect("scale debug info");
+        let listing =
+            disassemble_function_with_source(&module.functions[index], Some(debug_info), source);
+
+        assert!(listing.contains("; L2 | let sum = a + b;"), "{listing}");
+        assert!(listing.contains("; L3 | return sum * 2;"), "{listing}");
+        assert!(listing.find("; L2 |") < listing.find("; L3 |"), "{listing}");
+
+        // Each source comment sits right before the first instruction mapped to its line
+        let lines: Vec<&str> = listing.lines().collect();
+        for (i, text) in lines.iter().enumerate() {
+            let Some(rest) = text.trim().strip_prefix("; L") else {
+                continue;
+            };
+            let line: u32 = rest.split(' ').next().unwrap().parse().unwrap();
+            let next = lines[i + 1].trim();
+            let offset = u32::from_str_radix(&next[..4], 16).expect("instruction offset");
+            let entry = debug_info.lookup_location(offset).expect("mapped offset");
+            assert_eq!(entry.line, line, "{listing}");
+            let earlier = (0..offset).any(|o| {
+                debug_info
+                    .lookup_location(o)
+                    .is_some_and(|e| e.line == line)
+            });
+            assert!(
+                !earlier,
+                "L{line} printed after its first instruction\n{listing}"
+            );
+        }
+
+        // Without debug info the listing is the plain disassembly
+        assert_eq!(
+            disassemble_function_with_source(&module.functions[index], None, source),
+            disassemble_function(&module.functions[index])
+        );
+    }
 }
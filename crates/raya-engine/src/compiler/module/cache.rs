@@ -207,6 +207,7 @@ mod tests {
             },
             native_functions: vec![],
             jit_hints: vec![],
+            json_schemas: vec![],
             reflection: None,
             debug_info: None,
         }
@@ -11,12 +11,13 @@ use thiserror::Error;
 use crate::compiler::bytecode::{
     Function as BytecodeFunction, Module as BytecodeModule, NominalTypeExport, Opcode,
 };
+use crate::compiler::lower::{strip_disabled_features, ConstantValue, LoweringCache};
 use crate::compiler::{
     module_id_from_name, symbol_id_from_name, CompileError, Compiler, Export, Import, SymbolScope,
     SymbolType,
 };
 use crate::parser::ast::{
-    ExportDecl, Expression, ImportSpecifier, Module as AstModule, Pattern, Statement,
+    ExportDecl, Expression, ImportSpecifier, Module as AstModule, Pattern, Statement, VariableKind,
 };
 use crate::parser::checker::{
     Binder, CheckerPolicy, ScopeId, ScopeKind, Symbol, SymbolFlags, SymbolKind, TypeChecker,
@@ -27,8 +28,8 @@ use crate::parser::{Interner, Parser, Span, TypeContext};
 use super::cache::ModuleCache;
 use super::declaration::{
     builtin_global_exports, declaration_runtime_identity_path, load_declaration_module,
-    specialization_template_from_symbol, BuiltinSurfaceMode, DeclarationError, DeclarationModule,
-    DeclarationSourceKind, LateLinkRequirement, LateLinkSymbolRequirement,
+    specialization_template_from_symbol, type_alias_exports, BuiltinSurfaceMode, DeclarationError,
+    DeclarationModule, DeclarationSourceKind, LateLinkRequirement, LateLinkSymbolRequirement,
 };
 use super::exports::{ExportRegistry, ExportedSymbol, ModuleExports};
 use super::graph::{GraphError, ModuleGraph};
@@ -85,6 +86,8 @@ pub struct CompiledModule {
     pub imports: Vec<PathBuf>,
     /// Whether this module is a declaration-only placeholder.
     pub declaration_only: bool,
+    /// Non-fatal compiler warnings for this module, for the caller to report.
+    pub warnings: Vec<String>,
 }
 
 /// Multi-module compiler
@@ -125,6 +128,25 @@ pub struct ModuleCompiler {
     builtin_surface_mode: BuiltinSurfaceMode,
     /// Cached builtin global exports for the configured surface mode.
     builtin_globals: Option<ModuleExports>,
+    /// Features enabled for `//@@if(feature)` conditional compilation.
+    features: Vec<String>,
+    /// Compile calls to the builtin `assert` to nothing.
+    strip_assertions: bool,
+    /// Emit a source map (bytecode offset → source location) into each module.
+    sourcemap: bool,
+    /// Host-provided globals (name, type signature) predeclared in the entry module.
+    host_globals: Vec<(String, String)>,
+    /// Also lower the entry module to textual optimized IR (see `entry_ir`).
+    emit_entry_ir: bool,
+    /// Optimized IR of the entry module, captured when `emit_entry_ir` is set.
+    entry_ir: Option<String>,
+    /// Lowered functions of the entry module to reuse, updated by each compile.
+    entry_lowering_cache: Option<LoweringCache>,
+    /// Registered native names; `__NATIVE_CALL("name")` targets outside it are rejected.
+    known_natives: Option<HashSet<String>>,
+    /// Exported type aliases of modules reached by a type-only import, seen
+    /// by importers compiled before the module itself.
+    forward_type_exports: HashMap<PathBuf, ModuleExports>,
 }
 
 impl ModuleCompiler {
@@ -216,6 +238,15 @@ impl ModuleCompiler {
             checker_policy: CheckerPolicy::for_mode(TypeSystemMode::Raya),
             builtin_surface_mode: BuiltinSurfaceMode::RayaStrict,
             builtin_globals: None,
+            features: Vec::new(),
+            strip_assertions: false,
+            sourcemap: false,
+            host_globals: Vec::new(),
+            emit_entry_ir: false,
+            entry_ir: None,
+            entry_lowering_cache: None,
+            known_natives: None,
+            forward_type_exports: HashMap::new(),
         }
     }
 
@@ -237,6 +268,15 @@ impl ModuleCompiler {
             checker_policy: CheckerPolicy::for_mode(TypeSystemMode::Raya),
             builtin_surface_mode: BuiltinSurfaceMode::RayaStrict,
             builtin_globals: None,
+            features: Vec::new(),
+            strip_assertions: false,
+            sourcemap: false,
+            host_globals: Vec::new(),
+            emit_entry_ir: false,
+            entry_ir: None,
+            entry_lowering_cache: None,
+            known_natives: None,
+            forward_type_exports: HashMap::new(),
         })
     }
 
@@ -259,6 +299,68 @@ impl ModuleCompiler {
         self
     }
 
+    /// Enable features for `//@@if(feature)` conditional compilation.
+    pub fn with_features(mut self, features: Vec<String>) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Compile calls to the builtin `assert` to nothing (`--no-assertions`).
+    pub fn with_strip_assertions(mut self, enable: bool) -> Self {
+        self.strip_assertions = enable;
+        self
+    }
+
+    /// Emit a source map into compiled modules (needed for coverage and
+    /// source-resolved profiles).
+    pub fn with_sourcemap(mut self, enable: bool) -> Self {
+        self.sourcemap = enable;
+        self
+    }
+
+    /// Capture the entry module's optimized IR while compiling the graph.
+    pub fn with_entry_ir(mut self, enable: bool) -> Self {
+        self.emit_entry_ir = enable;
+        self
+    }
+
+    /// Textual optimized IR of the entry module, if `with_entry_ir` was enabled.
+    pub fn entry_ir(&self) -> Option<&str> {
+        self.entry_ir.as_deref()
+    }
+
+    /// Reuse the entry module's top-level functions lowered into `cache` by an
+    /// earlier compile, where their source and types are unchanged.
+    pub fn with_entry_lowering_cache(mut self, cache: LoweringCache) -> Self {
+        self.entry_lowering_cache = Some(cache);
+        self
+    }
+
+    /// The entry module's lowering cache, updated by the last compile.
+    pub fn take_entry_lowering_cache(&mut self) -> Option<LoweringCache> {
+        self.entry_lowering_cache.take()
+    }
+
+    /// Reject `__NATIVE_CALL("name", ...)` targets outside these registered names.
+    pub fn with_known_natives<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.known_natives = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Predeclare host-provided globals in the entry module.
+    ///
+    /// Each entry is a name and the canonical type signature of its value
+    /// (e.g. `number`, `string`). References resolve at runtime through the
+    /// ambient global table, which the host fills before execution.
+    pub fn with_host_globals(mut self, globals: Vec<(String, String)>) -> Self {
+        self.host_globals = globals;
+        self
+    }
+
     /// Configure builtin declaration surface for global symbol seeding.
     pub fn with_builtin_surface_mode(mut self, mode: BuiltinSurfaceMode) -> Self {
         if self.builtin_surface_mode != mode {
@@ -585,6 +687,7 @@ impl ModuleCompiler {
                     bytecode,
                     imports: node.imports.clone(),
                     declaration_only: true,
+                    warnings: Vec::new(),
                 });
                 continue;
             }
@@ -598,13 +701,26 @@ impl ModuleCompiler {
                         bytecode: cached.bytecode.clone(),
                         imports: node.imports.clone(),
                         declaration_only: false,
+                        warnings: Vec::new(),
                     });
                     continue;
                 }
             }
 
+            // A type-only import may target a module later in the order
+            let type_only_imports = self.graph.get(&path).unwrap().type_only_imports.clone();
+            for dep in type_only_imports {
+                if !self.exports.has_module(&dep) {
+                    if let Some(forward) = self.forward_type_exports.get(&dep) {
+                        self.exports.register(forward.clone());
+                    }
+                }
+            }
+
             // Compile the module with cross-module symbol resolution
-            let (bytecode, mut module_exports) = self.compile_single_with_exports(&path)?;
+            let is_entry = path == entry_path;
+            let (bytecode, mut module_exports, warnings) =
+                self.compile_single_with_exports(&path, is_entry)?;
 
             // Record `export * from "..."` chains so import resolution can
             // follow re-exported symbols transitively through ExportRegistry.
@@ -632,6 +748,7 @@ impl ModuleCompiler {
                 bytecode,
                 imports: node.imports.clone(),
                 declaration_only: false,
+                warnings,
             });
         }
 
@@ -642,6 +759,9 @@ impl ModuleCompiler {
     fn discover_modules(&mut self, entry_path: &Path) -> ModuleCompileResult<()> {
         let mut to_visit = vec![entry_path.to_path_buf()];
         let mut visited = HashSet::new();
+        // Names imported along each edge; `None` once any import on it is
+        // not a plain named import.
+        let mut imported_names = HashMap::<(PathBuf, PathBuf), Option<HashSet<String>>>::new();
 
         while let Some(path) = to_visit.pop() {
             if visited.contains(&path) {
@@ -658,10 +778,17 @@ impl ModuleCompiler {
             let imports = self.extract_imports(&source, &path)?;
 
             // Resolve and add each import
-            for import_specifier in imports {
+            for (import_specifier, names) in imports {
                 if let Some(resolved_path) = self.resolve_import_path(&import_specifier, &path)? {
                     self.graph
                         .add_dependency(path.clone(), resolved_path.clone());
+                    let edge_names = imported_names
+                        .entry((path.clone(), resolved_path.clone()))
+                        .or_insert_with(|| Some(HashSet::new()));
+                    match (edge_names.as_mut(), names) {
+                        (Some(edge_names), Some(names)) => edge_names.extend(names),
+                        _ => *edge_names = None,
+                    }
                     if !visited.contains(&resolved_path) {
                         to_visit.push(resolved_path);
                     }
@@ -669,6 +796,46 @@ impl ModuleCompiler {
             }
         }
 
+        self.mark_type_only_imports(imported_names)
+    }
+
+    /// Mark import edges that only bring in type aliases of the target.
+    ///
+    /// These edges carry no initialization order, so the cycles they close
+    /// are allowed.
+    fn mark_type_only_imports(
+        &mut self,
+        imported_names: HashMap<(PathBuf, PathBuf), Option<HashSet<String>>>,
+    ) -> ModuleCompileResult<()> {
+        for ((from, to), names) in imported_names {
+            let Some(names) = names else {
+                continue;
+            };
+            if names.is_empty() || self.declaration_modules.contains_key(&to) {
+                continue;
+            }
+            if !self.forward_type_exports.contains_key(&to) {
+                let source = self.read_module_source(&to)?;
+                let parser = Parser::new(&source).map_err(|e| ModuleCompileError::LexError {
+                    path: to.clone(),
+                    message: format!("{:?}", e),
+                })?;
+                let (mut ast, interner) =
+                    parser.parse().map_err(|e| ModuleCompileError::ParseError {
+                        path: to.clone(),
+                        message: format!("{:?}", e),
+                    })?;
+                strip_disabled_features(&mut ast, &self.features);
+                let module_identity = self.module_identity(&to);
+                let exports = type_alias_exports(&to, &module_identity, &ast, &interner)
+                    .map_err(|e| self.map_declaration_error(e))?;
+                self.forward_type_exports.insert(to.clone(), exports);
+            }
+            let aliases = &self.forward_type_exports[&to];
+            if names.iter().all(|name| aliases.symbols.contains_key(name)) {
+                self.graph.mark_type_only(&from, &to);
+            }
+        }
         Ok(())
     }
 
@@ -772,24 +939,40 @@ impl ModuleCompiler {
         Ok((decoded, declaration_module.exports.clone()))
     }
 
-    /// Extract import specifiers from source code
-    fn extract_imports(&self, source: &str, path: &Path) -> ModuleCompileResult<Vec<String>> {
+    /// Extract import specifiers from source code, with the imported names
+    /// when the import is only named specifiers
+    fn extract_imports(
+        &self,
+        source: &str,
+        path: &Path,
+    ) -> ModuleCompileResult<Vec<(String, Option<Vec<String>>)>> {
         let parser = Parser::new(source).map_err(|e| ModuleCompileError::LexError {
             path: path.to_path_buf(),
             message: format!("{:?}", e),
         })?;
 
-        let (ast, interner) = parser.parse().map_err(|e| ModuleCompileError::ParseError {
+        let (mut ast, interner) = parser.parse().map_err(|e| ModuleCompileError::ParseError {
             path: path.to_path_buf(),
             message: format!("{:?}", e),
         })?;
+        strip_disabled_features(&mut ast, &self.features);
 
         let mut imports = Vec::new();
         for stmt in &ast.statements {
             match stmt {
                 Statement::ImportDecl(import) => {
                     let specifier = interner.resolve(import.source.value).to_string();
-                    imports.push(specifier);
+                    let names = import
+                        .specifiers
+                        .iter()
+                        .map(|spec| match spec {
+                            ImportSpecifier::Named { name, .. } => {
+                                Some(interner.resolve(name.name).to_string())
+                            }
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>();
+                    imports.push((specifier, names));
                 }
                 Statement::ExportDecl(ExportDecl::Named {
                     source: Some(source),
@@ -797,7 +980,7 @@ impl ModuleCompiler {
                 })
                 | Statement::ExportDecl(ExportDecl::All { source, .. }) => {
                     let specifier = interner.resolve(source.value).to_string();
-                    imports.push(specifier);
+                    imports.push((specifier, None));
                 }
                 _ => {}
             }
@@ -914,13 +1097,67 @@ impl ModuleCompiler {
         Ok(())
     }
 
+    fn inject_host_globals(
+        &self,
+        binder: &mut Binder<'_>,
+        ast: &AstModule,
+        interner: &Interner,
+        current_path: &Path,
+    ) -> ModuleCompileResult<()> {
+        for (name, signature) in &self.host_globals {
+            if Self::has_top_level_declaration_before_offset(ast, interner, name, usize::MAX) {
+                return Err(ModuleCompileError::TypeError {
+                    path: current_path.to_path_buf(),
+                    message: format!(
+                        "Host binding '{}' conflicts with a top-level declaration of the same name",
+                        name
+                    ),
+                });
+            }
+            if binder.has_global_symbol(name) {
+                return Err(ModuleCompileError::TypeError {
+                    path: current_path.to_path_buf(),
+                    message: format!(
+                        "Host binding '{}' conflicts with a builtin global of the same name",
+                        name
+                    ),
+                });
+            }
+            let ty = binder.hydrate_imported_signature_type(signature);
+            let symbol = Symbol {
+                name: name.clone(),
+                kind: SymbolKind::Variable,
+                ty,
+                flags: SymbolFlags {
+                    is_exported: false,
+                    is_const: true,
+                    is_async: false,
+                    is_readonly: true,
+                    is_imported: false,
+                },
+                scope_id: ScopeId(0),
+                span: Span::new(0, 0, 0, 0),
+                referenced: false,
+            };
+            binder
+                .define_imported(symbol)
+                .map_err(|e| ModuleCompileError::TypeError {
+                    path: current_path.to_path_buf(),
+                    message: format!("Host binding '{}' is already defined", e.name),
+                })?;
+        }
+        Ok(())
+    }
+
     /// Compile a single module with cross-module symbol resolution
     ///
-    /// Returns the bytecode and the module's exports for use by dependent modules.
+    /// Returns the bytecode, the module's exports for use by dependent modules,
+    /// and any non-fatal compiler warnings.
     fn compile_single_with_exports(
         &mut self,
         path: &PathBuf,
-    ) -> ModuleCompileResult<(BytecodeModule, ModuleExports)> {
+        is_entry: bool,
+    ) -> ModuleCompileResult<(BytecodeModule, ModuleExports, Vec<String>)> {
         // Read source
         let source = self.read_module_source(path)?;
 
@@ -930,22 +1167,35 @@ impl ModuleCompiler {
             message: format!("{:?}", e),
         })?;
 
-        let (ast, interner) = parser.parse().map_err(|e| ModuleCompileError::ParseError {
+        let (mut ast, interner) = parser.parse().map_err(|e| ModuleCompileError::ParseError {
             path: path.clone(),
             message: format!("{:?}", e),
         })?;
 
+        // Drop `//@@if`-gated code for disabled features before anything sees it
+        strip_disabled_features(&mut ast, &self.features);
+
         // Bind
-        let mut type_ctx = TypeContext::new();
-        let mut binder = Binder::new(&mut type_ctx, &interner)
-            .with_mode(self.checker_mode)
-            .with_policy(self.checker_policy);
+        let inject_builtins = self.should_inject_builtin_globals(path);
+        let mut type_ctx = if inject_builtins {
+            TypeContext::with_builtins()
+        } else {
+            TypeContext::new()
+        };
+        let mut binder = if inject_builtins {
+            Binder::with_builtins(&mut type_ctx, &interner)
+        } else {
+            Binder::new(&mut type_ctx, &interner)
+        }
+        .with_mode(self.checker_mode)
+        .with_policy(self.checker_policy);
 
-        if self.should_inject_builtin_globals(path) {
-            let builtin_sigs = crate::builtins::to_checker_signatures();
-            binder.register_builtins(&builtin_sigs);
+        if inject_builtins {
             self.inject_builtin_globals(&mut binder, &ast, &interner, path)?;
         }
+        if is_entry {
+            self.inject_host_globals(&mut binder, &ast, &interner, path)?;
+        }
 
         // Inject imported symbols from the export registry
         self.inject_imports(&ast, path, &mut binder, &interner, None)?;
@@ -1006,10 +1256,17 @@ impl ModuleCompiler {
 
         let module_name = self.module_identity(path);
         // Extract exports for dependent modules
-        let module_exports =
-            self.extract_exports(&ast, path, &module_name, &symbols, &interner, &type_ctx);
+        let mut module_exports =
+            self.extract_exports(&ast, path, &module_name, &symbols, &interner, &type_ctx)?;
+        let imported_constants = self.collect_imported_constants(&ast, path, &interner)?;
+        Self::record_constant_export_values(
+            &mut module_exports,
+            &ast,
+            &interner,
+            &imported_constants,
+        );
 
-        let ambient_builtin_globals: Vec<String> = self
+        let mut ambient_builtin_globals: Vec<String> = self
             .builtin_globals
             .as_ref()
             .map(|exports| {
@@ -1032,6 +1289,9 @@ impl ModuleCompiler {
                 names
             })
             .unwrap_or_else(|| vec!["EventEmitter".to_string()]);
+        if is_entry {
+            ambient_builtin_globals.extend(self.host_globals.iter().map(|(name, _)| name.clone()));
+        }
 
         // Compile
         let allow_unresolved_runtime_fallback =
@@ -1046,6 +1306,22 @@ impl ModuleCompiler {
         compiler = compiler.with_module_identity(module_name.clone());
         compiler = compiler.with_emit_generic_templates(true);
         compiler = compiler.with_ambient_builtin_globals(ambient_builtin_globals);
+        compiler = compiler.with_strip_assertions(self.strip_assertions);
+        compiler = compiler.with_sourcemap(self.sourcemap);
+        compiler = compiler.with_imported_constants(imported_constants.into_iter().collect());
+        if let Some(ref known_natives) = self.known_natives {
+            compiler = compiler.with_known_natives(known_natives.iter().cloned());
+        }
+        let lowering_cache = if is_entry {
+            self.entry_lowering_cache.take()
+        } else {
+            None
+        };
+        if let Some(cache) = lowering_cache {
+            compiler = compiler
+                .with_source_text(source.clone())
+                .with_lowering_cache(cache);
+        }
 
         let mut bytecode =
             compiler
@@ -1054,6 +1330,22 @@ impl ModuleCompiler {
                     path: path.clone(),
                     source: e,
                 })?;
+        let warnings = compiler.take_warnings();
+        if let Some(cache) = compiler.take_lowering_cache() {
+            self.entry_lowering_cache = Some(cache);
+        }
+        if is_entry && self.emit_entry_ir {
+            use crate::compiler::ir::PrettyPrint;
+            let ir_module = compiler.compile_to_optimized_ir(&ast).map_err(|e| {
+                ModuleCompileError::CompileError {
+                    path: path.clone(),
+                    source: e,
+                }
+            })?;
+            // Drop warnings already reported by the bytecode compilation above.
+            compiler.take_warnings();
+            self.entry_ir = Some(ir_module.pretty_print());
+        }
         bytecode.metadata.name = module_name;
         self.populate_link_tables(&mut bytecode, path, &ast, &interner, &module_exports)?;
         let encoded = bytecode.encode();
@@ -1062,7 +1354,7 @@ impl ModuleCompiler {
             message: format!("Failed to finalize module checksum: {e}"),
         })?;
 
-        Ok((bytecode, module_exports))
+        Ok((bytecode, module_exports, warnings))
     }
 
     /// Inject symbols from imported modules into the binder
@@ -1109,6 +1401,14 @@ impl ModuleCompiler {
                                 continue;
                             }
 
+                            if let Some(error) = self.ambiguous_reexport_error(
+                                current_path,
+                                &specifier,
+                                &resolved_path,
+                                &import_name,
+                            ) {
+                                return Err(error);
+                            }
                             if let Some(exported) =
                                 self.exports.resolve_symbol(&resolved_path, &import_name)
                             {
@@ -1312,14 +1612,14 @@ impl ModuleCompiler {
 
     /// Extract exported symbols from a compiled module's symbol table
     fn extract_exports(
-        &self,
+        &mut self,
         ast: &AstModule,
         path: &Path,
         module_name: &str,
         symbols: &crate::parser::checker::SymbolTable,
         interner: &Interner,
         type_ctx: &TypeContext,
-    ) -> ModuleExports {
+    ) -> ModuleCompileResult<ModuleExports> {
         let mut exports = ModuleExports::new(path.to_path_buf(), module_name.to_string());
 
         for symbol in symbols.get_exported_symbols() {
@@ -1395,11 +1695,171 @@ impl ModuleCompiler {
                         type_ctx,
                     ));
                 }
+                Statement::ExportDecl(ExportDecl::Named {
+                    specifiers,
+                    source: Some(source),
+                    ..
+                }) => {
+                    // `export { a as b } from "./mod"` re-binds the origin symbol
+                    // under the exported name; importers link straight to the origin.
+                    let specifier = interner.resolve(source.value).to_string();
+                    let Some(resolved_path) = self.resolve_import_path(&specifier, path)? else {
+                        continue;
+                    };
+                    for spec in specifiers {
+                        let import_name = interner.resolve(spec.name.name).to_string();
+                        let exported_name = spec
+                            .alias
+                            .as_ref()
+                            .map(|ident| interner.resolve(ident.name).to_string())
+                            .unwrap_or_else(|| import_name.clone());
+                        if exports.has(&exported_name) {
+                            continue;
+                        }
+                        if let Some(error) = self.ambiguous_reexport_error(
+                            path,
+                            &specifier,
+                            &resolved_path,
+                            &import_name,
+                        ) {
+                            return Err(error);
+                        }
+                        let Some(origin) =
+                            self.exports.resolve_symbol(&resolved_path, &import_name)
+                        else {
+                            continue;
+                        };
+                        let mut symbol = origin.clone();
+                        symbol.name = exported_name;
+                        exports.add_symbol(symbol);
+                    }
+                }
                 _ => {}
             }
         }
 
-        exports
+        Ok(exports)
+    }
+
+    /// Literal values of imported constants, keyed by import-local name.
+    ///
+    /// These are inlined into the importer at link time instead of being
+    /// loaded from the exporting module's globals.
+    fn collect_imported_constants(
+        &mut self,
+        ast: &AstModule,
+        current_path: &Path,
+        interner: &Interner,
+    ) -> ModuleCompileResult<HashMap<String, ConstantValue>> {
+        let mut constants = HashMap::new();
+        for stmt in &ast.statements {
+            let Statement::ImportDecl(import) = stmt else {
+                continue;
+            };
+            let specifier = interner.resolve(import.source.value).to_string();
+            let Some(resolved_path) = self.resolve_import_path(&specifier, current_path)? else {
+                continue;
+            };
+            for spec in &import.specifiers {
+                let import_name = match spec {
+                    ImportSpecifier::Named { name, .. } => interner.resolve(name.name),
+                    ImportSpecifier::Default(_) => "default",
+                    ImportSpecifier::Namespace(_) => continue,
+                };
+                if let Some(value) = self
+                    .exports
+                    .resolve_symbol(&resolved_path, import_name)
+                    .and_then(|exported| exported.constant_value.clone())
+                {
+                    constants.insert(Self::import_local_binding_name(spec, interner), value);
+                }
+            }
+        }
+        Ok(constants)
+    }
+
+    /// Attach literal values to this module's exported `const` bindings.
+    ///
+    /// A constant qualifies when its initializer is a literal or names another
+    /// qualifying constant (local or imported).
+    fn record_constant_export_values(
+        module_exports: &mut ModuleExports,
+        ast: &AstModule,
+        interner: &Interner,
+        imported_constants: &HashMap<String, ConstantValue>,
+    ) {
+        let mut constants = imported_constants.clone();
+        for stmt in &ast.statements {
+            let Some(Statement::VariableDecl(decl)) = Self::top_level_declaration_stmt(stmt) else {
+                continue;
+            };
+            if decl.kind != VariableKind::Const {
+                continue;
+            }
+            let (Pattern::Identifier(ident), Some(init)) = (&decl.pattern, &decl.initializer)
+            else {
+                continue;
+            };
+            let value = match init {
+                Expression::Identifier(source) => {
+                    constants.get(interner.resolve(source.name)).cloned()
+                }
+                _ => ConstantValue::from_literal(init, interner),
+            };
+            let name = interner.resolve(ident.name).to_string();
+            match value {
+                Some(value) => {
+                    constants.insert(name, value);
+                }
+                // A non-literal local shadows an imported constant of the same name.
+                None => {
+                    constants.remove(&name);
+                }
+            }
+        }
+
+        let own_module_id = module_id_from_name(&module_exports.module_name);
+        for exported in module_exports.symbols.values_mut() {
+            if exported.module_id != own_module_id || !exported.is_const {
+                continue;
+            }
+            exported.constant_value = constants.get(&exported.local_name).cloned();
+            // Importers inline the value, so it is part of the export's interface:
+            // changing it must change the hash even though the type is the same.
+            if let Some(value) = &exported.constant_value {
+                exported.signature_hash = crate::parser::types::signature_hash(&format!(
+                    "{}|const={:?}",
+                    exported.type_signature, value
+                ));
+            }
+        }
+    }
+
+    /// Build the diagnostic for a name that several `export *` sources provide
+    fn ambiguous_reexport_error(
+        &self,
+        current_path: &Path,
+        specifier: &str,
+        resolved_path: &PathBuf,
+        name: &str,
+    ) -> Option<ModuleCompileError> {
+        let candidates = self.exports.resolve_candidates(resolved_path, name);
+        if candidates.len() < 2 {
+            return None;
+        }
+        let origins = candidates
+            .iter()
+            .map(|symbol| format!("'{}'", symbol.module_name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(ModuleCompileError::TypeError {
+            path: current_path.to_path_buf(),
+            message: format!(
+                "'{}' is ambiguous in '{}': it is re-exported by `export *` from {}. \
+                 Re-export one of them explicitly with `export {{ {} }} from \"...\"`",
+                name, specifier, origins, name
+            ),
+        })
     }
 
     fn top_level_module_scope_id(
@@ -1649,7 +2109,12 @@ impl ModuleCompiler {
         let module_global_slots = Self::collect_module_global_slots(ast, interner);
 
         // Export table: map exported symbols to runtime bytecode indices where available.
+        // Named re-exports belong to their origin module and are linked there.
+        let own_module_id = module_id_from_name(&module_exports.module_name);
         for exported in module_exports.symbols.values() {
+            if exported.module_id != own_module_id {
+                continue;
+            }
             let symbol_type = match exported.kind {
                 crate::parser::checker::SymbolKind::Function => Some(SymbolType::Function),
                 crate::parser::checker::SymbolKind::Class
@@ -1702,6 +2167,8 @@ impl ModuleCompiler {
                 ),
             });
         }
+        // Export symbols come from a hash map; sort so rebuilds encode identically.
+        bytecode.exports.sort_by(|a, b| a.name.cmp(&b.name));
 
         // Import table: capture named/default imports with deterministic target IDs.
         for stmt in &ast.statements {
@@ -1742,6 +2209,10 @@ impl ModuleCompiler {
                                 ) {
                                     continue;
                                 }
+                                // Constant values were inlined into this module.
+                                if exported.constant_value.is_some() {
+                                    continue;
+                                }
                                 if declaration_target {
                                     self.record_late_link_symbol_requirement(
                                         &target_module_name,
@@ -1753,7 +2224,7 @@ impl ModuleCompiler {
                                     module_specifier: specifier.clone(),
                                     symbol: import_name,
                                     alias: alias_name,
-                                    module_id: target_module_id,
+                                    module_id: exported.module_id,
                                     symbol_id: exported.symbol_id,
                                     scope: SymbolScope::Module,
                                     signature_hash: exported.signature_hash,
@@ -1785,6 +2256,10 @@ impl ModuleCompiler {
                                 ) {
                                     continue;
                                 }
+                                // Constant values were inlined into this module.
+                                if exported.constant_value.is_some() {
+                                    continue;
+                                }
                                 if declaration_target {
                                     self.record_late_link_symbol_requirement(
                                         &target_module_name,
@@ -1796,7 +2271,7 @@ impl ModuleCompiler {
                                     module_specifier: specifier.clone(),
                                     symbol: default_name,
                                     alias: Some(local_name),
-                                    module_id: target_module_id,
+                                    module_id: exported.module_id,
                                     symbol_id: exported.symbol_id,
                                     scope: SymbolScope::Module,
                                     signature_hash: exported.signature_hash,
@@ -1851,7 +2326,6 @@ impl ModuleCompiler {
                         continue;
                     };
                     let target_module_name = self.module_identity(&resolved_path);
-                    let target_module_id = module_id_from_name(&target_module_name);
                     let declaration_target = self.declaration_modules.contains_key(&resolved_path);
 
                     for spec in specifiers {
@@ -1889,7 +2363,7 @@ impl ModuleCompiler {
                             module_specifier: specifier.clone(),
                             symbol: import_name,
                             alias: alias_name,
-                            module_id: target_module_id,
+                            module_id: exported.module_id,
                             symbol_id: exported.symbol_id,
                             scope: SymbolScope::Module,
                             signature_hash: exported.signature_hash,
@@ -1970,6 +2444,7 @@ impl ModuleCompiler {
         self.declaration_modules.clear();
         self.declaration_virtual_by_identity.clear();
         self.late_link_requirements.clear();
+        self.forward_type_exports.clear();
     }
 
     /// Get the export registry
@@ -2506,6 +2981,66 @@ mod tests {
         assert!(compiler.exports().get(&utils_canonical).unwrap().has("add"));
     }
 
+    #[test]
+    fn test_imported_constant_is_inlined_at_link_time() {
+        let temp_dir = create_test_project();
+        let main_path = temp_dir.path().join("main.raya");
+        let math_path = temp_dir.path().join("math.raya");
+
+        fs::write(
+            &math_path,
+            r#"export const PI = 3.14159;
+               export function twice(x: number): number { return x * 2; }"#,
+        )
+        .unwrap();
+        fs::write(
+            &main_path,
+            r#"import { PI, twice } from "./math";
+               return twice(PI);"#,
+        )
+        .unwrap();
+
+        let mut compiler = ModuleCompiler::new(temp_dir.path().to_path_buf());
+        let compiled = compiler.compile(&main_path).expect("compile");
+        let main_module = compiled
+            .iter()
+            .find(|module| module.path == main_path.canonicalize().unwrap())
+            .expect("main module");
+
+        assert!(main_module.bytecode.constants.floats.contains(&3.14159));
+        let imported: Vec<&str> = main_module
+            .bytecode
+            .imports
+            .iter()
+            .map(|import| import.symbol.as_str())
+            .collect();
+        assert_eq!(imported, vec!["twice"]);
+    }
+
+    #[test]
+    fn test_constant_value_is_part_of_export_hash() {
+        let export_hash = |value: &str| {
+            let temp_dir = create_test_project();
+            let math_path = temp_dir.path().join("math.raya");
+            fs::write(&math_path, format!("export const PI = {value};")).unwrap();
+            let mut compiler = ModuleCompiler::new(temp_dir.path().to_path_buf());
+            compiler.compile(&math_path).expect("compile");
+            let exported = compiler
+                .exports()
+                .get(&math_path.canonicalize().unwrap())
+                .and_then(|exports| exports.get("PI"))
+                .cloned()
+                .expect("PI export");
+            (exported.type_signature, exported.signature_hash)
+        };
+
+        let (before_sig, before_hash) = export_hash("3.14");
+        let (after_sig, after_hash) = export_hash("3.15");
+        assert_eq!(before_sig, after_sig);
+        assert_ne!(before_hash, after_hash);
+        assert_eq!(export_hash("3.14").1, before_hash);
+    }
+
     #[test]
     fn test_aliased_import() {
         let temp_dir = create_test_project();
@@ -2553,6 +3088,44 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_type_only_cycle_compiles() {
+        let temp_dir = create_test_project();
+        let a_path = temp_dir.path().join("a.raya");
+        let b_path = temp_dir.path().join("b.raya");
+
+        fs::write(
+            &a_path,
+            r#"import { scale } from "./b";
+export type Point = { x: number; y: number };
+export let p: Point = scale({ x: 1, y: 2 });"#,
+        )
+        .unwrap();
+        fs::write(
+            &b_path,
+            r#"import { Point } from "./a";
+export function scale(p: Point): Point { return { x: p.x * 2, y: p.y * 2 }; }"#,
+        )
+        .unwrap();
+
+        let mut compiler = ModuleCompiler::new(temp_dir.path().to_path_buf());
+        let modules = compiler
+            .compile(&a_path)
+            .expect("type-only cycle should compile");
+        let order = modules
+            .iter()
+            .map(|module| {
+                module
+                    .path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["b.raya", "a.raya"]);
+    }
+
     #[test]
     fn test_diamond_dependency() {
         let temp_dir = create_test_project();
@@ -2786,6 +3359,231 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_named_reexport_is_visible_to_importers() {
+        let temp_dir = create_test_project();
+        let main_path = temp_dir.path().join("main.raya");
+        let index_path = temp_dir.path().join("index.raya");
+        let math_path = temp_dir.path().join("math.raya");
+
+        fs::write(
+            &math_path,
+            "export function add(a: number, b: number): number { return a + b; }",
+        )
+        .unwrap();
+        fs::write(&index_path, r#"export { add } from "./math";"#).unwrap();
+        fs::write(
+            &main_path,
+            r#"
+            import { add } from "./index";
+            let x: number = add(1, 2);
+            "#,
+        )
+        .unwrap();
+
+        let mut compiler = ModuleCompiler::new(temp_dir.path().to_path_buf());
+        let compiled = compiler
+            .compile(&main_path)
+            .unwrap_or_else(|e| panic!("Compilation failed: {:?}", e));
+
+        let math_id =
+            module_id_from_name(&compiler.module_identity(&math_path.canonicalize().unwrap()));
+        let main_module = compiled
+            .iter()
+            .find(|m| m.path == main_path.canonicalize().unwrap())
+            .expect("missing main.raya module");
+        let import = main_module
+            .bytecode
+            .imports
+            .iter()
+            .find(|import| import.symbol == "add")
+            .expect("main should import add");
+        assert_eq!(
+            import.module_id, math_id,
+            "re-exported import should link to the origin module"
+        );
+
+        let index_module = compiled
+            .iter()
+            .find(|m| m.path == index_path.canonicalize().unwrap())
+            .expect("missing index.raya module");
+        assert!(
+            index_module.bytecode.exports.is_empty(),
+            "aggregator should not own re-exported symbols"
+        );
+    }
+
+    #[test]
+    fn test_aliased_reexport_uses_new_name() {
+        let temp_dir = create_test_project();
+        let main_path = temp_dir.path().join("main.raya");
+        let index_path = temp_dir.path().join("index.raya");
+        let math_path = temp_dir.path().join("math.raya");
+
+        fs::write(
+            &math_path,
+            "export function add(a: number, b: number): number { return a + b; }",
+        )
+        .unwrap();
+        fs::write(&index_path, r#"export { add as sum } from "./math";"#).unwrap();
+        fs::write(
+            &main_path,
+            r#"
+            import { sum } from "./index";
+            let x: number = sum(1, 2);
+            "#,
+        )
+        .unwrap();
+
+        let mut compiler = ModuleCompiler::new(temp_dir.path().to_path_buf());
+        let result = compiler.compile(&main_path);
+        assert!(result.is_ok(), "Compilation failed: {:?}", result.err());
+
+        let index_exports = compiler
+            .exports()
+            .get(&index_path.canonicalize().unwrap())
+            .expect("missing index exports");
+        assert!(index_exports.has("sum"));
+        assert!(!index_exports.has("add"));
+
+        fs::write(
+            &main_path,
+            r#"
+            import { add } from "./index";
+            let x: number = add(1, 2);
+            "#,
+        )
+        .unwrap();
+        let mut compiler = ModuleCompiler::new(temp_dir.path().to_path_buf());
+        assert!(
+            compiler.compile(&main_path).is_err(),
+            "original name should not be exported by the aggregator"
+        );
+    }
+
+    #[test]
+    fn test_conflicting_star_reexports_are_rejected() {
+        let temp_dir = create_test_project();
+        let main_path = temp_dir.path().join("main.raya");
+        let index_path = temp_dir.path().join("index.raya");
+        let a_path = temp_dir.path().join("a.raya");
+        let b_path = temp_dir.path().join("b.raya");
+
+        fs::write(&a_path, "export let value: number = 1;").unwrap();
+        fs::write(&b_path, "export let value: number = 2;").unwrap();
+        fs::write(
+            &index_path,
+            r#"
+            export * from "./a";
+            export * from "./b";
+            "#,
+        )
+        .unwrap();
+        fs::write(
+            &main_path,
+            r#"
+            import { value } from "./index";
+            let x: number = value;
+            "#,
+        )
+        .unwrap();
+
+        let mut compiler = ModuleCompiler::new(temp_dir.path().to_path_buf());
+        let err = compiler.compile(&main_path).unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("'value' is ambiguous"),
+            "unexpected diagnostic: {}",
+            message
+        );
+    }
+
+    #[test]
+    fn test_feature_gated_declarations_follow_enabled_features() {
+        let temp_dir = create_test_project();
+        let main_path = temp_dir.path().join("main.raya");
+
+        fs::write(
+            &main_path,
+            r#"
+            //@@if(linux)
+            function platformName(): string { return linuxOnlyApi(); }
+
+            //@@if(!linux)
+            function fallbackName(): string { return "other"; }
+            "#,
+        )
+        .unwrap();
+
+        let function_names = |features: Vec<String>| -> Vec<String> {
+            let mut compiler =
+                ModuleCompiler::new(temp_dir.path().to_path_buf()).with_features(features);
+            match compiler.compile(&main_path) {
+                Ok(compiled) => compiled[0]
+                    .bytecode
+                    .functions
+                    .iter()
+                    .map(|f| f.name.clone())
+                    .collect(),
+                Err(e) => vec![format!("error: {}", e)],
+            }
+        };
+
+        // Without the feature the gated function is never type-checked, so the
+        // missing `linuxOnlyApi` does not produce an error.
+        let disabled = function_names(Vec::new());
+        assert!(
+            disabled.iter().any(|name| name == "fallbackName"),
+            "expected fallbackName, got {:?}",
+            disabled
+        );
+        assert!(!disabled.iter().any(|name| name == "platformName"));
+
+        let enabled = function_names(vec!["linux".to_string()]);
+        assert!(
+            enabled.iter().any(|name| name.starts_with("error:")),
+            "enabling linux should type-check the gated body: {:?}",
+            enabled
+        );
+    }
+
+    #[test]
+    fn test_feature_gates_apply_to_class_members_and_nested_statements() {
+        let temp_dir = create_test_project();
+        let main_path = temp_dir.path().join("main.raya");
+
+        fs::write(
+            &main_path,
+            r#"
+            let prefix: string = "os:";
+
+            class Platform {
+                //@@if(linux)
+                name(): string { return linuxOnlyApi(); }
+
+                label(): string { return "generic"; }
+            }
+
+            function describe(): string {
+                //@@if(linux)
+                let extra: string = linuxOnlyApi();
+                return prefix + new Platform().label();
+            }
+            "#,
+        )
+        .unwrap();
+
+        let compile = |features: Vec<String>| {
+            let mut compiler =
+                ModuleCompiler::new(temp_dir.path().to_path_buf()).with_features(features);
+            compiler.compile(&main_path).map(|_| ())
+        };
+
+        // The hoisted `prefix` declaration is not gated and must survive.
+        compile(Vec::new()).expect("disabled members and statements are not type-checked");
+        assert!(compile(vec!["linux".to_string()]).is_err());
+    }
+
     #[test]
     fn test_cache_hit() {
         let temp_dir = create_test_project();
@@ -252,6 +252,10 @@ fn strict_builtin_declaration_sources() -> &'static [(&'static str, &'static str
             "strict/array.d.raya",
             include_str!("../../../builtins/strict/array.d.raya"),
         ),
+        (
+            "strict/bigint.d.raya",
+            include_str!("../../../builtins/strict/bigint.d.raya"),
+        ),
         (
             "strict/buffer.d.raya",
             include_str!("../../../builtins/strict/buffer.d.raya"),
@@ -292,6 +296,14 @@ fn strict_builtin_declaration_sources() -> &'static [(&'static str, &'static str
             "strict/promise.d.raya",
             include_str!("../../../builtins/strict/promise.d.raya"),
         ),
+        (
+            "strict/random.d.raya",
+            include_str!("../../../builtins/strict/random.d.raya"),
+        ),
+        (
+            "strict/regexp_match.d.raya",
+            include_str!("../../../builtins/strict/regexp_match.d.raya"),
+        ),
         (
             "strict/set.d.raya",
             include_str!("../../../builtins/strict/set.d.raya"),
@@ -317,6 +329,10 @@ fn node_compat_builtin_declaration_sources() -> &'static [(&'static str, &'stati
             "node_compat/atomics.d.raya",
             include_str!("../../../builtins/node_compat/atomics.d.raya"),
         ),
+        (
+            "strict/bigint.d.raya",
+            include_str!("../../../builtins/strict/bigint.d.raya"),
+        ),
         (
             "node_compat/buffer.d.raya",
             include_str!("../../../builtins/node_compat/buffer.d.raya"),
@@ -377,6 +393,10 @@ fn node_compat_builtin_declaration_sources() -> &'static [(&'static str, &'stati
             "node_compat/promise.d.raya",
             include_str!("../../../builtins/node_compat/promise.d.raya"),
         ),
+        (
+            "node_compat/regexp_match.d.raya",
+            include_str!("../../../builtins/node_compat/regexp_match.d.raya"),
+        ),
         (
             "node_compat/set.d.raya",
             include_str!("../../../builtins/node_compat/set.d.raya"),
@@ -473,6 +493,7 @@ pub fn builtin_global_exports(mode: BuiltinSurfaceMode) -> Result<ModuleExports,
                 signature_hash: exported.signature_hash,
                 type_signature: exported.type_signature,
                 scope: SymbolScope::Module,
+                constant_value: None,
             });
         }
     }
@@ -665,24 +686,7 @@ fn extract_declaration_exports(
     ast: &ast::Module,
     interner: &Interner,
 ) -> Result<ModuleExports, DeclarationError> {
-    let mut locals = HashMap::<String, DeclarationItem>::new();
-
-    for stmt in &ast.statements {
-        if let Some(item) = declaration_item_from_statement(stmt) {
-            if let Some(name) = item.name(interner) {
-                locals.insert(name, item);
-            }
-            continue;
-        }
-
-        if let Statement::ExportDecl(ExportDecl::Declaration(inner)) = stmt {
-            if let Some(item) = declaration_item_from_statement(inner) {
-                if let Some(name) = item.name(interner) {
-                    locals.insert(name, item);
-                }
-            }
-        }
-    }
+    let locals = declaration_locals(ast, interner);
 
     let mut explicit_exports = Vec::<DeclarationExport>::new();
     for stmt in &ast.statements {
@@ -776,28 +780,88 @@ fn extract_declaration_exports(
         virtual_module_path.to_path_buf(),
         module_identity.to_string(),
     );
-
     for export in explicit_exports {
-        let symbol_id = symbol_id_from_name(module_identity, SymbolScope::Module, &export.name);
-        module_exports.add_symbol(ExportedSymbol {
-            name: export.name.clone(),
-            local_name: export.name,
-            kind: export.kind,
-            ty: TypeId::new(TypeContext::UNKNOWN_TYPE_ID),
-            is_const: export.is_const,
-            is_async: export.is_async,
-            module_name: module_identity.to_string(),
-            module_id: module_id_from_name(module_identity),
-            symbol_id,
-            signature_hash: signature_hash(&export.canonical_signature),
-            type_signature: export.canonical_signature,
-            scope: SymbolScope::Module,
-        });
+        add_declaration_export(&mut module_exports, module_identity, export);
     }
 
     Ok(module_exports)
 }
 
+/// Exported type aliases of a source module, projected from their
+/// annotations without binding or checking the module.
+///
+/// A module in an import cycle uses these for an alias-only import of a
+/// module that is compiled after it.
+pub(crate) fn type_alias_exports(
+    module_path: &Path,
+    module_identity: &str,
+    ast: &ast::Module,
+    interner: &Interner,
+) -> Result<ModuleExports, DeclarationError> {
+    let locals = declaration_locals(ast, interner);
+    let mut module_exports =
+        ModuleExports::new(module_path.to_path_buf(), module_identity.to_string());
+    for stmt in &ast.statements {
+        let Statement::ExportDecl(ExportDecl::Declaration(inner)) = stmt else {
+            continue;
+        };
+        if !matches!(inner.as_ref(), Statement::TypeAliasDecl(_)) {
+            continue;
+        }
+        if let Some(export) = export_from_statement(inner, interner, &locals, module_path)? {
+            add_declaration_export(&mut module_exports, module_identity, export);
+        }
+    }
+    Ok(module_exports)
+}
+
+/// Top-level declarations by name, exported or not
+fn declaration_locals(ast: &ast::Module, interner: &Interner) -> HashMap<String, DeclarationItem> {
+    let mut locals = HashMap::<String, DeclarationItem>::new();
+
+    for stmt in &ast.statements {
+        if let Some(item) = declaration_item_from_statement(stmt) {
+            if let Some(name) = item.name(interner) {
+                locals.insert(name, item);
+            }
+            continue;
+        }
+
+        if let Statement::ExportDecl(ExportDecl::Declaration(inner)) = stmt {
+            if let Some(item) = declaration_item_from_statement(inner) {
+                if let Some(name) = item.name(interner) {
+                    locals.insert(name, item);
+                }
+            }
+        }
+    }
+
+    locals
+}
+
+fn add_declaration_export(
+    module_exports: &mut ModuleExports,
+    module_identity: &str,
+    export: DeclarationExport,
+) {
+    let symbol_id = symbol_id_from_name(module_identity, SymbolScope::Module, &export.name);
+    module_exports.add_symbol(ExportedSymbol {
+        name: export.name.clone(),
+        local_name: export.name,
+        kind: export.kind,
+        ty: TypeId::new(TypeContext::UNKNOWN_TYPE_ID),
+        is_const: export.is_const,
+        is_async: export.is_async,
+        module_name: module_identity.to_string(),
+        module_id: module_id_from_name(module_identity),
+        symbol_id,
+        signature_hash: signature_hash(&export.canonical_signature),
+        type_signature: export.canonical_signature,
+        scope: SymbolScope::Module,
+        constant_value: None,
+    });
+}
+
 fn declaration_item_from_statement(stmt: &Statement) -> Option<DeclarationItem> {
     match stmt {
         Statement::FunctionDecl(func) => Some(DeclarationItem::Function(func.clone())),
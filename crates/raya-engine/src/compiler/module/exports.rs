@@ -5,6 +5,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::compiler::lower::ConstantValue;
 use crate::compiler::{
     module_id_from_name, symbol_id_from_name, ModuleId, SymbolId, SymbolScope, SymbolType,
     TypeSignatureHash,
@@ -40,6 +41,8 @@ pub struct ExportedSymbol {
     pub type_signature: String,
     /// Export symbol scope class.
     pub scope: SymbolScope,
+    /// Literal value of an exported `const`, inlined into importers at link time.
+    pub constant_value: Option<ConstantValue>,
 }
 
 impl ExportedSymbol {
@@ -78,6 +81,7 @@ impl ExportedSymbol {
             signature_hash: structural_sig.hash,
             type_signature: structural_sig.canonical,
             scope,
+            constant_value: None,
         }
     }
 
@@ -106,6 +110,7 @@ impl ExportedSymbol {
             signature_hash: structural_sig.hash,
             type_signature: structural_sig.canonical,
             scope,
+            constant_value: None,
         }
     }
 
@@ -204,23 +209,44 @@ impl ExportRegistry {
 
     /// Resolve a symbol from a module
     ///
-    /// This handles re-exports by following the chain.
+    /// This handles re-exports by following the chain. Names that are
+    /// ambiguous across several `export *` sources do not resolve.
     pub fn resolve_symbol(&self, module_path: &PathBuf, name: &str) -> Option<&ExportedSymbol> {
-        let exports = self.modules.get(module_path)?;
+        let mut candidates = self.resolve_candidates(module_path, name);
+        if candidates.len() == 1 {
+            candidates.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Collect the distinct symbols a name can resolve to from a module
+    ///
+    /// Direct exports shadow `export *` re-exports. More than one candidate
+    /// means several `export *` sources provide the name with different origins.
+    pub fn resolve_candidates(&self, module_path: &PathBuf, name: &str) -> Vec<&ExportedSymbol> {
+        let Some(exports) = self.modules.get(module_path) else {
+            return Vec::new();
+        };
 
         // First check direct exports
         if let Some(symbol) = exports.get(name) {
-            return Some(symbol);
+            return vec![symbol];
         }
 
-        // Then check re-exports
+        // Then check re-exports, keeping one entry per origin symbol
+        let mut candidates: Vec<&ExportedSymbol> = Vec::new();
         for reexport_path in &exports.reexports {
-            if let Some(symbol) = self.resolve_symbol(reexport_path, name) {
-                return Some(symbol);
+            for symbol in self.resolve_candidates(reexport_path, name) {
+                if !candidates.iter().any(|candidate| {
+                    candidate.module_id == symbol.module_id
+                        && candidate.symbol_id == symbol.symbol_id
+                }) {
+                    candidates.push(symbol);
+                }
             }
         }
-
-        None
+        candidates
     }
 
     /// Get all modules in the registry
@@ -256,6 +282,7 @@ mod tests {
             signature_hash: 101,
             type_signature: "fn(min=0,params=[],rest=_,ret=number)".to_string(),
             scope: SymbolScope::Module,
+            constant_value: None,
         };
 
         exports.add_symbol(symbol);
@@ -287,6 +314,7 @@ mod tests {
             signature_hash: 102,
             type_signature: "fn(min=0,params=[],rest=_,ret=number)".to_string(),
             scope: SymbolScope::Module,
+            constant_value: None,
         });
 
         registry.register(exports);
@@ -313,6 +341,7 @@ mod tests {
             signature_hash: 103,
             type_signature: "fn(min=0,params=[],rest=_,ret=number)".to_string(),
             scope: SymbolScope::Module,
+            constant_value: None,
         };
 
         let imported = exported.to_import_symbol(ScopeId(0));
@@ -3,6 +3,9 @@
 //! Tracks dependencies between modules and provides:
 //! - Cycle detection
 //! - Topological ordering for compilation
+//!
+//! An import that only brings in type aliases is a type-only edge. It has
+//! no initialization order, so a cycle closed by one is allowed.
 
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
@@ -42,6 +45,8 @@ pub struct ModuleNode {
     pub imports: Vec<PathBuf>,
     /// Modules that import this module (dependents)
     pub imported_by: Vec<PathBuf>,
+    /// Imports that only bring in type aliases
+    pub type_only_imports: HashSet<PathBuf>,
 }
 
 impl ModuleNode {
@@ -51,6 +56,7 @@ impl ModuleNode {
             path,
             imports: Vec::new(),
             imported_by: Vec::new(),
+            type_only_imports: HashSet::new(),
         }
     }
 }
@@ -103,6 +109,15 @@ impl ModuleGraph {
         self.entry_points.remove(&to);
     }
 
+    /// Mark an existing dependency edge as type-only
+    pub fn mark_type_only(&mut self, from: &PathBuf, to: &PathBuf) {
+        if let Some(node) = self.nodes.get_mut(from) {
+            if node.imports.contains(to) {
+                node.type_only_imports.insert(to.clone());
+            }
+        }
+    }
+
     /// Get a module node by path
     pub fn get(&self, path: &PathBuf) -> Option<&ModuleNode> {
         self.nodes.get(path)
@@ -130,6 +145,7 @@ impl ModuleGraph {
 
     /// Detect cycles in the graph
     ///
+    /// Type-only edges are ignored, since they carry no initialization order.
     /// Returns `Err(GraphError::CircularDependency)` if a cycle is found,
     /// with the cycle path included in the error.
     pub fn detect_cycles(&self) -> Result<(), GraphError> {
@@ -164,6 +180,9 @@ impl ModuleGraph {
 
         if let Some(module) = self.nodes.get(node) {
             for dep in &module.imports {
+                if module.type_only_imports.contains(dep) {
+                    continue;
+                }
                 if !visited.contains(dep) {
                     if let Some(cycle) = self.dfs_detect_cycle(dep, visited, rec_stack, path) {
                         return Some(cycle);
@@ -186,44 +205,55 @@ impl ModuleGraph {
     /// Get topological order of modules (dependencies first)
     ///
     /// Returns modules in an order where each module comes after all its dependencies.
-    /// This is the order modules should be compiled in.
+    /// This is the order modules should be compiled in. When only type-only
+    /// edges are left in a cycle, the module whose remaining imports are all
+    /// type-only goes first.
     pub fn topological_order(&self) -> Result<Vec<PathBuf>, GraphError> {
         // First check for cycles
         self.detect_cycles()?;
 
         let mut result = Vec::new();
-        let mut in_degree: HashMap<PathBuf, usize> = HashMap::new();
-
-        // Calculate in-degrees
-        for (path, node) in &self.nodes {
-            in_degree.entry(path.clone()).or_insert(0);
-            for dep in &node.imports {
-                *in_degree.entry(dep.clone()).or_insert(0) += 0; // Ensure dep exists
-            }
-        }
-
-        // Count imports (not imported_by) for each node
-        for node in self.nodes.values() {
-            for dep in &node.imports {
-                *in_degree.get_mut(dep).unwrap() += 1;
-            }
-        }
+        let mut done: HashSet<PathBuf> = HashSet::new();
 
-        // Wait, we need dependencies first, so we should use imported_by count
-        // Let me recalculate - we want leaves (modules with no imports) first
+        // Leaves (modules with no imports) come first
         let mut in_degree: HashMap<PathBuf, usize> = HashMap::new();
         for (path, node) in &self.nodes {
             in_degree.insert(path.clone(), node.imports.len());
         }
 
-        // Start with modules that have no imports (leaves)
         let mut queue: VecDeque<PathBuf> = in_degree
             .iter()
             .filter(|(_, &deg)| deg == 0)
             .map(|(p, _)| p.clone())
             .collect();
 
-        while let Some(path) = queue.pop_front() {
+        while result.len() < self.nodes.len() {
+            let path = match queue.pop_front() {
+                Some(path) => path,
+                None => {
+                    // Every remaining module waits on another; the value
+                    // edges are acyclic, so one waits only on type-only edges.
+                    let mut ready: Vec<&PathBuf> = self
+                        .nodes
+                        .values()
+                        .filter(|node| !done.contains(&node.path))
+                        .filter(|node| {
+                            node.imports.iter().all(|dep| {
+                                done.contains(dep) || node.type_only_imports.contains(dep)
+                            })
+                        })
+                        .map(|node| &node.path)
+                        .collect();
+                    ready.sort();
+                    match ready.first() {
+                        Some(path) => (*path).clone(),
+                        None => break,
+                    }
+                }
+            };
+            if !done.insert(path.clone()) {
+                continue;
+            }
             result.push(path.clone());
 
             // For each module that imports this one, decrement its in-degree
@@ -231,7 +261,7 @@ impl ModuleGraph {
                 for dependent in &node.imported_by {
                     if let Some(deg) = in_degree.get_mut(dependent) {
                         *deg -= 1;
-                        if *deg == 0 {
+                        if *deg == 0 && !done.contains(dependent) {
                             queue.push_back(dependent.clone());
                         }
                     }
@@ -354,6 +384,40 @@ mod tests {
         assert!(matches!(result, Err(GraphError::CircularDependency(_))));
     }
 
+    #[test]
+    fn test_type_only_cycle_allowed() {
+        let mut graph = ModuleGraph::new();
+        let a = PathBuf::from("/a.raya");
+        let b = PathBuf::from("/b.raya");
+
+        // a -> b for values, b -> a for type aliases only
+        graph.add_dependency(a.clone(), b.clone());
+        graph.add_dependency(b.clone(), a.clone());
+        graph.mark_type_only(&b, &a);
+
+        assert!(graph.detect_cycles().is_ok());
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order, vec![b, a]);
+    }
+
+    #[test]
+    fn test_type_only_edge_does_not_hide_value_cycle() {
+        let mut graph = ModuleGraph::new();
+        let a = PathBuf::from("/a.raya");
+        let b = PathBuf::from("/b.raya");
+        let c = PathBuf::from("/c.raya");
+
+        // a -> b -> c -> a for values, plus a type-only c -> b
+        graph.add_dependency(a.clone(), b.clone());
+        graph.add_dependency(b.clone(), c.clone());
+        graph.add_dependency(c.clone(), a.clone());
+        graph.add_dependency(c.clone(), b.clone());
+        graph.mark_type_only(&c, &b);
+
+        let result = graph.topological_order();
+        assert!(matches!(result, Err(GraphError::CircularDependency(_))));
+    }
+
     #[test]
     fn test_topological_order() {
         let mut graph = ModuleGraph::new();
@@ -1,6 +1,6 @@
 //! Module builder for constructing bytecode modules
 
-use crate::compiler::bytecode::{ClassDef, Function, Module, Opcode};
+use crate::compiler::bytecode::{ClassDef, ConstantPool, Function, Module, Opcode};
 use crate::compiler::error::{CompileError, CompileResult};
 use rustc_hash::FxHashMap;
 
@@ -9,8 +9,7 @@ pub struct ModuleBuilder {
     name: String,
     functions: Vec<Function>,
     classes: Vec<ClassDef>,
-    constants: Vec<Vec<u8>>,
-    constant_map: FxHashMap<Vec<u8>, u16>,
+    constants: ConstantPool,
 }
 
 impl ModuleBuilder {
@@ -19,8 +18,7 @@ impl ModuleBuilder {
             name,
             functions: Vec::new(),
             classes: Vec::new(),
-            constants: Vec::new(),
-            constant_map: FxHashMap::default(),
+            constants: ConstantPool::new(),
         }
     }
 
@@ -35,20 +33,16 @@ impl ModuleBuilder {
     }
 
     /// Add a string constant to the constant pool, returning its index
+    ///
+    /// Identical strings share one pool entry.
     pub fn add_string(&mut self, s: String) -> CompileResult<u16> {
-        let data = s.as_bytes().to_vec();
-        if let Some(&index) = self.constant_map.get(&data) {
-            return Ok(index);
+        if let Some(index) = self.constants.find_string(&s) {
+            return Ok(index as u16);
         }
-
-        if self.constants.len() >= 65535 {
+        if self.constants.strings.len() >= 65535 {
             return Err(CompileError::TooManyConstants);
         }
-
-        let index = self.constants.len() as u16;
-        self.constant_map.insert(data, index);
-        self.constants.push(s.into_bytes());
-        Ok(index)
+        Ok(self.constants.intern_string(s) as u16)
     }
 
     /// Build the final module
@@ -56,12 +50,7 @@ impl ModuleBuilder {
         let mut module = Module::new(self.name);
         module.functions = self.functions;
         module.classes = self.classes;
-        // Add string constants to constant pool
-        for data in self.constants {
-            if let Ok(s) = String::from_utf8(data) {
-                module.constants.add_string(s);
-            }
-        }
+        module.constants = self.constants;
         module
     }
 }
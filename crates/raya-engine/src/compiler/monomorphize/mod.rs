@@ -241,15 +241,22 @@ impl Default for MonomorphizationContext {
     }
 }
 
+/// Default maximum number of distinct specializations per generic function
+pub const DEFAULT_INSTANTIATION_BUDGET: usize = 64;
+
 /// Result of monomorphization
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct MonomorphizationResult {
     /// Number of functions specialized
     pub functions_specialized: usize,
     /// Number of classes specialized
     pub classes_specialized: usize,
+    /// Number of type-erased fallbacks created for generics over budget
+    pub functions_erased: usize,
     /// Number of call sites rewritten
     pub call_sites_rewritten: usize,
+    /// Warnings about generics that exceeded the instantiation budget
+    pub warnings: Vec<String>,
 }
 
 /// Perform monomorphization on an IR module
@@ -259,8 +266,10 @@ pub fn monomorphize(
     ir_module: &mut IrModule,
     type_ctx: &TypeContext,
     interner: &Interner,
+    instantiation_budget: usize,
 ) -> MonomorphizationResult {
-    let mut monomorphizer = Monomorphizer::new(type_ctx, interner);
+    let mut monomorphizer =
+        Monomorphizer::new(type_ctx, interner).with_instantiation_budget(instantiation_budget);
     monomorphizer.monomorphize(ir_module)
 }
 
@@ -8,7 +8,7 @@ use super::rewrite::CallSiteRewriter;
 use super::substitute::TypeSubstitution;
 use super::{
     InstantiationKind, MonoKey, MonomorphizationContext, MonomorphizationResult,
-    PendingInstantiation,
+    PendingInstantiation, DEFAULT_INSTANTIATION_BUDGET,
 };
 use crate::compiler::ir::instr::{NominalTypeId, FunctionId};
 use crate::compiler::ir::module::{IrClass, IrField, IrModule};
@@ -27,6 +27,14 @@ pub struct Monomorphizer<'a> {
     generic_functions: FxHashMap<FunctionId, GenericFunctionInfo>,
     /// Generic class definitions
     generic_classes: FxHashMap<NominalTypeId, GenericClassInfo>,
+    /// Maximum number of distinct specializations per generic function
+    instantiation_budget: usize,
+    /// Distinct specializations created so far, per generic function
+    instantiation_counts: FxHashMap<FunctionId, usize>,
+    /// Type-erased fallback specialization, per generic function over budget
+    erased_functions: FxHashMap<FunctionId, FunctionId>,
+    /// Warnings produced while specializing
+    warnings: Vec<String>,
     /// Statistics
     functions_specialized: usize,
     classes_specialized: usize,
@@ -41,11 +49,24 @@ impl<'a> Monomorphizer<'a> {
             ctx: MonomorphizationContext::new(),
             generic_functions: FxHashMap::default(),
             generic_classes: FxHashMap::default(),
+            instantiation_budget: DEFAULT_INSTANTIATION_BUDGET,
+            instantiation_counts: FxHashMap::default(),
+            erased_functions: FxHashMap::default(),
+            warnings: Vec::new(),
             functions_specialized: 0,
             classes_specialized: 0,
         }
     }
 
+    /// Set the maximum number of distinct specializations per generic function.
+    ///
+    /// Instantiations past the budget share a single type-erased specialization
+    /// instead of each getting their own copy of the function body.
+    pub fn with_instantiation_budget(mut self, budget: usize) -> Self {
+        self.instantiation_budget = budget;
+        self
+    }
+
     /// Register a generic function
     pub fn register_generic_function(&mut self, info: GenericFunctionInfo) {
         self.generic_functions.insert(info.func_id, info);
@@ -89,7 +110,9 @@ impl<'a> Monomorphizer<'a> {
         MonomorphizationResult {
             functions_specialized: self.functions_specialized,
             classes_specialized: self.classes_specialized,
+            functions_erased: self.erased_functions.len(),
             call_sites_rewritten,
+            warnings: std::mem::take(&mut self.warnings),
         }
     }
 
@@ -199,6 +222,19 @@ impl<'a> Monomorphizer<'a> {
             return;
         }
 
+        // Past the budget, route the instantiation to the shared erased copy
+        let count = self
+            .instantiation_counts
+            .get(&func_id)
+            .copied()
+            .unwrap_or(0);
+        if count >= self.instantiation_budget {
+            if let Some(erased_id) = self.erased_function(module, func_id) {
+                self.ctx.register_function(key.clone(), erased_id);
+            }
+            return;
+        }
+
         // Get the generic function
         let generic_func = match module.get_function(func_id) {
             Some(f) => f.clone(),
@@ -228,11 +264,51 @@ impl<'a> Monomorphizer<'a> {
         // Register the specialization
         self.ctx.register_function(key.clone(), new_id);
         self.functions_specialized += 1;
+        *self.instantiation_counts.entry(func_id).or_insert(0) += 1;
 
         // Check for any nested generic calls in the specialized function
         self.discover_nested_instantiations(module, new_id);
     }
 
+    /// Get or create the type-erased specialization of a generic function
+    ///
+    /// Every type parameter is substituted with `unknown`, so the body works on
+    /// boxed values regardless of the caller's type arguments. Creating it emits
+    /// a warning naming the function that exceeded the budget.
+    fn erased_function(
+        &mut self,
+        module: &mut IrModule,
+        func_id: FunctionId,
+    ) -> Option<FunctionId> {
+        if let Some(&erased_id) = self.erased_functions.get(&func_id) {
+            return Some(erased_id);
+        }
+
+        let generic_func = module.get_function(func_id)?.clone();
+        let type_params = self
+            .generic_functions
+            .get(&func_id)
+            .map(|info| &info.type_params)
+            .cloned()
+            .unwrap_or_default();
+
+        let erased_args = vec![TypeId::new(TypeContext::UNKNOWN_TYPE_ID); type_params.len()];
+        let substitution = TypeSubstitution::from_params_and_args(&type_params, &erased_args);
+        let mut erased = substitution.apply_function(&generic_func);
+        erased.name = format!("{}__mono_erased", generic_func.name);
+
+        let erased_id = module.add_function(erased);
+        self.erased_functions.insert(func_id, erased_id);
+        self.warnings.push(format!(
+            "generic function '{}' exceeded the instantiation budget of {}; \
+             further instantiations use a type-erased fallback",
+            generic_func.name, self.instantiation_budget
+        ));
+
+        self.discover_nested_instantiations(module, erased_id);
+        Some(erased_id)
+    }
+
     /// Specialize a generic class
     fn specialize_class(&mut self, module: &mut IrModule, key: &MonoKey, nominal_type_id: NominalTypeId) {
         // Check if already specialized
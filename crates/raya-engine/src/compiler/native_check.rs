@@ -0,0 +1,131 @@
+//! Native call verification
+//!
+//! Lowering resolves each `__NATIVE_CALL` either to an engine native id
+//! (`NativeCall`) or to an entry in the module's `native_functions` name table
+//! (`ModuleNativeCall`), which the VM links against its native registry at
+//! load time. Without this check a misspelled name or an unresolved id
+//! constant only shows up as an "unknown native" error when the module is
+//! loaded or the call runs.
+
+use super::error::{CompileError, CompileResult};
+use super::ir::{IrInstr, IrModule};
+use crate::vm::builtin::is_known_native_id;
+use rustc_hash::FxHashSet;
+
+/// Verify that every native call in `module` refers to a registered native
+///
+/// - `NativeCall` ids must belong to a family the VM dispatches; lowering
+///   falls back to id 0 when the id constant cannot be resolved.
+/// - `ModuleNativeCall` indices must fall inside `native_functions`.
+/// - When `known_natives` is given, every called name must be in it.
+pub fn verify_native_calls(
+    module: &IrModule,
+    known_natives: Option<&FxHashSet<String>>,
+) -> CompileResult<()> {
+    for func in &module.functions {
+        for block in &func.blocks {
+            for instr in &block.instructions {
+                let unresolved = match instr {
+                    IrInstr::NativeCall { native_id, .. } if !is_known_native_id(*native_id) => {
+                        Some(format!("native id {:#06x}", native_id))
+                    }
+                    IrInstr::ModuleNativeCall { local_idx, .. } => {
+                        match module.native_functions.get(*local_idx as usize) {
+                            Some(name) if known_natives.is_none_or(|k| k.contains(name)) => None,
+                            Some(name) => Some(name.clone()),
+                            None => Some(format!("native table index {}", local_idx)),
+                        }
+                    }
+                    _ => None,
+                };
+                if let Some(name) = unresolved {
+                    return Err(CompileError::UnresolvedNative {
+                        name,
+                        function: func.name.clone(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::ir::{
+        BasicBlock, BasicBlockId, IrFunction, Register, RegisterId, Terminator,
+    };
+    use crate::parser::TypeId;
+
+    fn module_with(instr: IrInstr, native_functions: &[&str]) -> IrModule {
+        let mut func = IrFunction::new("main", vec![], TypeId::new(0));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        block.add_instr(instr);
+        block.set_terminator(Terminator::Return(None));
+        func.add_block(block);
+
+        let mut module = IrModule::new("test");
+        module.add_function(func);
+        module.native_functions = native_functions.iter().map(|s| s.to_string()).collect();
+        module
+    }
+
+    fn module_call(local_idx: u16) -> IrInstr {
+        IrInstr::ModuleNativeCall {
+            dest: Some(Register::new(RegisterId::new(0), TypeId::new(1))),
+            local_idx,
+            args: vec![],
+        }
+    }
+
+    #[test]
+    fn test_known_module_native_passes() {
+        let module = module_with(module_call(0), &["math.abs"]);
+        let known: FxHashSet<String> = ["math.abs".to_string()].into_iter().collect();
+        assert!(verify_native_calls(&module, Some(&known)).is_ok());
+        assert!(verify_native_calls(&module, None).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_module_native_is_reported() {
+        let module = module_with(module_call(0), &["math.abz"]);
+        let known: FxHashSet<String> = ["math.abs".to_string()].into_iter().collect();
+        match verify_native_calls(&module, Some(&known)) {
+            Err(CompileError::UnresolvedNative { name, function }) => {
+                assert_eq!(name, "math.abz");
+                assert_eq!(function, "main");
+            }
+            other => panic!("expected UnresolvedNative, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_table_index_is_reported() {
+        let module = module_with(module_call(1), &["math.abs"]);
+        assert!(matches!(
+            verify_native_calls(&module, None),
+            Err(CompileError::UnresolvedNative { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unresolved_native_id_is_reported() {
+        let call = |native_id| IrInstr::NativeCall {
+            dest: None,
+            native_id,
+            args: vec![],
+        };
+        assert!(verify_native_calls(&module_with(call(0x0100), &[]), None).is_ok());
+        assert!(matches!(
+            verify_native_calls(&module_with(call(0), &[]), None),
+            Err(CompileError::UnresolvedNative { .. })
+        ));
+        match verify_native_calls(&module_with(call(0x7000), &[]), None) {
+            Err(CompileError::UnresolvedNative { name, .. }) => {
+                assert_eq!(name, "native id 0x7000")
+            }
+            other => panic!("expected UnresolvedNative, got {:?}", other),
+        }
+    }
+}
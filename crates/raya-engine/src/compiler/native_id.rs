@@ -23,6 +23,18 @@ pub const OBJECT_NEW: u16 = 0x0009;
 pub const OBJECT_GET_AMBIENT_GLOBAL: u16 = 0x000A;
 /// instanceof(obj, runtimeClassValue) using hidden type handles on imported/ambient classes
 pub const OBJECT_INSTANCE_OF_DYNAMIC_CLASS: u16 = 0x000B;
+/// keys(obj) -> own enumerable property names
+pub const OBJECT_KEYS: u16 = 0x000C;
+/// values(obj) -> own enumerable property values
+pub const OBJECT_VALUES: u16 = 0x000D;
+/// entries(obj) -> own enumerable [key, value] pairs
+pub const OBJECT_ENTRIES: u16 = 0x000E;
+/// freeze(obj) -> obj, shallowly frozen against field stores
+pub const OBJECT_FREEZE: u16 = 0x000F;
+/// isFrozen(obj) -> whether obj is frozen
+pub const OBJECT_IS_FROZEN: u16 = 0x0010;
+/// deepFreeze(obj) -> obj, with every reachable object frozen
+pub const OBJECT_DEEP_FREEZE: u16 = 0x0011;
 
 // ============================================================================
 // Array (0x01xx) - Must match raya-core/src/builtin.rs
@@ -131,6 +143,24 @@ pub const BUFFER_SLICE: u16 = 0x0708;
 pub const BUFFER_COPY: u16 = 0x0709;
 pub const BUFFER_TO_STRING: u16 = 0x070A;
 pub const BUFFER_FROM_STRING: u16 = 0x070B;
+pub const BUFFER_GET_UINT8: u16 = 0x070C;
+pub const BUFFER_SET_UINT8: u16 = 0x070D;
+pub const BUFFER_GET_INT16_LE: u16 = 0x070E;
+pub const BUFFER_GET_INT16_BE: u16 = 0x070F;
+pub const BUFFER_SET_INT16_LE: u16 = 0x0710;
+pub const BUFFER_SET_INT16_BE: u16 = 0x0711;
+pub const BUFFER_GET_UINT16_LE: u16 = 0x0712;
+pub const BUFFER_GET_UINT16_BE: u16 = 0x0713;
+pub const BUFFER_SET_UINT16_LE: u16 = 0x0714;
+pub const BUFFER_SET_UINT16_BE: u16 = 0x0715;
+pub const BUFFER_GET_INT32_LE: u16 = 0x0716;
+pub const BUFFER_GET_INT32_BE: u16 = 0x0717;
+pub const BUFFER_SET_INT32_LE: u16 = 0x0718;
+pub const BUFFER_SET_INT32_BE: u16 = 0x0719;
+pub const BUFFER_GET_FLOAT64_LE: u16 = 0x071A;
+pub const BUFFER_GET_FLOAT64_BE: u16 = 0x071B;
+pub const BUFFER_SET_FLOAT64_LE: u16 = 0x071C;
+pub const BUFFER_SET_FLOAT64_BE: u16 = 0x071D;
 
 // ============================================================================
 // Map (0x08xx)
@@ -226,6 +256,57 @@ pub const LOGGER_WARN: u16 = 0x1002;
 /// logger.error(...args) - Print error to stderr
 pub const LOGGER_ERROR: u16 = 0x1003;
 
+// ============================================================================
+// Random (0x11xx)
+// ============================================================================
+
+/// new Random(seed) - Create seeded generator
+pub const RANDOM_NEW: u16 = 0x1100;
+/// rng.nextInt(max) - Uniform integer in [0, max)
+pub const RANDOM_NEXT_INT: u16 = 0x1101;
+/// rng.nextFloat() - Uniform float in [0, 1)
+pub const RANDOM_NEXT_FLOAT: u16 = 0x1102;
+
+// ============================================================================
+// WeakRef / WeakMap (0x12xx)
+// ============================================================================
+
+/// new WeakRef(target) - Create weak reference handle
+pub const WEAK_REF_NEW: u16 = 0x1200;
+/// ref.deref() - Get target, or null once collected
+pub const WEAK_REF_DEREF: u16 = 0x1201;
+/// new WeakMap() - Create weak map handle
+pub const WEAK_MAP_NEW: u16 = 0x1210;
+/// map.get(key) - Get value for key, or null
+pub const WEAK_MAP_GET: u16 = 0x1211;
+/// map.set(key, value) - Set value for key
+pub const WEAK_MAP_SET: u16 = 0x1212;
+/// map.has(key) - Check if key exists
+pub const WEAK_MAP_HAS: u16 = 0x1213;
+/// map.delete(key) - Remove key
+pub const WEAK_MAP_DELETE: u16 = 0x1214;
+
+// ============================================================================
+// BigInt (0x13xx)
+// ============================================================================
+
+/// new BigInt(digits) - Parse decimal digits
+pub const BIGINT_NEW: u16 = 0x1300;
+/// a.add(b) - Sum
+pub const BIGINT_ADD: u16 = 0x1301;
+/// a.sub(b) - Difference
+pub const BIGINT_SUB: u16 = 0x1302;
+/// a.mul(b) - Product
+pub const BIGINT_MUL: u16 = 0x1303;
+/// a.div(b) - Quotient, truncated toward zero
+pub const BIGINT_DIV: u16 = 0x1304;
+/// a.mod(b) - Remainder
+pub const BIGINT_MOD: u16 = 0x1305;
+/// a.pow(exp) - Exponentiation
+pub const BIGINT_POW: u16 = 0x1306;
+/// a.compareTo(b) - Ordering as -1, 0, or 1
+pub const BIGINT_COMPARE_TO: u16 = 0x1307;
+
 // ============================================================================
 // Math (0x20xx) - std:math module
 // ============================================================================
@@ -514,6 +595,12 @@ pub fn native_name(id: u16) -> &'static str {
         OBJECT_NEW => "Object.new",
         OBJECT_GET_AMBIENT_GLOBAL => "Object.getAmbientGlobal",
         OBJECT_INSTANCE_OF_DYNAMIC_CLASS => "Object.instanceOfDynamicClass",
+        OBJECT_KEYS => "Object.keys",
+        OBJECT_VALUES => "Object.values",
+        OBJECT_ENTRIES => "Object.entries",
+        OBJECT_FREEZE => "Object.freeze",
+        OBJECT_IS_FROZEN => "Object.isFrozen",
+        OBJECT_DEEP_FREEZE => "Object.deepFreeze",
 
         // Array
         ARRAY_PUSH => "Array.push",
@@ -647,6 +734,30 @@ pub fn native_name(id: u16) -> &'static str {
         LOGGER_WARN => "logger.warn",
         LOGGER_ERROR => "logger.error",
 
+        // Random
+        RANDOM_NEW => "Random.new",
+        RANDOM_NEXT_INT => "Random.nextInt",
+        RANDOM_NEXT_FLOAT => "Random.nextFloat",
+
+        // WeakRef / WeakMap
+        WEAK_REF_NEW => "WeakRef.new",
+        WEAK_REF_DEREF => "WeakRef.deref",
+        WEAK_MAP_NEW => "WeakMap.new",
+        WEAK_MAP_GET => "WeakMap.get",
+        WEAK_MAP_SET => "WeakMap.set",
+        WEAK_MAP_HAS => "WeakMap.has",
+        WEAK_MAP_DELETE => "WeakMap.delete",
+
+        // BigInt
+        BIGINT_NEW => "BigInt.new",
+        BIGINT_ADD => "BigInt.add",
+        BIGINT_SUB => "BigInt.sub",
+        BIGINT_MUL => "BigInt.mul",
+        BIGINT_DIV => "BigInt.div",
+        BIGINT_MOD => "BigInt.mod",
+        BIGINT_POW => "BigInt.pow",
+        BIGINT_COMPARE_TO => "BigInt.compareTo",
+
         // Crypto
         CRYPTO_HASH => "crypto.hash",
         CRYPTO_HASH_BYTES => "crypto.hashBytes",
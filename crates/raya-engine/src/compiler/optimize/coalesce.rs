@@ -0,0 +1,442 @@
+//! Register Coalescing
+//!
+//! Lowering allocates a fresh register for every temporary, and codegen gives
+//! every distinct register its own local slot. This pass renames registers
+//! whose live ranges never overlap onto a shared register, so codegen emits
+//! far fewer local slots.
+//!
+//! Live ranges come from block-level liveness and are flattened into one
+//! interval per register over the linear instruction order, then assigned by
+//! linear scan. Registers that must keep a stable slot are never shared:
+//! parameters, RefCell and closure-capture registers, registers live on
+//! entry to an exception handler, and registers read without a definition.
+
+use crate::compiler::ir::{BasicBlockId, IrFunction, IrInstr, IrModule, RegisterId};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Register coalescer
+pub struct RegisterCoalescer;
+
+impl RegisterCoalescer {
+    /// Create a new coalescing pass
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Coalesce registers in every function of the module
+    pub fn coalesce(&self, module: &mut IrModule) {
+        for func in &mut module.functions {
+            self.coalesce_function(func);
+        }
+    }
+
+    /// Coalesce registers in a function
+    ///
+    /// Returns the number of registers renamed onto another register.
+    pub fn coalesce_function(&self, func: &mut IrFunction) -> usize {
+        let live_in = self.live_in(func);
+        let pinned = self.pinned_registers(func, &live_in);
+        let intervals = self.live_intervals(func, &live_in, &pinned);
+        let renames = self.assign(intervals);
+        if renames.is_empty() {
+            return 0;
+        }
+
+        for block in &mut func.blocks {
+            for instr in &mut block.instructions {
+                for reg in instr.registers_mut() {
+                    if let Some(&id) = renames.get(&reg.id) {
+                        reg.id = id;
+                    }
+                }
+            }
+            for reg in block.terminator.registers_mut() {
+                if let Some(&id) = renames.get(&reg.id) {
+                    reg.id = id;
+                }
+            }
+        }
+        renames.len()
+    }
+
+    /// Registers that must keep their own slot
+    fn pinned_registers(
+        &self,
+        func: &IrFunction,
+        live_in: &FxHashMap<BasicBlockId, FxHashSet<RegisterId>>,
+    ) -> FxHashSet<RegisterId> {
+        let mut pinned: FxHashSet<RegisterId> = func.params.iter().map(|p| p.id).collect();
+        let mut defined = FxHashSet::default();
+        let mut handlers = Vec::new();
+
+        for block in func.blocks() {
+            for instr in &block.instructions {
+                match instr {
+                    IrInstr::NewRefCell { dest, .. } => {
+                        pinned.insert(dest.id);
+                    }
+                    IrInstr::LoadRefCell { refcell, .. }
+                    | IrInstr::StoreRefCell { refcell, .. } => {
+                        pinned.insert(refcell.id);
+                    }
+                    IrInstr::MakeClosure { dest, captures, .. } => {
+                        pinned.insert(dest.id);
+                        pinned.extend(captures.iter().map(|c| c.id));
+                    }
+                    IrInstr::SetClosureCapture { closure, value, .. } => {
+                        pinned.insert(closure.id);
+                        pinned.insert(value.id);
+                    }
+                    IrInstr::SetupTry {
+                        catch_block,
+                        finally_block,
+                    } => {
+                        handlers.push(*catch_block);
+                        handlers.extend(*finally_block);
+                    }
+                    _ => {}
+                }
+                if let Some(dest) = instr.dest() {
+                    defined.insert(dest.id);
+                }
+            }
+        }
+
+        // A register read without any definition reads whatever its slot holds,
+        // so sharing that slot would change the value it observes
+        for block in func.blocks() {
+            let uses = block
+                .instructions
+                .iter()
+                .flat_map(|instr| instr.used_registers())
+                .chain(block.terminator.used_registers());
+            for reg in uses {
+                if !defined.contains(&reg.id) {
+                    pinned.insert(reg.id);
+                }
+            }
+        }
+
+        // Exceptions can enter a handler from anywhere in the protected region,
+        // which block-level liveness does not model
+        for handler in handlers {
+            if let Some(live) = live_in.get(&handler) {
+                pinned.extend(live.iter().copied());
+            }
+        }
+
+        pinned
+    }
+
+    /// Compute the registers live on entry to each block
+    fn live_in(&self, func: &IrFunction) -> FxHashMap<BasicBlockId, FxHashSet<RegisterId>> {
+        // Upward-exposed uses and definitions per block
+        let mut uses: FxHashMap<BasicBlockId, FxHashSet<RegisterId>> = FxHashMap::default();
+        let mut defs: FxHashMap<BasicBlockId, FxHashSet<RegisterId>> = FxHashMap::default();
+        for block in func.blocks() {
+            let block_uses = uses.entry(block.id).or_default();
+            let block_defs = defs.entry(block.id).or_default();
+            for instr in &block.instructions {
+                for reg in instr.used_registers() {
+                    if !block_defs.contains(&reg.id) {
+                        block_uses.insert(reg.id);
+                    }
+                }
+                if let Some(dest) = instr.dest() {
+                    block_defs.insert(dest.id);
+                }
+            }
+            for reg in block.terminator.used_registers() {
+                if !block_defs.contains(&reg.id) {
+                    block_uses.insert(reg.id);
+                }
+            }
+        }
+
+        let mut live_in: FxHashMap<BasicBlockId, FxHashSet<RegisterId>> = FxHashMap::default();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for block in func.blocks.iter().rev() {
+                let mut live: FxHashSet<RegisterId> = block
+                    .successors()
+                    .iter()
+                    .filter_map(|succ| live_in.get(succ))
+                    .flatten()
+                    .copied()
+                    .collect();
+                live.retain(|reg| !defs[&block.id].contains(reg));
+                live.extend(uses[&block.id].iter().copied());
+
+                let entry = live_in.entry(block.id).or_default();
+                if live.len() != entry.len() {
+                    *entry = live;
+                    changed = true;
+                }
+            }
+        }
+        live_in
+    }
+
+    /// Build one conservative `[start, end]` interval per unpinned register
+    /// over the linear instruction order
+    fn live_intervals(
+        &self,
+        func: &IrFunction,
+        live_in: &FxHashMap<BasicBlockId, FxHashSet<RegisterId>>,
+        pinned: &FxHashSet<RegisterId>,
+    ) -> Vec<(usize, usize, RegisterId)> {
+        let mut ranges: FxHashMap<RegisterId, (usize, usize)> = FxHashMap::default();
+        let mut extend = |reg: RegisterId, pos: usize| {
+            if pinned.contains(&reg) {
+                return;
+            }
+            let range = ranges.entry(reg).or_insert((pos, pos));
+            range.0 = range.0.min(pos);
+            range.1 = range.1.max(pos);
+        };
+
+        let mut pos = 0;
+        for block in func.blocks() {
+            let start = pos;
+            for instr in &block.instructions {
+                for reg in instr.used_registers() {
+                    extend(reg.id, pos);
+                }
+                if let Some(dest) = instr.dest() {
+                    extend(dest.id, pos);
+                }
+                pos += 1;
+            }
+            for reg in block.terminator.used_registers() {
+                extend(reg.id, pos);
+            }
+            let end = pos;
+            pos += 1;
+
+            // Values live across the block boundary cover the whole block edge
+            for reg in &live_in[&block.id] {
+                extend(*reg, start);
+            }
+            let live_out = block
+                .successors()
+                .into_iter()
+                .filter_map(|succ| live_in.get(&succ))
+                .flatten()
+                .copied()
+                .collect::<Vec<_>>();
+            for reg in live_out {
+                extend(reg, end);
+            }
+        }
+
+        let mut intervals: Vec<_> = ranges
+            .into_iter()
+            .map(|(reg, (start, end))| (start, end, reg))
+            .collect();
+        intervals.sort_unstable_by_key(|&(start, end, reg)| (start, end, reg.as_u32()));
+        intervals
+    }
+
+    /// Linear-scan assignment: each interval reuses a register whose interval
+    /// has already ended, or keeps its own id. Returns the renames to apply.
+    fn assign(
+        &self,
+        intervals: Vec<(usize, usize, RegisterId)>,
+    ) -> FxHashMap<RegisterId, RegisterId> {
+        let mut renames = FxHashMap::default();
+        let mut active: Vec<(usize, RegisterId)> = Vec::new();
+        let mut free: Vec<RegisterId> = Vec::new();
+
+        for (start, end, reg) in intervals {
+            // An interval ending at `start` is still read by the instruction
+            // that begins this one, so only strictly earlier intervals expire
+            active.retain(|&(active_end, color)| {
+                if active_end < start {
+                    free.push(color);
+                    false
+                } else {
+                    true
+                }
+            });
+
+            let color = free.pop().unwrap_or(reg);
+            if color != reg {
+                renames.insert(reg, color);
+            }
+            active.push((end, color));
+        }
+        renames
+    }
+}
+
+impl Default for RegisterCoalescer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::ir::{BasicBlock, BinaryOp, IrConstant, IrValue, Register, Terminator};
+    use crate::parser::TypeId;
+
+    fn make_reg(id: u32) -> Register {
+        Register::new(RegisterId::new(id), TypeId::new(16))
+    }
+
+    fn distinct_registers(func: &IrFunction) -> usize {
+        let mut ids = FxHashSet::default();
+        for block in func.blocks() {
+            for instr in &block.instructions {
+                ids.extend(instr.used_registers().into_iter().map(|r| r.id));
+                ids.extend(instr.dest().map(|r| r.id));
+            }
+            ids.extend(block.terminator.used_registers().into_iter().map(|r| r.id));
+        }
+        ids.len()
+    }
+
+    /// `acc = param; repeat n times: t1 = 1; t2 = acc + t1; acc = t2; return acc`
+    fn make_chain(n: u32) -> IrFunction {
+        let mut func = IrFunction::new("chain", vec![make_reg(0)], TypeId::new(16));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        let mut acc = make_reg(0);
+        let mut next = 1;
+        for _ in 0..n {
+            let one = make_reg(next);
+            let sum = make_reg(next + 1);
+            next += 2;
+            block.add_instr(IrInstr::Assign {
+                dest: one.clone(),
+                value: IrValue::Constant(IrConstant::I32(1)),
+            });
+            block.add_instr(IrInstr::BinaryOp {
+                dest: sum.clone(),
+                op: BinaryOp::Add,
+                left: acc,
+                right: one,
+            });
+            acc = sum;
+        }
+        block.set_terminator(Terminator::Return(Some(acc)));
+        func.add_block(block);
+        func
+    }
+
+    #[test]
+    fn test_short_lived_temporaries_share_registers() {
+        let mut func = make_chain(50);
+        assert_eq!(distinct_registers(&func), 101);
+
+        RegisterCoalescer::new().coalesce_function(&mut func);
+
+        assert!(distinct_registers(&func) <= 4);
+        // Parameters keep their own register
+        assert_eq!(func.params[0].id, RegisterId::new(0));
+    }
+
+    #[test]
+    fn test_overlapping_registers_are_not_merged() {
+        // r1 and r2 are both live at the add, so they must stay distinct
+        let mut func = IrFunction::new("overlap", vec![], TypeId::new(16));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        block.add_instr(IrInstr::Assign {
+            dest: make_reg(1),
+            value: IrValue::Constant(IrConstant::I32(1)),
+        });
+        block.add_instr(IrInstr::Assign {
+            dest: make_reg(2),
+            value: IrValue::Constant(IrConstant::I32(2)),
+        });
+        block.add_instr(IrInstr::BinaryOp {
+            dest: make_reg(3),
+            op: BinaryOp::Add,
+            left: make_reg(1),
+            right: make_reg(2),
+        });
+        block.set_terminator(Terminator::Return(Some(make_reg(3))));
+        func.add_block(block);
+
+        RegisterCoalescer::new().coalesce_function(&mut func);
+
+        match &func.blocks[0].instructions[2] {
+            IrInstr::BinaryOp { left, right, .. } => assert_ne!(left.id, right.id),
+            other => panic!("unexpected instruction {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_loop_carried_register_is_not_shared() {
+        // bb0: r1 = 0; jump bb1
+        // bb1: r2 = 1; r3 = r1 + r2; branch r3 ? bb1 : bb2
+        // bb2: return r1
+        // r1 is live around the loop, so r2 and r3 must not reuse it
+        let mut func = IrFunction::new("loop", vec![], TypeId::new(16));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.add_instr(IrInstr::Assign {
+            dest: make_reg(1),
+            value: IrValue::Constant(IrConstant::I32(0)),
+        });
+        entry.set_terminator(Terminator::Jump(BasicBlockId(1)));
+        let mut body = BasicBlock::new(BasicBlockId(1));
+        body.add_instr(IrInstr::Assign {
+            dest: make_reg(2),
+            value: IrValue::Constant(IrConstant::I32(1)),
+        });
+        body.add_instr(IrInstr::BinaryOp {
+            dest: make_reg(3),
+            op: BinaryOp::Add,
+            left: make_reg(1),
+            right: make_reg(2),
+        });
+        body.set_terminator(Terminator::Branch {
+            cond: make_reg(3),
+            then_block: BasicBlockId(1),
+            else_block: BasicBlockId(2),
+        });
+        let mut exit = BasicBlock::new(BasicBlockId(2));
+        exit.set_terminator(Terminator::Return(Some(make_reg(1))));
+        func.add_block(entry);
+        func.add_block(body);
+        func.add_block(exit);
+
+        RegisterCoalescer::new().coalesce_function(&mut func);
+
+        let loop_carried = match &func.blocks[2].terminator {
+            Terminator::Return(Some(reg)) => reg.id,
+            other => panic!("unexpected terminator {:?}", other),
+        };
+        for instr in &func.blocks[1].instructions {
+            assert_ne!(instr.dest().unwrap().id, loop_carried);
+        }
+    }
+
+    #[test]
+    fn test_refcell_registers_are_pinned() {
+        let mut func = IrFunction::new("cells", vec![], TypeId::new(16));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        block.add_instr(IrInstr::Assign {
+            dest: make_reg(1),
+            value: IrValue::Constant(IrConstant::I32(1)),
+        });
+        block.add_instr(IrInstr::NewRefCell {
+            dest: make_reg(2),
+            initial_value: make_reg(1),
+        });
+        block.add_instr(IrInstr::StoreLocal {
+            index: 0,
+            value: make_reg(2),
+        });
+        block.set_terminator(Terminator::Return(None));
+        func.add_block(block);
+
+        RegisterCoalescer::new().coalesce_function(&mut func);
+
+        match &func.blocks[0].instructions[1] {
+            IrInstr::NewRefCell { dest, .. } => assert_eq!(dest.id, RegisterId::new(2)),
+            other => panic!("unexpected instruction {:?}", other),
+        }
+    }
+}
@@ -41,6 +41,12 @@ impl ConstantFolder {
     }
 
     /// Fold constants in a basic block
+    ///
+    /// Instructions are rewritten one-for-one, so `instruction_spans` stays
+    /// aligned and a folded value keeps the source span of the operation it
+    /// replaced. Operations that would trap at runtime (integer division or
+    /// remainder by zero) are never folded, so the trap is still raised at the
+    /// original source site.
     fn fold_block(
         &self,
         block: &mut BasicBlock,
@@ -255,7 +261,7 @@ mod tests {
     use crate::compiler::ir::{
         BasicBlock, BasicBlockId, IrFunction, IrModule, Register, Terminator,
     };
-    use crate::parser::TypeId;
+    use crate::parser::{Span, TypeId};
 
     fn make_reg(id: u32) -> Register {
         Register::new(RegisterId::new(id), TypeId::new(1))
@@ -387,4 +393,96 @@ mod tests {
             panic!("Expected folded constant -42");
         }
     }
+
+    fn make_spanned_function(instrs: Vec<(IrInstr, Span)>) -> IrFunction {
+        let mut func = IrFunction::new("test", vec![], TypeId::new(0));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        for (instr, span) in instrs {
+            block.add_instr_spanned(instr, span);
+        }
+        block.set_terminator(Terminator::Return(None));
+        func.add_block(block);
+        func
+    }
+
+    fn const_i32(reg: u32, value: i32) -> IrInstr {
+        IrInstr::Assign {
+            dest: make_reg(reg),
+            value: IrValue::Constant(IrConstant::I32(value)),
+        }
+    }
+
+    #[test]
+    fn test_does_not_fold_trapping_integer_division() {
+        let folder = ConstantFolder::new();
+
+        for op in [BinaryOp::Div, BinaryOp::Mod] {
+            let div_span = Span::new(20, 25, 2, 9);
+            let instrs = vec![
+                (const_i32(0, 1), Span::new(20, 21, 2, 9)),
+                (const_i32(1, 0), Span::new(24, 25, 2, 13)),
+                (
+                    IrInstr::BinaryOp {
+                        dest: make_reg(2),
+                        op,
+                        left: make_reg(0),
+                        right: make_reg(1),
+                    },
+                    div_span,
+                ),
+            ];
+
+            let mut module = IrModule::new("test");
+            module.add_function(make_spanned_function(instrs));
+            folder.fold(&mut module);
+
+            let func = module.get_function(crate::ir::FunctionId::new(0)).unwrap();
+            let block = func.get_block(BasicBlockId(0)).unwrap();
+            assert!(
+                matches!(block.instructions[2], IrInstr::BinaryOp { .. }),
+                "{:?} by zero must be left for the runtime to trap",
+                op
+            );
+            assert_eq!(block.instruction_spans[2], div_span);
+        }
+    }
+
+    #[test]
+    fn test_fold_preserves_instruction_spans() {
+        let folder = ConstantFolder::new();
+
+        let spans = [
+            Span::new(8, 9, 1, 9),
+            Span::new(12, 13, 1, 13),
+            Span::new(8, 13, 1, 9),
+        ];
+        let instrs = vec![
+            (const_i32(0, 6), spans[0]),
+            (const_i32(1, 3), spans[1]),
+            (
+                IrInstr::BinaryOp {
+                    dest: make_reg(2),
+                    op: BinaryOp::Div,
+                    left: make_reg(0),
+                    right: make_reg(1),
+                },
+                spans[2],
+            ),
+        ];
+
+        let mut module = IrModule::new("test");
+        module.add_function(make_spanned_function(instrs));
+        folder.fold(&mut module);
+
+        let func = module.get_function(crate::ir::FunctionId::new(0)).unwrap();
+        let block = func.get_block(BasicBlockId(0)).unwrap();
+        assert!(matches!(
+            block.instructions[2],
+            IrInstr::Assign {
+                value: IrValue::Constant(IrConstant::I32(2)),
+                ..
+            }
+        ));
+        assert_eq!(block.instruction_spans, spans);
+    }
 }
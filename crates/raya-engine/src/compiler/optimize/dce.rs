@@ -2,7 +2,7 @@
 //!
 //! Removes instructions whose results are never used.
 
-use crate::compiler::ir::{IrFunction, IrInstr, IrModule, IrValue, RegisterId, Terminator};
+use crate::compiler::ir::{IrFunction, IrInstr, IrModule, RegisterId, Terminator};
 use rustc_hash::FxHashSet;
 
 /// Dead code eliminator
@@ -52,268 +52,12 @@ impl DeadCodeEliminator {
 
     /// Collect register uses from an instruction
     fn collect_instruction_uses(&self, instr: &IrInstr, used: &mut FxHashSet<RegisterId>) {
-        match instr {
-            IrInstr::Assign { value, .. } => {
-                if let IrValue::Register(reg) = value {
-                    used.insert(reg.id);
-                }
-            }
-            IrInstr::BinaryOp { left, right, .. } => {
-                used.insert(left.id);
-                used.insert(right.id);
-            }
-            IrInstr::UnaryOp { operand, .. } => {
-                used.insert(operand.id);
-            }
-            IrInstr::Call { args, .. } => {
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::ConstructType { object, args, .. } => {
-                used.insert(object.id);
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::CallMethodExact { object, args, .. } => {
-                used.insert(object.id);
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::NativeCall { args, .. } | IrInstr::ModuleNativeCall { args, .. } => {
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::IsNominal { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::ImplementsShape { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::CastNominal { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::CastShape { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::CastTupleLen { object, .. }
-            | IrInstr::CastObjectMinFields { object, .. }
-            | IrInstr::CastArrayElemKind { object, .. }
-            | IrInstr::CastKindMask { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::StoreLocal { value, .. } => {
-                used.insert(value.id);
-            }
-            IrInstr::LoadFieldExact { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::LoadFieldShape { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::CallMethodShape { object, args, .. } => {
-                used.insert(object.id);
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::StoreFieldExact { object, value, .. } => {
-                used.insert(object.id);
-                used.insert(value.id);
-            }
-            IrInstr::StoreFieldShape { object, value, .. } => {
-                used.insert(object.id);
-                used.insert(value.id);
-            }
-            IrInstr::DynGetProp { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::DynGetKeyed { object, key, .. } => {
-                used.insert(object.id);
-                used.insert(key.id);
-            }
-            IrInstr::DynSetProp { object, value, .. } => {
-                used.insert(object.id);
-                used.insert(value.id);
-            }
-            IrInstr::DynSetKeyed { object, key, value } => {
-                used.insert(object.id);
-                used.insert(key.id);
-                used.insert(value.id);
-            }
-            IrInstr::LateBoundMember { object, .. } => {
-                used.insert(object.id);
-            }
-            IrInstr::LoadElement { array, index, .. } => {
-                used.insert(array.id);
-                used.insert(index.id);
-            }
-            IrInstr::StoreElement {
-                array,
-                index,
-                value,
-            } => {
-                used.insert(array.id);
-                used.insert(index.id);
-                used.insert(value.id);
-            }
-            IrInstr::NewArray { len, .. } => {
-                used.insert(len.id);
-            }
-            IrInstr::ArrayLiteral { elements, .. } => {
-                for elem in elements {
-                    used.insert(elem.id);
-                }
-            }
-            IrInstr::ObjectLiteral { fields, .. } => {
-                for (_, value) in fields {
-                    used.insert(value.id);
-                }
-            }
-            IrInstr::ArrayLen { array, .. } => {
-                used.insert(array.id);
-            }
-            IrInstr::ArrayPush { array, element } => {
-                used.insert(array.id);
-                used.insert(element.id);
-            }
-            IrInstr::ArrayPop { array, .. } => {
-                used.insert(array.id);
-            }
-            IrInstr::StringLen { string, .. } => {
-                used.insert(string.id);
-            }
-            IrInstr::Typeof { operand, .. } => {
-                used.insert(operand.id);
-            }
-            IrInstr::Phi { sources, .. } => {
-                for (_, reg) in sources {
-                    used.insert(reg.id);
-                }
-            }
-            IrInstr::MakeClosure { captures, .. } => {
-                for capture in captures {
-                    used.insert(capture.id);
-                }
-            }
-            IrInstr::StoreCaptured { value, .. } => {
-                used.insert(value.id);
-            }
-            IrInstr::SetClosureCapture { closure, value, .. } => {
-                used.insert(closure.id);
-                used.insert(value.id);
-            }
-            IrInstr::NewRefCell { initial_value, .. } => {
-                used.insert(initial_value.id);
-            }
-            IrInstr::LoadRefCell { refcell, .. } => {
-                used.insert(refcell.id);
-            }
-            IrInstr::StoreRefCell { refcell, value } => {
-                used.insert(refcell.id);
-                used.insert(value.id);
-            }
-            IrInstr::CallClosure { closure, args, .. } => {
-                used.insert(closure.id);
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::LoadLocal { .. }
-            | IrInstr::LoadArgCount { .. }
-            | IrInstr::NewType { .. }
-            | IrInstr::LoadCaptured { .. }
-            | IrInstr::LoadGlobal { .. } => {
-                // No register uses
-            }
-            IrInstr::LoadArgLocal { index, .. } => {
-                // LoadArgLocal uses the index register
-                used.insert(index.id);
-            }
-            IrInstr::StoreGlobal { value, .. } => {
-                used.insert(value.id);
-            }
-            IrInstr::StringCompare { left, right, .. } => {
-                used.insert(left.id);
-                used.insert(right.id);
-            }
-            IrInstr::ToString { operand, .. } => {
-                used.insert(operand.id);
-            }
-            IrInstr::Spawn { args, .. } => {
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::SpawnClosure { closure, args, .. } => {
-                used.insert(closure.id);
-                for arg in args {
-                    used.insert(arg.id);
-                }
-            }
-            IrInstr::Await { task, .. } => {
-                used.insert(task.id);
-            }
-            IrInstr::AwaitAll { tasks, .. } => {
-                used.insert(tasks.id);
-            }
-            IrInstr::Sleep { duration_ms } => {
-                used.insert(duration_ms.id);
-            }
-            IrInstr::Yield => {
-                // No register uses
-            }
-            IrInstr::Debugger => {
-                // No register uses
-            }
-            IrInstr::NewMutex { .. } => {
-                // Creates a mutex, dest is handled by dest() method
-            }
-            IrInstr::NewChannel { capacity, .. } => {
-                // Uses capacity register
-                used.insert(capacity.id);
-            }
-            IrInstr::MutexLock { mutex } => {
-                used.insert(mutex.id);
-            }
-            IrInstr::MutexUnlock { mutex } => {
-                used.insert(mutex.id);
-            }
-            IrInstr::TaskCancel { task } => {
-                used.insert(task.id);
-            }
-            IrInstr::SetupTry { .. } | IrInstr::EndTry | IrInstr::PopToLocal { .. } => {
-                // No register uses
-            }
-            IrInstr::BindMethod { object, .. } => {
-                used.insert(object.id);
-            }
-        }
+        used.extend(instr.used_registers().into_iter().map(|reg| reg.id));
     }
 
     /// Collect register uses from a terminator
     fn collect_terminator_uses(&self, term: &Terminator, used: &mut FxHashSet<RegisterId>) {
-        match term {
-            Terminator::Branch { cond, .. } => {
-                used.insert(cond.id);
-            }
-            Terminator::BranchIfNull { value, .. } => {
-                used.insert(value.id);
-            }
-            Terminator::Return(Some(reg)) => {
-                used.insert(reg.id);
-            }
-            Terminator::Switch { value, .. } => {
-                used.insert(value.id);
-            }
-            Terminator::Throw(reg) => {
-                used.insert(reg.id);
-            }
-            Terminator::Jump(_) | Terminator::Return(None) | Terminator::Unreachable => {}
-        }
+        used.extend(term.used_registers().into_iter().map(|reg| reg.id));
     }
 
     /// Remove dead instructions from all blocks
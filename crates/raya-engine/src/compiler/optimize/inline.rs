@@ -1,49 +1,107 @@
 //! Function Inlining Optimization
 //!
-//! Automatically inlines small functions (1-2 instructions) at call sites.
-//! This eliminates function call overhead for trivial wrapper methods like `mutex.lock()`.
-
-use crate::compiler::ir::block::Terminator;
-use crate::compiler::ir::instr::{FunctionId, IrInstr};
-use crate::compiler::ir::value::{IrValue, Register, RegisterId};
-use crate::compiler::ir::{IrFunction, IrModule};
+//! Inlines small functions at their direct call sites. Two kinds of callee
+//! qualify:
+//!
+//! - Trivial bodies: one block of 1-2 instructions ending in a return, such
+//!   as wrapper methods like `mutex.lock()`. These may call out.
+//! - Leaves: functions that make no calls of their own, so they can never
+//!   recurse, of up to 16 instructions over up to 8 blocks. Class methods
+//!   and constructors are not treated as leaves, so decorators and virtual
+//!   dispatch keep seeing the real call.
+//!
+//! A single block ending in a return replaces the call in place. Otherwise
+//! the caller block is split at the call, the callee's blocks are copied in
+//! under fresh block and register ids, and every return assigns the call
+//! destination and jumps to the continuation block.
+//!
+//! Bodies containing `try`/`finally` are never inlined. A call inside the
+//! caller's own `try` stays covered after inlining because handlers are
+//! installed dynamically by `SetupTry`/`EndTry`, not by block layout.
+//! Growth per caller is capped by a budget.
+
+use crate::compiler::ir::{
+    BasicBlock, BasicBlockId, FunctionId, IrConstant, IrFunction, IrInstr, IrModule, IrValue,
+    Register, RegisterId, Terminator,
+};
+use crate::parser::token::Span;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 /// Maximum number of instructions (excluding terminator) for a function to be inlinable
 const MAX_INLINE_INSTRUCTIONS: usize = 2;
 
+/// Maximum instructions (excluding terminators) in an inlinable leaf
+const MAX_LEAF_INSTRUCTIONS: usize = 16;
+
+/// Maximum blocks in an inlinable leaf
+const MAX_LEAF_BLOCKS: usize = 8;
+
+/// Maximum instructions inlining may add to a single caller
+const MAX_GROWTH_PER_FUNCTION: usize = 256;
+
 /// Function inliner
-pub struct Inliner;
+pub struct Inliner {
+    /// Skip trivial bodies and only inline leaves
+    leaves_only: bool,
+}
 
 impl Inliner {
     /// Create a new inliner
     pub fn new() -> Self {
-        Self
+        Self { leaves_only: false }
+    }
+
+    /// Create an inliner that only inlines leaves
+    ///
+    /// Leaves never call out and are never methods or constructors, which
+    /// makes this safe at `OptLevel::Basic`.
+    pub fn leaves_only() -> Self {
+        Self { leaves_only: true }
     }
 
     /// Run inlining on an entire module
-    pub fn inline(&self, module: &mut IrModule) {
+    ///
+    /// Returns the number of call sites inlined.
+    pub fn inline(&self, module: &mut IrModule) -> usize {
         // Phase 1: Find all inlinable functions and cache their bodies
         let inlinable = self.find_inlinable_functions(module);
 
         if inlinable.is_empty() {
-            return;
+            return 0;
         }
 
         // Phase 2: Inline calls in all functions
-        // We need to iterate by index to allow mutable access
-        for func_idx in 0..module.functions.len() {
-            self.inline_calls_in_function(func_idx, &inlinable, module);
+        let mut inlined = 0;
+        for func in &mut module.functions {
+            inlined += self.inline_calls_in_function(func, &inlinable);
         }
+        inlined
     }
 
     /// Find all functions that are candidates for inlining
     fn find_inlinable_functions(&self, module: &IrModule) -> FxHashMap<FunctionId, InlinableBody> {
-        let mut inlinable = FxHashMap::default();
+        let mut dispatched = FxHashSet::default();
+        for class in &module.classes {
+            dispatched.extend(class.methods.iter().copied());
+            dispatched.extend(class.constructor);
+        }
 
+        let mut inlinable = FxHashMap::default();
         for (idx, func) in module.functions.iter().enumerate() {
             let func_id = FunctionId::new(idx as u32);
-            if let Some(body) = self.extract_inlinable_body(func, func_id) {
+            let trivial = if self.leaves_only {
+                None
+            } else {
+                self.extract_inlinable_body(func, func_id)
+            };
+            let body = trivial.or_else(|| {
+                if dispatched.contains(&func_id) {
+                    None
+                } else {
+                    self.extract_leaf_body(func)
+                }
+            });
+            if let Some(body) = body {
                 inlinable.insert(func_id, body);
             }
         }
@@ -51,7 +109,7 @@ impl Inliner {
         inlinable
     }
 
-    /// Check if a function is inlinable and extract its body if so
+    /// Check if a function has a trivial body and extract it if so
     fn extract_inlinable_body(
         &self,
         func: &IrFunction,
@@ -70,10 +128,9 @@ impl Inliner {
         }
 
         // Must have a simple return terminator
-        let return_value = match &block.terminator {
-            Terminator::Return(ret) => ret.clone(),
-            _ => return None,
-        };
+        if !matches!(block.terminator, Terminator::Return(_)) {
+            return None;
+        }
 
         // Check that instructions don't contain problematic patterns
         let param_count = func.params.len();
@@ -83,16 +140,11 @@ impl Inliner {
             }
         }
 
-        Some(InlinableBody {
-            params: func.params.clone(),
-            param_count: func.params.len(),
-            instructions: block.instructions.clone(),
-            return_value,
-        })
+        Some(InlinableBody::of(func))
     }
 
     /// Check if an instruction can be inlined.
-    /// Whitelist approach: only allow instructions the renamer knows how to handle.
+    /// Whitelist approach: only allow instructions that are safe to copy.
     fn is_inlinable_instruction(
         &self,
         instr: &IrInstr,
@@ -115,7 +167,6 @@ impl Inliner {
             IrInstr::StoreLocal { .. } => false,
             // LoadLocal for non-parameter indices cannot be inlined
             IrInstr::LoadLocal { index, .. } if (*index as usize) >= param_count => false,
-            // Instructions the renamer handles correctly
             IrInstr::Assign { .. }
             | IrInstr::BinaryOp { .. }
             | IrInstr::UnaryOp { .. }
@@ -156,715 +207,442 @@ impl Inliner {
             | IrInstr::Sleep { .. }
             | IrInstr::Yield
             | IrInstr::TaskCancel { .. } => true,
-            // Any other instruction: not safe to inline (renamer doesn't handle it)
+            // Any other instruction: not known to be safe to inline
             _ => false,
         }
     }
 
-    /// Inline calls in a single function
-    fn inline_calls_in_function(
-        &self,
-        func_idx: usize,
-        inlinable: &FxHashMap<FunctionId, InlinableBody>,
-        module: &mut IrModule,
-    ) {
-        // Find the maximum register ID used in this function to allocate fresh registers
-        let mut max_reg_id = self.find_max_register_id(&module.functions[func_idx]);
-
-        // Process each block
-        for block_idx in 0..module.functions[func_idx].blocks.len() {
-            let mut new_instructions = Vec::new();
-            let mut i = 0;
+    /// Check if a function is a small leaf and extract its body if so
+    fn extract_leaf_body(&self, func: &IrFunction) -> Option<InlinableBody> {
+        if func.blocks.is_empty() || func.blocks.len() > MAX_LEAF_BLOCKS {
+            return None;
+        }
+        if func.instruction_count() > MAX_LEAF_INSTRUCTIONS {
+            return None;
+        }
 
-            while i < module.functions[func_idx].blocks[block_idx]
+        let param_count = func.params.len();
+        for block in &func.blocks {
+            let instructions_ok = block
                 .instructions
-                .len()
-            {
-                let instr = &module.functions[func_idx].blocks[block_idx].instructions[i];
-
-                // Check if this is a call to an inlinable function
-                if let IrInstr::Call { dest, func, args } = instr {
-                    if let Some(body) = inlinable.get(func) {
-                        // Only inline if argument count matches parameter count
-                        // This avoids issues with mismatched this/self parameters
-                        if args.len() == body.param_count {
-                            // Inline this call
-                            let inlined =
-                                self.inline_call(dest.clone(), args, body, &mut max_reg_id);
-                            new_instructions.extend(inlined);
-                            i += 1;
-                            continue;
-                        }
-                    }
-                }
-
-                // Keep the original instruction
-                new_instructions
-                    .push(module.functions[func_idx].blocks[block_idx].instructions[i].clone());
-                i += 1;
+                .iter()
+                .all(|instr| Self::is_leaf_instruction(instr, param_count));
+            let terminator_ok = !matches!(block.terminator, Terminator::Unreachable);
+            // A branch back to the entry would need a PHI source for the
+            // caller's incoming edge.
+            let enters_entry = block.successors().contains(&func.entry_block);
+            if !instructions_ok || !terminator_ok || enters_entry {
+                return None;
             }
-
-            // Replace the block's instructions
-            module.functions[func_idx].blocks[block_idx].instructions = new_instructions;
         }
-    }
 
-    /// Find the maximum register ID used in a function
-    fn find_max_register_id(&self, func: &IrFunction) -> u32 {
-        let mut max_id = 0u32;
+        Some(InlinableBody::of(func))
+    }
 
-        // Check params
-        for param in &func.params {
-            max_id = max_id.max(param.id.as_u32());
+    /// Whitelist of instructions that neither call out, touch the caller's
+    /// frame, nor install exception handlers
+    fn is_leaf_instruction(instr: &IrInstr, param_count: usize) -> bool {
+        match instr {
+            // Parameters are read through their local slots; any other slot
+            // would alias the caller's locals.
+            IrInstr::LoadLocal { index, .. } => (*index as usize) < param_count,
+            IrInstr::Assign { .. }
+            | IrInstr::BinaryOp { .. }
+            | IrInstr::UnaryOp { .. }
+            | IrInstr::Phi { .. }
+            | IrInstr::LoadGlobal { .. }
+            | IrInstr::LoadFieldExact { .. }
+            | IrInstr::LoadElement { .. }
+            | IrInstr::ArrayLen { .. }
+            | IrInstr::StringLen { .. }
+            | IrInstr::StringCompare { .. }
+            | IrInstr::Typeof { .. } => true,
+            _ => false,
         }
+    }
 
-        // Check locals
-        for local in &func.locals {
-            max_id = max_id.max(local.id.as_u32());
+    /// Inline eligible call sites in one function, within the growth budget
+    fn inline_calls_in_function(
+        &self,
+        func: &mut IrFunction,
+        inlinable: &FxHashMap<FunctionId, InlinableBody>,
+    ) -> usize {
+        let mut budget = MAX_GROWTH_PER_FUNCTION;
+        let mut next_reg = max_register_id(func) + 1;
+        let mut next_block = func.block_ids().map(|id| id.0).max().unwrap_or(0) + 1;
+        let mut inlined = 0;
+
+        // Blocks to scan, with the position to resume scanning from
+        let mut worklist: Vec<(BasicBlockId, usize)> = func.block_ids().map(|id| (id, 0)).collect();
+        worklist.reverse();
+        while let Some((block_id, start)) = worklist.pop() {
+            let Some(block) = func.get_block(block_id) else {
+                continue;
+            };
+            let Some((pos, body)) = next_site(block, start, inlinable, budget) else {
+                continue;
+            };
+
+            budget -= body.size;
+            inlined += 1;
+            if body.is_straight_line() {
+                let resume = self.splice_call(func, block_id, pos, body, &mut next_reg);
+                // Spliced instructions are not inlined again.
+                worklist.push((block_id, resume));
+            } else {
+                let continuation =
+                    self.split_call(func, block_id, pos, body, &mut next_reg, &mut next_block);
+                // The rest of the original block may hold further calls.
+                worklist.push((continuation, 0));
+            }
         }
+        inlined
+    }
 
-        // Check all instructions
-        for block in &func.blocks {
-            for instr in &block.instructions {
-                if let Some(dest) = instr.dest() {
-                    max_id = max_id.max(dest.id.as_u32());
-                }
-                self.collect_register_ids(instr, &mut |id| {
-                    max_id = max_id.max(id);
-                });
-            }
+    /// Replace the call at `block_id[pos]` with a copy of a straight-line body
+    ///
+    /// Returns the position just past the copied instructions.
+    fn splice_call(
+        &self,
+        func: &mut IrFunction,
+        block_id: BasicBlockId,
+        pos: usize,
+        body: &InlinableBody,
+        next_reg: &mut u32,
+    ) -> usize {
+        let block = func.get_block_mut(block_id).expect("worklist block exists");
+        let IrInstr::Call { dest, args, .. } = block.instructions[pos].clone() else {
+            unreachable!("inline site must be a call");
+        };
+        let mut renamer = Renamer::new(body, &args, next_reg);
+        let callee = &body.blocks[0];
+        let mut copied: Vec<IrInstr> = callee
+            .instructions
+            .iter()
+            .map(|instr| renamer.copy_instr(instr))
+            .collect();
+        if let Terminator::Return(value) = &callee.terminator {
+            copied.extend(renamer.return_value(dest, value.clone()));
         }
 
-        max_id
+        let count = copied.len();
+        if block.instruction_spans.len() == block.instructions.len() {
+            let call_span = block.instruction_spans[pos];
+            block
+                .instruction_spans
+                .splice(pos..=pos, std::iter::repeat_n(call_span, count));
+        }
+        block.instructions.splice(pos..=pos, copied);
+        pos + count
     }
 
-    /// Collect all register IDs used in an instruction
-    fn collect_register_ids<F>(&self, instr: &IrInstr, f: &mut F)
-    where
-        F: FnMut(u32),
-    {
-        match instr {
-            IrInstr::Assign { dest, value } => {
-                f(dest.id.as_u32());
-                if let IrValue::Register(reg) = value {
-                    f(reg.id.as_u32());
-                }
-            }
-            IrInstr::BinaryOp {
-                dest, left, right, ..
-            } => {
-                f(dest.id.as_u32());
-                f(left.id.as_u32());
-                f(right.id.as_u32());
-            }
-            IrInstr::UnaryOp { dest, operand, .. } => {
-                f(dest.id.as_u32());
-                f(operand.id.as_u32());
-            }
-            IrInstr::Call { dest, args, .. } => {
-                if let Some(d) = dest {
-                    f(d.id.as_u32());
-                }
-                for arg in args {
-                    f(arg.id.as_u32());
-                }
-            }
-            IrInstr::LoadLocal { dest, .. } => f(dest.id.as_u32()),
-            IrInstr::StoreLocal { value, .. } => f(value.id.as_u32()),
-            IrInstr::LoadFieldExact { dest, object, .. } => {
-                f(dest.id.as_u32());
-                f(object.id.as_u32());
-            }
-            IrInstr::StoreFieldExact { object, value, .. } => {
-                f(object.id.as_u32());
-                f(value.id.as_u32());
-            }
-            IrInstr::MutexLock { mutex } => f(mutex.id.as_u32()),
-            IrInstr::MutexUnlock { mutex } => f(mutex.id.as_u32()),
-            IrInstr::NewMutex { dest } => f(dest.id.as_u32()),
-            IrInstr::NewChannel { dest, capacity } => {
-                f(dest.id.as_u32());
-                f(capacity.id.as_u32());
-            }
-            IrInstr::Sleep { duration_ms } => f(duration_ms.id.as_u32()),
-            IrInstr::TaskCancel { task } => f(task.id.as_u32()),
-            IrInstr::ArrayLen { dest, array } => {
-                f(dest.id.as_u32());
-                f(array.id.as_u32());
-            }
-            IrInstr::ArrayPush { array, element } => {
-                f(array.id.as_u32());
-                f(element.id.as_u32());
-            }
-            IrInstr::ArrayPop { dest, array } => {
-                f(dest.id.as_u32());
-                f(array.id.as_u32());
-            }
-            IrInstr::StringLen { dest, string } => {
-                f(dest.id.as_u32());
-                f(string.id.as_u32());
-            }
-            IrInstr::StringCompare {
-                dest, left, right, ..
-            } => {
-                f(dest.id.as_u32());
-                f(left.id.as_u32());
-                f(right.id.as_u32());
-            }
-            IrInstr::ToString { dest, operand } => {
-                f(dest.id.as_u32());
-                f(operand.id.as_u32());
-            }
-            IrInstr::Typeof { dest, operand } => {
-                f(dest.id.as_u32());
-                f(operand.id.as_u32());
-            }
-            IrInstr::LoadElement { dest, array, index } => {
-                f(dest.id.as_u32());
-                f(array.id.as_u32());
-                f(index.id.as_u32());
-            }
-            IrInstr::StoreElement {
-                array,
-                index,
-                value,
-            } => {
-                f(array.id.as_u32());
-                f(index.id.as_u32());
-                f(value.id.as_u32());
-            }
-            IrInstr::NativeCall { dest, args, .. }
-            | IrInstr::ModuleNativeCall { dest, args, .. } => {
-                if let Some(d) = dest {
-                    f(d.id.as_u32());
-                }
-                for arg in args {
-                    f(arg.id.as_u32());
+    /// Replace the call at `block_id[pos]` with a copy of a branching body
+    ///
+    /// Returns the id of the continuation block holding the instructions that
+    /// followed the call.
+    fn split_call(
+        &self,
+        func: &mut IrFunction,
+        block_id: BasicBlockId,
+        pos: usize,
+        body: &InlinableBody,
+        next_reg: &mut u32,
+        next_block: &mut u32,
+    ) -> BasicBlockId {
+        let mut fresh_block = || {
+            let id = BasicBlockId(*next_block);
+            *next_block += 1;
+            id
+        };
+        let block_map: FxHashMap<BasicBlockId, BasicBlockId> = body
+            .blocks
+            .iter()
+            .map(|block| (block.id, fresh_block()))
+            .collect();
+        let continuation_id = fresh_block();
+
+        // Split the caller block: everything after the call moves to the
+        // continuation, the call itself is dropped.
+        let block = func.get_block_mut(block_id).expect("worklist block exists");
+        let has_spans = block.instruction_spans.len() == block.instructions.len();
+        let call_span = if has_spans {
+            block.instruction_spans[pos]
+        } else {
+            Span::default()
+        };
+        let tail = block.instructions.split_off(pos + 1);
+        let Some(IrInstr::Call { dest, args, .. }) = block.instructions.pop() else {
+            unreachable!("inline site must be a call");
+        };
+        let tail_spans = if has_spans {
+            let tail_spans = block.instruction_spans.split_off(pos + 1);
+            block.instruction_spans.pop();
+            tail_spans
+        } else {
+            Vec::new()
+        };
+        let original_terminator = std::mem::replace(
+            &mut block.terminator,
+            Terminator::Jump(block_map[&body.entry]),
+        );
+        let original_terminator_span = std::mem::replace(&mut block.terminator_span, call_span);
+
+        // Successor PHIs now receive this edge from the continuation block.
+        for succ in original_terminator.successors() {
+            if let Some(succ_block) = func.get_block_mut(succ) {
+                for instr in &mut succ_block.instructions {
+                    if let IrInstr::Phi { sources, .. } = instr {
+                        for (pred, _) in sources.iter_mut() {
+                            if *pred == block_id {
+                                *pred = continuation_id;
+                            }
+                        }
+                    }
                 }
             }
-            IrInstr::CallMethodExact {
-                dest,
-                object,
-                args: method_args,
-                ..
-            } => {
-                if let Some(d) = dest {
-                    f(d.id.as_u32());
-                }
-                f(object.id.as_u32());
-                for arg in method_args {
-                    f(arg.id.as_u32());
+        }
+
+        let mut renamer = Renamer::new(body, &args, next_reg);
+        for leaf_block in &body.blocks {
+            let mut copy = BasicBlock::new(block_map[&leaf_block.id]);
+            for instr in &leaf_block.instructions {
+                let mut instr = renamer.copy_instr(instr);
+                if let IrInstr::Phi { sources, .. } = &mut instr {
+                    for (pred, _) in sources.iter_mut() {
+                        *pred = block_map[&*pred];
+                    }
                 }
+                push_instr(&mut copy, instr, has_spans, call_span);
             }
-            IrInstr::CallMethodShape {
-                dest,
-                object,
-                args: method_args,
-                ..
-            } => {
-                if let Some(d) = dest {
-                    f(d.id.as_u32());
+
+            copy.terminator = match leaf_block.terminator.clone() {
+                Terminator::Return(value) => {
+                    if let Some(assign) = renamer.return_value(dest.clone(), value) {
+                        push_instr(&mut copy, assign, has_spans, call_span);
+                    }
+                    Terminator::Jump(continuation_id)
                 }
-                f(object.id.as_u32());
-                for arg in method_args {
-                    f(arg.id.as_u32());
+                mut other => {
+                    for reg in other.registers_mut() {
+                        renamer.rename(reg);
+                    }
+                    retarget(other, &block_map)
                 }
-            }
-            IrInstr::StoreGlobal { value, .. } => f(value.id.as_u32()),
-            IrInstr::LoadGlobal { dest, .. } => f(dest.id.as_u32()),
-            // Add other cases as needed; these are the main ones for small inlinable functions
-            _ => {}
+            };
+            copy.terminator_span = call_span;
+            func.add_block(copy);
         }
+
+        let mut continuation = BasicBlock::new(continuation_id);
+        continuation.instructions = tail;
+        continuation.instruction_spans = tail_spans;
+        continuation.terminator = original_terminator;
+        continuation.terminator_span = original_terminator_span;
+        func.add_block(continuation);
+
+        continuation_id
     }
+}
 
-    /// Inline a single call, returning the replacement instructions
-    fn inline_call(
-        &self,
-        call_dest: Option<Register>,
-        args: &[Register],
-        body: &InlinableBody,
-        max_reg_id: &mut u32,
-    ) -> Vec<IrInstr> {
-        // Build parameter -> argument mapping (by register ID)
-        let mut reg_map: FxHashMap<RegisterId, Register> = FxHashMap::default();
-        for (param, arg) in body.params.iter().zip(args.iter()) {
-            reg_map.insert(param.id, arg.clone());
-        }
+impl Default for Inliner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        // Track which registers we've already allocated replacements for
-        let mut allocated: FxHashSet<RegisterId> = FxHashSet::default();
-        for param in &body.params {
-            allocated.insert(param.id);
-        }
+/// Cached copy of an inlinable function
+#[derive(Debug, Clone)]
+struct InlinableBody {
+    /// Parameter registers
+    params: Vec<Register>,
+    /// Entry block of `blocks`
+    entry: BasicBlockId,
+    /// Blocks to copy into the caller
+    blocks: Vec<BasicBlock>,
+    /// Instruction count, charged against the caller's growth budget
+    size: usize,
+}
 
-        let mut result = Vec::new();
-
-        // Clone and rename each instruction
-        for instr in &body.instructions {
-            let renamed = self.rename_instruction(
-                instr,
-                args,
-                body.param_count,
-                &mut reg_map,
-                &mut allocated,
-                max_reg_id,
-            );
-            // rename_instruction may return None if the instruction was elided (e.g., LoadLocal for param)
-            if let Some(renamed_instr) = renamed {
-                result.push(renamed_instr);
-            }
+impl InlinableBody {
+    fn of(func: &IrFunction) -> Self {
+        Self {
+            params: func.params.clone(),
+            entry: func.entry_block,
+            blocks: func.blocks.clone(),
+            size: func.instruction_count(),
         }
+    }
 
-        // Handle return value: if the callee returns a value and the caller expects one,
-        // we need to assign the return register to the call's destination
-        if let (Some(ret_reg), Some(call_dest)) = (&body.return_value, &call_dest) {
-            let renamed_ret = self.rename_register(ret_reg, &reg_map);
-            // Add assignment: call_dest = renamed_ret
-            result.push(IrInstr::Assign {
-                dest: call_dest.clone(),
-                value: IrValue::Register(renamed_ret),
-            });
+    /// One block ending in a return, copied into the caller block in place
+    fn is_straight_line(&self) -> bool {
+        self.blocks.len() == 1 && matches!(self.blocks[0].terminator, Terminator::Return(_))
+    }
+}
+
+/// Maps a callee's registers into the caller: parameters become the call
+/// arguments, everything else gets a fresh caller register.
+struct Renamer<'a> {
+    args: &'a [Register],
+    param_args: FxHashMap<RegisterId, Register>,
+    fresh: FxHashMap<RegisterId, RegisterId>,
+    next_reg: &'a mut u32,
+}
+
+impl<'a> Renamer<'a> {
+    fn new(body: &InlinableBody, args: &'a [Register], next_reg: &'a mut u32) -> Self {
+        let param_args = body
+            .params
+            .iter()
+            .map(|param| param.id)
+            .zip(args.iter().cloned())
+            .collect();
+        Self {
+            args,
+            param_args,
+            fresh: FxHashMap::default(),
+            next_reg,
         }
+    }
 
-        result
+    fn rename(&mut self, reg: &mut Register) {
+        if let Some(arg) = self.param_args.get(&reg.id) {
+            *reg = arg.clone();
+            return;
+        }
+        let next_reg = &mut *self.next_reg;
+        reg.id = *self.fresh.entry(reg.id).or_insert_with(|| {
+            let id = RegisterId::new(*next_reg);
+            *next_reg += 1;
+            id
+        });
     }
 
-    /// Rename registers in an instruction
-    /// Returns None if the instruction should be elided (e.g., LoadLocal for a parameter)
-    fn rename_instruction(
-        &self,
-        instr: &IrInstr,
-        args: &[Register],
-        param_count: usize,
-        reg_map: &mut FxHashMap<RegisterId, Register>,
-        allocated: &mut FxHashSet<RegisterId>,
-        max_reg_id: &mut u32,
-    ) -> Option<IrInstr> {
-        match instr {
-            IrInstr::Assign { dest, value } => Some(IrInstr::Assign {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                value: match value {
-                    IrValue::Register(reg) => IrValue::Register(self.rename_register(reg, reg_map)),
-                    IrValue::Constant(c) => IrValue::Constant(c.clone()),
-                },
-            }),
-            IrInstr::BinaryOp {
-                dest,
-                op,
-                left,
-                right,
-            } => Some(IrInstr::BinaryOp {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                op: *op,
-                left: self.rename_register(left, reg_map),
-                right: self.rename_register(right, reg_map),
-            }),
-            IrInstr::UnaryOp { dest, op, operand } => Some(IrInstr::UnaryOp {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                op: *op,
-                operand: self.rename_register(operand, reg_map),
-            }),
-            IrInstr::LoadLocal { dest, index } => {
-                // If this is loading a parameter, map the dest directly to the argument
-                // and elide the LoadLocal instruction
-                if (*index as usize) < param_count {
-                    // Map dest register to the corresponding argument
-                    let arg = &args[*index as usize];
-                    reg_map.insert(dest.id, arg.clone());
-                    allocated.insert(dest.id);
-                    None // Elide this instruction
-                } else {
-                    // Non-parameter local - keep the LoadLocal but this is risky
-                    // (would load from caller's local slot) - for safety, we shouldn't
-                    // inline functions that access non-param locals
-                    Some(IrInstr::LoadLocal {
-                        dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                        index: *index,
-                    })
-                }
-            }
-            IrInstr::StoreLocal { index, value } => {
-                // If storing to a parameter slot, this is unusual but could happen
-                // For safety, don't inline functions that store to locals
-                if (*index as usize) < param_count {
-                    // Storing to a parameter - this is a reassignment of the param
-                    // We can't easily handle this, so keep it (will be wrong)
-                    // TODO: For now, we should filter out functions that store to params
-                    Some(IrInstr::StoreLocal {
-                        index: *index,
-                        value: self.rename_register(value, reg_map),
-                    })
-                } else {
-                    Some(IrInstr::StoreLocal {
-                        index: *index,
-                        value: self.rename_register(value, reg_map),
-                    })
-                }
-            }
-            IrInstr::LoadFieldExact {
-                dest,
-                object,
-                field,
-                optional,
-            } => Some(IrInstr::LoadFieldExact {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                field: *field,
-                optional: *optional,
-            }),
-            IrInstr::StoreFieldExact {
-                object,
-                field,
-                value,
-            } => Some(IrInstr::StoreFieldExact {
-                object: self.rename_register(object, reg_map),
-                field: *field,
-                value: self.rename_register(value, reg_map),
-            }),
-            IrInstr::Call {
-                dest,
-                func,
-                args: call_args,
-            } => Some(IrInstr::Call {
-                dest: dest
-                    .as_ref()
-                    .map(|d| self.rename_or_allocate(d, reg_map, allocated, max_reg_id)),
-                func: *func,
-                args: call_args
-                    .iter()
-                    .map(|a| self.rename_register(a, reg_map))
-                    .collect(),
-            }),
-            IrInstr::ConstructType {
-                dest,
-                object,
-                nominal_type_id,
-                args,
-            } => Some(IrInstr::ConstructType {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                nominal_type_id: *nominal_type_id,
-                args: args
-                    .iter()
-                    .map(|a| self.rename_register(a, reg_map))
-                    .collect(),
-            }),
-            IrInstr::NativeCall {
-                dest,
-                native_id,
-                args: native_args,
-            } => Some(IrInstr::NativeCall {
-                dest: dest
-                    .as_ref()
-                    .map(|d| self.rename_or_allocate(d, reg_map, allocated, max_reg_id)),
-                native_id: *native_id,
-                args: native_args
-                    .iter()
-                    .map(|a| self.rename_register(a, reg_map))
-                    .collect(),
-            }),
-            IrInstr::ModuleNativeCall {
-                dest,
-                local_idx,
-                args: native_args,
-            } => Some(IrInstr::ModuleNativeCall {
-                dest: dest
-                    .as_ref()
-                    .map(|d| self.rename_or_allocate(d, reg_map, allocated, max_reg_id)),
-                local_idx: *local_idx,
-                args: native_args
-                    .iter()
-                    .map(|a| self.rename_register(a, reg_map))
-                    .collect(),
-            }),
-            IrInstr::MutexLock { mutex } => Some(IrInstr::MutexLock {
-                mutex: self.rename_register(mutex, reg_map),
-            }),
-            IrInstr::MutexUnlock { mutex } => Some(IrInstr::MutexUnlock {
-                mutex: self.rename_register(mutex, reg_map),
-            }),
-            IrInstr::NewMutex { dest } => Some(IrInstr::NewMutex {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-            }),
-            IrInstr::NewChannel { dest, capacity } => Some(IrInstr::NewChannel {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                capacity: self.rename_register(capacity, reg_map),
-            }),
-            IrInstr::Sleep { duration_ms } => Some(IrInstr::Sleep {
-                duration_ms: self.rename_register(duration_ms, reg_map),
-            }),
-            IrInstr::Yield => Some(IrInstr::Yield),
-            IrInstr::TaskCancel { task } => Some(IrInstr::TaskCancel {
-                task: self.rename_register(task, reg_map),
-            }),
-            IrInstr::ArrayLen { dest, array } => Some(IrInstr::ArrayLen {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                array: self.rename_register(array, reg_map),
-            }),
-            IrInstr::ArrayPush { array, element } => Some(IrInstr::ArrayPush {
-                array: self.rename_register(array, reg_map),
-                element: self.rename_register(element, reg_map),
-            }),
-            IrInstr::ArrayPop { dest, array } => Some(IrInstr::ArrayPop {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                array: self.rename_register(array, reg_map),
-            }),
-            IrInstr::StringLen { dest, string } => Some(IrInstr::StringLen {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                string: self.rename_register(string, reg_map),
-            }),
-            IrInstr::StringCompare {
-                dest,
-                left,
-                right,
-                mode,
-                negate,
-            } => Some(IrInstr::StringCompare {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                left: self.rename_register(left, reg_map),
-                right: self.rename_register(right, reg_map),
-                mode: *mode,
-                negate: *negate,
-            }),
-            IrInstr::ToString { dest, operand } => Some(IrInstr::ToString {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                operand: self.rename_register(operand, reg_map),
-            }),
-            IrInstr::Typeof { dest, operand } => Some(IrInstr::Typeof {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                operand: self.rename_register(operand, reg_map),
-            }),
-            IrInstr::LoadElement { dest, array, index } => Some(IrInstr::LoadElement {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                array: self.rename_register(array, reg_map),
-                index: self.rename_register(index, reg_map),
-            }),
-            IrInstr::StoreElement {
-                array,
-                index,
-                value,
-            } => Some(IrInstr::StoreElement {
-                array: self.rename_register(array, reg_map),
-                index: self.rename_register(index, reg_map),
-                value: self.rename_register(value, reg_map),
-            }),
-            IrInstr::NewType {
-                dest,
-                nominal_type_id,
-            } => Some(IrInstr::NewType {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                nominal_type_id: *nominal_type_id,
-            }),
-            IrInstr::IsNominal {
-                dest,
-                object,
-                nominal_type_id,
-            } => Some(IrInstr::IsNominal {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                nominal_type_id: *nominal_type_id,
-            }),
-            IrInstr::ImplementsShape {
-                dest,
-                object,
-                shape_id,
-            } => Some(IrInstr::ImplementsShape {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                shape_id: *shape_id,
-            }),
-            IrInstr::CastNominal {
-                dest,
-                object,
-                nominal_type_id,
-            } => Some(IrInstr::CastNominal {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                nominal_type_id: *nominal_type_id,
-            }),
-            IrInstr::CastShape {
-                dest,
-                object,
-                shape_id,
-            } => Some(IrInstr::CastShape {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                shape_id: *shape_id,
-            }),
-            IrInstr::CastTupleLen {
-                dest,
-                object,
-                expected_len,
-            } => Some(IrInstr::CastTupleLen {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                expected_len: *expected_len,
-            }),
-            IrInstr::CastObjectMinFields {
-                dest,
-                object,
-                required_fields,
-            } => Some(IrInstr::CastObjectMinFields {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                required_fields: *required_fields,
-            }),
-            IrInstr::CastArrayElemKind {
-                dest,
-                object,
-                expected_elem_mask,
-            } => Some(IrInstr::CastArrayElemKind {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                expected_elem_mask: *expected_elem_mask,
-            }),
-            IrInstr::CastKindMask {
-                dest,
-                object,
-                expected_kind_mask,
-            } => Some(IrInstr::CastKindMask {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                object: self.rename_register(object, reg_map),
-                expected_kind_mask: *expected_kind_mask,
-            }),
-            IrInstr::MakeClosure {
-                dest,
-                func,
-                captures,
-            } => Some(IrInstr::MakeClosure {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                func: *func,
-                captures: captures
-                    .iter()
-                    .map(|c| self.rename_register(c, reg_map))
-                    .collect(),
-            }),
-            IrInstr::CallClosure {
-                dest,
-                closure,
-                args: closure_args,
-            } => Some(IrInstr::CallClosure {
-                dest: dest
-                    .as_ref()
-                    .map(|d| self.rename_or_allocate(d, reg_map, allocated, max_reg_id)),
-                closure: self.rename_register(closure, reg_map),
-                args: closure_args
-                    .iter()
-                    .map(|a| self.rename_register(a, reg_map))
-                    .collect(),
-            }),
-            IrInstr::CallMethodExact {
-                dest,
-                object,
-                method,
-                args: method_args,
-                optional,
-            } => Some(IrInstr::CallMethodExact {
-                dest: dest
-                    .as_ref()
-                    .map(|d| self.rename_or_allocate(d, reg_map, allocated, max_reg_id)),
-                object: self.rename_register(object, reg_map),
-                method: *method,
-                args: method_args
-                    .iter()
-                    .map(|a| self.rename_register(a, reg_map))
-                    .collect(),
-                optional: *optional,
-            }),
-            IrInstr::CallMethodShape {
+    /// Copy a callee instruction into the caller
+    fn copy_instr(&mut self, instr: &IrInstr) -> IrInstr {
+        let mut instr = instr.clone();
+        for reg in instr.registers_mut() {
+            self.rename(reg);
+        }
+        // Parameter slot reads become copies of the call arguments.
+        if let IrInstr::LoadLocal { dest, index } = instr {
+            instr = IrInstr::Assign {
                 dest,
-                object,
-                shape_id,
-                method,
-                args: method_args,
-                optional,
-            } => Some(IrInstr::CallMethodShape {
-                dest: dest
-                    .as_ref()
-                    .map(|d| self.rename_or_allocate(d, reg_map, allocated, max_reg_id)),
-                object: self.rename_register(object, reg_map),
-                shape_id: *shape_id,
-                method: *method,
-                args: method_args
-                    .iter()
-                    .map(|a| self.rename_register(a, reg_map))
-                    .collect(),
-                optional: *optional,
-            }),
-            IrInstr::StoreGlobal { index, value } => Some(IrInstr::StoreGlobal {
-                index: *index,
-                value: self.rename_register(value, reg_map),
-            }),
-            IrInstr::LoadGlobal { dest, index } => Some(IrInstr::LoadGlobal {
-                dest: self.rename_or_allocate(dest, reg_map, allocated, max_reg_id),
-                index: *index,
-            }),
-            // For instructions we don't expect in inlinable functions,
-            // clone them as-is (they should have been filtered out)
-            other => Some(other.clone()),
+                value: IrValue::Register(self.args[index as usize].clone()),
+            };
         }
+        instr
     }
 
-    /// Rename a register using the mapping, or return as-is if not mapped
-    fn rename_register(
-        &self,
-        reg: &Register,
-        reg_map: &FxHashMap<RegisterId, Register>,
-    ) -> Register {
-        reg_map.get(&reg.id).cloned().unwrap_or_else(|| reg.clone())
+    /// The assignment of a callee return value to the call destination
+    fn return_value(&mut self, dest: Option<Register>, value: Option<Register>) -> Option<IrInstr> {
+        let dest = dest?;
+        let value = match value {
+            Some(mut reg) => {
+                self.rename(&mut reg);
+                IrValue::Register(reg)
+            }
+            None => IrValue::Constant(IrConstant::Null),
+        };
+        Some(IrInstr::Assign { dest, value })
     }
+}
 
-    /// Rename a destination register: allocate a fresh one if not already mapped
-    fn rename_or_allocate(
-        &self,
-        reg: &Register,
-        reg_map: &mut FxHashMap<RegisterId, Register>,
-        allocated: &mut FxHashSet<RegisterId>,
-        max_reg_id: &mut u32,
-    ) -> Register {
-        if let Some(mapped) = reg_map.get(&reg.id) {
-            return mapped.clone();
-        }
-
-        if allocated.contains(&reg.id) {
-            // Already allocated, use the mapping
-            return reg.clone();
-        }
+/// The first call at or after `start` in `block` that can be inlined within
+/// `budget`, with the body to inline
+fn next_site<'a>(
+    block: &BasicBlock,
+    start: usize,
+    inlinable: &'a FxHashMap<FunctionId, InlinableBody>,
+    budget: usize,
+) -> Option<(usize, &'a InlinableBody)> {
+    block
+        .instructions
+        .iter()
+        .enumerate()
+        .skip(start)
+        .find_map(|(pos, instr)| {
+            let IrInstr::Call {
+                func: callee, args, ..
+            } = instr
+            else {
+                return None;
+            };
+            let body = inlinable.get(callee)?;
+            // Only inline if argument count matches parameter count.
+            // This avoids issues with mismatched this/self parameters.
+            (body.params.len() == args.len() && body.size <= budget).then_some((pos, body))
+        })
+}
 
-        // Allocate a fresh register
-        *max_reg_id += 1;
-        let fresh = Register::new(RegisterId::new(*max_reg_id), reg.ty);
-        reg_map.insert(reg.id, fresh.clone());
-        allocated.insert(reg.id);
-        fresh
+fn push_instr(block: &mut BasicBlock, instr: IrInstr, has_spans: bool, span: Span) {
+    if has_spans {
+        block.add_instr_spanned(instr, span);
+    } else {
+        block.add_instr(instr);
     }
 }
 
-impl Default for Inliner {
-    fn default() -> Self {
-        Self::new()
+fn retarget(terminator: Terminator, map: &FxHashMap<BasicBlockId, BasicBlockId>) -> Terminator {
+    match terminator {
+        Terminator::Jump(target) => Terminator::Jump(map[&target]),
+        Terminator::Branch {
+            cond,
+            then_block,
+            else_block,
+        } => Terminator::Branch {
+            cond,
+            then_block: map[&then_block],
+            else_block: map[&else_block],
+        },
+        Terminator::BranchIfNull {
+            value,
+            null_block,
+            not_null_block,
+        } => Terminator::BranchIfNull {
+            value,
+            null_block: map[&null_block],
+            not_null_block: map[&not_null_block],
+        },
+        Terminator::Switch {
+            value,
+            cases,
+            default,
+        } => Terminator::Switch {
+            value,
+            cases: cases
+                .into_iter()
+                .map(|(case, target)| (case, map[&target]))
+                .collect(),
+            default: map[&default],
+        },
+        other @ (Terminator::Return(_) | Terminator::Throw(_) | Terminator::Unreachable) => other,
     }
 }
 
-/// Cached body of an inlinable function
-#[derive(Debug, Clone)]
-struct InlinableBody {
-    /// Parameter registers
-    params: Vec<Register>,
-    /// Number of parameters (for detecting LoadLocal of params)
-    param_count: usize,
-    /// Instructions to inline
-    instructions: Vec<IrInstr>,
-    /// Return value register (if any)
-    return_value: Option<Register>,
+/// Largest register id used anywhere in `func`
+fn max_register_id(func: &IrFunction) -> u32 {
+    let mut max_id = 0;
+    let regs = func.params.iter().chain(func.locals.iter());
+    for reg in regs {
+        max_id = max_id.max(reg.id.as_u32());
+    }
+    for block in &func.blocks {
+        for instr in &block.instructions {
+            for reg in instr.dest().into_iter().chain(instr.used_registers()) {
+                max_id = max_id.max(reg.id.as_u32());
+            }
+        }
+        for reg in block.terminator.used_registers() {
+            max_id = max_id.max(reg.id.as_u32());
+        }
+    }
+    max_id
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::compiler::ir::block::{BasicBlock, BasicBlockId, Terminator};
-    use crate::compiler::ir::value::IrConstant;
-    use crate::compiler::ir::IrModule;
+    use crate::compiler::ir::BinaryOp;
     use crate::parser::TypeId;
 
     fn make_reg(id: u32) -> Register {
@@ -990,4 +768,207 @@ mod tests {
         // Should have 2 instructions: assign temp = 42, then assign dest = temp
         assert_eq!(block.instructions.len(), 2);
     }
+
+    /// `fn add(a, b) { return a + b; }` in the shape lowering produces
+    fn add_function() -> IrFunction {
+        let mut func = IrFunction::new("add", vec![make_reg(0), make_reg(1)], TypeId::new(0));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        block.add_instr(IrInstr::LoadLocal {
+            dest: make_reg(2),
+            index: 0,
+        });
+        block.add_instr(IrInstr::LoadLocal {
+            dest: make_reg(3),
+            index: 1,
+        });
+        block.add_instr(IrInstr::BinaryOp {
+            dest: make_reg(4),
+            op: BinaryOp::Add,
+            left: make_reg(2),
+            right: make_reg(3),
+        });
+        block.set_terminator(Terminator::Return(Some(make_reg(4))));
+        func.add_block(block);
+        func
+    }
+
+    /// `fn abs(x) { if (x < 0) return -x; return x; }`
+    fn abs_function() -> IrFunction {
+        let mut func = IrFunction::new("abs", vec![make_reg(0)], TypeId::new(0));
+        let mut entry = BasicBlock::new(BasicBlockId(0));
+        entry.add_instr(IrInstr::LoadLocal {
+            dest: make_reg(1),
+            index: 0,
+        });
+        entry.add_instr(IrInstr::Assign {
+            dest: make_reg(2),
+            value: IrValue::Constant(IrConstant::I32(0)),
+        });
+        entry.add_instr(IrInstr::BinaryOp {
+            dest: make_reg(3),
+            op: BinaryOp::Less,
+            left: make_reg(1),
+            right: make_reg(2),
+        });
+        entry.set_terminator(Terminator::Branch {
+            cond: make_reg(3),
+            then_block: BasicBlockId(1),
+            else_block: BasicBlockId(2),
+        });
+        let mut negative = BasicBlock::new(BasicBlockId(1));
+        negative.add_instr(IrInstr::UnaryOp {
+            dest: make_reg(4),
+            op: crate::compiler::ir::UnaryOp::Neg,
+            operand: make_reg(1),
+        });
+        negative.set_terminator(Terminator::Return(Some(make_reg(4))));
+        let mut positive = BasicBlock::new(BasicBlockId(2));
+        positive.set_terminator(Terminator::Return(Some(make_reg(1))));
+        func.add_block(entry);
+        func.add_block(negative);
+        func.add_block(positive);
+        func
+    }
+
+    /// `fn main() { r1 = callee(r0, r0); r2 = r1 + r1; return r2; }`
+    fn caller(callee: u32, args: Vec<Register>) -> IrFunction {
+        let mut func = IrFunction::new("main", vec![], TypeId::new(0));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        block.add_instr(IrInstr::Assign {
+            dest: make_reg(0),
+            value: IrValue::Constant(IrConstant::I32(5)),
+        });
+        block.add_instr(IrInstr::Call {
+            dest: Some(make_reg(1)),
+            func: FunctionId::new(callee),
+            args,
+        });
+        block.add_instr(IrInstr::BinaryOp {
+            dest: make_reg(2),
+            op: BinaryOp::Add,
+            left: make_reg(1),
+            right: make_reg(1),
+        });
+        block.set_terminator(Terminator::Return(Some(make_reg(2))));
+        func.add_block(block);
+        func
+    }
+
+    fn has_call(func: &IrFunction) -> bool {
+        func.blocks()
+            .flat_map(|block| &block.instructions)
+            .any(|instr| matches!(instr, IrInstr::Call { .. }))
+    }
+
+    #[test]
+    fn test_inlines_single_block_leaf_in_place() {
+        let mut module = IrModule::new("test");
+        module.add_function(add_function());
+        module.add_function(caller(0, vec![make_reg(0), make_reg(0)]));
+
+        assert_eq!(Inliner::leaves_only().inline(&mut module), 1);
+        let main = &module.functions[1];
+        assert!(!has_call(main));
+        assert_eq!(main.block_count(), 1);
+        // r0 = 5, the three copied instructions, r1 = result, r2 = r1 + r1
+        let instructions = &main.blocks[0].instructions;
+        assert_eq!(instructions.len(), 6);
+        // Inlined registers never collide with the caller's.
+        assert!(instructions[1..4]
+            .iter()
+            .filter_map(|instr| instr.dest())
+            .all(|dest| dest.id.as_u32() > 2));
+        assert!(matches!(
+            &instructions[4],
+            IrInstr::Assign { dest, .. } if dest.id.as_u32() == 1
+        ));
+    }
+
+    #[test]
+    fn test_inlines_multi_block_leaf() {
+        let mut module = IrModule::new("test");
+        module.add_function(abs_function());
+        module.add_function(caller(0, vec![make_reg(0)]));
+
+        for inliner in [Inliner::leaves_only(), Inliner::new()] {
+            let mut module = module.clone();
+            assert_eq!(inliner.inline(&mut module), 1);
+            let main = &module.functions[1];
+            assert!(!has_call(main));
+            // entry, three copied leaf blocks, continuation
+            assert_eq!(main.block_count(), 5);
+            let continuation = main.blocks.last().unwrap().id;
+            let returns_to_continuation = main
+                .blocks()
+                .filter(|block| matches!(block.terminator, Terminator::Jump(target) if target == continuation))
+                .count();
+            assert_eq!(returns_to_continuation, 2);
+        }
+    }
+
+    #[test]
+    fn test_skips_non_leaf_and_methods() {
+        let mut module = IrModule::new("test");
+        module.add_function(add_function());
+        // Calls `add`, so it is not a leaf itself.
+        module.add_function(caller(0, vec![make_reg(0), make_reg(0)]));
+        module.add_function(caller(1, vec![]));
+
+        Inliner::leaves_only().inline(&mut module);
+        assert!(!has_call(&module.functions[1]));
+        assert!(has_call(&module.functions[2]));
+
+        let mut module = IrModule::new("test");
+        module.add_function(add_function());
+        module.add_function(caller(0, vec![make_reg(0), make_reg(0)]));
+        let mut class = crate::compiler::ir::IrClass::new("Math");
+        class.methods.push(FunctionId::new(0));
+        module.classes.push(class);
+
+        assert_eq!(Inliner::leaves_only().inline(&mut module), 0);
+    }
+
+    #[test]
+    fn test_skips_leaf_with_try() {
+        let mut func = add_function();
+        func.blocks[0].instructions.insert(
+            0,
+            IrInstr::SetupTry {
+                catch_block: BasicBlockId(0),
+                finally_block: None,
+            },
+        );
+        let mut module = IrModule::new("test");
+        module.add_function(func);
+        module.add_function(caller(0, vec![make_reg(0), make_reg(0)]));
+
+        assert_eq!(Inliner::leaves_only().inline(&mut module), 0);
+    }
+
+    #[test]
+    fn test_growth_budget_limits_inlining() {
+        let mut module = IrModule::new("test");
+        module.add_function(add_function());
+        let mut main = IrFunction::new("main", vec![], TypeId::new(0));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        block.add_instr(IrInstr::Assign {
+            dest: make_reg(0),
+            value: IrValue::Constant(IrConstant::I32(1)),
+        });
+        let calls = MAX_GROWTH_PER_FUNCTION; // each inlined add costs 3
+        for i in 0..calls {
+            block.add_instr(IrInstr::Call {
+                dest: Some(make_reg(i as u32 + 1)),
+                func: FunctionId::new(0),
+                args: vec![make_reg(0), make_reg(0)],
+            });
+        }
+        block.set_terminator(Terminator::Return(None));
+        main.add_block(block);
+        module.add_function(main);
+
+        let inlined = Inliner::leaves_only().inline(&mut module);
+        assert_eq!(inlined, MAX_GROWTH_PER_FUNCTION / 3);
+        assert!(has_call(&module.functions[1]));
+    }
 }
@@ -1,40 +1,56 @@
 //! IR Optimization Passes
 //!
 //! Provides basic optimizations on the IR before bytecode generation.
+//!
+//! Set `RAYA_PRINT_IR_AFTER` to a comma-separated list of pass names (see
+//! [`PASS_NAMES`]) to dump the IR after those passes while compiling.
 
+mod coalesce;
 mod constant_fold;
 mod dce;
 mod inline;
 mod phi_elim;
 
+pub use coalesce::RegisterCoalescer;
 pub use constant_fold::ConstantFolder;
 pub use dce::DeadCodeEliminator;
 pub use inline::Inliner;
 pub use phi_elim::PhiEliminator;
 
-use crate::compiler::ir::IrModule;
+use crate::compiler::ir::{IrModule, PrettyPrint};
 
 /// Optimization level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OptLevel {
     /// No optimizations
     None,
-    /// Basic optimizations (constant folding, DCE)
+    /// Basic optimizations (leaf inlining, constant folding, DCE, register coalescing)
     #[default]
     Basic,
     /// Full optimizations (includes inlining, etc.)
     Full,
 }
 
+/// Pass names accepted by [`Optimizer::with_print_ir_after`], in pipeline order
+pub const PASS_NAMES: &[&str] = &["inline", "const-fold", "dce", "phi-elim", "coalesce"];
+
+/// Comma-separated pass names whose output IR is dumped to stderr,
+/// e.g. `RAYA_PRINT_IR_AFTER=const-fold,dce`
+pub const PRINT_IR_AFTER_ENV: &str = "RAYA_PRINT_IR_AFTER";
+
 /// Optimizer that runs multiple passes over the IR
 pub struct Optimizer {
     level: OptLevel,
+    print_ir_after: Vec<&'static str>,
 }
 
 impl Optimizer {
     /// Create a new optimizer with the given level
     pub fn new(level: OptLevel) -> Self {
-        Self { level }
+        Self {
+            level,
+            print_ir_after: Vec::new(),
+        }
     }
 
     /// Create an optimizer with basic optimizations
@@ -47,8 +63,50 @@ impl Optimizer {
         Self::new(OptLevel::None)
     }
 
+    /// Capture the textual IR after each of the named passes
+    ///
+    /// Names come from [`PASS_NAMES`]; unknown names are reported and ignored.
+    pub fn with_print_ir_after<'s>(mut self, passes: impl IntoIterator<Item = &'s str>) -> Self {
+        for pass in passes.into_iter().map(str::trim).filter(|p| !p.is_empty()) {
+            match PASS_NAMES.iter().find(|name| **name == pass) {
+                Some(name) => self.print_ir_after.push(name),
+                None => eprintln!(
+                    "Warning: unknown optimizer pass '{}' (known passes: {})",
+                    pass,
+                    PASS_NAMES.join(", ")
+                ),
+            }
+        }
+        self
+    }
+
+    /// Capture IR after the passes listed in [`PRINT_IR_AFTER_ENV`], if set
+    pub fn with_print_ir_after_env(self) -> Self {
+        match std::env::var(PRINT_IR_AFTER_ENV) {
+            Ok(passes) => self.with_print_ir_after(passes.split(',')),
+            Err(_) => self,
+        }
+    }
+
     /// Run all optimization passes on the module
+    ///
+    /// IR requested with [`Optimizer::with_print_ir_after`] is written to stderr.
     pub fn optimize(&self, module: &mut IrModule) {
+        for (pass, ir) in self.optimize_with_ir_dumps(module) {
+            eprintln!("=== IR after {} ===\n{}", pass, ir);
+        }
+    }
+
+    /// Run all optimization passes, returning the textual (`.rir`) IR captured
+    /// after each requested pass, in the order the passes ran
+    pub fn optimize_with_ir_dumps(&self, module: &mut IrModule) -> Vec<(&'static str, String)> {
+        let mut dumps = Vec::new();
+        let mut after = |pass: &'static str, module: &IrModule| {
+            if self.print_ir_after.contains(&pass) {
+                dumps.push((pass, module.pretty_print()));
+            }
+        };
+
         // PHI elimination always runs (required for code generation)
         // even at OptLevel::None
         let phi_elim = PhiEliminator::new();
@@ -56,31 +114,47 @@ impl Optimizer {
         if self.level == OptLevel::None {
             // Still need to eliminate PHIs for code generation
             phi_elim.eliminate(module);
-            return;
+            after("phi-elim", module);
+            return dumps;
         }
 
         // Inlining in Basic mode has caused semantic regressions around constructor
-        // side effects and call argument remapping. Keep Basic mode conservative.
-        if self.level == OptLevel::Full {
-            let inliner = Inliner::new();
-            inliner.inline(module);
-        }
+        // side effects and call argument remapping. Keep Basic mode conservative:
+        // only call-free bodies of free functions are inlined there.
+        let inliner = if self.level == OptLevel::Full {
+            Inliner::new()
+        } else {
+            Inliner::leaves_only()
+        };
+        inliner.inline(module);
+        after("inline", module);
 
         // Run constant folding
         let folder = ConstantFolder::new();
         folder.fold(module);
+        after("const-fold", module);
 
         // Run dead code elimination
         let dce = DeadCodeEliminator::new();
         dce.eliminate(module);
+        after("dce", module);
 
         // Run constant folding again after DCE (may expose more opportunities)
         if self.level == OptLevel::Full {
             folder.fold(module);
+            after("const-fold", module);
         }
 
-        // PHI elimination must run last (required for bytecode generation)
+        // PHI elimination must run before coalescing (required for bytecode generation)
         phi_elim.eliminate(module);
+        after("phi-elim", module);
+
+        // Share registers with disjoint live ranges to shrink local slot counts
+        let coalescer = RegisterCoalescer::new();
+        coalescer.coalesce(module);
+        after("coalesce", module);
+
+        dumps
     }
 
     /// Get statistics about optimizations performed
@@ -109,6 +183,11 @@ impl Default for Optimizer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::compiler::ir::{
+        BasicBlock, BasicBlockId, BinaryOp, IrConstant, IrFunction, IrInstr, IrValue, Register,
+        RegisterId, Terminator,
+    };
+    use crate::parser::TypeId;
 
     #[test]
     fn test_optimizer_levels() {
@@ -118,4 +197,62 @@ mod tests {
         let opt = Optimizer::basic();
         assert_eq!(opt.level, OptLevel::Basic);
     }
+
+    fn constant_add_module() -> IrModule {
+        let reg = |id| Register::new(RegisterId::new(id), TypeId::new(1));
+        let mut func = IrFunction::new("test", vec![], TypeId::new(0));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        block.add_instr(IrInstr::Assign {
+            dest: reg(0),
+            value: IrValue::Constant(IrConstant::I32(10)),
+        });
+        block.add_instr(IrInstr::Assign {
+            dest: reg(1),
+            value: IrValue::Constant(IrConstant::I32(32)),
+        });
+        block.add_instr(IrInstr::BinaryOp {
+            dest: reg(2),
+            op: BinaryOp::Add,
+            left: reg(0),
+            right: reg(1),
+        });
+        block.set_terminator(Terminator::Return(Some(reg(2))));
+        func.add_block(block);
+
+        let mut module = IrModule::new("test");
+        module.add_function(func);
+        module
+    }
+
+    #[test]
+    fn test_print_ir_after_single_pass() {
+        let mut module = constant_add_module();
+        let dumps = Optimizer::basic()
+            .with_print_ir_after(["const-fold"])
+            .optimize_with_ir_dumps(&mut module);
+
+        assert_eq!(dumps.len(), 1);
+        let (pass, ir) = &dumps[0];
+        assert_eq!(*pass, "const-fold");
+        assert!(ir.contains("r2:1 = 42"), "folded constant in:\n{}", ir);
+        assert!(!ir.contains("r0:1 + r1:1"), "add folded away in:\n{}", ir);
+    }
+
+    #[test]
+    fn test_print_ir_after_follows_pipeline_order() {
+        let mut module = constant_add_module();
+        let dumps = Optimizer::basic()
+            .with_print_ir_after(" dce, const-fold ,bogus,".split(','))
+            .optimize_with_ir_dumps(&mut module);
+
+        let passes: Vec<&str> = dumps.iter().map(|(pass, _)| *pass).collect();
+        assert_eq!(passes, vec!["const-fold", "dce"]);
+        assert!(dumps[0].1.contains("r0:1 = 10"));
+        assert!(!dumps[1].1.contains("r0:1 = 10"), "dce removed dead assign");
+
+        let mut module = constant_add_module();
+        assert!(Optimizer::basic()
+            .optimize_with_ir_dumps(&mut module)
+            .is_empty());
+    }
 }
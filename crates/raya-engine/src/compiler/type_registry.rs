@@ -216,6 +216,24 @@ impl TypeRegistry {
                     ("setInt32", buffer::SET_INT32),
                     ("getFloat64", buffer::GET_FLOAT64),
                     ("setFloat64", buffer::SET_FLOAT64),
+                    ("getUint8", buffer::GET_UINT8),
+                    ("setUint8", buffer::SET_UINT8),
+                    ("getInt16LE", buffer::GET_INT16_LE),
+                    ("getInt16BE", buffer::GET_INT16_BE),
+                    ("setInt16LE", buffer::SET_INT16_LE),
+                    ("setInt16BE", buffer::SET_INT16_BE),
+                    ("getUint16LE", buffer::GET_UINT16_LE),
+                    ("getUint16BE", buffer::GET_UINT16_BE),
+                    ("setUint16LE", buffer::SET_UINT16_LE),
+                    ("setUint16BE", buffer::SET_UINT16_BE),
+                    ("getInt32LE", buffer::GET_INT32_LE),
+                    ("getInt32BE", buffer::GET_INT32_BE),
+                    ("setInt32LE", buffer::SET_INT32_LE),
+                    ("setInt32BE", buffer::SET_INT32_BE),
+                    ("getFloat64LE", buffer::GET_FLOAT64_LE),
+                    ("getFloat64BE", buffer::GET_FLOAT64_BE),
+                    ("setFloat64LE", buffer::SET_FLOAT64_LE),
+                    ("setFloat64BE", buffer::SET_FLOAT64_BE),
                     ("slice", buffer::SLICE),
                     ("copy", buffer::COPY),
                     ("toString", buffer::TO_STRING),
@@ -1413,7 +1431,7 @@ class Array<T> {
         assert_eq!(
             registry.lookup_return_type(0x0213),
             Some(TypeContext::ARRAY_TYPE_ID)
-        ); // matchAll → string[][] → Array
+        ); // matchAll → RegExpMatch[] → Array
         assert_eq!(registry.lookup_return_type(0x0214), Some(int_id)); // search → int
 
         // Variant IDs (compiler-internal remaps)
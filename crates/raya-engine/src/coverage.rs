@@ -0,0 +1,288 @@
+//! Line and branch coverage collection for the Raya VM.
+//!
+//! Disabled by default. `Vm::enable_coverage` installs a [`CoverageRecorder`].
+//! Each interpreter buffers the instructions it executes and the
+//! conditional-jump outcomes it observes in a [`CoverageBuffer`], which it
+//! merges into the recorder when its task slice ends, so the dispatch loop
+//! takes no lock. [`CoverageRecorder::report`] maps those bytecode offsets back
+//! to source lines through the module's `DebugInfo`, so the module must be
+//! compiled with a source map.
+
+use crate::compiler::bytecode::verify::operand_size;
+use crate::compiler::{Module, Opcode};
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashSet};
+
+/// An instruction site: module checksum, function index, bytecode offset.
+type Site = ([u8; 32], u32, u32);
+
+/// Coverage hits recorded by one interpreter, not yet merged.
+#[derive(Debug, Default)]
+pub struct CoverageBuffer {
+    executed: HashSet<Site>,
+    branches: HashSet<(Site, bool)>,
+}
+
+impl CoverageBuffer {
+    /// Record that the instruction at `offset` in `func_id` executed.
+    #[inline]
+    pub fn record_instruction(&mut self, module: &Module, func_id: usize, offset: u32) {
+        self.executed
+            .insert((module.checksum, func_id as u32, offset));
+    }
+
+    /// Record the outcome of the conditional jump at `offset` in `func_id`.
+    #[inline]
+    pub fn record_branch(&mut self, module: &Module, func_id: usize, offset: u32, taken: bool) {
+        self.branches
+            .insert(((module.checksum, func_id as u32, offset), taken));
+    }
+}
+
+/// Collects executed instructions and branch outcomes during a run.
+#[derive(Debug, Default)]
+pub struct CoverageRecorder {
+    /// Instructions executed at least once.
+    executed: Mutex<HashSet<Site>>,
+    /// Conditional jumps observed, with whether the jump was taken.
+    branches: Mutex<HashSet<(Site, bool)>>,
+}
+
+impl CoverageRecorder {
+    /// Create an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the hits of `buffer` into the recorder, leaving it empty.
+    pub fn merge(&self, buffer: &mut CoverageBuffer) {
+        if !buffer.executed.is_empty() {
+            self.executed.lock().extend(buffer.executed.drain());
+        }
+        if !buffer.branches.is_empty() {
+            self.branches.lock().extend(buffer.branches.drain());
+        }
+    }
+
+    /// Resolve the recorded sites of `module` to per-file line and branch coverage.
+    ///
+    /// Functions without debug info are skipped.
+    pub fn report(&self, module: &Module) -> CoverageReport {
+        let executed = self.executed.lock();
+        let branches = self.branches.lock();
+        let Some(debug_info) = module.debug_info.as_ref() else {
+            return CoverageReport::default();
+        };
+
+        let mut files: BTreeMap<String, FileCoverage> = BTreeMap::new();
+        for (func_id, function) in module.functions.iter().enumerate() {
+            let Some(func_debug) = debug_info.functions.get(func_id) else {
+                continue;
+            };
+            if func_debug.line_table.is_empty() {
+                continue;
+            }
+            let path = debug_info
+                .source_files
+                .get(func_debug.source_file_index as usize)
+                .cloned()
+                .or_else(|| module.metadata.source_file.clone())
+                .unwrap_or_else(|| module.metadata.name.clone());
+            let file = files.entry(path.clone()).or_insert_with(|| FileCoverage {
+                path,
+                ..Default::default()
+            });
+
+            let code = &function.code;
+            let mut ip = 0;
+            while ip < code.len() {
+                let Some(opcode) = Opcode::from_u8(code[ip]) else {
+                    break;
+                };
+                let offset = ip as u32;
+                ip += 1 + operand_size(opcode);
+
+                let line = func_debug
+                    .lookup_location(offset)
+                    .map(|entry| entry.line)
+                    .unwrap_or(0);
+                if line == 0 {
+                    continue;
+                }
+                let site = (module.checksum, func_id as u32, offset);
+                let hit = executed.contains(&site);
+                *file.lines.entry(line).or_insert(false) |= hit;
+
+                if opcode.is_conditional_jump() {
+                    let counts = file.branches.entry(line).or_insert((0, 0));
+                    counts.1 += 2;
+                    counts.0 += [true, false]
+                        .iter()
+                        .filter(|taken| branches.contains(&(site, **taken)))
+                        .count();
+                }
+            }
+        }
+
+        CoverageReport {
+            files: files.into_values().collect(),
+        }
+    }
+}
+
+/// Coverage of a single source file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileCoverage {
+    /// Source file path from the module's debug info.
+    pub path: String,
+    /// Executable lines and whether each ran at least once.
+    pub lines: BTreeMap<u32, bool>,
+    /// Per line: (branch outcomes observed, branch outcomes possible).
+    /// Each conditional jump contributes two outcomes: taken and not taken.
+    pub branches: BTreeMap<u32, (usize, usize)>,
+}
+
+impl FileCoverage {
+    /// Number of executable lines that ran.
+    pub fn lines_covered(&self) -> usize {
+        self.lines.values().filter(|hit| **hit).count()
+    }
+
+    /// Number of executable lines.
+    pub fn lines_total(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Number of branch outcomes observed.
+    pub fn branches_covered(&self) -> usize {
+        self.branches.values().map(|(covered, _)| covered).sum()
+    }
+
+    /// Number of possible branch outcomes.
+    pub fn branches_total(&self) -> usize {
+        self.branches.values().map(|(_, total)| total).sum()
+    }
+
+    /// Percentage of executable lines that ran (100 when there are none).
+    pub fn line_percent(&self) -> f64 {
+        percent(self.lines_covered(), self.lines_total())
+    }
+
+    /// Percentage of branch outcomes observed (100 when there are none).
+    pub fn branch_percent(&self) -> f64 {
+        percent(self.branches_covered(), self.branches_total())
+    }
+
+    /// Executable lines that never ran, in ascending order.
+    pub fn uncovered_lines(&self) -> Vec<u32> {
+        self.lines
+            .iter()
+            .filter(|(_, hit)| !**hit)
+            .map(|(line, _)| *line)
+            .collect()
+    }
+}
+
+/// Coverage of every source file in a module.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoverageReport {
+    /// Per-file coverage, sorted by path.
+    pub files: Vec<FileCoverage>,
+}
+
+fn percent(covered: usize, total: usize) -> f64 {
+    if total == 0 {
+        100.0
+    } else {
+        covered as f64 * 100.0 / total as f64
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::bytecode::module::{DebugInfo, FunctionDebugInfo};
+
+    /// `if (true) {} return;` — ConstTrue; JmpIfFalse +0; ReturnVoid, one line each.
+    fn branch_module() -> Module {
+        let mut module = Module::new("test".to_string());
+        let mut code = vec![Opcode::ConstTrue.to_u8(), Opcode::JmpIfFalse.to_u8()];
+        code.extend_from_slice(&0i32.to_le_bytes());
+        code.push(Opcode::ReturnVoid.to_u8());
+        module.functions.push(crate::compiler::Function {
+            name: "main".to_string(),
+            param_count: 0,
+            local_count: 0,
+            code,
+        });
+
+        let mut debug_info = DebugInfo::new();
+        debug_info.add_source_file("app.raya".to_string());
+        let mut func_debug = FunctionDebugInfo::new(0, 1, 1, 3, 1);
+        func_debug.add_line_entry(0, 1, 1);
+        func_debug.add_line_entry(1, 2, 1);
+        func_debug.add_line_entry(6, 3, 1);
+        debug_info.functions.push(func_debug);
+        module.debug_info = Some(debug_info);
+        module
+    }
+
+    #[test]
+    fn test_report_without_hits_is_uncovered() {
+        let module = branch_module();
+        let report = CoverageRecorder::new().report(&module);
+        assert_eq!(report.files.len(), 1);
+        let file = &report.files[0];
+        assert_eq!(file.path, "app.raya");
+        assert_eq!(file.lines_total(), 3);
+        assert_eq!(file.lines_covered(), 0);
+        assert_eq!(file.branches_total(), 2);
+        assert_eq!(file.uncovered_lines(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_report_counts_lines_and_branch_outcomes() {
+        let module = branch_module();
+        let mut buffer = CoverageBuffer::default();
+        buffer.record_instruction(&module, 0, 0);
+        buffer.record_instruction(&module, 0, 1);
+        buffer.record_branch(&module, 0, 1, false);
+        let recorder = CoverageRecorder::new();
+        recorder.merge(&mut buffer);
+
+        let file = &recorder.report(&module).files[0];
+        assert_eq!(file.lines_covered(), 2);
+        assert_eq!(file.uncovered_lines(), vec![3]);
+        assert_eq!(file.branches_covered(), 1);
+        assert_eq!(file.branch_percent(), 50.0);
+    }
+
+    #[test]
+    fn test_merge_combines_buffers_from_several_interpreters() {
+        let module = branch_module();
+        let recorder = CoverageRecorder::new();
+        let mut first = CoverageBuffer::default();
+        first.record_instruction(&module, 0, 0);
+        first.record_branch(&module, 0, 1, true);
+        let mut second = CoverageBuffer::default();
+        second.record_instruction(&module, 0, 6);
+        second.record_branch(&module, 0, 1, false);
+        recorder.merge(&mut first);
+        recorder.merge(&mut second);
+
+        let file = &recorder.report(&module).files[0];
+        assert_eq!(file.uncovered_lines(), vec![2]);
+        assert_eq!(file.branches_covered(), 2);
+    }
+
+    #[test]
+    fn test_report_without_debug_info_is_empty() {
+        let mut module = branch_module();
+        module.debug_info = None;
+        assert!(CoverageRecorder::new().report(&module).files.is_empty());
+    }
+}
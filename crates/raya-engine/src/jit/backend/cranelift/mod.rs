@@ -166,6 +166,7 @@ mod tests {
             debug_info: None,
             native_functions: vec![],
             jit_hints: vec![],
+            json_schemas: vec![],
         }
     }
 
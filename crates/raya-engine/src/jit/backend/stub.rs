@@ -99,6 +99,7 @@ mod tests {
             debug_info: None,
             native_functions: vec![],
             jit_hints: vec![],
+            json_schemas: vec![],
         }
     }
 
@@ -20,6 +20,7 @@ use crate::jit::backend::CraneliftBackend;
 use crate::jit::ir::instr::JitFunction;
 use crate::jit::pipeline::prewarm::PrewarmConfig;
 use crate::jit::pipeline::JitPipeline;
+use crate::jit::profiling::hot_profile::HotProfile;
 use crate::jit::runtime::code_cache::{CodeCache, LayoutDependency};
 
 /// Default code cache size: 64 MB
@@ -49,6 +50,16 @@ pub struct JitConfig {
     pub loop_threshold: u32,
     /// Maximum bytecode size for on-the-fly compilation candidates (default: 4096)
     pub max_adaptive_function_size: usize,
+    /// Generate float code bit-identical to the interpreter (default: false).
+    ///
+    /// Disables fused multiply-add selection and canonicalizes NaN results so
+    /// every float operation rounds exactly as the interpreter's does.
+    pub deterministic_float: bool,
+    /// Hot functions recorded by a previous run (default: None).
+    ///
+    /// Recorded functions are compiled at module load, ahead of and in
+    /// addition to the `max_prewarm_functions` heuristic candidates.
+    pub hot_profile: Option<Arc<HotProfile>>,
 }
 
 impl Default for JitConfig {
@@ -64,6 +75,8 @@ impl Default for JitConfig {
             call_threshold: 1000,
             loop_threshold: 10_000,
             max_adaptive_function_size: 4096,
+            deterministic_float: false,
+            hot_profile: None,
         }
     }
 }
@@ -102,7 +115,7 @@ impl JitEngine {
         };
 
         // Create the cranelift_jit JITModule for executable code
-        let jit_module = Self::create_jit_module()?;
+        let jit_module = Self::create_jit_module(config.deterministic_float)?;
 
         let code_cache = Arc::new(CodeCache::new(config.max_code_cache_size));
 
@@ -115,7 +128,7 @@ impl JitEngine {
     }
 
     /// Create a cranelift_jit JITModule targeting the host
-    fn create_jit_module() -> Result<JITModule, CodegenError> {
+    fn create_jit_module(deterministic_float: bool) -> Result<JITModule, CodegenError> {
         let mut flag_builder = settings::builder();
         flag_builder
             .set("opt_level", "speed")
@@ -124,10 +137,27 @@ impl JitEngine {
         flag_builder
             .set("is_pic", "false")
             .map_err(|e| CodegenError::BackendError(format!("Failed to set is_pic: {}", e)))?;
+        if deterministic_float {
+            flag_builder
+                .set("enable_nan_canonicalization", "true")
+                .map_err(|e| {
+                    CodegenError::BackendError(format!(
+                        "Failed to set enable_nan_canonicalization: {}",
+                        e
+                    ))
+                })?;
+        }
         let flags = settings::Flags::new(flag_builder);
 
-        let isa = cranelift_native::builder()
-            .map_err(|e| CodegenError::BackendError(format!("Failed to create native ISA: {}", e)))?
+        let mut isa_builder = cranelift_native::builder().map_err(|e| {
+            CodegenError::BackendError(format!("Failed to create native ISA: {}", e))
+        })?;
+        if deterministic_float {
+            // Only x86 exposes FMA as an optional ISA feature; other targets
+            // never fuse multiply-add on their own, so a missing flag is fine.
+            let _ = isa_builder.set("has_fma", "false");
+        }
+        let isa = isa_builder
             .finish(flags)
             .map_err(|e| CodegenError::BackendError(format!("Failed to finish ISA: {}", e)))?;
 
@@ -318,6 +348,18 @@ impl JitEngine {
         &self.code_cache
     }
 
+    /// Whether generated code uses interpreter-identical float semantics.
+    ///
+    /// Reads the live ISA settings: NaN canonicalization on and FMA off.
+    pub fn is_deterministic_float(&self) -> bool {
+        let isa = self.jit_module.isa();
+        let fma_enabled = isa
+            .isa_flags()
+            .iter()
+            .any(|flag| flag.name == "has_fma" && flag.as_bool() == Some(true));
+        isa.flags().enable_nan_canonicalization() && !fma_enabled
+    }
+
     /// Get a reference to the compilation pipeline
     pub fn pipeline(&self) -> &JitPipeline<CraneliftBackend> {
         &self.pipeline
@@ -448,6 +490,7 @@ mod tests {
             debug_info: None,
             native_functions: vec![],
             jit_hints: vec![],
+            json_schemas: vec![],
         }
     }
 
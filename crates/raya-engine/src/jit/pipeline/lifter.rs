@@ -1993,6 +1993,7 @@ mod tests {
             debug_info: None,
             native_functions: vec![],
             jit_hints: vec![],
+            json_schemas: vec![],
         }
     }
 
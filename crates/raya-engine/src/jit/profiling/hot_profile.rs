@@ -0,0 +1,281 @@
+//! Persisted hot-function profiles (`.rayaprof`)
+//!
+//! Adaptive compilation forgets everything it learned when the process exits.
+//! A `HotProfile` records which functions became hot during one run (keyed by
+//! module checksum) so the next run can compile them eagerly at module load
+//! instead of waiting for the call/loop thresholds to be reached again.
+
+use std::path::Path;
+use std::sync::atomic::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use super::counters::ModuleProfile;
+use super::policy::CompilationPolicy;
+use crate::compiler::bytecode::Module;
+
+/// Hot functions recorded across all modules of a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotProfile {
+    /// Format version (bumped on incompatible changes)
+    pub version: u32,
+    /// Per-module hot function records
+    pub modules: Vec<HotModuleProfile>,
+}
+
+/// Hot functions recorded for a single module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotModuleProfile {
+    /// Module checksum as lowercase hex (identifies the exact bytecode)
+    pub checksum: String,
+    /// Module name, for readability only
+    pub name: String,
+    /// Functions that were hot in the recorded run
+    pub functions: Vec<HotFunctionProfile>,
+}
+
+/// A single hot function and the counters that made it hot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotFunctionProfile {
+    /// Function index within the module
+    pub func_index: u32,
+    /// Function name, checked on load to reject stale entries
+    pub name: String,
+    /// Calls observed during the recorded run
+    pub call_count: u64,
+    /// Backward jumps observed during the recorded run
+    pub loop_count: u64,
+}
+
+impl HotProfile {
+    /// Current `.rayaprof` format version
+    pub const VERSION: u32 = 1;
+
+    /// Create an empty profile
+    pub fn new() -> Self {
+        HotProfile {
+            version: Self::VERSION,
+            modules: Vec::new(),
+        }
+    }
+
+    /// Record the functions of `module` that reached the policy thresholds
+    /// (or were already JIT-compiled) according to `profile`.
+    pub fn record_module(
+        &mut self,
+        module: &Module,
+        profile: &ModuleProfile,
+        policy: &CompilationPolicy,
+    ) {
+        let mut functions = Vec::new();
+        for (func_index, (func, counters)) in module
+            .functions
+            .iter()
+            .zip(profile.functions.iter())
+            .enumerate()
+        {
+            let calls = counters.call_count.load(Ordering::Relaxed);
+            let loops = counters.loop_count.load(Ordering::Relaxed);
+            let hot = calls >= policy.call_threshold
+                || loops >= policy.loop_threshold
+                || counters.is_jit_available();
+            if hot {
+                functions.push(HotFunctionProfile {
+                    func_index: func_index as u32,
+                    name: func.name.clone(),
+                    call_count: u64::from(calls),
+                    loop_count: u64::from(loops),
+                });
+            }
+        }
+        if functions.is_empty() {
+            return;
+        }
+
+        let checksum = checksum_hex(&module.checksum);
+        self.modules
+            .retain(|existing| existing.checksum != checksum);
+        self.modules.push(HotModuleProfile {
+            checksum,
+            name: module.metadata.name.clone(),
+            functions,
+        });
+        self.modules.sort_by(|a, b| a.checksum.cmp(&b.checksum));
+    }
+
+    /// Function indices recorded as hot for `module`, hottest first.
+    ///
+    /// Entries whose name no longer matches the function at that index are
+    /// skipped, so a profile from a different build is harmless.
+    pub fn hot_functions(&self, module: &Module) -> Vec<usize> {
+        let checksum = checksum_hex(&module.checksum);
+        let Some(recorded) = self.modules.iter().find(|m| m.checksum == checksum) else {
+            return Vec::new();
+        };
+
+        let mut functions = recorded
+            .functions
+            .iter()
+            .filter(|func| {
+                module
+                    .functions
+                    .get(func.func_index as usize)
+                    .is_some_and(|f| f.name == func.name)
+            })
+            .collect::<Vec<_>>();
+        functions
+            .sort_by_key(|func| std::cmp::Reverse(func.call_count.saturating_add(func.loop_count)));
+        functions
+            .into_iter()
+            .map(|func| func.func_index as usize)
+            .collect()
+    }
+
+    /// Serialize to the `.rayaprof` JSON form
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("hot profile serialization cannot fail")
+    }
+
+    /// Parse the `.rayaprof` JSON form
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let profile: HotProfile =
+            serde_json::from_str(json).map_err(|e| format!("invalid profile: {}", e))?;
+        if profile.version != Self::VERSION {
+            return Err(format!(
+                "unsupported profile version {} (expected {})",
+                profile.version,
+                Self::VERSION
+            ));
+        }
+        Ok(profile)
+    }
+
+    /// Write the profile to `path`
+    pub fn write_to_file(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Read a profile from `path`
+    pub fn read_from_file(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        Self::from_json(&json)
+    }
+}
+
+impl Default for HotProfile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn checksum_hex(checksum: &[u8; 32]) -> String {
+    checksum
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::bytecode::{Function, Opcode};
+
+    fn module_with(names: &[&str]) -> Module {
+        let mut module = Module::new("hot".to_string());
+        for name in names {
+            module.functions.push(Function {
+                name: name.to_string(),
+                param_count: 0,
+                local_count: 0,
+                code: vec![Opcode::ConstNull as u8, Opcode::Return as u8],
+            });
+        }
+        module.checksum = [7; 32];
+        module
+    }
+
+    fn policy() -> CompilationPolicy {
+        CompilationPolicy {
+            call_threshold: 10,
+            loop_threshold: 100,
+            max_function_size: 4096,
+        }
+    }
+
+    #[test]
+    fn test_records_only_functions_over_threshold() {
+        let module = module_with(&["main", "warm", "hot"]);
+        let counters = ModuleProfile::new(3);
+        for _ in 0..5 {
+            counters.record_call(1);
+        }
+        for _ in 0..20 {
+            counters.record_call(2);
+        }
+
+        let mut profile = HotProfile::new();
+        profile.record_module(&module, &counters, &policy());
+
+        assert_eq!(profile.modules.len(), 1);
+        assert_eq!(profile.modules[0].functions.len(), 1);
+        assert_eq!(profile.modules[0].functions[0].name, "hot");
+        assert_eq!(profile.modules[0].functions[0].call_count, 20);
+        assert_eq!(profile.hot_functions(&module), vec![2]);
+    }
+
+    #[test]
+    fn test_hot_functions_sorted_by_hotness() {
+        let module = module_with(&["a", "b"]);
+        let counters = ModuleProfile::new(2);
+        for _ in 0..10 {
+            counters.record_call(0);
+        }
+        for _ in 0..150 {
+            counters.record_loop(1);
+        }
+
+        let mut profile = HotProfile::new();
+        profile.record_module(&module, &counters, &policy());
+        assert_eq!(profile.hot_functions(&module), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let module = module_with(&["main", "hot"]);
+        let counters = ModuleProfile::new(2);
+        for _ in 0..10 {
+            counters.record_call(1);
+        }
+        let mut profile = HotProfile::new();
+        profile.record_module(&module, &counters, &policy());
+
+        let parsed = HotProfile::from_json(&profile.to_json()).unwrap();
+        assert_eq!(parsed, profile);
+    }
+
+    #[test]
+    fn test_stale_entries_ignored() {
+        let module = module_with(&["main", "hot"]);
+        let counters = ModuleProfile::new(2);
+        for _ in 0..10 {
+            counters.record_call(1);
+        }
+        let mut profile = HotProfile::new();
+        profile.record_module(&module, &counters, &policy());
+
+        let mut renamed = module_with(&["main", "other"]);
+        renamed.checksum = module.checksum;
+        assert!(profile.hot_functions(&renamed).is_empty());
+
+        let mut rebuilt = module_with(&["main", "hot"]);
+        rebuilt.checksum = [9; 32];
+        assert!(profile.hot_functions(&rebuilt).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_unknown_version() {
+        let json = r#"{"version": 99, "modules": []}"#;
+        assert!(HotProfile::from_json(json).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 //! Profiling infrastructure for hot function detection
 
 pub mod counters;
+pub mod hot_profile;
 pub mod policy;
 
 use crate::compiler::bytecode::Module;
@@ -6,11 +6,11 @@
 
 use crate::compiler::{Module, Opcode};
 use crate::jit::runtime::trampoline::{RuntimeContext, RuntimeHelperTable};
-use crate::vm::abi::{native_to_value, value_to_native, EngineContext};
-use crate::vm::gc::GarbageCollector;
+use crate::vm::abi::{native_to_value, value_to_native, EngineContext, NativeHeapScope};
+use crate::vm::gc::{write_barrier, GarbageCollector};
 use crate::vm::interpreter::{
-    ClassRegistry, ExecutionFrame, Interpreter, ModuleRuntimeLayout, ReturnAction,
-    RuntimeLayoutRegistry, SafepointCoordinator, ShapeAdapter, StructuralAdapterKey,
+    ClassRegistry, ConstantStringCache, ExecutionFrame, Interpreter, ModuleRuntimeLayout,
+    ReturnAction, RuntimeLayoutRegistry, SafepointCoordinator, ShapeAdapter, StructuralAdapterKey,
     StructuralSlotBinding,
 };
 use crate::vm::native_handler::NativeHandler;
@@ -63,8 +63,7 @@ pub struct JitRuntimeBridgeContext {
     pub semaphore_registry: *const SemaphoreRegistry,
     pub globals_by_index: *const parking_lot::RwLock<Vec<Value>>,
     pub builtin_global_slots: *const parking_lot::RwLock<FxHashMap<String, usize>>,
-    pub constant_string_cache:
-        *const parking_lot::RwLock<FxHashMap<([u8; 32], usize), Value>>,
+    pub constant_string_cache: *const parking_lot::RwLock<ConstantStringCache>,
     pub ephemeral_gc_roots: *const parking_lot::RwLock<Vec<Value>>,
     pub pinned_handles: *const parking_lot::RwLock<rustc_hash::FxHashSet<u64>>,
     pub tasks: *const Arc<parking_lot::RwLock<FxHashMap<TaskId, Arc<Task>>>>,
@@ -90,6 +89,8 @@ pub struct JitRuntimeBridgeContext {
     pub io_submit_tx: *const crossbeam::channel::Sender<IoSubmission>,
     pub max_preemptions: u32,
     pub current_frame_depth: usize,
+    /// Interpreters built for bridge calls canonicalize float NaNs
+    pub deterministic_float: bool,
 }
 
 /// Build a runtime context for a JIT invocation running inside interpreter thread loop.
@@ -104,7 +105,7 @@ pub fn build_runtime_bridge_context(
     semaphore_registry: &SemaphoreRegistry,
     globals_by_index: &parking_lot::RwLock<Vec<Value>>,
     builtin_global_slots: &parking_lot::RwLock<FxHashMap<String, usize>>,
-    constant_string_cache: &parking_lot::RwLock<FxHashMap<([u8; 32], usize), Value>>,
+    constant_string_cache: &parking_lot::RwLock<ConstantStringCache>,
     ephemeral_gc_roots: &parking_lot::RwLock<Vec<Value>>,
     pinned_handles: &parking_lot::RwLock<rustc_hash::FxHashSet<u64>>,
     tasks: &Arc<parking_lot::RwLock<FxHashMap<TaskId, Arc<Task>>>>,
@@ -158,6 +159,7 @@ pub fn build_runtime_bridge_context(
         io_submit_tx: io_submit_tx.map_or(std::ptr::null(), |tx| tx as *const _),
         max_preemptions,
         current_frame_depth,
+        deterministic_float: false,
     }
 }
 
@@ -467,7 +469,7 @@ fn jit_build_interpreter<'a>(bridge: &'a JitRuntimeBridgeContext) -> Option<Inte
         return None;
     }
 
-    Some(Interpreter::new(
+    let mut interpreter = Interpreter::new(
         unsafe { &*bridge.gc },
         unsafe { &*bridge.classes },
         unsafe { &*bridge.layouts },
@@ -498,7 +500,9 @@ fn jit_build_interpreter<'a>(bridge: &'a JitRuntimeBridgeContext) -> Option<Inte
         },
         bridge.max_preemptions,
         unsafe { &*bridge.stack_pool },
-    ))
+    );
+    interpreter.set_deterministic_float(bridge.deterministic_float);
+    Some(interpreter)
 }
 
 fn jit_raise_vm_error(bridge: &JitRuntimeBridgeContext, error: VmError) {
@@ -1088,6 +1092,7 @@ unsafe extern "C" fn helper_native_call_dispatch(
                 value_args.iter().map(|v| value_to_native(*v)).collect();
 
             let resolved = (&*bridge.resolved_natives).read();
+            let _heap = NativeHeapScope::enter(&*bridge.gc);
             match resolved.call(native_id, &ctx, &native_args) {
                 NativeCallResult::Value(v) => return native_to_value(v).raw(),
                 NativeCallResult::Suspend(io_request) => {
@@ -1406,6 +1411,7 @@ unsafe extern "C" fn helper_object_set_field(
         }
         StructuralSlotBinding::Dynamic(key) => {
             object.ensure_dyn_map().insert(key, Value::from_raw(value_raw));
+            write_barrier(Value::from_raw(value_raw));
             true
         }
         StructuralSlotBinding::Method(_) | StructuralSlotBinding::Missing => false,
@@ -1564,8 +1570,10 @@ unsafe extern "C" fn helper_object_set_shape_field(
             .set_field(slot, Value::from_raw(value_raw))
             .map(|_| JIT_STORE_SUCCESS)
             .unwrap_or(JIT_STORE_FALLBACK),
+        StructuralSlotBinding::Dynamic(_) if object.is_frozen() => JIT_STORE_FALLBACK,
         StructuralSlotBinding::Dynamic(key) => {
             object.ensure_dyn_map().insert(key, Value::from_raw(value_raw));
+            write_barrier(Value::from_raw(value_raw));
             JIT_STORE_SUCCESS
         }
         StructuralSlotBinding::Method(_) | StructuralSlotBinding::Missing => JIT_STORE_FALLBACK,
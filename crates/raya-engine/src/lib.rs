@@ -71,6 +71,9 @@ pub mod aot_profile;
 /// Profiler module: sampling-based CPU/wall-clock profiling
 pub mod profiler;
 
+/// Coverage module: line and branch coverage collection
+pub mod coverage;
+
 /// Linter module: AST-based lint analysis
 pub mod linter;
 
@@ -113,6 +116,7 @@ pub use parser::{
 pub use compiler::{
     // Disassembler
     disassemble_function,
+    disassemble_function_with_source,
     // IR
     ir,
     // Native IDs
@@ -204,6 +208,10 @@ pub use vm::{
     MutexError,
     MutexId,
     MutexRegistry,
+    // Native call sandboxing
+    NativeCallDecision,
+    NativeCallInfo,
+    NativeCallPolicy,
     Object,
     // Types
     PointerMap,
@@ -1,6 +1,6 @@
-//! Lint configuration: per-rule severity overrides.
+//! Lint configuration: per-rule severity overrides and enablement.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::rule::Severity;
 
@@ -9,6 +9,9 @@ use super::rule::Severity;
 pub struct LintConfig {
     /// Per-rule severity overrides. Key = rule name (e.g. "no-empty-block").
     overrides: HashMap<String, Severity>,
+    /// Rules enabled without an explicit severity. Rules that are off by
+    /// default run as warnings once enabled.
+    enabled: HashSet<String>,
 }
 
 impl LintConfig {
@@ -22,9 +25,26 @@ impl LintConfig {
         self.overrides.insert(rule_name.to_string(), severity);
     }
 
+    /// Enable or disable a rule, keeping its default severity when enabled.
+    pub fn set_enabled(&mut self, rule_name: &str, enabled: bool) {
+        if enabled {
+            self.enabled.insert(rule_name.to_string());
+            if self.overrides.get(rule_name) == Some(&Severity::Off) {
+                self.overrides.remove(rule_name);
+            }
+        } else {
+            self.enabled.remove(rule_name);
+            self.overrides.insert(rule_name.to_string(), Severity::Off);
+        }
+    }
+
     /// Get the effective severity for a rule, falling back to its default.
     pub fn effective_severity(&self, rule_name: &str, default: Severity) -> Severity {
-        self.overrides.get(rule_name).copied().unwrap_or(default)
+        match self.overrides.get(rule_name) {
+            Some(&severity) => severity,
+            None if default == Severity::Off && self.enabled.contains(rule_name) => Severity::Warn,
+            None => default,
+        }
     }
 
     /// Check if a rule is explicitly disabled.
@@ -79,4 +99,24 @@ mod tests {
             Severity::Off
         );
     }
+
+    #[test]
+    fn test_enable_opt_in_rule() {
+        let mut config = LintConfig::new();
+        config.set_enabled("no-implicit-any", true);
+
+        assert_eq!(
+            config.effective_severity("no-implicit-any", Severity::Off),
+            Severity::Warn
+        );
+        // Enabling keeps a non-off default untouched
+        config.set_enabled("prefer-const", true);
+        assert_eq!(
+            config.effective_severity("prefer-const", Severity::Error),
+            Severity::Error
+        );
+
+        config.set_enabled("no-implicit-any", false);
+        assert!(config.is_disabled("no-implicit-any"));
+    }
 }
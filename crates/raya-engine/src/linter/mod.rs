@@ -6,9 +6,13 @@
 //! # Architecture
 //!
 //! - Each rule implements [`LintRule`] and checks individual AST nodes.
+//!   Rules that reason about bindings (unused, shadowed, never reassigned)
+//!   read the module's [`ScopeAnalysis`](scope::ScopeAnalysis) from the
+//!   [`LintContext`], which is computed once per lint run.
 //! - The [`LintRunner`](runner::LintRunner) walks the AST once and dispatches
 //!   to all enabled rules (single-pass visitor).
-//! - [`Linter`] is the public entry point: create one, then call
+//! - [`Linter`] is the public entry point: create one, optionally register
+//!   extra rules with [`add_rule`](Linter::add_rule), then call
 //!   [`lint_source`](Linter::lint_source) or [`lint_ast`](Linter::lint_ast).
 //!
 //! # Example
@@ -27,6 +31,7 @@ pub mod config;
 pub mod rule;
 pub mod rules;
 mod runner;
+pub mod scope;
 
 pub use config::LintConfig;
 pub use rule::{Category, LintContext, LintDiagnostic, LintFix, LintRule, RuleMeta, Severity};
@@ -34,6 +39,7 @@ pub use rule::{Category, LintContext, LintDiagnostic, LintFix, LintRule, RuleMet
 use crate::parser::interner::Interner;
 use crate::parser::Parser;
 use runner::LintRunner;
+use scope::ScopeAnalysis;
 
 /// Result of linting a single file.
 #[derive(Debug)]
@@ -69,6 +75,11 @@ impl Linter {
         }
     }
 
+    /// Register an additional rule alongside the built-in ones.
+    pub fn add_rule(&mut self, rule: Box<dyn LintRule>) {
+        self.rules.push(rule);
+    }
+
     /// Lint a parsed AST module.
     ///
     /// The caller provides the AST, source, interner, and file path.
@@ -79,23 +90,29 @@ impl Linter {
         interner: &Interner,
         file_path: &str,
     ) -> LintResult {
-        // Filter out disabled rules and apply severity overrides.
-        let _active_rules: Vec<&Box<dyn LintRule>> = self
+        // Only run rules whose effective severity is not `Off`; this skips
+        // both disabled rules and opt-in rules that were not enabled.
+        let active_rules: Vec<&dyn LintRule> = self
             .rules
             .iter()
-            .filter(|r| !self.config.is_disabled(r.meta().name))
+            .map(|r| r.as_ref())
+            .filter(|r| {
+                let meta = r.meta();
+                self.config
+                    .effective_severity(meta.name, meta.default_severity)
+                    != Severity::Off
+            })
             .collect();
 
-        // Wrap active rules so the runner can use them.
-        // We pass references, but runner expects &[Box<dyn LintRule>].
-        // Instead, build a temporary vec of trait-object references.
+        let scopes = ScopeAnalysis::analyze(module);
         let ctx = LintContext {
             source,
             interner,
             file_path,
+            scopes: &scopes,
         };
 
-        let runner = LintRunner::new(&self.rules, ctx);
+        let runner = LintRunner::new(&active_rules, ctx);
         let mut diagnostics = runner.run(module);
 
         // Apply severity overrides and filter disabled.
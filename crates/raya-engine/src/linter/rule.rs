@@ -3,6 +3,7 @@
 //! Each lint rule implements `LintRule` and provides metadata (`RuleMeta`),
 //! and one or more `check_*` methods that inspect AST nodes.
 
+use super::scope::ScopeAnalysis;
 use crate::parser::ast;
 use crate::parser::interner::Interner;
 use crate::parser::token::Span;
@@ -53,6 +54,8 @@ pub struct LintContext<'a> {
     pub interner: &'a Interner,
     /// Path of the file being linted.
     pub file_path: &'a str,
+    /// Bindings of the module, analyzed once and shared by all rules.
+    pub scopes: &'a ScopeAnalysis,
 }
 
 /// A suggested auto-fix: replace a span with new text.
@@ -11,10 +11,14 @@ pub mod no_duplicate_imports;
 pub mod no_empty_block;
 pub mod no_fallthrough;
 pub mod no_floating_promise;
+pub mod no_implicit_any;
 pub mod no_invalid_typeof;
 pub mod no_self_assign;
+pub mod no_shadow;
 pub mod no_throw_literal;
 pub mod no_typeof_class;
+pub mod no_unused_imports;
+pub mod no_unused_var;
 pub mod prefer_const;
 
 use super::rule::LintRule;
@@ -32,6 +36,9 @@ pub fn all_rules() -> Vec<Box<dyn LintRule>> {
         Box::new(no_floating_promise::NoFloatingPromise),
         Box::new(no_invalid_typeof::NoInvalidTypeof),
         Box::new(no_typeof_class::NoTypeofClass),
+        Box::new(no_unused_var::NoUnusedVar),
+        Box::new(no_unused_imports::NoUnusedImports),
+        Box::new(no_shadow::NoShadow),
         // Style
         Box::new(no_empty_block::NoEmptyBlock),
         Box::new(prefer_const::PreferConst),
@@ -41,5 +48,6 @@ pub fn all_rules() -> Vec<Box<dyn LintRule>> {
         // Best Practice
         Box::new(no_throw_literal::NoThrowLiteral),
         Box::new(no_async_without_await::NoAsyncWithoutAwait),
+        Box::new(no_implicit_any::NoImplicitAny),
     ]
 }
@@ -54,6 +54,7 @@ fn is_constant(expr: &ast::Expression) -> bool {
         ast::Expression::BooleanLiteral(_)
             | ast::Expression::IntLiteral(_)
             | ast::Expression::FloatLiteral(_)
+            | ast::Expression::BigIntLiteral(_)
             | ast::Expression::StringLiteral(_)
             | ast::Expression::NullLiteral(_)
     )
@@ -37,9 +37,14 @@ impl LintRule for NoFallthrough {
         let case_count = switch.cases.len();
 
         for (i, case) in switch.cases.iter().enumerate() {
-            // Skip the last case (no fallthrough possible) and empty cases
-            // (empty cases are used for grouping: `case A: case B: ...`).
-            if i == case_count - 1 || case.consequent.is_empty() {
+            // Skip the last case (no fallthrough possible), empty cases (used
+            // for grouping: `case A: case B: ...`), guarded cases (which break
+            // implicitly) and cases that ask for `fallthrough`.
+            if i == case_count - 1
+                || case.consequent.is_empty()
+                || case.guard.is_some()
+                || case.fallthrough
+            {
                 continue;
             }
 
@@ -126,6 +131,49 @@ function f(x: int): void {
         assert!(!has_rule(&diags, "L1005"), "cases with break should be ok");
     }
 
+    #[test]
+    fn test_explicit_fallthrough_is_per_case() {
+        let source = r#"
+function f(x: int): void {
+    switch (x) {
+        case 1:
+            const a: int = 1;
+            fallthrough;
+        case 2:
+            const b: int = 2;
+        case 3:
+            break;
+    }
+}
+"#;
+        let diags = lint(source);
+        let flagged = diags.iter().filter(|d| d.code == "L1005").count();
+        assert_eq!(
+            flagged, 1,
+            "only the unguarded case without `fallthrough` falls through implicitly, got: {:?}",
+            diags
+        );
+    }
+
+    #[test]
+    fn test_guarded_case_ok() {
+        let source = r#"
+function f(x: int): void {
+    switch (x) {
+        case 1 when x > 0:
+            const a: int = 1;
+        case 2:
+            break;
+    }
+}
+"#;
+        let diags = lint(source);
+        assert!(
+            !has_rule(&diags, "L1005"),
+            "guarded cases end with an implicit break"
+        );
+    }
+
     #[test]
     fn test_empty_case_grouping_ok() {
         let source = r#"
@@ -0,0 +1,122 @@
+//! Rule: no-implicit-any (L3003)
+//!
+//! Flags function and method parameters without a type annotation, and
+//! `let` declarations with neither a type annotation nor an initializer.
+//! Mostly useful for `--mode ts/js` sources, so it is off by default.
+
+use crate::linter::rule::*;
+use crate::parser::ast;
+
+pub struct NoImplicitAny;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-implicit-any",
+    code: "L3003",
+    description: "Require type annotations where the type cannot be inferred",
+    category: Category::BestPractice,
+    default_severity: Severity::Off,
+    fixable: false,
+};
+
+fn check_params(params: &[ast::Parameter], ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+    params
+        .iter()
+        .filter(|p| p.type_annotation.is_none() && p.default_value.is_none())
+        .map(|p| {
+            let name = match &p.pattern {
+                ast::Pattern::Identifier(id) => ctx.interner.resolve(id.name).to_string(),
+                _ => "<pattern>".to_string(),
+            };
+            diagnostic(
+                format!("Parameter '{}' implicitly has type 'any'", name),
+                *p.pattern.span(),
+            )
+        })
+        .collect()
+}
+
+fn diagnostic(message: String, span: crate::parser::token::Span) -> LintDiagnostic {
+    LintDiagnostic {
+        rule: META.name,
+        code: META.code,
+        message,
+        span,
+        severity: META.default_severity,
+        fix: None,
+        notes: vec!["Add a type annotation.".to_string()],
+    }
+}
+
+impl LintRule for NoImplicitAny {
+    fn meta(&self) -> &RuleMeta {
+        &META
+    }
+
+    fn check_statement(&self, stmt: &ast::Statement, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        match stmt {
+            ast::Statement::FunctionDecl(decl) => check_params(&decl.params, ctx),
+            ast::Statement::VariableDecl(decl)
+                if decl.type_annotation.is_none() && decl.initializer.is_none() =>
+            {
+                let name = match &decl.pattern {
+                    ast::Pattern::Identifier(id) => ctx.interner.resolve(id.name).to_string(),
+                    _ => "<pattern>".to_string(),
+                };
+                vec![diagnostic(
+                    format!("Variable '{}' implicitly has type 'any'", name),
+                    decl.span,
+                )]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn check_class_member(
+        &self,
+        member: &ast::ClassMember,
+        ctx: &LintContext<'_>,
+    ) -> Vec<LintDiagnostic> {
+        match member {
+            ast::ClassMember::Method(method) => check_params(&method.params, ctx),
+            ast::ClassMember::Constructor(ctor) => check_params(&ctor.params, ctx),
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linter::rule::{LintDiagnostic, Severity};
+    use crate::linter::{LintConfig, Linter};
+
+    fn lint_enabled(source: &str) -> Vec<LintDiagnostic> {
+        let mut config = LintConfig::new();
+        config.set_severity("no-implicit-any", Severity::Warn);
+        let linter = Linter::with_config(config);
+        linter.lint_source(source, "test.raya").diagnostics
+    }
+
+    fn has_rule(diags: &[LintDiagnostic], code: &str) -> bool {
+        diags.iter().any(|d| d.code == code)
+    }
+
+    #[test]
+    fn test_off_by_default() {
+        let diags = Linter::new()
+            .lint_source("function f(x): void {}", "test.raya")
+            .diagnostics;
+        assert!(!has_rule(&diags, "L3003"));
+    }
+
+    #[test]
+    fn test_untyped_param_flagged_when_enabled() {
+        let diags = lint_enabled("function f(x): void {}");
+        assert!(has_rule(&diags, "L3003"), "got: {:?}", diags);
+    }
+
+    #[test]
+    fn test_typed_param_not_flagged() {
+        let diags = lint_enabled("function f(x: int): void {}");
+        assert!(!has_rule(&diags, "L3003"));
+    }
+}
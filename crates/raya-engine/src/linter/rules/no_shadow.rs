@@ -0,0 +1,82 @@
+//! Rule: no-shadow (L1009)
+//!
+//! Flags declarations that shadow a binding of an enclosing scope, which
+//! makes the outer binding unreachable and is a frequent source of confusion.
+
+use crate::linter::rule::*;
+use crate::parser::ast;
+
+pub struct NoShadow;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-shadow",
+    code: "L1009",
+    description: "Disallow declarations that shadow an outer-scope binding",
+    category: Category::Correctness,
+    default_severity: Severity::Warn,
+    fixable: false,
+};
+
+impl LintRule for NoShadow {
+    fn meta(&self) -> &RuleMeta {
+        &META
+    }
+
+    fn check_module(&self, _module: &ast::Module, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        ctx.scopes
+            .bindings
+            .iter()
+            .filter_map(|b| {
+                let outer = &ctx.scopes.bindings[b.shadows?];
+                let name_str = ctx.interner.resolve(b.name);
+                Some(LintDiagnostic {
+                    rule: META.name,
+                    code: META.code,
+                    message: format!("'{}' shadows a declaration in an outer scope", name_str),
+                    span: b.span,
+                    severity: META.default_severity,
+                    fix: None,
+                    notes: vec![format!(
+                        "'{}' is first declared at line {}.",
+                        name_str, outer.span.line
+                    )],
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linter::rule::LintDiagnostic;
+    use crate::linter::Linter;
+
+    fn lint(source: &str) -> Vec<LintDiagnostic> {
+        let linter = Linter::new();
+        linter.lint_source(source, "test.raya").diagnostics
+    }
+
+    fn has_rule(diags: &[LintDiagnostic], code: &str) -> bool {
+        diags.iter().any(|d| d.code == code)
+    }
+
+    #[test]
+    fn test_inner_shadow_flagged() {
+        let diags = lint("const x: int = 1;\nfunction f(): int { const x: int = 2; return x; }");
+        assert!(has_rule(&diags, "L1009"), "got: {:?}", diags);
+    }
+
+    #[test]
+    fn test_param_shadow_flagged() {
+        let diags = lint("const x: int = 1;\nfunction f(x: int): int { return x; }");
+        assert!(has_rule(&diags, "L1009"));
+    }
+
+    #[test]
+    fn test_sibling_scopes_not_flagged() {
+        let diags = lint(
+            "function f(): int { const x: int = 1; return x; }\nfunction g(): int { const x: int = 2; return x; }",
+        );
+        assert!(!has_rule(&diags, "L1009"));
+    }
+}
@@ -54,6 +54,7 @@ fn is_literal(expr: &ast::Expression) -> bool {
         ast::Expression::StringLiteral(_)
             | ast::Expression::IntLiteral(_)
             | ast::Expression::FloatLiteral(_)
+            | ast::Expression::BigIntLiteral(_)
             | ast::Expression::BooleanLiteral(_)
             | ast::Expression::NullLiteral(_)
     )
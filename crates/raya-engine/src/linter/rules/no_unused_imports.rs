@@ -0,0 +1,180 @@
+//! Rule: no-unused-imports (L1012)
+//!
+//! Flags imported names that are never referenced. Re-exported names count
+//! as used. The fix drops the unused names from the import, or removes the
+//! whole statement when none of its names are used.
+
+use crate::linter::rule::*;
+use crate::parser::ast;
+use crate::parser::checker::unused_imports::{find_unused_imports, import_local_name};
+use crate::parser::token::Span;
+
+pub struct NoUnusedImports;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-unused-imports",
+    code: "L1012",
+    description: "Disallow imported names that are never used",
+    category: Category::Correctness,
+    default_severity: Severity::Warn,
+    fixable: true,
+};
+
+impl LintRule for NoUnusedImports {
+    fn meta(&self) -> &RuleMeta {
+        &META
+    }
+
+    fn check_module(&self, module: &ast::Module, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        let unused = find_unused_imports(module);
+        let mut diagnostics = Vec::new();
+
+        for (i, import) in unused.iter().enumerate() {
+            // One fix per statement, attached to its first unused name.
+            let first_in_decl = i == 0 || !std::ptr::eq(unused[i - 1].decl, import.decl);
+            let fix = first_in_decl.then(|| {
+                let unused_here: Vec<Span> = unused
+                    .iter()
+                    .filter(|u| std::ptr::eq(u.decl, import.decl))
+                    .map(|u| u.local.span)
+                    .collect();
+                import_fix(import.decl, &unused_here, ctx)
+            });
+
+            diagnostics.push(LintDiagnostic {
+                rule: META.name,
+                code: META.code,
+                message: format!(
+                    "'{}' is imported but never used",
+                    ctx.interner.resolve(import.local.name)
+                ),
+                span: import.local.span,
+                severity: META.default_severity,
+                fix,
+                notes: vec![],
+            });
+        }
+
+        diagnostics
+    }
+}
+
+/// Rewrite `decl` without the names declared at `unused`, or delete the
+/// statement (with its semicolon and line break) if nothing is left.
+fn import_fix(decl: &ast::ImportDecl, unused: &[Span], ctx: &LintContext<'_>) -> LintFix {
+    let kept: Vec<&ast::ImportSpecifier> = decl
+        .specifiers
+        .iter()
+        .filter(|spec| !unused.contains(&import_local_name(spec).span))
+        .collect();
+
+    if kept.is_empty() {
+        let rest = &ctx.source[decl.span.end..];
+        let mut end = decl.span.end;
+        if rest.starts_with(';') {
+            end += 1;
+        }
+        let trailing = &ctx.source[end..];
+        let line_rest = trailing.len() - trailing.trim_start_matches([' ', '\t']).len();
+        if trailing[line_rest..].starts_with("\r\n") {
+            end += line_rest + 2;
+        } else if trailing[line_rest..].starts_with('\n') {
+            end += line_rest + 1;
+        }
+        return LintFix {
+            span: Span::new(decl.span.start, end, decl.span.line, decl.span.column),
+            replacement: String::new(),
+        };
+    }
+
+    let mut clauses = Vec::new();
+    let mut named = Vec::new();
+    for spec in kept {
+        match spec {
+            ast::ImportSpecifier::Default(local) => {
+                clauses.push(ctx.interner.resolve(local.name).to_string());
+            }
+            ast::ImportSpecifier::Namespace(local) => {
+                clauses.push(format!("* as {}", ctx.interner.resolve(local.name)));
+            }
+            ast::ImportSpecifier::Named { name, alias } => named.push(match alias {
+                Some(alias) => format!(
+                    "{} as {}",
+                    ctx.interner.resolve(name.name),
+                    ctx.interner.resolve(alias.name)
+                ),
+                None => ctx.interner.resolve(name.name).to_string(),
+            }),
+        }
+    }
+    if !named.is_empty() {
+        clauses.push(format!("{{ {} }}", named.join(", ")));
+    }
+
+    let source = &ctx.source[decl.source.span.start..decl.source.span.end];
+    LintFix {
+        span: decl.span,
+        replacement: format!("import {} from {}", clauses.join(", "), source),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linter::rule::LintDiagnostic;
+    use crate::linter::Linter;
+
+    fn lint(source: &str) -> Vec<LintDiagnostic> {
+        let linter = Linter::new();
+        linter.lint_source(source, "test.raya").diagnostics
+    }
+
+    fn unused_imports(diags: &[LintDiagnostic]) -> Vec<&LintDiagnostic> {
+        diags.iter().filter(|d| d.code == "L1012").collect()
+    }
+
+    #[test]
+    fn test_unused_named_import_flagged() {
+        let diags = lint("import { foo } from \"./a\";\n");
+        let unused = unused_imports(&diags);
+        assert_eq!(unused.len(), 1, "got: {:?}", diags);
+        assert_eq!(unused[0].message, "'foo' is imported but never used");
+    }
+
+    #[test]
+    fn test_used_imports_not_flagged() {
+        let source = r#"
+import { foo, Point } from "./a";
+import * as ns from "./b";
+import { bar } from "./c";
+export { bar };
+function f(p: Point): number { return foo(p) + ns.value; }
+"#;
+        let diags = lint(source);
+        assert!(unused_imports(&diags).is_empty(), "got: {:?}", diags);
+    }
+
+    #[test]
+    fn test_fix_removes_whole_import_line() {
+        let source = "import { foo } from \"./a\";\nexport const x: int = 1;\n";
+        let diags = lint(source);
+        let fix = unused_imports(&diags)[0].fix.as_ref().expect("fixable");
+        let mut fixed = source.to_string();
+        fixed.replace_range(fix.span.start..fix.span.end, &fix.replacement);
+        assert_eq!(fixed, "export const x: int = 1;\n");
+    }
+
+    #[test]
+    fn test_fix_keeps_used_names() {
+        let source = "import d, { foo, bar as baz } from \"./a\";\nexport { d, baz };\n";
+        let diags = lint(source);
+        let unused = unused_imports(&diags);
+        assert_eq!(unused.len(), 1, "got: {:?}", diags);
+        let fix = unused[0].fix.as_ref().expect("fixable");
+        let mut fixed = source.to_string();
+        fixed.replace_range(fix.span.start..fix.span.end, &fix.replacement);
+        assert_eq!(
+            fixed,
+            "import d, { bar as baz } from \"./a\";\nexport { d, baz };\n"
+        );
+    }
+}
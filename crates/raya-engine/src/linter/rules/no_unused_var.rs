@@ -0,0 +1,109 @@
+//! Rule: no-unused-var (L1008)
+//!
+//! Flags `let`/`const` bindings that are never read. Assigning to a variable
+//! does not count as a use. Module-level, exported and `_`-prefixed bindings
+//! are skipped.
+
+use crate::linter::rule::*;
+use crate::linter::scope::BindingKind;
+use crate::parser::ast;
+
+pub struct NoUnusedVar;
+
+static META: RuleMeta = RuleMeta {
+    name: "no-unused-var",
+    code: "L1008",
+    description: "Disallow variables that are declared but never read",
+    category: Category::Correctness,
+    default_severity: Severity::Warn,
+    fixable: false,
+};
+
+impl LintRule for NoUnusedVar {
+    fn meta(&self) -> &RuleMeta {
+        &META
+    }
+
+    fn check_module(&self, _module: &ast::Module, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        ctx.scopes
+            .bindings
+            .iter()
+            .filter(|b| matches!(b.kind, BindingKind::Let | BindingKind::Const))
+            .filter(|b| !b.referenced && !b.exported && !b.top_level)
+            .filter_map(|b| {
+                let name_str = ctx.interner.resolve(b.name);
+                if name_str.starts_with('_') {
+                    return None;
+                }
+                Some(LintDiagnostic {
+                    rule: META.name,
+                    code: META.code,
+                    message: format!("'{}' is declared but never read", name_str),
+                    span: b.span,
+                    severity: META.default_severity,
+                    fix: None,
+                    notes: vec![
+                        "Remove it, or prefix it with '_' if it is intentionally unused."
+                            .to_string(),
+                    ],
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linter::rule::LintDiagnostic;
+    use crate::linter::Linter;
+
+    fn lint(source: &str) -> Vec<LintDiagnostic> {
+        let linter = Linter::new();
+        linter.lint_source(source, "test.raya").diagnostics
+    }
+
+    fn has_rule(diags: &[LintDiagnostic], code: &str) -> bool {
+        diags.iter().any(|d| d.code == code)
+    }
+
+    #[test]
+    fn test_unused_let_flagged() {
+        let diags = lint("function f(): void { let x: int = 1; }");
+        assert!(has_rule(&diags, "L1008"), "got: {:?}", diags);
+    }
+
+    #[test]
+    fn test_read_variable_not_flagged() {
+        let diags = lint("function f(): int { const x: int = 1; return x; }");
+        assert!(!has_rule(&diags, "L1008"));
+    }
+
+    #[test]
+    fn test_write_only_variable_flagged() {
+        let diags = lint("function f(): void { let x: int = 1; x = 2; }");
+        assert!(has_rule(&diags, "L1008"));
+    }
+
+    #[test]
+    fn test_exported_not_flagged() {
+        let diags = lint("export const x: int = 1;");
+        assert!(!has_rule(&diags, "L1008"));
+    }
+
+    #[test]
+    fn test_module_level_not_flagged() {
+        let diags = lint("const x: int = 1;\nfunction f(): void { const y: int = 2; }");
+        assert_eq!(
+            diags.iter().filter(|d| d.code == "L1008").count(),
+            1,
+            "got: {:?}",
+            diags
+        );
+    }
+
+    #[test]
+    fn test_underscore_prefixed_not_flagged() {
+        let diags = lint("function f(): void { const _x: int = 1; }");
+        assert!(!has_rule(&diags, "L1008"));
+    }
+}
@@ -9,14 +9,14 @@ use super::rule::{LintContext, LintDiagnostic, LintRule};
 
 /// Runs all enabled lint rules over an AST in a single traversal.
 pub struct LintRunner<'a> {
-    rules: &'a [Box<dyn LintRule>],
+    rules: &'a [&'a dyn LintRule],
     ctx: LintContext<'a>,
     diagnostics: Vec<LintDiagnostic>,
 }
 
 impl<'a> LintRunner<'a> {
     /// Create a new runner with the given rules and context.
-    pub fn new(rules: &'a [Box<dyn LintRule>], ctx: LintContext<'a>) -> Self {
+    pub fn new(rules: &'a [&'a dyn LintRule], ctx: LintContext<'a>) -> Self {
         Self {
             rules,
             ctx,
@@ -72,6 +72,7 @@ impl<'a> Visitor for LintRunner<'a> {
 mod tests {
     use super::*;
     use crate::linter::rule::{Category, RuleMeta, Severity};
+    use crate::linter::scope::ScopeAnalysis;
     use crate::parser::interner::Interner;
     use crate::parser::token::Span;
 
@@ -113,7 +114,7 @@ mod tests {
     fn test_runner_dispatches_to_rules() {
         let mut interner = Interner::new();
         let source = "const x: int = 1;";
-        let rules: Vec<Box<dyn LintRule>> = vec![Box::new(FlagAllStatements)];
+        let rules: Vec<&dyn LintRule> = vec![&FlagAllStatements];
 
         let sym_x = interner.intern("x");
 
@@ -121,6 +122,7 @@ mod tests {
             source,
             interner: &interner,
             file_path: "test.raya",
+            scopes: &ScopeAnalysis::default(),
         };
 
         // Build a minimal module with one statement
@@ -153,11 +155,12 @@ mod tests {
     fn test_runner_empty_rules() {
         let interner = Interner::new();
         let source = "";
-        let rules: Vec<Box<dyn LintRule>> = vec![];
+        let rules: Vec<&dyn LintRule> = vec![];
         let ctx = LintContext {
             source,
             interner: &interner,
             file_path: "empty.raya",
+            scopes: &ScopeAnalysis::default(),
         };
         let module = ast::Module::new(vec![], Span::new(0, 0, 1, 1));
 
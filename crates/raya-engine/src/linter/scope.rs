@@ -0,0 +1,483 @@
+//! Lexical scope analysis shared by binding-aware lint rules.
+//!
+//! Walks a module once and records every declared binding together with how
+//! it is used: whether it is read, reassigned, exported, or shadows a binding
+//! of an enclosing scope. Declarations are hoisted to the top of their block
+//! so closures and functions may refer to bindings declared later.
+
+use std::collections::HashMap;
+
+use crate::parser::ast::{
+    self,
+    visitor::{self, Visitor},
+};
+use crate::parser::interner::Symbol;
+use crate::parser::token::Span;
+
+/// How a binding was introduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+    Let,
+    Const,
+    /// Function, method, arrow or catch parameter.
+    Param,
+    Function,
+    Class,
+    Import,
+}
+
+/// A single declared name and its usage.
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub name: Symbol,
+    pub kind: BindingKind,
+    /// Span of the declaring identifier.
+    pub span: Span,
+    /// Span of the whole declaration (e.g. the `let x = 1;` statement).
+    pub decl_span: Span,
+    /// Declared by a plain `name = init` declarator (no destructuring).
+    pub simple_with_initializer: bool,
+    /// Declared directly in the module scope.
+    pub top_level: bool,
+    pub exported: bool,
+    /// Read at least once. Assignments alone do not count as reads.
+    pub referenced: bool,
+    /// Target of an assignment, `++`/`--`, or a `for-of`/`for-in` pattern.
+    pub reassigned: bool,
+    /// Index of the binding in an enclosing scope this one shadows.
+    pub shadows: Option<usize>,
+}
+
+/// Result of analyzing a module: every binding, in declaration order.
+#[derive(Debug, Default)]
+pub struct ScopeAnalysis {
+    pub bindings: Vec<Binding>,
+}
+
+impl ScopeAnalysis {
+    /// Analyze all scopes of `module`.
+    pub fn analyze(module: &ast::Module) -> Self {
+        let mut analyzer = Analyzer {
+            bindings: Vec::new(),
+            scopes: Vec::new(),
+        };
+        analyzer.visit_module(module);
+        ScopeAnalysis {
+            bindings: analyzer.bindings,
+        }
+    }
+}
+
+struct Analyzer {
+    bindings: Vec<Binding>,
+    scopes: Vec<HashMap<Symbol, usize>>,
+}
+
+impl Analyzer {
+    fn with_scope(&mut self, f: impl FnOnce(&mut Self)) {
+        self.scopes.push(HashMap::new());
+        f(self);
+        self.scopes.pop();
+    }
+
+    fn lookup(&self, name: Symbol) -> Option<usize> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&name).copied())
+    }
+
+    fn declare(&mut self, id: &ast::Identifier, kind: BindingKind, decl_span: Span) {
+        let depth = self.scopes.len() - 1;
+        if self.scopes[depth].contains_key(&id.name) {
+            return;
+        }
+        let shadows = self.scopes[..depth]
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&id.name).copied());
+        let index = self.bindings.len();
+        self.bindings.push(Binding {
+            name: id.name,
+            kind,
+            span: id.span,
+            decl_span,
+            simple_with_initializer: false,
+            top_level: depth == 0,
+            exported: false,
+            referenced: false,
+            reassigned: false,
+            shadows,
+        });
+        self.scopes[depth].insert(id.name, index);
+    }
+
+    fn declare_variable(&mut self, decl: &ast::VariableDecl) {
+        let kind = match decl.kind {
+            ast::VariableKind::Let => BindingKind::Let,
+            ast::VariableKind::Const | ast::VariableKind::Using => BindingKind::Const,
+        };
+        self.declare_pattern(&decl.pattern, kind, decl.span);
+        if let (ast::Pattern::Identifier(id), Some(_)) = (&decl.pattern, &decl.initializer) {
+            if let Some(index) = self.lookup(id.name) {
+                self.bindings[index].simple_with_initializer = true;
+            }
+        }
+    }
+
+    fn declare_pattern(&mut self, pattern: &ast::Pattern, kind: BindingKind, decl_span: Span) {
+        match pattern {
+            ast::Pattern::Identifier(id) => self.declare(id, kind, decl_span),
+            ast::Pattern::Array(arr) => {
+                for elem in arr.elements.iter().flatten() {
+                    self.declare_pattern(&elem.pattern, kind, decl_span);
+                }
+                if let Some(rest) = &arr.rest {
+                    self.declare_pattern(rest, kind, decl_span);
+                }
+            }
+            ast::Pattern::Object(obj) => {
+                for prop in &obj.properties {
+                    self.declare_pattern(&prop.value, kind, decl_span);
+                }
+                if let Some(rest) = &obj.rest {
+                    self.declare(rest, kind, decl_span);
+                }
+            }
+            ast::Pattern::Rest(rest) => self.declare_pattern(&rest.argument, kind, decl_span),
+        }
+    }
+
+    /// Visit default-value expressions nested in a binding pattern.
+    fn visit_pattern_defaults(&mut self, pattern: &ast::Pattern) {
+        match pattern {
+            ast::Pattern::Identifier(_) => {}
+            ast::Pattern::Array(arr) => {
+                for elem in arr.elements.iter().flatten() {
+                    self.visit_pattern_defaults(&elem.pattern);
+                    if let Some(default) = &elem.default {
+                        self.visit_expression(default);
+                    }
+                }
+                if let Some(rest) = &arr.rest {
+                    self.visit_pattern_defaults(rest);
+                }
+            }
+            ast::Pattern::Object(obj) => {
+                for prop in &obj.properties {
+                    self.visit_pattern_defaults(&prop.value);
+                    if let Some(default) = &prop.default {
+                        self.visit_expression(default);
+                    }
+                }
+            }
+            ast::Pattern::Rest(rest) => self.visit_pattern_defaults(&rest.argument),
+        }
+    }
+
+    /// Mark every identifier in an assignment-target pattern as reassigned.
+    fn reassign_pattern(&mut self, pattern: &ast::Pattern) {
+        match pattern {
+            ast::Pattern::Identifier(id) => self.reassign(id.name),
+            ast::Pattern::Array(arr) => {
+                for elem in arr.elements.iter().flatten() {
+                    self.reassign_pattern(&elem.pattern);
+                }
+                if let Some(rest) = &arr.rest {
+                    self.reassign_pattern(rest);
+                }
+            }
+            ast::Pattern::Object(obj) => {
+                for prop in &obj.properties {
+                    self.reassign_pattern(&prop.value);
+                }
+                if let Some(rest) = &obj.rest {
+                    self.reassign(rest.name);
+                }
+            }
+            ast::Pattern::Rest(rest) => self.reassign_pattern(&rest.argument),
+        }
+    }
+
+    fn reference(&mut self, name: Symbol) {
+        if let Some(index) = self.lookup(name) {
+            self.bindings[index].referenced = true;
+        }
+    }
+
+    fn reassign(&mut self, name: Symbol) {
+        if let Some(index) = self.lookup(name) {
+            self.bindings[index].reassigned = true;
+        }
+    }
+
+    /// Declare the bindings a block introduces before walking it.
+    fn hoist(&mut self, statements: &[ast::Statement]) {
+        for stmt in statements {
+            self.hoist_statement(stmt, false);
+        }
+    }
+
+    fn hoist_statement(&mut self, stmt: &ast::Statement, exported: bool) {
+        let first = self.bindings.len();
+        match stmt {
+            ast::Statement::VariableDecl(decl) => self.declare_variable(decl),
+            ast::Statement::FunctionDecl(decl) => {
+                self.declare(&decl.name, BindingKind::Function, decl.span);
+            }
+            ast::Statement::ClassDecl(decl) => {
+                self.declare(&decl.name, BindingKind::Class, decl.span);
+            }
+            ast::Statement::ImportDecl(decl) => {
+                for spec in &decl.specifiers {
+                    let id = match spec {
+                        ast::ImportSpecifier::Named { name, alias } => {
+                            alias.as_ref().unwrap_or(name)
+                        }
+                        ast::ImportSpecifier::Namespace(id) | ast::ImportSpecifier::Default(id) => {
+                            id
+                        }
+                    };
+                    self.declare(id, BindingKind::Import, decl.span);
+                }
+            }
+            ast::Statement::ExportDecl(ast::ExportDecl::Declaration(inner)) => {
+                self.hoist_statement(inner, true);
+            }
+            _ => {}
+        }
+        if exported {
+            for binding in &mut self.bindings[first..] {
+                binding.exported = true;
+            }
+        }
+    }
+
+    fn visit_params(&mut self, params: &[ast::Parameter]) {
+        for param in params {
+            for decorator in &param.decorators {
+                self.visit_decorator(decorator);
+            }
+            self.declare_pattern(&param.pattern, BindingKind::Param, *param.pattern.span());
+            self.visit_pattern_defaults(&param.pattern);
+            if let Some(default) = &param.default_value {
+                self.visit_expression(default);
+            }
+        }
+    }
+
+    fn visit_for_left(&mut self, left: &ast::ForOfLeft) {
+        match left {
+            ast::ForOfLeft::VariableDecl(decl) => self.visit_variable_decl(decl),
+            ast::ForOfLeft::Pattern(pattern) => self.reassign_pattern(pattern),
+        }
+    }
+}
+
+impl Visitor for Analyzer {
+    fn visit_module(&mut self, module: &ast::Module) {
+        self.with_scope(|this| {
+            this.hoist(&module.statements);
+            visitor::walk_module(this, module);
+        });
+    }
+
+    fn visit_statement(&mut self, stmt: &ast::Statement) {
+        match stmt {
+            ast::Statement::ForIn(stmt) => self.with_scope(|this| {
+                this.visit_for_left(&stmt.left);
+                this.visit_expression(&stmt.right);
+                this.visit_statement(&stmt.body);
+            }),
+            ast::Statement::Try(stmt) => {
+                self.visit_block_statement(&stmt.body);
+                if let Some(catch) = &stmt.catch_clause {
+                    self.with_scope(|this| {
+                        if let Some(param) = &catch.param {
+                            this.declare_pattern(param, BindingKind::Param, catch.span);
+                        }
+                        this.visit_block_statement(&catch.body);
+                    });
+                }
+                if let Some(finally) = &stmt.finally_clause {
+                    self.visit_block_statement(finally);
+                }
+            }
+            _ => visitor::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_variable_decl(&mut self, decl: &ast::VariableDecl) {
+        // Loop-header declarations are not hoisted; this is a no-op otherwise.
+        self.declare_variable(decl);
+        self.visit_pattern_defaults(&decl.pattern);
+        if let Some(init) = &decl.initializer {
+            self.visit_expression(init);
+        }
+    }
+
+    fn visit_function_decl(&mut self, decl: &ast::FunctionDecl) {
+        self.with_scope(|this| {
+            this.visit_params(&decl.params);
+            this.visit_block_statement(&decl.body);
+        });
+    }
+
+    fn visit_class_decl(&mut self, decl: &ast::ClassDecl) {
+        for decorator in &decl.decorators {
+            self.visit_decorator(decorator);
+        }
+        for member in &decl.members {
+            match member {
+                ast::ClassMember::Field(field) => {
+                    if let Some(init) = &field.initializer {
+                        self.visit_expression(init);
+                    }
+                }
+                ast::ClassMember::Method(method) => self.with_scope(|this| {
+                    this.visit_params(&method.params);
+                    if let Some(body) = &method.body {
+                        this.visit_block_statement(body);
+                    }
+                }),
+                ast::ClassMember::Constructor(ctor) => self.with_scope(|this| {
+                    this.visit_params(&ctor.params);
+                    this.visit_block_statement(&ctor.body);
+                }),
+                ast::ClassMember::StaticBlock(block) => self.visit_block_statement(block),
+            }
+        }
+    }
+
+    fn visit_export_decl(&mut self, decl: &ast::ExportDecl) {
+        if let ast::ExportDecl::Named {
+            specifiers,
+            source: None,
+            ..
+        } = decl
+        {
+            for spec in specifiers {
+                if let Some(index) = self.lookup(spec.name.name) {
+                    self.bindings[index].exported = true;
+                }
+            }
+        }
+        visitor::walk_export_decl(self, decl);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ast::ForStatement) {
+        self.with_scope(|this| visitor::walk_for_statement(this, stmt));
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ast::ForOfStatement) {
+        self.with_scope(|this| {
+            this.visit_for_left(&stmt.left);
+            this.visit_expression(&stmt.right);
+            this.visit_statement(&stmt.body);
+        });
+    }
+
+    fn visit_block_statement(&mut self, stmt: &ast::BlockStatement) {
+        self.with_scope(|this| {
+            this.hoist(&stmt.statements);
+            visitor::walk_block_statement(this, stmt);
+        });
+    }
+
+    fn visit_expression(&mut self, expr: &ast::Expression) {
+        match expr {
+            ast::Expression::Identifier(id) => self.reference(id.name),
+            ast::Expression::Assignment(assign) => {
+                if let ast::Expression::Identifier(id) = assign.left.as_ref() {
+                    self.reassign(id.name);
+                } else {
+                    self.visit_expression(&assign.left);
+                }
+                self.visit_expression(&assign.right);
+            }
+            ast::Expression::Unary(unary)
+                if matches!(
+                    unary.operator,
+                    ast::UnaryOperator::PrefixIncrement
+                        | ast::UnaryOperator::PrefixDecrement
+                        | ast::UnaryOperator::PostfixIncrement
+                        | ast::UnaryOperator::PostfixDecrement
+                ) =>
+            {
+                if let ast::Expression::Identifier(id) = unary.operand.as_ref() {
+                    self.reassign(id.name);
+                } else {
+                    self.visit_expression(&unary.operand);
+                }
+            }
+            _ => visitor::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_member_expression(&mut self, expr: &ast::MemberExpression) {
+        // The property name is not a variable reference.
+        self.visit_expression(&expr.object);
+    }
+
+    fn visit_arrow_function(&mut self, func: &ast::ArrowFunction) {
+        self.with_scope(|this| {
+            this.visit_params(&func.params);
+            match &func.body {
+                ast::ArrowBody::Expression(expr) => this.visit_expression(expr),
+                ast::ArrowBody::Block(block) => this.visit_block_statement(block),
+            }
+        });
+    }
+
+    fn visit_type_annotation(&mut self, _ty: &ast::TypeAnnotation) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    fn analyze(source: &str) -> (ScopeAnalysis, crate::parser::interner::Interner) {
+        let parser = Parser::new(source).expect("lex");
+        let (module, interner) = parser.parse().expect("parse");
+        (ScopeAnalysis::analyze(&module), interner)
+    }
+
+    fn binding<'a>(
+        analysis: &'a ScopeAnalysis,
+        interner: &crate::parser::interner::Interner,
+        name: &str,
+    ) -> &'a Binding {
+        analysis
+            .bindings
+            .iter()
+            .find(|b| interner.resolve(b.name) == name)
+            .expect("binding")
+    }
+
+    #[test]
+    fn test_tracks_reads_and_reassignments() {
+        let (analysis, interner) = analyze(
+            "function f(): int { let a: int = 1; let b: int = 2; b = a; let c: int = 0; c++; return b; }",
+        );
+        let a = binding(&analysis, &interner, "a");
+        assert!(a.referenced && !a.reassigned);
+        let b = binding(&analysis, &interner, "b");
+        assert!(b.referenced && b.reassigned);
+        let c = binding(&analysis, &interner, "c");
+        assert!(!c.referenced && c.reassigned);
+    }
+
+    #[test]
+    fn test_records_shadowed_binding() {
+        let (analysis, interner) =
+            analyze("const x: int = 1; function f(): int { const x: int = 2; return x; }");
+        let inner = analysis
+            .bindings
+            .iter()
+            .rfind(|b| interner.resolve(b.name) == "x")
+            .unwrap();
+        assert_eq!(inner.shadows, Some(0));
+        assert!(!inner.top_level && analysis.bindings[0].top_level);
+    }
+}
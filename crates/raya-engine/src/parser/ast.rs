@@ -44,12 +44,19 @@ pub struct Module {
 
     /// Span covering the entire module
     pub span: Span,
+
+    /// `//@@if(feature)` conditions on statements and class members, at any depth
+    pub feature_gates: Vec<FeatureGate>,
 }
 
 impl Module {
     /// Create a new module
     pub fn new(statements: Vec<Statement>, span: Span) -> Self {
-        Self { statements, span }
+        Self {
+            statements,
+            span,
+            feature_gates: Vec::new(),
+        }
     }
 
     /// Check if the module is empty
@@ -19,6 +19,9 @@ pub enum Expression {
     /// Float literal: 3.14, 1.0e10
     FloatLiteral(FloatLiteral),
 
+    /// BigInt literal: 123n
+    BigIntLiteral(BigIntLiteral),
+
     /// String literal: "hello"
     StringLiteral(StringLiteral),
 
@@ -100,6 +103,12 @@ pub enum Expression {
     /// Type cast expression: expr as TypeName
     TypeCast(TypeCastExpression),
 
+    /// Satisfies expression: expr satisfies TypeName
+    Satisfies(SatisfiesExpression),
+
+    /// Const assertion: expr as const
+    ConstAssertion(ConstAssertionExpression),
+
     /// Regex literal: /pattern/flags
     RegexLiteral(RegexLiteral),
 
@@ -116,6 +125,7 @@ impl Expression {
         match self {
             Expression::IntLiteral(e) => &e.span,
             Expression::FloatLiteral(e) => &e.span,
+            Expression::BigIntLiteral(e) => &e.span,
             Expression::StringLiteral(e) => &e.span,
             Expression::TemplateLiteral(e) => &e.span,
             Expression::BooleanLiteral(e) => &e.span,
@@ -143,6 +153,8 @@ impl Expression {
             Expression::Super(span) => span,
             Expression::InstanceOf(e) => &e.span,
             Expression::TypeCast(e) => &e.span,
+            Expression::Satisfies(e) => &e.span,
+            Expression::ConstAssertion(e) => &e.span,
             Expression::RegexLiteral(e) => &e.span,
             Expression::TaggedTemplate(e) => &e.span,
             Expression::DynamicImport(e) => &e.span,
@@ -155,6 +167,7 @@ impl Expression {
             self,
             Expression::IntLiteral(_)
                 | Expression::FloatLiteral(_)
+                | Expression::BigIntLiteral(_)
                 | Expression::StringLiteral(_)
                 | Expression::TemplateLiteral(_)
                 | Expression::BooleanLiteral(_)
@@ -193,6 +206,14 @@ pub struct FloatLiteral {
     pub span: Span,
 }
 
+/// BigInt literal: 123n
+#[derive(Debug, Clone, PartialEq)]
+pub struct BigIntLiteral {
+    /// Decimal digits without the `n` suffix or separators
+    pub digits: crate::parser::interner::Symbol,
+    pub span: Span,
+}
+
 /// String literal: "hello"
 #[derive(Debug, Clone, PartialEq)]
 pub struct StringLiteral {
@@ -695,6 +716,30 @@ pub struct TypeCastExpression {
     pub span: Span,
 }
 
+/// Const assertion: expr as const
+///
+/// Freezes a literal to its narrowest type: literal primitives, readonly
+/// tuples for arrays and readonly properties for objects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstAssertionExpression {
+    /// The literal expression being frozen
+    pub object: Box<Expression>,
+    pub span: Span,
+}
+
+/// Satisfies expression: expr satisfies TypeName
+///
+/// Checks that `object` is assignable to `target_type` without changing its
+/// inferred type (unlike `as`, which replaces it).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SatisfiesExpression {
+    /// The expression being checked
+    pub object: Box<Expression>,
+    /// The type it must satisfy
+    pub target_type: TypeAnnotation,
+    pub span: Span,
+}
+
 /// Tagged template literal: tag`hello ${name}`
 #[derive(Debug, Clone, PartialEq)]
 pub struct TaggedTemplateExpression {
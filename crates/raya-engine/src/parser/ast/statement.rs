@@ -137,7 +137,7 @@ impl Statement {
 /// Variable declaration: let x = 42; or const y: number = 10;
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariableDecl {
-    /// let or const
+    /// let, const or using
     pub kind: VariableKind,
 
     /// Pattern (identifier or destructuring)
@@ -156,6 +156,12 @@ pub struct VariableDecl {
 pub enum VariableKind {
     Let,
     Const,
+    /// `using x = acquire();` - a const binding whose value is disposable,
+    /// i.e. has a `dispose()` method, called when the enclosing block exits
+    /// (normally, by return/break/continue, or by a throw). The parser
+    /// rewrites the rest of the block into a try statement that performs
+    /// the call; see `desugar_using_declarations`.
+    Using,
 }
 
 // ============================================================================
@@ -172,6 +178,9 @@ pub enum VariableKind {
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionDecl {
+    /// Compiler annotations (//@@tag value)
+    pub annotations: Vec<Annotation>,
+
     /// Function name
     pub name: Identifier,
 
@@ -271,6 +280,18 @@ pub enum ClassMember {
     StaticBlock(BlockStatement),
 }
 
+impl ClassMember {
+    /// Get the span of this member
+    pub fn span(&self) -> &Span {
+        match self {
+            ClassMember::Field(field) => &field.span,
+            ClassMember::Method(method) => &method.span,
+            ClassMember::Constructor(ctor) => &ctor.span,
+            ClassMember::StaticBlock(block) => &block.span,
+        }
+    }
+}
+
 /// Visibility modifier for class members (Java-like semantics)
 ///
 /// | Modifier | Same Class | Subclass | Other Classes |
@@ -414,9 +435,25 @@ pub struct Annotation {
 }
 
 impl Annotation {
-    /// Parse an annotation from its raw content (e.g., "json user_name")
+    /// Parse an annotation from its raw content (e.g., "json user_name" or "if(linux)")
     pub fn from_content(content: &str, span: Span) -> Self {
         let content = content.trim();
+        if let Some((tag, rest)) = content.split_once('(') {
+            if let Some(argument) = rest.strip_suffix(')') {
+                if !tag.contains(' ') {
+                    let argument = argument.trim();
+                    return Self {
+                        tag: tag.to_string(),
+                        value: if argument.is_empty() {
+                            None
+                        } else {
+                            Some(argument.to_string())
+                        },
+                        span,
+                    };
+                }
+            }
+        }
         if let Some(space_idx) = content.find(' ') {
             let tag = content[..space_idx].to_string();
             let value = content[space_idx + 1..].trim().to_string();
@@ -434,11 +471,52 @@ impl Annotation {
         }
     }
 
+    /// Feature named by a `//@@if(feature)` annotation (`!feature` negates it)
+    pub fn feature_condition(&self) -> Option<&str> {
+        if self.tag == "if" {
+            self.value.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Gate the statement or class member at `target` on this annotation's
+    /// `//@@if(feature)` condition, if it has one
+    pub fn feature_gate(&self, target: Span) -> Option<FeatureGate> {
+        self.feature_condition().map(|condition| FeatureGate {
+            target,
+            condition: condition.to_string(),
+        })
+    }
+
+    /// Message of a `//@@deprecated("message")` annotation (empty when omitted)
+    pub fn deprecation_message(&self) -> Option<&str> {
+        if self.tag == "deprecated" {
+            Some(self.value.as_deref().map_or("", |v| v.trim_matches('"')))
+        } else {
+            None
+        }
+    }
+
     /// Check if this is a "skip" annotation (value is "-")
     pub fn is_skip(&self) -> bool {
         self.value.as_deref() == Some("-")
     }
 
+    /// Get the JSON key of a `//@@json name` or `//@@json name,omitempty` annotation
+    /// Returns None if this is not a json annotation, has no name, or is a skip
+    pub fn json_field_name(&self) -> Option<&str> {
+        if self.tag != "json" || self.is_skip() {
+            return None;
+        }
+        let name = self.value.as_deref()?.split(',').next()?.trim();
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+
     /// Get the proto field number if this is a proto annotation
     /// Returns None if this is not a proto annotation or if the value is "-" (skip)
     pub fn proto_field_number(&self) -> Option<u32> {
@@ -469,6 +547,29 @@ impl Annotation {
     }
 }
 
+/// A `//@@if(feature)` condition on the statement or class member at `target`
+///
+/// Gates are collected into [`crate::parser::ast::Module::feature_gates`]
+/// rather than dropping anything while parsing; the compiler strips disabled
+/// items before binding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureGate {
+    /// Span of the gated statement or class member
+    pub target: Span,
+    /// Feature name, prefixed with `!` when negated
+    pub condition: String,
+}
+
+impl FeatureGate {
+    /// Whether the condition holds with `features` enabled
+    pub fn is_enabled(&self, features: &[String]) -> bool {
+        match self.condition.strip_prefix('!') {
+            Some(feature) => !features.iter().any(|enabled| enabled == feature.trim()),
+            None => features.iter().any(|enabled| *enabled == self.condition),
+        }
+    }
+}
+
 // ============================================================================
 // Type Alias (Interfaces BANNED)
 // ============================================================================
@@ -513,10 +614,23 @@ pub struct SwitchStatement {
 pub struct SwitchCase {
     /// None for default case
     pub test: Option<Expression>,
+    /// `when` guard: the case only matches if this also evaluates to true
+    pub guard: Option<Expression>,
     pub consequent: Vec<Statement>,
+    /// Body ends with an explicit `fallthrough;` into the next case
+    pub fallthrough: bool,
     pub span: Span,
 }
 
+impl SwitchCase {
+    /// Whether control reaching the end of this case's body continues into the
+    /// next case. Unguarded cases keep C-style fallthrough; a case with a
+    /// `when` guard ends with an implicit break unless it says `fallthrough`.
+    pub fn falls_through(&self) -> bool {
+        self.fallthrough || self.consequent.is_empty() || self.guard.is_none()
+    }
+}
+
 /// While loop
 #[derive(Debug, Clone, PartialEq)]
 pub struct WhileStatement {
@@ -242,10 +242,12 @@ impl FunctionType {
 // Array Type
 // ============================================================================
 
-/// Array type: T[]
+/// Array type: T[] or readonly T[]
 #[derive(Debug, Clone, PartialEq)]
 pub struct ArrayType {
     pub element_type: Box<TypeAnnotation>,
+    /// `readonly T[]`: mutation is rejected by the checker (compile-time only)
+    pub readonly: bool,
 }
 
 impl ArrayType {
@@ -253,6 +255,7 @@ impl ArrayType {
     pub fn new(element_type: TypeAnnotation) -> Self {
         Self {
             element_type: Box::new(element_type),
+            readonly: false,
         }
     }
 }
@@ -261,16 +264,21 @@ impl ArrayType {
 // Tuple Type
 // ============================================================================
 
-/// Tuple type: [number, string, boolean]
+/// Tuple type: [number, string, boolean] or readonly [number, string]
 #[derive(Debug, Clone, PartialEq)]
 pub struct TupleType {
     pub element_types: Vec<TypeAnnotation>,
+    /// `readonly [..]`: mutation is rejected by the checker (compile-time only)
+    pub readonly: bool,
 }
 
 impl TupleType {
     /// Create a new tuple type
     pub fn new(element_types: Vec<TypeAnnotation>) -> Self {
-        Self { element_types }
+        Self {
+            element_types,
+            readonly: false,
+        }
     }
 
     /// Get the number of elements in this tuple
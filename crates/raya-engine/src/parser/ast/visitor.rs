@@ -370,6 +370,9 @@ pub fn walk_switch_statement<V: Visitor>(visitor: &mut V, stmt: &SwitchStatement
         if let Some(test) = &case.test {
             visitor.visit_expression(test);
         }
+        if let Some(guard) = &case.guard {
+            visitor.visit_expression(guard);
+        }
         for consequent in &case.consequent {
             visitor.visit_statement(consequent);
         }
@@ -416,6 +419,7 @@ pub fn walk_expression<V: Visitor>(visitor: &mut V, expr: &Expression) {
     match expr {
         Expression::IntLiteral(_)
         | Expression::FloatLiteral(_)
+        | Expression::BigIntLiteral(_)
         | Expression::StringLiteral(_)
         | Expression::BooleanLiteral(_)
         | Expression::NullLiteral(_)
@@ -476,6 +480,11 @@ pub fn walk_expression<V: Visitor>(visitor: &mut V, expr: &Expression) {
             visitor.visit_expression(&cast.object);
             visitor.visit_type_annotation(&cast.target_type);
         }
+        Expression::Satisfies(satisfies) => {
+            visitor.visit_expression(&satisfies.object);
+            visitor.visit_type_annotation(&satisfies.target_type);
+        }
+        Expression::ConstAssertion(assertion) => visitor.visit_expression(&assertion.object),
         Expression::TaggedTemplate(tagged) => {
             visitor.visit_expression(&tagged.tag);
             for part in &tagged.template.parts {
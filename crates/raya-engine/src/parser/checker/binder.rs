@@ -9,10 +9,13 @@ use super::symbols::{ScopeId, ScopeKind, Symbol, SymbolFlags, SymbolKind, Symbol
 use super::{CheckerPolicy, TypeSystemMode};
 use crate::parser::ast::*;
 use crate::parser::types::try_hydrate_type_from_canonical_signature;
-use crate::parser::types::ty::{ClassType, MethodSignature, PropertySignature, Type, TypeReference};
-use crate::parser::types::{TypeContext, TypeId};
+use crate::parser::types::ty::{
+    ClassType, MethodSignature, PropertySignature, Type, TypeReference,
+};
+use crate::parser::types::{apply_utility_type, TypeContext, TypeId, UtilityType};
 use crate::parser::Interner;
 use crate::parser::Span;
+use std::sync::OnceLock;
 
 /// Binder - builds symbol tables from AST
 ///
@@ -29,6 +32,8 @@ pub struct Binder<'a> {
     /// Tracks function names that have been fully bound (in bind_function).
     /// Used to detect duplicate function declarations in user code.
     bound_functions: std::collections::HashMap<String, crate::parser::Span>,
+    /// Span of the module's `export default`, used to reject a second one.
+    default_export_span: Option<crate::parser::Span>,
     /// When true, duplicate top-level class/function declarations are rejected.
     /// Some helper/builtin compilation paths intentionally disable this.
     reject_duplicate_top_level_declarations: bool,
@@ -40,6 +45,26 @@ pub struct Binder<'a> {
     policy: CheckerPolicy,
 }
 
+/// Every builtin class and function, bound once per process: the type
+/// context they were interned into and the global scope that names them
+pub(crate) struct BoundBuiltins {
+    pub(crate) type_ctx: TypeContext,
+    symbols: SymbolTable,
+}
+
+/// The process-wide [`BoundBuiltins`], binding them on first use
+pub(crate) fn bound_builtins() -> &'static BoundBuiltins {
+    static BUILTINS: OnceLock<BoundBuiltins> = OnceLock::new();
+    BUILTINS.get_or_init(|| {
+        let mut type_ctx = TypeContext::new();
+        let interner = Interner::new();
+        let mut binder = Binder::new(&mut type_ctx, &interner);
+        binder.register_builtins(crate::builtins::checker_signatures());
+        let symbols = binder.symbols;
+        BoundBuiltins { type_ctx, symbols }
+    })
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum BinderFallbackReason {
     UnresolvedTypeParse,
@@ -63,6 +88,7 @@ impl<'a> Binder<'a> {
             interner,
             bound_classes: std::collections::HashMap::new(),
             bound_functions: std::collections::HashMap::new(),
+            default_export_span: None,
             reject_duplicate_top_level_declarations: true,
             generic_type_alias_params: rustc_hash::FxHashMap::default(),
             mode: TypeSystemMode::Raya,
@@ -70,6 +96,24 @@ impl<'a> Binder<'a> {
         }
     }
 
+    /// Create a binder whose global scope already holds every builtin class
+    /// and function.
+    ///
+    /// Equivalent to `new` followed by `register_builtins(checker_signatures())`,
+    /// but copies the global scope bound once per process instead of binding
+    /// the builtins again. `type_ctx` must come from
+    /// [`TypeContext::with_builtins`] so the copied symbols' TypeIds exist.
+    pub fn with_builtins(type_ctx: &'a mut TypeContext, interner: &'a Interner) -> Self {
+        let builtins = bound_builtins();
+        debug_assert!(
+            type_ctx.len() >= builtins.type_ctx.len(),
+            "Binder::with_builtins needs a TypeContext::with_builtins() context"
+        );
+        let mut binder = Self::new(type_ctx, interner);
+        binder.symbols = builtins.symbols.clone();
+        binder
+    }
+
     /// Set checker/binder behavior mode.
     pub fn with_mode(mut self, mode: TypeSystemMode) -> Self {
         self.mode = mode;
@@ -204,9 +248,22 @@ impl<'a> Binder<'a> {
             }
         }
 
+        self.register_builtin_array_iterator();
         self.register_builtin_event_emitter();
     }
 
+    /// Array is intrinsic and has no builtin class signature, so its `for-of`
+    /// element type is declared here alongside the ones from `ClassSig`.
+    fn register_builtin_array_iterator(&mut self) {
+        if self.symbols.builtin_iterator_element("Array").is_some() {
+            return;
+        }
+        let type_params = vec!["T".to_string()];
+        let element_ty = self.parse_type_string("T", &type_params);
+        self.symbols
+            .set_builtin_iterator_element("Array", type_params, element_ty);
+    }
+
     fn register_builtin_event_emitter(&mut self) {
         if self.symbols.resolve("EventEmitter").is_some() {
             return;
@@ -1504,6 +1561,15 @@ impl<'a> Binder<'a> {
             })
             .collect();
 
+        if let Some(ref element) = class_sig.iterator_element {
+            let element_ty = self.parse_type_string(element, &type_params);
+            self.symbols.set_builtin_iterator_element(
+                &class_sig.name,
+                type_params.clone(),
+                element_ty,
+            );
+        }
+
         let extends = self
             .builtin_parent_type_name(&class_sig.name)
             .and_then(|parent| self.type_ctx.lookup_named_type(parent));
@@ -1591,6 +1657,7 @@ impl<'a> Binder<'a> {
             .collect();
         let return_ty = self.parse_type_string(&func_sig.return_type, &func_sig.type_params);
         let func_ty = self.type_ctx.function_type(param_types, return_ty, false);
+        self.symbols.add_builtin_overload(&func_sig.name, func_ty);
 
         let symbol = Symbol {
             name: func_sig.name.clone(),
@@ -1966,6 +2033,7 @@ impl<'a> Binder<'a> {
 
     /// Bind a statement
     fn bind_stmt(&mut self, stmt: &Statement) -> Result<(), BindError> {
+        self.record_statement_uses(stmt);
         match stmt {
             Statement::VariableDecl(decl) => self.bind_var_decl(decl),
             Statement::FunctionDecl(func) => self.bind_function(func),
@@ -1988,6 +2056,60 @@ impl<'a> Binder<'a> {
         }
     }
 
+    /// Record identifier uses in the expressions a statement owns directly
+    ///
+    /// Nested statements record their own uses when they are bound, so every use
+    /// resolves from the scope it appears in. Variable initializers and `for`
+    /// headers are recorded by `bind_var_decl` and `bind_for`.
+    fn record_statement_uses(&mut self, stmt: &Statement) {
+        match stmt {
+            Statement::Expression(expr_stmt) => self.record_uses(&expr_stmt.expression),
+            Statement::Return(ret) => {
+                if let Some(ref value) = ret.value {
+                    self.record_uses(value);
+                }
+            }
+            Statement::Throw(throw) => self.record_uses(&throw.value),
+            Statement::If(if_stmt) => self.record_uses(&if_stmt.condition),
+            Statement::While(while_stmt) => self.record_uses(&while_stmt.condition),
+            Statement::DoWhile(do_while) => self.record_uses(&do_while.condition),
+            Statement::Switch(switch_stmt) => {
+                self.record_uses(&switch_stmt.discriminant);
+                for case in &switch_stmt.cases {
+                    for expr in case.test.iter().chain(case.guard.iter()) {
+                        self.record_uses(expr);
+                    }
+                }
+            }
+            Statement::ForOf(for_of) => self.record_uses(&for_of.right),
+            Statement::ForIn(for_in) => self.record_uses(&for_in.right),
+            Statement::ExportDecl(ExportDecl::Default { expression, .. }) => {
+                self.record_uses(expression)
+            }
+            _ => {}
+        }
+    }
+
+    /// Record every identifier use in `expr` against the current scope
+    fn record_uses(&mut self, expr: &Expression) {
+        let mut collector = IdentifierUseCollector::default();
+        collector.visit_expression(expr);
+        for (name, span) in collector.uses {
+            let name = self.resolve(name);
+            self.symbols.record_reference(&name, span);
+        }
+    }
+
+    /// Record identifier uses in parameter default values (call once the
+    /// parameters are defined, since a default may refer to earlier ones)
+    fn record_param_default_uses(&mut self, params: &[Parameter]) {
+        for param in params {
+            if let Some(ref default) = param.default_value {
+                self.record_uses(default);
+            }
+        }
+    }
+
     /// Bind an export declaration
     fn bind_export(&mut self, export: &ExportDecl) -> Result<(), BindError> {
         match export {
@@ -2002,7 +2124,11 @@ impl<'a> Binder<'a> {
                 }
                 Ok(())
             }
-            ExportDecl::Named { specifiers, .. } => {
+            ExportDecl::Named {
+                specifiers,
+                source: None,
+                ..
+            } => {
                 // Mark each named export as exported
                 for spec in specifiers {
                     let name = self.resolve(spec.name.name);
@@ -2011,11 +2137,22 @@ impl<'a> Binder<'a> {
                 }
                 Ok(())
             }
-            ExportDecl::All { .. } => {
+            ExportDecl::Named {
+                source: Some(_), ..
+            }
+            | ExportDecl::All { .. } => {
                 // Re-exports are handled at module linking time, not binding time
                 Ok(())
             }
             ExportDecl::Default { expression, span } => {
+                if let Some(original) = self.default_export_span {
+                    return Err(BindError::DuplicateDefaultExport {
+                        original,
+                        duplicate: *span,
+                    });
+                }
+                self.default_export_span = Some(*span);
+
                 // export default <expr> — create a "default" symbol with the expression's type
                 // For identifier expressions (e.g., `export default logger`), copy the symbol's type
                 if let Expression::Identifier(ident) = expression.as_ref() {
@@ -2325,6 +2462,12 @@ impl<'a> Binder<'a> {
                 self.expression_uses_linker_dep_binding(&instanceof.object)
             }
             Expression::TypeCast(cast) => self.expression_uses_linker_dep_binding(&cast.object),
+            Expression::Satisfies(satisfies) => {
+                self.expression_uses_linker_dep_binding(&satisfies.object)
+            }
+            Expression::ConstAssertion(assertion) => {
+                self.expression_uses_linker_dep_binding(&assertion.object)
+            }
             Expression::TemplateLiteral(tpl) => tpl.parts.iter().any(|part| match part {
                 crate::parser::ast::TemplatePart::Expression(expr) => {
                     self.expression_uses_linker_dep_binding(expr)
@@ -2347,6 +2490,7 @@ impl<'a> Binder<'a> {
             | Expression::JsxFragment(_)
             | Expression::IntLiteral(_)
             | Expression::FloatLiteral(_)
+            | Expression::BigIntLiteral(_)
             | Expression::StringLiteral(_)
             | Expression::BooleanLiteral(_)
             | Expression::NullLiteral(_)
@@ -2364,7 +2508,12 @@ impl<'a> Binder<'a> {
             None => self.inference_fallback_type(),
         };
 
-        let is_const = matches!(decl.kind, VariableKind::Const);
+        // The initializer is evaluated before the new names are in scope
+        if let Some(ref init) = decl.initializer {
+            self.record_uses(init);
+        }
+
+        let is_const = matches!(decl.kind, VariableKind::Const | VariableKind::Using);
         let is_imported = decl
             .initializer
             .as_ref()
@@ -2623,6 +2772,8 @@ impl<'a> Binder<'a> {
                 })?;
         }
 
+        self.record_param_default_uses(&func.params);
+
         // Bind body statements
         for stmt in &func.body.statements {
             self.bind_stmt(stmt)?;
@@ -3207,6 +3358,7 @@ impl<'a> Binder<'a> {
                             // Method parameters are mutable (same semantics as function params)
                             self.bind_pattern_names(&param.pattern, param_ty, false, false)?;
                         }
+                        self.record_param_default_uses(&method.params);
 
                         for stmt in &body.statements {
                             self.bind_stmt(stmt)?;
@@ -3227,12 +3379,18 @@ impl<'a> Binder<'a> {
                         // Constructor parameters are mutable
                         self.bind_pattern_names(&param.pattern, param_ty, false, false)?;
                     }
+                    self.record_param_default_uses(&ctor.params);
 
                     for stmt in &ctor.body.statements {
                         self.bind_stmt(stmt)?;
                     }
                     self.symbols.pop_scope();
                 }
+                ClassMember::Field(field) => {
+                    if let Some(ref init) = field.initializer {
+                        self.record_uses(init);
+                    }
+                }
                 _ => {}
             }
         }
@@ -3389,9 +3547,12 @@ impl<'a> Binder<'a> {
         if let Some(ref init) = for_stmt.init {
             match init {
                 ForInit::VariableDecl(decl) => self.bind_var_decl(decl)?,
-                ForInit::Expression(_) => {}
+                ForInit::Expression(expr) => self.record_uses(expr),
             }
         }
+        for expr in for_stmt.test.iter().chain(for_stmt.update.iter()) {
+            self.record_uses(expr);
+        }
 
         // Bind body
         self.bind_stmt(&for_stmt.body)?;
@@ -3674,6 +3835,28 @@ impl<'a> Binder<'a> {
                     });
                 }
 
+                // Built-in utility types (Partial<T>, Pick<T, K>, ...) unless shadowed.
+                if let Some(kind) = UtilityType::from_name(&name).filter(|_| {
+                    self.symbols
+                        .resolve_from_scope(&name, self.symbols.current_scope_id())
+                        .is_none()
+                }) {
+                    let type_args = type_ref.type_args.as_deref().unwrap_or(&[]);
+                    if type_args.len() != kind.arity() {
+                        return Err(BindError::InvalidTypeArguments {
+                            name,
+                            expected: kind.arity(),
+                            actual: type_args.len(),
+                            span,
+                        });
+                    }
+                    let mut resolved_args = Vec::with_capacity(type_args.len());
+                    for arg in type_args {
+                        resolved_args.push(self.resolve_type_annotation(arg)?);
+                    }
+                    return Ok(apply_utility_type(self.type_ctx, kind, &resolved_args));
+                }
+
                 if let Some(symbol) = self
                     .symbols
                     .resolve_from_scope(&name, self.symbols.current_scope_id())
@@ -3753,7 +3936,11 @@ impl<'a> Binder<'a> {
 
             AstType::Array(arr) => {
                 let elem_ty = self.resolve_type_annotation(&arr.element_type)?;
-                Ok(self.type_ctx.array_type(elem_ty))
+                if arr.readonly {
+                    Ok(self.type_ctx.readonly_array_type(elem_ty))
+                } else {
+                    Ok(self.type_ctx.array_type(elem_ty))
+                }
             }
 
             AstType::Tuple(tuple) => {
@@ -3762,7 +3949,11 @@ impl<'a> Binder<'a> {
                     .iter()
                     .map(|e| self.resolve_type_annotation(e))
                     .collect();
-                Ok(self.type_ctx.tuple_type(elem_tys?))
+                if tuple.readonly {
+                    Ok(self.type_ctx.readonly_tuple_type(elem_tys?))
+                } else {
+                    Ok(self.type_ctx.tuple_type(elem_tys?))
+                }
             }
 
             AstType::Union(union) => {
@@ -4252,6 +4443,80 @@ impl<'a> Binder<'a> {
     }
 }
 
+/// Collects identifier uses in an expression, in source order
+///
+/// Member property names are not uses, and names bound by nested arrow
+/// functions (parameters and local variables) are skipped so they don't
+/// resolve to an outer symbol of the same name.
+#[derive(Default)]
+struct IdentifierUseCollector {
+    uses: Vec<(crate::parser::Symbol, Span)>,
+    shadowed: Vec<crate::parser::Symbol>,
+}
+
+impl IdentifierUseCollector {
+    fn shadow_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Identifier(ident) => self.shadowed.push(ident.name),
+            Pattern::Array(array) => {
+                for elem in array.elements.iter().flatten() {
+                    self.shadow_pattern(&elem.pattern);
+                }
+                if let Some(ref rest) = array.rest {
+                    self.shadow_pattern(rest);
+                }
+            }
+            Pattern::Object(object) => {
+                for prop in &object.properties {
+                    self.shadow_pattern(&prop.value);
+                }
+                if let Some(ref rest) = object.rest {
+                    self.shadowed.push(rest.name);
+                }
+            }
+            Pattern::Rest(rest) => self.shadow_pattern(&rest.argument),
+        }
+    }
+}
+
+impl Visitor for IdentifierUseCollector {
+    fn visit_expression(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if !self.shadowed.contains(&ident.name) {
+                    self.uses.push((ident.name, ident.span));
+                }
+            }
+            Expression::Member(member) => self.visit_expression(&member.object),
+            _ => walk_expression(self, expr),
+        }
+    }
+
+    fn visit_arrow_function(&mut self, func: &ArrowFunction) {
+        let depth = self.shadowed.len();
+        for param in &func.params {
+            if let Some(ref default) = param.default_value {
+                self.visit_expression(default);
+            }
+            self.shadow_pattern(&param.pattern);
+        }
+        match &func.body {
+            ArrowBody::Expression(expr) => self.visit_expression(expr),
+            ArrowBody::Block(block) => self.visit_block_statement(block),
+        }
+        self.shadowed.truncate(depth);
+    }
+
+    fn visit_variable_decl(&mut self, decl: &VariableDecl) {
+        if let Some(ref init) = decl.initializer {
+            self.visit_expression(init);
+        }
+        self.shadow_pattern(&decl.pattern);
+    }
+
+    fn visit_type_annotation(&mut self, _ty: &TypeAnnotation) {}
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4277,6 +4542,119 @@ mod tests {
         assert_eq!(symbol.kind, SymbolKind::Variable);
     }
 
+    #[test]
+    fn test_symbol_at_and_references() {
+        let source = "let count = 1;\n\
+                      function bump(by: number): number {\n\
+                          let count = by;\n\
+                          return count + by;\n\
+                      }\n\
+                      count = count + bump(2);\n";
+        let (symbols, _ctx) = parse_and_bind(source);
+
+        // Byte spans of every `count` in source order
+        let counts: Vec<Span> = source
+            .match_indices("count")
+            .map(|(start, text)| Span::new(start, start + text.len(), 0, 0))
+            .collect();
+        let starts = |spans: Vec<Span>| spans.iter().map(|s| s.start).collect::<Vec<_>>();
+
+        // Go-to-definition from the last use lands on the top-level declaration
+        let outer = symbols.symbol_at(counts[4]).unwrap();
+        assert_eq!(outer.span.start, counts[0].start);
+        assert_eq!(
+            starts(symbols.references(outer)),
+            starts(vec![counts[0], counts[3], counts[4]])
+        );
+
+        // The shadowing local only sees its own uses
+        let inner = symbols.symbol_at(counts[2]).unwrap();
+        assert_eq!(inner.span.start, counts[1].start);
+        assert_eq!(
+            starts(symbols.references(inner)),
+            starts(vec![counts[1], counts[2]])
+        );
+
+        let call_start = source.rfind("bump").unwrap();
+        let bump = symbols
+            .symbol_at(Span::new(call_start, call_start + 4, 0, 0))
+            .unwrap();
+        assert_eq!(bump.kind, SymbolKind::Function);
+        assert_eq!(symbols.references(bump).len(), 2);
+    }
+
+    #[test]
+    fn test_builtin_iterator_elements_cover_map_set_and_array() {
+        let parser = Parser::new("").unwrap();
+        let (module, interner) = parser.parse().unwrap();
+        let mut ctx = TypeContext::new();
+        let mut binder = Binder::new(&mut ctx, &interner);
+        binder.register_builtins(crate::builtins::checker_signatures());
+        let symbols = binder.bind_module(&module).unwrap();
+
+        let expected: [(&str, &[&str]); 3] =
+            [("Map", &["K", "V"]), ("Set", &["T"]), ("Array", &["T"])];
+        for (name, type_params) in expected {
+            let (params, _) = symbols
+                .builtin_iterator_element(name)
+                .unwrap_or_else(|| panic!("no for-of element for {name}"));
+            assert_eq!(params, type_params, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_with_builtins_matches_registering_builtins() {
+        let parser = Parser::new("let m = new Map<string, number>();").unwrap();
+        let (module, interner) = parser.parse().unwrap();
+
+        let mut registered_ctx = TypeContext::with_builtins();
+        let mut binder = Binder::new(&mut registered_ctx, &interner);
+        binder.register_builtins(crate::builtins::checker_signatures());
+        let registered = binder.bind_module(&module).unwrap();
+
+        let mut cached_ctx = TypeContext::with_builtins();
+        let cached = Binder::with_builtins(&mut cached_ctx, &interner)
+            .bind_module(&module)
+            .unwrap();
+
+        for name in ["Map", "Error", "EventEmitter", "JSON", "parseInt"] {
+            let expected = registered.resolve(name).map(|sym| (sym.kind, sym.ty));
+            assert!(expected.is_some(), "{name} should be bound");
+            assert_eq!(
+                cached.resolve(name).map(|sym| (sym.kind, sym.ty)),
+                expected,
+                "{name}"
+            );
+        }
+        assert_eq!(registered_ctx.len(), cached_ctx.len());
+    }
+
+    #[test]
+    fn test_references_in_field_initializers_and_param_defaults() {
+        let source = "const step = 2;\n\
+                      function next(n: number, by: number = step): number { return n + by; }\n\
+                      class Counter {\n\
+                          size: number = step;\n\
+                          constructor(start: number = step) {}\n\
+                          add(by: number = step): void {}\n\
+                      }\n\
+                      const inc = (by: number = step): number => by;\n";
+        let (symbols, _ctx) = parse_and_bind(source);
+
+        let step = symbols.symbol_at(Span::new(6, 10, 0, 0)).unwrap();
+        assert_eq!(step.span.start, 6);
+        let uses: Vec<usize> = source.match_indices("step").map(|(i, _)| i).collect();
+        assert_eq!(uses.len(), 6);
+        assert_eq!(
+            symbols
+                .references(step)
+                .iter()
+                .map(|s| s.start)
+                .collect::<Vec<_>>(),
+            uses
+        );
+    }
+
     #[test]
     fn test_bind_function() {
         let (symbols, _ctx) =
@@ -4,6 +4,9 @@
 //! into the binder/type checker.
 
 /// Method signature for a builtin class
+///
+/// Methods declared more than once under the same name (and staticness) form an
+/// overload set; the checker picks the first one accepting the call arguments.
 #[derive(Debug, Clone)]
 pub struct BuiltinMethod {
     pub name: String,
@@ -41,9 +44,13 @@ pub struct BuiltinClass {
     pub properties: Vec<BuiltinProperty>,
     pub methods: Vec<BuiltinMethod>,
     pub constructor_params: Option<Vec<(String, String)>>,
+    /// Type yielded by `for-of` over an instance, written in terms of `type_params`
+    pub iterator_element: Option<String>,
 }
 
 /// Function signature for a builtin function
+///
+/// Functions declared more than once under the same name form an overload set.
 #[derive(Debug, Clone)]
 pub struct BuiltinFunction {
     pub name: String,
@@ -88,6 +95,7 @@ impl BuiltinClass {
             properties: Vec::new(),
             methods: Vec::new(),
             constructor_params: None,
+            iterator_element: None,
         }
     }
 
@@ -106,6 +114,11 @@ impl BuiltinClass {
         self
     }
 
+    pub fn with_iterator_element(mut self, element: &str) -> Self {
+        self.iterator_element = Some(element.to_string());
+        self
+    }
+
     pub fn with_method(mut self, name: &str, params: Vec<(&str, &str)>, return_type: &str) -> Self {
         let min_params = params.len();
         self.methods.push(BuiltinMethod {
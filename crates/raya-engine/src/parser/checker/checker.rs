@@ -8,15 +8,21 @@ use super::captures::{
     CaptureInfo, ClosureCaptures, ClosureId, FreeVariableCollector, ModuleCaptureInfo,
 };
 use super::error::{CheckError, CheckWarning};
-use super::exhaustiveness::{check_switch_exhaustiveness, ExhaustivenessResult};
+use super::exhaustiveness::{
+    check_switch_exhaustiveness, get_discriminant_field, ExhaustivenessResult,
+};
 use super::narrowing::{apply_type_guard, TypeEnv};
 use super::symbols::{SymbolKind, SymbolTable};
-use super::type_guards::{extract_all_type_guards, extract_type_guard, TypeGuard};
+use super::type_guards::{
+    extract_all_falsy_type_guards, extract_all_type_guards, extract_type_guard, TypeGuard,
+};
 use super::{CheckerPolicy, TypeSystemMode};
 use crate::parser::ast::*;
 use crate::parser::token::Span;
 use crate::parser::types::normalize::contains_type_variables;
-use crate::parser::types::{AssignabilityContext, GenericContext, TypeContext, TypeId};
+use crate::parser::types::{
+    apply_utility_type, AssignabilityContext, GenericContext, TypeContext, TypeId, UtilityType,
+};
 use crate::{Interner, Symbol as ParserSymbol};
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -62,70 +68,32 @@ struct ClassAstSummary {
     extends: Option<String>,
     abstract_methods: FxHashSet<String>,
     concrete_methods: FxHashSet<String>,
+    /// Instance field declarations by name, for field-shadowing diagnostics.
+    fields: FxHashMap<String, Span>,
 }
 
-/// Negate a type guard
-fn negate_guard(guard: &TypeGuard) -> TypeGuard {
-    match guard {
-        TypeGuard::TypeOf {
-            var,
-            type_name,
-            negated,
-        } => TypeGuard::TypeOf {
-            var: var.clone(),
-            type_name: type_name.clone(),
-            negated: !negated,
-        },
-        TypeGuard::Discriminant {
-            var,
-            field,
-            variant,
-            negated,
-        } => TypeGuard::Discriminant {
-            var: var.clone(),
-            field: field.clone(),
-            variant: variant.clone(),
-            negated: !negated,
-        },
-        TypeGuard::Nullish {
-            var,
-            field,
-            negated,
-        } => TypeGuard::Nullish {
-            var: var.clone(),
-            field: field.clone(),
-            negated: !negated,
-        },
-        TypeGuard::IsArray { var, negated } => TypeGuard::IsArray {
-            var: var.clone(),
-            negated: !negated,
-        },
-        TypeGuard::IsInteger { var, negated } => TypeGuard::IsInteger {
-            var: var.clone(),
-            negated: !negated,
-        },
-        TypeGuard::IsNaN { var, negated } => TypeGuard::IsNaN {
-            var: var.clone(),
-            negated: !negated,
-        },
-        TypeGuard::IsFinite { var, negated } => TypeGuard::IsFinite {
-            var: var.clone(),
-            negated: !negated,
-        },
-        TypeGuard::TypePredicate {
-            var,
-            predicate,
-            negated,
-        } => TypeGuard::TypePredicate {
-            var: var.clone(),
-            predicate: predicate.clone(),
-            negated: !negated,
-        },
-        TypeGuard::Truthiness { var, negated } => TypeGuard::Truthiness {
-            var: var.clone(),
-            negated: !negated,
-        },
-    }
+/// A declaration annotated with `//@@deprecated("message")`.
+#[derive(Debug, Clone)]
+struct Deprecation {
+    message: String,
+    /// Span of the declaration's name (matches the bound symbol's span)
+    span: Span,
+}
+
+/// Array methods that mutate the receiver, rejected on `readonly` arrays and tuples.
+fn is_mutating_array_method(name: &str) -> bool {
+    matches!(
+        name,
+        "push"
+            | "pop"
+            | "shift"
+            | "unshift"
+            | "splice"
+            | "sort"
+            | "reverse"
+            | "fill"
+            | "copyWithin"
+    )
 }
 
 /// Type checker
@@ -208,6 +176,15 @@ pub struct TypeChecker<'a> {
     in_assignment_lhs: bool,
     /// AST-derived class summaries used for abstract-contract checks.
     class_ast_summaries: FxHashMap<String, ClassAstSummary>,
+    /// Deprecated functions/classes keyed by name, and methods keyed by `Class.method`.
+    deprecations: FxHashMap<String, Deprecation>,
+    /// Warn when inference produces `unknown`/`any` at assignment, return and
+    /// argument positions.
+    strict_unknown: bool,
+    /// Variables whose `unknown`/`any` type was written out (annotation or cast)
+    /// rather than inferred. Only tracked with `strict_unknown`.
+    /// Keyed by (declaration_scope_id, variable_name).
+    explicit_unknown_vars: FxHashSet<(u32, String)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -251,6 +228,9 @@ impl<'a> TypeChecker<'a> {
             policy: CheckerPolicy::for_mode(TypeSystemMode::Raya),
             in_assignment_lhs: false,
             class_ast_summaries: FxHashMap::default(),
+            deprecations: FxHashMap::default(),
+            strict_unknown: false,
+            explicit_unknown_vars: FxHashSet::default(),
         }
     }
 
@@ -267,6 +247,13 @@ impl<'a> TypeChecker<'a> {
         self
     }
 
+    /// Report expressions whose `unknown`/`any` type came from inference
+    /// (`raya check --strict-unknown`).
+    pub fn with_strict_unknown(mut self, enable: bool) -> Self {
+        self.strict_unknown = enable;
+        self
+    }
+
     #[inline]
     fn is_strict_mode(&self) -> bool {
         self.policy.strict_assignability
@@ -655,6 +642,12 @@ impl<'a> TypeChecker<'a> {
             }
             Expression::Member(_) => true,
             Expression::TypeCast(cast) => self.infer_constructible_alias_expr(&cast.object),
+            Expression::Satisfies(satisfies) => {
+                self.infer_constructible_alias_expr(&satisfies.object)
+            }
+            Expression::ConstAssertion(assertion) => {
+                self.infer_constructible_alias_expr(&assertion.object)
+            }
             Expression::Parenthesized(expr) => {
                 self.infer_constructible_alias_expr(&expr.expression)
             }
@@ -692,6 +685,103 @@ impl<'a> TypeChecker<'a> {
             .contains(&(self.current_scope.0, name.to_string()))
     }
 
+    fn set_explicit_unknown_var_state(&mut self, name: &str, explicit: bool) {
+        if !self.strict_unknown {
+            return;
+        }
+        let symbol = self.symbols.resolve_from_scope(name, self.current_scope);
+        let scope_id = symbol.map(|s| s.scope_id.0).unwrap_or(self.current_scope.0);
+        let key = (scope_id, name.to_string());
+        if explicit {
+            self.explicit_unknown_vars.insert(key);
+        } else {
+            self.explicit_unknown_vars.remove(&key);
+        }
+    }
+
+    fn is_explicit_unknown_var(&self, name: &str) -> bool {
+        if let Some(symbol) = self.symbols.resolve_from_scope(name, self.current_scope) {
+            return self
+                .explicit_unknown_vars
+                .contains(&(symbol.scope_id.0, name.to_string()));
+        }
+        self.explicit_unknown_vars
+            .contains(&(self.current_scope.0, name.to_string()))
+    }
+
+    /// Mark annotated parameters so their declared `unknown`/`any` type is not
+    /// reported as an inference leak.
+    fn record_annotated_params(&mut self, params: &[Parameter]) {
+        for param in params {
+            if param.type_annotation.is_none() {
+                continue;
+            }
+            if let Pattern::Identifier(ident) = &param.pattern {
+                let name = self.resolve(ident.name);
+                self.set_explicit_unknown_var_state(&name, true);
+            }
+        }
+    }
+
+    /// Whether `expr` has an `unknown`/`any` type that inference produced,
+    /// rather than one written out through an annotation or `as` cast.
+    fn is_inferred_unknown(&self, expr: &Expression, ty: TypeId) -> bool {
+        use crate::parser::types::Type;
+        let is_unknown_or_any =
+            |ty: TypeId| matches!(self.type_ctx.get(ty), Some(Type::Unknown | Type::Any));
+        if !is_unknown_or_any(ty) {
+            return false;
+        }
+        // Members and call results of a known type carry a declared `unknown`;
+        // if inference produced it, the leak was reported at its source.
+        let base_leaked =
+            |base: &Expression| self.get_expr_type(base).is_none_or(is_unknown_or_any);
+        match expr {
+            Expression::Identifier(ident) => {
+                !self.is_explicit_unknown_var(&self.resolve(ident.name))
+            }
+            Expression::TypeCast(_) | Expression::ConstAssertion(_) => false,
+            Expression::Parenthesized(paren) => self.is_inferred_unknown(&paren.expression, ty),
+            Expression::Satisfies(satisfies) => self.is_inferred_unknown(&satisfies.object, ty),
+            Expression::Member(member) => base_leaked(&member.object),
+            Expression::Index(index) => base_leaked(&index.object),
+            Expression::Call(call) => base_leaked(&call.callee),
+            _ => true,
+        }
+    }
+
+    /// Warn when `expr` flows into `position` with an inferred `unknown`/`any`
+    /// type. Returns whether it did.
+    fn report_inferred_unknown(&mut self, expr: &Expression, ty: TypeId, position: &str) -> bool {
+        if !self.strict_unknown || !self.is_inferred_unknown(expr, ty) {
+            return false;
+        }
+        let span = *expr.span();
+        let already_reported = self.warnings.iter().any(
+            |warning| matches!(warning, CheckWarning::InferredUnknown { span: s, .. } if *s == span),
+        );
+        if !already_reported {
+            let ty = self.format_type(ty);
+            self.warnings.push(CheckWarning::InferredUnknown {
+                ty,
+                position: position.to_string(),
+                span,
+            });
+        }
+        true
+    }
+
+    fn report_inferred_unknown_args(&mut self, arguments: &[Expression]) {
+        if !self.strict_unknown {
+            return;
+        }
+        for arg in arguments {
+            if let Some(arg_ty) = self.get_expr_type(arg) {
+                self.report_inferred_unknown(arg, arg_ty, "argument");
+            }
+        }
+    }
+
     fn infer_helper_expected_this_type(&self, target_expr: &Expression) -> Option<TypeId> {
         match target_expr {
             Expression::Identifier(ident) => {
@@ -752,6 +842,7 @@ impl<'a> TypeChecker<'a> {
     /// Inferred types should be applied to the symbol table using `update_type`.
     pub fn check_module(mut self, module: &Module) -> Result<CheckResult, Vec<CheckError>> {
         self.index_class_ast_summaries(module);
+        self.index_deprecations(module);
         // Mirror binder module scope so top-level resolution is local->global.
         self.enter_scope();
         for stmt in &module.statements {
@@ -759,8 +850,9 @@ impl<'a> TypeChecker<'a> {
         }
         self.exit_scope();
 
-        // Collect unused variable warnings
+        // Collect unused variable and import warnings
         self.collect_unused_warnings();
+        self.collect_unused_import_warnings(module);
 
         if self.errors.is_empty() {
             Ok(CheckResult {
@@ -795,14 +887,21 @@ impl<'a> TypeChecker<'a> {
 
                 let mut abstract_methods = FxHashSet::default();
                 let mut concrete_methods = FxHashSet::default();
+                let mut fields = FxHashMap::default();
                 for member in &class.members {
-                    if let ClassMember::Method(method) = member {
-                        let method_name = self.resolve(method.name.name);
-                        if method.is_abstract || method.body.is_none() {
-                            abstract_methods.insert(method_name);
-                        } else {
-                            concrete_methods.insert(method_name);
+                    match member {
+                        ClassMember::Method(method) => {
+                            let method_name = self.resolve(method.name.name);
+                            if method.is_abstract || method.body.is_none() {
+                                abstract_methods.insert(method_name);
+                            } else {
+                                concrete_methods.insert(method_name);
+                            }
                         }
+                        ClassMember::Field(field) if !field.is_static => {
+                            fields.insert(self.resolve(field.name.name), field.name.span);
+                        }
+                        _ => {}
                     }
                 }
 
@@ -813,6 +912,7 @@ impl<'a> TypeChecker<'a> {
                         extends,
                         abstract_methods,
                         concrete_methods,
+                        fields,
                     },
                 );
             }
@@ -823,6 +923,89 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    fn index_deprecations(&mut self, module: &Module) {
+        self.deprecations.clear();
+        for stmt in &module.statements {
+            self.index_deprecations_stmt(stmt);
+        }
+    }
+
+    fn index_deprecations_stmt(&mut self, stmt: &Statement) {
+        fn deprecation(annotations: &[Annotation], span: Span) -> Option<Deprecation> {
+            annotations
+                .iter()
+                .find_map(Annotation::deprecation_message)
+                .map(|message| Deprecation {
+                    message: message.to_string(),
+                    span,
+                })
+        }
+
+        match stmt {
+            Statement::FunctionDecl(func) => {
+                if let Some(entry) = deprecation(&func.annotations, func.name.span) {
+                    let name = self.resolve(func.name.name);
+                    self.deprecations.insert(name, entry);
+                }
+            }
+            Statement::ClassDecl(class) => {
+                let class_name = self.resolve(class.name.name);
+                for member in &class.members {
+                    if let ClassMember::Method(method) = member {
+                        if let Some(entry) = deprecation(&method.annotations, method.name.span) {
+                            let key = format!("{}.{}", class_name, self.resolve(method.name.name));
+                            self.deprecations.insert(key, entry);
+                        }
+                    }
+                }
+                if let Some(entry) = deprecation(&class.annotations, class.name.span) {
+                    self.deprecations.insert(class_name, entry);
+                }
+            }
+            Statement::ExportDecl(ExportDecl::Declaration(inner_stmt)) => {
+                self.index_deprecations_stmt(inner_stmt);
+            }
+            _ => {}
+        }
+    }
+
+    /// Warn when `name` resolves to a function or class annotated `//@@deprecated`.
+    fn warn_if_deprecated_symbol(&mut self, name: &str, use_span: Span) {
+        let Some(deprecation) = self.deprecations.get(name) else {
+            return;
+        };
+        // A local binding that shadows the deprecated declaration is not a use of it.
+        let declared_here = self
+            .symbols
+            .resolve_from_scope(name, self.current_scope)
+            .is_some_and(|symbol| symbol.span == deprecation.span);
+        if !declared_here {
+            return;
+        }
+        self.warnings.push(CheckWarning::Deprecated {
+            name: name.to_string(),
+            message: deprecation.message.clone(),
+            span: use_span,
+            declaration: deprecation.span,
+        });
+    }
+
+    /// Warn when `class_name.method_name` is annotated `//@@deprecated`.
+    fn warn_if_deprecated_method(&mut self, class_name: &str, method_name: &str, use_span: Span) {
+        if self.deprecations.is_empty() {
+            return;
+        }
+        let name = format!("{}.{}", class_name, method_name);
+        if let Some(deprecation) = self.deprecations.get(&name) {
+            self.warnings.push(CheckWarning::Deprecated {
+                message: deprecation.message.clone(),
+                declaration: deprecation.span,
+                name,
+                span: use_span,
+            });
+        }
+    }
+
     fn required_abstract_methods_for_class(
         &self,
         class_name: &str,
@@ -896,6 +1079,72 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// A field redeclared in a subclass reuses the inherited field's slot
+    /// (see `register_class` in the lowerer), so its type must be assignable
+    /// to the inherited one or the slot would be read as the wrong kind of
+    /// value.
+    fn check_field_shadowing(&mut self, class: &ClassDecl) {
+        let Some(own) = self
+            .current_class_type
+            .and_then(|ty| self.resolve_class_type(ty))
+        else {
+            return;
+        };
+
+        for member in &class.members {
+            let ClassMember::Field(field) = member else {
+                continue;
+            };
+            if field.is_static {
+                continue;
+            }
+            let name = self.resolve(field.name.name);
+            let Some(field_ty) = own.properties.iter().find(|p| p.name == name).map(|p| p.ty)
+            else {
+                continue;
+            };
+            let Some((inherited_ty, owner)) = self.inherited_field(own.extends, &name) else {
+                continue;
+            };
+            // Generic parents are checked once instantiated, not against `T`.
+            if contains_type_variables(self.type_ctx, inherited_ty) {
+                continue;
+            }
+
+            if !self
+                .make_assignability_ctx()
+                .is_assignable(field_ty, inherited_ty)
+            {
+                let parent_span = self
+                    .class_ast_summaries
+                    .get(&owner)
+                    .and_then(|summary| summary.fields.get(&name))
+                    .copied();
+                self.errors.push(CheckError::IncompatibleFieldShadow {
+                    field: name,
+                    parent: owner,
+                    expected: self.type_ctx.format_type(inherited_ty),
+                    actual: self.type_ctx.format_type(field_ty),
+                    span: field.name.span,
+                    parent_span,
+                });
+            }
+        }
+    }
+
+    /// Nearest ancestor instance field named `name`, with the declaring class.
+    fn inherited_field(&mut self, parent: Option<TypeId>, name: &str) -> Option<(TypeId, String)> {
+        let mut cursor = parent;
+        while let Some(ty) = cursor {
+            let class = self.resolve_class_type(ty)?;
+            if let Some(prop) = class.properties.iter().find(|p| p.name == name) {
+                return Some((prop.ty, class.name));
+            }
+            cursor = class.extends;
+        }
+        None
+    }
+
     /// Collect warnings for unused variables across all scopes
     fn collect_unused_warnings(&mut self) {
         use super::symbols::SymbolKind;
@@ -930,6 +1179,16 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Collect warnings for imported names that are never referenced
+    fn collect_unused_import_warnings(&mut self, module: &Module) {
+        for unused in super::unused_imports::find_unused_imports(module) {
+            self.warnings.push(CheckWarning::UnusedImport {
+                name: self.resolve(unused.local.name),
+                span: unused.local.span,
+            });
+        }
+    }
+
     /// Get the errors collected during checking
     pub fn errors(&self) -> &[CheckError] {
         &self.errors
@@ -1000,10 +1259,15 @@ impl<'a> TypeChecker<'a> {
 
         if let Some(ref init) = decl.initializer {
             let init_ty = self.check_expr(init);
+            let init_leaked = self.report_inferred_unknown(init, init_ty, "assigned value");
 
             match &decl.pattern {
                 Pattern::Identifier(ident) => {
                     let name = self.resolve(ident.name);
+                    self.set_explicit_unknown_var_state(
+                        &name,
+                        decl.type_annotation.is_some() || !init_leaked,
+                    );
 
                     // Determine the variable's type
                     let var_ty = if decl.type_annotation.is_some() {
@@ -1306,6 +1570,7 @@ impl<'a> TypeChecker<'a> {
 
         // Enter function scope (mirrors binder's push_scope for function)
         self.enter_scope();
+        self.record_annotated_params(&func.params);
 
         // Register parameter types in type_env for destructuring patterns
         for (i, param) in func.params.iter().enumerate() {
@@ -1605,6 +1870,7 @@ impl<'a> TypeChecker<'a> {
 
                         // Enter method scope (binder creates one for every concrete method body)
                         self.enter_scope();
+                        self.record_annotated_params(&method.params);
 
                         // Register parameter types in type_env for destructuring patterns
                         if let Some(ref pts) = param_types {
@@ -1657,6 +1923,7 @@ impl<'a> TypeChecker<'a> {
 
                     // Enter constructor scope (binder always creates one)
                     self.enter_scope();
+                    self.record_annotated_params(&ctor.params);
 
                     // Register parameter types in type_env for destructuring patterns
                     for param in &ctor.params {
@@ -1759,6 +2026,7 @@ impl<'a> TypeChecker<'a> {
         }
 
         self.check_abstract_class_contract(class);
+        self.check_field_shadowing(class);
 
         // Exit class scope
         self.exit_scope();
@@ -1840,6 +2108,9 @@ impl<'a> TypeChecker<'a> {
                     if let Some(test) = &case.test {
                         self.collect_this_assignments_expr(test, assigned);
                     }
+                    if let Some(guard) = &case.guard {
+                        self.collect_this_assignments_expr(guard, assigned);
+                    }
                     for cons in &case.consequent {
                         self.collect_this_assignments_stmt(cons, assigned);
                     }
@@ -1916,6 +2187,10 @@ impl<'a> TypeChecker<'a> {
                 self.collect_this_assignments_expr(&p.expression, assigned)
             }
             Expression::TypeCast(c) => self.collect_this_assignments_expr(&c.object, assigned),
+            Expression::Satisfies(s) => self.collect_this_assignments_expr(&s.object, assigned),
+            Expression::ConstAssertion(c) => {
+                self.collect_this_assignments_expr(&c.object, assigned)
+            }
             Expression::InstanceOf(i) => self.collect_this_assignments_expr(&i.object, assigned),
             Expression::Await(a) => self.collect_this_assignments_expr(&a.argument, assigned),
             Expression::Array(a) => {
@@ -1950,6 +2225,7 @@ impl<'a> TypeChecker<'a> {
     fn check_return(&mut self, ret: &ReturnStatement) {
         if let Some(ref expr) = ret.value {
             let expr_ty = self.check_expr(expr);
+            self.report_inferred_unknown(expr, expr_ty, "return value");
 
             if let Some(expected_ty) = self.current_function_return_type {
                 self.check_assignable(expr_ty, expected_ty, *expr.span());
@@ -2145,8 +2421,9 @@ impl<'a> TypeChecker<'a> {
             self.check_assignable(cond_ty, bool_ty, *if_stmt.condition.span());
         }
 
-        // Try to extract single type guard (used for else-branch / early-return narrowing)
-        let type_guard = extract_type_guard(&if_stmt.condition, self.interner);
+        // Guards that hold when the condition is false (used for else-branch / early-exit
+        // narrowing, including `||` compound conditions)
+        let falsy_guards = extract_all_falsy_type_guards(&if_stmt.condition, self.interner);
 
         // Extract all type guards including from && compound conditions (used for then-branch)
         let all_guards = extract_all_type_guards(&if_stmt.condition, self.interner);
@@ -2183,34 +2460,32 @@ impl<'a> TypeChecker<'a> {
         // Restore environment and apply negated guard for else branch
         self.type_env = saved_env.clone();
 
-        let apply_negated_guard = |checker: &mut TypeChecker<'_>, guard: &TypeGuard| {
-            let negated_guard = negate_guard(guard);
-            let var_name = get_guard_var(&negated_guard);
-            if let Some(var_ty) = checker.get_var_type(var_name) {
-                if let Some(narrowed_ty) =
-                    apply_type_guard(checker.type_ctx, var_ty, &negated_guard)
-                {
-                    checker.type_env.set(var_name.clone(), narrowed_ty);
+        // The false path reaches the else branch, or the continuation when there is none.
+        for guard in &falsy_guards {
+            let var_name = get_guard_var(guard);
+            if let Some(var_ty) = self.get_var_type(var_name) {
+                if let Some(narrowed_ty) = apply_type_guard(self.type_ctx, var_ty, guard) {
+                    self.type_env.set(var_name.clone(), narrowed_ty);
                 }
             }
-        };
-
+        }
         if let Some(ref else_branch) = if_stmt.else_branch {
-            if let Some(ref guard) = type_guard {
-                apply_negated_guard(self, guard);
-            }
             self.check_stmt(else_branch);
-        } else if let Some(ref guard) = type_guard {
-            // No else branch: the false path still reaches continuation,
-            // so include negated-guard narrowing in the merge environment.
-            apply_negated_guard(self, guard);
         }
 
         let else_env = self.type_env.clone();
 
-        if then_returns && if_stmt.else_branch.is_none() {
-            // Then-branch always exits, no else: continuation uses the narrowed env
+        let else_returns = if_stmt
+            .else_branch
+            .as_ref()
+            .is_some_and(|e| Self::stmt_definitely_returns(e));
+
+        if then_returns && !else_returns {
+            // Then-branch always exits: continuation is only reached through the false path
             self.type_env = else_env;
+        } else if else_returns && !then_returns {
+            // Else-branch always exits: continuation is only reached through the then-branch
+            self.type_env = then_env;
         } else {
             // Normal merge of both branches
             self.type_env = then_env.merge(&else_env, self.type_ctx);
@@ -2361,6 +2636,9 @@ impl<'a> TypeChecker<'a> {
         let Some(ty) = self.type_ctx.get(iterable_ty).cloned() else {
             return self.type_ctx.unknown_type();
         };
+        if let Some(elem_ty) = self.for_of_element_from_builtin(&ty) {
+            return elem_ty;
+        }
 
         match ty {
             Type::Array(arr) => arr.element,
@@ -2487,6 +2765,41 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Element type of a `for-of` over a builtin class whose signature declares one
+    fn for_of_element_from_builtin(&mut self, ty: &crate::parser::types::Type) -> Option<TypeId> {
+        use crate::parser::types::{GenericContext, Type};
+
+        let (name, type_args) = match ty {
+            Type::Array(arr) => ("Array".to_string(), vec![arr.element]),
+            Type::Set(set_ty) => ("Set".to_string(), vec![set_ty.element]),
+            Type::Map(map_ty) => ("Map".to_string(), vec![map_ty.key, map_ty.value]),
+            Type::Reference(reference) => (
+                reference.name.clone(),
+                reference.type_args.clone().unwrap_or_default(),
+            ),
+            Type::Generic(generic) => {
+                let name = match self.type_ctx.get(generic.base)? {
+                    Type::Reference(reference) => reference.name.clone(),
+                    Type::Class(class_ty) => class_ty.name.clone(),
+                    _ => return None,
+                };
+                (name, generic.type_args.clone())
+            }
+            _ => return None,
+        };
+
+        let symbols = self.symbols;
+        let (type_params, element) = symbols.builtin_iterator_element(&name)?;
+        if type_params.len() != type_args.len() {
+            return None;
+        }
+        let mut gen_ctx = GenericContext::new(self.type_ctx);
+        for (param, &arg) in type_params.iter().zip(&type_args) {
+            gen_ctx.add_substitution(param.clone(), arg);
+        }
+        Some(gen_ctx.apply_substitution(element).unwrap_or(element))
+    }
+
     fn for_of_element_from_reference(
         &mut self,
         name: &str,
@@ -2557,18 +2870,6 @@ impl<'a> TypeChecker<'a> {
         // Check discriminant and get its type
         let discriminant_ty = self.check_expr(&switch_stmt.discriminant);
 
-        // Check exhaustiveness for discriminated unions
-        let exhaustiveness =
-            check_switch_exhaustiveness(self.type_ctx, discriminant_ty, switch_stmt, self.interner);
-
-        // Report non-exhaustive matches
-        if let ExhaustivenessResult::NonExhaustive(missing) = exhaustiveness {
-            self.errors.push(CheckError::NonExhaustiveMatch {
-                missing,
-                span: switch_stmt.span,
-            });
-        }
-
         enum SwitchNarrowingBase {
             TypeofVar(String),
             DiscriminantVar { var: String, field: String },
@@ -2591,7 +2892,30 @@ impl<'a> TypeChecker<'a> {
             _ => None,
         };
 
+        // Check exhaustiveness for discriminated unions. `switch (x.kind)` is
+        // checked against the union type of `x` when `kind` is its discriminant.
+        let union_ty = match &narrowing_base {
+            Some(SwitchNarrowingBase::DiscriminantVar { var, field }) => self
+                .get_var_type(var)
+                .filter(|&var_ty| {
+                    get_discriminant_field(self.type_ctx, var_ty).as_ref() == Some(field)
+                })
+                .unwrap_or(discriminant_ty),
+            _ => discriminant_ty,
+        };
+        let exhaustiveness =
+            check_switch_exhaustiveness(self.type_ctx, union_ty, switch_stmt, self.interner);
+
+        // Report non-exhaustive matches
+        if let ExhaustivenessResult::NonExhaustive(missing) = exhaustiveness {
+            self.errors.push(CheckError::NonExhaustiveMatch {
+                missing,
+                span: switch_stmt.span,
+            });
+        }
+
         // Check cases
+        let mut entered_by_fallthrough = false;
         for case in &switch_stmt.cases {
             let saved_env = self.type_env.clone();
 
@@ -2626,10 +2950,39 @@ impl<'a> TypeChecker<'a> {
                 }
             }
 
+            // A `when` guard is checked under the case's narrowing. It only narrows
+            // the body when the previous case can't fall into it without the guard.
+            if let Some(ref guard) = case.guard {
+                let guard_ty = self.check_expr(guard);
+                let bool_ty = self.type_ctx.boolean_type();
+                self.check_assignable(guard_ty, bool_ty, *guard.span());
+                let type_guards = if entered_by_fallthrough {
+                    Vec::new()
+                } else {
+                    extract_all_type_guards(guard, self.interner)
+                };
+                for type_guard in type_guards {
+                    let var_name = get_guard_var(&type_guard);
+                    if let Some(var_ty) = self.get_var_type(var_name) {
+                        if let Some(narrowed_ty) =
+                            apply_type_guard(self.type_ctx, var_ty, &type_guard)
+                        {
+                            self.type_env.set(var_name.clone(), narrowed_ty);
+                        }
+                    }
+                }
+            }
+
             for stmt in &case.consequent {
                 self.check_stmt(stmt);
             }
 
+            entered_by_fallthrough = case.falls_through()
+                && !case
+                    .consequent
+                    .last()
+                    .is_some_and(Self::stmt_definitely_returns);
+
             // Cases are checked independently; don't leak branch narrowing into sibling cases.
             self.type_env = saved_env;
         }
@@ -2650,7 +3003,10 @@ impl<'a> TypeChecker<'a> {
             // Check catch parameter pattern (handles destructuring)
             if let Some(ref param) = catch.param {
                 let catch_ty = match param {
-                    Pattern::Identifier(_) => {
+                    Pattern::Identifier(ident) => {
+                        // Thrown values are `unknown` by design, not an inference gap.
+                        let name = self.resolve(ident.name);
+                        self.set_explicit_unknown_var_state(&name, true);
                         if self.policy.use_unknown_in_catch_variables {
                             self.type_ctx.unknown_type()
                         } else {
@@ -2686,6 +3042,10 @@ impl<'a> TypeChecker<'a> {
     fn check_expr(&mut self, expr: &Expression) -> TypeId {
         let ty = match expr {
             Expression::IntLiteral(_) | Expression::FloatLiteral(_) => self.type_ctx.number_type(),
+            Expression::BigIntLiteral(lit) => match self.type_ctx.lookup_named_type("BigInt") {
+                Some(bigint_ty) => bigint_ty,
+                None => self.fallback_type(lit.span, FallbackReason::Unavoidable, "bigint-literal"),
+            },
             Expression::StringLiteral(_) => self.type_ctx.string_type(),
             Expression::TemplateLiteral(tpl) => {
                 // Preserve type information/diagnostics for interpolated expressions.
@@ -2702,7 +3062,11 @@ impl<'a> TypeChecker<'a> {
             Expression::Binary(bin) => self.check_binary(bin),
             Expression::Logical(log) => self.check_logical(log),
             Expression::Unary(un) => self.check_unary(un),
-            Expression::Call(call) => self.check_call(call),
+            Expression::Call(call) => {
+                let ty = self.check_call(call);
+                self.report_inferred_unknown_args(&call.arguments);
+                ty
+            }
             Expression::Member(member) => self.check_member(member),
             Expression::Array(arr) => self.check_array(arr),
             Expression::Object(obj) => self.check_object(obj),
@@ -2715,12 +3079,21 @@ impl<'a> TypeChecker<'a> {
             Expression::Parenthesized(paren) => self.check_expr(&paren.expression),
             Expression::Arrow(arrow) => self.check_arrow(arrow),
             Expression::Index(index) => self.check_index(index),
-            Expression::New(new_expr) => self.check_new(new_expr),
+            Expression::New(new_expr) => {
+                let ty = self.check_new(new_expr);
+                self.report_inferred_unknown_args(&new_expr.arguments);
+                ty
+            }
             Expression::This(span) => self.check_this(*span),
             Expression::Await(await_expr) => self.check_await(await_expr),
             Expression::AsyncCall(async_call) => self.check_async_call(async_call),
             Expression::InstanceOf(instanceof) => self.check_instanceof(instanceof),
             Expression::TypeCast(cast) => self.check_type_cast(cast),
+            Expression::Satisfies(satisfies) => self.check_satisfies(satisfies),
+            Expression::ConstAssertion(assertion) => {
+                self.check_expr(&assertion.object);
+                self.const_type_of(&assertion.object)
+            }
             Expression::RegexLiteral(_) => self.type_ctx.regexp_type(),
             Expression::TaggedTemplate(tagged) => self.check_tagged_template(tagged),
             Expression::DynamicImport(dynamic_import) => self.check_dynamic_import(dynamic_import),
@@ -2825,6 +3198,8 @@ impl<'a> TypeChecker<'a> {
             return self.type_ctx.function_type(vec![], any_ty, false);
         }
 
+        self.warn_if_deprecated_symbol(&name, ident.span);
+
         // First check for narrowed type in type environment
         if let Some(narrowed_ty) = self.type_env.get(&name) {
             return narrowed_ty;
@@ -2858,9 +3233,11 @@ impl<'a> TypeChecker<'a> {
                         }
                     }
                 }
+                let suggestion = self.symbols.suggest_similar_name(&name, self.current_scope);
                 self.errors.push(CheckError::UndefinedVariable {
                     name,
                     span: ident.span,
+                    suggestion,
                 });
                 self.type_ctx.unknown_type()
             }
@@ -2926,8 +3303,12 @@ impl<'a> TypeChecker<'a> {
             BinaryOperator::Equal
             | BinaryOperator::NotEqual
             | BinaryOperator::StrictEqual
-            | BinaryOperator::StrictNotEqual
-            | BinaryOperator::LessThan
+            | BinaryOperator::StrictNotEqual => {
+                self.warn_if_disjoint_equality(bin, left_ty, right_ty);
+                self.type_ctx.boolean_type()
+            }
+
+            BinaryOperator::LessThan
             | BinaryOperator::LessEqual
             | BinaryOperator::GreaterThan
             | BinaryOperator::GreaterEqual => {
@@ -2950,6 +3331,41 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Warn on an equality whose operands are primitives of different kinds.
+    /// Equality never coerces, so e.g. `1 == "1"` is always false.
+    fn warn_if_disjoint_equality(&mut self, bin: &BinaryExpression, left: TypeId, right: TypeId) {
+        let (Some(left_kind), Some(right_kind)) = (
+            self.primitive_equality_kind(left),
+            self.primitive_equality_kind(right),
+        ) else {
+            return;
+        };
+        if left_kind == right_kind {
+            return;
+        }
+        self.warnings.push(CheckWarning::SuspiciousEquality {
+            left: left_kind.to_string(),
+            right: right_kind.to_string(),
+            always_true: matches!(
+                bin.operator,
+                BinaryOperator::NotEqual | BinaryOperator::StrictNotEqual
+            ),
+            span: bin.span,
+        });
+    }
+
+    /// Primitive kind compared by equality; `int` and `number` share one.
+    fn primitive_equality_kind(&self, ty: TypeId) -> Option<&'static str> {
+        use crate::parser::types::{PrimitiveType, Type};
+        match self.type_ctx.get(ty)? {
+            Type::Primitive(PrimitiveType::Number | PrimitiveType::Int)
+            | Type::NumberLiteral(_) => Some("number"),
+            Type::Primitive(PrimitiveType::String) | Type::StringLiteral(_) => Some("string"),
+            Type::Primitive(PrimitiveType::Boolean) | Type::BooleanLiteral(_) => Some("boolean"),
+            _ => None,
+        }
+    }
+
     /// Check logical expression
     fn check_logical(&mut self, log: &LogicalExpression) -> TypeId {
         match log.operator {
@@ -3057,6 +3473,8 @@ impl<'a> TypeChecker<'a> {
             | UnaryOperator::PrefixDecrement
             | UnaryOperator::PostfixIncrement
             | UnaryOperator::PostfixDecrement => {
+                // Increment/decrement write back to the operand
+                self.check_const_reassignment(&un.operand, un.span);
                 // Increment/decrement require number
                 let number_ty = self.type_ctx.number_type();
                 self.check_assignable(operand_ty, number_ty, *un.operand.span());
@@ -3073,6 +3491,22 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Report a write to a `const` binding
+    ///
+    /// Only a bare identifier rebinds the variable; writing through it
+    /// (`obj.field = v`, `arr[i] = v`) mutates the value it holds and is allowed.
+    fn check_const_reassignment(&mut self, target: &Expression, span: Span) {
+        if let Expression::Identifier(ident) = target {
+            let name = self.resolve(ident.name);
+            if let Some(symbol) = self.symbols.resolve_from_scope(&name, self.current_scope) {
+                if symbol.flags.is_const {
+                    self.errors
+                        .push(CheckError::ConstReassignment { name, span });
+                }
+            }
+        }
+    }
+
     /// Check function call
     fn check_call(&mut self, call: &CallExpression) -> TypeId {
         // super(...) constructor call
@@ -3154,6 +3588,12 @@ impl<'a> TypeChecker<'a> {
             .map(|arg| (self.check_expr(arg), *arg.span()))
             .collect();
 
+        // Builtins declared with several signatures resolve to the first
+        // overload that accepts the arguments.
+        if let Some((name, candidates)) = self.builtin_overload_candidates(&call.callee) {
+            return self.resolve_overloaded_call(name, &candidates, &arg_types, call.span);
+        }
+
         // Clone the function type to avoid borrow checker issues. Structural
         // callable object/interface signatures are projected to function types.
         let mut func_ty_opt = self.type_ctx.get(callee_ty).cloned();
@@ -3460,23 +3900,149 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
-    fn compute_fn_arity_bounds(
-        &self,
-        func: &crate::parser::types::ty::FunctionType,
-    ) -> (usize, usize) {
-        let fixed_len = func.params.len();
-        match func
-            .rest_param
-            .and_then(|rest| self.type_ctx.get(rest))
-            .cloned()
-        {
-            Some(crate::parser::types::Type::Tuple(t)) => (
-                func.min_params + t.elements.len(),
-                fixed_len + t.elements.len(),
-            ),
-            Some(crate::parser::types::Type::Array(_)) => (func.min_params, usize::MAX),
-            Some(_) => (func.min_params, usize::MAX),
-            None => (func.min_params, fixed_len),
+    /// Overload set behind a callee that names a builtin function or class
+    /// method declared with more than one signature, keyed by display name.
+    fn builtin_overload_candidates(
+        &mut self,
+        callee: &Expression,
+    ) -> Option<(String, Vec<TypeId>)> {
+        match callee {
+            Expression::Identifier(ident) => {
+                let name = self.resolve(ident.name);
+                let symbol = self.symbols.resolve_from_scope(&name, self.current_scope)?;
+                if symbol.kind != SymbolKind::Function {
+                    return None;
+                }
+                let overloads = self.symbols.builtin_overloads(&name)?;
+                // A user declaration shadowing the builtin is not overloaded.
+                if !overloads.contains(&symbol.ty) {
+                    return None;
+                }
+                Some((name, overloads.to_vec()))
+            }
+            Expression::Member(member) => {
+                let method_name = self.resolve(member.property.name);
+                let static_class = match member.object.as_ref() {
+                    Expression::Identifier(ident) => {
+                        let name = self.resolve(ident.name);
+                        self.symbols
+                            .resolve_from_scope(&name, self.current_scope)
+                            .filter(|symbol| symbol.kind == SymbolKind::Class)
+                            .map(|symbol| symbol.ty)
+                    }
+                    _ => None,
+                };
+                let (class, methods) = if let Some(class_ty) = static_class {
+                    let class = self.resolve_class_type(class_ty)?;
+                    let methods = class.static_methods.clone();
+                    (class, methods)
+                } else {
+                    let object_ty = self.get_expr_type(&member.object)?;
+                    let class = self.resolve_class_type(object_ty)?;
+                    let methods = class.methods.clone();
+                    (class, methods)
+                };
+                let overloads: Vec<TypeId> = methods
+                    .iter()
+                    .filter(|method| method.name == method_name)
+                    .map(|method| method.ty)
+                    .collect();
+                if overloads.len() < 2 {
+                    return None;
+                }
+                Some((format!("{}.{}", class.name, method_name), overloads))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check a call against each overload in declaration order, returning the
+    /// result type of the first match or reporting every candidate.
+    fn resolve_overloaded_call(
+        &mut self,
+        name: String,
+        candidates: &[TypeId],
+        arg_types: &[(TypeId, crate::parser::Span)],
+        span: crate::parser::Span,
+    ) -> TypeId {
+        for &candidate in candidates {
+            if let Some(return_ty) = self.match_overload(candidate, arg_types) {
+                return return_ty;
+            }
+        }
+        let candidates = candidates.iter().map(|&ty| self.format_type(ty)).collect();
+        self.errors.push(CheckError::NoMatchingOverload {
+            name,
+            candidates,
+            span,
+        });
+        self.type_ctx.unknown_type()
+    }
+
+    /// Check arguments against one overload without reporting errors.
+    fn match_overload(
+        &mut self,
+        sig_ty: TypeId,
+        arg_types: &[(TypeId, crate::parser::Span)],
+    ) -> Option<TypeId> {
+        use crate::parser::types::Type;
+        let func = match self.type_ctx.get(sig_ty).cloned()? {
+            Type::Function(func) => func,
+            _ => return None,
+        };
+        let (min_params, max_params) = self.compute_fn_arity_bounds(&func);
+        if arg_types.len() < min_params || arg_types.len() > max_params {
+            return None;
+        }
+        let param_tys: Vec<TypeId> = (0..arg_types.len())
+            .map(|i| self.helper_param_type_at(&func, i))
+            .collect::<Option<_>>()?;
+
+        let (generic_params, concrete_params): (Vec<_>, Vec<_>) = param_tys
+            .into_iter()
+            .zip(arg_types.iter().map(|(arg_ty, _)| *arg_ty))
+            .partition(|(param_ty, _)| contains_type_variables(self.type_ctx, *param_ty));
+        let mut assign_ctx = self.make_assignability_ctx();
+        if !concrete_params
+            .into_iter()
+            .all(|(param_ty, arg_ty)| assign_ctx.is_assignable(arg_ty, param_ty))
+        {
+            return None;
+        }
+
+        if generic_params.is_empty() && !contains_type_variables(self.type_ctx, func.return_type) {
+            return Some(func.return_type);
+        }
+        let mut gen_ctx = GenericContext::new(self.type_ctx);
+        for (param_ty, arg_ty) in generic_params {
+            if !matches!(gen_ctx.unify(param_ty, arg_ty), Ok(true)) {
+                return None;
+            }
+        }
+        Some(
+            gen_ctx
+                .apply_substitution(func.return_type)
+                .unwrap_or(func.return_type),
+        )
+    }
+
+    fn compute_fn_arity_bounds(
+        &self,
+        func: &crate::parser::types::ty::FunctionType,
+    ) -> (usize, usize) {
+        let fixed_len = func.params.len();
+        match func
+            .rest_param
+            .and_then(|rest| self.type_ctx.get(rest))
+            .cloned()
+        {
+            Some(crate::parser::types::Type::Tuple(t)) => (
+                func.min_params + t.elements.len(),
+                fixed_len + t.elements.len(),
+            ),
+            Some(crate::parser::types::Type::Array(_)) => (func.min_params, usize::MAX),
+            Some(_) => (func.min_params, usize::MAX),
+            None => (func.min_params, fixed_len),
         }
     }
 
@@ -3795,6 +4361,7 @@ impl<'a> TypeChecker<'a> {
             // Literals don't have free variables
             Expression::IntLiteral(_)
             | Expression::FloatLiteral(_)
+            | Expression::BigIntLiteral(_)
             | Expression::StringLiteral(_)
             | Expression::BooleanLiteral(_)
             | Expression::NullLiteral(_) => {}
@@ -3905,6 +4472,9 @@ impl<'a> TypeChecker<'a> {
                     if let Some(ref test) = case.test {
                         self.collect_free_vars_expr(test, collector);
                     }
+                    if let Some(ref guard) = case.guard {
+                        self.collect_free_vars_expr(guard, collector);
+                    }
                     for stmt in &case.consequent {
                         self.collect_free_vars_stmt(stmt, collector);
                     }
@@ -4014,6 +4584,7 @@ impl<'a> TypeChecker<'a> {
         // Collect parameter names for binding
         let mut param_names = Vec::new();
         let mut param_types = Vec::new();
+        self.record_annotated_params(&arrow.params);
 
         for param in &arrow.params {
             if !self.allows_implicit_any() && param.type_annotation.is_none() {
@@ -4581,6 +5152,7 @@ impl<'a> TypeChecker<'a> {
             // Look up the class symbol to get its type
             if let Some(symbol) = self.symbols.resolve_from_scope(&name, self.current_scope) {
                 if symbol.kind == SymbolKind::Class {
+                    self.warn_if_deprecated_symbol(&name, ident.span);
                     // Check if the class is abstract (cannot be instantiated)
                     if let Some(crate::parser::types::Type::Class(class)) =
                         self.type_ctx.get(symbol.ty).cloned()
@@ -5066,6 +5638,98 @@ impl<'a> TypeChecker<'a> {
         self.resolve_type_annotation(&cast.target_type)
     }
 
+    /// Check `expr satisfies T`: the expression must be assignable to `T`
+    /// but keeps its own inferred type.
+    fn check_satisfies(&mut self, satisfies: &SatisfiesExpression) -> TypeId {
+        let object_ty = self.check_expr(&satisfies.object);
+        let target_ty = self.resolve_type_annotation(&satisfies.target_type);
+
+        let mut assign_ctx = self.make_assignability_ctx();
+        if !assign_ctx.is_assignable(object_ty, target_ty) {
+            self.errors.push(CheckError::UnsatisfiedType {
+                expected: self.type_ctx.format_type(target_ty),
+                actual: self.type_ctx.format_type(object_ty),
+                span: satisfies.span,
+            });
+        }
+        object_ty
+    }
+
+    /// Narrowest type of an `as const` operand: literal primitives, readonly
+    /// tuples and objects with readonly properties. Non-literal parts keep the
+    /// type already inferred for them.
+    fn const_type_of(&mut self, expr: &Expression) -> TypeId {
+        use crate::parser::types::ty::{ObjectType, PropertySignature};
+        match expr {
+            Expression::StringLiteral(lit) => {
+                let value = self.resolve(lit.value);
+                self.type_ctx.string_literal(value)
+            }
+            Expression::IntLiteral(lit) => self.type_ctx.number_literal(lit.value as f64),
+            Expression::FloatLiteral(lit) => self.type_ctx.number_literal(lit.value),
+            Expression::BooleanLiteral(lit) => self.type_ctx.boolean_literal(lit.value),
+            Expression::Unary(unary) if unary.operator == UnaryOperator::Minus => {
+                match unary.operand.as_ref() {
+                    Expression::IntLiteral(lit) => {
+                        self.type_ctx.number_literal(-(lit.value as f64))
+                    }
+                    Expression::FloatLiteral(lit) => self.type_ctx.number_literal(-lit.value),
+                    _ => self.inferred_type_of(expr),
+                }
+            }
+            Expression::Parenthesized(paren) => self.const_type_of(&paren.expression),
+            Expression::Array(arr) => {
+                let mut elements = Vec::with_capacity(arr.elements.len());
+                for element in &arr.elements {
+                    match element {
+                        Some(ArrayElement::Expression(elem)) => {
+                            elements.push(self.const_type_of(elem));
+                        }
+                        // Spreads and holes have no fixed position.
+                        _ => return self.inferred_type_of(expr),
+                    }
+                }
+                self.type_ctx.readonly_tuple_type(elements)
+            }
+            Expression::Object(obj) => {
+                let mut properties = Vec::with_capacity(obj.properties.len());
+                for property in &obj.properties {
+                    let ObjectProperty::Property(prop) = property else {
+                        return self.inferred_type_of(expr);
+                    };
+                    let name = match &prop.key {
+                        PropertyKey::Identifier(ident) => self.resolve(ident.name),
+                        PropertyKey::StringLiteral(lit) => self.resolve(lit.value),
+                        PropertyKey::IntLiteral(lit) => lit.value.to_string(),
+                        PropertyKey::Computed(_) => return self.inferred_type_of(expr),
+                    };
+                    let ty = self.const_type_of(&prop.value);
+                    properties.push(PropertySignature {
+                        name,
+                        ty,
+                        optional: false,
+                        readonly: true,
+                        visibility: Default::default(),
+                    });
+                }
+                self.type_ctx
+                    .intern(crate::parser::types::Type::Object(ObjectType {
+                        properties,
+                        index_signature: None,
+                        call_signatures: vec![],
+                        construct_signatures: vec![],
+                    }))
+            }
+            _ => self.inferred_type_of(expr),
+        }
+    }
+
+    /// Type recorded for an already-checked expression.
+    fn inferred_type_of(&mut self, expr: &Expression) -> TypeId {
+        self.get_expr_type(expr)
+            .unwrap_or_else(|| self.type_ctx.unknown_type())
+    }
+
     /// Check member access
     fn check_member(&mut self, member: &MemberExpression) -> TypeId {
         let property_name = self.resolve(member.property.name);
@@ -5189,6 +5853,11 @@ impl<'a> TypeChecker<'a> {
                         // Check static methods
                         for method in &class.static_methods {
                             if method.name == property_name {
+                                self.warn_if_deprecated_method(
+                                    &class.name,
+                                    &property_name,
+                                    member.property.span,
+                                );
                                 return method.ty;
                             }
                         }
@@ -5242,6 +5911,20 @@ impl<'a> TypeChecker<'a> {
             other => other,
         };
 
+        // `readonly` arrays and tuples keep their read-only methods only.
+        let is_readonly_array = match &obj_type {
+            Some(crate::parser::types::Type::Array(arr)) => arr.readonly,
+            Some(crate::parser::types::Type::Tuple(tuple)) => tuple.readonly,
+            _ => false,
+        };
+        if is_readonly_array && is_mutating_array_method(&property_name) {
+            self.errors.push(CheckError::ReadonlyArrayMutation {
+                ty: self.type_ctx.format_type(lookup_object_ty),
+                operation: format!("mutating method '{}'", property_name),
+                span: member.span,
+            });
+        }
+
         // Check for built-in array methods
         if let Some(crate::parser::types::Type::Array(arr)) = &obj_type {
             let elem_ty = arr.element;
@@ -5407,6 +6090,7 @@ impl<'a> TypeChecker<'a> {
                     });
                     return self.type_ctx.unknown_type();
                 }
+                self.warn_if_deprecated_method(&owner_name, &property_name, member.property.span);
                 return ty;
             }
 
@@ -6354,11 +7038,14 @@ impl<'a> TypeChecker<'a> {
                         .function_type(vec![regexp_ty], result_ty, false),
                 )
             }
-            // matchAll(pattern: RegExp) -> Array<string[]>
+            // matchAll(pattern: RegExp) -> Array<RegExpMatch>
             "matchAll" => {
                 let regexp_ty = self.type_ctx.regexp_type();
-                let inner_arr_ty = self.type_ctx.array_type(string_ty);
-                let arr_ty = self.type_ctx.array_type(inner_arr_ty);
+                let match_ty = self
+                    .type_ctx
+                    .lookup_named_type("RegExpMatch")
+                    .unwrap_or_else(|| self.type_ctx.unknown_type());
+                let arr_ty = self.type_ctx.array_type(match_ty);
                 Some(self.type_ctx.function_type(vec![regexp_ty], arr_ty, false))
             }
             // search(pattern: RegExp) -> number
@@ -6728,6 +7415,18 @@ impl<'a> TypeChecker<'a> {
                 void_ty,
                 false,
             )),
+            // Typed accessors with explicit byte order, e.g. getInt32BE(index) -> number
+            "getUint8" | "getInt16LE" | "getInt16BE" | "getUint16LE" | "getUint16BE"
+            | "getInt32LE" | "getInt32BE" | "getFloat64LE" | "getFloat64BE" => Some(
+                self.type_ctx
+                    .function_type(vec![number_ty], number_ty, false),
+            ),
+            // ...and their setters, e.g. setInt32BE(index: number, value: number) -> void
+            "setUint8" | "setInt16LE" | "setInt16BE" | "setUint16LE" | "setUint16BE"
+            | "setInt32LE" | "setInt32BE" | "setFloat64LE" | "setFloat64BE" => Some(
+                self.type_ctx
+                    .function_type(vec![number_ty, number_ty], void_ty, false),
+            ),
             // slice(start: number, end?: number) -> Buffer
             "slice" => Some(self.type_ctx.function_type_with_min_params(
                 vec![number_ty, number_ty],
@@ -6992,19 +7691,20 @@ impl<'a> TypeChecker<'a> {
             }
         }
 
-        // Check const reassignment for simple identifiers
-        if let Expression::Identifier(ident) = &*assign.left {
-            let name = self.resolve(ident.name);
-            if let Some(symbol) = self.symbols.resolve_from_scope(&name, self.current_scope) {
-                if symbol.flags.is_const {
-                    self.errors.push(CheckError::ConstReassignment {
-                        name: name.clone(),
-                        span: assign.span,
-                    });
-                }
+        // Element assignment through a `readonly` array or tuple
+        if let Expression::Index(index) = &*assign.left {
+            let object_ty = self.check_expr(&index.object);
+            if self.is_readonly_array_like(object_ty) {
+                self.errors.push(CheckError::ReadonlyArrayMutation {
+                    ty: self.type_ctx.format_type(object_ty),
+                    operation: "element assignment".to_string(),
+                    span: index.span,
+                });
             }
         }
 
+        self.check_const_reassignment(&assign.left, assign.span);
+
         // For simple identifier assignments, use the declared type (not narrowed)
         // so that reassignment back to the original wider type is allowed.
         // e.g., inside `while (val != null)`, `val = ch.tryReceive()` should work
@@ -7028,6 +7728,7 @@ impl<'a> TypeChecker<'a> {
         // Evaluate RHS before clearing narrowing so `current = current.next`
         // can use the narrowed type of `current` when evaluating `current.next`.
         let right_ty = self.check_expr(&assign.right);
+        self.report_inferred_unknown(&assign.right, right_ty, "assigned value");
 
         if let Expression::Member(member) = &*assign.left {
             let field_name = self.resolve(member.property.name);
@@ -7144,8 +7845,20 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Check if `ty` is a `readonly` array or tuple
+    fn is_readonly_array_like(&self, ty: TypeId) -> bool {
+        match self.type_ctx.get(ty) {
+            Some(crate::parser::types::Type::Array(arr)) => arr.readonly,
+            Some(crate::parser::types::Type::Tuple(tuple)) => tuple.readonly,
+            _ => false,
+        }
+    }
+
     /// Check if a property is readonly on a given type
     fn is_readonly_property(&self, ty: TypeId, property_name: &str) -> bool {
+        if property_name == "length" && self.is_readonly_array_like(ty) {
+            return true;
+        }
         if let Some(resolved) = self.type_ctx.get(ty) {
             match resolved {
                 crate::parser::types::Type::Class(class) => {
@@ -7353,6 +8066,32 @@ impl<'a> TypeChecker<'a> {
                         "type-reference-record-arity",
                     );
                 }
+                // Built-in utility types (Partial<T>, Pick<T, K>, ...) unless shadowed.
+                if let Some(kind) = UtilityType::from_name(&name).filter(|_| {
+                    self.symbols
+                        .resolve_from_scope(&name, self.current_scope)
+                        .is_none()
+                }) {
+                    let type_args = type_ref.type_args.as_deref().unwrap_or(&[]);
+                    if type_args.len() != kind.arity() {
+                        self.errors.push(CheckError::InvalidTypeReferenceArity {
+                            name: name.clone(),
+                            expected: kind.arity(),
+                            actual: type_args.len(),
+                            span: type_ref.name.span,
+                        });
+                        return self.fallback_type(
+                            type_ref.name.span,
+                            FallbackReason::RecoverableUnsupportedExpr,
+                            "type-reference-utility-arity",
+                        );
+                    }
+                    let resolved_args: Vec<TypeId> = type_args
+                        .iter()
+                        .map(|arg| self.resolve_type_annotation(arg))
+                        .collect();
+                    return apply_utility_type(self.type_ctx, kind, &resolved_args);
+                }
                 // Note: Date and Buffer are now normal classes, looked up from symbol table
 
                 // Check method-level type parameters (e.g. K, U from generic methods)
@@ -7413,7 +8152,11 @@ impl<'a> TypeChecker<'a> {
 
             AstType::Array(arr) => {
                 let elem_ty = self.resolve_type_annotation(&arr.element_type);
-                self.type_ctx.array_type(elem_ty)
+                if arr.readonly {
+                    self.type_ctx.readonly_array_type(elem_ty)
+                } else {
+                    self.type_ctx.array_type(elem_ty)
+                }
             }
 
             AstType::Tuple(tuple) => {
@@ -7422,7 +8165,11 @@ impl<'a> TypeChecker<'a> {
                     .iter()
                     .map(|e| self.resolve_type_annotation(e))
                     .collect();
-                self.type_ctx.tuple_type(elem_tys)
+                if tuple.readonly {
+                    self.type_ctx.readonly_tuple_type(elem_tys)
+                } else {
+                    self.type_ctx.tuple_type(elem_tys)
+                }
             }
 
             AstType::Union(union) => {
@@ -8330,6 +9077,366 @@ mod tests {
         checker.exit_scope();
     }
 
+    fn parse_and_check_with_builtins(
+        source: &str,
+        builtins: &[super::super::builtins::BuiltinSignatures],
+    ) -> Result<(), Vec<CheckError>> {
+        let parser = Parser::new(source).unwrap();
+        let (module, interner) = parser.parse().unwrap();
+
+        let mut type_ctx = TypeContext::new();
+        let mut binder = Binder::new(&mut type_ctx, &interner);
+        binder.register_builtins(builtins);
+        let symbols = binder.bind_module(&module).unwrap();
+
+        let checker = TypeChecker::new(&mut type_ctx, &symbols, &interner);
+        checker.check_module(&module).map(|_| ())
+    }
+
+    fn overloaded_pad_builtins() -> Vec<super::super::builtins::BuiltinSignatures> {
+        use super::super::builtins::{BuiltinClass, BuiltinFunction, BuiltinSignatures};
+        vec![BuiltinSignatures::new("pad")
+            .with_function(BuiltinFunction::new(
+                "pad",
+                vec![("width", "number")],
+                "string",
+            ))
+            .with_function(BuiltinFunction::new(
+                "pad",
+                vec![("text", "string"), ("width", "number")],
+                "number",
+            ))
+            .with_class(
+                BuiltinClass::new("Padder")
+                    .with_static_method("pad", vec![("width", "number")], "string")
+                    .with_static_method("pad", vec![("text", "string")], "boolean"),
+            )]
+    }
+
+    #[test]
+    fn test_for_of_uses_builtin_iterator_elements() {
+        let builtins = crate::builtins::checker_signatures();
+        let result = parse_and_check_with_builtins(
+            r#"
+            function total(counts: Map<string, number>, tags: Set<string>, sizes: number[]): number {
+                let sum: number = 0;
+                for (const [name, count] of counts) {
+                    const label: string = name;
+                    sum = sum + count;
+                }
+                for (const tag of tags) {
+                    const label: string = tag;
+                }
+                for (const size of sizes) {
+                    sum = sum + size;
+                }
+                return sum;
+            }
+            "#,
+            builtins,
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        for (iterable, element) in [
+            ("Map<string, number>", "[string, number]"),
+            ("Set<boolean>", "boolean"),
+            ("Array<int>", "int"),
+        ] {
+            let source = format!(
+                "function first(items: {iterable}): void {{\n\
+                 for (const item of items) {{ const wrong: string = item; }}\n\
+                 }}"
+            );
+            let errors = parse_and_check_with_builtins(&source, builtins).unwrap_err();
+            let mismatch = errors.iter().find_map(|e| match e {
+                CheckError::TypeMismatch { actual, .. } => Some(actual.as_str()),
+                _ => None,
+            });
+            assert_eq!(mismatch, Some(element), "{iterable}: {errors:?}");
+        }
+    }
+
+    #[test]
+    fn test_bigint_literal_has_builtin_type() {
+        use super::super::builtins::{BuiltinClass, BuiltinSignatures};
+        let builtins = vec![BuiltinSignatures::new("BigInt").with_class(
+            BuiltinClass::new("BigInt")
+                .with_constructor(vec![("value", "string")])
+                .with_method("add", vec![("other", "BigInt")], "BigInt"),
+        )];
+
+        let result = parse_and_check_with_builtins("let total: BigInt = 1n.add(2n);", &builtins);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let errors = parse_and_check_with_builtins("let n: number = 5n;", &builtins).unwrap_err();
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, CheckError::TypeMismatch { .. })),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn test_check_overloaded_builtin_resolves_each_signature() {
+        let result = parse_and_check_with_builtins(
+            r#"
+            let a: string = pad(4);
+            let b: number = pad("x", 4);
+            let c: string = Padder.pad(4);
+            let d: boolean = Padder.pad("x");
+            "#,
+            &overloaded_pad_builtins(),
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[test]
+    fn test_check_overloaded_builtin_without_match_lists_candidates() {
+        let errors =
+            parse_and_check_with_builtins("pad(true);", &overloaded_pad_builtins()).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CheckError::NoMatchingOverload {
+                name, candidates, ..
+            } => {
+                assert_eq!(name, "pad");
+                assert_eq!(
+                    candidates,
+                    &vec![
+                        "(number) => string".to_string(),
+                        "(string, number) => number".to_string(),
+                    ]
+                );
+            }
+            other => panic!("expected NoMatchingOverload, got {other:?}"),
+        }
+
+        let errors = parse_and_check_with_builtins("Padder.pad(1, 2);", &overloaded_pad_builtins())
+            .unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            CheckError::NoMatchingOverload { name, candidates, .. }
+                if name == "Padder.pad" && candidates.len() == 2
+        ));
+    }
+
+    #[test]
+    fn test_check_readonly_array_element_assignment_errors() {
+        let errors = parse_and_check(
+            r#"
+            let xs: readonly number[] = [1, 2, 3];
+            xs[0] = 4;
+            "#,
+        )
+        .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            CheckError::ReadonlyArrayMutation { ty, operation, .. }
+                if ty == "readonly number[]" && operation == "element assignment"
+        ));
+    }
+
+    #[test]
+    fn test_check_readonly_array_rejects_mutating_methods_but_allows_reads() {
+        let errors = parse_and_check(
+            r#"
+            let pair: readonly [number, number] = [1, 2];
+            let xs: readonly number[] = [3, 1, 2];
+            let first: number = xs[0];
+            let n: number = xs.length;
+            let copy: number[] = xs.slice(0);
+            copy.push(first + n + pair[1]);
+            xs.push(4);
+            xs.sort();
+            "#,
+        )
+        .unwrap_err();
+        let operations: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                CheckError::ReadonlyArrayMutation { operation, .. } => operation.as_str(),
+                other => panic!("expected ReadonlyArrayMutation, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            operations,
+            vec!["mutating method 'push'", "mutating method 'sort'"]
+        );
+    }
+
+    #[test]
+    fn test_check_mutable_array_is_assignable_to_readonly() {
+        let result = parse_and_check(
+            r#"
+            function total(xs: readonly number[]): number { return xs.length; }
+            let ys: number[] = [1, 2];
+            let view: readonly number[] = ys;
+            let tuple: readonly [number, string] = [1, "a"];
+            total(ys);
+            "#,
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let errors = parse_and_check(
+            r#"
+            let view: readonly number[] = [1, 2];
+            let ys: number[] = view;
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(&errors[0], CheckError::TypeMismatch { .. }));
+    }
+
+    #[test]
+    fn test_check_partial_makes_properties_optional() {
+        let result = parse_and_check(
+            r#"
+            type Config = { name: string, limits: { max: number } };
+            let empty: Partial<{ a: number }> = {};
+            let overrides: Partial<Config> = { limits: { max: 3 } };
+            "#,
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[test]
+    fn test_check_required_makes_properties_mandatory() {
+        let errors = parse_and_check(
+            r#"
+            type Options = { verbose?: boolean };
+            let opts: Required<Options> = {};
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(&errors[0], CheckError::TypeMismatch { .. }));
+    }
+
+    #[test]
+    fn test_check_pick_keeps_only_selected_keys() {
+        let result = parse_and_check(
+            r#"
+            let picked: Pick<{ a: number, b: string }, "a"> = { a: 1 };
+            let a: number = picked.a;
+            "#,
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let result = parse_and_check(
+            r#"
+            let picked: Pick<{ a: number, b: string }, "a"> = { a: 1 };
+            picked.b;
+            "#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_satisfies_keeps_inferred_type() {
+        let result = parse_and_check(
+            r#"
+            type Named = { name: string };
+            let item = { name: "widget", count: 3 } satisfies Named;
+            let count: number = item.count;
+            "#,
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[test]
+    fn test_check_satisfies_reports_nonconforming_expression() {
+        let errors = parse_and_check(
+            r#"
+            type Named = { name: string };
+            let item = { count: 3 } satisfies Named;
+            "#,
+        )
+        .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(
+            matches!(
+                &errors[0],
+                CheckError::UnsatisfiedType { expected, actual, .. }
+                    if expected.contains("name: string") && actual.contains("count: number")
+            ),
+            "{:?}",
+            errors
+        );
+    }
+
+    /// Display the checked type of the last expression statement in `source`.
+    fn last_expr_type(source: &str) -> String {
+        let parser = Parser::new(source).unwrap();
+        let (module, interner) = parser.parse().unwrap();
+
+        let mut type_ctx = TypeContext::new();
+        let binder = Binder::new(&mut type_ctx, &interner);
+        let symbols = binder.bind_module(&module).unwrap();
+
+        let expr = module
+            .statements
+            .iter()
+            .rev()
+            .find_map(|stmt| match stmt {
+                Statement::Expression(stmt) => Some(&stmt.expression),
+                _ => None,
+            })
+            .expect("expression statement");
+        let checker = TypeChecker::new(&mut type_ctx, &symbols, &interner);
+        let result = checker.check_module(&module).unwrap();
+        let ty = result.expr_types[&(expr as *const _ as usize)];
+        type_ctx.format_type(ty)
+    }
+
+    #[test]
+    fn test_check_const_assertion_keeps_string_literal() {
+        assert_eq!(last_expr_type(r#""a" as const;"#), r#""a""#);
+        assert_eq!(last_expr_type(r#""a";"#), "string");
+    }
+
+    #[test]
+    fn test_check_const_assertion_array_is_readonly_tuple() {
+        assert_eq!(last_expr_type("[1, 2] as const;"), "readonly [1, 2]");
+
+        let errors = parse_and_check(
+            r#"
+            const pair = [1, 2] as const;
+            pair.push(3);
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            CheckError::ReadonlyArrayMutation { operation, .. }
+                if operation == "mutating method 'push'"
+        ));
+    }
+
+    #[test]
+    fn test_check_const_assertion_object_properties_are_readonly_literals() {
+        let result = parse_and_check(
+            r#"
+            const shape = { kind: "circle", radius: 2 } as const;
+            let kind: "circle" = shape.kind;
+            let radius: 2 = shape.radius;
+            "#,
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let errors = parse_and_check(
+            r#"
+            const shape = { kind: "circle" } as const;
+            shape.kind = "square";
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            CheckError::ReadonlyAssignment { property, .. } if property == "kind"
+        ));
+    }
+
     #[test]
     fn test_check_simple_arithmetic() {
         let result = parse_and_check("1 + 2;");
@@ -8345,6 +9452,106 @@ mod tests {
         assert!(matches!(errors[0], CheckError::UndefinedVariable { .. }));
     }
 
+    #[test]
+    fn test_undefined_variable_suggests_close_name() {
+        let errors = parse_and_check("let length = 1;\nlenght;").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CheckError::UndefinedVariable { suggestion, .. } => {
+                assert_eq!(suggestion.as_deref(), Some("length"));
+            }
+            other => panic!("expected UndefinedVariable, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_undefined_variable_without_close_name() {
+        let errors = parse_and_check("let length = 1;\nwidth;").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CheckError::UndefinedVariable { suggestion, .. } => assert_eq!(*suggestion, None),
+            other => panic!("expected UndefinedVariable, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_switch_over_union_lists_missing_variants() {
+        let errors = parse_and_check(
+            r#"
+            type Shape =
+                | { kind: "circle"; radius: number }
+                | { kind: "square"; side: number }
+                | { kind: "triangle"; base: number };
+            function describe(s: Shape): void {
+                switch (s.kind) {
+                    case "circle": break;
+                    case "square": break;
+                }
+            }
+        "#,
+        )
+        .unwrap_err();
+        let missing: Vec<&Vec<String>> = errors
+            .iter()
+            .filter_map(|e| match e {
+                CheckError::NonExhaustiveMatch { missing, .. } => Some(missing),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(missing, vec![&vec!["triangle".to_string()]]);
+    }
+
+    #[test]
+    fn test_switch_over_union_with_default_is_exhaustive() {
+        let result = parse_and_check(
+            r#"
+            type Shape =
+                | { kind: "circle"; radius: number }
+                | { kind: "square"; side: number }
+                | { kind: "triangle"; base: number };
+            function describe(s: Shape): void {
+                switch (s.kind) {
+                    case "circle": break;
+                    default: break;
+                }
+            }
+        "#,
+        );
+        assert!(result.is_ok(), "unexpected errors: {:?}", result.err());
+    }
+
+    #[test]
+    fn test_const_binding_cannot_be_reassigned() {
+        for source in [
+            "const total: number = 1;\ntotal = 2;",
+            "const total: number = 1;\ntotal += 2;",
+            "const total: number = 1;\ntotal++;",
+        ] {
+            let errors = parse_and_check(source).unwrap_err();
+            assert!(
+                matches!(
+                    errors.as_slice(),
+                    [CheckError::ConstReassignment { name, .. }] if name == "total"
+                ),
+                "{source:?}: expected ConstReassignment, got {errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_const_binding_allows_field_mutation() {
+        let result = parse_and_check(
+            r#"
+            class Counter { value: number = 0; }
+            const counter = new Counter();
+            counter.value = counter.value + 1;
+            const values: number[] = [1, 2];
+            values[0] = 3;
+        "#,
+        );
+        assert!(result.is_ok(), "Expected ok, got {:?}", result);
+    }
+
     #[test]
     fn test_check_type_mismatch() {
         let result = parse_and_check(r#"let x: number = "hello";"#);
@@ -8569,6 +9776,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_early_return_narrows_rest_of_body() {
+        let result = parse_and_check(
+            r#"
+            class TcpStream {}
+
+            function useStream(stream: TcpStream): void {}
+
+            function serve(stream: TcpStream | null): void {
+                if (stream == null) return;
+                useStream(stream);
+            }
+        "#,
+        );
+        assert!(
+            result.is_ok(),
+            "Expected stream to be non-null after early return, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_early_throw_on_falsy_narrows_rest_of_body() {
+        let result = parse_and_check(
+            r#"
+            class Error { constructor(message: string) {} }
+            class TcpStream {}
+
+            function useStream(stream: TcpStream): void {}
+
+            function serve(ok: TcpStream | null): void {
+                if (!ok) throw new Error("");
+                useStream(ok);
+            }
+        "#,
+        );
+        assert!(
+            result.is_ok(),
+            "Expected ok to be non-null after early throw, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_early_return_on_or_condition_narrows_each_operand() {
+        let result = parse_and_check(
+            r#"
+            class TcpStream {}
+
+            function pipe(from: TcpStream, to: TcpStream): void {}
+
+            function serve(a: TcpStream | null, b: TcpStream | null): void {
+                if (a == null || b == null) {
+                    return;
+                }
+                pipe(a, b);
+            }
+        "#,
+        );
+        assert!(
+            result.is_ok(),
+            "Expected both operands of || to be narrowed after early return, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_exiting_else_branch_keeps_then_narrowing() {
+        let result = parse_and_check(
+            r#"
+            class TcpStream {}
+
+            function useStream(stream: TcpStream): void {}
+
+            function serve(stream: TcpStream | null): void {
+                if (stream != null) {
+                    useStream(stream);
+                } else {
+                    return;
+                }
+                useStream(stream);
+            }
+        "#,
+        );
+        assert!(
+            result.is_ok(),
+            "Expected then-branch narrowing to survive an exiting else, got {:?}",
+            result
+        );
+    }
+
     #[test]
     fn test_task_is_cancelled_method_type_checked() {
         let result = parse_and_check(
@@ -8923,4 +10221,54 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_same_type_field_shadow_allowed() {
+        let result = parse_and_check(
+            r#"
+            class Base {
+                label: string = "base";
+            }
+            class Derived extends Base {
+                label: string = "derived";
+            }
+        "#,
+        );
+        assert!(result.is_ok(), "Expected ok, got {:?}", result);
+    }
+
+    #[test]
+    fn test_incompatible_field_shadow_reports_both_fields() {
+        let source = r#"
+            class Base {
+                count: number = 0;
+            }
+            class Derived extends Base {
+                count: string = "zero";
+            }
+        "#;
+        let errors = parse_and_check(source).unwrap_err();
+        let (span, parent_span) = errors
+            .iter()
+            .find_map(|e| match e {
+                CheckError::IncompatibleFieldShadow {
+                    field,
+                    parent,
+                    expected,
+                    actual,
+                    span,
+                    parent_span,
+                } if field == "count" && parent == "Base" => {
+                    assert_eq!(expected, "number");
+                    assert_eq!(actual, "string");
+                    Some((*span, *parent_span))
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("expected IncompatibleFieldShadow, got {:?}", errors));
+        let parent_span = parent_span.expect("parent field location");
+        assert_eq!(&source[span.start..span.end], "count");
+        assert_eq!(&source[parent_span.start..parent_span.end], "count");
+        assert!(parent_span.line < span.line);
+    }
 }
@@ -137,10 +137,18 @@ impl Diagnostic {
                 diag
             }
 
-            UndefinedVariable { name, span } => {
-                Diagnostic::error(format!("Cannot find name '{}'", name))
+            UndefinedVariable {
+                name,
+                span,
+                suggestion,
+            } => {
+                let diag = Diagnostic::error(format!("Cannot find name '{}'", name))
                     .with_code(error_code(error))
-                    .with_primary_label(file_id, *span, "not found in this scope")
+                    .with_primary_label(file_id, *span, "not found in this scope");
+                match suggestion {
+                    Some(suggestion) => diag.with_help(format!("did you mean '{}'?", suggestion)),
+                    None => diag,
+                }
             }
 
             NotCallable { ty, span } => Diagnostic::error(format!("Type '{}' is not callable", ty))
@@ -171,6 +179,21 @@ impl Diagnostic {
                     .with_primary_label(file_id, *span, "incorrect number of arguments")
             }
 
+            NoMatchingOverload {
+                name,
+                candidates,
+                span,
+            } => {
+                let mut diag =
+                    Diagnostic::error(format!("No overload of '{}' matches this call", name))
+                        .with_code(error_code(error))
+                        .with_primary_label(file_id, *span, "no matching overload");
+                for candidate in candidates {
+                    diag = diag.with_note(format!("candidate: {}{}", name, candidate));
+                }
+                diag
+            }
+
             PropertyNotFound { property, ty, span } => Diagnostic::error(format!(
                 "Property '{}' does not exist on type '{}'",
                 property, ty
@@ -285,6 +308,28 @@ impl Diagnostic {
                     .with_help("Readonly properties can only be assigned in the constructor")
             }
 
+            UnsatisfiedType {
+                expected,
+                actual,
+                span,
+            } => Diagnostic::error(format!("Type '{}' does not satisfy '{}'", actual, expected))
+                .with_code(error_code(error))
+                .with_primary_label(
+                    file_id,
+                    *span,
+                    format!("expected to satisfy '{}'", expected),
+                ),
+
+            ReadonlyArrayMutation {
+                ty,
+                operation,
+                span,
+            } => Diagnostic::error(format!("Cannot mutate readonly type '{}'", ty))
+                .with_code(error_code(error))
+                .with_primary_label(file_id, *span, format!("{} is not allowed", operation))
+                .with_note("`readonly` is enforced at compile time only")
+                .with_help("Copy it with `slice()` to get a mutable array"),
+
             ConstReassignment { name, span } => {
                 Diagnostic::error(format!("Cannot assign to const variable '{}'", name))
                     .with_code(error_code(error))
@@ -358,6 +403,33 @@ impl Diagnostic {
             .with_code(error_code(error))
             .with_primary_label(file_id, *span, "missing property initialization")
             .with_help("Add an initializer or assign this property in every constructor path"),
+            IncompatibleFieldShadow {
+                field,
+                parent,
+                expected,
+                actual,
+                span,
+                parent_span,
+            } => {
+                let diag = Diagnostic::error(format!(
+                    "Field '{}' of type '{}' is incompatible with inherited '{}.{}' of type '{}'",
+                    field, actual, parent, field, expected
+                ))
+                .with_code(error_code(error))
+                .with_primary_label(file_id, *span, format!("redeclared as '{}'", actual))
+                .with_help(format!(
+                    "A redeclared field reuses the parent's slot; declare it as '{}' (or a subtype) or rename it",
+                    expected
+                ));
+                match parent_span {
+                    Some(parent_span) => diag.with_secondary_label(
+                        file_id,
+                        *parent_span,
+                        format!("declared as '{}' here", expected),
+                    ),
+                    None => diag,
+                }
+            }
             UnboundMethodCall { name, span } => Diagnostic::error(format!(
                 "Method value '{}' must be explicitly bound before calling",
                 name
@@ -457,6 +529,14 @@ impl Diagnostic {
                     .with_code(ErrorCode("E3017"))
                     .with_primary_label(file_id, *span, "invalid rest parameter")
             }
+
+            DuplicateDefaultExport {
+                original,
+                duplicate,
+            } => Diagnostic::error("A module cannot have more than one default export")
+                .with_code(ErrorCode("E3007"))
+                .with_primary_label(file_id, *duplicate, "duplicate default export")
+                .with_secondary_label(file_id, *original, "first default export here"),
         }
     }
 
@@ -470,6 +550,13 @@ impl Diagnostic {
                     .with_help(format!("Prefix with _ to suppress: _{}", name))
             }
 
+            CheckWarning::UnusedImport { name, span } => {
+                Diagnostic::warning(format!("'{}' is imported but never used", name))
+                    .with_code(ErrorCode(warning.code().as_str()))
+                    .with_primary_label(file_id, *span, "unused import")
+                    .with_help("Remove the import, or run `raya lint --fix`")
+            }
+
             CheckWarning::UnreachableCode { span } => {
                 Diagnostic::warning("Unreachable code detected")
                     .with_code(ErrorCode(warning.code().as_str()))
@@ -495,6 +582,45 @@ impl Diagnostic {
             .with_code(ErrorCode(warning.code().as_str()))
             .with_primary_label(file_id, *span, "unsupported tsconfig flag")
             .with_help("Flag parsed successfully, but behavior is not implemented yet"),
+
+            CheckWarning::Deprecated {
+                name,
+                message,
+                span,
+                declaration,
+            } => {
+                let title = if message.is_empty() {
+                    format!("'{}' is deprecated", name)
+                } else {
+                    format!("'{}' is deprecated: {}", name, message)
+                };
+                Diagnostic::warning(title)
+                    .with_code(ErrorCode(warning.code().as_str()))
+                    .with_primary_label(file_id, *span, "deprecated API used here")
+                    .with_secondary_label(file_id, *declaration, "marked deprecated here")
+            }
+
+            CheckWarning::InferredUnknown { ty, position, span } => {
+                Diagnostic::warning(format!("{} has inferred type '{}'", position, ty))
+                    .with_code(ErrorCode(warning.code().as_str()))
+                    .with_primary_label(file_id, *span, "type inference gave up here")
+                    .with_help("Add a type annotation, or cast with `as unknown` if intended")
+            }
+
+            CheckWarning::SuspiciousEquality {
+                left,
+                right,
+                always_true,
+                span,
+            } => Diagnostic::warning(format!(
+                "This comparison is always {}: '{}' and '{}' are never equal",
+                if *always_true { "true" } else { "false" },
+                left,
+                right
+            ))
+            .with_code(ErrorCode(warning.code().as_str()))
+            .with_primary_label(file_id, *span, "operands have disjoint types")
+            .with_help("Equality does not coerce; convert explicitly with String(x) or Number(x)"),
         }
     }
 
@@ -646,6 +772,10 @@ pub fn error_code(error: &CheckError) -> ErrorCode {
         InvalidIntrinsicInferenceContext { .. } => ErrorCode("E2028"),
         InvalidTypeReferenceArity { .. } => ErrorCode("E2029"),
         UnsupportedExpressionTypingPath { .. } => ErrorCode("E2030"),
+        NoMatchingOverload { .. } => ErrorCode("E2031"),
+        ReadonlyArrayMutation { .. } => ErrorCode("E2032"),
+        UnsatisfiedType { .. } => ErrorCode("E2033"),
+        IncompatibleFieldShadow { .. } => ErrorCode("E2034"),
         // Decorator errors
         InvalidDecorator { .. } => ErrorCode("E2100"),
         DecoratorSignatureMismatch { .. } => ErrorCode("E2101"),
@@ -707,6 +837,29 @@ mod tests {
         assert!(diag.inner.message.contains("$type"));
     }
 
+    #[test]
+    fn test_from_check_error_no_matching_overload_lists_candidates() {
+        let error = CheckError::NoMatchingOverload {
+            name: "pad".to_string(),
+            candidates: vec![
+                "(number) => string".to_string(),
+                "(string, number) => string".to_string(),
+            ],
+            span: Span::new(0, 9, 1, 1),
+        };
+
+        let diag = Diagnostic::from_check_error(&error, 0);
+        assert_eq!(diag.code, Some(ErrorCode("E2031")));
+        assert!(diag.inner.message.contains("pad"));
+        assert_eq!(
+            diag.inner.notes,
+            vec![
+                "candidate: pad(number) => string".to_string(),
+                "candidate: pad(string, number) => string".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn test_json_output() {
         let error = CheckError::TypeMismatch {
@@ -734,6 +887,7 @@ mod tests {
         let error = CheckError::UndefinedVariable {
             name: "foo".to_string(),
             span: Span::new(5, 8, 1, 5),
+            suggestion: None,
         };
 
         let diag = Diagnostic::from_check_error(&error, 0);
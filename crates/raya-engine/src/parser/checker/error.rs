@@ -77,6 +77,15 @@ pub enum BindError {
         /// Location of the invalid rest parameter
         span: Span,
     },
+
+    /// Second `export default` in the same module
+    #[error("A module cannot have more than one default export")]
+    DuplicateDefaultExport {
+        /// Location of the first default export
+        original: Span,
+        /// Location of the duplicate default export
+        duplicate: Span,
+    },
 }
 
 /// Errors that can occur during type checking
@@ -102,6 +111,8 @@ pub enum CheckError {
         name: String,
         /// Location where variable was referenced
         span: Span,
+        /// Closest visible name, offered as a "did you mean" hint
+        suggestion: Option<String>,
     },
 
     /// Attempting to call a non-function type
@@ -126,6 +137,17 @@ pub enum CheckError {
         span: Span,
     },
 
+    /// No signature of an overloaded builtin accepts the call arguments
+    #[error("NoMatchingOverload: no overload of '{name}' matches the call ({} candidates)", candidates.len())]
+    NoMatchingOverload {
+        /// Callee name (e.g., "parse", "Buffer.from")
+        name: String,
+        /// Candidate signatures, in declaration order
+        candidates: Vec<String>,
+        /// Location of call expression
+        span: Span,
+    },
+
     /// Non-exhaustive match/switch expression
     #[error("Non-exhaustive match: missing cases {}", missing.join(", "))]
     NonExhaustiveMatch {
@@ -258,6 +280,28 @@ pub enum CheckError {
         span: Span,
     },
 
+    /// Expression does not satisfy the type named by `satisfies`
+    #[error("UnsatisfiedType: type '{actual}' does not satisfy '{expected}'")]
+    UnsatisfiedType {
+        /// Type named after `satisfies`
+        expected: String,
+        /// Inferred type of the expression
+        actual: String,
+        /// Location of the satisfies expression
+        span: Span,
+    },
+
+    /// Mutation of a `readonly` array or tuple
+    #[error("ReadonlyArrayMutation: {operation} is not allowed on readonly type '{ty}'")]
+    ReadonlyArrayMutation {
+        /// Readonly array or tuple type
+        ty: String,
+        /// Rejected operation (e.g., "mutating method 'push'")
+        operation: String,
+        /// Location of the mutation
+        span: Span,
+    },
+
     /// Cannot assign to const variable
     #[error("ConstReassignment: cannot assign to const variable '{name}'")]
     ConstReassignment {
@@ -357,6 +401,24 @@ pub enum CheckError {
         span: Span,
     },
 
+    /// Subclass field redeclares an inherited field with a type the parent's
+    /// slot can't hold.
+    #[error("E_INCOMPATIBLE_FIELD_SHADOW: field '{field}' of type '{actual}' shadows '{parent}.{field}' of type '{expected}'")]
+    IncompatibleFieldShadow {
+        /// Field name
+        field: String,
+        /// Class that declares the shadowed field
+        parent: String,
+        /// Type of the shadowed field
+        expected: String,
+        /// Type of the redeclared field
+        actual: String,
+        /// Location of the redeclared field
+        span: Span,
+        /// Location of the shadowed field, when declared in the same module
+        parent_span: Option<Span>,
+    },
+
     /// Calling an extracted method without explicit binding.
     #[error(
         "E_METHOD_BIND_REQUIRED: method value '{name}' must be explicitly bound before calling"
@@ -413,6 +475,7 @@ impl CheckError {
             CheckError::UndefinedVariable { span, .. } => *span,
             CheckError::NotCallable { span, .. } => *span,
             CheckError::ArgumentCountMismatch { span, .. } => *span,
+            CheckError::NoMatchingOverload { span, .. } => *span,
             CheckError::NonExhaustiveMatch { span, .. } => *span,
             CheckError::PropertyNotFound { span, .. } => *span,
             CheckError::ReturnTypeMismatch { span, .. } => *span,
@@ -427,6 +490,8 @@ impl CheckError {
             CheckError::AbstractClassInstantiation { span, .. } => *span,
             CheckError::UndefinedMember { span, .. } => *span,
             CheckError::ReadonlyAssignment { span, .. } => *span,
+            CheckError::ReadonlyArrayMutation { span, .. } => *span,
+            CheckError::UnsatisfiedType { span, .. } => *span,
             CheckError::ConstReassignment { span, .. } => *span,
             CheckError::NewNonClass { span, .. } => *span,
             CheckError::StrictAnyForbidden { span } => *span,
@@ -438,6 +503,7 @@ impl CheckError {
             CheckError::InvalidTypeReferenceArity { span, .. } => *span,
             CheckError::UnsupportedExpressionTypingPath { span, .. } => *span,
             CheckError::StrictPropertyInitialization { span, .. } => *span,
+            CheckError::IncompatibleFieldShadow { span, .. } => *span,
             CheckError::UnboundMethodCall { span, .. } => *span,
             CheckError::InvalidDecorator { span, .. } => *span,
             CheckError::DecoratorSignatureMismatch { span, .. } => *span,
@@ -457,6 +523,7 @@ impl BindError {
             BindError::InvalidTypeArguments { span, .. } => *span,
             BindError::RequiredAfterOptional { span, .. } => *span,
             BindError::InvalidRestParameter { span, .. } => *span,
+            BindError::DuplicateDefaultExport { duplicate, .. } => *duplicate,
         }
     }
 }
@@ -480,6 +547,12 @@ pub enum WarningCode {
     ShadowedVariable,
     /// Unsupported tsconfig compiler option currently ignored (W1006)
     UnsupportedTsFlag,
+    /// Use of an API marked `//@@deprecated` (W1007)
+    Deprecated,
+    /// Inferred `unknown`/`any` escaping into a value position (W1008)
+    InferredUnknown,
+    /// Equality comparison between primitives that can never be equal (W1009)
+    SuspiciousEquality,
 }
 
 impl WarningCode {
@@ -492,6 +565,9 @@ impl WarningCode {
             WarningCode::UnreachableCode => "W1004",
             WarningCode::ShadowedVariable => "W1005",
             WarningCode::UnsupportedTsFlag => "W1006",
+            WarningCode::Deprecated => "W1007",
+            WarningCode::InferredUnknown => "W1008",
+            WarningCode::SuspiciousEquality => "W1009",
         }
     }
 
@@ -504,6 +580,9 @@ impl WarningCode {
             "unreachable-code" => Some(WarningCode::UnreachableCode),
             "shadowed-variable" => Some(WarningCode::ShadowedVariable),
             "unsupported-ts-flag" => Some(WarningCode::UnsupportedTsFlag),
+            "deprecated" => Some(WarningCode::Deprecated),
+            "inferred-unknown" => Some(WarningCode::InferredUnknown),
+            "suspicious-equality" => Some(WarningCode::SuspiciousEquality),
             _ => None,
         }
     }
@@ -520,6 +599,14 @@ pub enum CheckWarning {
         span: Span,
     },
 
+    /// Imported name (or whole imported module) never referenced
+    UnusedImport {
+        /// Local name bound by the import
+        name: String,
+        /// Location of the imported name
+        span: Span,
+    },
+
     /// Code after return/throw/break/continue that will never execute
     UnreachableCode {
         /// Location of unreachable code
@@ -542,6 +629,39 @@ pub enum CheckWarning {
         /// Anchor span in the user's source file
         span: Span,
     },
+    /// Use of a function, class or method annotated with `//@@deprecated`
+    Deprecated {
+        /// Name of the deprecated declaration
+        name: String,
+        /// Message given in the annotation (may be empty)
+        message: String,
+        /// Location of the use
+        span: Span,
+        /// Location of the deprecated declaration
+        declaration: Span,
+    },
+    /// Expression whose `unknown`/`any` type came from inference rather than an
+    /// annotation (only reported with `strict_unknown`)
+    InferredUnknown {
+        /// Inferred type (`unknown` or `any`)
+        ty: String,
+        /// Where the value flows: argument, return value or assigned value
+        position: String,
+        /// Location of the expression
+        span: Span,
+    },
+    /// `==`/`!=` (or `===`/`!==`) between primitives of disjoint types, which
+    /// Raya never coerces, so the result is constant
+    SuspiciousEquality {
+        /// Type of the left operand
+        left: String,
+        /// Type of the right operand
+        right: String,
+        /// Whether the comparison is always true (`!=`) rather than always false
+        always_true: bool,
+        /// Location of the comparison
+        span: Span,
+    },
 }
 
 impl CheckWarning {
@@ -549,9 +669,13 @@ impl CheckWarning {
     pub fn span(&self) -> Span {
         match self {
             CheckWarning::UnusedVariable { span, .. } => *span,
+            CheckWarning::UnusedImport { span, .. } => *span,
             CheckWarning::UnreachableCode { span } => *span,
             CheckWarning::ShadowedVariable { shadow, .. } => *shadow,
             CheckWarning::UnsupportedTsFlag { span, .. } => *span,
+            CheckWarning::Deprecated { span, .. } => *span,
+            CheckWarning::InferredUnknown { span, .. } => *span,
+            CheckWarning::SuspiciousEquality { span, .. } => *span,
         }
     }
 
@@ -559,9 +683,13 @@ impl CheckWarning {
     pub fn code(&self) -> WarningCode {
         match self {
             CheckWarning::UnusedVariable { .. } => WarningCode::UnusedVariable,
+            CheckWarning::UnusedImport { .. } => WarningCode::UnusedImport,
             CheckWarning::UnreachableCode { .. } => WarningCode::UnreachableCode,
             CheckWarning::ShadowedVariable { .. } => WarningCode::ShadowedVariable,
             CheckWarning::UnsupportedTsFlag { .. } => WarningCode::UnsupportedTsFlag,
+            CheckWarning::Deprecated { .. } => WarningCode::Deprecated,
+            CheckWarning::InferredUnknown { .. } => WarningCode::InferredUnknown,
+            CheckWarning::SuspiciousEquality { .. } => WarningCode::SuspiciousEquality,
         }
     }
 }
@@ -610,6 +738,9 @@ mod tests {
         assert_eq!(WarningCode::UnusedParameter.as_str(), "W1003");
         assert_eq!(WarningCode::UnreachableCode.as_str(), "W1004");
         assert_eq!(WarningCode::ShadowedVariable.as_str(), "W1005");
+        assert_eq!(WarningCode::Deprecated.as_str(), "W1007");
+        assert_eq!(WarningCode::InferredUnknown.as_str(), "W1008");
+        assert_eq!(WarningCode::SuspiciousEquality.as_str(), "W1009");
     }
 
     #[test]
@@ -634,6 +765,18 @@ mod tests {
             WarningCode::from_name("shadowed-variable"),
             Some(WarningCode::ShadowedVariable)
         );
+        assert_eq!(
+            WarningCode::from_name("deprecated"),
+            Some(WarningCode::Deprecated)
+        );
+        assert_eq!(
+            WarningCode::from_name("inferred-unknown"),
+            Some(WarningCode::InferredUnknown)
+        );
+        assert_eq!(
+            WarningCode::from_name("suspicious-equality"),
+            Some(WarningCode::SuspiciousEquality)
+        );
         assert_eq!(WarningCode::from_name("unknown"), None);
         assert_eq!(WarningCode::from_name(""), None);
     }
@@ -161,8 +161,10 @@ pub fn check_typeof_exhaustiveness(
 
 /// Extract all variant values from a discriminated union type
 ///
-/// Returns None if the type is not a discriminated union.
-fn extract_union_variants(ctx: &TypeContext, ty: TypeId) -> Option<HashSet<String>> {
+/// Variants are returned in declaration order so diagnostics list missing
+/// cases the way the union spells them. Returns None if the type is not a
+/// discriminated union.
+fn extract_union_variants(ctx: &TypeContext, ty: TypeId) -> Option<Vec<String>> {
     let type_def = ctx.get(ty)?;
 
     match type_def {
@@ -171,13 +173,17 @@ fn extract_union_variants(ctx: &TypeContext, ty: TypeId) -> Option<HashSet<Strin
 
             // Use the value_map from discriminant inference
             // The value_map contains all discriminant values that exist in the union
-            let variants: HashSet<String> = discriminant.value_map.keys().cloned().collect();
-
+            let mut variants: Vec<(&String, usize)> = discriminant
+                .value_map
+                .iter()
+                .map(|(value, &index)| (value, index))
+                .collect();
             if variants.is_empty() {
-                None
-            } else {
-                Some(variants)
+                return None;
             }
+            variants.sort_by(|a, b| (a.1, a.0).cmp(&(b.1, b.0)));
+
+            Some(variants.into_iter().map(|(v, _)| v.clone()).collect())
         }
         _ => None,
     }
@@ -219,10 +225,12 @@ fn has_default_case(cases: &[SwitchCase]) -> bool {
 }
 
 /// Extract all tested variants from switch cases
+///
+/// Cases with a `when` guard may not match, so they don't cover their variant.
 fn extract_tested_variants(cases: &[SwitchCase], interner: &Interner) -> HashSet<String> {
     let mut variants = HashSet::new();
 
-    for case in cases {
+    for case in cases.iter().filter(|case| case.guard.is_none()) {
         if let Some(ref test) = case.test {
             if let Some(variant) = extract_variant_from_expression(test, interner) {
                 variants.insert(variant);
@@ -299,6 +307,23 @@ mod tests {
         assert!(variants.contains("error"));
     }
 
+    #[test]
+    fn test_guarded_case_does_not_cover_variant() {
+        let (switch_stmt, interner) = parse_switch(
+            r#"
+            switch (x) {
+                case "ok" when retries > 0: break;
+                case "error": break;
+            }
+        "#,
+        );
+
+        let variants = extract_tested_variants(&switch_stmt.cases, &interner);
+        assert_eq!(variants.len(), 1);
+        assert!(!variants.contains("ok"));
+        assert!(variants.contains("error"));
+    }
+
     #[test]
     fn test_extract_variant_from_string_literal() {
         let parser = Parser::new(r#""test_variant""#).unwrap();
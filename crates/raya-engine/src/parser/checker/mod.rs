@@ -20,6 +20,7 @@ pub mod exhaustiveness;
 pub mod narrowing;
 pub mod symbols;
 pub mod type_guards;
+pub mod unused_imports;
 
 /// Type system behavior mode for checker/binder.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -106,6 +106,17 @@ impl Scope {
     }
 }
 
+/// One source occurrence of a symbol (its declaration or a use)
+#[derive(Debug, Clone)]
+struct SymbolOccurrence {
+    /// Span of the identifier
+    span: Span,
+    /// Scope the referenced symbol is defined in
+    scope_id: ScopeId,
+    /// Name of the referenced symbol
+    name: String,
+}
+
 /// Symbol table with scope tree
 ///
 /// Manages scopes and symbols for name resolution during type checking.
@@ -116,6 +127,12 @@ pub struct SymbolTable {
     current_scope: ScopeId,
     /// Generic type alias parameter order by alias name.
     generic_type_alias_params: FxHashMap<String, Vec<String>>,
+    /// Builtin function signatures by name, in declaration order.
+    builtin_overloads: FxHashMap<String, Vec<TypeId>>,
+    /// `for-of` element types of builtin classes: (class type params, element type).
+    builtin_iterator_elements: FxHashMap<String, (Vec<String>, TypeId)>,
+    /// Every declaration and identifier use seen during binding, in source order.
+    occurrences: Vec<SymbolOccurrence>,
 }
 
 impl SymbolTable {
@@ -127,6 +144,9 @@ impl SymbolTable {
             scopes: vec![global_scope],
             current_scope: ScopeId(0),
             generic_type_alias_params: FxHashMap::default(),
+            builtin_overloads: FxHashMap::default(),
+            builtin_iterator_elements: FxHashMap::default(),
+            occurrences: Vec::new(),
         }
     }
 
@@ -159,6 +179,46 @@ impl SymbolTable {
         self.generic_type_alias_params.get(name).map(Vec::as_slice)
     }
 
+    /// Record one signature of a builtin function.
+    ///
+    /// Builtins declared more than once under the same name form an overload set.
+    pub fn add_builtin_overload(&mut self, name: &str, ty: TypeId) {
+        let sigs = self.builtin_overloads.entry(name.to_string()).or_default();
+        if !sigs.contains(&ty) {
+            sigs.push(ty);
+        }
+    }
+
+    /// Look up the overload set of a builtin function.
+    ///
+    /// Returns `None` unless the builtin has more than one signature.
+    pub fn builtin_overloads(&self, name: &str) -> Option<&[TypeId]> {
+        self.builtin_overloads
+            .get(name)
+            .filter(|sigs| sigs.len() > 1)
+            .map(Vec::as_slice)
+    }
+
+    /// Record the type a builtin class yields when iterated with `for-of`.
+    ///
+    /// `element` may mention the class's `type_params` as type variables.
+    pub fn set_builtin_iterator_element(
+        &mut self,
+        name: &str,
+        type_params: Vec<String>,
+        element: TypeId,
+    ) {
+        self.builtin_iterator_elements
+            .insert(name.to_string(), (type_params, element));
+    }
+
+    /// Look up the `for-of` element type of a builtin class.
+    pub fn builtin_iterator_element(&self, name: &str) -> Option<(&[String], TypeId)> {
+        self.builtin_iterator_elements
+            .get(name)
+            .map(|(params, element)| (params.as_slice(), *element))
+    }
+
     /// Pop the current scope, returning to its parent
     ///
     /// Does nothing if already at global scope.
@@ -234,6 +294,15 @@ impl SymbolTable {
         // Set the scope ID
         symbol.scope_id = scope_id;
 
+        // Builtins and synthesized symbols have no source location to index
+        if !symbol.span.is_empty() {
+            self.occurrences.push(SymbolOccurrence {
+                span: symbol.span,
+                scope_id,
+                name: symbol.name.clone(),
+            });
+        }
+
         // Insert symbol
         self.scopes[scope_id.0 as usize]
             .symbols
@@ -308,6 +377,93 @@ impl SymbolTable {
         }
     }
 
+    /// Record a use of `name` at `span`, resolved from the current scope
+    ///
+    /// Uses that do not resolve to any symbol are ignored.
+    pub fn record_reference(&mut self, name: &str, span: Span) {
+        if let Some(symbol) = self.resolve_from_scope(name, self.current_scope) {
+            let occurrence = SymbolOccurrence {
+                span,
+                scope_id: symbol.scope_id,
+                name: symbol.name.clone(),
+            };
+            self.occurrences.push(occurrence);
+        }
+    }
+
+    /// Find the symbol whose declaration or use covers `span`
+    ///
+    /// When occurrences nest, the innermost one wins. This backs go-to-definition:
+    /// the returned symbol's `span` is its declaration.
+    pub fn symbol_at(&self, span: Span) -> Option<&Symbol> {
+        let occurrence = self
+            .occurrences
+            .iter()
+            .filter(|occ| occ.span.start <= span.start && span.end <= occ.span.end)
+            .min_by_key(|occ| occ.span.len())?;
+        self.scopes
+            .get(occurrence.scope_id.0 as usize)?
+            .symbols
+            .get(&occurrence.name)
+    }
+
+    /// Spans of every occurrence of `symbol`, its declaration included, in source order
+    pub fn references(&self, symbol: &Symbol) -> Vec<Span> {
+        let mut spans: Vec<Span> = self
+            .occurrences
+            .iter()
+            .filter(|occ| occ.scope_id == symbol.scope_id && occ.name == symbol.name)
+            .map(|occ| occ.span)
+            .collect();
+        spans.sort_by_key(|span| span.start);
+        spans.dedup();
+        spans
+    }
+
+    /// Find the visible name closest to `name`, for "did you mean" hints
+    ///
+    /// Walks the same scope chain as `resolve_from_scope` and returns the
+    /// name with the smallest edit distance, provided it is at most
+    /// `MAX_SUGGESTION_DISTANCE` and shorter than `name` itself (so `x` never
+    /// suggests `y`). Ties prefer the innermost scope, then alphabetical order.
+    pub fn suggest_similar_name(&self, name: &str, mut scope_id: ScopeId) -> Option<String> {
+        if (scope_id.0 as usize) >= self.scopes.len() && !self.scopes.is_empty() {
+            scope_id = ScopeId((self.scopes.len() - 1) as u32);
+        }
+        let name_len = name.chars().count();
+        let mut best: Option<(usize, &str)> = None;
+        loop {
+            let scope = self.scopes.get(scope_id.0 as usize)?;
+            let mut scope_best: Option<(usize, &str)> = None;
+            for candidate in scope.symbols.keys() {
+                if candidate == name || candidate.starts_with("__") {
+                    continue;
+                }
+                let distance = edit_distance(name, candidate);
+                if distance > MAX_SUGGESTION_DISTANCE || distance >= name_len {
+                    continue;
+                }
+                let better = match scope_best {
+                    Some((d, c)) => (distance, candidate.as_str()) < (d, c),
+                    None => true,
+                };
+                if better {
+                    scope_best = Some((distance, candidate));
+                }
+            }
+            if let Some((distance, candidate)) = scope_best {
+                if best.is_none_or(|(d, _)| distance < d) {
+                    best = Some((distance, candidate));
+                }
+            }
+
+            match scope.parent {
+                Some(parent) => scope_id = parent,
+                None => return best.map(|(_, candidate)| candidate.to_string()),
+            }
+        }
+    }
+
     /// Get the current scope
     pub fn current(&self) -> &Scope {
         &self.scopes[self.current_scope.0 as usize]
@@ -441,6 +597,25 @@ impl Default for SymbolTable {
     }
 }
 
+/// Largest edit distance at which an unknown name gets a suggestion
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Levenshtein distance between two strings, counted in chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != cb);
+            curr[j + 1] = substitute.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
 /// Error indicating a duplicate symbol definition
 #[derive(Debug, Clone)]
 pub struct DuplicateSymbolError {
@@ -668,4 +843,40 @@ mod tests {
         let err = result.unwrap_err();
         assert_eq!(err.name, "logger");
     }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("length", "length"), 0);
+        assert_eq!(edit_distance("lenght", "length"), 2);
+        assert_eq!(edit_distance("count", "counts"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_suggest_similar_name_prefers_inner_scope() {
+        let mut table = SymbolTable::new();
+        let ty = TypeContext::new().number_type();
+        let symbol = |name: &str, scope: ScopeId| Symbol {
+            name: name.to_string(),
+            kind: SymbolKind::Variable,
+            ty,
+            flags: SymbolFlags::default(),
+            scope_id: scope,
+            span: Span::new(0, 0, 0, 0),
+            referenced: false,
+        };
+        table.define(symbol("totals", ScopeId(0))).unwrap();
+        let inner = table.push_scope(ScopeKind::Block);
+        table.define(symbol("totalz", inner)).unwrap();
+
+        assert_eq!(
+            table.suggest_similar_name("total", inner).as_deref(),
+            Some("totalz")
+        );
+        assert_eq!(
+            table.suggest_similar_name("total", ScopeId(0)).as_deref(),
+            Some("totals")
+        );
+        assert_eq!(table.suggest_similar_name("x", inner), None);
+    }
 }
@@ -395,6 +395,33 @@ pub fn extract_all_type_guards(expr: &Expression, interner: &Interner) -> Vec<Ty
     vec![]
 }
 
+/// Extract the type guards that hold when a condition is false.
+///
+/// This is what the else branch, and the code after `if (cond) return;`,
+/// may assume. `!a` yields the guards of `a`, and `a || b` yields the negated
+/// guards of both sides, so `if (x == null || y == null) return;` narrows
+/// both variables afterwards.
+pub fn extract_all_falsy_type_guards(expr: &Expression, interner: &Interner) -> Vec<TypeGuard> {
+    if let Expression::Unary(unary) = expr {
+        if matches!(unary.operator, UnaryOperator::Not) {
+            return extract_all_type_guards(&unary.operand, interner);
+        }
+    }
+
+    if let Expression::Logical(logical) = expr {
+        if matches!(logical.operator, LogicalOperator::Or) {
+            let mut guards = extract_all_falsy_type_guards(&logical.left, interner);
+            guards.extend(extract_all_falsy_type_guards(&logical.right, interner));
+            return guards;
+        }
+    }
+
+    extract_type_guard(expr, interner)
+        .or_else(|| extract_call_type_guard(expr, interner))
+        .map(|guard| vec![negate_extracted_guard(&guard)])
+        .unwrap_or_default()
+}
+
 fn negate_extracted_guard(guard: &TypeGuard) -> TypeGuard {
     match guard {
         TypeGuard::TypeOf {
@@ -745,4 +772,25 @@ mod tests {
         let guards = extract_all_type_guards(&expr, &interner);
         assert_eq!(guards.len(), 0);
     }
+
+    #[test]
+    fn test_falsy_guards_of_or_condition() {
+        // After `if (x == null || !y) return;` both x and y are known to be non-null
+        let (expr, interner) = parse_expr("x == null || !y");
+        let guards = extract_all_falsy_type_guards(&expr, &interner);
+        assert_eq!(
+            guards,
+            vec![
+                TypeGuard::Nullish {
+                    var: "x".to_string(),
+                    field: None,
+                    negated: true,
+                },
+                TypeGuard::Truthiness {
+                    var: "y".to_string(),
+                    negated: false,
+                },
+            ]
+        );
+    }
 }
@@ -0,0 +1,119 @@
+//! Unused import detection
+//!
+//! Finds import bindings that are never referenced in their module. A name
+//! counts as used when it appears as an identifier expression, a type
+//! reference, a JSX tag, or in a local `export { ... }` list (re-exports).
+//! Matching is by name, so a local binding that shadows an import hides it
+//! rather than producing a false warning.
+
+use rustc_hash::FxHashSet;
+
+use crate::parser::ast::visitor::{self, Visitor};
+use crate::parser::ast::*;
+use crate::parser::interner::Symbol;
+
+/// An import binding that is never referenced
+#[derive(Debug, Clone, Copy)]
+pub struct UnusedImport<'a> {
+    /// Import declaration the binding belongs to
+    pub decl: &'a ImportDecl,
+    /// Local name bound by the import (the alias for `{ a as b }`)
+    pub local: &'a Identifier,
+}
+
+/// Local name bound by an import specifier
+pub fn import_local_name(spec: &ImportSpecifier) -> &Identifier {
+    match spec {
+        ImportSpecifier::Named { name, alias } => alias.as_ref().unwrap_or(name),
+        ImportSpecifier::Namespace(local) | ImportSpecifier::Default(local) => local,
+    }
+}
+
+/// Find the import bindings of `module` that are never referenced.
+///
+/// Imports without specifiers only run the imported module for its side
+/// effects and are never reported.
+pub fn find_unused_imports(module: &Module) -> Vec<UnusedImport<'_>> {
+    let mut usage = NameUsage::default();
+    usage.visit_module(module);
+
+    let mut unused = Vec::new();
+    for stmt in &module.statements {
+        let Statement::ImportDecl(decl) = stmt else {
+            continue;
+        };
+        for spec in &decl.specifiers {
+            let local = import_local_name(spec);
+            if !usage.used.contains(&local.name) {
+                unused.push(UnusedImport { decl, local });
+            }
+        }
+    }
+    unused
+}
+
+/// Collects every name read anywhere in a module
+#[derive(Default)]
+struct NameUsage {
+    used: FxHashSet<Symbol>,
+}
+
+impl NameUsage {
+    fn use_jsx_name(&mut self, name: &JsxElementName) {
+        match name {
+            JsxElementName::Identifier(id) => {
+                self.used.insert(id.name);
+            }
+            JsxElementName::Namespaced { .. } => {}
+            JsxElementName::MemberExpression { object, .. } => self.use_jsx_name(object),
+        }
+    }
+}
+
+impl Visitor for NameUsage {
+    fn visit_export_decl(&mut self, decl: &ExportDecl) {
+        if let ExportDecl::Named {
+            specifiers,
+            source: None,
+            ..
+        } = decl
+        {
+            for spec in specifiers {
+                self.used.insert(spec.name.name);
+            }
+        }
+        visitor::walk_export_decl(self, decl);
+    }
+
+    fn visit_expression(&mut self, expr: &Expression) {
+        if let Expression::Identifier(id) = expr {
+            self.used.insert(id.name);
+        }
+        visitor::walk_expression(self, expr);
+    }
+
+    fn visit_jsx_element(&mut self, elem: &JsxElement) {
+        self.use_jsx_name(&elem.opening.name);
+        for attr in &elem.opening.attributes {
+            if let JsxAttribute::Attribute {
+                value: Some(value), ..
+            } = attr
+            {
+                match value {
+                    JsxAttributeValue::Expression(expr) => self.visit_expression(expr),
+                    JsxAttributeValue::JsxElement(elem) => self.visit_jsx_element(elem),
+                    JsxAttributeValue::JsxFragment(frag) => self.visit_jsx_fragment(frag),
+                    JsxAttributeValue::StringLiteral(_) => {}
+                }
+            }
+        }
+        visitor::walk_jsx_element(self, elem);
+    }
+
+    fn visit_type_annotation(&mut self, ty: &TypeAnnotation) {
+        if let Type::Reference(reference) = &ty.ty {
+            self.used.insert(reference.name.name);
+        }
+        visitor::walk_type_annotation(self, ty);
+    }
+}
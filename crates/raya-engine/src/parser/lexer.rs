@@ -27,9 +27,13 @@ enum LogosToken {
     #[regex(r"/\*", lex_block_comment)]
     BlockComment,
 
-    // Compiler annotations: //@@tag or //@@tag value
-    // Examples: //@@json, //@@json user_name, //@@json age,omitempty, //@@json -
-    #[regex(r"//@@[a-zA-Z_][a-zA-Z0-9_]*( [^\n]*)?", parse_annotation)]
+    // Compiler annotations: //@@tag, //@@tag value or //@@tag(argument)
+    // Examples: //@@json, //@@json user_name, //@@json age,omitempty, //@@json -, //@@if(linux),
+    // //@@deprecated("use parse() instead")
+    #[regex(
+        r#"//@@[a-zA-Z_][a-zA-Z0-9_]*(\(("[^"\n]*"|[^)\n]*)\))?( [^\n]*)?"#,
+        parse_annotation
+    )]
     Annotation(String),
 
     // Keywords (must come before identifiers)
@@ -210,6 +214,10 @@ enum LogosToken {
     #[regex(r"\.[0-9]+(_[0-9]+)*([eE][+-]?[0-9]+(_[0-9]+)*)?", parse_float)]
     FloatLiteral(f64),
 
+    // Arbitrary-precision integers keep their digits as text: 123n
+    #[regex(r"[0-9]+(_[0-9]+)*n", parse_bigint)]
+    BigIntLiteral(String),
+
     // Strings
     #[regex(r#""([^"\\]|\\.)*""#, parse_string)]
     #[regex(r"'([^'\\]|\\.)*'", parse_string)]
@@ -432,6 +440,15 @@ fn parse_float(lex: &mut logos::Lexer<'_, LogosToken>) -> Option<f64> {
     lex.slice().replace('_', "").parse().ok()
 }
 
+fn parse_bigint(lex: &mut logos::Lexer<'_, LogosToken>) -> String {
+    let digits = lex.slice().trim_end_matches('n').replace('_', "");
+    // Normalize leading zeros so `007n` and `7n` intern to the same digits
+    match digits.trim_start_matches('0') {
+        "" => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
 fn parse_string(lex: &mut logos::Lexer<'_, LogosToken>) -> Option<String> {
     let s = lex.slice();
     let inner = &s[1..s.len() - 1]; // Remove quotes
@@ -568,6 +585,32 @@ fn unescape_string(s: &str) -> String {
     result
 }
 
+/// Number of UTF-16 code units a UTF-8 byte contributes to a column.
+///
+/// Continuation bytes count for nothing, so summing over the bytes of a
+/// character yields its UTF-16 length.
+fn utf16_width(byte: u8) -> u32 {
+    match byte {
+        0x80..=0xBF => 0,
+        0xF0..=0xFF => 2,
+        _ => 1,
+    }
+}
+
+/// A single text edit, expressed as byte offsets.
+///
+/// The bytes `start..old_end` of the old source were replaced by the bytes
+/// `start..new_end` of the new source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Offset where the edit begins (same in old and new source).
+    pub start: usize,
+    /// End of the replaced range in the old source.
+    pub old_end: usize,
+    /// End of the inserted range in the new source.
+    pub new_end: usize,
+}
+
 /// Main lexer structure.
 pub struct Lexer<'a> {
     source: &'a str,
@@ -625,261 +668,374 @@ impl<'a> Lexer<'a> {
         let mut line = 1u32;
         let mut column = 1u32;
 
-        while pos < self.source.len() {
-            // Skip whitespace and comments manually before checking for template literal
-            // This is needed because logos skips whitespace internally, but we need to
-            // check for backticks BEFORE logos processes them
-            let bytes = self.source.as_bytes();
-            while pos < bytes.len() {
-                let ch = bytes[pos];
-                match ch {
-                    b' ' | b'\t' | b'\r' => {
-                        column += 1;
-                        pos += 1;
-                    }
-                    b'\n' => {
-                        line += 1;
-                        column = 1;
-                        pos += 1;
-                    }
-                    b'/' if pos + 1 < bytes.len() => {
-                        // Check for comments
-                        match bytes[pos + 1] {
-                            b'/' => {
-                                // Check for //@@annotation - don't skip, let logos handle it
-                                if pos + 3 < bytes.len()
-                                    && bytes[pos + 2] == b'@'
-                                    && bytes[pos + 3] == b'@'
-                                {
-                                    break; // Not a comment, let logos tokenize
-                                }
-                                // Line comment - skip to end of line
-                                pos += 2;
-                                column += 2;
-                                while pos < bytes.len() && bytes[pos] != b'\n' {
-                                    pos += 1;
-                                    column += 1;
-                                }
+        while self.lex_next(&mut pos, &mut line, &mut column) {}
+
+        // Add EOF token
+        let eof_span = Span::new(self.source.len(), self.source.len(), line, column);
+        self.tokens.push((Token::Eof, eof_span));
+
+        if self.errors.is_empty() {
+            Ok((self.tokens, self.interner))
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Relex `source` after `edit`, reusing tokens from a previous lex of the old source.
+    ///
+    /// Tokens before the edit are kept as-is. Lexing restarts just before the
+    /// edit and continues until the new stream lines up with the old one again,
+    /// after which the remaining old tokens are spliced in with their spans
+    /// shifted. A multi-line string or comment opened by the edit simply keeps
+    /// the window growing until it closes.
+    ///
+    /// `previous` and `interner` must be the output of lexing the old source.
+    #[allow(clippy::type_complexity)]
+    pub fn relex(
+        source: &'a str,
+        previous: &[(Token, Span)],
+        interner: Interner,
+        edit: TextEdit,
+    ) -> Result<(Vec<(Token, Span)>, Interner), Vec<LexError>> {
+        let body = match previous.last() {
+            Some((Token::Eof, _)) => &previous[..previous.len() - 1],
+            _ => previous,
+        };
+
+        // Restart one token before the first one touching the edit, so tokens
+        // that merge across the edit boundary (and templates, whose span only
+        // covers the opening backtick) are relexed.
+        let first_affected = body
+            .iter()
+            .position(|(_, span)| span.end >= edit.start)
+            .unwrap_or(body.len());
+        let restart = first_affected.saturating_sub(1);
+
+        let mut lexer = Self::with_interner(source, interner);
+        lexer.tokens.extend_from_slice(&body[..restart]);
+
+        let (mut pos, mut line, mut column) = match body.get(restart) {
+            Some((_, span)) => (span.start, span.line, span.column),
+            None => (0, 1, 1),
+        };
+
+        let delta = edit.new_end as isize - edit.old_end as isize;
+        let mut old_index = restart;
+        let mut synced = false;
+
+        loop {
+            let count = lexer.tokens.len();
+            if !lexer.lex_next(&mut pos, &mut line, &mut column) {
+                break;
+            }
+            if lexer.tokens.len() == count {
+                continue;
+            }
+
+            let (token, span) = &lexer.tokens[count];
+            if span.start < edit.new_end {
+                continue;
+            }
+
+            // Find the old token that would sit at the same place after the edit.
+            while old_index < body.len()
+                && (body[old_index].1.start as isize + delta) < span.start as isize
+            {
+                old_index += 1;
+            }
+            let Some((old_token, old_span)) = body.get(old_index) else {
+                continue;
+            };
+            if (old_span.start as isize + delta) as usize != span.start
+                || old_span.end - old_span.start != span.end - span.start
+                || old_token != token
+            {
+                continue;
+            }
+
+            // Lexer state now matches the old run, so the rest is unchanged.
+            let line_delta = span.line as i64 - old_span.line as i64;
+            let column_delta = span.column as i64 - old_span.column as i64;
+            let sync_line = old_span.line;
+            for (old_token, old_span) in &previous[old_index + 1..] {
+                let shifted_column = if old_span.line == sync_line {
+                    (old_span.column as i64 + column_delta) as u32
+                } else {
+                    old_span.column
+                };
+                let shifted = Span::new(
+                    (old_span.start as isize + delta) as usize,
+                    (old_span.end as isize + delta) as usize,
+                    (old_span.line as i64 + line_delta) as u32,
+                    shifted_column,
+                );
+                lexer.tokens.push((old_token.clone(), shifted));
+            }
+            synced = true;
+            break;
+        }
+
+        if !synced {
+            let eof_span = Span::new(source.len(), source.len(), line, column);
+            lexer.tokens.push((Token::Eof, eof_span));
+        }
+
+        if lexer.errors.is_empty() {
+            Ok((lexer.tokens, lexer.interner))
+        } else {
+            Err(lexer.errors)
+        }
+    }
+
+    /// Lex the next token at `pos`, advancing `pos`/`line`/`column` past it.
+    ///
+    /// Returns `false` once the end of the source is reached.
+    fn lex_next(&mut self, pos: &mut usize, line: &mut u32, column: &mut u32) -> bool {
+        // Skip whitespace and comments manually before checking for template literal
+        // This is needed because logos skips whitespace internally, but we need to
+        // check for backticks BEFORE logos processes them
+        let bytes = self.source.as_bytes();
+        while *pos < bytes.len() {
+            let ch = bytes[*pos];
+            match ch {
+                b' ' | b'\t' | b'\r' => {
+                    *column += 1;
+                    *pos += 1;
+                }
+                b'\n' => {
+                    *line += 1;
+                    *column = 1;
+                    *pos += 1;
+                }
+                b'/' if *pos + 1 < bytes.len() => {
+                    // Check for comments
+                    match bytes[*pos + 1] {
+                        b'/' => {
+                            // Check for //@@annotation - don't skip, let logos handle it
+                            if *pos + 3 < bytes.len()
+                                && bytes[*pos + 2] == b'@'
+                                && bytes[*pos + 3] == b'@'
+                            {
+                                break; // Not a comment, let logos tokenize
                             }
-                            b'*' => {
-                                // Block comment - skip to */
-                                pos += 2;
-                                column += 2;
-                                while pos + 1 < bytes.len() {
-                                    if bytes[pos] == b'*' && bytes[pos + 1] == b'/' {
-                                        pos += 2;
-                                        column += 2;
-                                        break;
-                                    }
-                                    if bytes[pos] == b'\n' {
-                                        line += 1;
-                                        column = 1;
-                                    } else {
-                                        column += 1;
-                                    }
-                                    pos += 1;
+                            // Line comment - skip to end of line
+                            *pos += 2;
+                            *column += 2;
+                            while *pos < bytes.len() && bytes[*pos] != b'\n' {
+                                *column += utf16_width(bytes[*pos]);
+                                *pos += 1;
+                            }
+                        }
+                        b'*' => {
+                            // Block comment - skip to */
+                            *pos += 2;
+                            *column += 2;
+                            while *pos + 1 < bytes.len() {
+                                if bytes[*pos] == b'*' && bytes[*pos + 1] == b'/' {
+                                    *pos += 2;
+                                    *column += 2;
+                                    break;
                                 }
+                                if bytes[*pos] == b'\n' {
+                                    *line += 1;
+                                    *column = 1;
+                                } else {
+                                    *column += utf16_width(bytes[*pos]);
+                                }
+                                *pos += 1;
                             }
-                            _ => break, // Not a comment, stop skipping
                         }
+                        _ => break, // Not a comment, stop skipping
                     }
-                    _ => break, // Not whitespace, stop skipping
                 }
+                _ => break, // Not whitespace, stop skipping
             }
+        }
 
-            // Check if we reached the end after skipping whitespace
-            if pos >= self.source.len() {
-                break;
-            }
+        // Check if we reached the end after skipping whitespace
+        if *pos >= self.source.len() {
+            return false;
+        }
 
-            // Check for template literal first
-            if self.source.as_bytes()[pos] == b'`' {
-                let start_span = Span::new(pos, pos + 1, line, column);
-                pos += 1; // Skip opening backtick
-                column += 1;
-
-                match self.lex_template(pos) {
-                    Ok((template, end_pos)) => {
-                        self.tokens
-                            .push((Token::TemplateLiteral(template), start_span));
-
-                        // Update line/column for consumed template
-                        for c in self.source[pos..end_pos].chars() {
-                            if c == '\n' {
-                                line += 1;
-                                column = 1;
-                            } else {
-                                column += 1;
-                            }
+        // Check for template literal first
+        if self.source.as_bytes()[*pos] == b'`' {
+            let start_span = Span::new(*pos, *pos + 1, *line, *column);
+            *pos += 1; // Skip opening backtick
+            *column += 1;
+
+            match self.lex_template(*pos) {
+                Ok((template, end_pos)) => {
+                    self.tokens
+                        .push((Token::TemplateLiteral(template), start_span));
+
+                    // Update line/column for consumed template
+                    for c in self.source[*pos..end_pos].chars() {
+                        if c == '\n' {
+                            *line += 1;
+                            *column = 1;
+                        } else {
+                            *column += c.len_utf16() as u32;
                         }
-                        pos = end_pos;
-                        continue;
                     }
-                    Err(err) => {
-                        self.errors.push(err);
-                        // Skip to end of line or next backtick for error recovery
-                        while pos < self.source.len() {
-                            let ch = self.source.as_bytes()[pos];
-                            if ch == b'\n' || ch == b'`' {
-                                break;
-                            }
-                            if ch == b'\n' {
-                                line += 1;
-                                column = 1;
-                            } else {
-                                column += 1;
-                            }
-                            pos += 1;
+                    *pos = end_pos;
+                    return true;
+                }
+                Err(err) => {
+                    self.errors.push(err);
+                    // Skip to end of line or next backtick for error recovery
+                    while *pos < self.source.len() {
+                        let ch = self.source.as_bytes()[*pos];
+                        if ch == b'\n' || ch == b'`' {
+                            break;
                         }
-                        continue;
+                        if ch == b'\n' {
+                            *line += 1;
+                            *column = 1;
+                        } else {
+                            *column += utf16_width(ch);
+                        }
+                        *pos += 1;
                     }
+                    return true;
                 }
             }
+        }
 
-            // Check for regex literal: /pattern/flags
-            // A `/` starts a regex when NOT preceded by a value-producing token
-            // and NOT followed by `/` or `*` (which are comments)
-            if self.source.as_bytes()[pos] == b'/'
-                && (pos + 1 >= self.source.len()
-                    || (self.source.as_bytes()[pos + 1] != b'/'
-                        && self.source.as_bytes()[pos + 1] != b'*'))
-            {
-                // In JSX closing tags (e.g. `</div>`), `/` must remain a slash token.
-                // Guard this specific context before regex-literal detection.
-                let jsx_closing_tag_slash = self
-                    .tokens
-                    .last()
-                    .map_or(false, |(tok, _)| matches!(tok, Token::Less))
-                    && (pos + 1 < self.source.len()
-                        && (self.source.as_bytes()[pos + 1].is_ascii_alphabetic()
-                            || self.source.as_bytes()[pos + 1] == b'>'));
-
-                let is_division = self.tokens.last().map_or(false, |(tok, _)| {
-                    matches!(
-                        tok,
-                        Token::Identifier(_)
-                            | Token::IntLiteral(_)
-                            | Token::FloatLiteral(_)
-                            | Token::StringLiteral(_)
-                            | Token::TemplateLiteral(_)
-                            | Token::RegexLiteral(_, _)
-                            | Token::True
-                            | Token::False
-                            | Token::Null
-                            | Token::This
-                            | Token::Super
-                            | Token::RightParen
-                            | Token::RightBracket
-                            | Token::RightBrace
-                            | Token::PlusPlus
-                            | Token::MinusMinus
-                    )
-                });
-
-                if !is_division && !jsx_closing_tag_slash {
-                    // Scan regex pattern
-                    let start = pos;
-                    pos += 1; // skip opening /
-                    let pattern_start = pos;
-                    let mut in_char_class = false;
-
-                    while pos < self.source.len() {
-                        let ch = self.source.as_bytes()[pos];
-                        if ch == b'\\' && pos + 1 < self.source.len() {
-                            pos += 2; // skip escaped char
-                        } else if ch == b'[' {
-                            in_char_class = true;
-                            pos += 1;
-                        } else if ch == b']' {
-                            in_char_class = false;
-                            pos += 1;
-                        } else if ch == b'/' && !in_char_class {
-                            break;
-                        } else if ch == b'\n' {
-                            break; // unterminated regex
-                        } else {
-                            pos += 1;
-                        }
+        // Check for regex literal: /pattern/flags
+        // A `/` starts a regex when NOT preceded by a value-producing token
+        // and NOT followed by `/` or `*` (which are comments)
+        if self.source.as_bytes()[*pos] == b'/'
+            && (*pos + 1 >= self.source.len()
+                || (self.source.as_bytes()[*pos + 1] != b'/'
+                    && self.source.as_bytes()[*pos + 1] != b'*'))
+        {
+            // In JSX closing tags (e.g. `</div>`), `/` must remain a slash token.
+            // Guard this specific context before regex-literal detection.
+            let jsx_closing_tag_slash = self
+                .tokens
+                .last()
+                .map_or(false, |(tok, _)| matches!(tok, Token::Less))
+                && (*pos + 1 < self.source.len()
+                    && (self.source.as_bytes()[*pos + 1].is_ascii_alphabetic()
+                        || self.source.as_bytes()[*pos + 1] == b'>'));
+
+            let is_division = self.tokens.last().map_or(false, |(tok, _)| {
+                matches!(
+                    tok,
+                    Token::Identifier(_)
+                        | Token::IntLiteral(_)
+                        | Token::FloatLiteral(_)
+                        | Token::BigIntLiteral(_)
+                        | Token::StringLiteral(_)
+                        | Token::TemplateLiteral(_)
+                        | Token::RegexLiteral(_, _)
+                        | Token::True
+                        | Token::False
+                        | Token::Null
+                        | Token::This
+                        | Token::Super
+                        | Token::RightParen
+                        | Token::RightBracket
+                        | Token::RightBrace
+                        | Token::PlusPlus
+                        | Token::MinusMinus
+                )
+            });
+
+            if !is_division && !jsx_closing_tag_slash {
+                // Scan regex pattern
+                let start = *pos;
+                *pos += 1; // skip opening /
+                let pattern_start = *pos;
+                let mut in_char_class = false;
+
+                while *pos < self.source.len() {
+                    let ch = self.source.as_bytes()[*pos];
+                    if ch == b'\\' && *pos + 1 < self.source.len() {
+                        *pos += 2; // skip escaped char
+                    } else if ch == b'[' {
+                        in_char_class = true;
+                        *pos += 1;
+                    } else if ch == b']' {
+                        in_char_class = false;
+                        *pos += 1;
+                    } else if ch == b'/' && !in_char_class {
+                        break;
+                    } else if ch == b'\n' {
+                        break; // unterminated regex
+                    } else {
+                        *pos += 1;
                     }
+                }
 
-                    if pos < self.source.len() && self.source.as_bytes()[pos] == b'/' {
-                        let pattern = &self.source[pattern_start..pos];
-                        pos += 1; // skip closing /
+                if *pos < self.source.len() && self.source.as_bytes()[*pos] == b'/' {
+                    let pattern = &self.source[pattern_start..*pos];
+                    *pos += 1; // skip closing /
 
-                        // Scan flags (gimsuvy)
-                        let flags_start = pos;
-                        while pos < self.source.len() {
-                            let ch = self.source.as_bytes()[pos];
-                            if ch.is_ascii_alphabetic() {
-                                pos += 1;
-                            } else {
-                                break;
-                            }
+                    // Scan flags (gimsuvy)
+                    let flags_start = *pos;
+                    while *pos < self.source.len() {
+                        let ch = self.source.as_bytes()[*pos];
+                        if ch.is_ascii_alphabetic() {
+                            *pos += 1;
+                        } else {
+                            break;
                         }
-                        let flags = &self.source[flags_start..pos];
+                    }
+                    let flags = &self.source[flags_start..*pos];
 
-                        let pattern_sym = self.interner.intern(pattern);
-                        let flags_sym = self.interner.intern(flags);
-                        let span = Span::new(start, pos, line, column);
-                        self.tokens
-                            .push((Token::RegexLiteral(pattern_sym, flags_sym), span));
+                    let pattern_sym = self.interner.intern(pattern);
+                    let flags_sym = self.interner.intern(flags);
+                    let span = Span::new(start, *pos, *line, *column);
+                    self.tokens
+                        .push((Token::RegexLiteral(pattern_sym, flags_sym), span));
 
-                        // Update column for the consumed regex
-                        column += (pos - start) as u32;
-                        continue;
-                    }
-                    // If we didn't find closing /, reset and fall through to logos
-                    pos = start;
+                    // Update column for the consumed regex
+                    *column += self.source[start..*pos].encode_utf16().count() as u32;
+                    return true;
                 }
+                // If we didn't find closing /, reset and fall through to logos
+                *pos = start;
             }
+        }
 
-            // Use logos for regular tokens
-            let mut logos_lexer = LogosToken::lexer(&self.source[pos..]);
+        // Use logos for regular tokens
+        let mut logos_lexer = LogosToken::lexer(&self.source[*pos..]);
 
-            if let Some(token_result) = logos_lexer.next() {
-                let range = logos_lexer.span();
-                let abs_start = pos + range.start;
-                let abs_end = pos + range.end;
+        if let Some(token_result) = logos_lexer.next() {
+            let range = logos_lexer.span();
+            let abs_start = *pos + range.start;
+            let abs_end = *pos + range.end;
 
-                let span = Span::new(abs_start, abs_end, line, column);
+            let span = Span::new(abs_start, abs_end, *line, *column);
 
-                match token_result {
-                    Ok(logos_token) => {
-                        let token = self.convert_token(logos_token);
-                        self.tokens.push((token, span));
-                    }
-                    Err(_) => {
-                        let char = self.source[abs_start..].chars().next().unwrap_or('\0');
-                        self.errors
-                            .push(LexError::UnexpectedCharacter { char, span });
-                    }
+            match token_result {
+                Ok(logos_token) => {
+                    let token = self.convert_token(logos_token);
+                    self.tokens.push((token, span));
                 }
-
-                // Update line and column
-                for c in self.source[abs_start..abs_end].chars() {
-                    if c == '\n' {
-                        line += 1;
-                        column = 1;
-                    } else {
-                        column += 1;
-                    }
+                Err(_) => {
+                    let char = self.source[abs_start..].chars().next().unwrap_or('\0');
+                    self.errors
+                        .push(LexError::UnexpectedCharacter { char, span });
                 }
-
-                pos = abs_end;
-            } else {
-                break;
             }
-        }
 
-        // Add EOF token
-        let eof_span = Span::new(self.source.len(), self.source.len(), line, column);
-        self.tokens.push((Token::Eof, eof_span));
+            // Update line and column
+            for c in self.source[abs_start..abs_end].chars() {
+                if c == '\n' {
+                    *line += 1;
+                    *column = 1;
+                } else {
+                    *column += c.len_utf16() as u32;
+                }
+            }
 
-        if self.errors.is_empty() {
-            Ok((self.tokens, self.interner))
+            *pos = abs_end;
         } else {
-            Err(self.errors)
+            return false;
         }
+        true
     }
 
     fn convert_token(&mut self, logos_token: LogosToken) -> Token {
@@ -939,6 +1095,7 @@ impl<'a> Lexer<'a> {
             LogosToken::PrivateIdentifier(s) => Token::PrivateIdentifier(self.interner.intern(&s)),
             LogosToken::IntLiteral(n) => Token::IntLiteral(n),
             LogosToken::FloatLiteral(n) => Token::FloatLiteral(n),
+            LogosToken::BigIntLiteral(s) => Token::BigIntLiteral(self.interner.intern(&s)),
             LogosToken::StringLiteral(s) => Token::StringLiteral(self.interner.intern(&s)),
             LogosToken::EqualEqualEqual => Token::EqualEqualEqual,
             LogosToken::BangEqualEqual => Token::BangEqualEqual,
@@ -1339,6 +1496,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_annotation_with_argument() {
+        let source = "//@@if(linux)";
+        let lexer = Lexer::new(source);
+        let (tokens, interner) = lexer.tokenize().expect("should lex");
+        assert_eq!(tokens.len(), 2); // Annotation + EOF
+        if let Token::Annotation(sym) = &tokens[0].0 {
+            assert_eq!(interner.resolve(*sym), "if(linux)");
+        } else {
+            panic!("Expected Annotation token, got {:?}", tokens[0].0);
+        }
+    }
+
     #[test]
     fn test_annotation_skip() {
         let source = "//@@json -";
@@ -1415,4 +1585,93 @@ class User {
             "//@... should not produce Annotation tokens"
         );
     }
+
+    fn assert_relex_matches(old_src: &str, new_src: &str, edit: TextEdit) {
+        let (previous, interner) = Lexer::new(old_src).tokenize().expect("should lex");
+        let (incremental, interner) =
+            Lexer::relex(new_src, &previous, interner, edit).expect("should relex");
+        let (full, full_interner) = Lexer::new(new_src).tokenize().expect("should lex");
+
+        assert_eq!(
+            incremental.len(),
+            full.len(),
+            "token count for {:?}",
+            new_src
+        );
+        for ((a, a_span), (b, b_span)) in incremental.iter().zip(&full) {
+            assert_eq!(a_span, b_span, "span mismatch in {:?}", new_src);
+            assert_eq!(std::mem::discriminant(a), std::mem::discriminant(b));
+            if let (Token::Identifier(a), Token::Identifier(b)) = (a, b) {
+                assert_eq!(interner.resolve(*a), full_interner.resolve(*b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_incremental_relex_matches_full_relex() {
+        // Rename an identifier in the middle of a line.
+        assert_relex_matches(
+            "let x = 1;\nlet y = x + 2;\n",
+            "let x = 1;\nlet total = x + 2;\n",
+            TextEdit {
+                start: 15,
+                old_end: 16,
+                new_end: 20,
+            },
+        );
+        // Insert a new line, shifting later lines.
+        assert_relex_matches(
+            "let a = 1;\nlet b = 2;\n",
+            "let a = 1;\nlet c = 3;\nlet b = 2;\n",
+            TextEdit {
+                start: 11,
+                old_end: 11,
+                new_end: 22,
+            },
+        );
+        // Delete text so two identifiers merge.
+        assert_relex_matches(
+            "let ab = a b;\n",
+            "let ab = ab;\n",
+            TextEdit {
+                start: 10,
+                old_end: 11,
+                new_end: 10,
+            },
+        );
+    }
+
+    #[test]
+    fn test_incremental_relex_widens_for_comments_and_strings() {
+        // Opening a block comment swallows the following lines.
+        assert_relex_matches(
+            "let a = 1;\nlet b = 2; */\nlet c = 3;\n",
+            "/* let a = 1;\nlet b = 2; */\nlet c = 3;\n",
+            TextEdit {
+                start: 0,
+                old_end: 0,
+                new_end: 3,
+            },
+        );
+        // Closing a string changes how the rest of the line lexes.
+        assert_relex_matches(
+            "let s = \"a\";\nlet t = s;\n",
+            "let s = \"a b c\";\nlet t = s;\n",
+            TextEdit {
+                start: 10,
+                old_end: 10,
+                new_end: 14,
+            },
+        );
+        // Template literal spanning lines.
+        assert_relex_matches(
+            "let t = `a\nb`;\nlet u = 1;\n",
+            "let t = `a\nxx\nb`;\nlet u = 1;\n",
+            TextEdit {
+                start: 11,
+                old_end: 11,
+                new_end: 14,
+            },
+        );
+    }
 }
@@ -43,9 +43,9 @@ pub mod checker;
 
 // Re-exports for convenience
 pub use interner::{Interner, Symbol};
-pub use lexer::{LexError, Lexer};
+pub use lexer::{LexError, Lexer, TextEdit};
 pub use parser::{ParseError, Parser};
-pub use token::{Span, TemplatePart, Token};
+pub use token::{byte_offset_from_utf16, utf16_position, Span, TemplatePart, Token};
 
 // Type system re-exports
 pub use types::{Type, TypeContext, TypeId};
@@ -39,6 +39,13 @@ pub struct Parser {
 
     /// Current recursion depth (for preventing stack overflow)
     depth: usize,
+
+    /// Statements to emit before the next top-level statement
+    /// (e.g. the class behind `export default class Foo {}`)
+    hoisted_statements: Vec<Statement>,
+
+    /// `//@@if(feature)` conditions seen so far, by gated statement or member
+    feature_gates: Vec<FeatureGate>,
 }
 
 /// Backtracking snapshot for speculative parsing.
@@ -76,6 +83,8 @@ impl Parser {
             pos: 0,
             errors: Vec::new(),
             depth: 0,
+            hoisted_statements: Vec::new(),
+            feature_gates: Vec::new(),
         })
     }
 
@@ -105,9 +114,21 @@ impl Parser {
             pos: 0,
             errors: Vec::new(),
             depth: 0,
+            hoisted_statements: Vec::new(),
+            feature_gates: Vec::new(),
         }
     }
 
+    /// Record the `//@@if(feature)` conditions in `annotations` against the
+    /// statement or class member at `target`.
+    pub(crate) fn record_feature_gates(&mut self, annotations: &[Annotation], target: Span) {
+        self.feature_gates.extend(
+            annotations
+                .iter()
+                .filter_map(|annotation| annotation.feature_gate(target)),
+        );
+    }
+
     /// Parse a single expression from this parser.
     ///
     /// Used for parsing template literal expressions.
@@ -131,8 +152,27 @@ impl Parser {
         // Parse top-level statements until EOF
         while !self.at_eof() {
             match self.parse_statement() {
-                Ok(stmt) => statements.push(stmt),
+                Ok(Statement::VariableDecl(decl)) if decl.kind == VariableKind::Using => {
+                    // There is no enclosing block whose exit could dispose it
+                    self.errors.push(ParseError {
+                        kind: ParseErrorKind::InvalidSyntax {
+                            reason: "using declarations are only allowed inside a block"
+                                .to_string(),
+                        },
+                        span: decl.span,
+                        message: "using declaration at module top level".to_string(),
+                        suggestion: Some(
+                            "Move it into a function body or block that owns the resource"
+                                .to_string(),
+                        ),
+                    });
+                }
+                Ok(stmt) => {
+                    statements.append(&mut self.hoisted_statements);
+                    statements.push(stmt);
+                }
                 Err(err) => {
+                    self.hoisted_statements.clear();
                     self.errors.push(err);
                     // Attempt recovery by synchronizing to next statement
                     self.sync_to_statement_boundary();
@@ -151,7 +191,38 @@ impl Parser {
             return Err(self.errors);
         }
 
-        Ok((Module { statements, span }, self.interner))
+        let module = Module {
+            statements,
+            span,
+            feature_gates: self.feature_gates,
+        };
+        Ok((module, self.interner))
+    }
+
+    /// Parse `source` as a single standalone expression.
+    ///
+    /// Used by tools like the REPL that evaluate fragments rather than whole
+    /// modules. Tokens left over after the expression are rejected. The Interner
+    /// is returned alongside so Symbol values can be resolved.
+    pub fn parse_expression(source: &str) -> Result<(Expression, Interner), ParseError> {
+        let mut parser = Self::new(source).map_err(|errors| {
+            let error = &errors[0];
+            ParseError::invalid_syntax(error.to_string(), *error.span())
+        })?;
+
+        let expression = expr::parse_expression(&mut parser)?;
+        if !parser.errors.is_empty() {
+            return Err(parser.errors.remove(0));
+        }
+        if !parser.at_eof() {
+            return Err(ParseError::unexpected_token(
+                vec![Token::Eof],
+                parser.current().clone(),
+                parser.current_span(),
+            ));
+        }
+
+        Ok((expression, parser.interner))
     }
 
     // ========================================================================
@@ -109,7 +109,12 @@ fn parse_expression_with_precedence(
     let mut left = parse_prefix(parser)?;
 
     loop {
-        let current_precedence = get_precedence(parser.current());
+        // `satisfies` is a contextual keyword binding like `as`.
+        let current_precedence = if is_satisfies_keyword(parser) {
+            Precedence::Relational
+        } else {
+            get_precedence(parser.current())
+        };
 
         // Standard precedence climbing: continue while current_prec >= min_prec
         // Special case: allow postfix operators through even if precedence is None
@@ -145,6 +150,11 @@ fn parse_expression_with_precedence(
     Ok(left)
 }
 
+/// Check if the current token is the contextual `satisfies` keyword.
+fn is_satisfies_keyword(parser: &Parser) -> bool {
+    matches!(parser.current(), Token::Identifier(sym) if parser.resolve(*sym) == "satisfies")
+}
+
 fn looks_like_generic_call_suffix(parser: &mut Parser) -> bool {
     if !matches!(parser.current(), Token::Less) {
         return false;
@@ -650,6 +660,19 @@ fn parse_infix(
         return parse_postfix(parser, logical);
     }
 
+    // Parse: expr satisfies TypeName
+    if is_satisfies_keyword(parser) {
+        parser.advance();
+        let target_type = super::types::parse_type_annotation(parser)?;
+        let span = parser.combine_spans(&start_span, &target_type.span);
+        let satisfies = Expression::Satisfies(SatisfiesExpression {
+            object: Box::new(left),
+            target_type,
+            span,
+        });
+        return parse_postfix(parser, satisfies);
+    }
+
     // Binary operators
     let op_token = parser.current().clone();
 
@@ -718,6 +741,17 @@ fn parse_infix(
         Token::As => {
             // Parse: expr as TypeName
             parser.advance();
+            if parser.check(&Token::Const) {
+                // Parse: expr as const
+                let const_span = parser.current_span();
+                parser.advance();
+                let span = parser.combine_spans(&start_span, &const_span);
+                let assertion = Expression::ConstAssertion(ConstAssertionExpression {
+                    object: Box::new(left),
+                    span,
+                });
+                return parse_postfix(parser, assertion);
+            }
             let target_type = super::types::parse_type_annotation(parser)?;
             let span = parser.combine_spans(&start_span, &target_type.span);
             let cast = Expression::TypeCast(TypeCastExpression {
@@ -1128,6 +1162,16 @@ pub fn parse_primary(parser: &mut Parser) -> Result<Expression, ParseError> {
             }))
         }
 
+        // BigInt literal
+        Token::BigIntLiteral(digits) => {
+            let digits = *digits;
+            parser.advance();
+            Ok(Expression::BigIntLiteral(BigIntLiteral {
+                digits,
+                span: start_span,
+            }))
+        }
+
         // Float literal
         Token::FloatLiteral(value) => {
             let value = *value;
@@ -1836,6 +1880,7 @@ fn parse_block_statement(parser: &mut Parser) -> Result<BlockStatement, ParseErr
     parser.expect(Token::RightBrace)?;
     let span = parser.combine_spans(&start_span, &end_span);
 
+    let statements = super::stmt::desugar_using_declarations(parser, statements);
     Ok(BlockStatement { statements, span })
 }
 
@@ -65,16 +65,30 @@ fn parse_statement_inner(parser: &mut Parser) -> Result<Statement, ParseError> {
         Token::Annotation(_) => {
             // Annotations can appear before class or type declarations
             let annotations = parse_annotations(parser)?;
-            match parser.current() {
+            let gates = feature_annotations(&annotations);
+            let hoisted_before = parser.hoisted_statements.len();
+            let statement = match parser.current() {
                 Token::Class | Token::Abstract | Token::At => {
                     parse_class_declaration_with_annotations(parser, annotations)
                 }
                 Token::Type => parse_type_alias_declaration(parser, annotations),
                 Token::Interface => parse_interface_declaration(parser, annotations),
                 // Allow annotations before other statements (e.g., //@@builtin_primitive before const)
-                // — annotations are discarded for non-class/type declarations
-                _ => parse_statement(parser),
+                // — annotations are discarded for non-class/type/function declarations
+                _ => parse_statement(parser).map(|mut statement| {
+                    attach_annotations(&mut statement, annotations);
+                    statement
+                }),
+            }?;
+            // `//@@if(feature)` also gates whatever the statement hoisted
+            let hoisted_spans: Vec<Span> = parser.hoisted_statements[hoisted_before..]
+                .iter()
+                .map(|hoisted| *hoisted.span())
+                .collect();
+            for target in std::iter::once(*statement.span()).chain(hoisted_spans) {
+                parser.record_feature_gates(&gates, target);
             }
+            Ok(statement)
         }
         Token::If => parse_if_statement(parser),
         Token::While => parse_while_statement(parser),
@@ -112,6 +126,11 @@ fn parse_statement_inner(parser: &mut Parser) -> Result<Statement, ParseError> {
             Ok(Statement::Empty(span))
         }
         _ => {
+            // `using` is contextual: only `using <identifier>` starts a declaration
+            if is_using_declaration_start(parser) {
+                return parse_variable_declaration(parser);
+            }
+
             // Check for labeled statement: identifier followed by colon at statement level
             if matches!(parser.current(), Token::Identifier(_)) {
                 if let Some(Token::Colon) = parser.peek() {
@@ -142,20 +161,38 @@ fn parse_statement_inner(parser: &mut Parser) -> Result<Statement, ParseError> {
 // Variable Declarations
 // ============================================================================
 
+/// Whether the current token starts a `using x = ...` declaration
+fn is_using_declaration_start(parser: &Parser) -> bool {
+    matches!(parser.current(), Token::Identifier(name) if parser.resolve(*name) == "using")
+        && matches!(parser.peek(), Some(Token::Identifier(_)))
+}
+
 /// Parse variable declaration: let x = 1; or const y: number = 2;
 fn parse_variable_declaration(parser: &mut Parser) -> Result<Statement, ParseError> {
     let start_span = parser.current_span();
 
-    // Parse let or const
+    // Parse let, const or using
     let kind = match parser.current() {
         Token::Let => VariableKind::Let,
         Token::Const => VariableKind::Const,
+        Token::Identifier(_) => VariableKind::Using,
         _ => unreachable!(),
     };
     parser.advance();
 
     // Parse pattern (for now, just identifier - destructuring later)
     let pattern = super::pattern::parse_pattern(parser)?;
+    if kind == VariableKind::Using && !matches!(pattern, Pattern::Identifier(_)) {
+        use super::ParseErrorKind;
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidSyntax {
+                reason: "using declarations must bind a single identifier".to_string(),
+            },
+            span: *pattern.span(),
+            message: "Destructuring is not allowed in a using declaration".to_string(),
+            suggestion: Some("Bind the resource to a name: using res = value;".to_string()),
+        });
+    }
 
     // Optional type annotation
     let type_annotation = if parser.check(&Token::Colon) {
@@ -170,15 +207,20 @@ fn parse_variable_declaration(parser: &mut Parser) -> Result<Statement, ParseErr
         parser.advance();
         Some(super::expr::parse_expression(parser)?)
     } else {
-        if kind == VariableKind::Const {
+        if kind != VariableKind::Let {
             use super::ParseErrorKind;
+            let keyword = if kind == VariableKind::Using {
+                "using"
+            } else {
+                "const"
+            };
             return Err(ParseError {
                 kind: ParseErrorKind::InvalidSyntax {
-                    reason: "const declarations must have an initializer".to_string(),
+                    reason: format!("{} declarations must have an initializer", keyword),
                 },
                 span: start_span,
-                message: "Missing initializer for const declaration".to_string(),
-                suggestion: Some("Add an initializer: const x = value;".to_string()),
+                message: format!("Missing initializer for {} declaration", keyword),
+                suggestion: Some(format!("Add an initializer: {} x = value;", keyword)),
             });
         }
         None
@@ -272,6 +314,7 @@ fn parse_function_declaration(parser: &mut Parser) -> Result<Statement, ParseErr
     let span = parser.combine_spans(&start_span, &body.span);
 
     Ok(Statement::FunctionDecl(FunctionDecl {
+        annotations: Vec::new(),
         name,
         type_params,
         params,
@@ -464,9 +507,77 @@ fn parse_block_statement(parser: &mut Parser) -> Result<BlockStatement, ParseErr
     parser.expect(Token::RightBrace)?;
     let span = parser.combine_spans(&start_span, &end_span);
 
+    let statements = desugar_using_declarations(parser, statements);
     Ok(BlockStatement { statements, span })
 }
 
+/// Rewrite the `using` declarations of a block into explicit disposal.
+///
+/// Everything after `using x = init;` moves into
+/// `try { rest } catch (e) { x.dispose(); throw e; } finally { x.dispose(); }`.
+/// The finally body is inlined at `return`/`break`/`continue` and runs when
+/// the block falls off its end; the catch disposes and rethrows, since a
+/// catch-less try would swallow the exception. Later declarations nest
+/// inside earlier ones, so resources are disposed in reverse order.
+pub(super) fn desugar_using_declarations(
+    parser: &mut Parser,
+    mut statements: Vec<Statement>,
+) -> Vec<Statement> {
+    let using = statements
+        .iter()
+        .enumerate()
+        .find_map(|(pos, stmt)| match stmt {
+            Statement::VariableDecl(VariableDecl {
+                kind: VariableKind::Using,
+                pattern: Pattern::Identifier(resource),
+                span,
+                ..
+            }) => Some((pos, resource.clone(), *span)),
+            _ => None,
+        });
+    let Some((pos, resource, span)) = using else {
+        return statements;
+    };
+    let rest = desugar_using_declarations(parser, statements.split_off(pos + 1));
+    let dispose = parser.intern("dispose");
+    let error = Identifier::new(parser.intern("__using_error"), span);
+
+    let dispose_call = Statement::Expression(ExpressionStatement {
+        expression: Expression::Call(CallExpression {
+            callee: Box::new(Expression::Member(MemberExpression {
+                object: Box::new(Expression::Identifier(resource)),
+                property: Identifier::new(dispose, span),
+                optional: false,
+                span,
+            })),
+            type_args: None,
+            arguments: Vec::new(),
+            optional: false,
+            span,
+        }),
+        span,
+    });
+    let block = |statements| BlockStatement { statements, span };
+
+    statements.push(Statement::Try(TryStatement {
+        body: block(rest),
+        catch_clause: Some(CatchClause {
+            param: Some(Pattern::Identifier(error.clone())),
+            body: block(vec![
+                dispose_call.clone(),
+                Statement::Throw(ThrowStatement {
+                    value: Expression::Identifier(error),
+                    span,
+                }),
+            ]),
+            span,
+        }),
+        finally_clause: Some(block(vec![dispose_call])),
+        span,
+    }));
+    statements
+}
+
 // ============================================================================
 // Control Flow Statements
 // ============================================================================
@@ -1198,22 +1309,29 @@ fn parse_switch_statement(parser: &mut Parser) -> Result<Statement, ParseError>
 
         let case_start = parser.current_span();
 
-        let test = if parser.check(&Token::Case) {
+        let (test, guard) = if parser.check(&Token::Case) {
             parser.advance();
-            // Parse the case test expression
+            // Parse the case test expression and optional `when` guard
             let test_expr = super::expr::parse_expression(parser)?;
+            let guard = if is_contextual_keyword(parser, "when") {
+                parser.advance();
+                Some(super::expr::parse_expression(parser)?)
+            } else {
+                None
+            };
             parser.expect(Token::Colon)?;
-            Some(test_expr)
+            (Some(test_expr), guard)
         } else if parser.check(&Token::Default) {
             parser.advance();
             parser.expect(Token::Colon)?;
-            None
+            (None, None)
         } else {
             return Err(parser.unexpected_token(&[Token::Case, Token::Default]));
         };
 
         // Parse consequent statements until next case/default/end
         let mut consequent = Vec::new();
+        let mut fallthrough = None;
         let mut consequent_guard = super::guards::LoopGuard::new("switch_case_consequent");
 
         while !parser.check(&Token::Case)
@@ -1222,20 +1340,43 @@ fn parse_switch_statement(parser: &mut Parser) -> Result<Statement, ParseError>
             && !parser.at_eof()
         {
             consequent_guard.check()?;
+            if let Some(span) = fallthrough {
+                return Err(ParseError::invalid_syntax(
+                    "'fallthrough' must be the last statement of a case",
+                    span,
+                ));
+            }
+            if is_fallthrough_statement(parser) {
+                fallthrough = Some(parser.current_span());
+                parser.advance();
+                if parser.check(&Token::Semicolon) {
+                    parser.advance();
+                }
+                continue;
+            }
             consequent.push(parse_switch_case_statement(parser)?);
         }
 
-        let case_end = if let Some(last) = consequent.last() {
-            *last.span()
-        } else {
-            parser.current_span()
+        if let (Some(span), true) = (fallthrough, parser.check(&Token::RightBrace)) {
+            return Err(ParseError::invalid_syntax(
+                "'fallthrough' cannot appear in the last case of a switch",
+                span,
+            ));
+        }
+
+        let case_end = match (fallthrough, consequent.last()) {
+            (Some(span), _) => span,
+            (None, Some(last)) => *last.span(),
+            (None, None) => parser.current_span(),
         };
 
         let case_span = parser.combine_spans(&case_start, &case_end);
 
         cases.push(SwitchCase {
             test,
+            guard,
             consequent,
+            fallthrough: fallthrough.is_some(),
             span: case_span,
         });
     }
@@ -1252,6 +1393,21 @@ fn parse_switch_statement(parser: &mut Parser) -> Result<Statement, ParseError>
     }))
 }
 
+/// Check if the current token is the contextual keyword `word`.
+fn is_contextual_keyword(parser: &Parser, word: &str) -> bool {
+    matches!(parser.current(), Token::Identifier(sym) if parser.resolve(*sym) == word)
+}
+
+/// `fallthrough` is contextual: it only counts as the statement when it
+/// stands alone, so `fallthrough = 1;` still assigns a variable.
+fn is_fallthrough_statement(parser: &Parser) -> bool {
+    is_contextual_keyword(parser, "fallthrough")
+        && matches!(
+            parser.peek(),
+            Some(Token::Semicolon | Token::Case | Token::Default | Token::RightBrace) | None
+        )
+}
+
 /// Parse a statement within a switch case.
 ///
 /// Switch cases allow block statements `case X: { ... }` which is different
@@ -1482,7 +1638,18 @@ fn parse_class_member(parser: &mut Parser) -> Result<ClassMember, ParseError> {
 
     // Parse annotations (//@@tag)
     let annotations = parse_annotations(parser)?;
+    let gates = feature_annotations(&annotations);
+    let member = parse_class_member_with_annotations(parser, start_span, annotations)?;
+    parser.record_feature_gates(&gates, *member.span());
+    Ok(member)
+}
 
+/// Parse the rest of a class member after its annotations
+fn parse_class_member_with_annotations(
+    parser: &mut Parser,
+    start_span: Span,
+    annotations: Vec<Annotation>,
+) -> Result<ClassMember, ParseError> {
     // Parse decorators (@decorator)
     let decorators = parse_decorators(parser)?;
 
@@ -1821,6 +1988,30 @@ fn parse_decorator(parser: &mut Parser) -> Result<Decorator, ParseError> {
 // Compiler Annotations
 // ============================================================================
 
+/// Attach annotations parsed ahead of a function or (exported) class declaration.
+fn attach_annotations(statement: &mut Statement, annotations: Vec<Annotation>) {
+    match statement {
+        Statement::FunctionDecl(func) => func.annotations = annotations,
+        Statement::ClassDecl(class) if class.annotations.is_empty() => {
+            class.annotations = annotations
+        }
+        Statement::ExportDecl(ExportDecl::Declaration(inner)) => {
+            attach_annotations(inner, annotations)
+        }
+        _ => {}
+    }
+}
+
+/// The `//@@if(feature)` annotations among `annotations`, kept to record
+/// against the declaration they precede once it is parsed.
+fn feature_annotations(annotations: &[Annotation]) -> Vec<Annotation> {
+    annotations
+        .iter()
+        .filter(|annotation| annotation.feature_condition().is_some())
+        .cloned()
+        .collect()
+}
+
 /// Parse compiler annotations (//@@tag or //@@tag value)
 fn parse_annotations(parser: &mut Parser) -> Result<Vec<Annotation>, ParseError> {
     let mut annotations = Vec::new();
@@ -2070,6 +2261,30 @@ fn parse_export_declaration(parser: &mut Parser) -> Result<Statement, ParseError
         // export default <expression>;
         parser.advance(); // consume 'default'
 
+        // export default class Foo {} / export default function foo() {}
+        // desugars to the declaration followed by `export default Foo;`
+        let declaration = match parser.current() {
+            Token::Class | Token::Abstract => Some(parse_class_declaration(parser)?),
+            Token::Function => Some(parse_function_declaration(parser)?),
+            Token::Async if matches!(parser.peek(), Some(Token::Function)) => {
+                Some(parse_function_declaration(parser)?)
+            }
+            _ => None,
+        };
+        if let Some(declaration) = declaration {
+            let name = match &declaration {
+                Statement::ClassDecl(class) => class.name.clone(),
+                Statement::FunctionDecl(func) => func.name.clone(),
+                _ => unreachable!("only class and function declarations are parsed here"),
+            };
+            let span = parser.combine_spans(&start_span, declaration.span());
+            parser.hoisted_statements.push(declaration);
+            return Ok(Statement::ExportDecl(ExportDecl::Default {
+                expression: Box::new(Expression::Identifier(name)),
+                span,
+            }));
+        }
+
         let expr = super::expr::parse_expression(parser)?;
 
         if parser.check(&Token::Semicolon) {
@@ -100,11 +100,18 @@ fn parse_intersection_type(parser: &mut Parser) -> Result<TypeAnnotation, ParseE
 fn parse_primary_type(parser: &mut Parser) -> Result<TypeAnnotation, ParseError> {
     let start_span = parser.current_span();
 
-    // Support readonly type modifier (e.g., `readonly unknown[]`) in type position.
-    // Runtime/type-checker currently treat this as a non-mutability-affecting wrapper.
+    // `readonly T[]` / `readonly [A, B]` mark the array or tuple immutable for the
+    // checker. On any other type the modifier is accepted and has no effect.
     if parser.check(&Token::Readonly) {
         parser.advance();
-        return parse_primary_type(parser);
+        let mut inner = parse_primary_type(parser)?;
+        match &mut inner.ty {
+            Type::Array(arr) => arr.readonly = true,
+            Type::Tuple(tuple) => tuple.readonly = true,
+            _ => {}
+        }
+        inner.span = parser.combine_spans(&start_span, &inner.span);
+        return Ok(inner);
     }
 
     let mut base_type = match parser.current() {
@@ -304,7 +311,7 @@ fn parse_primary_type(parser: &mut Parser) -> Result<TypeAnnotation, ParseError>
                 let span = parser.combine_spans(&start_span, &end_span);
 
                 TypeAnnotation {
-                    ty: Type::Tuple(TupleType { element_types }),
+                    ty: Type::Tuple(TupleType::new(element_types)),
                     span,
                 }
             } else {
@@ -315,9 +322,7 @@ fn parse_primary_type(parser: &mut Parser) -> Result<TypeAnnotation, ParseError>
                 let span = parser.combine_spans(&start_span, &first_element.span);
 
                 TypeAnnotation {
-                    ty: Type::Tuple(TupleType {
-                        element_types: vec![first_element],
-                    }),
+                    ty: Type::Tuple(TupleType::new(vec![first_element])),
                     span,
                 }
             }
@@ -428,9 +433,7 @@ fn parse_primary_type(parser: &mut Parser) -> Result<TypeAnnotation, ParseError>
             parser.advance(); // consume ]
             let span = parser.combine_spans(&start_span, &parser.current_span());
             base_type = TypeAnnotation {
-                ty: Type::Array(ArrayType {
-                    element_type: Box::new(base_type),
-                }),
+                ty: Type::Array(ArrayType::new(base_type)),
                 span,
             };
             continue;
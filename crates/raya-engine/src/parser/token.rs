@@ -79,6 +79,7 @@ pub enum Token {
     // Literals
     IntLiteral(i64),
     FloatLiteral(f64),
+    BigIntLiteral(Symbol), // Interned decimal digits (123n → "123")
     StringLiteral(Symbol), // Interned string
     TemplateLiteral(Vec<TemplatePart>),
     RegexLiteral(Symbol, Symbol), // (pattern, flags)
@@ -181,11 +182,20 @@ pub enum TemplatePart {
 }
 
 /// Source location information for a token.
+///
+/// `start`/`end` are byte offsets into the UTF-8 source. `line` and `column`
+/// are 1-based, with `column` counted in UTF-16 code units so it maps directly
+/// onto LSP positions. Use [`utf16_position`] and [`byte_offset_from_utf16`]
+/// to convert between the two.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Span {
+    /// Byte offset of the first byte.
     pub start: usize,
+    /// Byte offset one past the last byte.
     pub end: usize,
+    /// 1-based line of `start`.
     pub line: u32,
+    /// 1-based UTF-16 code-unit column of `start`.
     pub column: u32,
 }
 
@@ -211,6 +221,11 @@ impl Span {
         &source[self.start..self.end]
     }
 
+    /// 1-based `(line, column)` of `end`, with the column in UTF-16 code units.
+    pub fn end_position(&self, source: &str) -> (u32, u32) {
+        utf16_position(source, self.end)
+    }
+
     pub fn merge(&self, other: &Span) -> Span {
         Span {
             start: self.start.min(other.start),
@@ -221,6 +236,55 @@ impl Span {
     }
 }
 
+/// Convert a byte offset into a 1-based `(line, column)` pair, with the column
+/// counted in UTF-16 code units.
+///
+/// Offsets past the end of `source` are clamped; offsets inside a multibyte
+/// character resolve to the start of that character.
+pub fn utf16_position(source: &str, offset: usize) -> (u32, u32) {
+    let offset = offset.min(source.len());
+    let mut line = 1;
+    let mut column = 1;
+    for (index, ch) in source.char_indices() {
+        if index + ch.len_utf8() > offset {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += ch.len_utf16() as u32;
+        }
+    }
+    (line, column)
+}
+
+/// Convert a 1-based line and UTF-16 column back into a byte offset.
+///
+/// Returns `None` if the line does not exist, or if the column is past the end
+/// of the line or falls in the middle of a surrogate pair.
+pub fn byte_offset_from_utf16(source: &str, line: u32, column: u32) -> Option<usize> {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        source
+            .match_indices('\n')
+            .nth(line as usize - 2)
+            .map(|(index, _)| index + 1)?
+    };
+    let mut current = 1;
+    for (index, ch) in source[line_start..].char_indices() {
+        if current == column {
+            return Some(line_start + index);
+        }
+        if current > column || ch == '\n' {
+            return None;
+        }
+        current += ch.len_utf16() as u32;
+    }
+    (current == column).then_some(source.len())
+}
+
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -274,6 +338,7 @@ impl fmt::Display for Token {
             Token::Of => write!(f, "of"),
             Token::IntLiteral(n) => write!(f, "{}", n),
             Token::FloatLiteral(n) => write!(f, "{}", n),
+            Token::BigIntLiteral(_) => write!(f, "<bigint>n"),
             Token::StringLiteral(_) => write!(f, "\"<string>\""),
             Token::TemplateLiteral(_) => write!(f, "`...`"),
             Token::RegexLiteral(_, _) => write!(f, "/<regex>/"),
@@ -408,6 +473,7 @@ impl Token {
             self,
             Token::IntLiteral(_)
                 | Token::FloatLiteral(_)
+                | Token::BigIntLiteral(_)
                 | Token::StringLiteral(_)
                 | Token::TemplateLiteral(_)
                 | Token::RegexLiteral(_, _)
@@ -29,6 +29,20 @@ impl Default for TypeContext {
 }
 
 impl TypeContext {
+    /// Create a type context with all builtin classes and functions bound.
+    ///
+    /// The builtins are bound once per process and cached. Each call returns a
+    /// clone whose type storage is shared with the cache (types are `Arc`ed), so
+    /// repeated compiles only pay for copying the lookup tables. Pair it with
+    /// [`crate::parser::checker::Binder::with_builtins`] to get the matching
+    /// global scope; registering the builtins again on the result also reuses
+    /// the cached TypeIds.
+    pub fn with_builtins() -> Self {
+        crate::parser::checker::binder::bound_builtins()
+            .type_ctx
+            .clone()
+    }
+
     /// Create a new empty type context
     pub fn new() -> Self {
         let mut ctx = TypeContext {
@@ -107,11 +121,15 @@ impl TypeContext {
         // Pre-intern Array<Unknown> as canonical array dispatch type
         let array = ctx.intern(Type::Array(super::ty::ArrayType {
             element: unknown_id,
+            readonly: false,
         }));
         ctx.register_named_type("Array".into(), array);
 
         // JsonArray = Array<Json> — type alias for JSON arrays (runtime: Array)
-        let json_array = ctx.intern(Type::Array(super::ty::ArrayType { element: json }));
+        let json_array = ctx.intern(Type::Array(super::ty::ArrayType {
+            element: json,
+            readonly: false,
+        }));
         ctx.register_named_type("JsonArray".into(), json_array);
 
         // JsonObject = {[key: string]: Json} — dynamic string-keyed map
@@ -461,7 +479,18 @@ impl TypeContext {
 
     /// Create an array type
     pub fn array_type(&mut self, element: TypeId) -> TypeId {
-        self.intern(Type::Array(super::ty::ArrayType { element }))
+        self.intern(Type::Array(super::ty::ArrayType {
+            element,
+            readonly: false,
+        }))
+    }
+
+    /// Create a `readonly` array type
+    pub fn readonly_array_type(&mut self, element: TypeId) -> TypeId {
+        self.intern(Type::Array(super::ty::ArrayType {
+            element,
+            readonly: true,
+        }))
     }
 
     /// Create a task type (for async functions)
@@ -471,7 +500,18 @@ impl TypeContext {
 
     /// Create a tuple type
     pub fn tuple_type(&mut self, elements: Vec<TypeId>) -> TypeId {
-        self.intern(Type::Tuple(super::ty::TupleType { elements }))
+        self.intern(Type::Tuple(super::ty::TupleType {
+            elements,
+            readonly: false,
+        }))
+    }
+
+    /// Create a `readonly` tuple type
+    pub fn readonly_tuple_type(&mut self, elements: Vec<TypeId>) -> TypeId {
+        self.intern(Type::Tuple(super::ty::TupleType {
+            elements,
+            readonly: true,
+        }))
     }
 
     /// Create a symbolic keyof type (keyof T)
@@ -705,7 +745,12 @@ impl TypeContext {
                 format!("{}({}) => {}", async_prefix, params.join(", "), ret)
             }
             Type::Array(a) => {
-                format!("{}[]", self.format_type_depth(a.element, depth + 1))
+                let readonly = if a.readonly { "readonly " } else { "" };
+                format!(
+                    "{}{}[]",
+                    readonly,
+                    self.format_type_depth(a.element, depth + 1)
+                )
             }
             Type::Task(t) => {
                 format!("Promise<{}>", self.format_type_depth(t.result, depth + 1))
@@ -729,7 +774,8 @@ impl TypeContext {
                     .iter()
                     .map(|e| self.format_type_depth(*e, depth + 1))
                     .collect();
-                format!("[{}]", elems.join(", "))
+                let readonly = if t.readonly { "readonly " } else { "" };
+                format!("{}[{}]", readonly, elems.join(", "))
             }
             Type::Object(o) => {
                 let max_members = if depth == 0 { 6 } else { 3 };
@@ -854,6 +900,23 @@ impl TypeContext {
     pub fn is_empty(&self) -> bool {
         self.types.is_empty()
     }
+
+    /// Hash of every type and named type, in TypeId order.
+    ///
+    /// Two contexts with the same fingerprint assign the same TypeIds to the
+    /// same types.
+    pub fn fingerprint(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = rustc_hash::FxHasher::default();
+        for ty in &self.types {
+            ty.hash(&mut hasher);
+        }
+        let mut named: Vec<_> = self.named_types.iter().collect();
+        named.sort_by(|a, b| a.0.cmp(b.0));
+        named.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 #[cfg(test)]
@@ -861,6 +924,28 @@ mod tests {
     use super::*;
     use crate::parser::types::ty::{PrimitiveType, Type};
 
+    #[test]
+    fn test_with_builtins_shares_builtin_type_ids() {
+        let first = TypeContext::with_builtins();
+        let second = TypeContext::with_builtins();
+        let map = first.lookup_named_type("Map").expect("Map should be bound");
+        let error = first
+            .lookup_named_type("Error")
+            .expect("Error should be bound");
+        assert_eq!(second.lookup_named_type("Map"), Some(map));
+        assert_eq!(second.lookup_named_type("Error"), Some(error));
+        assert_eq!(first.len(), second.len());
+
+        // Every compile still registers builtins with its binder; that must
+        // resolve to the cached IDs rather than minting new ones.
+        let mut ctx = TypeContext::with_builtins();
+        let interner = crate::parser::Interner::new();
+        crate::parser::checker::Binder::new(&mut ctx, &interner)
+            .register_builtins(crate::builtins::checker_signatures());
+        assert_eq!(ctx.lookup_named_type("Map"), Some(map));
+        assert_eq!(ctx.lookup_named_type("Error"), Some(error));
+    }
+
     #[test]
     fn test_type_interning() {
         let mut ctx = TypeContext::new();
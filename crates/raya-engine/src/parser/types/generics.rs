@@ -100,7 +100,11 @@ impl<'a> GenericContext<'a> {
 
             Type::Array(arr) => {
                 let elem = self.apply_substitution(arr.element)?;
-                Ok(self.type_ctx.array_type(elem))
+                if arr.readonly {
+                    Ok(self.type_ctx.readonly_array_type(elem))
+                } else {
+                    Ok(self.type_ctx.array_type(elem))
+                }
             }
 
             Type::Task(task) => {
@@ -114,7 +118,11 @@ impl<'a> GenericContext<'a> {
                 for elem in elem_ids {
                     elements.push(self.apply_substitution(elem)?);
                 }
-                Ok(self.type_ctx.tuple_type(elements))
+                if tuple.readonly {
+                    Ok(self.type_ctx.readonly_tuple_type(elements))
+                } else {
+                    Ok(self.type_ctx.tuple_type(elements))
+                }
             }
 
             Type::Object(obj) => {
@@ -279,15 +287,25 @@ impl<'a> GenericContext<'a> {
                     substituted_args.push(substituted);
                 }
                 if !changed {
-                    Ok(ty)
-                } else {
-                    Ok(self
-                        .type_ctx
-                        .intern(Type::Reference(super::ty::TypeReference {
-                            name: reference.name,
-                            type_args: Some(substituted_args),
-                        })))
+                    return Ok(ty);
+                }
+                // Deferred utility applications (e.g. `Partial<T>`) resolve once
+                // their arguments are concrete, unless a named type shadows them.
+                if let Some(kind) = super::utility::UtilityType::from_name(&reference.name)
+                    .filter(|_| self.type_ctx.lookup_named_type(&reference.name).is_none())
+                {
+                    return Ok(super::utility::apply_utility_type(
+                        self.type_ctx,
+                        kind,
+                        &substituted_args,
+                    ));
                 }
+                Ok(self
+                    .type_ctx
+                    .intern(Type::Reference(super::ty::TypeReference {
+                        name: reference.name,
+                        type_args: Some(substituted_args),
+                    })))
             }
 
             // Other types don't contain type variables
@@ -12,6 +12,7 @@ pub mod normalize;
 pub mod signature;
 pub mod subtyping;
 pub mod ty;
+pub mod utility;
 
 pub use assignability::{AssignabilityContext, CoercionKind};
 pub use bare_union::{BareUnionDetector, BareUnionError, BareUnionInfo, BareUnionTransform};
@@ -26,3 +27,4 @@ pub use signature::{
 };
 pub use subtyping::SubtypingContext;
 pub use ty::{PrimitiveType, Type, TypeId};
+pub use utility::{apply_utility_type, UtilityType};
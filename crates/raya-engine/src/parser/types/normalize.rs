@@ -23,7 +23,11 @@ pub fn normalize_type(ctx: &mut TypeContext, ty: TypeId) -> TypeId {
         Type::Union(union) => normalize_union(ctx, &union.members),
         Type::Array(arr) => {
             let elem = normalize_type(ctx, arr.element);
-            ctx.array_type(elem)
+            if arr.readonly {
+                ctx.readonly_array_type(elem)
+            } else {
+                ctx.array_type(elem)
+            }
         }
         Type::Task(task) => {
             let result = normalize_type(ctx, task.result);
@@ -35,7 +39,11 @@ pub fn normalize_type(ctx: &mut TypeContext, ty: TypeId) -> TypeId {
                 .iter()
                 .map(|&e| normalize_type(ctx, e))
                 .collect();
-            ctx.tuple_type(elements)
+            if tuple.readonly {
+                ctx.readonly_tuple_type(elements)
+            } else {
+                ctx.tuple_type(elements)
+            }
         }
         Type::Function(func) => {
             let params: Vec<_> = func
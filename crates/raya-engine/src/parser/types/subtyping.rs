@@ -381,31 +381,40 @@ impl<'a> SubtypingContext<'a> {
                 props_match && methods_empty && call_match && construct_match
             }
 
-            // Array subtyping: T[] <: U[] if T <: U
-            (Type::Array(a1), Type::Array(a2)) => self.is_subtype(a1.element, a2.element),
+            // Array subtyping: T[] <: U[] if T <: U. A mutable array may flow
+            // into a `readonly` one, but not the other way around.
+            (Type::Array(a1), Type::Array(a2)) => {
+                (!a1.readonly || a2.readonly) && self.is_subtype(a1.element, a2.element)
+            }
 
             // Tuple-to-array subtyping: [T1, ..., Tn] <: U[] if every Ti <: U.
             // Needed so heterogeneous tuple-like literals remain assignable to
             // explicit array annotations such as `(A | B)[]`.
-            (Type::Tuple(tup), Type::Array(arr)) => tup
-                .elements
-                .iter()
-                .all(|&elem| self.is_subtype(elem, arr.element)),
+            (Type::Tuple(tup), Type::Array(arr)) => {
+                (!tup.readonly || arr.readonly)
+                    && tup
+                        .elements
+                        .iter()
+                        .all(|&elem| self.is_subtype(elem, arr.element))
+            }
 
             // Promise subtyping: Promise<T> <: Promise<U> if T <: U (covariant)
             (Type::Task(t1), Type::Task(t2)) => self.is_subtype(t1.result, t2.result),
 
             // Array-to-tuple subtyping: T[] <: [U1, U2, ..., Un] if T <: Ui for all i.
             // This allows array literals (typed as T[]) to satisfy tuple expectations.
-            (Type::Array(arr), Type::Tuple(tup)) => tup
-                .elements
-                .iter()
-                .all(|&elem| self.is_subtype(arr.element, elem)),
+            (Type::Array(arr), Type::Tuple(tup)) => {
+                (!arr.readonly || tup.readonly)
+                    && tup
+                        .elements
+                        .iter()
+                        .all(|&elem| self.is_subtype(arr.element, elem))
+            }
 
             // Tuple subtyping: [T1, T2, ..., Tn] <: [U1, U2, ..., Um]
             // if n = m and Ti <: Ui for all i
             (Type::Tuple(t1), Type::Tuple(t2)) => {
-                if t1.elements.len() != t2.elements.len() {
+                if t1.elements.len() != t2.elements.len() || (t1.readonly && !t2.readonly) {
                     return false;
                 }
 
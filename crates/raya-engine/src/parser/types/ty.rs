@@ -124,11 +124,13 @@ pub struct FunctionType {
     pub rest_param: Option<TypeId>,
 }
 
-/// Array type: T[]
+/// Array type: T[] or readonly T[]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ArrayType {
     /// Element type
     pub element: TypeId,
+    /// Whether the array is `readonly` (checked at compile time only)
+    pub readonly: bool,
 }
 
 /// Promise type: Promise<T> - represents an async computation that yields T
@@ -161,11 +163,13 @@ pub struct ChannelType {
     pub message: TypeId,
 }
 
-/// Tuple type: [T1, T2, ..., Tn]
+/// Tuple type: [T1, T2, ..., Tn] or readonly [T1, T2, ..., Tn]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TupleType {
     /// Element types
     pub elements: Vec<TypeId>,
+    /// Whether the tuple is `readonly` (checked at compile time only)
+    pub readonly: bool,
 }
 
 /// Object type property
@@ -421,7 +425,12 @@ impl fmt::Display for Type {
                     write!(f, "{}", func.return_type)
                 }
             }
-            Type::Array(a) => write!(f, "{}[]", a.element),
+            Type::Array(a) => {
+                if a.readonly {
+                    write!(f, "readonly ")?;
+                }
+                write!(f, "{}[]", a.element)
+            }
             Type::Task(t) => write!(f, "Promise<{}>", t.result),
             Type::Mutex => write!(f, "Mutex"),
             Type::RegExp => write!(f, "RegExp"),
@@ -432,6 +441,9 @@ impl fmt::Display for Type {
             Type::Buffer => write!(f, "Buffer"),
             Type::Json => write!(f, "json"),
             Type::Tuple(t) => {
+                if t.readonly {
+                    write!(f, "readonly ")?;
+                }
                 write!(f, "[")?;
                 for (i, elem) in t.elements.iter().enumerate() {
                     if i > 0 {
@@ -0,0 +1,293 @@
+//! Built-in utility types
+//!
+//! `Partial<T>`, `Required<T>`, `Readonly<T>`, `Pick<T, K>` and `Omit<T, K>`
+//! are resolved structurally into object types. An application whose target
+//! or keys are not concrete yet (e.g. a type variable) stays symbolic as a
+//! `Type::Reference` and is resolved again once substitution fills it in.
+
+use super::context::TypeContext;
+use super::ty::{ObjectType, PropertySignature, Type, TypeId, TypeReference};
+use crate::parser::ast::Visibility;
+
+/// Upper bound on `extends` chains walked while collecting members.
+const MAX_MEMBER_DEPTH: usize = 32;
+
+/// A built-in utility type constructor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UtilityType {
+    /// `Partial<T>`: every property optional
+    Partial,
+    /// `Required<T>`: every property required
+    Required,
+    /// `Readonly<T>`: every property readonly (arrays/tuples become `readonly`)
+    Readonly,
+    /// `Pick<T, K>`: only the properties named by `K`
+    Pick,
+    /// `Omit<T, K>`: every property except those named by `K`
+    Omit,
+}
+
+impl UtilityType {
+    /// Look up a utility type by name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Partial" => Some(UtilityType::Partial),
+            "Required" => Some(UtilityType::Required),
+            "Readonly" => Some(UtilityType::Readonly),
+            "Pick" => Some(UtilityType::Pick),
+            "Omit" => Some(UtilityType::Omit),
+            _ => None,
+        }
+    }
+
+    /// Type name as written in source
+    pub fn name(self) -> &'static str {
+        match self {
+            UtilityType::Partial => "Partial",
+            UtilityType::Required => "Required",
+            UtilityType::Readonly => "Readonly",
+            UtilityType::Pick => "Pick",
+            UtilityType::Omit => "Omit",
+        }
+    }
+
+    /// Number of type arguments
+    pub fn arity(self) -> usize {
+        match self {
+            UtilityType::Pick | UtilityType::Omit => 2,
+            UtilityType::Partial | UtilityType::Required | UtilityType::Readonly => 1,
+        }
+    }
+}
+
+/// Apply a utility type, keeping it symbolic when it cannot be resolved yet.
+pub fn apply_utility_type(ctx: &mut TypeContext, kind: UtilityType, args: &[TypeId]) -> TypeId {
+    resolve_utility_type(ctx, kind, args).unwrap_or_else(|| {
+        ctx.intern(Type::Reference(TypeReference {
+            name: kind.name().to_string(),
+            type_args: Some(args.to_vec()),
+        }))
+    })
+}
+
+/// Structurally resolve a utility type application.
+///
+/// Returns `None` if the argument count is wrong or the target/keys are not
+/// concrete object-like types and string literal keys.
+pub fn resolve_utility_type(
+    ctx: &mut TypeContext,
+    kind: UtilityType,
+    args: &[TypeId],
+) -> Option<TypeId> {
+    if args.len() != kind.arity() {
+        return None;
+    }
+    let target = args[0];
+
+    if kind == UtilityType::Readonly {
+        match ctx.get(target).cloned()? {
+            Type::Array(arr) => return Some(ctx.readonly_array_type(arr.element)),
+            Type::Tuple(tuple) => return Some(ctx.readonly_tuple_type(tuple.elements)),
+            _ => {}
+        }
+    }
+
+    let (mut properties, mut index_signature) = object_members(ctx, target, 0)?;
+    match kind {
+        UtilityType::Partial => properties.iter_mut().for_each(|p| p.optional = true),
+        UtilityType::Required => properties.iter_mut().for_each(|p| p.optional = false),
+        UtilityType::Readonly => properties.iter_mut().for_each(|p| p.readonly = true),
+        UtilityType::Pick => {
+            let keys = key_names(ctx, args[1])?;
+            properties.retain(|p| keys.contains(&p.name));
+            index_signature = None;
+        }
+        UtilityType::Omit => {
+            let keys = key_names(ctx, args[1])?;
+            properties.retain(|p| !keys.contains(&p.name));
+        }
+    }
+
+    Some(ctx.intern(Type::Object(ObjectType {
+        properties,
+        index_signature,
+        call_signatures: vec![],
+        construct_signatures: vec![],
+    })))
+}
+
+/// Public members of an object-like type as property signatures, with
+/// inherited members first and overrides replacing them in place.
+fn object_members(
+    ctx: &TypeContext,
+    ty: TypeId,
+    depth: usize,
+) -> Option<(Vec<PropertySignature>, Option<(String, TypeId)>)> {
+    if depth > MAX_MEMBER_DEPTH {
+        return None;
+    }
+    match ctx.get(ty)?.clone() {
+        Type::Object(obj) => Some((obj.properties, obj.index_signature)),
+        Type::Interface(iface) => {
+            let mut members = Vec::new();
+            for parent in iface.extends {
+                let (inherited, _) = object_members(ctx, parent, depth + 1)?;
+                merge_members(&mut members, inherited);
+            }
+            merge_members(&mut members, iface.properties);
+            let methods = iface.methods.into_iter().map(method_as_property).collect();
+            merge_members(&mut members, methods);
+            Some((members, None))
+        }
+        Type::Class(class) => {
+            let mut members = Vec::new();
+            if let Some(parent) = class.extends {
+                let (inherited, _) = object_members(ctx, parent, depth + 1)?;
+                merge_members(&mut members, inherited);
+            }
+            merge_members(&mut members, class.properties);
+            let methods = class.methods.into_iter().map(method_as_property).collect();
+            merge_members(&mut members, methods);
+            members.retain(|p| p.visibility == Visibility::Public);
+            Some((members, None))
+        }
+        Type::Reference(type_ref) if type_ref.type_args.is_none() => {
+            let named = ctx.lookup_named_type(&type_ref.name)?;
+            if named == ty {
+                return None;
+            }
+            object_members(ctx, named, depth + 1)
+        }
+        _ => None,
+    }
+}
+
+fn method_as_property(method: super::ty::MethodSignature) -> PropertySignature {
+    PropertySignature {
+        name: method.name,
+        ty: method.ty,
+        optional: false,
+        readonly: false,
+        visibility: method.visibility,
+    }
+}
+
+fn merge_members(members: &mut Vec<PropertySignature>, incoming: Vec<PropertySignature>) {
+    for prop in incoming {
+        if let Some(existing) = members.iter_mut().find(|p| p.name == prop.name) {
+            *existing = prop;
+        } else {
+            members.push(prop);
+        }
+    }
+}
+
+/// Property names selected by a `Pick`/`Omit` key argument.
+fn key_names(ctx: &TypeContext, keys: TypeId) -> Option<Vec<String>> {
+    match ctx.get(keys)? {
+        Type::StringLiteral(name) => Some(vec![name.clone()]),
+        Type::Union(union) => union
+            .members
+            .iter()
+            .map(|&member| match ctx.get(member)? {
+                Type::StringLiteral(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect(),
+        Type::Never => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(ctx: &mut TypeContext, props: &[(&str, TypeId, bool)]) -> TypeId {
+        ctx.intern(Type::Object(ObjectType {
+            properties: props
+                .iter()
+                .map(|&(name, ty, optional)| PropertySignature {
+                    name: name.to_string(),
+                    ty,
+                    optional,
+                    readonly: false,
+                    visibility: Visibility::Public,
+                })
+                .collect(),
+            index_signature: None,
+            call_signatures: vec![],
+            construct_signatures: vec![],
+        }))
+    }
+
+    fn properties(ctx: &TypeContext, ty: TypeId) -> Vec<(String, bool, bool)> {
+        match ctx.get(ty) {
+            Some(Type::Object(obj)) => obj
+                .properties
+                .iter()
+                .map(|p| (p.name.clone(), p.optional, p.readonly))
+                .collect(),
+            other => panic!("expected object type, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_partial_and_required_toggle_optional() {
+        let mut ctx = TypeContext::new();
+        let num = ctx.number_type();
+        let target = object(&mut ctx, &[("a", num, false), ("b", num, true)]);
+
+        let partial = apply_utility_type(&mut ctx, UtilityType::Partial, &[target]);
+        assert_eq!(
+            properties(&ctx, partial),
+            vec![("a".into(), true, false), ("b".into(), true, false)]
+        );
+
+        let required = apply_utility_type(&mut ctx, UtilityType::Required, &[target]);
+        assert_eq!(
+            properties(&ctx, required),
+            vec![("a".into(), false, false), ("b".into(), false, false)]
+        );
+    }
+
+    #[test]
+    fn test_pick_and_omit_select_keys() {
+        let mut ctx = TypeContext::new();
+        let num = ctx.number_type();
+        let target = object(&mut ctx, &[("a", num, false), ("b", num, false)]);
+        let key_a = ctx.string_literal("a");
+
+        let picked = apply_utility_type(&mut ctx, UtilityType::Pick, &[target, key_a]);
+        assert_eq!(properties(&ctx, picked), vec![("a".into(), false, false)]);
+
+        let omitted = apply_utility_type(&mut ctx, UtilityType::Omit, &[target, key_a]);
+        assert_eq!(properties(&ctx, omitted), vec![("b".into(), false, false)]);
+    }
+
+    #[test]
+    fn test_readonly_marks_properties_and_arrays() {
+        let mut ctx = TypeContext::new();
+        let num = ctx.number_type();
+        let target = object(&mut ctx, &[("a", num, false)]);
+
+        let readonly = apply_utility_type(&mut ctx, UtilityType::Readonly, &[target]);
+        assert_eq!(properties(&ctx, readonly), vec![("a".into(), false, true)]);
+
+        let array = ctx.array_type(num);
+        let readonly_array = apply_utility_type(&mut ctx, UtilityType::Readonly, &[array]);
+        assert_eq!(readonly_array, ctx.readonly_array_type(num));
+    }
+
+    #[test]
+    fn test_generic_application_stays_symbolic() {
+        let mut ctx = TypeContext::new();
+        let t = ctx.type_variable("T");
+
+        let partial = apply_utility_type(&mut ctx, UtilityType::Partial, &[t]);
+        assert!(matches!(
+            ctx.get(partial),
+            Some(Type::Reference(type_ref)) if type_ref.name == "Partial"
+        ));
+    }
+}
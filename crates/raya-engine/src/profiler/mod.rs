@@ -7,6 +7,7 @@
 //!
 //! - **cpuprofile**: Chrome DevTools JSON (viewable in Chrome, VS Code, speedscope.app)
 //! - **flamegraph**: Brendan Gregg folded stacks (for flamegraph.pl / speedscope)
+//! - **heapsnapshot**: Chrome DevTools heap snapshot JSON (from a GC heap graph)
 
 pub mod output;
 
@@ -180,6 +181,11 @@ impl Profiler {
         self.enabled.store(true, Ordering::Release);
     }
 
+    /// Suspend sampling without discarding captured samples; [`start`] resumes.
+    pub fn pause(&self) {
+        self.enabled.store(false, Ordering::Release);
+    }
+
     /// Stop profiling and drain all captured samples.
     pub fn stop(&self) -> ProfileData {
         self.enabled.store(false, Ordering::Release);
@@ -396,6 +402,22 @@ mod tests {
         assert_eq!(data.start_time_us, 0);
     }
 
+    #[test]
+    fn test_profiler_pause_keeps_samples() {
+        let profiler = Profiler::new(ProfileConfig::default());
+        profiler.start();
+        let _ = profiler.tx.try_send(StackSample {
+            timestamp_us: 1,
+            task_id: 0,
+            frames: Vec::new(),
+        });
+        profiler.pause();
+        assert!(!profiler.is_enabled());
+        profiler.start();
+        assert!(profiler.is_enabled());
+        assert_eq!(profiler.stop().samples.len(), 1);
+    }
+
     #[test]
     fn test_profiler_stop_drains_samples() {
         let profiler = Profiler::new(ProfileConfig::default());
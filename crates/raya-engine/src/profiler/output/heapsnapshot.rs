@@ -0,0 +1,216 @@
+//! Chrome DevTools `.heapsnapshot` JSON output.
+//!
+//! Loadable in the Chrome DevTools Memory panel, which computes dominators and
+//! retained sizes from the node/edge graph.
+
+use crate::vm::gc::{HeapEdgeKind, HeapGraph, HeapNodeKind};
+use rustc_hash::FxHashMap;
+use serde_json::json;
+
+/// Node fields, in the order they appear in the flat `nodes` array.
+const NODE_FIELDS: [&str; 7] = [
+    "type",
+    "name",
+    "id",
+    "self_size",
+    "edge_count",
+    "trace_node_id",
+    "detachedness",
+];
+
+/// Node type names; a node's `type` field indexes into this list.
+const NODE_TYPES: [&str; 10] = [
+    "hidden",
+    "array",
+    "string",
+    "object",
+    "code",
+    "closure",
+    "regexp",
+    "number",
+    "native",
+    "synthetic",
+];
+
+const NODE_TYPE_STRING: usize = 2;
+const NODE_TYPE_OBJECT: usize = 3;
+const NODE_TYPE_CLOSURE: usize = 5;
+const NODE_TYPE_NATIVE: usize = 8;
+const NODE_TYPE_SYNTHETIC: usize = 9;
+
+/// Edge fields, in the order they appear in the flat `edges` array.
+const EDGE_FIELDS: [&str; 3] = ["type", "name_or_index", "to_node"];
+
+/// Edge type names; an edge's `type` field indexes into this list.
+const EDGE_TYPES: [&str; 7] = [
+    "context", "element", "property", "internal", "hidden", "shortcut", "weak",
+];
+
+const EDGE_TYPE_ELEMENT: usize = 1;
+const EDGE_TYPE_PROPERTY: usize = 2;
+
+/// Interned string table shared by node names and property edge names.
+#[derive(Default)]
+struct StringTable {
+    strings: Vec<String>,
+    index: FxHashMap<String, usize>,
+}
+
+impl StringTable {
+    fn intern(&mut self, value: &str) -> usize {
+        if let Some(&index) = self.index.get(value) {
+            return index;
+        }
+        let index = self.strings.len();
+        self.strings.push(value.to_string());
+        self.index.insert(value.to_string(), index);
+        index
+    }
+}
+
+impl HeapGraph {
+    /// Convert to Chrome DevTools `.heapsnapshot` JSON string.
+    ///
+    /// A synthetic `(GC roots)` node is emitted first with one edge per root.
+    pub fn to_heapsnapshot_json(&self) -> String {
+        let field_count = NODE_FIELDS.len();
+        let mut strings = StringTable::default();
+        let mut nodes = Vec::with_capacity((self.nodes.len() + 1) * field_count);
+        let mut edges = Vec::new();
+
+        // Snapshot node ids must be unique; odd ids mirror V8's convention for
+        // heap objects.
+        let node_id = |index: usize| (index * 2 + 1) as u64;
+        let to_node = |graph_index: usize| ((graph_index + 1) * field_count) as u64;
+
+        let root_name = strings.intern("(GC roots)");
+        nodes.extend([
+            NODE_TYPE_SYNTHETIC as u64,
+            root_name as u64,
+            node_id(0),
+            0,
+            self.roots.len() as u64,
+            0,
+            0,
+        ]);
+        for (position, &root) in self.roots.iter().enumerate() {
+            edges.extend([EDGE_TYPE_ELEMENT as u64, position as u64, to_node(root)]);
+        }
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let node_type = match node.kind {
+                HeapNodeKind::Object | HeapNodeKind::Array => NODE_TYPE_OBJECT,
+                HeapNodeKind::String => NODE_TYPE_STRING,
+                HeapNodeKind::Closure => NODE_TYPE_CLOSURE,
+                HeapNodeKind::Native => NODE_TYPE_NATIVE,
+            };
+            let name = strings.intern(&node.type_name);
+            nodes.extend([
+                node_type as u64,
+                name as u64,
+                node_id(index + 1),
+                node.self_size as u64,
+                node.edges.len() as u64,
+                0,
+                0,
+            ]);
+            for edge in &node.edges {
+                let (edge_type, name_or_index) = match &edge.kind {
+                    HeapEdgeKind::Element(position) => (EDGE_TYPE_ELEMENT, *position),
+                    HeapEdgeKind::Property(name) => (EDGE_TYPE_PROPERTY, strings.intern(name)),
+                };
+                edges.extend([edge_type as u64, name_or_index as u64, to_node(edge.to)]);
+            }
+        }
+
+        let snapshot = json!({
+            "snapshot": {
+                "meta": {
+                    "node_fields": NODE_FIELDS,
+                    "node_types": [NODE_TYPES, "string", "number", "number", "number", "number", "number"],
+                    "edge_fields": EDGE_FIELDS,
+                    "edge_types": [EDGE_TYPES, "string_or_number", "node"],
+                    "trace_function_info_fields": [],
+                    "trace_node_fields": [],
+                    "sample_fields": [],
+                    "location_fields": [],
+                },
+                "node_count": self.nodes.len() + 1,
+                "edge_count": edges.len() / EDGE_FIELDS.len(),
+                "trace_function_count": 0,
+            },
+            "nodes": nodes,
+            "edges": edges,
+            "trace_function_infos": [],
+            "trace_tree": [],
+            "samples": [],
+            "locations": [],
+            "strings": strings.strings,
+        });
+        serde_json::to_string(&snapshot).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::vm::gc::{HeapEdge, HeapEdgeKind, HeapGraph, HeapNode, HeapNodeKind};
+
+    fn node(kind: HeapNodeKind, name: &str, size: usize, edges: Vec<HeapEdge>) -> HeapNode {
+        HeapNode {
+            address: 0,
+            kind,
+            type_name: name.to_string(),
+            self_size: size,
+            edges,
+        }
+    }
+
+    #[test]
+    fn test_empty_graph_has_root_only() {
+        let json = HeapGraph::default().to_heapsnapshot_json();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["snapshot"]["node_count"], 1);
+        assert_eq!(parsed["nodes"].as_array().unwrap().len(), 7);
+        assert_eq!(parsed["strings"][0], "(GC roots)");
+    }
+
+    #[test]
+    fn test_nodes_and_edges_are_flattened() {
+        let graph = HeapGraph {
+            nodes: vec![
+                node(
+                    HeapNodeKind::Array,
+                    "Array",
+                    8000,
+                    vec![HeapEdge {
+                        kind: HeapEdgeKind::Element(0),
+                        to: 1,
+                    }],
+                ),
+                node(HeapNodeKind::String, "RayaString", 40, vec![]),
+            ],
+            roots: vec![0],
+        };
+        let parsed: serde_json::Value =
+            serde_json::from_str(&graph.to_heapsnapshot_json()).unwrap();
+        let nodes = parsed["nodes"].as_array().unwrap();
+        let edges = parsed["edges"].as_array().unwrap();
+        let strings = parsed["strings"].as_array().unwrap();
+
+        assert_eq!(parsed["snapshot"]["node_count"], 3);
+        assert_eq!(parsed["snapshot"]["edge_count"], 2);
+        // Root edge points at the array node (second node, offset 7).
+        assert_eq!(edges[2], 7);
+        // Array node: object type, named "Array", self size 8000, one edge.
+        assert_eq!(nodes[7], 3);
+        assert_eq!(strings[nodes[8].as_u64().unwrap() as usize], "Array");
+        assert_eq!(nodes[10], 8000);
+        assert_eq!(nodes[11], 1);
+        // Array element edge points at the string node (offset 14).
+        assert_eq!(edges[5], 14);
+    }
+}
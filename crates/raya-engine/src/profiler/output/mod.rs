@@ -2,3 +2,4 @@
 
 pub mod cpuprofile;
 pub mod flamegraph;
+pub mod heapsnapshot;
@@ -10,15 +10,24 @@
 //! Conversion is zero-cost via `value_to_native`/`native_to_value`.
 
 use parking_lot::{Mutex, RwLock};
+use std::cell::Cell;
+use std::marker::PhantomData;
 use std::ptr::NonNull;
 #[allow(unused_imports)]
 use std::sync::Arc;
+use std::time::Instant;
 
-use raya_sdk::{AbiResult, ClassInfo, NativeContext, NativeValue};
+use raya_sdk::{
+    AbiResult, ClassInfo, HandleKind, HandleReservation, HeapHooks, NativeContext, NativeValue,
+};
 
+use crate::vm::ffi::{pin_value, unpin_value};
 use crate::vm::gc::GarbageCollector as Gc;
-use crate::vm::interpreter::{ClassRegistry, RuntimeLayoutRegistry};
-use crate::vm::object::{Array, Buffer, ChannelObject, Class, Object, RayaString};
+use crate::vm::interpreter::{ClassRegistry, HandleLimits, RuntimeLayoutRegistry};
+use crate::vm::json::{js_classify, JSView};
+use crate::vm::object::{
+    global_layout_names, Array, Buffer, ChannelObject, Class, Object, RayaString,
+};
 use crate::vm::reflect::ClassMetadataRegistry;
 use crate::vm::scheduler::TaskId;
 use crate::vm::value::Value;
@@ -39,6 +48,101 @@ pub fn native_to_value(val: NativeValue) -> Value {
     unsafe { Value::from_raw(val.to_bits()) }
 }
 
+// ============================================================================
+// SDK Heap Hooks
+// ============================================================================
+
+thread_local! {
+    /// Heap that SDK `String`/`Vec` conversions allocate into on this thread
+    static NATIVE_HEAP_GC: Cell<Option<NonNull<Mutex<Gc>>>> = const { Cell::new(None) };
+}
+
+/// Routes `raya_sdk::ToRaya` heap allocations on this thread to `gc` for
+/// as long as the scope is alive (i.e. for the duration of a native call).
+pub struct NativeHeapScope<'a> {
+    previous: Option<NonNull<Mutex<Gc>>>,
+    _gc: PhantomData<&'a Mutex<Gc>>,
+}
+
+impl<'a> NativeHeapScope<'a> {
+    /// Enter a scope allocating into `gc`, installing the SDK hooks if needed.
+    pub fn enter(gc: &'a Mutex<Gc>) -> Self {
+        raya_sdk::install_heap_hooks(HeapHooks {
+            read_string: read_sdk_string,
+            create_string: create_sdk_string,
+            visit_array: visit_sdk_array,
+            create_array: create_sdk_array,
+        });
+        let previous = NATIVE_HEAP_GC.with(|cell| cell.replace(Some(NonNull::from(gc))));
+        Self {
+            previous,
+            _gc: PhantomData,
+        }
+    }
+}
+
+impl Drop for NativeHeapScope<'_> {
+    fn drop(&mut self) {
+        NATIVE_HEAP_GC.with(|cell| cell.set(self.previous));
+    }
+}
+
+/// Allocate `obj` in the current scope's heap, or `None` outside a scope.
+fn alloc_in_scope<T: 'static>(obj: T) -> Option<NativeValue> {
+    let gc = NATIVE_HEAP_GC.with(Cell::get)?;
+    // SAFETY: the pointer was set by a live `NativeHeapScope`, which
+    // borrows the GC for its whole lifetime and clears it on drop.
+    let gc = unsafe { gc.as_ref() };
+    let gc_ptr = gc.lock().allocate(obj);
+    let ptr = NonNull::new(gc_ptr.as_ptr()).unwrap();
+    Some(value_to_native(unsafe { Value::from_ptr(ptr) }))
+}
+
+fn read_sdk_string(val: NativeValue) -> Option<String> {
+    let v = native_to_value(val);
+    if !v.is_ptr() {
+        return None;
+    }
+    pin_value(val);
+    let contents = match js_classify(v) {
+        JSView::Str(s_ptr) => Some(unsafe { &*s_ptr }.as_str().to_string()),
+        _ => None,
+    };
+    unpin_value(val);
+    contents
+}
+
+fn create_sdk_string(s: &str) -> Option<NativeValue> {
+    alloc_in_scope(RayaString::new(s.to_string()))
+}
+
+fn visit_sdk_array(val: NativeValue, visit: &mut dyn FnMut(&[NativeValue])) -> bool {
+    let v = native_to_value(val);
+    if !v.is_ptr() {
+        return false;
+    }
+    let JSView::Arr(arr_ptr) = js_classify(v) else {
+        return false;
+    };
+    pin_value(val);
+    let array = unsafe { &*arr_ptr };
+    let items: Vec<NativeValue> = (0..array.len())
+        .filter_map(|i| array.get(i))
+        .map(value_to_native)
+        .collect();
+    visit(&items);
+    unpin_value(val);
+    true
+}
+
+fn create_sdk_array(items: &[NativeValue]) -> Option<NativeValue> {
+    let mut arr = Array::new(0, 0);
+    for item in items {
+        arr.push(native_to_value(*item));
+    }
+    alloc_in_scope(arr)
+}
+
 // ============================================================================
 // EngineContext
 // ============================================================================
@@ -65,6 +169,18 @@ pub struct EngineContext<'a> {
 
     /// Reflect metadata for field/method name lookups
     pub(crate) class_metadata: &'a RwLock<ClassMetadataRegistry>,
+
+    /// Open file/socket limits (None = unlimited)
+    pub(crate) handle_limits: Option<&'a HandleLimits>,
+
+    /// Execution timeout deadline (None = no timeout)
+    pub(crate) deadline: Option<Instant>,
+
+    /// Arguments passed to the running program (None = no arguments)
+    pub(crate) program_args: Option<&'a RwLock<Vec<String>>>,
+
+    /// Callbacks run when the program exits (None = exit hooks unsupported)
+    pub(crate) exit_hooks: Option<&'a Mutex<Vec<Value>>>,
 }
 
 impl<'a> EngineContext<'a> {
@@ -83,9 +199,37 @@ impl<'a> EngineContext<'a> {
             layouts,
             current_task,
             class_metadata,
+            handle_limits: None,
+            deadline: None,
+            program_args: None,
+            exit_hooks: None,
         }
     }
 
+    /// Enforce the VM's open file/socket limits on native calls
+    pub fn with_handle_limits(mut self, handle_limits: &'a HandleLimits) -> Self {
+        self.handle_limits = Some(handle_limits);
+        self
+    }
+
+    /// Expose the VM's execution timeout deadline to native calls
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Expose the VM's program arguments to native calls
+    pub fn with_program_args(mut self, program_args: &'a RwLock<Vec<String>>) -> Self {
+        self.program_args = Some(program_args);
+        self
+    }
+
+    /// Let native calls register callbacks run when the program exits
+    pub fn with_exit_hooks(mut self, exit_hooks: &'a Mutex<Vec<Value>>) -> Self {
+        self.exit_hooks = Some(exit_hooks);
+        self
+    }
+
     /// Allocate a GC pointer and wrap as NativeValue
     fn alloc_ptr<T: 'static>(&self, obj: T) -> NativeValue {
         let gc_ptr = self.gc.lock().allocate(obj);
@@ -250,10 +394,11 @@ impl NativeContext for EngineContext<'_> {
         if !v.is_ptr() {
             return Err("Expected string, got non-pointer".into());
         }
-        let s_ptr =
-            unsafe { v.as_ptr::<RayaString>() }.ok_or_else(|| "Expected string".to_string())?;
-        let s = unsafe { &*s_ptr.as_ptr() };
-        Ok(s.data.clone())
+        let JSView::Str(s_ptr) = js_classify(v) else {
+            return Err("Expected string".into());
+        };
+        let s = unsafe { &*s_ptr };
+        Ok(s.as_str().to_string())
     }
 
     fn read_buffer(&self, val: NativeValue) -> AbiResult<Vec<u8>> {
@@ -276,8 +421,10 @@ impl NativeContext for EngineContext<'_> {
         if !v.is_ptr() {
             return Err("Expected Array, got non-pointer".into());
         }
-        let arr_ptr = unsafe { v.as_ptr::<Array>() }.ok_or_else(|| "Expected Array".to_string())?;
-        let array = unsafe { &*arr_ptr.as_ptr() };
+        let JSView::Arr(arr_ptr) = js_classify(v) else {
+            return Err("Expected Array".into());
+        };
+        let array = unsafe { &*arr_ptr };
         Ok(array.len())
     }
 
@@ -286,8 +433,10 @@ impl NativeContext for EngineContext<'_> {
         if !v.is_ptr() {
             return Err("Expected Array, got non-pointer".into());
         }
-        let arr_ptr = unsafe { v.as_ptr::<Array>() }.ok_or_else(|| "Expected Array".to_string())?;
-        let array = unsafe { &*arr_ptr.as_ptr() };
+        let JSView::Arr(arr_ptr) = js_classify(v) else {
+            return Err("Expected Array".into());
+        };
+        let array = unsafe { &*arr_ptr };
         array.get(index).map(value_to_native).ok_or_else(|| {
             format!("Array index {} out of bounds (len={})", index, array.len()).into()
         })
@@ -339,6 +488,41 @@ impl NativeContext for EngineContext<'_> {
             .ok_or_else(|| "Object has no nominal class identity".into())
     }
 
+    fn object_entries(&self, val: NativeValue) -> AbiResult<Vec<(String, NativeValue)>> {
+        let JSView::Struct {
+            ptr,
+            layout_id,
+            nominal_type_id,
+        } = js_classify(native_to_value(val))
+        else {
+            return Err("Expected Object".into());
+        };
+        let obj = unsafe { &*ptr };
+        let names = self
+            .layouts
+            .read()
+            .layout_field_names(layout_id)
+            .map(<[String]>::to_vec)
+            .or_else(|| global_layout_names(layout_id));
+        let names = match (names, nominal_type_id) {
+            (Some(names), _) => names,
+            (None, Some(nominal_type_id)) => self
+                .nominal_type_field_names(nominal_type_id as usize)?
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect(),
+            (None, None) => return Err("Object has no field names".into()),
+        };
+        Ok(names
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let value = obj.get_field(index).unwrap_or(Value::null());
+                (name, value_to_native(value))
+            })
+            .collect())
+    }
+
     // ========================================================================
     // Class Operations
     // ========================================================================
@@ -497,6 +681,58 @@ impl NativeContext for EngineContext<'_> {
             true
         }
     }
+
+    // ========================================================================
+    // Host Resource Accounting
+    // ========================================================================
+
+    fn reserve_handle(&self, kind: HandleKind) -> AbiResult<HandleReservation> {
+        match self.handle_limits {
+            Some(limits) => limits.reserve(kind).map_err(|e| e.to_string().into()),
+            None => Ok(HandleReservation::unlimited()),
+        }
+    }
+
+    fn release_handle(&self, kind: HandleKind) {
+        if let Some(limits) = self.handle_limits {
+            limits.release(kind);
+        }
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    // ========================================================================
+    // Program Environment
+    // ========================================================================
+
+    fn program_args(&self) -> Vec<String> {
+        self.program_args
+            .map(|args| args.read().clone())
+            .unwrap_or_default()
+    }
+
+    fn register_exit_hook(&self, hook: NativeValue) -> AbiResult<()> {
+        let Some(exit_hooks) = self.exit_hooks else {
+            return Err("exit hooks are not supported in this context".into());
+        };
+        let hook = native_to_value(hook);
+        if !hook.is_ptr() {
+            return Err("exit hook must be a function".into());
+        }
+        exit_hooks.lock().push(hook);
+        Ok(())
+    }
+
+    fn take_exit_hooks(&self) -> Vec<NativeValue> {
+        self.exit_hooks
+            .map(|hooks| std::mem::take(&mut *hooks.lock()))
+            .unwrap_or_default()
+            .into_iter()
+            .map(value_to_native)
+            .collect()
+    }
 }
 
 impl EngineContext<'_> {
@@ -554,7 +790,7 @@ pub fn string_read(val: NativeValue) -> AbiResult<String> {
     }
     let s_ptr = unsafe { v.as_ptr::<RayaString>() }.ok_or_else(|| "Expected string".to_string())?;
     let s = unsafe { &*s_ptr.as_ptr() };
-    Ok(s.data.clone())
+    Ok(s.as_str().to_string())
 }
 
 /// Allocate a new String
@@ -669,3 +905,92 @@ pub fn task_cancel(_ctx: &EngineContext<'_>, _task_id: u64) -> AbiResult<()> {
 pub fn task_is_done(_ctx: &EngineContext<'_>, _task_id: u64) -> AbiResult<bool> {
     Err("task_is_done not yet implemented".into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raya_sdk::{FromRaya, NativeError, ToRaya};
+
+    #[test]
+    fn test_sdk_string_round_trip_multibyte() {
+        let gc = Mutex::new(Gc::default());
+        let _heap = NativeHeapScope::enter(&gc);
+
+        let text = "héllo, 世界 🚀";
+        let value = text.to_raya();
+        assert!(value.is_ptr());
+        assert_eq!(String::from_raya(value).unwrap(), text);
+
+        let owned = String::from("ça marche — ✓").to_raya();
+        assert_eq!(String::from_raya(owned).unwrap(), "ça marche — ✓");
+    }
+
+    #[test]
+    fn test_sdk_string_rejects_non_string_pointer() {
+        let gc = Mutex::new(Gc::default());
+        let _heap = NativeHeapScope::enter(&gc);
+
+        let gc_ptr = gc.lock().allocate(Array::new(0, 0));
+        let ptr = NonNull::new(gc_ptr.as_ptr()).unwrap();
+        let array = value_to_native(unsafe { Value::from_ptr(ptr) });
+        assert!(matches!(
+            String::from_raya(array),
+            Err(NativeError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sdk_vec_round_trip() {
+        let gc = Mutex::new(Gc::default());
+        let _heap = NativeHeapScope::enter(&gc);
+
+        let value = vec![1, -2, 3].to_raya();
+        assert_eq!(Vec::<i32>::from_raya(value).unwrap(), vec![1, -2, 3]);
+
+        let nested = vec![vec![1, 2], vec![], vec![3]];
+        let value = nested.clone().to_raya();
+        assert_eq!(Vec::<Vec<i32>>::from_raya(value).unwrap(), nested);
+
+        let empty: Vec<i32> = Vec::new();
+        assert!(Vec::<i32>::from_raya(empty.to_raya()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sdk_vec_rejects_mismatched_elements() {
+        let gc = Mutex::new(Gc::default());
+        let _heap = NativeHeapScope::enter(&gc);
+
+        let mut mixed = Array::new(0, 0);
+        mixed.push(Value::i32(1));
+        mixed.push(Value::bool(true));
+        let value = alloc_in_scope(mixed).unwrap();
+        match Vec::<i32>::from_raya(value) {
+            Err(NativeError::TypeMismatch { expected, got }) => {
+                assert_eq!(expected, "i32");
+                assert_eq!(got, "bool");
+            }
+            other => panic!("expected TypeMismatch, got {:?}", other),
+        }
+
+        assert!(matches!(
+            Vec::<i32>::from_raya("not an array".to_raya()),
+            Err(NativeError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            Vec::<i32>::from_raya(NativeValue::i32(4)),
+            Err(NativeError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sdk_string_scope_is_cleared_on_drop() {
+        let gc = Mutex::new(Gc::default());
+        drop(NativeHeapScope::enter(&gc));
+        let orphan = "orphan".to_raya();
+        assert!(orphan.is_error());
+        assert_eq!(
+            orphan.take_error_message(),
+            Some("cannot allocate a string outside a native call".to_string())
+        );
+    }
+}
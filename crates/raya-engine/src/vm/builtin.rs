@@ -144,6 +144,9 @@ pub mod array {
     pub const NEW: u16 = 0x0116;
     /// `arr.splice(start, deleteCount, ...items)` - Remove/add elements
     pub const SPLICE: u16 = 0x0117;
+    /// `Array.withCapacity(n)` / strict `new Array(n)` - Reserve room for `n` elements (a hint,
+    /// not a length)
+    pub const RESERVE: u16 = 0x0118;
 }
 
 /// Built-in method IDs for strings
@@ -260,10 +263,46 @@ pub mod buffer {
     pub const SLICE: u16 = 0x0708;
     /// `buf.copy(target, targetStart, sourceStart, sourceEnd)` - Copy bytes
     pub const COPY: u16 = 0x0709;
-    /// `buf.toString(encoding)` - Convert to string
+    /// `buf.toString(encoding, fatal)` - Decode to string (also `decodeUtf8`/`decodeUtf16LE`)
     pub const TO_STRING: u16 = 0x070A;
-    /// `Buffer.fromString(str, encoding)` - Create from string
+    /// `Buffer.fromString(str, encoding)` - Encode a string (also `encodeUtf8`/`encodeUtf16LE`)
     pub const FROM_STRING: u16 = 0x070B;
+    /// `buf.getUint8(index)` - Get unsigned byte, bounds-checked
+    pub const GET_UINT8: u16 = 0x070C;
+    /// `buf.setUint8(index, value)` - Set unsigned byte
+    pub const SET_UINT8: u16 = 0x070D;
+    /// `buf.getInt16LE(index)` - Get little-endian int16
+    pub const GET_INT16_LE: u16 = 0x070E;
+    /// `buf.getInt16BE(index)` - Get big-endian int16
+    pub const GET_INT16_BE: u16 = 0x070F;
+    /// `buf.setInt16LE(index, value)` - Set little-endian int16
+    pub const SET_INT16_LE: u16 = 0x0710;
+    /// `buf.setInt16BE(index, value)` - Set big-endian int16
+    pub const SET_INT16_BE: u16 = 0x0711;
+    /// `buf.getUint16LE(index)` - Get little-endian uint16
+    pub const GET_UINT16_LE: u16 = 0x0712;
+    /// `buf.getUint16BE(index)` - Get big-endian uint16
+    pub const GET_UINT16_BE: u16 = 0x0713;
+    /// `buf.setUint16LE(index, value)` - Set little-endian uint16
+    pub const SET_UINT16_LE: u16 = 0x0714;
+    /// `buf.setUint16BE(index, value)` - Set big-endian uint16
+    pub const SET_UINT16_BE: u16 = 0x0715;
+    /// `buf.getInt32LE(index)` - Get little-endian int32
+    pub const GET_INT32_LE: u16 = 0x0716;
+    /// `buf.getInt32BE(index)` - Get big-endian int32
+    pub const GET_INT32_BE: u16 = 0x0717;
+    /// `buf.setInt32LE(index, value)` - Set little-endian int32
+    pub const SET_INT32_LE: u16 = 0x0718;
+    /// `buf.setInt32BE(index, value)` - Set big-endian int32
+    pub const SET_INT32_BE: u16 = 0x0719;
+    /// `buf.getFloat64LE(index)` - Get little-endian float64
+    pub const GET_FLOAT64_LE: u16 = 0x071A;
+    /// `buf.getFloat64BE(index)` - Get big-endian float64
+    pub const GET_FLOAT64_BE: u16 = 0x071B;
+    /// `buf.setFloat64LE(index, value)` - Set little-endian float64
+    pub const SET_FLOAT64_LE: u16 = 0x071C;
+    /// `buf.setFloat64BE(index, value)` - Set big-endian float64
+    pub const SET_FLOAT64_BE: u16 = 0x071D;
 }
 
 /// Built-in method IDs for Map<K, V>
@@ -832,7 +871,7 @@ pub mod runtime {
     /// `Vm.isStdlibAllowed(module)` - Check if a specific stdlib module is allowed
     pub const IS_STDLIB_ALLOWED: u16 = 0x3035;
 
-    // ── VM Introspection & Resource Control (0x3040-0x304A) ──
+    // ── VM Introspection & Resource Control (0x3040-0x304C) ──
     /// `Vm.heapUsed()` - Current heap allocation in bytes
     pub const HEAP_USED: u16 = 0x3040;
     /// `Vm.heapLimit()` - Max heap size (0 = unlimited)
@@ -855,6 +894,10 @@ pub mod runtime {
     pub const LOADED_MODULES: u16 = 0x3049;
     /// `Vm.hasModule(name)` - Check if a module is loaded
     pub const HAS_MODULE: u16 = 0x304A;
+    /// `Vm.memoryUsage().heapTotal` - Bytes the heap may hold before the next GC
+    pub const HEAP_TOTAL: u16 = 0x304B;
+    /// `Vm.memoryUsage().gcCount` - Number of collections run so far
+    pub const GC_COUNT: u16 = 0x304C;
 
     // ── VmInstance Debug Control (0x3070-0x3081) ──
     /// `instance.enableDebug()` - Activate DebugState for child VM
@@ -1201,6 +1244,73 @@ pub fn is_logger_method(method_id: u16) -> bool {
     (0x1000..=0x10FF).contains(&method_id)
 }
 
+/// Built-in method IDs for Random
+pub mod random {
+    /// `new Random(seed)` - Create seeded generator
+    pub const NEW: u16 = 0x1100;
+    /// `rng.nextInt(max)` - Uniform integer in [0, max)
+    pub const NEXT_INT: u16 = 0x1101;
+    /// `rng.nextFloat()` - Uniform float in [0, 1)
+    pub const NEXT_FLOAT: u16 = 0x1102;
+}
+
+/// Check if a method ID is a built-in random method
+pub fn is_random_method(method_id: u16) -> bool {
+    (0x1100..=0x11FF).contains(&method_id)
+}
+
+/// Built-in method IDs for WeakRef<T>
+pub mod weak_ref {
+    /// `new WeakRef(target)` - Create weak reference handle
+    pub const NEW: u16 = 0x1200;
+    /// `ref.deref()` - Get target, or null once collected
+    pub const DEREF: u16 = 0x1201;
+}
+
+/// Built-in method IDs for WeakMap<K, V>
+pub mod weak_map {
+    /// `new WeakMap()` - Create weak map handle
+    pub const NEW: u16 = 0x1210;
+    /// `map.get(key)` - Get value for key, or null
+    pub const GET: u16 = 0x1211;
+    /// `map.set(key, value)` - Set value for key
+    pub const SET: u16 = 0x1212;
+    /// `map.has(key)` - Check if key exists
+    pub const HAS: u16 = 0x1213;
+    /// `map.delete(key)` - Remove key
+    pub const DELETE: u16 = 0x1214;
+}
+
+/// Check if a method ID is a built-in weak reference or weak map method
+pub fn is_weak_ref_method(method_id: u16) -> bool {
+    (0x1200..=0x12FF).contains(&method_id)
+}
+
+/// Built-in method IDs for BigInt
+pub mod bigint {
+    /// `new BigInt(digits)` - Parse decimal digits
+    pub const NEW: u16 = 0x1300;
+    /// `a.add(b)` - Sum
+    pub const ADD: u16 = 0x1301;
+    /// `a.sub(b)` - Difference
+    pub const SUB: u16 = 0x1302;
+    /// `a.mul(b)` - Product
+    pub const MUL: u16 = 0x1303;
+    /// `a.div(b)` - Quotient, truncated toward zero
+    pub const DIV: u16 = 0x1304;
+    /// `a.mod(b)` - Remainder
+    pub const MOD: u16 = 0x1305;
+    /// `a.pow(exp)` - Exponentiation
+    pub const POW: u16 = 0x1306;
+    /// `a.compareTo(b)` - Ordering as -1, 0, or 1
+    pub const COMPARE_TO: u16 = 0x1307;
+}
+
+/// Check if a method ID is a built-in bigint method
+pub fn is_bigint_method(method_id: u16) -> bool {
+    (0x1300..=0x13FF).contains(&method_id)
+}
+
 /// Built-in method IDs for Math (std:math)
 pub mod math {
     /// `math.abs(x)` - Absolute value
@@ -1391,6 +1501,28 @@ pub fn is_proxy_method(method_id: u16) -> bool {
     (0x0DB0..=0x0DBF).contains(&method_id)
 }
 
+/// Check if a native ID belongs to a family the VM dispatches
+///
+/// Ids outside these families can never reach a handler, so the compiler
+/// rejects them after lowering.
+pub fn is_known_native_id(native_id: u16) -> bool {
+    // Object, Array … JSON (0x0001-0x0CFF) are dispatched by the interpreter itself
+    (0x0001..=0x0CFF).contains(&native_id)
+        || is_reflect_method(native_id)
+        || is_number_method(native_id)
+        || is_logger_method(native_id)
+        || is_random_method(native_id)
+        || is_weak_ref_method(native_id)
+        || is_bigint_method(native_id)
+        || is_math_method(native_id)
+        || is_runtime_method(native_id)
+        || is_crypto_method(native_id)
+        || is_time_method(native_id)
+        || is_path_method(native_id)
+        || is_compress_method(native_id)
+        || is_url_method(native_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1422,4 +1554,14 @@ mod tests {
         assert!(is_string_method(string::TRIM));
         assert!(!is_string_method(array::PUSH));
     }
+
+    #[test]
+    fn test_is_known_native_id() {
+        assert!(is_known_native_id(array::RESERVE));
+        assert!(is_known_native_id(math::ABS));
+        assert!(is_known_native_id(runtime::COMPILE));
+        assert!(!is_known_native_id(0));
+        assert!(!is_known_native_id(0x7000));
+        assert!(!is_known_native_id(0xFFFF));
+    }
 }
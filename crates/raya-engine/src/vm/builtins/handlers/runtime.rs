@@ -393,7 +393,7 @@ pub fn call_runtime_method(
         }
         let s_ptr = unsafe { v.as_ptr::<RayaString>() };
         let s = unsafe { &*s_ptr.unwrap().as_ptr() };
-        Ok(s.data.clone())
+        Ok(s.as_str().to_string())
     };
 
     let result = match method_id {
@@ -1346,6 +1346,20 @@ pub fn call_runtime_method(
             Value::i32(bytes_freed as i32)
         }
 
+        runtime::HEAP_TOTAL => {
+            // Vm.memoryUsage().heapTotal — bytes the heap may hold before the next collection
+            let gc = ctx.gc.lock();
+            let stats = gc.heap_stats();
+            drop(gc);
+            Value::f64(stats.threshold.max(stats.allocated_bytes) as f64)
+        }
+
+        runtime::GC_COUNT => {
+            // Vm.memoryUsage().gcCount — number of collections run by this VM
+            let collections = ctx.gc.lock().stats().collections;
+            Value::i32(collections as i32)
+        }
+
         runtime::VERSION => {
             // Vm.version(): string — Raya VM version
             allocate_string(ctx, "0.1.0".to_string())
@@ -2098,11 +2112,8 @@ fn parse_source(source: &str) -> Result<(ast::Module, Interner), VmError> {
 
 /// Type-check a parsed AST, returning a TypedAstEntry
 fn typecheck_ast(ast: ast::Module, interner: Interner) -> Result<TypedAstEntry, VmError> {
-    let mut type_ctx = TypeContext::new();
-    let mut binder = Binder::new(&mut type_ctx, &interner);
-
-    let builtin_sigs = crate::builtins::to_checker_signatures();
-    binder.register_builtins(&builtin_sigs);
+    let mut type_ctx = TypeContext::with_builtins();
+    let binder = Binder::with_builtins(&mut type_ctx, &interner);
 
     let mut symbols = binder
         .bind_module(&ast)
@@ -2128,7 +2139,7 @@ fn typecheck_ast(ast: ast::Module, interner: Interner) -> Result<TypedAstEntry,
 }
 
 /// Compile Raya source code to a Module (full pipeline)
-fn compile_source(source: &str) -> Result<Module, VmError> {
+pub(crate) fn compile_source(source: &str) -> Result<Module, VmError> {
     compile_source_impl(source, false)
 }
 
@@ -36,6 +36,8 @@ pub struct BuiltinModule {
 }
 
 /// Type signature for a builtin class method
+///
+/// Repeating a method name declares an overload; overloads are tried in order.
 #[derive(Debug, Clone)]
 pub struct MethodSig {
     /// Method name
@@ -74,9 +76,13 @@ pub struct ClassSig {
     pub methods: &'static [MethodSig],
     /// Constructor parameters (None if not constructible)
     pub constructor: Option<&'static [(&'static str, &'static str)]>,
+    /// Type each `for-of` iteration yields, in terms of `type_params` (None if not iterable)
+    pub iterator_element: Option<&'static str>,
 }
 
 /// Type signature for a builtin function
+///
+/// Repeating a function name declares an overload; overloads are tried in order.
 #[derive(Debug, Clone)]
 pub struct FunctionSig {
     /// Function name
@@ -103,12 +109,30 @@ pub struct BuiltinSignatures {
 // Include the generated index (from build.rs)
 include!(concat!(env!("OUT_DIR"), "/builtins_index.rs"));
 
+/// Builtins compiled from their embedded source on first access when the
+/// precompiled index does not provide them
+static SOURCE_BUILTINS: &[(&str, &str)] = &[
+    (
+        "WeakMap",
+        include_str!("../../../builtins/node_compat/weak_map.raya"),
+    ),
+    (
+        "WeakRef",
+        include_str!("../../../builtins/node_compat/weak_refs.raya"),
+    ),
+    (
+        "BigInt",
+        include_str!("../../../builtins/strict/bigint.raya"),
+    ),
+];
+
 /// Cache for decoded modules
 static DECODED_CACHE: OnceLock<Vec<(&'static str, Module)>> = OnceLock::new();
 
 /// Get all decoded builtin modules
 ///
-/// This decodes the bytecode on first access and caches the result.
+/// This decodes the bytecode (or compiles the source of builtins missing
+/// from the precompiled index) on first access and caches the result.
 pub fn get_all_builtins() -> &'static [(&'static str, Module)] {
     DECODED_CACHE.get_or_init(|| {
         let mut modules = Vec::new();
@@ -127,10 +151,24 @@ pub fn get_all_builtins() -> &'static [(&'static str, Module)] {
             }
         }
 
+        for &(name, source) in source_only_builtins() {
+            match handlers::runtime::compile_source(source) {
+                Ok(module) => modules.push((name, module)),
+                Err(e) => eprintln!("Warning: Failed to compile builtin '{}': {}", name, e),
+            }
+        }
+
         modules
     })
 }
 
+/// Source builtins the precompiled index does not provide
+fn source_only_builtins() -> impl Iterator<Item = &'static (&'static str, &'static str)> {
+    SOURCE_BUILTINS
+        .iter()
+        .filter(|(name, _)| BUILTINS.iter().all(|b| b.name != *name))
+}
+
 /// Get a specific builtin module by name
 ///
 /// Returns `None` if the builtin doesn't exist or failed to decode.
@@ -150,12 +188,15 @@ pub fn get_builtin_bytecode(name: &str) -> Option<&'static [u8]> {
 
 /// List all available builtin names
 pub fn builtin_names() -> impl Iterator<Item = &'static str> {
-    BUILTINS.iter().map(|b| b.name)
+    BUILTINS
+        .iter()
+        .map(|b| b.name)
+        .chain(source_only_builtins().map(|&(name, _)| name))
 }
 
 /// Get the number of available builtins
 pub fn builtin_count() -> usize {
-    BUILTINS.len()
+    builtin_names().count()
 }
 
 // ============================================================================
@@ -172,6 +213,15 @@ pub fn get_signatures(name: &str) -> Option<&'static BuiltinSignatures> {
     BUILTIN_SIGS.iter().find(|s| s.name == name)
 }
 
+/// Checker-format builtin signatures, converted once and cached.
+///
+/// Prefer this over [`to_checker_signatures`] on hot paths such as per-module
+/// compilation.
+pub fn checker_signatures() -> &'static [crate::parser::checker::BuiltinSignatures] {
+    static SIGNATURES: OnceLock<Vec<crate::parser::checker::BuiltinSignatures>> = OnceLock::new();
+    SIGNATURES.get_or_init(to_checker_signatures)
+}
+
 /// Convert static signatures to the checker's BuiltinSignatures format
 ///
 /// This converts the static &'static str signatures to owned Strings
@@ -223,6 +273,7 @@ pub fn to_checker_signatures() -> Vec<crate::parser::checker::BuiltinSignatures>
                                     .map(|(n, t)| (n.to_string(), t.to_string()))
                                     .collect()
                             }),
+                            iterator_element: c.iterator_element.map(str::to_string),
                         }
                     })
                     .collect(),
@@ -407,7 +458,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
                 MethodSig {
                     name: "split",
-                    params: &[("separator", "string"), ("limit", "number")],
+                    params: &[("separator", "string | RegExp"), ("limit", "number")],
                     min_params: 1,
                     return_type: "string[]",
                     is_static: false,
@@ -451,7 +502,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     name: "matchAll",
                     params: &[("pattern", "RegExp")],
                     min_params: 1,
-                    return_type: "string[][]",
+                    return_type: "Array<RegExpMatch>",
                     is_static: false,
                 },
                 MethodSig {
@@ -470,6 +521,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: None,
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -504,6 +556,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: None,
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -559,6 +612,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: None,
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -638,7 +692,8 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 },
             ],
-            constructor: Some(&[]),
+            constructor: Some(&[("entries", "Array<[K, V]> | null")]),
+            iterator_element: Some("[K, V]"),
         }],
         functions: &[],
     },
@@ -732,7 +787,139 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 },
             ],
+            constructor: Some(&[("values", "Array<T> | null")]),
+            iterator_element: Some("T"),
+        }],
+        functions: &[],
+    },
+    // WeakMap<K, V> (entries are dropped once their key is collected)
+    BuiltinSignatures {
+        name: "WeakMap",
+        classes: &[ClassSig {
+            name: "WeakMap",
+            type_params: &["K", "V"],
+            properties: &[],
+            methods: &[
+                MethodSig {
+                    name: "get",
+                    params: &[("key", "K")],
+                    min_params: 1,
+                    return_type: "V | null",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "set",
+                    params: &[("key", "K"), ("value", "V")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "has",
+                    params: &[("key", "K")],
+                    min_params: 1,
+                    return_type: "boolean",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "delete",
+                    params: &[("key", "K")],
+                    min_params: 1,
+                    return_type: "boolean",
+                    is_static: false,
+                },
+            ],
             constructor: Some(&[]),
+            iterator_element: None,
+        }],
+        functions: &[],
+    },
+    // WeakRef<T> (target is not traced by the GC)
+    BuiltinSignatures {
+        name: "WeakRef",
+        classes: &[ClassSig {
+            name: "WeakRef",
+            type_params: &["T"],
+            properties: &[],
+            methods: &[MethodSig {
+                name: "deref",
+                params: &[],
+                min_params: 0,
+                return_type: "T | null",
+                is_static: false,
+            }],
+            constructor: Some(&[("target", "T")]),
+            iterator_element: None,
+        }],
+        functions: &[],
+    },
+    // BigInt (arbitrary-precision integer; `123n` literals construct one)
+    BuiltinSignatures {
+        name: "BigInt",
+        classes: &[ClassSig {
+            name: "BigInt",
+            type_params: &[],
+            properties: &[],
+            methods: &[
+                MethodSig {
+                    name: "add",
+                    params: &[("other", "BigInt")],
+                    min_params: 1,
+                    return_type: "BigInt",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "sub",
+                    params: &[("other", "BigInt")],
+                    min_params: 1,
+                    return_type: "BigInt",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "mul",
+                    params: &[("other", "BigInt")],
+                    min_params: 1,
+                    return_type: "BigInt",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "div",
+                    params: &[("other", "BigInt")],
+                    min_params: 1,
+                    return_type: "BigInt",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "mod",
+                    params: &[("other", "BigInt")],
+                    min_params: 1,
+                    return_type: "BigInt",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "pow",
+                    params: &[("exponent", "number")],
+                    min_params: 1,
+                    return_type: "BigInt",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "compareTo",
+                    params: &[("other", "BigInt")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "toString",
+                    params: &[],
+                    min_params: 0,
+                    return_type: "string",
+                    is_static: false,
+                },
+            ],
+            constructor: Some(&[("value", "string")]),
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -790,6 +977,132 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     return_type: "void",
                     is_static: false,
                 },
+                MethodSig {
+                    name: "getUint8",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setUint8",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getInt16LE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getInt16BE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setInt16LE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setInt16BE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getUint16LE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getUint16BE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setUint16LE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setUint16BE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getInt32LE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getInt32BE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setInt32LE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setInt32BE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getFloat64LE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "getFloat64BE",
+                    params: &[("offset", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setFloat64LE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "setFloat64BE",
+                    params: &[("offset", "number"), ("value", "number")],
+                    min_params: 2,
+                    return_type: "void",
+                    is_static: false,
+                },
                 MethodSig {
                     name: "slice",
                     params: &[("start", "number"), ("end", "number")],
@@ -818,6 +1131,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: Some(&[("size", "number")]),
+            iterator_element: None,
         }],
         functions: &[
             FunctionSig {
@@ -832,6 +1146,30 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 params: &[("str", "string")],
                 return_type: "Buffer",
             },
+            FunctionSig {
+                name: "encodeUtf8",
+                type_params: &[],
+                params: &[("input", "string")],
+                return_type: "Buffer",
+            },
+            FunctionSig {
+                name: "decodeUtf8",
+                type_params: &[],
+                params: &[("buffer", "Buffer"), ("fatal", "boolean")],
+                return_type: "string",
+            },
+            FunctionSig {
+                name: "encodeUtf16LE",
+                type_params: &[],
+                params: &[("input", "string")],
+                return_type: "Buffer",
+            },
+            FunctionSig {
+                name: "decodeUtf16LE",
+                type_params: &[],
+                params: &[("buffer", "Buffer"), ("fatal", "boolean")],
+                return_type: "string",
+            },
         ],
     },
     // Typed arrays + DataView
@@ -854,6 +1192,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("byteLength", "int")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "Uint8Array",
@@ -892,6 +1231,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     },
                 ],
                 constructor: Some(&[("source", "int | ArrayBuffer")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "Int8Array",
@@ -930,6 +1270,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     },
                 ],
                 constructor: Some(&[("source", "int | ArrayBuffer")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "Int32Array",
@@ -968,6 +1309,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     },
                 ],
                 constructor: Some(&[("source", "int | ArrayBuffer")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "Float64Array",
@@ -1006,6 +1348,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     },
                 ],
                 constructor: Some(&[("source", "int | ArrayBuffer")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "DataView",
@@ -1107,6 +1450,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     },
                 ],
                 constructor: Some(&[("buffer", "ArrayBuffer")]),
+                iterator_element: None,
             },
         ],
         functions: &[],
@@ -1261,6 +1605,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: Some(&[]),
+            iterator_element: None,
         }],
         functions: &[
             FunctionSig {
@@ -1343,6 +1688,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: Some(&[("capacity", "number")]),
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -1377,6 +1723,49 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: Some(&[("value", "T")]),
+            iterator_element: None,
+        }],
+        functions: &[],
+    },
+    // Random
+    BuiltinSignatures {
+        name: "Random",
+        classes: &[ClassSig {
+            name: "Random",
+            type_params: &[],
+            properties: &[],
+            methods: &[
+                MethodSig {
+                    name: "nextInt",
+                    params: &[("max", "number")],
+                    min_params: 1,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "nextFloat",
+                    params: &[],
+                    min_params: 0,
+                    return_type: "number",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "shuffle",
+                    params: &[("array", "Array<Object>")],
+                    min_params: 1,
+                    return_type: "Array<Object>",
+                    is_static: false,
+                },
+                MethodSig {
+                    name: "choice",
+                    params: &[("array", "Array<Object>")],
+                    min_params: 1,
+                    return_type: "Object",
+                    is_static: false,
+                },
+            ],
+            constructor: Some(&[("seed", "number")]),
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -1460,6 +1849,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: None, // Promises are created via async keyword
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -1515,6 +1905,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: None,
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -1570,6 +1961,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "TypeError",
@@ -1594,6 +1986,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "RangeError",
@@ -1618,6 +2011,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "ReferenceError",
@@ -1642,6 +2036,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "SyntaxError",
@@ -1666,6 +2061,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "URIError",
@@ -1690,6 +2086,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "EvalError",
@@ -1714,6 +2111,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "AggregateError",
@@ -1743,6 +2141,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("errors", "Array<Error>"), ("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "ChannelClosedError",
@@ -1767,6 +2166,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
             ClassSig {
                 name: "AssertionError",
@@ -1791,6 +2191,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     is_static: false,
                 }],
                 constructor: Some(&[("message", "string")]),
+                iterator_element: None,
             },
         ],
         functions: &[],
@@ -1882,6 +2283,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: Some(&[]),
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -1938,6 +2340,11 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                     ty: "string",
                     is_static: false,
                 },
+                PropertySig {
+                    name: "groups",
+                    ty: "string[]",
+                    is_static: false,
+                },
             ],
             methods: &[
                 MethodSig {
@@ -1956,6 +2363,7 @@ static BUILTIN_SIGS: &[BuiltinSignatures] = &[
                 },
             ],
             constructor: None,
+            iterator_element: None,
         }],
         functions: &[],
     },
@@ -1985,6 +2393,19 @@ mod tests {
         // Otherwise, test passes (builtins not precompiled yet)
     }
 
+    #[test]
+    fn test_weak_builtins_compile_from_source() {
+        for name in ["WeakMap", "WeakRef"] {
+            assert!(builtin_names().any(|n| n == name));
+            let module = get_builtin(name).unwrap_or_else(|| panic!("{} builtin", name));
+            assert!(
+                module.classes.iter().any(|class| class.name == name),
+                "{} class in its builtin module",
+                name
+            );
+        }
+    }
+
     #[test]
     fn test_nonexistent_builtin() {
         assert!(get_builtin("NonExistent").is_none());
@@ -2025,4 +2446,76 @@ mod tests {
         assert!(descriptor.has_getter);
         assert!(!descriptor.has_setter);
     }
+
+    #[test]
+    fn test_iterator_element_signatures() {
+        let element = |name: &str| {
+            get_signatures(name)
+                .and_then(|sig| sig.classes.iter().find(|c| c.name == name))
+                .and_then(|class| class.iterator_element)
+        };
+        assert_eq!(element("Map"), Some("[K, V]"));
+        assert_eq!(element("Set"), Some("T"));
+        assert_eq!(element("Buffer"), None);
+
+        let sigs = to_checker_signatures();
+        let set_class = sigs
+            .iter()
+            .flat_map(|sig| &sig.classes)
+            .find(|c| c.name == "Set")
+            .expect("Set class signature");
+        assert_eq!(set_class.iterator_element.as_deref(), Some("T"));
+    }
+
+    #[test]
+    fn test_weak_collection_signatures() {
+        let sigs = to_checker_signatures();
+        let class = |name: &str| {
+            sigs.iter()
+                .flat_map(|sig| &sig.classes)
+                .find(|c| c.name == name)
+                .unwrap_or_else(|| panic!("{} class signature", name))
+        };
+
+        let weak_map = class("WeakMap");
+        assert_eq!(weak_map.type_params, vec!["K", "V"]);
+        let mut methods: Vec<_> = weak_map.methods.iter().map(|m| m.name.as_str()).collect();
+        methods.sort_unstable();
+        assert_eq!(methods, vec!["delete", "get", "has", "set"]);
+
+        let weak_ref = class("WeakRef");
+        let deref = weak_ref
+            .methods
+            .iter()
+            .find(|m| m.name == "deref")
+            .expect("WeakRef.deref signature");
+        assert_eq!(deref.return_type, "T | null");
+    }
+
+    #[test]
+    fn test_bigint_signatures() {
+        let sig = get_signatures("BigInt").expect("BigInt signatures");
+        let class = sig
+            .classes
+            .iter()
+            .find(|c| c.name == "BigInt")
+            .expect("BigInt class signature");
+        let methods: Vec<_> = class.methods.iter().map(|m| m.name).collect();
+        for name in [
+            "add",
+            "sub",
+            "mul",
+            "div",
+            "mod",
+            "pow",
+            "compareTo",
+            "toString",
+        ] {
+            assert!(methods.contains(&name), "missing BigInt.{}", name);
+        }
+        assert_eq!(
+            class.constructor.map(|params| params.to_vec()),
+            Some(vec![("value", "string")])
+        );
+    }
 }
@@ -12,6 +12,15 @@ pub const DEFAULT_PREEMPT_THRESHOLD_MS: u64 = 10;
 /// Default initial GC heap threshold in bytes (1 MB).
 pub const DEFAULT_GC_THRESHOLD: usize = 1024 * 1024;
 
+/// Default bytes traced or freed per incremental GC step (64 KB).
+pub const DEFAULT_GC_STEP_BYTES: usize = 64 * 1024;
+
+/// Default time budget for running `process.onExit` hooks at shutdown, in milliseconds.
+pub const DEFAULT_EXIT_HOOK_BUDGET_MS: u64 = 5_000;
+
+/// How often a blocked host thread rechecks its cancellation flag, in milliseconds.
+pub const CANCEL_POLL_INTERVAL_MS: u64 = 10;
+
 /// JIT adaptive compilation policy check mask.
 /// The interpreter checks compilation policy every `(count & MASK) == 0` calls,
 /// i.e. every 64 calls with the default mask of 0x3F.
@@ -8,13 +8,19 @@
 //! - Opaque pointers for VM objects
 //! - Manual memory management
 
+use super::{native_to_value, value_to_native};
+use crate::compiler::module::ModuleCompiler;
 use crate::compiler::Module;
+use crate::vm::gc::header_ptr_from_value_ptr;
 use crate::vm::interpreter::Vm;
+use crate::vm::object::RayaString;
 use crate::vm::value::Value;
 use crate::vm::VmError;
+use raya_sdk::{NativeCallResult, NativeContext, NativeValue};
+use std::any::TypeId;
 use std::ffi::{CStr, CString};
-use std::os::raw::{c_char, c_int};
-use std::path::Path;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::{Path, PathBuf};
 use std::ptr;
 
 // ============================================================================
@@ -39,6 +45,38 @@ pub struct RayaModule {
     _private: [u8; 0],
 }
 
+// ============================================================================
+// Value Type Tags
+// ============================================================================
+
+/// `raya_value_type()` tag for null
+pub const RAYA_VALUE_NULL: c_int = 0;
+/// `raya_value_type()` tag for booleans
+pub const RAYA_VALUE_BOOL: c_int = 1;
+/// `raya_value_type()` tag for 32-bit integers
+pub const RAYA_VALUE_I32: c_int = 2;
+/// `raya_value_type()` tag for 64-bit integers
+pub const RAYA_VALUE_I64: c_int = 3;
+/// `raya_value_type()` tag for 64-bit floats
+pub const RAYA_VALUE_F64: c_int = 4;
+/// `raya_value_type()` tag for strings
+pub const RAYA_VALUE_STRING: c_int = 5;
+/// `raya_value_type()` tag for other heap values (objects, arrays, ...)
+pub const RAYA_VALUE_OBJECT: c_int = 6;
+/// `raya_value_type()` tag for any other primitive (u32, f32, u64)
+pub const RAYA_VALUE_OTHER: c_int = 7;
+
+/// C function that Raya code can call by name (see `raya_vm_register_callback()`)
+///
+/// Receives the call's arguments and the `user_data` given at registration. The
+/// argument values are borrowed for the duration of the call and must not be
+/// freed. The returned value (NULL for `null`) is owned and freed by the VM.
+pub type RayaCallback = unsafe extern "C" fn(
+    args: *const *const RayaValue,
+    argc: usize,
+    user_data: *mut c_void,
+) -> *mut RayaValue;
+
 /// Error information
 #[repr(C)]
 pub struct RayaError {
@@ -51,9 +89,34 @@ struct VmHandle {
 }
 
 // Internal representation of Value (not exposed to C)
-#[allow(dead_code)]
 struct ValueHandle {
     value: Value,
+    /// String contents copied out of the VM heap, so the handle stays readable
+    /// after the VM collects the string or is destroyed
+    string: Option<String>,
+}
+
+impl ValueHandle {
+    fn new(value: Value) -> Self {
+        let string = unsafe { value_as_raya_string(value) };
+        Self { value, string }
+    }
+
+    fn into_raw(self) -> *mut RayaValue {
+        Box::into_raw(Box::new(self)) as *mut RayaValue
+    }
+}
+
+// Host pointer handed back to a callback; the host owns its thread safety
+struct CallbackUserData(*mut c_void);
+
+unsafe impl Send for CallbackUserData {}
+unsafe impl Sync for CallbackUserData {}
+
+impl CallbackUserData {
+    fn get(&self) -> *mut c_void {
+        self.0
+    }
 }
 
 // Internal representation of Module (not exposed to C)
@@ -73,6 +136,17 @@ unsafe fn rust_to_c_string(s: &str) -> *mut c_char {
     }
 }
 
+/// Copy the contents of a heap string value
+unsafe fn value_as_raya_string(value: Value) -> Option<String> {
+    let raw_ptr = value.as_ptr::<u8>()?;
+    let header = &*header_ptr_from_value_ptr(raw_ptr.as_ptr());
+    if header.type_id() != TypeId::of::<RayaString>() {
+        return None;
+    }
+    let string = value.as_ptr::<RayaString>()?;
+    Some((*string.as_ptr()).as_str().to_string())
+}
+
 /// Create error from string
 unsafe fn create_error_str(msg: &str) -> *mut RayaError {
     let message = rust_to_c_string(msg);
@@ -276,17 +350,276 @@ pub unsafe extern "C" fn raya_vm_execute(
     let module_handle = &*(module as *const ModuleHandle);
 
     match vm_handle.vm.execute(&module_handle.module) {
+        Ok(value) => ValueHandle::new(value).into_raw(),
+        Err(e) => {
+            set_error(error, e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Compile and run a source snippet on an existing VM
+///
+/// Lets a host create one VM and evaluate many snippets without paying VM
+/// setup for each. Each eval is isolated: the VM is reset first (see
+/// `Vm::reset`), so heap objects, global values and tasks from earlier evals are
+/// gone. Only state registered on the VM itself by the host (native modules,
+/// classes) is shared between evals. Snippets must be self-contained; imports
+/// of other source modules are rejected.
+///
+/// # Arguments
+/// * `vm` - Pointer to RayaVM (must not be NULL)
+/// * `source` - Null-terminated Raya source code
+/// * `result` - Optional pointer to receive the result value
+/// * `error` - Optional pointer to receive error information
+///
+/// # Returns
+/// * 1 on success
+/// * 0 on failure (check error parameter)
+///
+/// # Safety
+/// - VM pointer must be valid and not be running another eval
+/// - Source must be a valid null-terminated string
+/// - A value written to `result` must be freed with `raya_value_free()`
+#[no_mangle]
+pub unsafe extern "C" fn raya_vm_eval(
+    vm: *mut RayaVM,
+    source: *const c_char,
+    result: *mut *mut RayaValue,
+    error: *mut *mut RayaError,
+) -> c_int {
+    if vm.is_null() || source.is_null() {
+        set_error_str(error, "Invalid arguments (null pointer)");
+        return 0;
+    }
+
+    let source = match CStr::from_ptr(source).to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => {
+            set_error_str(error, "Invalid UTF-8 in source");
+            return 0;
+        }
+    };
+
+    let entry_path = PathBuf::from("<eval>.raya");
+    let mut compiler = ModuleCompiler::new(PathBuf::from("."));
+    let compiled = match compiler.compile_with_virtual_entry_source(&entry_path, source) {
+        Ok(compiled) => compiled,
+        Err(e) => {
+            set_error_str(error, &e.to_string());
+            return 0;
+        }
+    };
+    if compiled
+        .iter()
+        .any(|module| module.path != entry_path && !module.declaration_only)
+    {
+        set_error_str(
+            error,
+            "raya_vm_eval does not support importing other modules",
+        );
+        return 0;
+    }
+    let Some(entry) = compiled
+        .into_iter()
+        .find(|module| module.path == entry_path)
+    else {
+        set_error_str(error, "Eval snippet produced no entry module");
+        return 0;
+    };
+
+    let vm_handle = &mut *(vm as *mut VmHandle);
+    vm_handle.vm.reset();
+    match vm_handle.vm.execute(&entry.bytecode) {
         Ok(value) => {
-            let handle = Box::new(ValueHandle { value });
-            Box::into_raw(handle) as *mut RayaValue
+            if !result.is_null() {
+                *result = ValueHandle::new(value).into_raw();
+            }
+            1
         }
         Err(e) => {
             set_error(error, e);
-            ptr::null_mut()
+            0
         }
     }
 }
 
+/// Call a function of a loaded module by name with arguments
+///
+/// Looks in the module most recently run by `raya_vm_execute()` or
+/// `raya_vm_eval()` first, then in the other loaded modules (where the name
+/// must be unique). The call runs to completion on the VM's scheduler.
+///
+/// # Arguments
+/// * `vm` - Pointer to RayaVM (must not be NULL)
+/// * `name` - Null-terminated function name
+/// * `args` - Array of `arg_count` argument values (may be NULL when `arg_count` is 0)
+/// * `arg_count` - Number of arguments; must match the function's arity
+/// * `result` - Optional pointer to receive the result value
+/// * `error` - Optional pointer to receive error information
+///
+/// # Returns
+/// * 1 on success
+/// * 0 on failure (arity mismatch, runtime error, invalid arguments)
+/// * -1 if no loaded module defines the function
+///
+/// # Safety
+/// - VM pointer must be valid and `name` a valid null-terminated string
+/// - `args` must point to `arg_count` valid values, which stay owned by the caller
+/// - A value written to `result` must be freed with `raya_value_free()`
+#[no_mangle]
+pub unsafe extern "C" fn raya_vm_call_function(
+    vm: *mut RayaVM,
+    name: *const c_char,
+    args: *const *mut RayaValue,
+    arg_count: usize,
+    result: *mut *mut RayaValue,
+    error: *mut *mut RayaError,
+) -> c_int {
+    if vm.is_null() || name.is_null() || (args.is_null() && arg_count > 0) {
+        set_error_str(error, "Invalid arguments (null pointer)");
+        return 0;
+    }
+
+    let name = match CStr::from_ptr(name).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error_str(error, "Invalid UTF-8 in function name");
+            return 0;
+        }
+    };
+
+    let vm_handle = &mut *(vm as *mut VmHandle);
+    if !vm_handle.vm.has_function(name) {
+        set_error_str(error, &format!("Function '{}' not found", name));
+        return -1;
+    }
+
+    let handles: &[*mut RayaValue] = if arg_count == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(args, arg_count)
+    };
+    let mut values = Vec::with_capacity(arg_count);
+    for (i, &handle) in handles.iter().enumerate() {
+        if handle.is_null() {
+            set_error_str(error, &format!("Argument {} is a null pointer", i));
+            return 0;
+        }
+        let handle = &*(handle as *const ValueHandle);
+        let value = match &handle.string {
+            Some(s) => {
+                let gc_ptr = vm_handle
+                    .vm
+                    .shared_state()
+                    .gc
+                    .lock()
+                    .allocate(RayaString::new(s.clone()));
+                Value::from_ptr(ptr::NonNull::new(gc_ptr.as_ptr()).unwrap())
+            }
+            None => handle.value,
+        };
+        values.push(value);
+    }
+
+    match vm_handle.vm.call_function(name, values) {
+        Ok(value) => {
+            if !result.is_null() {
+                *result = ValueHandle::new(value).into_raw();
+            }
+            1
+        }
+        Err(e) => {
+            set_error(error, e);
+            0
+        }
+    }
+}
+
+/// Register a C callback that Raya code can call by name
+///
+/// The callback becomes a native function resolved when a module is loaded, so
+/// it must be registered before the code that uses it is executed. Raya code
+/// calls it with `__NATIVE_CALL<T>("name", args...)`.
+///
+/// # Arguments
+/// * `vm` - Pointer to RayaVM (must not be NULL)
+/// * `name` - Null-terminated native function name (e.g. "host.log")
+/// * `callback` - Function to invoke (must not be NULL)
+/// * `user_data` - Opaque pointer passed back to every invocation
+/// * `error` - Optional pointer to receive error information
+///
+/// # Returns
+/// * 1 on success
+/// * 0 on failure (check error parameter)
+///
+/// # Safety
+/// - VM pointer must be valid and name a valid null-terminated string
+/// - `user_data` must stay valid for the VM's lifetime; the callback may run on
+///   any VM worker thread, so access to it must be thread-safe
+#[no_mangle]
+pub unsafe extern "C" fn raya_vm_register_callback(
+    vm: *mut RayaVM,
+    name: *const c_char,
+    callback: Option<RayaCallback>,
+    user_data: *mut c_void,
+    error: *mut *mut RayaError,
+) -> c_int {
+    let Some(callback) = callback else {
+        set_error_str(error, "Invalid arguments (null callback)");
+        return 0;
+    };
+    if vm.is_null() || name.is_null() {
+        set_error_str(error, "Invalid arguments (null pointer)");
+        return 0;
+    }
+
+    let name = match CStr::from_ptr(name).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_error_str(error, "Invalid UTF-8 in callback name");
+            return 0;
+        }
+    };
+
+    let vm_handle = &*(vm as *const VmHandle);
+    let mut registry = vm_handle.vm.native_registry().write();
+    if registry.contains(name) {
+        set_error_str(
+            error,
+            &format!("Native function '{}' is already registered", name),
+        );
+        return 0;
+    }
+
+    let user_data = CallbackUserData(user_data);
+    registry.register(
+        name,
+        move |ctx: &dyn NativeContext, args: &[NativeValue]| {
+            let handles: Vec<ValueHandle> = args
+                .iter()
+                .map(|arg| ValueHandle::new(native_to_value(*arg)))
+                .collect();
+            let arg_ptrs: Vec<*const RayaValue> = handles
+                .iter()
+                .map(|handle| handle as *const ValueHandle as *const RayaValue)
+                .collect();
+
+            let returned = callback(arg_ptrs.as_ptr(), arg_ptrs.len(), user_data.get());
+            if returned.is_null() {
+                return NativeCallResult::null();
+            }
+
+            let returned = Box::from_raw(returned as *mut ValueHandle);
+            match &returned.string {
+                Some(s) => NativeCallResult::Value(ctx.create_string(s)),
+                None => NativeCallResult::Value(value_to_native(returned.value)),
+            }
+        },
+    );
+    1
+}
+
 // ============================================================================
 // Value Creation Functions
 // ============================================================================
@@ -300,10 +633,7 @@ pub unsafe extern "C" fn raya_vm_execute(
 /// The returned value must be freed with `raya_value_free()`
 #[no_mangle]
 pub unsafe extern "C" fn raya_value_null() -> *mut RayaValue {
-    let handle = Box::new(ValueHandle {
-        value: Value::null(),
-    });
-    Box::into_raw(handle) as *mut RayaValue
+    ValueHandle::new(Value::null()).into_raw()
 }
 
 /// Create a boolean value
@@ -318,10 +648,7 @@ pub unsafe extern "C" fn raya_value_null() -> *mut RayaValue {
 /// The returned value must be freed with `raya_value_free()`
 #[no_mangle]
 pub unsafe extern "C" fn raya_value_bool(value: c_int) -> *mut RayaValue {
-    let handle = Box::new(ValueHandle {
-        value: Value::bool(value != 0),
-    });
-    Box::into_raw(handle) as *mut RayaValue
+    ValueHandle::new(Value::bool(value != 0)).into_raw()
 }
 
 /// Create a 32-bit integer value
@@ -336,10 +663,74 @@ pub unsafe extern "C" fn raya_value_bool(value: c_int) -> *mut RayaValue {
 /// The returned value must be freed with `raya_value_free()`
 #[no_mangle]
 pub unsafe extern "C" fn raya_value_i32(value: i32) -> *mut RayaValue {
-    let handle = Box::new(ValueHandle {
-        value: Value::i32(value),
-    });
-    Box::into_raw(handle) as *mut RayaValue
+    ValueHandle::new(Value::i32(value)).into_raw()
+}
+
+/// Create a 64-bit integer value
+///
+/// The VM stores i64 values in 32 bits, so values outside the i32 range are
+/// stored as a float (`RAYA_VALUE_F64`), like any Raya `number`. Magnitudes
+/// above 2^53 round to the nearest float.
+///
+/// # Arguments
+/// * `value` - Integer value
+///
+/// # Returns
+/// * Pointer to RayaValue representing the integer
+///
+/// # Safety
+/// The returned value must be freed with `raya_value_free()`
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_i64(value: i64) -> *mut RayaValue {
+    let value = if i32::try_from(value).is_ok() {
+        Value::i64(value)
+    } else {
+        Value::f64(value as f64)
+    };
+    ValueHandle::new(value).into_raw()
+}
+
+/// Create a 64-bit floating point value
+///
+/// # Arguments
+/// * `value` - Float value
+///
+/// # Returns
+/// * Pointer to RayaValue representing the float
+///
+/// # Safety
+/// The returned value must be freed with `raya_value_free()`
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_f64(value: f64) -> *mut RayaValue {
+    ValueHandle::new(Value::f64(value)).into_raw()
+}
+
+/// Create a string value
+///
+/// # Arguments
+/// * `value` - Null-terminated UTF-8 string (copied)
+///
+/// # Returns
+/// * Pointer to RayaValue representing the string
+/// * NULL if `value` is NULL or not valid UTF-8
+///
+/// # Safety
+/// - String must be a valid null-terminated string
+/// - The returned value must be freed with `raya_value_free()`
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_string(value: *const c_char) -> *mut RayaValue {
+    if value.is_null() {
+        return ptr::null_mut();
+    }
+
+    match CStr::from_ptr(value).to_str() {
+        Ok(s) => ValueHandle {
+            value: Value::null(),
+            string: Some(s.to_string()),
+        }
+        .into_raw(),
+        Err(_) => ptr::null_mut(),
+    }
 }
 
 /// Free a value
@@ -360,6 +751,226 @@ pub unsafe extern "C" fn raya_value_free(value: *mut RayaValue) {
     drop(handle);
 }
 
+// ============================================================================
+// Value Access Functions
+// ============================================================================
+
+/// Get the type tag of a value
+///
+/// # Arguments
+/// * `value` - Pointer to RayaValue
+///
+/// # Returns
+/// * One of the `RAYA_VALUE_*` tags
+/// * -1 if value is NULL
+///
+/// # Safety
+/// Value pointer must be valid (created by a Raya API function)
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_type(value: *const RayaValue) -> c_int {
+    if value.is_null() {
+        return -1;
+    }
+
+    let handle = &*(value as *const ValueHandle);
+    let value = handle.value;
+    if handle.string.is_some() {
+        RAYA_VALUE_STRING
+    } else if value.is_null() {
+        RAYA_VALUE_NULL
+    } else if value.is_bool() {
+        RAYA_VALUE_BOOL
+    } else if value.is_i32() {
+        RAYA_VALUE_I32
+    } else if value.is_i64() {
+        RAYA_VALUE_I64
+    } else if value.is_f64() {
+        RAYA_VALUE_F64
+    } else if value.is_ptr() {
+        RAYA_VALUE_OBJECT
+    } else {
+        RAYA_VALUE_OTHER
+    }
+}
+
+/// Read a 32-bit integer value
+///
+/// # Arguments
+/// * `value` - Pointer to RayaValue
+/// * `out` - Receives the integer on success
+///
+/// # Returns
+/// * 1 on success
+/// * 0 if the value is not an i32 (or an argument is NULL)
+///
+/// # Safety
+/// Pointers must be valid
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_as_i32(value: *const RayaValue, out: *mut i32) -> c_int {
+    if value.is_null() || out.is_null() {
+        return 0;
+    }
+
+    match (*(value as *const ValueHandle)).value.as_i32() {
+        Some(v) => {
+            *out = v;
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Read a 64-bit integer value
+///
+/// # Arguments
+/// * `value` - Pointer to RayaValue
+/// * `out` - Receives the integer on success
+///
+/// # Returns
+/// * 1 on success
+/// * 0 if the value is not an i64, an i32 or a whole float within ±2^53
+///   (or an argument is NULL)
+///
+/// # Safety
+/// Pointers must be valid
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_as_i64(value: *const RayaValue, out: *mut i64) -> c_int {
+    /// Largest magnitude up to which every whole float is exact (2^53)
+    const MAX_EXACT_FLOAT_INT: f64 = 9_007_199_254_740_992.0;
+
+    if value.is_null() || out.is_null() {
+        return 0;
+    }
+
+    let value = (*(value as *const ValueHandle)).value;
+    let whole_float = value
+        .as_f64()
+        .filter(|f| f.fract() == 0.0 && f.abs() <= MAX_EXACT_FLOAT_INT)
+        .map(|f| f as i64);
+    match value
+        .as_i64()
+        .or_else(|| value.as_i32().map(i64::from))
+        .or(whole_float)
+    {
+        Some(v) => {
+            *out = v;
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Read a 64-bit floating point value
+///
+/// # Arguments
+/// * `value` - Pointer to RayaValue
+/// * `out` - Receives the float on success
+///
+/// # Returns
+/// * 1 on success
+/// * 0 if the value is not an f64 (or an argument is NULL)
+///
+/// # Safety
+/// Pointers must be valid
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_as_f64(value: *const RayaValue, out: *mut f64) -> c_int {
+    if value.is_null() || out.is_null() {
+        return 0;
+    }
+
+    let handle = &*(value as *const ValueHandle);
+    if handle.string.is_some() {
+        return 0;
+    }
+    match handle.value.as_f64() {
+        Some(v) => {
+            *out = v;
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Read a boolean value
+///
+/// # Arguments
+/// * `value` - Pointer to RayaValue
+/// * `out` - Receives 1 (true) or 0 (false) on success
+///
+/// # Returns
+/// * 1 on success
+/// * 0 if the value is not a boolean (or an argument is NULL)
+///
+/// # Safety
+/// Pointers must be valid
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_as_bool(value: *const RayaValue, out: *mut c_int) -> c_int {
+    if value.is_null() || out.is_null() {
+        return 0;
+    }
+
+    match (*(value as *const ValueHandle)).value.as_bool() {
+        Some(v) => {
+            *out = c_int::from(v);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Read a string value
+///
+/// # Arguments
+/// * `value` - Pointer to RayaValue
+/// * `out` - Receives a newly allocated null-terminated copy of the string
+///
+/// # Returns
+/// * 1 on success
+/// * 0 if the value is not a string (or an argument is NULL)
+///
+/// # Safety
+/// - Pointers must be valid
+/// - The string written to `out` must be freed with `raya_string_free()`
+#[no_mangle]
+pub unsafe extern "C" fn raya_value_as_string(
+    value: *const RayaValue,
+    out: *mut *mut c_char,
+) -> c_int {
+    if value.is_null() || out.is_null() {
+        return 0;
+    }
+
+    match &(*(value as *const ValueHandle)).string {
+        Some(s) => {
+            let c_str = rust_to_c_string(s);
+            if c_str.is_null() {
+                // Interior NUL bytes cannot be represented as a C string
+                return 0;
+            }
+            *out = c_str;
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Free a string returned by `raya_value_as_string()`
+///
+/// # Arguments
+/// * `s` - Pointer to string (may be NULL)
+///
+/// # Safety
+/// - String must have been returned by `raya_value_as_string()`
+/// - String must not be used after this call
+#[no_mangle]
+pub unsafe extern "C" fn raya_string_free(s: *mut c_char) {
+    if s.is_null() {
+        return;
+    }
+
+    let _ = CString::from_raw(s);
+}
+
 // ============================================================================
 // Error Handling Functions
 // ============================================================================
@@ -448,6 +1059,222 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_eval_reuses_vm_across_snippets() {
+        unsafe fn eval_number(vm: *mut RayaVM, source: &str) -> f64 {
+            let source = CString::new(source).unwrap();
+            let mut result: *mut RayaValue = ptr::null_mut();
+            let mut error: *mut RayaError = ptr::null_mut();
+            let ok = raya_vm_eval(vm, source.as_ptr(), &mut result, &mut error);
+            if ok == 0 {
+                let message = CStr::from_ptr(raya_error_message(error)).to_string_lossy();
+                panic!("eval failed: {}", message);
+            }
+
+            let mut i: i32 = 0;
+            let mut f: f64 = 0.0;
+            let number = if raya_value_as_i32(result, &mut i) == 1 {
+                f64::from(i)
+            } else {
+                assert_eq!(raya_value_as_f64(result, &mut f), 1);
+                f
+            };
+            raya_value_free(result);
+            number
+        }
+
+        unsafe {
+            let vm = raya_vm_new(ptr::null_mut());
+            assert_eq!(eval_number(vm, "return 1 + 2;"), 3.0);
+            assert_eq!(eval_number(vm, "let x = 20; return x * 2 + 2;"), 42.0);
+            raya_vm_destroy(vm);
+        }
+    }
+
+    #[test]
+    fn test_call_function_with_arguments() {
+        unsafe {
+            let vm = raya_vm_new(ptr::null_mut());
+            let source = CString::new(
+                "function add(a: number, b: number): number { return a + b; }\n\
+                 function greet(name: string): string { return \"hi \" + name; }\n\
+                 return 0;",
+            )
+            .unwrap();
+            let mut error: *mut RayaError = ptr::null_mut();
+            assert_eq!(
+                raya_vm_eval(vm, source.as_ptr(), ptr::null_mut(), &mut error),
+                1
+            );
+
+            let name = CString::new("add").unwrap();
+            let args = [raya_value_f64(40.0), raya_value_f64(2.0)];
+            let mut result: *mut RayaValue = ptr::null_mut();
+            let ok = raya_vm_call_function(
+                vm,
+                name.as_ptr(),
+                args.as_ptr(),
+                args.len(),
+                &mut result,
+                &mut error,
+            );
+            assert_eq!(ok, 1);
+            assert!(error.is_null());
+            let mut sum: f64 = 0.0;
+            assert_eq!(raya_value_as_f64(result, &mut sum), 1);
+            assert_eq!(sum, 42.0);
+            raya_value_free(result);
+            for arg in args {
+                raya_value_free(arg);
+            }
+
+            let name = CString::new("greet").unwrap();
+            let who = CString::new("raya").unwrap();
+            let args = [raya_value_string(who.as_ptr())];
+            let ok = raya_vm_call_function(
+                vm,
+                name.as_ptr(),
+                args.as_ptr(),
+                args.len(),
+                &mut result,
+                &mut error,
+            );
+            assert_eq!(ok, 1);
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(raya_value_as_string(result, &mut out), 1);
+            assert_eq!(CStr::from_ptr(out).to_str().unwrap(), "hi raya");
+            raya_string_free(out);
+            raya_value_free(result);
+            raya_value_free(args[0]);
+
+            raya_vm_destroy(vm);
+        }
+    }
+
+    #[test]
+    fn test_call_function_reports_missing_function_and_arity() {
+        unsafe {
+            let vm = raya_vm_new(ptr::null_mut());
+            let source =
+                CString::new("function add(a: number, b: number): number { return a + b; }")
+                    .unwrap();
+            assert_eq!(
+                raya_vm_eval(vm, source.as_ptr(), ptr::null_mut(), ptr::null_mut()),
+                1
+            );
+
+            let missing = CString::new("nope").unwrap();
+            let mut error: *mut RayaError = ptr::null_mut();
+            let status = raya_vm_call_function(
+                vm,
+                missing.as_ptr(),
+                ptr::null(),
+                0,
+                ptr::null_mut(),
+                &mut error,
+            );
+            assert_eq!(status, -1);
+            let message = CStr::from_ptr(raya_error_message(error)).to_str().unwrap();
+            assert!(message.contains("'nope' not found"), "{}", message);
+            raya_error_free(error);
+
+            let name = CString::new("add").unwrap();
+            let args = [raya_value_f64(1.0)];
+            let mut error: *mut RayaError = ptr::null_mut();
+            let status = raya_vm_call_function(
+                vm,
+                name.as_ptr(),
+                args.as_ptr(),
+                args.len(),
+                ptr::null_mut(),
+                &mut error,
+            );
+            assert_eq!(status, 0);
+            let message = CStr::from_ptr(raya_error_message(error)).to_str().unwrap();
+            assert!(
+                message.contains("expects 2 argument(s), got 1"),
+                "{}",
+                message
+            );
+            raya_error_free(error);
+            raya_value_free(args[0]);
+
+            raya_vm_destroy(vm);
+        }
+    }
+
+    #[test]
+    fn test_registered_callback_is_callable_from_raya() {
+        unsafe extern "C" fn bump(
+            args: *const *const RayaValue,
+            argc: usize,
+            user_data: *mut c_void,
+        ) -> *mut RayaValue {
+            let counter = &*(user_data as *const std::sync::atomic::AtomicI32);
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+            assert_eq!(argc, 1);
+            let mut step: f64 = 0.0;
+            let mut int_step: i32 = 0;
+            if raya_value_as_i32(*args, &mut int_step) == 1 {
+                step = f64::from(int_step);
+            } else {
+                assert_eq!(raya_value_as_f64(*args, &mut step), 1);
+            }
+            raya_value_f64(step * 10.0)
+        }
+
+        unsafe {
+            let counter = std::sync::atomic::AtomicI32::new(0);
+            let vm = raya_vm_new(ptr::null_mut());
+            let name = CString::new("host.bump").unwrap();
+            let mut error: *mut RayaError = ptr::null_mut();
+            let registered = raya_vm_register_callback(
+                vm,
+                name.as_ptr(),
+                Some(bump),
+                &counter as *const _ as *mut c_void,
+                &mut error,
+            );
+            assert_eq!(registered, 1);
+            assert!(error.is_null());
+
+            let source = CString::new(
+                r#"
+                const a: number = __NATIVE_CALL<number>("host.bump", 1);
+                const b: number = __NATIVE_CALL<number>("host.bump", 2);
+                return a + b;
+                "#,
+            )
+            .unwrap();
+            let mut result: *mut RayaValue = ptr::null_mut();
+            let ok = raya_vm_eval(vm, source.as_ptr(), &mut result, &mut error);
+            if ok == 0 {
+                let message = CStr::from_ptr(raya_error_message(error)).to_string_lossy();
+                panic!("eval failed: {}", message);
+            }
+
+            let mut sum: f64 = 0.0;
+            assert_eq!(raya_value_as_f64(result, &mut sum), 1);
+            assert_eq!(sum, 30.0);
+            assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+            // Registering the same name twice is rejected
+            let again = raya_vm_register_callback(
+                vm,
+                name.as_ptr(),
+                Some(bump),
+                ptr::null_mut(),
+                &mut error,
+            );
+            assert_eq!(again, 0);
+            raya_error_free(error);
+
+            raya_value_free(result);
+            raya_vm_destroy(vm);
+        }
+    }
+
     #[test]
     fn test_value_creation() {
         unsafe {
@@ -468,6 +1295,150 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_value_accessors_round_trip() {
+        unsafe {
+            let null = raya_value_null();
+            assert_eq!(raya_value_type(null), RAYA_VALUE_NULL);
+            raya_value_free(null);
+
+            let bool_val = raya_value_bool(1);
+            let mut b: c_int = 0;
+            assert_eq!(raya_value_type(bool_val), RAYA_VALUE_BOOL);
+            assert_eq!(raya_value_as_bool(bool_val, &mut b), 1);
+            assert_eq!(b, 1);
+            raya_value_free(bool_val);
+
+            let int_val = raya_value_i32(-42);
+            let mut i: i32 = 0;
+            assert_eq!(raya_value_type(int_val), RAYA_VALUE_I32);
+            assert_eq!(raya_value_as_i32(int_val, &mut i), 1);
+            assert_eq!(i, -42);
+            raya_value_free(int_val);
+
+            let long_val = raya_value_i64(-7);
+            let mut l: i64 = 0;
+            assert_eq!(raya_value_type(long_val), RAYA_VALUE_I64);
+            assert_eq!(raya_value_as_i64(long_val, &mut l), 1);
+            assert_eq!(l, -7);
+            raya_value_free(long_val);
+
+            let float_val = raya_value_f64(2.5);
+            let mut f: f64 = 0.0;
+            assert_eq!(raya_value_type(float_val), RAYA_VALUE_F64);
+            assert_eq!(raya_value_as_f64(float_val, &mut f), 1);
+            assert_eq!(f, 2.5);
+            raya_value_free(float_val);
+
+            let input = CString::new("hello").unwrap();
+            let string_val = raya_value_string(input.as_ptr());
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(raya_value_type(string_val), RAYA_VALUE_STRING);
+            assert_eq!(raya_value_as_string(string_val, &mut out), 1);
+            assert_eq!(CStr::from_ptr(out).to_str().unwrap(), "hello");
+            raya_string_free(out);
+            raya_value_free(string_val);
+        }
+    }
+
+    #[test]
+    fn test_value_accessor_type_mismatch() {
+        unsafe {
+            let int_val = raya_value_i32(7);
+            let mut f: f64 = 0.0;
+            let mut b: c_int = 0;
+            let mut s: *mut c_char = ptr::null_mut();
+            assert_eq!(raya_value_as_f64(int_val, &mut f), 0);
+            assert_eq!(raya_value_as_bool(int_val, &mut b), 0);
+            assert_eq!(raya_value_as_string(int_val, &mut s), 0);
+            assert!(s.is_null());
+            raya_value_free(int_val);
+
+            let input = CString::new("7").unwrap();
+            let string_val = raya_value_string(input.as_ptr());
+            let mut i: i32 = 0;
+            assert_eq!(raya_value_as_i32(string_val, &mut i), 0);
+            assert_eq!(raya_value_as_f64(string_val, &mut f), 0);
+            raya_value_free(string_val);
+
+            assert_eq!(raya_value_type(ptr::null()), -1);
+        }
+    }
+
+    #[test]
+    fn test_value_i64_range_and_widening() {
+        unsafe {
+            let mut l: i64 = 0;
+            let mut f: f64 = 0.0;
+            let wide = i64::from(i32::MAX) + 1;
+            let wide_val = raya_value_i64(wide);
+            assert_eq!(raya_value_type(wide_val), RAYA_VALUE_F64);
+            assert_eq!(raya_value_as_i64(wide_val, &mut l), 1);
+            assert_eq!(l, wide);
+            raya_value_free(wide_val);
+
+            let min_val = raya_value_i64(i64::MIN);
+            assert_eq!(raya_value_as_f64(min_val, &mut f), 1);
+            assert_eq!(f, i64::MIN as f64);
+            assert_eq!(raya_value_as_i64(min_val, &mut l), 0);
+            raya_value_free(min_val);
+
+            let int_val = raya_value_i32(i32::MIN);
+            assert_eq!(raya_value_as_i64(int_val, &mut l), 1);
+            assert_eq!(l, i64::from(i32::MIN));
+            raya_value_free(int_val);
+
+            let float_val = raya_value_f64(1.5);
+            assert_eq!(raya_value_as_i64(float_val, &mut l), 0);
+            raya_value_free(float_val);
+
+            let long_val = raya_value_i64(5);
+            let mut i: i32 = 0;
+            assert_eq!(raya_value_as_i32(long_val, &mut i), 0);
+            assert_eq!(raya_value_as_f64(long_val, &mut f), 0);
+            raya_value_free(long_val);
+        }
+    }
+
+    #[test]
+    fn test_value_type_tags_are_stable() {
+        assert_eq!(RAYA_VALUE_NULL, 0);
+        assert_eq!(RAYA_VALUE_BOOL, 1);
+        assert_eq!(RAYA_VALUE_I32, 2);
+        assert_eq!(RAYA_VALUE_I64, 3);
+        assert_eq!(RAYA_VALUE_F64, 4);
+        assert_eq!(RAYA_VALUE_STRING, 5);
+        assert_eq!(RAYA_VALUE_OBJECT, 6);
+        assert_eq!(RAYA_VALUE_OTHER, 7);
+    }
+
+    #[test]
+    fn test_value_type_of_returned_object() {
+        unsafe {
+            let vm = raya_vm_new(ptr::null_mut());
+            let source = CString::new(
+                "class Point { x: number; constructor(x: number) { this.x = x; } }\n\
+                 return new Point(3);",
+            )
+            .unwrap();
+            let mut result: *mut RayaValue = ptr::null_mut();
+            let mut error: *mut RayaError = ptr::null_mut();
+            assert_eq!(
+                raya_vm_eval(vm, source.as_ptr(), &mut result, &mut error),
+                1
+            );
+
+            assert_eq!(raya_value_type(result), RAYA_VALUE_OBJECT);
+            let mut f: f64 = 0.0;
+            let mut s: *mut c_char = ptr::null_mut();
+            assert_eq!(raya_value_as_f64(result, &mut f), 0);
+            assert_eq!(raya_value_as_string(result, &mut s), 0);
+            raya_value_free(result);
+
+            raya_vm_destroy(vm);
+        }
+    }
+
     #[test]
     fn test_version() {
         unsafe {
@@ -22,9 +22,13 @@ pub use loader::{Library, LoadError};
 // Re-export C API types
 pub use c_api::{
     raya_error_free, raya_error_message, raya_module_free, raya_module_load_bytes,
-    raya_module_load_file, raya_value_bool, raya_value_free, raya_value_i32, raya_value_null,
-    raya_version, raya_vm_destroy, raya_vm_execute, raya_vm_new, RayaError, RayaModule, RayaVM,
-    RayaValue,
+    raya_module_load_file, raya_string_free, raya_value_as_bool, raya_value_as_f64,
+    raya_value_as_i32, raya_value_as_i64, raya_value_as_string, raya_value_bool, raya_value_f64,
+    raya_value_free, raya_value_i32, raya_value_i64, raya_value_null, raya_value_string,
+    raya_value_type, raya_version, raya_vm_call_function, raya_vm_destroy, raya_vm_eval,
+    raya_vm_execute, raya_vm_new, raya_vm_register_callback, RayaCallback, RayaError, RayaModule,
+    RayaVM, RayaValue, RAYA_VALUE_BOOL, RAYA_VALUE_F64, RAYA_VALUE_I32, RAYA_VALUE_I64,
+    RAYA_VALUE_NULL, RAYA_VALUE_OBJECT, RAYA_VALUE_OTHER, RAYA_VALUE_STRING,
 };
 
 // ============================================================================
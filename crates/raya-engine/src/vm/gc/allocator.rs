@@ -0,0 +1,49 @@
+//! Backing-store allocation for the GC heap
+//!
+//! The heap lays out every object itself (header, backlink, value) and asks a
+//! `GcAllocator` only for the raw block holding it. Embedders can supply their
+//! own allocator (an arena, a tracking allocator, ...) through
+//! `VmOptions::gc_allocator`; by default blocks come from the global allocator.
+
+use std::alloc::Layout;
+use std::sync::Arc;
+
+/// Source of raw memory blocks for GC-managed objects
+///
+/// # Safety
+///
+/// Implementations must follow the `GlobalAlloc` contract: `alloc` returns
+/// either null or a block valid for `layout` that stays valid until it is
+/// passed back to `dealloc` with the same layout.
+pub unsafe trait GcAllocator: Send + Sync {
+    /// Allocate a block for `layout`, or return null on failure
+    ///
+    /// # Safety
+    ///
+    /// `layout` has a non-zero size.
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8;
+
+    /// Release a block previously returned by `alloc`
+    ///
+    /// # Safety
+    ///
+    /// `ptr` was returned by `alloc` on this allocator with the same `layout`.
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout);
+}
+
+/// Default allocator: the process-wide global allocator
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemGcAllocator;
+
+unsafe impl GcAllocator for SystemGcAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        std::alloc::alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        std::alloc::dealloc(ptr, layout)
+    }
+}
+
+/// Shared handle to the allocator a heap draws from
+pub type SharedGcAllocator = Arc<dyn GcAllocator>;
@@ -1,15 +1,27 @@
 //! Mark-sweep garbage collector
 //!
 //! This module implements a simple mark-sweep garbage collector.
+//!
+//! Collection is stop-the-world by default. In incremental mode both phases
+//! are spread over allocations: marking drains a work list of gray objects a
+//! step at a time, with [`write_barrier`] shading anything stored into the
+//! heap meanwhile, and the dead are then freed a step at a time.
 
+use super::allocator::SharedGcAllocator;
 use super::header::GcHeader;
 use super::heap::Heap;
+use super::heap_graph::HeapGraph;
 use super::ptr::GcPtr;
 use super::roots::RootSet;
 use crate::vm::interpreter::VmContextId;
+use crate::vm::object::{RayaString, StringData};
 use crate::vm::types::TypeRegistry;
 use crate::vm::value::Value;
 use dashmap::DashMap;
+use rustc_hash::FxHashMap;
+use std::any::Any;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::time::{Duration, Instant};
@@ -42,6 +54,56 @@ pub fn unregister_external_roots_provider(context_id: VmContextId) {
     external_root_providers().remove(&context_id.as_u64());
 }
 
+/// Number of contexts with an incremental mark in progress
+static MARKING_CONTEXTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Objects the write barrier shaded, by the id of the context that is marking
+/// them. A context has an entry exactly while its mark is in progress.
+fn barrier_buffers() -> &'static DashMap<u64, Vec<usize>> {
+    static BUFFERS: OnceLock<DashMap<u64, Vec<usize>>> = OnceLock::new();
+    BUFFERS.get_or_init(DashMap::new)
+}
+
+/// Write barrier for incremental marking
+///
+/// Must follow every store of a heap value into an `Object` field or dynamic
+/// property, or an `Array` element. While the value's context is marking, an
+/// unmarked value is queued for the collector to trace before the mark ends,
+/// so a reference moved into an already-scanned object is not missed.
+/// Otherwise this is a single atomic load.
+#[inline]
+pub fn write_barrier(value: Value) {
+    if MARKING_CONTEXTS.load(Ordering::Acquire) != 0 {
+        shade_stored_value(value);
+    }
+}
+
+/// [`write_barrier`] for several stored values
+#[inline]
+pub fn write_barrier_all(values: &[Value]) {
+    if MARKING_CONTEXTS.load(Ordering::Acquire) != 0 {
+        values.iter().copied().for_each(shade_stored_value);
+    }
+}
+
+#[cold]
+fn shade_stored_value(value: Value) {
+    if !value.is_heap_allocated() {
+        return;
+    }
+    let Some(ptr) = (unsafe { value.as_ptr::<u8>() }) else {
+        return;
+    };
+    let header_ptr = unsafe { super::header_mut_ptr_from_value_ptr(ptr.as_ptr()) };
+    let header = unsafe { &*header_ptr };
+    if header.is_marked() {
+        return;
+    }
+    if let Some(mut buffer) = barrier_buffers().get_mut(&header.context_id().as_u64()) {
+        buffer.push(header_ptr as usize);
+    }
+}
+
 /// Garbage collector statistics
 #[derive(Debug, Clone)]
 pub struct GcStats {
@@ -83,6 +145,12 @@ pub struct GcStats {
 
     /// Live bytes after last collection
     pub live_bytes: usize,
+
+    /// Largest number of bytes traced by a single incremental mark step
+    pub max_mark_step_bytes: usize,
+
+    /// Largest number of bytes freed by a single incremental sweep step
+    pub max_sweep_step_bytes: usize,
 }
 
 impl Default for GcStats {
@@ -101,6 +169,8 @@ impl Default for GcStats {
             last_freed_bytes: 0,
             live_objects: 0,
             live_bytes: 0,
+            max_mark_step_bytes: 0,
+            max_sweep_step_bytes: 0,
         }
     }
 }
@@ -141,6 +211,14 @@ impl GcStats {
         self.live_bytes = live_bytes;
     }
 
+    /// Account for objects freed after the collection that found them dead
+    fn record_deferred_free(&mut self, freed: usize, freed_bytes: usize) {
+        self.objects_freed += freed;
+        self.bytes_freed += freed_bytes;
+        self.last_freed_count += freed;
+        self.last_freed_bytes += freed_bytes;
+    }
+
     /// Get survival rate (0.0 to 1.0)
     pub fn survival_rate(&self) -> f64 {
         if self.last_marked_count == 0 {
@@ -150,6 +228,32 @@ impl GcStats {
     }
 }
 
+/// A weak structure a script object holds through an untraced handle
+enum WeakCell {
+    /// `WeakRef` target, `None` once collected
+    Ref(Option<Value>),
+    /// `WeakMap` entries as `(key, value)`, by key identity
+    Map(FxHashMap<u64, (Value, Value)>),
+}
+
+struct WeakSlot {
+    /// Object holding the handle; the slot is freed once it is collected
+    owner: Value,
+    cell: WeakCell,
+}
+
+/// Whether the mark phase reached `value` (values outside the heap always count)
+fn value_is_marked(value: Value) -> bool {
+    if !value.is_heap_allocated() {
+        return true;
+    }
+    let Some(ptr) = (unsafe { value.as_ptr::<u8>() }) else {
+        return true;
+    };
+    let header_ptr = unsafe { super::header_ptr_from_value_ptr(ptr.as_ptr()) };
+    unsafe { (*header_ptr).is_marked() }
+}
+
 /// Mark-sweep garbage collector
 pub struct GarbageCollector {
     /// Heap allocator
@@ -163,6 +267,32 @@ pub struct GarbageCollector {
 
     /// Statistics
     stats: GcStats,
+
+    /// Weak structures by handle (`None` for free slots). Their contents are
+    /// never traced; a slot is freed once its owner is collected.
+    weak_cells: Vec<Option<WeakSlot>>,
+
+    /// Free slots in `weak_cells`, reused before the table grows
+    free_weak_cells: Vec<usize>,
+
+    /// Incremental collection budget in bytes per step (None = stop-the-world)
+    incremental_step_bytes: Option<usize>,
+
+    /// Whether an incremental mark is in progress
+    marking: bool,
+
+    /// Gray objects: marked, but their references not yet traced
+    mark_stack: Vec<*mut GcHeader>,
+
+    /// Objects allocated during the incremental mark. They start marked and
+    /// are traced when the mark finishes, so stores made while initializing
+    /// them need no barrier.
+    allocated_while_marking: Vec<*mut GcHeader>,
+
+    /// Live short strings by contents, so that allocating an equal one
+    /// returns the existing object. Weak: entries are dropped once their
+    /// string is unmarked at the end of a mark.
+    short_strings: FxHashMap<StringData, GcPtr<RayaString>>,
 }
 
 impl GarbageCollector {
@@ -173,6 +303,13 @@ impl GarbageCollector {
             roots: RootSet::new(),
             threshold: crate::vm::defaults::DEFAULT_GC_THRESHOLD,
             stats: GcStats::default(),
+            weak_cells: Vec::new(),
+            free_weak_cells: Vec::new(),
+            incremental_step_bytes: None,
+            marking: false,
+            mark_stack: Vec::new(),
+            allocated_while_marking: Vec::new(),
+            short_strings: FxHashMap::default(),
         }
     }
 
@@ -186,19 +323,62 @@ impl GarbageCollector {
         self.heap.set_max_heap_size(bytes);
     }
 
+    /// Enable incremental collection with a per-step budget in bytes, or
+    /// return to stop-the-world collection with `None`
+    ///
+    /// Each allocation then does one step of pending work: tracing up to the
+    /// budget's worth of objects while marking, or freeing up to the budget's
+    /// worth of dead objects while sweeping. An object larger than the budget
+    /// is handled in a step of its own. Switching back to stop-the-world
+    /// abandons an unfinished mark and frees whatever is still pending.
+    pub fn set_incremental(&mut self, step_bytes: Option<usize>) {
+        self.incremental_step_bytes = step_bytes.map(|bytes| bytes.max(1));
+        if self.incremental_step_bytes.is_none() {
+            self.abandon_marking();
+            self.finish_sweep();
+        }
+    }
+
+    /// Set the allocator backing the heap (before anything is allocated)
+    pub fn set_allocator(&mut self, allocator: SharedGcAllocator) {
+        self.heap.set_allocator(allocator);
+    }
+
     /// Context identity for externally registered root providers.
     pub fn context_id(&self) -> VmContextId {
         self.heap.context_id()
     }
 
     /// Allocate a value
+    ///
+    /// A short, non-interned [`RayaString`] equal to a live one is not
+    /// allocated: the existing object is returned instead, since strings are
+    /// immutable and compare by contents.
     pub fn allocate<T: 'static>(&mut self, value: T) -> GcPtr<T> {
-        // Check if we should collect
-        if self.should_collect() && !has_external_roots_provider(self.heap.context_id()) {
-            self.collect();
+        let shareable = (&value as &dyn Any)
+            .downcast_ref::<RayaString>()
+            .and_then(RayaString::shareable_contents)
+            .cloned();
+        if let Some(existing) = shareable
+            .as_ref()
+            .and_then(|contents| self.short_strings.get(contents).copied())
+        {
+            if self.marking {
+                self.shade_header(unsafe { existing.header_mut() });
+            }
+            // SAFETY: `T` is `RayaString`, as the downcast above succeeded.
+            return unsafe { GcPtr::new(NonNull::new_unchecked(existing.as_ptr().cast())) };
         }
 
-        self.heap.allocate(value)
+        self.maybe_collect();
+
+        let ptr = self.heap.allocate(value);
+        self.shade_new_allocation(unsafe { ptr.header_mut() });
+        if let Some(contents) = shareable {
+            let string = unsafe { GcPtr::new(NonNull::new_unchecked(ptr.as_ptr().cast())) };
+            self.short_strings.insert(contents, string);
+        }
+        ptr
     }
 
     /// Allocate an array
@@ -206,12 +386,19 @@ impl GarbageCollector {
     where
         T: 'static + Default + Clone,
     {
-        // Check if we should collect
-        if self.should_collect() && !has_external_roots_provider(self.heap.context_id()) {
-            self.collect();
-        }
+        self.maybe_collect();
+
+        let ptr = self.heap.allocate_array(len);
+        self.shade_new_allocation(unsafe { ptr.header_mut() });
+        ptr
+    }
 
-        self.heap.allocate_array(len)
+    /// Allocate black while marking, to be traced once the mark finishes
+    fn shade_new_allocation(&mut self, header: &mut GcHeader) {
+        if self.marking {
+            header.mark();
+            self.allocated_while_marking.push(header);
+        }
     }
 
     /// Add a root
@@ -224,11 +411,114 @@ impl GarbageCollector {
         self.roots.clear_stack_roots();
     }
 
+    /// Number of stack roots, to pass to [`Self::truncate_stack_roots`]
+    pub fn stack_root_count(&self) -> usize {
+        self.roots.stack_root_count()
+    }
+
+    /// Release the stack roots added since [`Self::stack_root_count`]
+    /// returned `count`
+    pub fn truncate_stack_roots(&mut self, count: usize) {
+        self.roots.truncate_stack_roots(count);
+    }
+
+    /// Create a weak reference to `target`, held by `owner`, and return its handle
+    pub fn create_weak_ref(&mut self, owner: Value, target: Value) -> u64 {
+        self.insert_weak_cell(owner, WeakCell::Ref(Some(target)))
+    }
+
+    /// Target of a weak reference, or `None` once it has been collected
+    pub fn weak_ref_target(&self, handle: u64) -> Option<Value> {
+        match self.weak_cell(handle)? {
+            WeakCell::Ref(target) => *target,
+            WeakCell::Map(_) => None,
+        }
+    }
+
+    /// Create an empty weak map, held by `owner`, and return its handle
+    pub fn create_weak_map(&mut self, owner: Value) -> u64 {
+        self.insert_weak_cell(owner, WeakCell::Map(FxHashMap::default()))
+    }
+
+    /// Value stored under `key` in a weak map
+    pub fn weak_map_get(&self, handle: u64, key: Value) -> Option<Value> {
+        match self.weak_cell(handle)? {
+            WeakCell::Map(entries) => entries.get(&key.raw()).map(|&(_, value)| value),
+            WeakCell::Ref(_) => None,
+        }
+    }
+
+    /// Whether a weak map has an entry for `key`
+    pub fn weak_map_has(&self, handle: u64, key: Value) -> bool {
+        match self.weak_cell(handle) {
+            Some(WeakCell::Map(entries)) => entries.contains_key(&key.raw()),
+            _ => false,
+        }
+    }
+
+    /// Store `value` under `key` in a weak map; `false` if `handle` is not a weak map
+    pub fn weak_map_set(&mut self, handle: u64, key: Value, value: Value) -> bool {
+        match self.weak_cell_mut(handle) {
+            Some(WeakCell::Map(entries)) => {
+                entries.insert(key.raw(), (key, value));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove `key` from a weak map, returning whether it was present
+    pub fn weak_map_delete(&mut self, handle: u64, key: Value) -> bool {
+        match self.weak_cell_mut(handle) {
+            Some(WeakCell::Map(entries)) => entries.remove(&key.raw()).is_some(),
+            _ => false,
+        }
+    }
+
+    fn insert_weak_cell(&mut self, owner: Value, cell: WeakCell) -> u64 {
+        let slot = Some(WeakSlot { owner, cell });
+        match self.free_weak_cells.pop() {
+            Some(index) => {
+                self.weak_cells[index] = slot;
+                index as u64
+            }
+            None => {
+                self.weak_cells.push(slot);
+                (self.weak_cells.len() - 1) as u64
+            }
+        }
+    }
+
+    fn weak_cell(&self, handle: u64) -> Option<&WeakCell> {
+        let slot = self.weak_cells.get(usize::try_from(handle).ok()?)?;
+        slot.as_ref().map(|slot| &slot.cell)
+    }
+
+    fn weak_cell_mut(&mut self, handle: u64) -> Option<&mut WeakCell> {
+        let slot = self.weak_cells.get_mut(usize::try_from(handle).ok()?)?;
+        slot.as_mut().map(|slot| &mut slot.cell)
+    }
+
     /// Check if we should collect
     fn should_collect(&self) -> bool {
         self.heap.allocated_bytes() > self.threshold
     }
 
+    /// Allocation-time GC work: continue an incremental mark or sweep, or
+    /// start a collection once the threshold is crossed
+    fn maybe_collect(&mut self) {
+        if self.marking {
+            self.mark_step();
+        } else if self.heap.detached_count() > 0 {
+            self.sweep_step();
+        } else if self.should_collect() && !has_external_roots_provider(self.heap.context_id()) {
+            match self.incremental_step_bytes {
+                Some(_) => self.start_incremental_mark(),
+                None => self.collect(),
+            }
+        }
+    }
+
     fn external_root_snapshot(&self) -> ExternalRootSnapshot {
         external_root_providers()
             .get(&self.heap.context_id().as_u64())
@@ -241,16 +531,24 @@ impl GarbageCollector {
     }
 
     /// Run garbage collection
+    ///
+    /// Always runs to completion: an unfinished incremental mark is abandoned
+    /// and any pending incremental sweep is finished first.
     pub fn collect(&mut self) {
         let snapshot = self.external_root_snapshot();
         if !snapshot.complete {
             return;
         }
+        self.abandon_marking();
+        self.finish_sweep();
         let start = Instant::now();
 
         // Mark phase
         let marked_count = self.mark(snapshot.roots);
 
+        // Keep live keys' weak map values, drop everything else about to be freed
+        self.process_weak_cells();
+
         // Sweep phase
         let (freed_count, freed_bytes) = self.sweep();
 
@@ -269,7 +567,150 @@ impl GarbageCollector {
             live_bytes,
         );
 
-        // Adjust threshold (grow by 2x current usage)
+        self.adjust_threshold();
+    }
+
+    /// Shade the roots and start tracing from them a step at a time
+    ///
+    /// Relies on every object being unmarked between collections, which
+    /// [`Heap::detach_unmarked`] restores at the end of each one.
+    fn start_incremental_mark(&mut self) {
+        let snapshot = self.external_root_snapshot();
+        if !snapshot.complete {
+            return;
+        }
+        barrier_buffers().insert(self.heap.context_id().as_u64(), Vec::new());
+        MARKING_CONTEXTS.fetch_add(1, Ordering::AcqRel);
+        self.marking = true;
+
+        self.shade_roots(snapshot.roots);
+        self.mark_step();
+    }
+
+    /// Trace gray objects up to the incremental step budget, finishing the
+    /// mark once none are left
+    fn mark_step(&mut self) {
+        let budget = self.incremental_step_bytes.unwrap_or(usize::MAX);
+        let mut traced_count = 0;
+        let mut traced_bytes = 0;
+
+        self.drain_barrier_buffer();
+        while let Some(&header_ptr) = self.mark_stack.last() {
+            let size = unsafe { (*header_ptr).size() };
+            if traced_count > 0 && traced_bytes + size > budget {
+                break;
+            }
+            self.mark_stack.pop();
+            traced_count += 1;
+            traced_bytes += size + self.trace(header_ptr) * std::mem::size_of::<Value>();
+        }
+        self.stats.max_mark_step_bytes = self.stats.max_mark_step_bytes.max(traced_bytes);
+
+        if self.mark_stack.is_empty() {
+            self.finish_marking();
+        }
+    }
+
+    /// Final atomic step of an incremental mark: re-shade the roots (which
+    /// have no barrier), trace everything allocated or stored meanwhile, then
+    /// detach the dead for [`Self::sweep_step`] to free a budget at a time
+    fn finish_marking(&mut self) {
+        let snapshot = self.external_root_snapshot();
+        if !snapshot.complete {
+            // Try again on the next allocation
+            return;
+        }
+        let start = Instant::now();
+
+        self.shade_roots(snapshot.roots);
+        let allocated = std::mem::take(&mut self.allocated_while_marking);
+        self.mark_stack.extend(allocated);
+        loop {
+            self.drain_barrier_buffer();
+            if self.mark_stack.is_empty() {
+                break;
+            }
+            self.drain_mark_stack();
+        }
+        self.stop_marking();
+
+        self.process_weak_cells();
+        // Unlink the dead now so heap walks never hand them back before they're freed
+        self.heap.detach_unmarked();
+
+        let live_objects = self.heap.allocation_count();
+        self.stats.update(
+            start.elapsed(),
+            live_objects,
+            0,
+            0,
+            live_objects,
+            self.heap.allocated_bytes(),
+        );
+    }
+
+    /// Leave the marking state, dropping the barrier buffer
+    fn stop_marking(&mut self) {
+        if !self.marking {
+            return;
+        }
+        self.marking = false;
+        barrier_buffers().remove(&self.heap.context_id().as_u64());
+        MARKING_CONTEXTS.fetch_sub(1, Ordering::AcqRel);
+    }
+
+    /// Drop an unfinished incremental mark, clearing its mark bits
+    fn abandon_marking(&mut self) {
+        if !self.marking {
+            return;
+        }
+        self.stop_marking();
+        self.mark_stack.clear();
+        self.allocated_while_marking.clear();
+        for header_ptr in self.heap.iter_allocations() {
+            unsafe {
+                (*header_ptr).unmark();
+            }
+        }
+    }
+
+    /// Move the objects the write barrier queued onto the mark stack
+    fn drain_barrier_buffer(&mut self) {
+        let queued = barrier_buffers()
+            .get_mut(&self.heap.context_id().as_u64())
+            .map(|mut buffer| std::mem::take(&mut *buffer))
+            .unwrap_or_default();
+        for header_ptr in queued {
+            self.shade_header(header_ptr as *mut GcHeader);
+        }
+    }
+
+    /// Free detached dead objects up to the incremental step budget
+    fn sweep_step(&mut self) {
+        let budget = self.incremental_step_bytes.unwrap_or(usize::MAX);
+        let (freed_count, freed_bytes) = self.heap.free_detached(budget);
+
+        self.stats.record_deferred_free(freed_count, freed_bytes);
+        self.stats.max_sweep_step_bytes = self.stats.max_sweep_step_bytes.max(freed_bytes);
+        if self.heap.detached_count() == 0 {
+            self.stats.live_bytes = self.heap.allocated_bytes();
+            self.adjust_threshold();
+        }
+    }
+
+    /// Free every object still pending from an incremental collection
+    fn finish_sweep(&mut self) {
+        if self.heap.detached_count() == 0 {
+            return;
+        }
+        let (freed_count, freed_bytes) = self.heap.free_detached(usize::MAX);
+        self.stats.record_deferred_free(freed_count, freed_bytes);
+        self.stats.live_bytes = self.heap.allocated_bytes();
+        self.adjust_threshold();
+    }
+
+    /// Adjust threshold (grow by 2x current usage)
+    fn adjust_threshold(&mut self) {
         let current_usage = self.heap.allocated_bytes();
         self.threshold = (current_usage * 2).max(crate::vm::defaults::DEFAULT_GC_THRESHOLD);
     }
@@ -284,15 +725,8 @@ impl GarbageCollector {
             }
         }
 
-        // Mark from roots (collect first to avoid borrow checker issues)
-        let roots: Vec<Value> = self.roots.iter().collect();
-        for root in roots {
-            self.mark_value(root);
-        }
-
-        for root in external_roots {
-            self.mark_value(root);
-        }
+        self.shade_roots(external_roots);
+        self.drain_mark_stack();
 
         // Count marked objects
         let mut marked = 0;
@@ -305,10 +739,30 @@ impl GarbageCollector {
         marked
     }
 
-    /// Mark a single value and its references
+    /// Shade the root set and `external_roots`
+    fn shade_roots(&mut self, external_roots: Vec<Value>) {
+        // Collect first to avoid borrow checker issues
+        let roots: Vec<Value> = self.roots.iter().collect();
+        for root in roots.into_iter().chain(external_roots) {
+            self.shade(root);
+        }
+    }
+
+    /// Mark a single value and everything reachable from it
     fn mark_value(&mut self, value: Value) {
-        use crate::vm::object::{Array, Object};
+        self.shade(value);
+        self.drain_mark_stack();
+    }
 
+    /// Trace every gray object
+    fn drain_mark_stack(&mut self) {
+        while let Some(header_ptr) = self.mark_stack.pop() {
+            self.trace(header_ptr);
+        }
+    }
+
+    /// Mark a value and queue it for tracing, unless it is already marked
+    fn shade(&mut self, value: Value) {
         // Only mark heap-allocated values
         if !value.is_heap_allocated() {
             return;
@@ -321,8 +775,10 @@ impl GarbageCollector {
         };
 
         // Recover the header through the stored back-pointer immediately before the value.
-        let header_ptr = unsafe { super::header_ptr_from_value_ptr(ptr) as *mut GcHeader };
+        self.shade_header(unsafe { super::header_mut_ptr_from_value_ptr(ptr) });
+    }
 
+    fn shade_header(&mut self, header_ptr: *mut GcHeader) {
         // Check if already marked (avoid cycles and redundant work)
         unsafe {
             if (*header_ptr).is_marked() {
@@ -332,105 +788,146 @@ impl GarbageCollector {
             // Mark this object
             (*header_ptr).mark();
         }
+        self.mark_stack.push(header_ptr);
+    }
+
+    /// Shade every reference held by a marked object
+    /// Returns the number of reference slots visited
+    fn trace(&mut self, header_ptr: *mut GcHeader) -> usize {
+        use crate::vm::object::{Array, Object};
+
+        let ptr = unsafe { (header_ptr as *mut u8).add((*header_ptr).value_offset()) };
 
         // Get type information
         let type_id = unsafe { (*header_ptr).type_id() };
-        let type_registry = self.heap.type_registry();
-
-        if let Some(type_info) = type_registry.get(type_id) {
-            // Special handling for Object and Array types (dynamic field counts)
-            let type_name = type_info.name;
-            match type_name {
-                "Object" => {
-                    // Cast to Object and mark each field
-                    let obj = unsafe { &*(ptr as *const Object) };
-                    for &field_value in &obj.fields {
-                        self.mark_value(field_value);
-                    }
-                    if let Some(dyn_map) = obj.dyn_map() {
-                        let dyn_values: Vec<Value> = dyn_map.values().copied().collect();
-                        for value in dyn_values {
-                            self.mark_value(value);
-                        }
-                    }
-                    return;
+        let type_registry = self.heap.type_registry().clone();
+
+        let Some(type_info) = type_registry.get(type_id) else {
+            return 0;
+        };
+
+        // Special handling for Object and Array types (dynamic field counts)
+        let type_name = type_info.name;
+        match type_name {
+            "Object" => {
+                // Cast to Object and shade each field
+                let obj = unsafe { &*(ptr as *const Object) };
+                for &field_value in &obj.fields {
+                    self.shade(field_value);
                 }
-                "Array" => {
-                    // Cast to Array and mark each element
-                    let arr = unsafe { &*(ptr as *const Array) };
-                    for &elem_value in &arr.elements {
-                        self.mark_value(elem_value);
+                let mut slots = obj.fields.len();
+                if let Some(dyn_map) = obj.dyn_map() {
+                    for &value in dyn_map.values() {
+                        self.shade(value);
                     }
-                    return;
-                }
-                "RayaString" => {
-                    // Strings have no GC pointers
-                    return;
+                    slots += dyn_map.len();
                 }
-                "BoundMethod" => {
-                    // Trace the receiver (it's a GC-allocated object)
-                    let bm = unsafe { &*(ptr as *const crate::vm::object::BoundMethod) };
-                    self.mark_value(bm.receiver);
-                    return;
-                }
-                "BoundNativeMethod" => {
-                    let bm = unsafe { &*(ptr as *const crate::vm::object::BoundNativeMethod) };
-                    self.mark_value(bm.receiver);
-                    return;
-                }
-                _ => {
-                    // Use normal pointer map traversal for other types
+                return slots;
+            }
+            "Array" => {
+                // Cast to Array and shade each element
+                let arr = unsafe { &*(ptr as *const Array) };
+                for &elem_value in &arr.elements {
+                    self.shade(elem_value);
                 }
+                return arr.elements.len();
+            }
+            "RayaString" => {
+                // Strings have no GC pointers
+                return 0;
             }
+            "BoundMethod" => {
+                // Trace the receiver (it's a GC-allocated object)
+                let bm = unsafe { &*(ptr as *const crate::vm::object::BoundMethod) };
+                self.shade(bm.receiver);
+                return 1;
+            }
+            "BoundNativeMethod" => {
+                let bm = unsafe { &*(ptr as *const crate::vm::object::BoundNativeMethod) };
+                self.shade(bm.receiver);
+                return 1;
+            }
+            _ => {
+                // Use normal pointer map traversal for other types
+            }
+        }
 
-            // If this type has no pointers, we're done
-            if !type_info.has_pointers() {
-                return;
+        // If this type has no pointers, we're done
+        if !type_info.has_pointers() {
+            return 0;
+        }
+
+        // Traverse all pointer fields using type metadata
+        let mut field_values = Vec::new();
+        type_info.for_each_pointer(ptr, |field_ptr| {
+            // Read the Value from this pointer field
+            let field_value = unsafe { *(field_ptr as *const Value) };
+            field_values.push(field_value);
+        });
+
+        let slots = field_values.len();
+        for field_value in field_values {
+            self.shade(field_value);
+        }
+        slots
+    }
+
+    /// Settle weak structures after the mark phase
+    ///
+    /// Weak map entries are ephemerons: a value is marked once its key is,
+    /// repeating until no further value becomes reachable. Then entries and
+    /// references to unmarked objects are cleared, and the slots of unmarked
+    /// owners are freed. Shared short strings that went unmarked are
+    /// forgotten too.
+    fn process_weak_cells(&mut self) {
+        loop {
+            let mut reachable = Vec::new();
+            for slot in self.weak_cells.iter().flatten() {
+                let WeakCell::Map(entries) = &slot.cell else {
+                    continue;
+                };
+                if !value_is_marked(slot.owner) {
+                    continue;
+                }
+                reachable.extend(
+                    entries
+                        .values()
+                        .filter(|&&(key, value)| value_is_marked(key) && !value_is_marked(value))
+                        .map(|&(_, value)| value),
+                );
+            }
+            if reachable.is_empty() {
+                break;
             }
+            for value in reachable {
+                self.mark_value(value);
+            }
+        }
 
-            // Traverse all pointer fields using type metadata
-            // Collect field values first to avoid borrow checker issues
-            let mut field_values = Vec::new();
-            type_info.for_each_pointer(ptr, |field_ptr| {
-                // Read the Value from this pointer field
-                let field_value = unsafe { *(field_ptr as *const Value) };
-                field_values.push(field_value);
-            });
-
-            // Now recursively mark each field
-            for field_value in field_values {
-                self.mark_value(field_value);
+        for (index, slot) in self.weak_cells.iter_mut().enumerate() {
+            let Some(weak) = slot else { continue };
+            if !value_is_marked(weak.owner) {
+                *slot = None;
+                self.free_weak_cells.push(index);
+                continue;
+            }
+            match &mut weak.cell {
+                WeakCell::Ref(target) => {
+                    if target.is_some_and(|target| !value_is_marked(target)) {
+                        *target = None;
+                    }
+                }
+                WeakCell::Map(entries) => entries.retain(|_, &mut (key, _)| value_is_marked(key)),
             }
         }
+        self.short_strings.retain(|_, string| string.is_marked());
     }
 
     /// Sweep phase: free unmarked objects
     /// Returns (freed_count, freed_bytes)
     fn sweep(&mut self) -> (usize, usize) {
-        let mut freed_count = 0;
-        let mut freed_bytes = 0;
-
-        // Collect unmarked allocations
-        let to_free: Vec<(*mut GcHeader, usize)> = self
-            .heap
-            .iter_allocations()
-            .filter(|&header_ptr| unsafe { !(*header_ptr).is_marked() })
-            .map(|header_ptr| {
-                let size = unsafe { (*header_ptr).size() };
-                (header_ptr, size)
-            })
-            .collect();
-
-        // Free them
-        for (header_ptr, size) in to_free {
-            unsafe {
-                self.heap.free(header_ptr);
-            }
-            freed_count += 1;
-            freed_bytes += size;
-        }
-
-        (freed_count, freed_bytes)
+        self.heap.detach_unmarked();
+        self.heap.free_detached(usize::MAX)
     }
 
     /// Get GC statistics
@@ -442,6 +939,7 @@ impl GarbageCollector {
     pub fn heap_stats(&self) -> HeapStats {
         HeapStats {
             allocated_bytes: self.heap.allocated_bytes(),
+            total_allocated_bytes: self.heap.total_allocated_bytes(),
             allocation_count: self.heap.allocation_count(),
             threshold: self.threshold,
         }
@@ -451,6 +949,17 @@ impl GarbageCollector {
     pub fn heap(&self) -> &Heap {
         &self.heap
     }
+
+    /// Capture the current object graph, including references from the
+    /// root set and registered external root providers
+    pub fn heap_graph(&self) -> HeapGraph {
+        let external = self.external_root_snapshot();
+        HeapGraph::capture(
+            self.heap.iter_allocations(),
+            self.roots.iter().chain(external.roots),
+            self.heap.type_registry(),
+        )
+    }
 }
 
 /// Heap statistics
@@ -459,6 +968,9 @@ pub struct HeapStats {
     /// Total allocated bytes
     pub allocated_bytes: usize,
 
+    /// Cumulative bytes allocated, including objects since freed
+    pub total_allocated_bytes: usize,
+
     /// Number of allocations
     pub allocation_count: usize,
 
@@ -474,6 +986,13 @@ impl Default for GarbageCollector {
     }
 }
 
+impl Drop for GarbageCollector {
+    fn drop(&mut self) {
+        // Keep the barrier's fast path off once no context is marking
+        self.stop_marking();
+    }
+}
+
 // SAFETY: GarbageCollector is only accessed through a Mutex in SharedVmState,
 // which ensures synchronized access to the internal heap and raw pointers.
 unsafe impl Send for GarbageCollector {}
@@ -546,6 +1065,280 @@ mod tests {
         assert_eq!(stats.collections, 1);
     }
 
+    #[test]
+    fn test_heap_graph_records_rooted_array() {
+        use crate::vm::object::Array;
+
+        let mut gc = GarbageCollector::default();
+        let string = gc.allocate(crate::vm::object::RayaString::new("leak".to_string()));
+        let mut array = Array::new(0, 1000);
+        array.elements[0] =
+            unsafe { Value::from_ptr(std::ptr::NonNull::new(string.as_ptr()).unwrap()) };
+        let array = gc.allocate(array);
+        let _unrooted = gc.allocate(Array::new(0, 4));
+        gc.add_root(unsafe { Value::from_ptr(std::ptr::NonNull::new(array.as_ptr()).unwrap()) });
+
+        let graph = gc.heap_graph();
+        assert_eq!(graph.nodes.len(), 3);
+        let array_index = graph
+            .nodes
+            .iter()
+            .position(|node| node.self_size >= 1000 * std::mem::size_of::<Value>())
+            .expect("large array node");
+        let array_node = &graph.nodes[array_index];
+        assert_eq!(array_node.type_name, "Array");
+        assert_eq!(graph.roots, vec![array_index]);
+        assert_eq!(array_node.edges.len(), 1);
+        assert_eq!(graph.nodes[array_node.edges[0].to].type_name, "RayaString");
+    }
+
+    fn string_value(gc: &mut GarbageCollector, text: &str) -> Value {
+        let string = gc.allocate(crate::vm::object::RayaString::new(text.to_string()));
+        unsafe { Value::from_ptr(std::ptr::NonNull::new(string.as_ptr()).unwrap()) }
+    }
+
+    #[test]
+    fn test_weak_ref_cleared_after_target_collected() {
+        let mut gc = GarbageCollector::default();
+        let owner = string_value(&mut gc, "owner");
+        let target = string_value(&mut gc, "weak");
+        let handle = gc.create_weak_ref(owner, target);
+
+        // A rooted target survives, and the weak reference does not count as a root.
+        gc.add_root(owner);
+        gc.add_root(target);
+        gc.collect();
+        assert_eq!(gc.weak_ref_target(handle), Some(target));
+
+        gc.clear_stack_roots();
+        gc.add_root(owner);
+        gc.collect();
+        assert_eq!(gc.weak_ref_target(handle), None);
+        assert_eq!(gc.heap_stats().allocation_count, 1);
+    }
+
+    #[test]
+    fn test_weak_cell_slots_are_reused_after_owner_collected() {
+        let mut gc = GarbageCollector::default();
+        let target = string_value(&mut gc, "target");
+        let first_owner = string_value(&mut gc, "first");
+        let first = gc.create_weak_ref(first_owner, target);
+
+        gc.add_root(target);
+        gc.collect();
+
+        let second_owner = string_value(&mut gc, "second");
+        let second = gc.create_weak_map(second_owner);
+        assert_eq!(second, first);
+        assert_eq!(gc.weak_cells.len(), 1);
+        assert_eq!(gc.weak_ref_target(second), None);
+    }
+
+    #[test]
+    fn test_weak_map_values_live_only_while_their_keys_do() {
+        let mut gc = GarbageCollector::default();
+        let owner = string_value(&mut gc, "owner");
+        let key = string_value(&mut gc, "key");
+        let value = string_value(&mut gc, "value");
+        let chained = string_value(&mut gc, "chained");
+        let dead_key = string_value(&mut gc, "dead key");
+        let dead_value = string_value(&mut gc, "dead value");
+        let map = gc.create_weak_map(owner);
+        assert!(gc.weak_map_set(map, key, value));
+        // `value` is only reachable through the map, and keys another entry.
+        assert!(gc.weak_map_set(map, value, chained));
+        assert!(gc.weak_map_set(map, dead_key, dead_value));
+
+        gc.add_root(owner);
+        gc.add_root(key);
+        gc.collect();
+        assert_eq!(gc.weak_map_get(map, key), Some(value));
+        assert_eq!(gc.weak_map_get(map, value), Some(chained));
+        assert!(!gc.weak_map_has(map, dead_key));
+        assert_eq!(gc.heap_stats().allocation_count, 4);
+
+        // Without its owner the map goes away, and so do its values.
+        gc.clear_stack_roots();
+        gc.add_root(key);
+        gc.collect();
+        assert!(!gc.weak_map_has(map, key));
+        assert_eq!(gc.heap_stats().allocation_count, 1);
+    }
+
+    #[test]
+    fn test_incremental_sweep_respects_step_budget() {
+        use crate::vm::object::RayaString;
+
+        const STEP_BYTES: usize = 256;
+
+        let mut gc = GarbageCollector::default();
+        gc.set_threshold(4096);
+        gc.set_incremental(Some(STEP_BYTES));
+
+        for i in 0..500 {
+            gc.allocate(RayaString::new(format!("garbage {}", i)));
+        }
+
+        let stats = gc.stats();
+        assert!(stats.collections > 0);
+        assert!(stats.objects_freed > 0);
+        assert!(stats.max_sweep_step_bytes > 0);
+        assert!(stats.max_sweep_step_bytes <= STEP_BYTES);
+
+        // A full collection frees whatever the steps have not reached yet.
+        gc.collect();
+        assert_eq!(gc.heap_stats().allocation_count, 0);
+    }
+
+    #[test]
+    fn test_incremental_mark_respects_step_budget() {
+        use crate::vm::object::RayaString;
+
+        const STEP_BYTES: usize = 256;
+
+        let mut gc = GarbageCollector::default();
+        for i in 0..100 {
+            let live = string_value(&mut gc, &format!("live {}", i));
+            gc.add_root(live);
+        }
+        gc.set_threshold(4096);
+        gc.set_incremental(Some(STEP_BYTES));
+
+        let mut steps = 0;
+        while gc.stats().collections == 0 {
+            gc.allocate(RayaString::new(format!("garbage {}", steps)));
+            if gc.marking {
+                steps += 1;
+            }
+        }
+
+        // The mark took several steps, none of them tracing past the budget.
+        let stats = gc.stats();
+        assert!(steps > 1);
+        assert!(stats.max_mark_step_bytes > 0);
+        assert!(stats.max_mark_step_bytes <= STEP_BYTES);
+
+        gc.collect();
+        assert_eq!(gc.heap_stats().allocation_count, 100);
+    }
+
+    #[test]
+    fn test_write_barrier_keeps_values_moved_into_traced_objects() {
+        use crate::vm::object::Array;
+
+        let mut gc = GarbageCollector::default();
+        gc.set_incremental(Some(1));
+        let moved = string_value(&mut gc, "moved");
+        let mut source = Array::new(0, 0);
+        source.push(moved);
+        let source = gc.allocate(source);
+        let target = gc.allocate(Array::new(0, 0));
+        gc.add_root(unsafe { Value::from_ptr(std::ptr::NonNull::new(source.as_ptr()).unwrap()) });
+        gc.add_root(unsafe { Value::from_ptr(std::ptr::NonNull::new(target.as_ptr()).unwrap()) });
+
+        // The first step traces `target` (the last root shaded) and nothing else.
+        gc.start_incremental_mark();
+        assert!(gc.marking);
+        assert!(target.is_marked());
+        assert!(!gc.mark_stack.is_empty());
+
+        // Move the only reference from the untraced array into the traced one.
+        unsafe {
+            (*target.as_ptr()).push(moved);
+            (*source.as_ptr()).pop();
+        }
+        while gc.marking {
+            gc.mark_step();
+        }
+
+        assert_eq!(gc.heap().detached_count(), 0);
+        assert_eq!(gc.heap_stats().allocation_count, 3);
+        assert_eq!(gc.stats().collections, 1);
+    }
+
+    #[test]
+    fn test_heap_walks_skip_objects_pending_sweep() {
+        use crate::vm::object::RayaString;
+
+        let mut gc = GarbageCollector::default();
+        gc.set_threshold(4096);
+        gc.set_incremental(Some(64));
+        let live = string_value(&mut gc, "live");
+        gc.add_root(live);
+
+        let mut i = 0;
+        while gc.heap().detached_count() == 0 {
+            gc.allocate(RayaString::new(format!("garbage {}", i)));
+            i += 1;
+        }
+
+        // Reflection (findInstances, getReferrers) and snapshots walk the heap
+        // mid-sweep: only the root and the allocation that started the
+        // collection may show up, never an object queued for freeing.
+        let visible: Vec<_> = gc.heap().iter_allocations().collect();
+        assert_eq!(visible.len(), 2);
+        assert_eq!(gc.heap_stats().allocation_count, 2);
+        assert_eq!(gc.heap_graph().nodes.len(), 2);
+
+        gc.collect();
+        assert_eq!(gc.heap().detached_count(), 0);
+        assert_eq!(gc.heap_stats().allocation_count, 1);
+    }
+
+    #[test]
+    fn test_equal_short_strings_share_one_object() {
+        use crate::vm::object::RayaString;
+
+        let mut gc = GarbageCollector::default();
+        let short = gc.allocate(RayaString::new("short".to_string()));
+        let again = gc.allocate(RayaString::from_concat("sh", "ort"));
+        assert_eq!(short.addr(), again.addr());
+        assert_eq!(gc.heap_stats().allocation_count, 1);
+
+        // Long strings and interned literals always get their own object.
+        let long = "longer than the inline limit";
+        let first = gc.allocate(RayaString::new(long.to_string()));
+        let second = gc.allocate(RayaString::new(long.to_string()));
+        assert_ne!(first.addr(), second.addr());
+        let interned = gc.allocate(RayaString::interned("short".to_string(), 0));
+        assert_ne!(interned.addr(), short.addr());
+        assert_eq!(gc.heap_stats().allocation_count, 4);
+
+        // Collected strings are forgotten, not handed out again.
+        gc.collect();
+        assert_eq!(gc.heap_stats().allocation_count, 0);
+        assert!(gc.short_strings.is_empty());
+        gc.allocate(RayaString::new("short".to_string()));
+        assert_eq!(gc.heap_stats().allocation_count, 1);
+    }
+
+    #[test]
+    fn test_short_string_shared_while_marking_is_shaded() {
+        use crate::vm::object::{Array, RayaString};
+
+        let mut gc = GarbageCollector::default();
+        gc.set_incremental(Some(1));
+        let shared = gc.allocate(RayaString::new("shared".to_string()));
+        for _ in 0..2 {
+            let root = gc.allocate(Array::new(0, 0));
+            gc.add_root(unsafe { Value::from_ptr(std::ptr::NonNull::new(root.as_ptr()).unwrap()) });
+        }
+
+        gc.start_incremental_mark();
+        assert!(gc.marking);
+        assert!(!shared.is_marked());
+
+        // Handing the string out again keeps it alive through this mark.
+        let again = gc.allocate(RayaString::new("shared".to_string()));
+        assert_eq!(again.addr(), shared.addr());
+        assert!(shared.is_marked());
+        while gc.marking {
+            gc.mark_step();
+        }
+        assert_eq!(gc.heap().detached_count(), 0);
+        assert_eq!(gc.heap_stats().allocation_count, 3);
+    }
+
     #[test]
     fn test_gc_array() {
         let mut gc = GarbageCollector::default();
@@ -2,11 +2,12 @@
 //!
 //! This module provides the heap allocator that manages memory for all GC objects.
 
+use super::allocator::{SharedGcAllocator, SystemGcAllocator};
 use super::header::{DropFn, GcHeader};
 use super::ptr::GcPtr;
 use crate::vm::interpreter::VmContextId;
 use crate::vm::types::TypeRegistry;
-use std::alloc::{alloc, dealloc, Layout};
+use std::alloc::Layout;
 use std::any::TypeId;
 use std::ptr::NonNull;
 use std::sync::Arc;
@@ -22,11 +23,20 @@ pub struct Heap {
     /// All allocations (pointer to GcHeader)
     allocations: Vec<*mut GcHeader>,
 
+    /// Dead allocations awaiting [`Self::free_detached`], hidden from `iter_allocations`
+    detached: Vec<*mut GcHeader>,
+
     /// Total bytes allocated
     allocated_bytes: usize,
 
+    /// Cumulative bytes allocated over the heap's lifetime (never decreases)
+    total_allocated_bytes: usize,
+
     /// Maximum heap size (0 = unlimited)
     max_heap_bytes: usize,
+
+    /// Source of the raw blocks backing each allocation
+    allocator: SharedGcAllocator,
 }
 
 /// Generic drop shim for calling drop glue through a function pointer
@@ -53,11 +63,28 @@ impl Heap {
             context_id,
             type_registry,
             allocations: Vec::new(),
+            detached: Vec::new(),
             allocated_bytes: 0,
+            total_allocated_bytes: 0,
             max_heap_bytes: 0, // Unlimited by default
+            allocator: Arc::new(SystemGcAllocator),
         }
     }
 
+    /// Replace the allocator backing new objects
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heap already holds objects, since they must be freed by
+    /// the allocator that produced them.
+    pub fn set_allocator(&mut self, allocator: SharedGcAllocator) {
+        assert!(
+            self.allocations.is_empty(),
+            "GC allocator must be set before the first allocation"
+        );
+        self.allocator = allocator;
+    }
+
     /// Set maximum heap size
     pub fn set_max_heap_size(&mut self, bytes: usize) {
         self.max_heap_bytes = bytes;
@@ -109,7 +136,7 @@ impl Heap {
         };
 
         // Allocate memory
-        let ptr = unsafe { alloc(combined_layout) };
+        let ptr = unsafe { self.allocator.alloc(combined_layout) };
         if ptr.is_null() {
             panic!("Out of memory");
         }
@@ -142,6 +169,7 @@ impl Heap {
         // Track allocation
         self.allocations.push(header_ptr);
         self.allocated_bytes += combined_layout.size();
+        self.total_allocated_bytes += combined_layout.size();
 
         // Return GC pointer
         unsafe { GcPtr::new(NonNull::new_unchecked(value_ptr)) }
@@ -180,7 +208,7 @@ impl Heap {
         };
 
         // Allocate memory
-        let ptr = unsafe { alloc(combined_layout) };
+        let ptr = unsafe { self.allocator.alloc(combined_layout) };
         if ptr.is_null() {
             panic!("Out of memory");
         }
@@ -215,6 +243,7 @@ impl Heap {
         // Track allocation
         self.allocations.push(header_ptr);
         self.allocated_bytes += combined_layout.size();
+        self.total_allocated_bytes += combined_layout.size();
 
         // Create slice pointer
         let slice_ptr = std::ptr::slice_from_raw_parts_mut(array_ptr, len);
@@ -227,6 +256,17 @@ impl Heap {
     ///
     /// The header pointer must be valid and allocated by this heap.
     pub unsafe fn free(&mut self, header_ptr: *mut GcHeader) {
+        // Remove from allocations tracking
+        if let Some(pos) = self.allocations.iter().position(|&p| p == header_ptr) {
+            self.allocations.swap_remove(pos);
+        }
+
+        self.release(header_ptr);
+    }
+
+    /// Run drop glue and return the block to the allocator, without touching
+    /// the allocation lists
+    unsafe fn release(&mut self, header_ptr: *mut GcHeader) {
         // Get the allocation size from the header
         let header = &*header_ptr;
         let total_size = header.size();
@@ -234,18 +274,13 @@ impl Heap {
         // Run drop glue if this type has a destructor
         header.run_drop(header_ptr);
 
-        // Remove from allocations tracking
-        if let Some(pos) = self.allocations.iter().position(|&p| p == header_ptr) {
-            self.allocations.swap_remove(pos);
-        }
-
         // Decrement allocated bytes
         self.allocated_bytes = self.allocated_bytes.saturating_sub(total_size);
 
         // Actually deallocate the memory
         // GcHeader is 8-byte aligned, so we use the same alignment for deallocation
         let layout = Layout::from_size_align_unchecked(total_size, header.align());
-        dealloc(header_ptr as *mut u8, layout);
+        self.allocator.dealloc(header_ptr as *mut u8, layout);
     }
 
     /// Get total allocated bytes
@@ -253,6 +288,11 @@ impl Heap {
         self.allocated_bytes
     }
 
+    /// Get cumulative bytes allocated, including objects since freed
+    pub fn total_allocated_bytes(&self) -> usize {
+        self.total_allocated_bytes
+    }
+
     /// Get number of allocations
     pub fn allocation_count(&self) -> usize {
         self.allocations.len()
@@ -262,6 +302,58 @@ impl Heap {
     pub fn iter_allocations(&self) -> impl Iterator<Item = *mut GcHeader> + '_ {
         self.allocations.iter().copied()
     }
+
+    /// Move every unmarked allocation out of the allocation list and clear
+    /// the mark bit of every survivor, returning the number and total size of
+    /// the objects detached
+    ///
+    /// Detached objects still count towards `allocated_bytes` until released
+    /// by [`Self::free_detached`], but heap walks (reflection, snapshots) no
+    /// longer see them.
+    pub fn detach_unmarked(&mut self) -> (usize, usize) {
+        let detached = &mut self.detached;
+        let before = detached.len();
+        let mut bytes = 0;
+        self.allocations.retain(|&header_ptr| {
+            let header = unsafe { &mut *header_ptr };
+            if header.is_marked() {
+                header.unmark();
+                return true;
+            }
+            bytes += header.size();
+            detached.push(header_ptr);
+            false
+        });
+        (detached.len() - before, bytes)
+    }
+
+    /// Number of detached objects not yet freed
+    pub fn detached_count(&self) -> usize {
+        self.detached.len()
+    }
+
+    /// Free detached objects until the next one would take the total past
+    /// `budget` bytes, returning the number and total size freed
+    ///
+    /// At least one object is freed when any are pending, so an object larger
+    /// than the budget is freed on its own.
+    pub fn free_detached(&mut self, budget: usize) -> (usize, usize) {
+        let mut freed_count = 0;
+        let mut freed_bytes = 0;
+        while let Some(&header_ptr) = self.detached.last() {
+            let size = unsafe { (*header_ptr).size() };
+            if freed_count > 0 && freed_bytes + size > budget {
+                break;
+            }
+            self.detached.pop();
+            unsafe {
+                self.release(header_ptr);
+            }
+            freed_count += 1;
+            freed_bytes += size;
+        }
+        (freed_count, freed_bytes)
+    }
 }
 
 impl Default for Heap {
@@ -274,21 +366,14 @@ impl Default for Heap {
 
 impl Drop for Heap {
     fn drop(&mut self) {
-        // Free all remaining allocations
-        for &header_ptr in &self.allocations {
+        // Free all remaining allocations, including detached ones
+        let allocations = std::mem::take(&mut self.allocations);
+        let detached = std::mem::take(&mut self.detached);
+        for header_ptr in allocations.into_iter().chain(detached) {
             unsafe {
-                let header = &*header_ptr;
-
-                // Run drop glue if this type has a destructor
-                header.run_drop(header_ptr);
-
-                // Deallocate the memory
-                let total_size = header.size();
-                let layout = Layout::from_size_align_unchecked(total_size, header.align());
-                dealloc(header_ptr as *mut u8, layout);
+                self.release(header_ptr);
             }
         }
-        self.allocations.clear();
     }
 }
 
@@ -535,4 +620,55 @@ mod tests {
         assert_eq!(heap.allocated_bytes(), 0);
         assert_eq!(DROP_COUNT.load(Ordering::SeqCst), alloc_count);
     }
+
+    #[test]
+    fn test_custom_allocator_sees_allocs_and_frees() {
+        use crate::vm::gc::GcAllocator;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct CountingAllocator {
+            allocs: AtomicUsize,
+            frees: AtomicUsize,
+        }
+
+        unsafe impl GcAllocator for CountingAllocator {
+            unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+                self.allocs.fetch_add(1, Ordering::SeqCst);
+                std::alloc::alloc(layout)
+            }
+
+            unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+                self.frees.fetch_add(1, Ordering::SeqCst);
+                std::alloc::dealloc(ptr, layout)
+            }
+        }
+
+        let counter = Arc::new(CountingAllocator::default());
+        let mut heap = Heap::default();
+        heap.set_allocator(counter.clone());
+
+        let _ptr1 = heap.allocate(10i32);
+        let _ptr2 = heap.allocate(String::from("hello"));
+        let _arr = heap.allocate_array::<u64>(4);
+        assert_eq!(counter.allocs.load(Ordering::SeqCst), 3);
+
+        let header_ptr = heap.iter_allocations().next().unwrap();
+        unsafe {
+            heap.free(header_ptr);
+        }
+        assert_eq!(counter.frees.load(Ordering::SeqCst), 1);
+
+        // Remaining blocks go back to the same allocator on drop
+        drop(heap);
+        assert_eq!(counter.frees.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "GC allocator must be set before the first allocation")]
+    fn test_set_allocator_after_allocation_panics() {
+        let mut heap = Heap::default();
+        let _ptr = heap.allocate(1i32);
+        heap.set_allocator(Arc::new(SystemGcAllocator));
+    }
 }
@@ -0,0 +1,246 @@
+//! Heap graph capture for memory analysis
+//!
+//! A [`HeapGraph`] is a point-in-time view of every allocation owned by a
+//! [`GarbageCollector`](super::GarbageCollector): one node per object with its
+//! type and size, plus the references between objects and from the root set.
+//! It does not compute retained sizes; viewers such as Chrome DevTools derive
+//! dominators from the edges (see `to_heapsnapshot_json` in the profiler).
+
+use super::header::GcHeader;
+use crate::vm::object::{
+    Array, BoundMethod, BoundNativeMethod, ChannelObject, Closure, Object, PropKeyId, Proxy,
+    RayaString, RefCell,
+};
+use crate::vm::types::TypeRegistry;
+use crate::vm::value::Value;
+use rustc_hash::FxHashMap;
+use std::any::TypeId;
+
+/// Broad category of a heap node, used by viewers to group objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeapNodeKind {
+    /// Plain or class-instance object
+    Object,
+    /// Array of values
+    Array,
+    /// String data
+    String,
+    /// Closure with captured values
+    Closure,
+    /// Any other VM-internal allocation
+    Native,
+}
+
+/// How a reference is reached from its owner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeapEdgeKind {
+    /// Array element or positional field, by index
+    Element(usize),
+    /// Named property (dynamic properties and internal slots)
+    Property(String),
+}
+
+/// A reference from one node to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapEdge {
+    /// Edge label
+    pub kind: HeapEdgeKind,
+    /// Index of the target node in [`HeapGraph::nodes`]
+    pub to: usize,
+}
+
+/// A single heap allocation.
+#[derive(Debug, Clone)]
+pub struct HeapNode {
+    /// Stable identity for this capture (address of the object data)
+    pub address: usize,
+    /// Object category
+    pub kind: HeapNodeKind,
+    /// Type name (`Array`, `Object`, `RayaString`, ...)
+    pub type_name: String,
+    /// Bytes owned by this object alone: the GC allocation plus any backing
+    /// buffer it owns exclusively (array elements, string bytes, fields)
+    pub self_size: usize,
+    /// Outgoing references to other heap objects
+    pub edges: Vec<HeapEdge>,
+}
+
+/// Point-in-time object graph of a GC heap.
+#[derive(Debug, Clone, Default)]
+pub struct HeapGraph {
+    /// All allocations, in heap order
+    pub nodes: Vec<HeapNode>,
+    /// Indices of nodes directly referenced from GC roots
+    pub roots: Vec<usize>,
+}
+
+impl HeapGraph {
+    /// Capture every allocation reachable through `allocations`.
+    ///
+    /// References to values outside the captured heap are dropped.
+    pub(super) fn capture(
+        allocations: impl Iterator<Item = *mut GcHeader>,
+        roots: impl Iterator<Item = Value>,
+        type_registry: &TypeRegistry,
+    ) -> Self {
+        let headers: Vec<*mut GcHeader> = allocations.collect();
+        let index_of: FxHashMap<usize, usize> = headers
+            .iter()
+            .enumerate()
+            .map(|(index, &header_ptr)| (value_address(header_ptr), index))
+            .collect();
+        let resolve = |value: Value| -> Option<usize> {
+            if !value.is_heap_allocated() {
+                return None;
+            }
+            let ptr = unsafe { value.as_ptr::<u8>() }?;
+            index_of.get(&(ptr.as_ptr() as usize)).copied()
+        };
+
+        let nodes = headers
+            .iter()
+            .map(|&header_ptr| {
+                let header = unsafe { &*header_ptr };
+                let address = value_address(header_ptr);
+                let (kind, type_name, extra_size, children) =
+                    describe(address as *const u8, header.type_id(), type_registry);
+                let edges = children
+                    .into_iter()
+                    .filter_map(|(kind, value)| resolve(value).map(|to| HeapEdge { kind, to }))
+                    .collect();
+                HeapNode {
+                    address,
+                    kind,
+                    type_name,
+                    self_size: header.size() + extra_size,
+                    edges,
+                }
+            })
+            .collect();
+
+        let mut root_indices: Vec<usize> = roots.filter_map(resolve).collect();
+        root_indices.sort_unstable();
+        root_indices.dedup();
+
+        Self {
+            nodes,
+            roots: root_indices,
+        }
+    }
+
+    /// Total bytes across all nodes
+    pub fn total_size(&self) -> usize {
+        self.nodes.iter().map(|node| node.self_size).sum()
+    }
+}
+
+fn value_address(header_ptr: *mut GcHeader) -> usize {
+    let header = unsafe { &*header_ptr };
+    unsafe { (header_ptr as *mut u8).add(header.value_offset()) as usize }
+}
+
+type Children = Vec<(HeapEdgeKind, Value)>;
+
+/// Classify the object at `ptr` and list the values it references.
+///
+/// Returns `(kind, type name, owned buffer bytes, children)`.
+fn describe(
+    ptr: *const u8,
+    type_id: TypeId,
+    type_registry: &TypeRegistry,
+) -> (HeapNodeKind, String, usize, Children) {
+    let value_size = std::mem::size_of::<Value>();
+    let elements = |values: &[Value]| -> Children {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| (HeapEdgeKind::Element(index), value))
+            .collect()
+    };
+    let property = |name: &str, value: Value| (HeapEdgeKind::Property(name.to_string()), value);
+
+    if type_id == TypeId::of::<Object>() {
+        let object = unsafe { &*(ptr as *const Object) };
+        let mut children = elements(&object.fields);
+        let mut extra = object.fields.capacity() * value_size;
+        if let Some(dyn_map) = object.dyn_map() {
+            extra += dyn_map.capacity() * (std::mem::size_of::<PropKeyId>() + value_size);
+            for (key, &value) in dyn_map {
+                children.push(property(&format!("#{}", key), value));
+            }
+        }
+        (HeapNodeKind::Object, "Object".to_string(), extra, children)
+    } else if type_id == TypeId::of::<Array>() {
+        let array = unsafe { &*(ptr as *const Array) };
+        (
+            HeapNodeKind::Array,
+            "Array".to_string(),
+            array.elements.capacity() * value_size,
+            elements(&array.elements),
+        )
+    } else if type_id == TypeId::of::<RayaString>() {
+        let string = unsafe { &*(ptr as *const RayaString) };
+        (
+            HeapNodeKind::String,
+            "RayaString".to_string(),
+            string.heap_capacity(),
+            Vec::new(),
+        )
+    } else if type_id == TypeId::of::<Closure>() {
+        let closure = unsafe { &*(ptr as *const Closure) };
+        (
+            HeapNodeKind::Closure,
+            format!("Closure#{}", closure.func_id),
+            closure.captures.capacity() * value_size,
+            elements(&closure.captures),
+        )
+    } else if type_id == TypeId::of::<BoundMethod>() {
+        let method = unsafe { &*(ptr as *const BoundMethod) };
+        let children = vec![property("receiver", method.receiver)];
+        (
+            HeapNodeKind::Closure,
+            "BoundMethod".to_string(),
+            0,
+            children,
+        )
+    } else if type_id == TypeId::of::<BoundNativeMethod>() {
+        let method = unsafe { &*(ptr as *const BoundNativeMethod) };
+        let children = vec![property("receiver", method.receiver)];
+        (
+            HeapNodeKind::Closure,
+            "BoundNativeMethod".to_string(),
+            0,
+            children,
+        )
+    } else if type_id == TypeId::of::<RefCell>() {
+        let cell = unsafe { &*(ptr as *const RefCell) };
+        let children = vec![property("value", cell.value)];
+        (HeapNodeKind::Native, "RefCell".to_string(), 0, children)
+    } else if type_id == TypeId::of::<Proxy>() {
+        let proxy = unsafe { &*(ptr as *const Proxy) };
+        let children = vec![
+            property("target", proxy.target),
+            property("handler", proxy.handler),
+        ];
+        (HeapNodeKind::Object, "Proxy".to_string(), 0, children)
+    } else if type_id == TypeId::of::<ChannelObject>() {
+        let channel = unsafe { &*(ptr as *const ChannelObject) };
+        let children = elements(&channel.queued_values());
+        (HeapNodeKind::Native, "Channel".to_string(), 0, children)
+    } else {
+        let mut children = Vec::new();
+        let name = match type_registry.get(type_id) {
+            Some(type_info) => {
+                if type_info.has_pointers() {
+                    type_info.for_each_pointer(ptr as *mut u8, |field_ptr| {
+                        let value = unsafe { *(field_ptr as *const Value) };
+                        children.push((HeapEdgeKind::Element(children.len()), value));
+                    });
+                }
+                type_info.name.to_string()
+            }
+            None => "(native)".to_string(),
+        };
+        (HeapNodeKind::Native, name, 0, children)
+    }
+}
@@ -10,8 +10,10 @@
 //! - **GcHeader**: Metadata for each allocated object (16 bytes)
 //! - **GcPtr**: Smart pointer to GC-managed objects
 //! - **Heap**: Memory allocator with GC integration
+//! - **GcAllocator**: Pluggable source of the heap's raw memory blocks
 //! - **RootSet**: Tracking of GC roots (stack, globals)
 //! - **GarbageCollector**: Mark-sweep collection algorithm
+//! - **HeapGraph**: Object graph capture for heap snapshots
 //!
 //! # Memory Layout
 //!
@@ -40,20 +42,24 @@
 //! gc.collect();
 //! ```
 
+mod allocator;
 mod collector;
 mod header;
 mod heap;
+mod heap_graph;
 mod nursery;
 mod ptr;
 mod roots;
 
 // Re-export public types
+pub use allocator::{GcAllocator, SharedGcAllocator, SystemGcAllocator};
 pub use collector::{
-    register_external_roots_provider, unregister_external_roots_provider, ExternalRootSnapshot,
-    GarbageCollector, GcStats, HeapStats,
+    register_external_roots_provider, unregister_external_roots_provider, write_barrier,
+    write_barrier_all, ExternalRootSnapshot, GarbageCollector, GcStats, HeapStats,
 };
 pub use header::{header_mut_ptr_from_value_ptr, header_ptr_from_value_ptr, GcHeader};
 pub use heap::Heap;
+pub use heap_graph::{HeapEdge, HeapEdgeKind, HeapGraph, HeapNode, HeapNodeKind};
 pub use nursery::Nursery;
 pub use ptr::GcPtr;
 pub use roots::RootSet;
@@ -48,6 +48,16 @@ impl RootSet {
         self.stack_roots.clear();
     }
 
+    /// Number of stack roots
+    pub fn stack_root_count(&self) -> usize {
+        self.stack_roots.len()
+    }
+
+    /// Drop the stack roots added after the first `count`
+    pub fn truncate_stack_roots(&mut self, count: usize) {
+        self.stack_roots.truncate(count);
+    }
+
     /// Iterate over all roots
     pub fn iter(&self) -> impl Iterator<Item = Value> + '_ {
         self.stack_roots
@@ -12,10 +12,13 @@ use crate::compiler::Module;
 use crate::vm::gc::{GarbageCollector, GcStats, HeapStats};
 use crate::vm::interpreter::{CapabilityRegistry, ClassRegistry, ModuleRegistry};
 use crate::vm::scheduler::TaskId;
+use crate::vm::stack::StackGrowth;
 use crate::vm::types::TypeRegistry;
 use crate::vm::value::Value;
+use crate::vm::VmError;
 use dashmap::DashMap;
 use parking_lot::RwLock;
+use raya_sdk::{HandleKind, HandleReservation};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -62,6 +65,12 @@ pub struct ResourceLimits {
     /// Preemption time slice in milliseconds (how long a task runs before preemption).
     /// Default: 10ms.
     pub preempt_threshold_ms: u64,
+
+    /// Maximum number of open file handles (None = unlimited)
+    pub max_open_files: Option<usize>,
+
+    /// Maximum number of open sockets (None = unlimited)
+    pub max_open_sockets: Option<usize>,
 }
 
 impl Default for ResourceLimits {
@@ -72,6 +81,8 @@ impl Default for ResourceLimits {
             max_step_budget: None,
             max_preemptions: crate::vm::defaults::DEFAULT_MAX_PREEMPTIONS,
             preempt_threshold_ms: crate::vm::defaults::DEFAULT_PREEMPT_THRESHOLD_MS,
+            max_open_files: None,
+            max_open_sockets: None,
         }
     }
 }
@@ -105,6 +116,15 @@ impl ResourceLimits {
             ..Default::default()
         }
     }
+
+    /// Create resource limits with open file and socket limits
+    pub fn with_handle_limits(max_open_files: usize, max_open_sockets: usize) -> Self {
+        Self {
+            max_open_files: Some(max_open_files),
+            max_open_sockets: Some(max_open_sockets),
+            ..Default::default()
+        }
+    }
 }
 
 /// Resource usage counters for a VmContext
@@ -118,6 +138,12 @@ pub struct ResourceCounters {
 
     /// Peak number of tasks
     peak_tasks: AtomicUsize,
+
+    /// Current number of open file handles
+    open_files: AtomicUsize,
+
+    /// Current number of open sockets
+    open_sockets: AtomicUsize,
 }
 
 impl Default for ResourceCounters {
@@ -133,6 +159,8 @@ impl ResourceCounters {
             active_tasks: AtomicUsize::new(0),
             total_steps: AtomicU64::new(0),
             peak_tasks: AtomicUsize::new(0),
+            open_files: AtomicUsize::new(0),
+            open_sockets: AtomicUsize::new(0),
         }
     }
 
@@ -182,11 +210,89 @@ impl ResourceCounters {
         self.total_steps.load(Ordering::Relaxed)
     }
 
+    fn handle_counter(&self, kind: HandleKind) -> &AtomicUsize {
+        match kind {
+            HandleKind::File => &self.open_files,
+            HandleKind::Socket => &self.open_sockets,
+        }
+    }
+
+    /// Count a newly opened handle, failing if it would exceed `limit`
+    pub fn try_open_handle(&self, kind: HandleKind, limit: Option<usize>) -> bool {
+        self.handle_counter(kind)
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |open| match limit {
+                Some(max) if open >= max => None,
+                _ => Some(open + 1),
+            })
+            .is_ok()
+    }
+
+    /// Count a closed handle
+    pub fn close_handle(&self, kind: HandleKind) {
+        let counter = self.handle_counter(kind);
+        let _ = counter.fetch_update(Ordering::AcqRel, Ordering::Acquire, |open| {
+            open.checked_sub(1)
+        });
+    }
+
+    /// Get current number of open handles of `kind`
+    pub fn open_handles(&self, kind: HandleKind) -> usize {
+        self.handle_counter(kind).load(Ordering::Relaxed)
+    }
+
     /// Reset counters
     pub fn reset(&self) {
         self.active_tasks.store(0, Ordering::Relaxed);
         self.total_steps.store(0, Ordering::Relaxed);
         self.peak_tasks.store(0, Ordering::Relaxed);
+        self.open_files.store(0, Ordering::Relaxed);
+        self.open_sockets.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Open host handle limits shared between a VM and its native calls
+#[derive(Debug, Clone, Default)]
+pub struct HandleLimits {
+    /// Counters tracking currently open handles
+    pub counters: Arc<ResourceCounters>,
+
+    /// Maximum number of open file handles (None = unlimited)
+    pub max_open_files: Option<usize>,
+
+    /// Maximum number of open sockets (None = unlimited)
+    pub max_open_sockets: Option<usize>,
+}
+
+impl HandleLimits {
+    /// Create handle limits with fresh counters
+    pub fn new(max_open_files: Option<usize>, max_open_sockets: Option<usize>) -> Self {
+        Self {
+            counters: Arc::new(ResourceCounters::new()),
+            max_open_files,
+            max_open_sockets,
+        }
+    }
+
+    /// Get the configured limit for `kind`
+    pub fn limit(&self, kind: HandleKind) -> Option<usize> {
+        match kind {
+            HandleKind::File => self.max_open_files,
+            HandleKind::Socket => self.max_open_sockets,
+        }
+    }
+
+    /// Reserve a slot for a handle of `kind`, released when the reservation drops
+    pub fn reserve(&self, kind: HandleKind) -> Result<HandleReservation, VmError> {
+        if !self.counters.try_open_handle(kind, self.limit(kind)) {
+            return Err(VmError::RuntimeError("fd limit exceeded".to_string()));
+        }
+        let counters = self.counters.clone();
+        Ok(HandleReservation::new(move || counters.close_handle(kind)))
+    }
+
+    /// Release a handle slot previously kept open
+    pub fn release(&self, kind: HandleKind) {
+        self.counters.close_handle(kind);
     }
 }
 
@@ -204,6 +310,41 @@ pub struct VmOptions {
 
     /// Capabilities granted to this context
     pub capabilities: CapabilityRegistry,
+
+    /// Initial stack allocation per task in bytes (None = engine default).
+    /// Stacks grow from this size on demand up to `max_stack_size`.
+    pub initial_stack_size: Option<usize>,
+
+    /// Maximum stack size per task in bytes (None = engine default).
+    /// Exceeding it raises `VmError::StackOverflow`.
+    pub max_stack_size: Option<usize>,
+
+    /// Growth policy for task stacks
+    pub stack_growth: StackGrowth,
+
+    /// Make float arithmetic bit-identical between the interpreter and the JIT.
+    ///
+    /// The interpreter rounds every float operation individually in bytecode
+    /// order; with this flag the JIT does the same (no FMA contraction), and
+    /// both return the canonical quiet NaN from `+ - * /` instead of
+    /// propagating NaN payloads.
+    pub deterministic_float: bool,
+
+    /// Policy consulted before every native call (None = all natives allowed).
+    /// Denied calls raise a catchable `VmError::SecurityError`.
+    pub native_policy: Option<crate::vm::NativeCallPolicy>,
+
+    /// Allocator backing the GC heap's raw memory (None = global allocator).
+    /// Only backing-store acquisition changes; object layout stays the same.
+    pub gc_allocator: Option<crate::vm::gc::SharedGcAllocator>,
+
+    /// Mark and sweep in bounded steps interleaved with allocation instead
+    /// of all at once. Object stores go through the GC write barrier while
+    /// a mark is in progress.
+    pub gc_incremental: bool,
+
+    /// Maximum bytes traced or freed per incremental GC step (used with `gc_incremental`)
+    pub gc_step_bytes: usize,
 }
 
 impl Default for VmOptions {
@@ -213,6 +354,14 @@ impl Default for VmOptions {
             gc_threshold: crate::vm::defaults::DEFAULT_GC_THRESHOLD,
             type_registry: Arc::new(crate::vm::types::create_standard_registry()),
             capabilities: CapabilityRegistry::new(),
+            initial_stack_size: None,
+            max_stack_size: None,
+            stack_growth: StackGrowth::default(),
+            deterministic_float: false,
+            native_policy: None,
+            gc_allocator: None,
+            gc_incremental: false,
+            gc_step_bytes: crate::vm::defaults::DEFAULT_GC_STEP_BYTES,
         }
     }
 }
@@ -285,6 +434,14 @@ impl VmContext {
             gc.set_max_heap_size(max_heap);
         }
 
+        if let Some(allocator) = options.gc_allocator {
+            gc.set_allocator(allocator);
+        }
+
+        if options.gc_incremental {
+            gc.set_incremental(Some(options.gc_step_bytes));
+        }
+
         Self {
             id,
             gc,
@@ -616,6 +773,32 @@ mod tests {
 
         let step_limit = ResourceLimits::with_step_budget(1000);
         assert_eq!(step_limit.max_step_budget, Some(1000));
+
+        let handle_limit = ResourceLimits::with_handle_limits(8, 4);
+        assert_eq!(handle_limit.max_open_files, Some(8));
+        assert_eq!(handle_limit.max_open_sockets, Some(4));
+    }
+
+    #[test]
+    fn test_handle_limits_reserve_and_release() {
+        let limits = HandleLimits::new(None, Some(1));
+
+        let slot = limits.reserve(HandleKind::Socket).unwrap();
+        let err = limits.reserve(HandleKind::Socket).unwrap_err();
+        assert!(err.to_string().contains("fd limit exceeded"));
+
+        // Dropping an unkept reservation frees the slot
+        drop(slot);
+        limits.reserve(HandleKind::Socket).unwrap().keep();
+        assert_eq!(limits.counters.open_handles(HandleKind::Socket), 1);
+        limits.release(HandleKind::Socket);
+        assert_eq!(limits.counters.open_handles(HandleKind::Socket), 0);
+
+        // Files are unlimited here
+        for _ in 0..4 {
+            limits.reserve(HandleKind::File).unwrap().keep();
+        }
+        assert_eq!(limits.counters.open_handles(HandleKind::File), 4);
     }
 
     #[test]
@@ -668,6 +851,7 @@ mod tests {
             gc_threshold: 512 * 1024,                                 // 512 KB
             type_registry: Arc::new(crate::vm::types::create_standard_registry()),
             capabilities: CapabilityRegistry::new(),
+            ..Default::default()
         };
 
         let ctx = VmContext::with_options(options);
@@ -5,7 +5,7 @@
 //! when the task needs to wait for something.
 
 use super::execution::{ExecutionFrame, ExecutionResult, OpcodeResult, ReturnAction};
-use super::{ClassRegistry, SafepointCoordinator};
+use super::{ClassRegistry, ConstantStringCache, FieldInlineCache, SafepointCoordinator};
 use crate::compiler::{Module, Opcode};
 use crate::vm::builtins::handlers::{
     call_runtime_method as runtime_handler, RuntimeHandlerContext,
@@ -17,7 +17,7 @@ use crate::vm::scheduler::{SuspendReason, Task, TaskId, TaskState};
 use crate::vm::stack::Stack;
 use crate::vm::sync::{MutexRegistry, SemaphoreRegistry};
 use crate::vm::value::Value;
-use crate::vm::VmError;
+use crate::vm::{SourceLocation, VmError};
 use crossbeam_deque::Injector;
 use parking_lot::RwLock;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -221,6 +221,16 @@ mod tests {
     }
 }
 
+/// What a call crossing into a module does about the module's deferred init.
+pub(in crate::vm::interpreter) enum LazyModuleInit {
+    /// The init has run, or the calling task is running it.
+    Ready,
+    /// Run the module's entry `main` (function index) before the callee.
+    Run(usize),
+    /// Another task is running the init; suspend until it finishes.
+    Wait,
+}
+
 /// Task interpreter that can suspend and resume
 ///
 /// This struct holds references to shared state and executes a task.
@@ -251,9 +261,8 @@ pub struct Interpreter<'a> {
     /// Ambient builtin global slot mapping (name -> absolute global slot index).
     pub(in crate::vm::interpreter) builtin_global_slots: &'a RwLock<FxHashMap<String, usize>>,
 
-    /// VM-local interned constant strings keyed by `(module checksum, constant index)`.
-    pub(in crate::vm::interpreter) constant_string_cache:
-        &'a RwLock<FxHashMap<([u8; 32], usize), Value>>,
+    /// VM-local interned constant strings.
+    pub(in crate::vm::interpreter) constant_string_cache: &'a RwLock<ConstantStringCache>,
 
     /// Freshly allocated values rooted only until they are published into a
     /// stable root set such as task state or a shared cache.
@@ -365,17 +374,47 @@ pub struct Interpreter<'a> {
     /// Debug state for debugger coordination (None = no debugger attached)
     pub(in crate::vm::interpreter) debug_state: Option<Arc<super::debug_state::DebugState>>,
 
+    /// Open file/socket limits enforced on native calls (None = unlimited)
+    pub(in crate::vm::interpreter) handle_limits: Option<&'a crate::vm::HandleLimits>,
+
+    /// Execution timeout deadline passed to native calls (None = no timeout)
+    pub(in crate::vm::interpreter) deadline: Option<std::time::Instant>,
+
+    /// Host cancellation flag polled at each safepoint (None = not cancellable)
+    pub(in crate::vm::interpreter) cancel_flag: Option<Arc<std::sync::atomic::AtomicBool>>,
+
+    /// Program arguments exposed to native calls (None = no arguments)
+    pub(in crate::vm::interpreter) program_args: Option<&'a RwLock<Vec<String>>>,
+
+    /// Exit hooks registered by native calls (None = exit hooks unsupported)
+    pub(in crate::vm::interpreter) exit_hooks: Option<&'a parking_lot::Mutex<Vec<Value>>>,
+
+    /// Policy checked before each native dispatch (None = all natives allowed)
+    pub(in crate::vm::interpreter) native_policy: Option<crate::vm::NativeCallPolicy>,
+
+    /// Return the canonical quiet NaN from float arithmetic, matching the JIT
+    pub(in crate::vm::interpreter) deterministic_float: bool,
+
     /// Sampling profiler (None when profiling is disabled).
     pub(in crate::vm::interpreter) profiler: Option<Arc<crate::profiler::Profiler>>,
 
     /// Current function ID for profiler stack capture.
     pub(in crate::vm::interpreter) profiler_func_id: usize,
 
+    /// Coverage recorder (None when coverage is disabled).
+    pub(in crate::vm::interpreter) coverage: Option<Arc<crate::coverage::CoverageRecorder>>,
+
+    /// Coverage hits of this task slice, merged into `coverage` by `flush_coverage`.
+    pub(in crate::vm::interpreter) coverage_buffer: crate::coverage::CoverageBuffer,
+
     /// Current bytecode offset for offline AOT profile site recording.
     pub(in crate::vm::interpreter) current_bytecode_offset_for_aot_profile: u32,
 
     /// Current module checksum for offline AOT profile recording.
     pub(in crate::vm::interpreter) current_module_checksum_for_aot_profile: [u8; 32],
+
+    /// Inline caches for structural field access sites, lent by the worker.
+    pub(in crate::vm::interpreter) field_inline_cache: FieldInlineCache,
 }
 
 impl<'a> Interpreter<'a> {
@@ -413,6 +452,77 @@ impl<'a> Interpreter<'a> {
             })
     }
 
+    /// Claim a deferred module init for `task_id` as a call enters `func_id`.
+    ///
+    /// The claiming task owns the init until the module's `main` returns; calls
+    /// back into the module from that task (import cycles) proceed without
+    /// starting the init again, while other tasks wait for it to finish.
+    pub(in crate::vm::interpreter) fn take_lazy_module_init(
+        &self,
+        module: &Module,
+        func_id: usize,
+        task_id: TaskId,
+    ) -> LazyModuleInit {
+        if !self
+            .module_layouts
+            .read()
+            .get(&module.checksum)
+            .is_some_and(|layout| layout.lazy_init || layout.init_owner.is_some())
+        {
+            return LazyModuleInit::Ready;
+        }
+        let mut layouts = self.module_layouts.write();
+        let Some(layout) = layouts.get_mut(&module.checksum) else {
+            return LazyModuleInit::Ready;
+        };
+        if layout.lazy_init {
+            layout.lazy_init = false;
+            // A task that starts in `main` itself runs the init as its body.
+            match module.functions.iter().rposition(|f| f.name == "main") {
+                Some(init_fn_id) if init_fn_id != func_id => {
+                    layout.init_owner = Some(task_id);
+                    return LazyModuleInit::Run(init_fn_id);
+                }
+                _ => layout.initialized = true,
+            }
+        }
+        match layout.init_owner {
+            Some(owner) if owner != task_id => {
+                layout.init_waiters.push(task_id);
+                LazyModuleInit::Wait
+            }
+            _ => LazyModuleInit::Ready,
+        }
+    }
+
+    /// Finish a deferred module init when `task_id` returns from the module's
+    /// `main`, and wake the tasks that were waiting for it.
+    pub(in crate::vm::interpreter) fn finish_lazy_module_init(
+        &self,
+        module: &Module,
+        func_id: usize,
+        task_id: TaskId,
+    ) {
+        if module
+            .functions
+            .get(func_id)
+            .is_none_or(|f| f.name != "main")
+        {
+            return;
+        }
+        let waiters = match self.module_layouts.write().get_mut(&module.checksum) {
+            Some(layout) if layout.init_owner == Some(task_id) => layout.finish_init(),
+            _ => return,
+        };
+        let tasks = self.tasks.read();
+        for waiter in waiters.iter().filter_map(|id| tasks.get(id)) {
+            if waiter.resume_if_pending() {
+                waiter.clear_suspend_reason();
+                self.injector.push(waiter.clone());
+            }
+        }
+    }
+
     #[inline]
     pub(in crate::vm::interpreter) fn module_resolved_natives(
         &self,
@@ -651,7 +761,7 @@ impl<'a> Interpreter<'a> {
 
         if header.type_id() == std::any::TypeId::of::<RayaString>() {
             let s = unsafe { &*ptr.cast::<RayaString>().as_ptr() };
-            return s.data.clone();
+            return s.as_str().to_string();
         }
         if header.type_id() == std::any::TypeId::of::<Object>() {
             let obj = unsafe { &*ptr.cast::<Object>().as_ptr() };
@@ -660,7 +770,7 @@ impl<'a> Interpreter<'a> {
                     let msg_header = unsafe { &*header_ptr_from_value_ptr(msg_ptr.as_ptr()) };
                     if msg_header.type_id() == std::any::TypeId::of::<RayaString>() {
                         let s = unsafe { &*msg_ptr.cast::<RayaString>().as_ptr() };
-                        return s.data.clone();
+                        return s.as_str().to_string();
                     }
                 }
             }
@@ -764,7 +874,7 @@ impl<'a> Interpreter<'a> {
         safepoint: &'a SafepointCoordinator,
         globals_by_index: &'a RwLock<Vec<Value>>,
         builtin_global_slots: &'a RwLock<FxHashMap<String, usize>>,
-        constant_string_cache: &'a RwLock<FxHashMap<([u8; 32], usize), Value>>,
+        constant_string_cache: &'a RwLock<ConstantStringCache>,
         ephemeral_gc_roots: &'a RwLock<Vec<Value>>,
         pinned_handles: &'a RwLock<FxHashSet<u64>>,
         tasks: &'a Arc<RwLock<FxHashMap<TaskId, Arc<Task>>>>,
@@ -818,6 +928,13 @@ impl<'a> Interpreter<'a> {
             max_preemptions,
             stack_pool,
             debug_state: None,
+            handle_limits: None,
+            deadline: None,
+            cancel_flag: None,
+            program_args: None,
+            exit_hooks: None,
+            native_policy: None,
+            deterministic_float: false,
             #[cfg(feature = "jit")]
             code_cache: None,
             #[cfg(feature = "jit")]
@@ -837,8 +954,11 @@ impl<'a> Interpreter<'a> {
             current_module_id_for_profiling: None,
             profiler: None,
             profiler_func_id: 0,
+            coverage: None,
+            coverage_buffer: crate::coverage::CoverageBuffer::default(),
             current_bytecode_offset_for_aot_profile: 0,
             current_module_checksum_for_aot_profile: [0; 32],
+            field_inline_cache: FieldInlineCache::new(),
         }
     }
 
@@ -847,11 +967,68 @@ impl<'a> Interpreter<'a> {
         self.debug_state = debug_state;
     }
 
+    /// Set the open file/socket limits enforced on native calls.
+    pub fn set_handle_limits(&mut self, handle_limits: Option<&'a crate::vm::HandleLimits>) {
+        self.handle_limits = handle_limits;
+    }
+
+    /// Set the execution timeout deadline passed to native calls.
+    pub fn set_deadline(&mut self, deadline: Option<std::time::Instant>) {
+        self.deadline = deadline;
+    }
+
+    /// Set the host cancellation flag checked at each safepoint.
+    pub fn set_cancel_flag(&mut self, cancel_flag: Option<Arc<std::sync::atomic::AtomicBool>>) {
+        self.cancel_flag = cancel_flag;
+    }
+
+    /// Set the program arguments passed to native calls.
+    pub fn set_program_args(&mut self, program_args: Option<&'a RwLock<Vec<String>>>) {
+        self.program_args = program_args;
+    }
+
+    /// Set the exit hook list native calls register callbacks into.
+    pub fn set_exit_hooks(&mut self, exit_hooks: Option<&'a parking_lot::Mutex<Vec<Value>>>) {
+        self.exit_hooks = exit_hooks;
+    }
+
+    /// Set the policy consulted before each native call.
+    pub fn set_native_policy(&mut self, policy: Option<crate::vm::NativeCallPolicy>) {
+        self.native_policy = policy;
+    }
+
+    /// Canonicalize NaN results of float arithmetic (see `VmOptions::deterministic_float`).
+    pub fn set_deterministic_float(&mut self, enabled: bool) {
+        self.deterministic_float = enabled;
+    }
+
     /// Set the profiler for sampling.
     pub fn set_profiler(&mut self, profiler: Option<Arc<crate::profiler::Profiler>>) {
         self.profiler = profiler;
     }
 
+    /// Set the coverage recorder fed with executed instructions and branches.
+    pub fn set_coverage(&mut self, coverage: Option<Arc<crate::coverage::CoverageRecorder>>) {
+        self.coverage = coverage;
+    }
+
+    /// Use `cache` for structural field access sites.
+    pub fn set_field_inline_cache(&mut self, cache: FieldInlineCache) {
+        self.field_inline_cache = cache;
+    }
+
+    /// Take back the field inline cache, leaving an empty one.
+    pub fn take_field_inline_cache(&mut self) -> FieldInlineCache {
+        std::mem::take(&mut self.field_inline_cache)
+    }
+
+    /// Merge the coverage recorded by this interpreter into the shared recorder.
+    pub fn flush_coverage(&mut self) {
+        if let Some(ref coverage) = self.coverage {
+            coverage.merge(&mut self.coverage_buffer);
+        }
+    }
+
     /// Set the JIT code cache for native dispatch.
     ///
     /// Called by the reactor worker after constructing the interpreter.
@@ -1024,6 +1201,9 @@ impl<'a> Interpreter<'a> {
                     if frame.is_closure {
                         task.pop_closure();
                     }
+                    if matches!(frame.return_action, ReturnAction::Discard) {
+                        self.finish_lazy_module_init(&module, current_func_id, task.id());
+                    }
                     module = frame.module;
                     task.set_current_module(module.clone());
                     current_func_id = frame.func_id;
@@ -1051,8 +1231,65 @@ impl<'a> Interpreter<'a> {
             }
         }
 
-        // Initialize the task if this is a fresh start
-        if ip == 0 && stack_guard.depth() == 0 && frames.is_empty() {
+        // Macro to save all frame state before leaving run()
+        macro_rules! save_frame_state {
+            () => {
+                task.set_ip(ip);
+                task.set_current_func_id(current_func_id);
+                task.set_current_locals_base(locals_base);
+                task.set_current_module(module.clone());
+                task.save_execution_frames(frames);
+            };
+        }
+
+        // Run a deferred module init before the first call into its module. The
+        // callee frame is already set up, so the module's `main` is pushed on
+        // top of it and returns straight back into the callee. While another
+        // task is running the init, the caller suspends at the callee's entry.
+        macro_rules! enter_lazy_module_init {
+            () => {
+                match self.take_lazy_module_init(&module, current_func_id, task.id()) {
+                    LazyModuleInit::Ready => {}
+                    LazyModuleInit::Run(init_fn_id) => {
+                        frames.push(ExecutionFrame {
+                            module: module.clone(),
+                            func_id: current_func_id,
+                            ip,
+                            locals_base,
+                            is_closure: false,
+                            return_action: ReturnAction::Discard,
+                            arg_count: current_arg_count,
+                        });
+                        task.push_call_frame(init_fn_id);
+                        locals_base = stack_guard.depth();
+                        for _ in 0..module.functions[init_fn_id].local_count {
+                            if let Err(e) = stack_guard.push(Value::null()) {
+                                return ExecutionResult::Failed(e);
+                            }
+                        }
+                        current_func_id = init_fn_id;
+                        #[cfg(feature = "jit")]
+                        {
+                            self.current_func_id_for_profiling = current_func_id;
+                        }
+                        self.profiler_func_id = current_func_id;
+                        code = &module.functions[init_fn_id].code;
+                        current_arg_count = 0;
+                        ip = 0;
+                    }
+                    LazyModuleInit::Wait => {
+                        save_frame_state!();
+                        drop(stack_guard);
+                        return ExecutionResult::Suspended(SuspendReason::ModuleInit);
+                    }
+                }
+            };
+        }
+
+        // Initialize the task if this is a fresh start (a task without locals
+        // that waited on a module init before its first instruction is not)
+        if ip == 0 && stack_guard.depth() == 0 && frames.is_empty() && task.call_frame_count() == 0
+        {
             task.push_call_frame(current_func_id);
 
             for _ in 0..entry_local_count {
@@ -1069,17 +1306,10 @@ impl<'a> Interpreter<'a> {
                     }
                 }
             }
-        }
 
-        // Macro to save all frame state before leaving run()
-        macro_rules! save_frame_state {
-            () => {
-                task.set_ip(ip);
-                task.set_current_func_id(current_func_id);
-                task.set_current_locals_base(locals_base);
-                task.set_current_module(module.clone());
-                task.save_execution_frames(frames);
-            };
+            // Tasks spawned from an imported async function start directly in
+            // the exporting module.
+            enter_lazy_module_init!();
         }
 
         // Helper: handle return from current function (frame pop)
@@ -1098,6 +1328,9 @@ impl<'a> Interpreter<'a> {
                     if frame.is_closure {
                         task.pop_closure();
                     }
+                    if matches!(frame.return_action, ReturnAction::Discard) {
+                        self.finish_lazy_module_init(&module, current_func_id, task.id());
+                    }
 
                     // Restore caller's state
                     module = frame.module;
@@ -1197,6 +1430,17 @@ impl<'a> Interpreter<'a> {
                 ));
             }
 
+            // Check for host cancellation
+            if self
+                .cancel_flag
+                .as_ref()
+                .is_some_and(|flag| flag.load(std::sync::atomic::Ordering::Relaxed))
+            {
+                save_frame_state!();
+                drop(stack_guard);
+                return ExecutionResult::Failed(VmError::Cancelled);
+            }
+
             // Bounds check - implicit return at end of function
             if ip >= code.len() {
                 let local_count = module.functions[current_func_id].local_count;
@@ -1279,6 +1523,14 @@ impl<'a> Interpreter<'a> {
             self.current_bytecode_offset_for_aot_profile = (ip - 1) as u32;
             self.current_module_checksum_for_aot_profile = module.checksum;
 
+            if self.coverage.is_some() {
+                self.coverage_buffer.record_instruction(
+                    module.as_ref(),
+                    current_func_id,
+                    (ip - 1) as u32,
+                );
+            }
+
             // Debug check: test breakpoints, step modes, and debugger statements
             // when a debugger is attached. The fast path (no debugger) is a single
             // atomic relaxed load.
@@ -1317,6 +1569,7 @@ impl<'a> Interpreter<'a> {
             }
 
             // Execute the opcode
+            let instruction_offset = ip - 1;
             match self.execute_opcode(
                 task,
                 &mut stack_guard,
@@ -1329,7 +1582,17 @@ impl<'a> Interpreter<'a> {
                 current_arg_count,
             ) {
                 OpcodeResult::Continue => {
-                    // Continue to next instruction
+                    if self.coverage.is_some() && opcode.is_conditional_jump() {
+                        // Conditional jumps carry a 4-byte offset; anything
+                        // other than the fallthrough means the jump was taken.
+                        let taken = ip != instruction_offset + 5;
+                        self.coverage_buffer.record_branch(
+                            module.as_ref(),
+                            current_func_id,
+                            instruction_offset as u32,
+                            taken,
+                        );
+                    }
                 }
                 OpcodeResult::Return(value) => {
                     if let Some(result) = handle_frame_return!(value) {
@@ -1351,6 +1614,7 @@ impl<'a> Interpreter<'a> {
                     return_action,
                 } => {
                     let callee_module = callee_module.unwrap_or_else(|| module.clone());
+                    let crosses_module = !Arc::ptr_eq(&callee_module, &module);
 
                     #[cfg(feature = "jit")]
                     let mut forced_callee_ip: Option<usize> = None;
@@ -1412,7 +1676,7 @@ impl<'a> Interpreter<'a> {
                                     crate::jit::runtime::trampoline::JitExitInfo::default();
                                 let jit_resolved_natives =
                                     parking_lot::RwLock::new(self.module_resolved_natives(&module));
-                                let bridge_ctx =
+                                let mut bridge_ctx =
                                     crate::jit::runtime::helpers::build_runtime_bridge_context(
                                         self.safepoint,
                                         task,
@@ -1444,6 +1708,7 @@ impl<'a> Interpreter<'a> {
                                         frames.len(),
                                         self.io_submit_tx,
                                     );
+                                bridge_ctx.deterministic_float = self.deterministic_float;
                                 let mut runtime_ctx =
                                     crate::jit::runtime::helpers::build_runtime_context(
                                         &bridge_ctx,
@@ -1695,8 +1960,27 @@ impl<'a> Interpreter<'a> {
                     {
                         ip = 0;
                     }
+
+                    if crosses_module {
+                        enter_lazy_module_init!();
+                    }
                 }
                 OpcodeResult::Error(e) => {
+                    // Report overflow depth in terms of the task's call stack;
+                    // `Stack` does not see interpreter-managed frames.
+                    let e = match e {
+                        VmError::StackOverflow { .. } => VmError::StackOverflow {
+                            depth: task.call_frame_count(),
+                        },
+                        other => other,
+                    };
+                    // Resolve the faulting instruction before unwinding moves
+                    // `module`/`current_func_id` to a caller frame.
+                    let fault_location = SourceLocation::resolve(
+                        module.as_ref(),
+                        current_func_id,
+                        self.current_bytecode_offset_for_aot_profile,
+                    );
                     if matches!(e, VmError::StackUnderflow)
                         && std::env::var("RAYA_DEBUG_STACK_UNDERFLOW").is_ok()
                     {
@@ -1782,6 +2066,9 @@ impl<'a> Interpreter<'a> {
                             if frame.is_closure {
                                 task.pop_closure();
                             }
+                            if matches!(frame.return_action, ReturnAction::Discard) {
+                                self.finish_lazy_module_init(&module, current_func_id, task.id());
+                            }
                             // Restore caller's context — don't clean stack here,
                             // the exception handler's stack_size will handle unwinding
                             module = frame.module;
@@ -1806,7 +2093,10 @@ impl<'a> Interpreter<'a> {
                     if !handled {
                         task.set_ip(ip);
                         drop(stack_guard);
-                        return ExecutionResult::Failed(e);
+                        return ExecutionResult::Failed(match fault_location {
+                            Some(location) => e.with_location(location),
+                            None => e,
+                        });
                     }
                 }
             }
@@ -1,6 +1,7 @@
 //! Array built-in method handlers
 
 use crate::compiler::Module;
+use crate::vm::gc::{write_barrier, write_barrier_all};
 use crate::vm::interpreter::Interpreter;
 use crate::vm::object::{Array, RayaString};
 use crate::vm::scheduler::Task;
@@ -156,7 +157,7 @@ impl<'a> Interpreter<'a> {
                         if let (Some(a_ptr), Some(b_ptr)) = (a_str_ptr, b_str_ptr) {
                             let a_str = unsafe { &*a_ptr.as_ptr() };
                             let b_str = unsafe { &*b_ptr.as_ptr() };
-                            a_str.data == b_str.data
+                            a_str.as_str() == b_str.as_str()
                         } else {
                             false
                         }
@@ -312,6 +313,7 @@ impl<'a> Interpreter<'a> {
                 let old_elements = std::mem::take(&mut arr.elements);
                 arr.elements = new_elements;
                 drop(old_elements); // Explicitly drop old elements
+                write_barrier_all(&arr.elements);
 
                 // Create removed array with same element type as source array
                 let mut removed = Array::new(arr.type_id, removed_vals.len());
@@ -326,6 +328,36 @@ impl<'a> Interpreter<'a> {
                 stack.push(value)?;
                 Ok(())
             }
+            array::RESERVE => {
+                // withCapacity(n): pre-size backing storage; length is unchanged
+                if arg_count != 1 {
+                    return Err(VmError::RuntimeError(format!(
+                        "Array.withCapacity expects 1 argument, got {}",
+                        arg_count
+                    )));
+                }
+                let capacity_val = stack.pop()?;
+                let array_val = stack.pop()?;
+                if !array_val.is_ptr() {
+                    return Err(VmError::TypeError("Expected array".to_string()));
+                }
+                let capacity = if let Some(n) = capacity_val.as_i32() {
+                    n.max(0) as usize
+                } else if let Some(n) = capacity_val.as_f64() {
+                    if n.is_finite() && n > 0.0 {
+                        n as usize
+                    } else {
+                        0
+                    }
+                } else {
+                    0
+                };
+                let arr_ptr = unsafe { array_val.as_ptr::<Array>() };
+                let arr = unsafe { &mut *arr_ptr.unwrap().as_ptr() };
+                arr.reserve(capacity);
+                stack.push(array_val)?;
+                Ok(())
+            }
             array::REVERSE => {
                 if arg_count != 0 {
                     return Err(VmError::RuntimeError(format!(
@@ -416,7 +448,7 @@ impl<'a> Interpreter<'a> {
                         if let (Some(a_ptr), Some(b_ptr)) = (a_str_ptr, b_str_ptr) {
                             let a_str = unsafe { &*a_ptr.as_ptr() };
                             let b_str = unsafe { &*b_ptr.as_ptr() };
-                            a_str.data == b_str.data
+                            a_str.as_str() == b_str.as_str()
                         } else {
                             false
                         }
@@ -474,6 +506,7 @@ impl<'a> Interpreter<'a> {
                 for i in start..end.min(arr.len()) {
                     arr.elements[i] = fill_value;
                 }
+                write_barrier(fill_value);
 
                 stack.push(array_val)?;
                 Ok(())
@@ -534,7 +567,7 @@ impl<'a> Interpreter<'a> {
                     let sep_val = stack.pop()?;
                     if let Some(ptr) = unsafe { sep_val.as_ptr::<RayaString>() } {
                         let s = unsafe { &*ptr.as_ptr() };
-                        s.data.clone()
+                        s.as_str().to_string()
                     } else {
                         ",".to_string()
                     }
@@ -555,7 +588,7 @@ impl<'a> Interpreter<'a> {
                     .iter()
                     .map(|v| {
                         if let Some(ptr) = unsafe { v.as_ptr::<RayaString>() } {
-                            unsafe { &*ptr.as_ptr() }.data.clone()
+                            unsafe { &*ptr.as_ptr() }.as_str().to_string()
                         } else if let Some(i) = v.as_i32() {
                             i.to_string()
                         } else if let Some(f) = v.as_f64() {
@@ -0,0 +1,391 @@
+//! BigInt built-in method handlers
+//!
+//! BigInt values cross the native boundary as canonical decimal strings
+//! (`-` prefix when negative, no leading zeros), so the `BigInt` class in
+//! `builtins/strict/bigint.raya` only stores a string.
+
+use crate::vm::interpreter::Interpreter;
+use crate::vm::object::RayaString;
+use crate::vm::stack::Stack;
+use crate::vm::value::Value;
+use crate::vm::VmError;
+use std::cmp::Ordering;
+
+/// Limb base: each limb holds nine decimal digits
+const BASE: u64 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+
+/// Sign-magnitude arbitrary-precision integer with little-endian base-10^9 limbs
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BigInt {
+    negative: bool,
+    /// No trailing zero limbs; zero is empty and never negative
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    fn zero() -> Self {
+        Self {
+            negative: false,
+            limbs: Vec::new(),
+        }
+    }
+
+    fn from_limbs(negative: bool, limbs: Vec<u32>) -> Self {
+        let limbs = trimmed(limbs);
+        let negative = negative && !limbs.is_empty();
+        Self { negative, limbs }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Parse an optionally signed run of decimal digits
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (negative, digits) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut limbs = Vec::with_capacity(digits.len() / BASE_DIGITS + 1);
+        let mut end = digits.len();
+        while end > 0 {
+            let start = end.saturating_sub(BASE_DIGITS);
+            limbs.push(digits[start..end].parse::<u32>().ok()?);
+            end = start;
+        }
+        Some(Self::from_limbs(negative, limbs))
+    }
+
+    fn to_decimal(&self) -> String {
+        let Some((top, rest)) = self.limbs.split_last() else {
+            return "0".to_string();
+        };
+        let mut out = String::with_capacity(self.limbs.len() * BASE_DIGITS + 1);
+        if self.negative {
+            out.push('-');
+        }
+        out.push_str(&top.to_string());
+        for limb in rest.iter().rev() {
+            out.push_str(&format!("{:09}", limb));
+        }
+        out
+    }
+
+    fn negated(mut self) -> Self {
+        self.negative = !self.negative && !self.is_zero();
+        self
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.limbs, &other.limbs),
+            (true, true) => cmp_mag(&other.limbs, &self.limbs),
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        if self.negative == other.negative {
+            return Self::from_limbs(self.negative, add_mag(&self.limbs, &other.limbs));
+        }
+        match cmp_mag(&self.limbs, &other.limbs) {
+            Ordering::Equal => Self::zero(),
+            Ordering::Greater => {
+                Self::from_limbs(self.negative, sub_mag(&self.limbs, &other.limbs))
+            }
+            Ordering::Less => Self::from_limbs(other.negative, sub_mag(&other.limbs, &self.limbs)),
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.clone().negated())
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Self::from_limbs(
+            self.negative != other.negative,
+            mul_mag(&self.limbs, &other.limbs),
+        )
+    }
+
+    /// Quotient truncated toward zero and remainder with the dividend's sign
+    fn div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if other.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = div_rem_mag(&self.limbs, &other.limbs);
+        Some((
+            Self::from_limbs(self.negative != other.negative, quotient),
+            Self::from_limbs(self.negative, remainder),
+        ))
+    }
+
+    fn pow(&self, mut exponent: u32) -> Self {
+        let mut result = Self::from_limbs(false, vec![1]);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+}
+
+fn trimmed(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let sum =
+            carry + a.get(i).copied().unwrap_or(0) as u64 + b.get(i).copied().unwrap_or(0) as u64;
+        out.push((sum % BASE) as u32);
+        carry = sum / BASE;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// `a - b` for magnitudes with `a >= b`
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut diff = limb as i64 - borrow - b.get(i).copied().unwrap_or(0) as i64;
+        borrow = if diff < 0 {
+            diff += BASE as i64;
+            1
+        } else {
+            0
+        };
+        out.push(diff as u32);
+    }
+    out
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let cur = out[i + j] + x as u64 * y as u64 + carry;
+            out[i + j] = cur % BASE;
+            carry = cur / BASE;
+        }
+        out[i + b.len()] += carry;
+    }
+    out.into_iter().map(|limb| limb as u32).collect()
+}
+
+fn mul_small(a: &[u32], factor: u32) -> Vec<u32> {
+    trimmed(mul_mag(a, &[factor]))
+}
+
+/// Schoolbook long division; each quotient limb is found by binary search
+fn div_rem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder: Vec<u32> = Vec::new();
+    for (i, &limb) in a.iter().enumerate().rev() {
+        // remainder = remainder * BASE + limb
+        remainder.insert(0, limb);
+        remainder = trimmed(remainder);
+        if cmp_mag(&remainder, b) == Ordering::Less {
+            continue;
+        }
+        let (mut lo, mut hi) = (1u32, (BASE - 1) as u32);
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            let product = mul_small(b, mid);
+            if cmp_mag(&product, &remainder) == Ordering::Greater {
+                hi = mid - 1;
+            } else {
+                lo = mid;
+            }
+        }
+        remainder = trimmed(sub_mag(&remainder, &mul_small(b, lo)));
+        quotient[i] = lo;
+    }
+    (quotient, remainder)
+}
+
+fn bigint_arg(value: Value) -> Result<BigInt, VmError> {
+    let string_ptr = if value.is_ptr() {
+        unsafe { value.as_ptr::<RayaString>() }
+    } else {
+        None
+    };
+    let Some(string_ptr) = string_ptr else {
+        return Err(VmError::TypeError("Expected BigInt digits".to_string()));
+    };
+    let text = unsafe { &*string_ptr.as_ptr() }.as_str().to_string();
+    BigInt::parse(&text)
+        .ok_or_else(|| VmError::RuntimeError(format!("Cannot convert {} to a BigInt", text)))
+}
+
+fn exponent_arg(value: Value) -> Result<u32, VmError> {
+    let exponent = value
+        .as_i32()
+        .map(|v| v as f64)
+        .or_else(|| value.as_f64())
+        .ok_or_else(|| VmError::TypeError("Expected number exponent".to_string()))?;
+    if exponent.fract() != 0.0 || !(0.0..=u32::MAX as f64).contains(&exponent) {
+        return Err(VmError::RuntimeError(format!(
+            "BigInt exponent must be a non-negative integer, got {}",
+            exponent
+        )));
+    }
+    Ok(exponent as u32)
+}
+
+impl<'a> Interpreter<'a> {
+    /// Handle built-in bigint methods
+    pub(in crate::vm::interpreter) fn call_bigint_method(
+        &mut self,
+        stack: &mut Stack,
+        method_id: u16,
+        args: &[Value],
+    ) -> Result<(), VmError> {
+        use crate::vm::builtin::bigint;
+
+        let arg = |index: usize| {
+            args.get(index).copied().ok_or_else(|| {
+                VmError::RuntimeError(format!(
+                    "BigInt native {:#06x} expects {} arguments",
+                    method_id,
+                    index + 1
+                ))
+            })
+        };
+
+        let result = match method_id {
+            id if id == bigint::NEW => bigint_arg(arg(0)?)?,
+            id if id == bigint::ADD => bigint_arg(arg(0)?)?.add(&bigint_arg(arg(1)?)?),
+            id if id == bigint::SUB => bigint_arg(arg(0)?)?.sub(&bigint_arg(arg(1)?)?),
+            id if id == bigint::MUL => bigint_arg(arg(0)?)?.mul(&bigint_arg(arg(1)?)?),
+            id if id == bigint::DIV || id == bigint::MOD => {
+                let (quotient, remainder) = bigint_arg(arg(0)?)?
+                    .div_rem(&bigint_arg(arg(1)?)?)
+                    .ok_or_else(|| VmError::RuntimeError("Division by zero".to_string()))?;
+                if id == bigint::DIV {
+                    quotient
+                } else {
+                    remainder
+                }
+            }
+            id if id == bigint::POW => bigint_arg(arg(0)?)?.pow(exponent_arg(arg(1)?)?),
+            id if id == bigint::COMPARE_TO => {
+                let ordering = bigint_arg(arg(0)?)?.cmp(&bigint_arg(arg(1)?)?);
+                return stack.push(Value::i32(ordering as i32));
+            }
+            _ => {
+                return Err(VmError::RuntimeError(format!(
+                    "BigInt method {:#06x} not implemented",
+                    method_id
+                )))
+            }
+        };
+
+        let gc_ptr = self
+            .gc
+            .lock()
+            .allocate(RayaString::new(result.to_decimal()));
+        let value = unsafe { Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap()) };
+        stack.push(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        BigInt::parse(text).expect("valid bigint")
+    }
+
+    #[test]
+    fn test_parse_canonicalizes() {
+        assert_eq!(big("0").to_decimal(), "0");
+        assert_eq!(big("-0").to_decimal(), "0");
+        assert_eq!(big("+007").to_decimal(), "7");
+        assert_eq!(
+            big("-123456789012345678901234567890").to_decimal(),
+            "-123456789012345678901234567890"
+        );
+        assert!(BigInt::parse("").is_none());
+        assert!(BigInt::parse("-").is_none());
+        assert!(BigInt::parse("12a").is_none());
+        assert!(BigInt::parse("1_000").is_none());
+    }
+
+    #[test]
+    fn test_arithmetic_across_limbs() {
+        let a = big("999999999999999999");
+        assert_eq!(a.add(&big("1")).to_decimal(), "1000000000000000000");
+        assert_eq!(big("1000000000000000000").sub(&big("1")), a);
+        assert_eq!(big("5").sub(&big("12")).to_decimal(), "-7");
+        assert_eq!(
+            big("123456789123456789")
+                .mul(&big("-987654321987654321"))
+                .to_decimal(),
+            "-121932631356500531347203169112635269"
+        );
+        assert_eq!(
+            big("2").pow(100).to_decimal(),
+            "1267650600228229401496703205376"
+        );
+        assert_eq!(big("-3").pow(3).to_decimal(), "-27");
+        assert_eq!(big("12").pow(0).to_decimal(), "1");
+    }
+
+    #[test]
+    fn test_div_rem_truncates_toward_zero() {
+        let (q, r) = big("1267650600228229401496703205377")
+            .div_rem(&big("1125899906842624"))
+            .unwrap();
+        assert_eq!(q.to_decimal(), "1125899906842624");
+        assert_eq!(r.to_decimal(), "1");
+
+        let (q, r) = big("-7").div_rem(&big("2")).unwrap();
+        assert_eq!((q.to_decimal(), r.to_decimal()), ("-3".into(), "-1".into()));
+        let (q, r) = big("7").div_rem(&big("-2")).unwrap();
+        assert_eq!((q.to_decimal(), r.to_decimal()), ("-3".into(), "1".into()));
+        assert!(big("1").div_rem(&BigInt::zero()).is_none());
+    }
+
+    #[test]
+    fn test_cmp_respects_sign() {
+        assert_eq!(big("-10").cmp(&big("2")), Ordering::Less);
+        assert_eq!(big("-10").cmp(&big("-2")), Ordering::Less);
+        assert_eq!(big("1000000000").cmp(&big("999999999")), Ordering::Greater);
+        assert_eq!(big("0").cmp(&big("-0")), Ordering::Equal);
+    }
+}
@@ -3,6 +3,7 @@
 //! Each module implements handler methods as `impl Interpreter` blocks.
 
 pub mod array;
+pub mod bigint;
 pub mod reflect;
 pub mod regexp;
 pub mod string;
@@ -1,7 +1,7 @@
 //! Reflect built-in method handlers and helpers
 
 use crate::compiler::Module;
-use crate::vm::gc::header_ptr_from_value_ptr;
+use crate::vm::gc::{header_ptr_from_value_ptr, write_barrier};
 use crate::vm::interpreter::core::value_to_f64;
 use crate::vm::interpreter::Interpreter;
 use crate::vm::object::{
@@ -13,6 +13,7 @@ use crate::vm::scheduler::Task;
 use crate::vm::stack::Stack;
 use crate::vm::value::Value;
 use crate::vm::VmError;
+use rustc_hash::FxHashSet;
 use std::ptr::NonNull;
 use std::sync::Arc;
 
@@ -69,6 +70,89 @@ impl<'a> Interpreter<'a> {
         field_names
     }
 
+    /// Own enumerable properties of `target` as `(key, value)` pairs, backing
+    /// `Object.keys`/`values`/`entries`.
+    ///
+    /// Fields come first in layout (declaration) order, followed by dynamic
+    /// keyed properties in insertion order. Methods live on the class, not
+    /// the instance, so they never appear; non-objects have no own properties.
+    pub(in crate::vm::interpreter) fn object_own_entries(
+        &self,
+        target: Value,
+    ) -> Vec<(String, Value)> {
+        let Some(ptr) = (unsafe { target.as_ptr::<u8>() }) else {
+            return Vec::new();
+        };
+        let header = unsafe { &*header_ptr_from_value_ptr(ptr.as_ptr()) };
+        if header.type_id() != std::any::TypeId::of::<Object>() {
+            return Vec::new();
+        }
+        let obj = unsafe { &*ptr.cast::<Object>().as_ptr() };
+
+        let mut entries = Vec::new();
+        let field_names = self.reflect_object_field_names(obj);
+        for (index, name) in field_names.into_iter().take(obj.field_count()).enumerate() {
+            if name.is_empty() {
+                continue;
+            }
+            if let Some(value) = obj.get_field(index) {
+                entries.push((name, value));
+            }
+        }
+
+        if let Some(dyn_map) = obj.dyn_map() {
+            for (key, value) in dyn_map {
+                let Some(name) = self.prop_key_name(*key) else {
+                    continue;
+                };
+                if !entries.iter().any(|(existing, _)| existing == &name) {
+                    entries.push((name, *value));
+                }
+            }
+        }
+
+        entries
+    }
+
+    /// Freeze `target` so later field stores raise a catchable error, backing
+    /// `Object.freeze`/`deepFreeze`.
+    ///
+    /// A plain freeze is shallow; with `deep` every object reachable through
+    /// fields and dynamic properties is frozen too. Non-objects are ignored.
+    pub(in crate::vm::interpreter) fn object_freeze(&self, target: Value, deep: bool) {
+        let mut pending = vec![target];
+        let mut visited = FxHashSet::default();
+        while let Some(value) = pending.pop() {
+            let Some(obj_ptr) = Self::plain_object_ptr(value) else {
+                continue;
+            };
+            if !visited.insert(obj_ptr.as_ptr() as usize) {
+                continue;
+            }
+            let obj = unsafe { &mut *obj_ptr.as_ptr() };
+            obj.freeze();
+            if !deep {
+                break;
+            }
+            pending.extend((0..obj.field_count()).filter_map(|index| obj.get_field(index)));
+            if let Some(dyn_map) = obj.dyn_map() {
+                pending.extend(dyn_map.values().copied());
+            }
+        }
+    }
+
+    /// Whether `target` is a frozen object, backing `Object.isFrozen`.
+    pub(in crate::vm::interpreter) fn object_is_frozen(&self, target: Value) -> bool {
+        Self::plain_object_ptr(target)
+            .is_some_and(|obj_ptr| unsafe { &*obj_ptr.as_ptr() }.is_frozen())
+    }
+
+    fn plain_object_ptr(value: Value) -> Option<NonNull<Object>> {
+        let ptr = unsafe { value.as_ptr::<u8>() }?;
+        let header = unsafe { &*header_ptr_from_value_ptr(ptr.as_ptr()) };
+        (header.type_id() == std::any::TypeId::of::<Object>()).then(|| ptr.cast::<Object>())
+    }
+
     fn reflect_object_class_name(&self, obj: &Object) -> String {
         if let Some(nominal_type_id) = obj.nominal_type_id_usize() {
             return self
@@ -97,6 +181,7 @@ impl<'a> Interpreter<'a> {
         }
         obj.ensure_dyn_map()
             .insert(self.intern_prop_key(property_key), value);
+        write_barrier(value);
         true
     }
 
@@ -269,7 +354,7 @@ impl<'a> Interpreter<'a> {
             }
             let s_ptr = unsafe { v.as_ptr::<RayaString>() };
             let s = unsafe { &*s_ptr.unwrap().as_ptr() };
-            Ok(s.data.clone())
+            Ok(s.as_str().to_string())
         };
 
         let result = match method_id {
@@ -1539,7 +1624,7 @@ impl<'a> Interpreter<'a> {
                     std::mem::size_of::<Array>() + arr.len() * 8
                 } else if let Some(ptr) = unsafe { value.as_ptr::<RayaString>() } {
                     let s = unsafe { &*ptr.as_ptr() };
-                    std::mem::size_of::<RayaString>() + s.data.len()
+                    std::mem::size_of::<RayaString>() + s.len()
                 } else {
                     8
                 };
@@ -1758,7 +1843,7 @@ impl<'a> Interpreter<'a> {
 
                 let method_name = if let Some(ptr) = unsafe { args[1].as_ptr::<RayaString>() } {
                     let s = unsafe { &*ptr.as_ptr() };
-                    s.data.clone()
+                    s.as_str().to_string()
                 } else {
                     return Err(VmError::RuntimeError(
                         "getSourceLocation: methodName must be a string".to_string(),
@@ -2687,7 +2772,7 @@ impl<'a> Interpreter<'a> {
             let s = unsafe { &*ptr.as_ptr() };
             return Ok(format!(
                 "\"{}\"",
-                s.data.replace('\\', "\\\\").replace('"', "\\\"")
+                s.as_str().replace('\\', "\\\\").replace('"', "\\\"")
             ));
         }
 
@@ -2781,7 +2866,7 @@ impl<'a> Interpreter<'a> {
 
         if let Some(ptr) = unsafe { value.as_ptr::<RayaString>() } {
             let s = unsafe { &*ptr.as_ptr() };
-            return std::mem::size_of::<RayaString>() + s.data.len();
+            return std::mem::size_of::<RayaString>() + s.len();
         }
 
         8 // default
@@ -2855,7 +2940,7 @@ impl<'a> Interpreter<'a> {
             visited.pop();
             return Ok(format!(
                 "\"{}\"",
-                s.data
+                s.as_str()
                     .replace('\\', "\\\\")
                     .replace('"', "\\\"")
                     .replace('\n', "\\n")
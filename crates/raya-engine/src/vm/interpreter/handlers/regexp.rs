@@ -43,7 +43,7 @@ impl<'a> Interpreter<'a> {
             id if id == regexp::TEST => {
                 let input = if !args.is_empty() && args[0].is_ptr() {
                     if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                        unsafe { &*s.as_ptr() }.data.clone()
+                        unsafe { &*s.as_ptr() }.as_str().to_string()
                     } else {
                         String::new()
                     }
@@ -55,7 +55,7 @@ impl<'a> Interpreter<'a> {
             id if id == regexp::EXEC => {
                 let input = if !args.is_empty() && args[0].is_ptr() {
                     if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                        unsafe { &*s.as_ptr() }.data.clone()
+                        unsafe { &*s.as_ptr() }.as_str().to_string()
                     } else {
                         String::new()
                     }
@@ -94,7 +94,7 @@ impl<'a> Interpreter<'a> {
             id if id == regexp::EXEC_ALL => {
                 let input = if !args.is_empty() && args[0].is_ptr() {
                     if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                        unsafe { &*s.as_ptr() }.data.clone()
+                        unsafe { &*s.as_ptr() }.as_str().to_string()
                     } else {
                         String::new()
                     }
@@ -134,7 +134,7 @@ impl<'a> Interpreter<'a> {
             id if id == regexp::REPLACE => {
                 let input = if !args.is_empty() && args[0].is_ptr() {
                     if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                        unsafe { &*s.as_ptr() }.data.clone()
+                        unsafe { &*s.as_ptr() }.as_str().to_string()
                     } else {
                         String::new()
                     }
@@ -143,7 +143,7 @@ impl<'a> Interpreter<'a> {
                 };
                 let replacement = if args.len() > 1 && args[1].is_ptr() {
                     if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                        unsafe { &*s.as_ptr() }.data.clone()
+                        unsafe { &*s.as_ptr() }.as_str().to_string()
                     } else {
                         String::new()
                     }
@@ -160,7 +160,7 @@ impl<'a> Interpreter<'a> {
             id if id == regexp::SPLIT => {
                 let input = if !args.is_empty() && args[0].is_ptr() {
                     if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                        unsafe { &*s.as_ptr() }.data.clone()
+                        unsafe { &*s.as_ptr() }.as_str().to_string()
                     } else {
                         String::new()
                     }
@@ -206,7 +206,7 @@ impl<'a> Interpreter<'a> {
                 // Returns: array of [matched_text, start_index] arrays, respecting 'g' flag
                 let input = if !args.is_empty() && args[0].is_ptr() {
                     if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                        unsafe { &*s.as_ptr() }.data.clone()
+                        unsafe { &*s.as_ptr() }.as_str().to_string()
                     } else {
                         String::new()
                     }
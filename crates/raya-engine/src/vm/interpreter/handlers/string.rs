@@ -2,7 +2,7 @@
 
 use crate::compiler::Module;
 use crate::vm::interpreter::Interpreter;
-use crate::vm::object::{Array, RayaString, RegExpObject};
+use crate::vm::object::{Array, LayoutId, Object, RayaString, RegExpObject};
 use crate::vm::scheduler::Task;
 use crate::vm::stack::Stack;
 use crate::vm::value::Value;
@@ -18,6 +18,15 @@ fn to_i32_arg(value: Value, default: i32) -> i32 {
         .unwrap_or(default)
 }
 
+/// Optional `limit` argument of `split`; zero or negative means no limit.
+#[inline]
+fn split_limit(args: &[Value]) -> Option<usize> {
+    args.get(1)
+        .map(|&limit| to_i32_arg(limit, 0))
+        .filter(|&limit| limit > 0)
+        .map(|limit| limit as usize)
+}
+
 impl<'a> Interpreter<'a> {
     /// Handle built-in string methods
     pub(in crate::vm::interpreter) fn call_string_method(
@@ -44,7 +53,7 @@ impl<'a> Interpreter<'a> {
         }
         let str_ptr = unsafe { string_val.as_ptr::<RayaString>() };
         let raya_str = unsafe { &*str_ptr.unwrap().as_ptr() };
-        let s = &raya_str.data;
+        let s = raya_str.as_str();
 
         match method_id {
             string::CHAR_AT => {
@@ -103,7 +112,7 @@ impl<'a> Interpreter<'a> {
                 }
                 let search_val = args[0];
                 let search_str = if let Some(ptr) = unsafe { search_val.as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
@@ -132,7 +141,7 @@ impl<'a> Interpreter<'a> {
                 }
                 let search_val = args[0];
                 let search_str = if let Some(ptr) = unsafe { search_val.as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
@@ -149,7 +158,7 @@ impl<'a> Interpreter<'a> {
                 }
                 let prefix_val = args[0];
                 let prefix_str = if let Some(ptr) = unsafe { prefix_val.as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
@@ -166,7 +175,7 @@ impl<'a> Interpreter<'a> {
                 }
                 let suffix_val = args[0];
                 let suffix_str = if let Some(ptr) = unsafe { suffix_val.as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
@@ -201,47 +210,37 @@ impl<'a> Interpreter<'a> {
                 stack.push(value)?;
                 Ok(())
             }
-            string::SPLIT => {
+            string::SPLIT | string::SPLIT_REGEXP => {
+                use crate::vm::json::view::{js_classify, JSView};
+
                 if !(1..=2).contains(&arg_count) {
                     return Err(VmError::RuntimeError(format!(
                         "String.split expects 1-2 arguments, got {}",
                         arg_count
                     )));
                 }
-                let sep_val = args[0];
-                let sep_str = if let Some(ptr) = unsafe { sep_val.as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
-                } else {
-                    String::new()
-                };
-
-                // Get optional limit argument (try both i32 and i64)
-                // In Raya, limit 0 means "no limit"
-                let limit = if arg_count == 2 {
-                    let raw_limit = to_i32_arg(args[1], 0);
-                    if raw_limit > 0 {
-                        Some(raw_limit as usize)
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
-
-                // Split and optionally limit the parts
-                let parts: Vec<_> = if sep_str.is_empty() {
-                    let chars: Vec<_> = s.chars().map(|c| c.to_string()).collect();
-                    if let Some(limit) = limit {
-                        chars.into_iter().take(limit).collect()
-                    } else {
-                        chars
+                let limit = split_limit(&args);
+
+                // The lowerer only picks SPLIT_REGEXP when it can see the
+                // separator is a RegExp, so a `string | RegExp` separator can
+                // still arrive here as a RegExp.
+                let parts: Vec<String> = match js_classify(args[0]) {
+                    JSView::Str(ptr) => {
+                        let sep = unsafe { &*ptr }.as_str();
+                        let max = limit.unwrap_or(usize::MAX);
+                        if sep.is_empty() {
+                            s.chars().take(max).map(|c| c.to_string()).collect()
+                        } else {
+                            s.split(sep).take(max).map(str::to_string).collect()
+                        }
                     }
-                } else {
-                    let all_parts: Vec<_> = s.split(&sep_str).map(|p| p.to_string()).collect();
-                    if let Some(limit) = limit {
-                        all_parts.into_iter().take(limit).collect()
-                    } else {
-                        all_parts
+                    _ => {
+                        let handle = self.regexp_handle_from_value(args[0])?;
+                        let re_ptr = handle as *const RegExpObject;
+                        if re_ptr.is_null() {
+                            return Err(VmError::RuntimeError("Invalid regexp handle".to_string()));
+                        }
+                        unsafe { &*re_ptr }.split(s, limit)
                     }
                 };
 
@@ -282,7 +281,7 @@ impl<'a> Interpreter<'a> {
                 }
                 let search_val = args[0];
                 let search_str = if let Some(ptr) = unsafe { search_val.as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
@@ -307,7 +306,7 @@ impl<'a> Interpreter<'a> {
                 let target_length = to_i32_arg(args[0], 0) as usize;
                 let pad_str = if arg_count >= 2 {
                     if let Some(ptr) = unsafe { args[1].as_ptr::<RayaString>() } {
-                        unsafe { &*ptr.as_ptr() }.data.clone()
+                        unsafe { &*ptr.as_ptr() }.as_str().to_string()
                     } else {
                         " ".to_string()
                     }
@@ -316,7 +315,7 @@ impl<'a> Interpreter<'a> {
                 };
 
                 let result = if s.len() >= target_length {
-                    s.clone()
+                    s.to_string()
                 } else {
                     let pad_len = target_length - s.len();
                     let pad_repeated = pad_str.repeat((pad_len / pad_str.len().max(1)) + 1);
@@ -341,7 +340,7 @@ impl<'a> Interpreter<'a> {
                 let target_length = to_i32_arg(args[0], 0) as usize;
                 let pad_str = if arg_count >= 2 {
                     if let Some(ptr) = unsafe { args[1].as_ptr::<RayaString>() } {
-                        unsafe { &*ptr.as_ptr() }.data.clone()
+                        unsafe { &*ptr.as_ptr() }.as_str().to_string()
                     } else {
                         " ".to_string()
                     }
@@ -350,7 +349,7 @@ impl<'a> Interpreter<'a> {
                 };
 
                 let result = if s.len() >= target_length {
-                    s.clone()
+                    s.to_string()
                 } else {
                     let pad_len = target_length - s.len();
                     let pad_repeated = pad_str.repeat((pad_len / pad_str.len().max(1)) + 1);
@@ -448,7 +447,7 @@ impl<'a> Interpreter<'a> {
                 Ok(())
             }
             string::MATCH_ALL => {
-                // matchAll(regexp): returns array of [match, index] arrays
+                // matchAll(regexp): returns every match, not just the first
                 if arg_count != 1 {
                     return Err(VmError::RuntimeError(format!(
                         "String.matchAll expects 1 argument, got {}",
@@ -463,28 +462,38 @@ impl<'a> Interpreter<'a> {
                 }
                 let re = unsafe { &*re_ptr };
 
-                // Return all matches as array of [match, index] arrays
+                // One `RegExpMatch` per match; its fields are, in order,
+                // match, index, input and groups.
+                let (class_id, field_count, layout_id) = self.regexp_match_class_layout()?;
                 let mut result_arr = Array::new(0, 0);
-                for m in re.compiled.find_iter(s) {
-                    // Create inner array [match_string, index]
-                    let mut match_arr = Array::new(0, 0);
+                for (matched, index, groups) in re.exec_all(s) {
+                    let mut groups_arr = Array::new(0, 0);
+                    for group in groups {
+                        let gc_ptr = self.gc.lock().allocate(RayaString::new(group));
+                        groups_arr.push(unsafe {
+                            Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap())
+                        });
+                    }
+                    let groups_ptr = self.gc.lock().allocate(groups_arr);
+                    let groups_val = unsafe {
+                        Value::from_ptr(std::ptr::NonNull::new(groups_ptr.as_ptr()).unwrap())
+                    };
 
-                    // Add match string
-                    let raya_string = RayaString::new(m.as_str().to_string());
-                    let gc_ptr = self.gc.lock().allocate(raya_string);
+                    let match_ptr = self.gc.lock().allocate(RayaString::new(matched));
                     let match_val = unsafe {
-                        Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap())
+                        Value::from_ptr(std::ptr::NonNull::new(match_ptr.as_ptr()).unwrap())
                     };
-                    match_arr.push(match_val);
 
-                    // Add index
-                    match_arr.push(Value::i32(m.start() as i32));
-
-                    let inner_gc_ptr = self.gc.lock().allocate(match_arr);
-                    let inner_val = unsafe {
-                        Value::from_ptr(std::ptr::NonNull::new(inner_gc_ptr.as_ptr()).unwrap())
-                    };
-                    result_arr.push(inner_val);
+                    let mut match_obj =
+                        Object::new_nominal(layout_id, class_id as u32, field_count);
+                    match_obj.fields[0] = match_val;
+                    match_obj.fields[1] = Value::i32(index as i32);
+                    match_obj.fields[2] = string_val;
+                    match_obj.fields[3] = groups_val;
+                    let obj_ptr = self.gc.lock().allocate(match_obj);
+                    result_arr.push(unsafe {
+                        Value::from_ptr(std::ptr::NonNull::new(obj_ptr.as_ptr()).unwrap())
+                    });
                 }
                 let gc_ptr = self.gc.lock().allocate(result_arr);
                 let value =
@@ -529,7 +538,7 @@ impl<'a> Interpreter<'a> {
                 let re = unsafe { &*re_ptr };
 
                 let replacement = if let Some(ptr) = unsafe { args[1].as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
@@ -548,59 +557,6 @@ impl<'a> Interpreter<'a> {
                 stack.push(value)?;
                 Ok(())
             }
-            string::SPLIT_REGEXP => {
-                // split(regexp, limit?): split string by regexp
-                if !(1..=2).contains(&arg_count) {
-                    return Err(VmError::RuntimeError(format!(
-                        "String.split expects 1-2 arguments, got {}",
-                        arg_count
-                    )));
-                }
-                let regexp_val = args[0];
-                let handle = self.regexp_handle_from_value(regexp_val)?;
-                let re_ptr = handle as *const RegExpObject;
-                if re_ptr.is_null() {
-                    return Err(VmError::RuntimeError("Invalid regexp handle".to_string()));
-                }
-                let re = unsafe { &*re_ptr };
-
-                // Get optional limit argument (try both i32 and i64)
-                // In Raya, limit 0 means "no limit"
-                let limit = if arg_count == 2 {
-                    let raw_limit = to_i32_arg(args[1], 0);
-                    if raw_limit > 0 {
-                        Some(raw_limit as usize)
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
-
-                // Split and optionally limit the parts
-                let all_parts: Vec<_> = re.compiled.split(s).map(|p| p.to_string()).collect();
-                let parts: Vec<_> = if let Some(limit) = limit {
-                    all_parts.into_iter().take(limit).collect()
-                } else {
-                    all_parts
-                };
-
-                let mut arr = Array::new(0, 0);
-                for part in parts {
-                    let raya_string = RayaString::new(part);
-                    let gc_ptr = self.gc.lock().allocate(raya_string);
-                    let value = unsafe {
-                        Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap())
-                    };
-                    arr.push(value);
-                }
-
-                let gc_ptr = self.gc.lock().allocate(arr);
-                let value =
-                    unsafe { Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap()) };
-                stack.push(value)?;
-                Ok(())
-            }
             string::REPLACE_WITH_REGEXP => {
                 // replaceWith is now handled as a compiler intrinsic (inline loop + CallClosure).
                 // This path should never be reached.
@@ -660,12 +616,12 @@ impl<'a> Interpreter<'a> {
                     )));
                 }
                 let search_str = if let Some(ptr) = unsafe { args[0].as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
                 let replacement_str = if let Some(ptr) = unsafe { args[1].as_ptr::<RayaString>() } {
-                    unsafe { &*ptr.as_ptr() }.data.clone()
+                    unsafe { &*ptr.as_ptr() }.as_str().to_string()
                 } else {
                     String::new()
                 };
@@ -704,4 +660,22 @@ impl<'a> Interpreter<'a> {
             ))),
         }
     }
+    /// Allocation metadata `(class id, field count, layout)` for the builtin
+    /// `RegExpMatch` class.
+    fn regexp_match_class_layout(&self) -> Result<(usize, usize, LayoutId), VmError> {
+        let class_id = self
+            .classes
+            .read()
+            .get_class_by_name("RegExpMatch")
+            .map(|class| class.id)
+            .ok_or_else(|| VmError::RuntimeError("RegExpMatch class is not loaded".to_string()))?;
+        match self.nominal_allocation(class_id) {
+            Some((layout_id, field_count)) if field_count >= 4 => {
+                Ok((class_id, field_count, layout_id))
+            }
+            _ => Err(VmError::RuntimeError(
+                "RegExpMatch class has an unexpected layout".to_string(),
+            )),
+        }
+    }
 }
@@ -0,0 +1,198 @@
+//! Monomorphic inline caches for structural field access
+//!
+//! `LoadFieldShape`, `StoreFieldShape` and `OptionalFieldShape` map a slot of
+//! the shape the compiler expected onto the receiver's actual layout. Resolving
+//! that through the shared shape-adapter table takes a lock and clones an `Arc`
+//! on every access. Each access site instead remembers the last receiver layout
+//! it saw and the field slot that layout resolved to, so repeat accesses with
+//! the same layout skip the adapter table entirely. A receiver with a different
+//! layout misses, takes the slow path, and replaces the entry.
+//!
+//! Interpreters are rebuilt for every task slice, so the caches live in a
+//! [`FieldInlineCachePool`] in `SharedVmState`: a worker takes a cache when a
+//! slice starts and returns it when the slice ends, keeping entries warm across
+//! calls and tasks without locking on each access.
+//!
+//! Only concrete field bindings are cached. Method, dynamic-key and missing
+//! bindings always take the slow path, so a layout that later grows fields
+//! can never be served a stale "missing" answer.
+
+use crate::vm::object::{LayoutId, ShapeId};
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+
+/// Identity of an access site: the address of the instruction's operands
+/// inside the module bytecode, which stays fixed while the module is alive.
+pub type FieldSiteId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FieldCacheEntry {
+    layout: LayoutId,
+    shape: ShapeId,
+    expected_slot: usize,
+    field_slot: usize,
+}
+
+/// Field inline caches, one entry per access site
+///
+/// Entries are validated against the full `(layout, shape, expected slot)`
+/// key, so a site address reused by a later module can only miss.
+#[derive(Debug, Default)]
+pub struct FieldInlineCache {
+    sites: FxHashMap<FieldSiteId, FieldCacheEntry>,
+    hits: u64,
+    misses: u64,
+}
+
+impl FieldInlineCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Field slot cached at `site` for this receiver layout, if any
+    #[inline]
+    pub fn lookup(
+        &mut self,
+        site: FieldSiteId,
+        layout: LayoutId,
+        shape: ShapeId,
+        expected_slot: usize,
+    ) -> Option<usize> {
+        match self.sites.get(&site) {
+            Some(entry)
+                if entry.layout == layout
+                    && entry.shape == shape
+                    && entry.expected_slot == expected_slot =>
+            {
+                self.hits += 1;
+                Some(entry.field_slot)
+            }
+            _ => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Remember that `layout` resolves `expected_slot` of `shape` to `field_slot` at `site`
+    ///
+    /// Layout 0 has no registered member names and is never cached.
+    #[inline]
+    pub fn record(
+        &mut self,
+        site: FieldSiteId,
+        layout: LayoutId,
+        shape: ShapeId,
+        expected_slot: usize,
+        field_slot: usize,
+    ) {
+        if layout == 0 {
+            return;
+        }
+        self.sites.insert(
+            site,
+            FieldCacheEntry {
+                layout,
+                shape,
+                expected_slot,
+                field_slot,
+            },
+        );
+    }
+
+    /// Number of lookups served from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of lookups that fell back to the adapter table
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+/// Field inline caches not currently lent to an interpreter
+#[derive(Debug, Default)]
+pub struct FieldInlineCachePool {
+    caches: Mutex<Vec<FieldInlineCache>>,
+}
+
+impl FieldInlineCachePool {
+    /// Create an empty pool
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take a cache from the pool, or an empty one if none is free
+    pub fn acquire(&self) -> FieldInlineCache {
+        self.caches.lock().pop().unwrap_or_default()
+    }
+
+    /// Return a cache taken with [`acquire`](Self::acquire)
+    pub fn release(&self, cache: FieldInlineCache) {
+        self.caches.lock().push(cache);
+    }
+
+    /// Total `(hits, misses)` of the pooled caches
+    pub fn stats(&self) -> (u64, u64) {
+        self.caches
+            .lock()
+            .iter()
+            .fold((0, 0), |(hits, misses), cache| {
+                (hits + cache.hits, misses + cache.misses)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monomorphic_site_hits_after_first_access() {
+        let mut cache = FieldInlineCache::new();
+        assert_eq!(cache.lookup(0x100, 7, 42, 1), None);
+        cache.record(0x100, 7, 42, 1, 3);
+        for _ in 0..10 {
+            assert_eq!(cache.lookup(0x100, 7, 42, 1), Some(3));
+        }
+        assert_eq!(cache.hits(), 10);
+        assert_eq!(cache.misses(), 1);
+    }
+
+    #[test]
+    fn test_layout_change_misses_and_replaces_entry() {
+        let mut cache = FieldInlineCache::new();
+        cache.record(0x100, 7, 42, 1, 3);
+        assert_eq!(cache.lookup(0x100, 8, 42, 1), None);
+        cache.record(0x100, 8, 42, 1, 0);
+        assert_eq!(cache.lookup(0x100, 8, 42, 1), Some(0));
+        assert_eq!(cache.lookup(0x100, 7, 42, 1), None);
+    }
+
+    #[test]
+    fn test_entries_are_keyed_by_site_and_full_shape_slot() {
+        let mut cache = FieldInlineCache::new();
+        cache.record(0x100, 7, 42, 1, 3);
+        assert_eq!(cache.lookup(0x200, 7, 42, 1), None);
+        assert_eq!(cache.lookup(0x100, 7, 43, 1), None);
+        assert_eq!(cache.lookup(0x100, 7, 42, 0), None);
+
+        cache.record(0x300, 0, 42, 1, 3);
+        assert_eq!(cache.lookup(0x300, 0, 42, 1), None);
+    }
+
+    #[test]
+    fn test_pooled_cache_keeps_entries_between_acquires() {
+        let pool = FieldInlineCachePool::new();
+        let mut cache = pool.acquire();
+        cache.record(0x100, 7, 42, 1, 3);
+        pool.release(cache);
+
+        let mut cache = pool.acquire();
+        assert_eq!(cache.lookup(0x100, 7, 42, 1), Some(3));
+        pool.release(cache);
+        assert_eq!(pool.stats(), (1, 0));
+    }
+}
@@ -164,7 +164,7 @@ fn marshal_recursive(
             // Try to extract as string
             if let Some(str_ptr) = value.as_ptr::<RayaString>() {
                 let raya_string = &*str_ptr.as_ptr();
-                return Ok(MarshalledValue::String(raya_string.data.clone()));
+                return Ok(MarshalledValue::String(raya_string.as_str().to_string()));
             }
 
             // TODO: Handle arrays, objects
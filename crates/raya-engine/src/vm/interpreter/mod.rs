@@ -7,6 +7,7 @@ mod core;
 pub mod debug_state;
 pub(crate) mod execution;
 mod handlers;
+mod inline_cache;
 mod marshal;
 mod module_registry;
 mod native_module_registry;
@@ -19,19 +20,22 @@ pub use capabilities::{Capability, CapabilityError, CapabilityRegistry};
 pub use capabilities::{HttpCapability, LogCapability, ReadCapability};
 pub use class_registry::{ClassRegistry, RuntimeLayoutRegistry};
 pub use context::{
-    ContextRegistry, ResourceCounters, ResourceLimits, VmContext, VmContextId, VmOptions,
+    ContextRegistry, HandleLimits, ResourceCounters, ResourceLimits, VmContext, VmContextId,
+    VmOptions,
 };
 pub use core::Interpreter;
 pub use debug_state::DebugState;
 pub use execution::{ControlFlow, ExecutionFrame, ExecutionResult, OpcodeResult, ReturnAction};
+pub use inline_cache::{FieldInlineCache, FieldInlineCachePool, FieldSiteId};
 pub use marshal::{marshal, unmarshal, ForeignHandleManager, MarshalError, MarshalledValue};
 pub use module_registry::ModuleRegistry;
 pub use native_module_registry::{NativeFn, NativeModule, NativeModuleRegistry};
 pub use safepoint::{SafepointCoordinator, StopReason};
-#[cfg(feature = "jit")]
-pub use shared_state::{JitTelemetry, JitTelemetrySnapshot};
 pub use shared_state::{
-    ModuleRuntimeLayout, PromiseMicrotask, PropertyKeyRegistry, RuntimeTypeHandleRegistry,
-    ShapeAdapter, SharedVmState, StructuralAdapterKey, StructuralSlotBinding,
+    ConstantStringCache, ModuleRuntimeLayout, PromiseMicrotask, PropertyKeyRegistry,
+    RuntimeTypeHandleRegistry, ShapeAdapter, SharedVmState, StructuralAdapterKey,
+    StructuralSlotBinding,
 };
+#[cfg(feature = "jit")]
+pub use shared_state::{JitTelemetry, JitTelemetrySnapshot};
 pub use vm_facade::{Vm, VmStats};
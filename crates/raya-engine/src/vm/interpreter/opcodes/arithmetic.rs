@@ -337,7 +337,7 @@ impl<'a> Interpreter<'a> {
                     Ok(v) => v,
                     Err(e) => return OpcodeResult::Error(e),
                 };
-                if let Err(e) = stack.push(Value::f64(a + b)) {
+                if let Err(e) = stack.push(self.float_result(a + b)) {
                     return OpcodeResult::Error(e);
                 }
                 OpcodeResult::Continue
@@ -352,7 +352,7 @@ impl<'a> Interpreter<'a> {
                     Ok(v) => v,
                     Err(e) => return OpcodeResult::Error(e),
                 };
-                if let Err(e) = stack.push(Value::f64(a - b)) {
+                if let Err(e) = stack.push(self.float_result(a - b)) {
                     return OpcodeResult::Error(e);
                 }
                 OpcodeResult::Continue
@@ -367,7 +367,7 @@ impl<'a> Interpreter<'a> {
                     Ok(v) => v,
                     Err(e) => return OpcodeResult::Error(e),
                 };
-                if let Err(e) = stack.push(Value::f64(a * b)) {
+                if let Err(e) = stack.push(self.float_result(a * b)) {
                     return OpcodeResult::Error(e);
                 }
                 OpcodeResult::Continue
@@ -395,7 +395,7 @@ impl<'a> Interpreter<'a> {
                     Ok(v) => v,
                     Err(e) => return OpcodeResult::Error(e),
                 };
-                if let Err(e) = stack.push(Value::f64(a / b)) {
+                if let Err(e) = stack.push(self.float_result(a / b)) {
                     return OpcodeResult::Error(e);
                 }
                 OpcodeResult::Continue
@@ -458,4 +458,15 @@ impl<'a> Interpreter<'a> {
             _ => unreachable!("Not an arithmetic opcode: {:?}", opcode),
         }
     }
+
+    /// Box a float arithmetic result. In deterministic mode a NaN becomes the
+    /// canonical quiet NaN, as Cranelift's NaN canonicalization does for JIT code.
+    #[inline]
+    fn float_result(&self, value: f64) -> Value {
+        if self.deterministic_float && value.is_nan() {
+            Value::f64(f64::NAN)
+        } else {
+            Value::f64(value)
+        }
+    }
 }
@@ -351,7 +351,7 @@ impl<'a> Interpreter<'a> {
                     if let (Some(a_ptr), Some(b_ptr)) = (a_str, b_str) {
                         let a_ref = unsafe { &*a_ptr.as_ptr() };
                         let b_ref = unsafe { &*b_ptr.as_ptr() };
-                        a_ref.data == b_ref.data
+                        a_ref.content_eq(b_ref)
                     } else {
                         a == b
                     }
@@ -392,7 +392,7 @@ impl<'a> Interpreter<'a> {
                     if let (Some(a_ptr), Some(b_ptr)) = (a_str, b_str) {
                         let a_ref = unsafe { &*a_ptr.as_ptr() };
                         let b_ref = unsafe { &*b_ptr.as_ptr() };
-                        a_ref.data != b_ref.data
+                        !a_ref.content_eq(b_ref)
                     } else {
                         a != b
                     }
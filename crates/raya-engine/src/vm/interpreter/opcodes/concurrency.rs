@@ -565,6 +565,16 @@ impl<'a> Interpreter<'a> {
                 // Look up the task and cancel it
                 if let Some(target_task) = self.tasks.read().get(&target_id).cloned() {
                     target_task.cancel();
+                    // Wake a sleeping target so it unwinds now; the reactor drops
+                    // its timer entry once it comes due.
+                    if matches!(
+                        target_task.suspend_reason(),
+                        Some(SuspendReason::Sleep { .. })
+                    ) && target_task.resume_if_pending()
+                    {
+                        target_task.clear_suspend_reason();
+                        self.injector.push(target_task);
+                    }
                 }
                 // Silently ignore if task not found (may have already completed)
 
@@ -1,7 +1,7 @@
 //! Exception handling opcode handlers: Try, EndTry, Throw, Rethrow
 
 use crate::compiler::Opcode;
-use crate::vm::gc::header_ptr_from_value_ptr;
+use crate::vm::gc::{header_ptr_from_value_ptr, write_barrier};
 use crate::vm::interpreter::execution::OpcodeResult;
 use crate::vm::interpreter::Interpreter;
 use crate::vm::object::{Object, RayaString};
@@ -119,6 +119,7 @@ impl<'a> Interpreter<'a> {
         let key = self.intern_prop_key(field_name);
         if map.contains_key(&key) {
             map.insert(key, value);
+            write_barrier(value);
             true
         } else {
             false
@@ -130,7 +131,7 @@ impl<'a> Interpreter<'a> {
             return Some(String::new());
         }
         if let Some(ptr) = Self::string_ptr_from_value(value) {
-            return Some(unsafe { &*ptr.as_ptr() }.data.clone());
+            return Some(unsafe { &*ptr.as_ptr() }.as_str().to_string());
         }
         if let Some(i) = value.as_i32() {
             return Some(i.to_string());
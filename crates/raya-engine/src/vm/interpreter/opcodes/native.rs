@@ -8,13 +8,14 @@ use crate::compiler::native_id::{
     CHANNEL_RECEIVE, CHANNEL_SEND, CHANNEL_TRY_RECEIVE, CHANNEL_TRY_SEND,
 };
 use crate::compiler::{Module, Opcode};
-use crate::vm::builtin::{buffer, date, map, mutex, regexp, set, url};
-use crate::vm::gc::header_ptr_from_value_ptr;
+use crate::vm::builtin::{buffer, date, map, mutex, random, regexp, set, url, weak_map, weak_ref};
+use crate::vm::gc::{header_ptr_from_value_ptr, write_barrier};
 use crate::vm::interpreter::execution::{OpcodeResult, ReturnAction};
 use crate::vm::interpreter::Interpreter;
 use crate::vm::object::{
-    Array, BoundMethod, BoundNativeMethod, Buffer, ChannelObject, Class, Closure, DateObject,
-    LayoutId, MapObject, Object, RayaString, RegExpObject, SetObject, TypeHandle,
+    Array, BoundMethod, BoundNativeMethod, Buffer, BufferElement, ByteOrder, ChannelObject, Class,
+    Closure, DateObject, LayoutId, MapObject, Object, RandomObject, RayaString, RegExpObject,
+    SetObject, TextEncoding, TypeHandle,
 };
 use crate::vm::scheduler::{Task, TaskId, TaskState};
 use crate::vm::stack::Stack;
@@ -33,7 +34,18 @@ fn value_as_string(arg: Value) -> Result<String, VmError> {
     let Some(s) = (unsafe { arg.as_ptr::<RayaString>() }) else {
         return Err(VmError::TypeError("Expected string".to_string()));
     };
-    Ok(unsafe { &*s.as_ptr() }.data.clone())
+    Ok(unsafe { &*s.as_ptr() }.as_str().to_string())
+}
+
+/// Encoding name passed at `args[index]`; UTF-8 when omitted. Unknown names
+/// throw a `TypeError`, as in Node.
+fn encoding_arg(args: &[Value], index: usize) -> Result<TextEncoding, VmError> {
+    let Some(&name) = args.get(index).filter(|v| !v.is_null()) else {
+        return Ok(TextEncoding::Utf8);
+    };
+    let name = value_as_string(name)?;
+    TextEncoding::from_name(&name)
+        .ok_or_else(|| VmError::TypeError(format!("Unknown encoding: {}", name)))
 }
 
 fn is_uri_unreserved(byte: u8) -> bool {
@@ -76,6 +88,54 @@ fn percent_decode_uri_component(input: &str) -> Result<String, VmError> {
     String::from_utf8(out).map_err(|_| VmError::RuntimeError("Invalid UTF-8".to_string()))
 }
 
+/// Element, byte order and direction (`true` = write) of a typed `Buffer`
+/// accessor. The unsuffixed accessors are little-endian.
+fn buffer_accessor(id: u16) -> Option<(BufferElement, ByteOrder, bool)> {
+    use BufferElement::*;
+    use ByteOrder::*;
+
+    Some(match id {
+        buffer::GET_BYTE | buffer::GET_UINT8 => (Uint8, Little, false),
+        buffer::SET_BYTE | buffer::SET_UINT8 => (Uint8, Little, true),
+        buffer::GET_INT16_LE => (Int16, Little, false),
+        buffer::GET_INT16_BE => (Int16, Big, false),
+        buffer::SET_INT16_LE => (Int16, Little, true),
+        buffer::SET_INT16_BE => (Int16, Big, true),
+        buffer::GET_UINT16_LE => (Uint16, Little, false),
+        buffer::GET_UINT16_BE => (Uint16, Big, false),
+        buffer::SET_UINT16_LE => (Uint16, Little, true),
+        buffer::SET_UINT16_BE => (Uint16, Big, true),
+        buffer::GET_INT32 | buffer::GET_INT32_LE => (Int32, Little, false),
+        buffer::GET_INT32_BE => (Int32, Big, false),
+        buffer::SET_INT32 | buffer::SET_INT32_LE => (Int32, Little, true),
+        buffer::SET_INT32_BE => (Int32, Big, true),
+        buffer::GET_FLOAT64 | buffer::GET_FLOAT64_LE => (Float64, Little, false),
+        buffer::GET_FLOAT64_BE => (Float64, Big, false),
+        buffer::SET_FLOAT64 | buffer::SET_FLOAT64_LE => (Float64, Little, true),
+        buffer::SET_FLOAT64_BE => (Float64, Big, true),
+        _ => return None,
+    })
+}
+
+/// Byte offset argument of a typed `Buffer` accessor. Integral offsets are
+/// accepted whether they arrive as an int or a number; a negative, fractional
+/// or non-finite offset is returned as the `Err` value.
+fn buffer_offset(value: Value) -> Result<usize, f64> {
+    if let Some(i) = value.as_i32() {
+        return usize::try_from(i).map_err(|_| f64::from(i));
+    }
+    let offset = value
+        .as_f64()
+        .or_else(|| value.as_i64().map(|v| v as f64))
+        .unwrap_or(f64::NAN);
+    if offset.fract() == 0.0 && offset >= 0.0 {
+        // Offsets past `usize::MAX` saturate and fail the bounds check.
+        Ok(offset as usize)
+    } else {
+        Err(offset)
+    }
+}
+
 impl<'a> Interpreter<'a> {
     fn normalize_dynamic_value(&self, value: Value) -> Value {
         use crate::vm::json::view::{js_classify, JSView};
@@ -95,6 +155,27 @@ impl<'a> Interpreter<'a> {
         }
     }
 
+    /// Elements of an optional `Map`/`Set` initializer array (`null` means empty).
+    fn collection_initializer(
+        &self,
+        init: Option<Value>,
+        what: &str,
+    ) -> Result<Vec<Value>, VmError> {
+        use crate::vm::json::view::{js_classify, JSView};
+
+        match init {
+            None => Ok(Vec::new()),
+            Some(value) if value.is_null() => Ok(Vec::new()),
+            Some(value) => match js_classify(value) {
+                JSView::Arr(ptr) => Ok(unsafe { (*ptr).elements.clone() }),
+                _ => Err(VmError::TypeError(format!(
+                    "{} initializer must be an array",
+                    what
+                ))),
+            },
+        }
+    }
+
     fn collect_dynamic_entries(&self, value: Value) -> Vec<(String, Value)> {
         use crate::vm::json::view::{js_classify, JSView};
 
@@ -161,6 +242,7 @@ impl<'a> Interpreter<'a> {
                     } else {
                         obj.ensure_dyn_map()
                             .insert(self.intern_prop_key(key), *value);
+                        write_barrier(*value);
                     }
                 }
             }
@@ -388,12 +470,19 @@ impl<'a> Interpreter<'a> {
             let obj_ptr = unsafe { target.as_ptr::<Object>() }
                 .ok_or_else(|| VmError::TypeError("Expected object".to_string()))?;
             let obj = unsafe { &mut *obj_ptr.as_ptr() };
+            if obj.is_frozen() {
+                return Err(VmError::TypeError(format!(
+                    "Cannot define property '{}' on frozen object",
+                    key
+                )));
+            }
             if let Some(field_index) = self.get_field_index_for_value(target, key) {
                 obj.set_field(field_index, value)
                     .map_err(VmError::RuntimeError)?;
             } else {
                 let prop_key = self.intern_prop_key(key);
                 obj.ensure_dyn_map().insert(prop_key, value);
+                write_barrier(value);
             }
         }
 
@@ -507,6 +596,29 @@ impl<'a> Interpreter<'a> {
             .ok_or_else(|| VmError::RuntimeError("Set.setPtr is not a valid handle".to_string()))
     }
 
+    fn weak_handle_from_value(value: Value) -> Result<u64, VmError> {
+        Self::decode_u64_handle(value)
+            .ok_or_else(|| VmError::RuntimeError("Invalid weak reference handle".to_string()))
+    }
+
+    fn random_handle_from_value(&self, value: Value) -> Result<u64, VmError> {
+        if let Some(handle) = Self::decode_u64_handle(value) {
+            return Ok(handle);
+        }
+        let obj_ptr = unsafe { value.as_ptr::<Object>() }.ok_or_else(|| {
+            VmError::TypeError("Expected Random object or random handle".to_string())
+        })?;
+        let obj = unsafe { &*obj_ptr.as_ptr() };
+        let field_index = self
+            .get_field_index_for_value(value, "rngPtr")
+            .ok_or_else(|| VmError::RuntimeError("Random field 'rngPtr' not found".to_string()))?;
+        let raw = obj
+            .get_field(field_index)
+            .ok_or_else(|| VmError::RuntimeError("Random.rngPtr is missing".to_string()))?;
+        Self::decode_u64_handle(raw)
+            .ok_or_else(|| VmError::RuntimeError("Random.rngPtr is not a valid handle".to_string()))
+    }
+
     pub(in crate::vm::interpreter) fn regexp_handle_from_value(
         &self,
         value: Value,
@@ -687,6 +799,30 @@ impl<'a> Interpreter<'a> {
         Ok(Some(descriptor))
     }
 
+    /// Run the native call policy (if any) for a call about to be dispatched.
+    fn check_native_policy(
+        &self,
+        id: Option<u16>,
+        name: &str,
+        args: &[Value],
+    ) -> Result<(), VmError> {
+        let Some(policy) = self.native_policy.as_ref() else {
+            return Ok(());
+        };
+        let call = crate::vm::NativeCallInfo { name, id, args };
+        match policy.check(&call) {
+            crate::vm::NativeCallDecision::Allow => Ok(()),
+            crate::vm::NativeCallDecision::Log => {
+                policy.audit(&call);
+                Ok(())
+            }
+            crate::vm::NativeCallDecision::Deny(reason) => Err(VmError::SecurityError(format!(
+                "native call '{}' denied: {}",
+                name, reason
+            ))),
+        }
+    }
+
     pub(in crate::vm::interpreter) fn exec_native_ops(
         &mut self,
         stack: &mut Stack,
@@ -752,6 +888,13 @@ impl<'a> Interpreter<'a> {
                 }
                 args.reverse();
 
+                if self.native_policy.is_some() {
+                    let name = crate::compiler::native_id::native_name(native_id);
+                    if let Err(e) = self.check_native_policy(Some(native_id), name, &args) {
+                        return OpcodeResult::Error(e);
+                    }
+                }
+
                 // Route builtin array native IDs through shared array handler.
                 // Native array calls use args = [receiver, ...methodArgs].
                 if crate::vm::builtin::is_array_method(native_id) {
@@ -803,6 +946,14 @@ impl<'a> Interpreter<'a> {
                     };
                 }
 
+                // BigInt natives take and return canonical decimal strings.
+                if crate::vm::builtin::is_bigint_method(native_id) {
+                    return match self.call_bigint_method(stack, native_id, &args) {
+                        Ok(()) => OpcodeResult::Continue,
+                        Err(e) => OpcodeResult::Error(e),
+                    };
+                }
+
                 // Execute native call - handle channel operations specially for suspension
                 match native_id {
                     id if id == crate::compiler::native_id::OBJECT_NEW => {
@@ -816,6 +967,89 @@ impl<'a> Interpreter<'a> {
                         OpcodeResult::Continue
                     }
 
+                    id if id == crate::compiler::native_id::OBJECT_KEYS
+                        || id == crate::compiler::native_id::OBJECT_VALUES
+                        || id == crate::compiler::native_id::OBJECT_ENTRIES =>
+                    {
+                        let Some(&target) = args.first() else {
+                            return OpcodeResult::Error(VmError::RuntimeError(format!(
+                                "{} requires 1 argument",
+                                crate::compiler::native_id::native_name(id)
+                            )));
+                        };
+                        let entries = self.object_own_entries(target);
+                        let mut gc = self.gc.lock();
+                        // Each allocation may collect, so keep the result and the
+                        // strings and pairs built for it rooted until it is pushed.
+                        let roots = gc.stack_root_count();
+                        let result_ptr = gc.allocate(Array::new(0, entries.len()));
+                        let result = unsafe {
+                            Value::from_ptr(std::ptr::NonNull::new(result_ptr.as_ptr()).unwrap())
+                        };
+                        gc.add_root(result);
+                        for (_, value) in &entries {
+                            gc.add_root(*value);
+                        }
+                        for (index, (key, value)) in entries.into_iter().enumerate() {
+                            let element = if id == crate::compiler::native_id::OBJECT_VALUES {
+                                value
+                            } else {
+                                let key_ptr = gc.allocate(RayaString::new(key));
+                                let key = unsafe {
+                                    Value::from_ptr(
+                                        std::ptr::NonNull::new(key_ptr.as_ptr()).unwrap(),
+                                    )
+                                };
+                                if id == crate::compiler::native_id::OBJECT_KEYS {
+                                    key
+                                } else {
+                                    gc.add_root(key);
+                                    let mut pair = Array::new(0, 2);
+                                    pair.set(0, key).ok();
+                                    pair.set(1, value).ok();
+                                    let pair_ptr = gc.allocate(pair);
+                                    unsafe {
+                                        Value::from_ptr(
+                                            std::ptr::NonNull::new(pair_ptr.as_ptr()).unwrap(),
+                                        )
+                                    }
+                                }
+                            };
+                            unsafe { &mut *result_ptr.as_ptr() }
+                                .set(index, element)
+                                .ok();
+                        }
+                        gc.truncate_stack_roots(roots);
+                        drop(gc);
+                        if let Err(e) = stack.push(result) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+
+                    id if id == crate::compiler::native_id::OBJECT_FREEZE
+                        || id == crate::compiler::native_id::OBJECT_DEEP_FREEZE
+                        || id == crate::compiler::native_id::OBJECT_IS_FROZEN =>
+                    {
+                        let Some(&target) = args.first() else {
+                            return OpcodeResult::Error(VmError::RuntimeError(format!(
+                                "{} requires 1 argument",
+                                crate::compiler::native_id::native_name(id)
+                            )));
+                        };
+                        let result = if id == crate::compiler::native_id::OBJECT_IS_FROZEN {
+                            Value::bool(self.object_is_frozen(target))
+                        } else {
+                            let deep = id == crate::compiler::native_id::OBJECT_DEEP_FREEZE;
+                            self.object_freeze(target, deep);
+                            target
+                        };
+                        if let Err(e) = stack.push(result) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+
                     id if id == crate::compiler::native_id::OBJECT_GET_AMBIENT_GLOBAL => {
                         if args.len() != 1 {
                             return OpcodeResult::Error(VmError::RuntimeError(
@@ -829,15 +1063,12 @@ impl<'a> Interpreter<'a> {
                             ));
                         };
                         let name = unsafe { &*name_ptr.as_ptr() };
-                        let Some(slot) = self
-                            .builtin_global_slots
-                            .read()
-                            .get(name.data.as_str())
-                            .copied()
+                        let Some(slot) =
+                            self.builtin_global_slots.read().get(name.as_str()).copied()
                         else {
                             return OpcodeResult::Error(VmError::RuntimeError(format!(
                                 "ambient builtin global '{}' is not initialized",
-                                name.data
+                                name.as_str()
                             )));
                         };
                         let value = self
@@ -1168,32 +1399,13 @@ impl<'a> Interpreter<'a> {
                         }
                         OpcodeResult::Continue
                     }
-                    id if id == buffer::GET_BYTE => {
-                        let handle = match self.buffer_handle_from_value(args[0]) {
-                            Ok(h) => h,
-                            Err(err) => return OpcodeResult::Error(err),
-                        };
-                        let index = args[1].as_i32().unwrap_or(0) as usize;
-                        let buf_ptr = handle as *const Buffer;
-                        if buf_ptr.is_null() {
-                            return OpcodeResult::Error(VmError::RuntimeError(
-                                "Invalid buffer handle".to_string(),
-                            ));
-                        }
-                        let buf = unsafe { &*buf_ptr };
-                        let value = buf.get_byte(index).unwrap_or(0);
-                        if let Err(e) = stack.push(Value::i32(value as i32)) {
-                            return OpcodeResult::Error(e);
-                        }
-                        OpcodeResult::Continue
-                    }
-                    id if id == buffer::SET_BYTE => {
+                    id if buffer_accessor(id).is_some() => {
+                        let (element, order, is_write) =
+                            buffer_accessor(id).expect("guarded by buffer_accessor");
                         let handle = match self.buffer_handle_from_value(args[0]) {
                             Ok(h) => h,
                             Err(err) => return OpcodeResult::Error(err),
                         };
-                        let index = args[1].as_i32().unwrap_or(0) as usize;
-                        let value = args[2].as_i32().unwrap_or(0) as u8;
                         let buf_ptr = handle as *mut Buffer;
                         if buf_ptr.is_null() {
                             return OpcodeResult::Error(VmError::RuntimeError(
@@ -1201,92 +1413,37 @@ impl<'a> Interpreter<'a> {
                             ));
                         }
                         let buf = unsafe { &mut *buf_ptr };
-                        if let Err(msg) = buf.set_byte(index, value) {
-                            return OpcodeResult::Error(VmError::RuntimeError(msg));
-                        }
-                        if let Err(e) = stack.push(Value::null()) {
-                            return OpcodeResult::Error(e);
-                        }
-                        OpcodeResult::Continue
-                    }
-                    id if id == buffer::GET_INT32 => {
-                        let handle = match self.buffer_handle_from_value(args[0]) {
-                            Ok(h) => h,
-                            Err(err) => return OpcodeResult::Error(err),
+                        let index = match buffer_offset(args[1]) {
+                            Ok(index) => index,
+                            Err(offset) => {
+                                return OpcodeResult::Error(VmError::RuntimeError(format!(
+                                    "Buffer index {} out of bounds for {} (length: {})",
+                                    offset,
+                                    element.name(),
+                                    buf.length()
+                                )));
+                            }
                         };
-                        let index = args[1].as_i32().unwrap_or(0) as usize;
-                        let buf_ptr = handle as *const Buffer;
-                        if buf_ptr.is_null() {
-                            return OpcodeResult::Error(VmError::RuntimeError(
-                                "Invalid buffer handle".to_string(),
-                            ));
-                        }
-                        let buf = unsafe { &*buf_ptr };
-                        let value = buf.get_int32(index).unwrap_or(0);
-                        if let Err(e) = stack.push(Value::i32(value)) {
-                            return OpcodeResult::Error(e);
-                        }
-                        OpcodeResult::Continue
-                    }
-                    id if id == buffer::SET_INT32 => {
-                        let handle = match self.buffer_handle_from_value(args[0]) {
-                            Ok(h) => h,
-                            Err(err) => return OpcodeResult::Error(err),
+                        let result = if is_write {
+                            let value = args[2]
+                                .as_f64()
+                                .or_else(|| args[2].as_i32().map(f64::from))
+                                .or_else(|| args[2].as_i64().map(|v| v as f64))
+                                .unwrap_or(0.0);
+                            buf.write_element(index, element, order, value)
+                                .map(|()| Value::null())
+                        } else {
+                            buf.read_element(index, element, order)
+                                .map(|v| match element {
+                                    BufferElement::Float64 => Value::f64(v),
+                                    _ => Value::i32(v as i32),
+                                })
                         };
-                        let index = args[1].as_i32().unwrap_or(0) as usize;
-                        let value = args[2].as_i32().unwrap_or(0);
-                        let buf_ptr = handle as *mut Buffer;
-                        if buf_ptr.is_null() {
-                            return OpcodeResult::Error(VmError::RuntimeError(
-                                "Invalid buffer handle".to_string(),
-                            ));
-                        }
-                        let buf = unsafe { &mut *buf_ptr };
-                        if let Err(msg) = buf.set_int32(index, value) {
-                            return OpcodeResult::Error(VmError::RuntimeError(msg));
-                        }
-                        if let Err(e) = stack.push(Value::null()) {
-                            return OpcodeResult::Error(e);
-                        }
-                        OpcodeResult::Continue
-                    }
-                    id if id == buffer::GET_FLOAT64 => {
-                        let handle = match self.buffer_handle_from_value(args[0]) {
-                            Ok(h) => h,
-                            Err(err) => return OpcodeResult::Error(err),
+                        let value = match result {
+                            Ok(value) => value,
+                            Err(msg) => return OpcodeResult::Error(VmError::RuntimeError(msg)),
                         };
-                        let index = args[1].as_i32().unwrap_or(0) as usize;
-                        let buf_ptr = handle as *const Buffer;
-                        if buf_ptr.is_null() {
-                            return OpcodeResult::Error(VmError::RuntimeError(
-                                "Invalid buffer handle".to_string(),
-                            ));
-                        }
-                        let buf = unsafe { &*buf_ptr };
-                        let value = buf.get_float64(index).unwrap_or(0.0);
-                        if let Err(e) = stack.push(Value::f64(value)) {
-                            return OpcodeResult::Error(e);
-                        }
-                        OpcodeResult::Continue
-                    }
-                    id if id == buffer::SET_FLOAT64 => {
-                        let handle = match self.buffer_handle_from_value(args[0]) {
-                            Ok(h) => h,
-                            Err(err) => return OpcodeResult::Error(err),
-                        };
-                        let index = args[1].as_i32().unwrap_or(0) as usize;
-                        let value = args[2].as_f64().unwrap_or(0.0);
-                        let buf_ptr = handle as *mut Buffer;
-                        if buf_ptr.is_null() {
-                            return OpcodeResult::Error(VmError::RuntimeError(
-                                "Invalid buffer handle".to_string(),
-                            ));
-                        }
-                        let buf = unsafe { &mut *buf_ptr };
-                        if let Err(msg) = buf.set_float64(index, value) {
-                            return OpcodeResult::Error(VmError::RuntimeError(msg));
-                        }
-                        if let Err(e) = stack.push(Value::null()) {
+                        if let Err(e) = stack.push(value) {
                             return OpcodeResult::Error(e);
                         }
                         OpcodeResult::Continue
@@ -1385,8 +1542,16 @@ impl<'a> Interpreter<'a> {
                             ));
                         }
                         let buf = unsafe { &*buf_ptr };
-                        // encoding argument (args[1]) — currently only utf8/ascii supported
-                        let text = String::from_utf8_lossy(&buf.data).into_owned();
+                        // args[1] = encoding name, args[2] = fatal (throw on invalid input)
+                        let encoding = match encoding_arg(&args, 1) {
+                            Ok(encoding) => encoding,
+                            Err(e) => return OpcodeResult::Error(e),
+                        };
+                        let fatal = args.get(2).and_then(|v| v.as_bool()).unwrap_or(false);
+                        let text = match buf.decode(encoding, fatal) {
+                            Ok(text) => text,
+                            Err(msg) => return OpcodeResult::Error(VmError::RuntimeError(msg)),
+                        };
                         let s = RayaString::new(text);
                         let gc_ptr = self.gc.lock().allocate(s);
                         let val = unsafe {
@@ -1398,26 +1563,19 @@ impl<'a> Interpreter<'a> {
                         OpcodeResult::Continue
                     }
                     id if id == buffer::FROM_STRING => {
-                        // args[0] = string pointer, args[1] = encoding (ignored, utf8)
-                        if !args[0].is_ptr() {
-                            return OpcodeResult::Error(VmError::TypeError(
-                                "Expected string".to_string(),
-                            ));
-                        }
-                        let str_ptr = unsafe { args[0].as_ptr::<RayaString>() };
-                        let s = match str_ptr {
-                            Some(p) => unsafe { &*p.as_ptr() },
-                            None => {
-                                return OpcodeResult::Error(VmError::TypeError(
-                                    "Expected string".to_string(),
-                                ))
-                            }
+                        // args[0] = string pointer, args[1] = encoding name
+                        let text = match value_as_string(args[0]) {
+                            Ok(text) => text,
+                            Err(e) => return OpcodeResult::Error(e),
+                        };
+                        let encoding = match encoding_arg(&args, 1) {
+                            Ok(encoding) => encoding,
+                            Err(e) => return OpcodeResult::Error(e),
                         };
-                        let bytes = s.data.as_bytes();
-                        let mut buf = Buffer::new(bytes.len());
-                        buf.data.copy_from_slice(bytes);
+                        let buf = Buffer::encode(&text, encoding);
+                        let len = buf.length();
                         let new_handle = self.allocate_pinned_handle(buf);
-                        let value = match self.alloc_buffer_object(new_handle, bytes.len()) {
+                        let value = match self.alloc_buffer_object(new_handle, len) {
                             Ok(v) => v,
                             Err(e) => return OpcodeResult::Error(e),
                         };
@@ -1511,7 +1669,21 @@ impl<'a> Interpreter<'a> {
                     }
                     // Map native calls
                     id if id == map::NEW => {
-                        let map = MapObject::new();
+                        let mut map = MapObject::new();
+                        let pairs = match self.collection_initializer(args.first().copied(), "Map")
+                        {
+                            Ok(pairs) => pairs,
+                            Err(err) => return OpcodeResult::Error(err),
+                        };
+                        for pair in pairs {
+                            let entry = match self.collection_initializer(Some(pair), "Map entry") {
+                                Ok(entry) => entry,
+                                Err(err) => return OpcodeResult::Error(err),
+                            };
+                            let key = entry.first().copied().unwrap_or(Value::null());
+                            let value = entry.get(1).copied().unwrap_or(Value::null());
+                            map.set(key, value);
+                        }
                         let handle = self.allocate_pinned_handle(map);
                         if let Err(e) = stack.push(Value::u64(handle)) {
                             return OpcodeResult::Error(e);
@@ -1716,7 +1888,15 @@ impl<'a> Interpreter<'a> {
                     }
                     // Set native calls
                     id if id == set::NEW => {
-                        let set_obj = SetObject::new();
+                        let mut set_obj = SetObject::new();
+                        let values = match self.collection_initializer(args.first().copied(), "Set")
+                        {
+                            Ok(values) => values,
+                            Err(err) => return OpcodeResult::Error(err),
+                        };
+                        for value in values {
+                            set_obj.add(value);
+                        }
                         let handle = self.allocate_pinned_handle(set_obj);
                         if let Err(e) = stack.push(Value::u64(handle)) {
                             return OpcodeResult::Error(e);
@@ -2070,7 +2250,7 @@ impl<'a> Interpreter<'a> {
                     0x0F03u16 => {
                         // PARSE_INT: parse string to integer
                         let result = if let Some(ptr) = unsafe { args[0].as_ptr::<RayaString>() } {
-                            let s = unsafe { &*ptr.as_ptr() }.data.trim();
+                            let s = unsafe { &*ptr.as_ptr() }.as_str().trim();
                             // Parse integer, handling leading whitespace and optional sign
                             s.parse::<i64>()
                                 .map(|v| v as f64)
@@ -2098,7 +2278,7 @@ impl<'a> Interpreter<'a> {
                     0x0F04u16 => {
                         // PARSE_FLOAT: parse string to float
                         let result = if let Some(ptr) = unsafe { args[0].as_ptr::<RayaString>() } {
-                            let s = unsafe { &*ptr.as_ptr() }.data.trim();
+                            let s = unsafe { &*ptr.as_ptr() }.as_str().trim();
                             s.parse::<f64>().unwrap_or(f64::NAN)
                         } else if let Some(n) = args[0].as_f64() {
                             n
@@ -2196,7 +2376,7 @@ impl<'a> Interpreter<'a> {
                             ));
                         }
                         let key = if let Some(ptr) = unsafe { key_val.as_ptr::<RayaString>() } {
-                            unsafe { &*ptr.as_ptr() }.data.clone()
+                            unsafe { &*ptr.as_ptr() }.as_str().to_string()
                         } else {
                             return OpcodeResult::Error(VmError::TypeError(
                                 "Object.defineProperty key must be a string".to_string(),
@@ -2227,7 +2407,7 @@ impl<'a> Interpreter<'a> {
                             return OpcodeResult::Continue;
                         }
                         let key = if let Some(ptr) = unsafe { key_val.as_ptr::<RayaString>() } {
-                            unsafe { &*ptr.as_ptr() }.data.clone()
+                            unsafe { &*ptr.as_ptr() }.as_str().to_string()
                         } else {
                             return OpcodeResult::Error(VmError::TypeError(
                                 "Object.getOwnPropertyDescriptor key must be a string".to_string(),
@@ -2422,7 +2602,7 @@ impl<'a> Interpreter<'a> {
                                 return String::new();
                             }
                             if let Some(ptr) = unsafe { value.as_ptr::<RayaString>() } {
-                                return unsafe { &*ptr.as_ptr() }.data.clone();
+                                return unsafe { &*ptr.as_ptr() }.as_str().to_string();
                             }
                             if let Some(i) = value.as_i32() {
                                 return i.to_string();
@@ -2459,6 +2639,152 @@ impl<'a> Interpreter<'a> {
                         }
                         OpcodeResult::Continue
                     }
+                    // Random native calls
+                    id if id == random::NEW => {
+                        let seed = args[0]
+                            .as_f64()
+                            .or_else(|| args[0].as_i64().map(|v| v as f64))
+                            .or_else(|| args[0].as_i32().map(|v| v as f64))
+                            .unwrap_or(0.0);
+                        let handle = self.allocate_pinned_handle(RandomObject::from_seed(seed));
+                        if let Err(e) = stack.push(Value::u64(handle)) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    id if id == random::NEXT_INT => {
+                        let handle = match self.random_handle_from_value(args[0]) {
+                            Ok(h) => h,
+                            Err(err) => return OpcodeResult::Error(err),
+                        };
+                        let max = args[1]
+                            .as_f64()
+                            .or_else(|| args[1].as_i64().map(|v| v as f64))
+                            .or_else(|| args[1].as_i32().map(|v| v as f64))
+                            .unwrap_or(0.0);
+                        if !(max >= 1.0 && max <= u32::MAX as f64 && max.fract() == 0.0) {
+                            return OpcodeResult::Error(VmError::RuntimeError(format!(
+                                "Random.nextInt: max must be an integer in [1, 2^32), got {}",
+                                max
+                            )));
+                        }
+                        let rng_ptr = handle as *mut RandomObject;
+                        if rng_ptr.is_null() {
+                            return OpcodeResult::Error(VmError::RuntimeError(
+                                "Invalid random handle".to_string(),
+                            ));
+                        }
+                        let rng = unsafe { &mut *rng_ptr };
+                        let n = rng.next_int(max as u32);
+                        let value = match i32::try_from(n) {
+                            Ok(n) => Value::i32(n),
+                            Err(_) => Value::f64(n as f64),
+                        };
+                        if let Err(e) = stack.push(value) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    id if id == random::NEXT_FLOAT => {
+                        let handle = match self.random_handle_from_value(args[0]) {
+                            Ok(h) => h,
+                            Err(err) => return OpcodeResult::Error(err),
+                        };
+                        let rng_ptr = handle as *mut RandomObject;
+                        if rng_ptr.is_null() {
+                            return OpcodeResult::Error(VmError::RuntimeError(
+                                "Invalid random handle".to_string(),
+                            ));
+                        }
+                        let rng = unsafe { &mut *rng_ptr };
+                        if let Err(e) = stack.push(Value::f64(rng.next_float())) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    // WeakRef native calls: args are (owner, target) for NEW
+                    id if id == weak_ref::NEW => {
+                        let handle = self.gc.lock().create_weak_ref(args[0], args[1]);
+                        if let Err(e) = stack.push(Value::u64(handle)) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    id if id == weak_ref::DEREF => {
+                        let handle = match Self::weak_handle_from_value(args[0]) {
+                            Ok(h) => h,
+                            Err(e) => return OpcodeResult::Error(e),
+                        };
+                        let target = self.gc.lock().weak_ref_target(handle);
+                        if let Err(e) = stack.push(target.unwrap_or(Value::null())) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    // WeakMap native calls: args are (owner) for NEW, (handle, key, ...) otherwise
+                    id if id == weak_map::NEW => {
+                        let handle = self.gc.lock().create_weak_map(args[0]);
+                        if let Err(e) = stack.push(Value::u64(handle)) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    id if id == weak_map::GET => {
+                        let handle = match Self::weak_handle_from_value(args[0]) {
+                            Ok(h) => h,
+                            Err(e) => return OpcodeResult::Error(e),
+                        };
+                        let value = self.gc.lock().weak_map_get(handle, args[1]);
+                        let result = value.unwrap_or(Value::null());
+                        if let Err(e) = stack.push(result) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    id if id == weak_map::SET => {
+                        let handle = match Self::weak_handle_from_value(args[0]) {
+                            Ok(h) => h,
+                            Err(e) => return OpcodeResult::Error(e),
+                        };
+                        let key = args[1];
+                        if !key.is_heap_allocated() {
+                            return OpcodeResult::Error(VmError::TypeError(
+                                "Invalid value used as weak map key".to_string(),
+                            ));
+                        }
+                        if !self.gc.lock().weak_map_set(handle, key, args[2]) {
+                            return OpcodeResult::Error(VmError::RuntimeError(
+                                "Invalid weak map handle".to_string(),
+                            ));
+                        }
+                        let result = Value::null();
+                        if let Err(e) = stack.push(result) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    id if id == weak_map::HAS => {
+                        let handle = match Self::weak_handle_from_value(args[0]) {
+                            Ok(h) => h,
+                            Err(e) => return OpcodeResult::Error(e),
+                        };
+                        let result = Value::bool(self.gc.lock().weak_map_has(handle, args[1]));
+                        if let Err(e) = stack.push(result) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
+                    id if id == weak_map::DELETE => {
+                        let handle = match Self::weak_handle_from_value(args[0]) {
+                            Ok(h) => h,
+                            Err(e) => return OpcodeResult::Error(e),
+                        };
+                        let result = Value::bool(self.gc.lock().weak_map_delete(handle, args[1]));
+                        if let Err(e) = stack.push(result) {
+                            return OpcodeResult::Error(e);
+                        }
+                        OpcodeResult::Continue
+                    }
                     // Date native calls
                     id if id == date::NOW => {
                         use std::time::{SystemTime, UNIX_EPOCH};
@@ -2733,7 +3059,7 @@ impl<'a> Interpreter<'a> {
                     id if id == date::PARSE => {
                         let input = if !args.is_empty() && args[0].is_ptr() {
                             if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2753,7 +3079,7 @@ impl<'a> Interpreter<'a> {
                     id if id == regexp::NEW => {
                         let pattern = if args[0].is_ptr() {
                             if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2762,7 +3088,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let flags = if args.len() > 1 && args[1].is_ptr() {
                             if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2790,7 +3116,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let input = if args[1].is_ptr() {
                             if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2816,7 +3142,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let input = if args[1].is_ptr() {
                             if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2877,7 +3203,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let input = if args[1].is_ptr() {
                             if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2936,7 +3262,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let input = if args[1].is_ptr() {
                             if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2945,7 +3271,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let replacement = if args[2].is_ptr() {
                             if let Some(s) = unsafe { args[2].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -2977,7 +3303,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let input = if args[1].is_ptr() {
                             if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -3033,7 +3359,7 @@ impl<'a> Interpreter<'a> {
                         };
                         let input = if args[1].is_ptr() {
                             if let Some(s) = unsafe { args[1].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 String::new()
                             }
@@ -3143,7 +3469,7 @@ impl<'a> Interpreter<'a> {
                         }
                         let json_str = if args[0].is_ptr() {
                             if let Some(s) = unsafe { args[0].as_ptr::<RayaString>() } {
-                                unsafe { &*s.as_ptr() }.data.clone()
+                                unsafe { &*s.as_ptr() }.as_str().to_string()
                             } else {
                                 return OpcodeResult::Error(VmError::TypeError(
                                     "JSON.parse requires a string argument".to_string(),
@@ -3234,7 +3560,9 @@ impl<'a> Interpreter<'a> {
             }
 
             Opcode::ModuleNativeCall => {
-                use crate::vm::abi::{native_to_value, value_to_native, EngineContext};
+                use crate::vm::abi::{
+                    native_to_value, value_to_native, EngineContext, NativeHeapScope,
+                };
                 use raya_sdk::NativeCallResult;
 
                 let local_idx = match Self::read_u16(code, ip) {
@@ -3256,14 +3584,35 @@ impl<'a> Interpreter<'a> {
                 }
                 args.reverse();
 
+                if self.native_policy.is_some() {
+                    let name = module
+                        .native_functions
+                        .get(local_idx as usize)
+                        .map(String::as_str)
+                        .unwrap_or("<unknown>");
+                    if let Err(e) = self.check_native_policy(None, name, &args) {
+                        return OpcodeResult::Error(e);
+                    }
+                }
+
                 // Create EngineContext for handler
-                let ctx = EngineContext::new(
+                let mut ctx = EngineContext::new(
                     self.gc,
                     self.classes,
                     self.layouts,
                     task.id(),
                     self.class_metadata,
                 );
+                if let Some(limits) = self.handle_limits {
+                    ctx = ctx.with_handle_limits(limits);
+                }
+                ctx = ctx.with_deadline(self.deadline);
+                if let Some(program_args) = self.program_args {
+                    ctx = ctx.with_program_args(program_args);
+                }
+                if let Some(exit_hooks) = self.exit_hooks {
+                    ctx = ctx.with_exit_hooks(exit_hooks);
+                }
 
                 // Convert arguments to NativeValue (zero-cost)
                 let native_args: Vec<raya_sdk::NativeValue> =
@@ -3271,6 +3620,7 @@ impl<'a> Interpreter<'a> {
 
                 // Dispatch via module-local resolved native table.
                 let resolved = self.module_resolved_natives(module);
+                let _heap = NativeHeapScope::enter(self.gc);
                 match resolved.call(local_idx, &ctx, &native_args) {
                     NativeCallResult::Value(val) => {
                         if let Err(e) = stack.push(native_to_value(val)) {
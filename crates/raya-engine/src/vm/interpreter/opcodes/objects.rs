@@ -3,12 +3,12 @@
 
 use crate::compiler::Module;
 use crate::compiler::Opcode;
-use crate::vm::gc::header_ptr_from_value_ptr;
+use crate::vm::gc::{header_ptr_from_value_ptr, write_barrier};
 use crate::vm::interpreter::execution::{OpcodeResult, ReturnAction};
 use crate::vm::interpreter::shared_state::{
     ShapeAdapter, StructuralAdapterKey, StructuralSlotBinding,
 };
-use crate::vm::interpreter::Interpreter;
+use crate::vm::interpreter::{FieldSiteId, Interpreter};
 use crate::vm::object::{Array, BoundMethod, Closure, Object, RayaString};
 use crate::vm::stack::Stack;
 use crate::vm::value::Value;
@@ -396,6 +396,51 @@ impl<'a> Interpreter<'a> {
         )
     }
 
+    /// `remap_shape_slot_binding` behind the inline cache of access site `site`.
+    ///
+    /// Only field bindings backed by a shape adapter are cached; the
+    /// identity fallback used when no adapter can be built is not.
+    #[inline]
+    fn cached_shape_slot_binding(
+        &mut self,
+        site: FieldSiteId,
+        obj: &Object,
+        required_shape: crate::vm::object::ShapeId,
+        expected_slot: usize,
+    ) -> StructuralSlotBinding {
+        let layout_id = obj.layout_id();
+        if let Some(slot) =
+            self.field_inline_cache
+                .lookup(site, layout_id, required_shape, expected_slot)
+        {
+            return StructuralSlotBinding::Field(slot);
+        }
+        let adapter_key = StructuralAdapterKey {
+            provider_layout: layout_id,
+            required_shape,
+        };
+        let cached = self
+            .structural_shape_adapters
+            .read()
+            .get(&adapter_key)
+            .cloned();
+        let adapter = cached.or_else(|| self.ensure_shape_adapter_for_object(obj, required_shape));
+        let Some(adapter) = adapter else {
+            return StructuralSlotBinding::Field(expected_slot);
+        };
+        let binding = adapter.binding_for_slot(expected_slot);
+        if let StructuralSlotBinding::Field(slot) = binding {
+            self.field_inline_cache
+                .record(site, layout_id, required_shape, expected_slot, slot);
+        }
+        binding
+    }
+
+    /// Whether `obj_val` may carry property descriptors (accessors or writability).
+    fn may_have_property_descriptors(&self, obj_val: Value) -> bool {
+        self.metadata.lock().has_any_property_metadata(obj_val)
+    }
+
     fn get_value_field_by_name(&self, obj_val: Value, field_name: &str) -> Option<Value> {
         let index = self.field_index_for_value(obj_val, field_name)?;
         let obj_ptr = unsafe { obj_val.as_ptr::<Object>() }?;
@@ -624,6 +669,7 @@ impl<'a> Interpreter<'a> {
             }
 
             Opcode::LoadFieldShape => {
+                let site = code.as_ptr() as FieldSiteId + *ip;
                 let shape_id = match Self::read_u64(code, ip) {
                     Ok(v) => v,
                     Err(e) => return OpcodeResult::Error(e),
@@ -657,7 +703,8 @@ impl<'a> Interpreter<'a> {
                     crate::aot_profile::AotSiteKind::LoadFieldShape,
                     obj.layout_id(),
                 );
-                let slot_binding = self.remap_shape_slot_binding(obj, shape_id, field_offset);
+                let slot_binding =
+                    self.cached_shape_slot_binding(site, obj, shape_id, field_offset);
                 if let StructuralSlotBinding::Missing = slot_binding {
                     if let Err(e) = stack.push(Value::null()) {
                         return OpcodeResult::Error(e);
@@ -692,7 +739,13 @@ impl<'a> Interpreter<'a> {
                         unreachable!()
                     }
                 };
-                if let Some(field_name) = self.field_name_for_offset(obj, field_offset) {
+                // Objects without property metadata have no accessors to honor.
+                let descriptor_field = if self.may_have_property_descriptors(actual_obj) {
+                    self.field_name_for_offset(obj, field_offset)
+                } else {
+                    None
+                };
+                if let Some(field_name) = descriptor_field {
                     if let Some(getter) = self.descriptor_accessor(actual_obj, &field_name, "get") {
                         match self.callable_frame_for_value(
                             getter,
@@ -808,6 +861,7 @@ impl<'a> Interpreter<'a> {
             }
 
             Opcode::StoreFieldShape => {
+                let site = code.as_ptr() as FieldSiteId + *ip;
                 let shape_id = match Self::read_u64(code, ip) {
                     Ok(v) => v,
                     Err(e) => return OpcodeResult::Error(e),
@@ -837,11 +891,19 @@ impl<'a> Interpreter<'a> {
                     crate::aot_profile::AotSiteKind::StoreFieldShape,
                     obj.layout_id(),
                 );
-                let slot_binding = self.remap_shape_slot_binding(obj, shape_id, field_offset);
+                let slot_binding =
+                    self.cached_shape_slot_binding(site, obj, shape_id, field_offset);
                 let field_offset = match slot_binding {
                     StructuralSlotBinding::Field(offset) => offset,
+                    StructuralSlotBinding::Dynamic(key) if obj.is_frozen() => {
+                        return OpcodeResult::Error(VmError::TypeError(format!(
+                            "Cannot assign to property '{}' of frozen object",
+                            self.prop_key_name(key).unwrap_or_default()
+                        )));
+                    }
                     StructuralSlotBinding::Dynamic(key) => {
                         obj.ensure_dyn_map().insert(key, value);
+                        write_barrier(value);
                         return OpcodeResult::Continue;
                     }
                     StructuralSlotBinding::Method(_) => {
@@ -855,8 +917,14 @@ impl<'a> Interpreter<'a> {
                         ));
                     }
                 };
-                if let Some(field_name) = self.field_name_for_offset(obj, field_offset) {
-                    if let Some(setter) = self.descriptor_accessor(actual_obj, &field_name, "set") {
+                // Objects without property metadata have no descriptors to honor.
+                let descriptor_field = if self.may_have_property_descriptors(actual_obj) {
+                    self.field_name_for_offset(obj, field_offset)
+                } else {
+                    None
+                };
+                if let Some(field_name) = descriptor_field.as_deref() {
+                    if let Some(setter) = self.descriptor_accessor(actual_obj, field_name, "set") {
                         match self.callable_frame_for_value(
                             setter,
                             stack,
@@ -874,16 +942,16 @@ impl<'a> Interpreter<'a> {
                         }
                     }
                     if self
-                        .descriptor_accessor(actual_obj, &field_name, "get")
+                        .descriptor_accessor(actual_obj, field_name, "get")
                         .is_some()
-                        && !self.is_field_writable(actual_obj, &field_name)
+                        && !self.is_field_writable(actual_obj, field_name)
                     {
                         return OpcodeResult::Error(VmError::TypeError(format!(
                             "Cannot set property '{}' which has only a getter",
                             field_name
                         )));
                     }
-                    if !self.is_field_writable(actual_obj, &field_name) {
+                    if !self.is_field_writable(actual_obj, field_name) {
                         return OpcodeResult::Error(VmError::TypeError(format!(
                             "Cannot assign to non-writable property '{}'",
                             field_name
@@ -893,8 +961,8 @@ impl<'a> Interpreter<'a> {
                 if let Err(e) = obj.set_field(field_offset, value) {
                     return OpcodeResult::Error(VmError::RuntimeError(e));
                 }
-                if let Some(field_name) = self.field_name_for_offset(obj, field_offset) {
-                    self.sync_descriptor_value(actual_obj, &field_name, value);
+                if let Some(field_name) = descriptor_field.as_deref() {
+                    self.sync_descriptor_value(actual_obj, field_name, value);
                 }
                 OpcodeResult::Continue
             }
@@ -960,6 +1028,7 @@ impl<'a> Interpreter<'a> {
             }
 
             Opcode::OptionalFieldShape => {
+                let site = code.as_ptr() as FieldSiteId + *ip;
                 let shape_id = match Self::read_u64(code, ip) {
                     Ok(v) => v,
                     Err(e) => return OpcodeResult::Error(e),
@@ -997,7 +1066,8 @@ impl<'a> Interpreter<'a> {
                 let actual_obj = crate::vm::reflect::unwrap_proxy_target(obj_val);
                 let obj_ptr = unsafe { actual_obj.as_ptr::<Object>() };
                 let obj = unsafe { &*obj_ptr.unwrap().as_ptr() };
-                let slot_binding = self.remap_shape_slot_binding(obj, shape_id, field_offset);
+                let slot_binding =
+                    self.cached_shape_slot_binding(site, obj, shape_id, field_offset);
                 if let StructuralSlotBinding::Missing = slot_binding {
                     if let Err(e) = stack.push(Value::null()) {
                         return OpcodeResult::Error(e);
@@ -7,6 +7,7 @@ use crate::vm::object::RayaString;
 use crate::vm::stack::Stack;
 use crate::vm::value::Value;
 use crate::vm::VmError;
+use std::borrow::Cow;
 
 impl<'a> Interpreter<'a> {
     pub(in crate::vm::interpreter) fn exec_string_ops(
@@ -49,7 +50,7 @@ impl<'a> Interpreter<'a> {
                         // Check if it's already a string
                         let ptr = unsafe { val.as_ptr::<RayaString>() };
                         if let Some(str_ptr) = ptr {
-                            unsafe { &*str_ptr.as_ptr() }.data.clone()
+                            unsafe { &*str_ptr.as_ptr() }.as_str().to_string()
                         } else {
                             "[object]".to_string()
                         }
@@ -58,29 +59,30 @@ impl<'a> Interpreter<'a> {
                     }
                 };
 
-                let a_str = if a_val.is_ptr() {
+                // Borrow string operands so the result is built in one step.
+                let a_str: Cow<'_, str> = if a_val.is_ptr() {
                     let ptr = unsafe { a_val.as_ptr::<RayaString>() };
                     if let Some(str_ptr) = ptr {
-                        unsafe { &*str_ptr.as_ptr() }.data.clone()
+                        Cow::Borrowed(unsafe { &*str_ptr.as_ptr() }.as_str())
                     } else {
-                        "[object]".to_string()
+                        Cow::Borrowed("[object]")
                     }
                 } else {
-                    value_to_string(&a_val)
+                    Cow::Owned(value_to_string(&a_val))
                 };
 
-                let b_str = if b_val.is_ptr() {
+                let b_str: Cow<'_, str> = if b_val.is_ptr() {
                     let ptr = unsafe { b_val.as_ptr::<RayaString>() };
                     if let Some(str_ptr) = ptr {
-                        unsafe { &*str_ptr.as_ptr() }.data.clone()
+                        Cow::Borrowed(unsafe { &*str_ptr.as_ptr() }.as_str())
                     } else {
-                        "[object]".to_string()
+                        Cow::Borrowed("[object]")
                     }
                 } else {
-                    value_to_string(&b_val)
+                    Cow::Owned(value_to_string(&b_val))
                 };
 
-                let result = RayaString::new(format!("{}{}", a_str, b_str));
+                let result = RayaString::from_concat(&a_str, &b_str);
                 let gc_ptr = self.gc.lock().allocate(result);
                 let value =
                     unsafe { Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap()) };
@@ -123,7 +125,7 @@ impl<'a> Interpreter<'a> {
                     let b_ptr = unsafe { b_val.as_ptr::<RayaString>() };
                     let a = unsafe { &*a_ptr.unwrap().as_ptr() };
                     let b = unsafe { &*b_ptr.unwrap().as_ptr() };
-                    a.data == b.data
+                    a.content_eq(b)
                 } else {
                     false
                 };
@@ -148,7 +150,7 @@ impl<'a> Interpreter<'a> {
                     let b_ptr = unsafe { b_val.as_ptr::<RayaString>() };
                     let a = unsafe { &*a_ptr.unwrap().as_ptr() };
                     let b = unsafe { &*b_ptr.unwrap().as_ptr() };
-                    a.data != b.data
+                    !a.content_eq(b)
                 } else {
                     true
                 };
@@ -174,10 +176,10 @@ impl<'a> Interpreter<'a> {
                     let a = unsafe { &*a_ptr.unwrap().as_ptr() };
                     let b = unsafe { &*b_ptr.unwrap().as_ptr() };
                     match opcode {
-                        Opcode::Slt => a.data < b.data,
-                        Opcode::Sle => a.data <= b.data,
-                        Opcode::Sgt => a.data > b.data,
-                        Opcode::Sge => a.data >= b.data,
+                        Opcode::Slt => a.as_str() < b.as_str(),
+                        Opcode::Sle => a.as_str() <= b.as_str(),
+                        Opcode::Sgt => a.as_str() > b.as_str(),
+                        Opcode::Sge => a.as_str() >= b.as_str(),
                         _ => unreachable!(),
                     }
                 } else {
@@ -215,7 +217,7 @@ impl<'a> Interpreter<'a> {
                 } else if val.is_ptr() {
                     // Check if it's already a string
                     if let Some(ptr) = unsafe { val.as_ptr::<RayaString>() } {
-                        unsafe { &*ptr.as_ptr() }.data.clone()
+                        unsafe { &*ptr.as_ptr() }.as_str().to_string()
                     } else {
                         "[object]".to_string()
                     }
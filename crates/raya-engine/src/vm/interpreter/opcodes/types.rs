@@ -6,7 +6,7 @@ use crate::compiler::type_registry::TypeRegistry;
 use crate::compiler::{Module, Opcode};
 use crate::parser::TypeContext;
 use crate::vm::builtin::{array, channel, map, regexp, set, string};
-use crate::vm::gc::header_ptr_from_value_ptr;
+use crate::vm::gc::{header_ptr_from_value_ptr, write_barrier};
 use crate::vm::interpreter::execution::OpcodeResult;
 use crate::vm::interpreter::{Interpreter, ReturnAction};
 use crate::vm::object::{
@@ -222,7 +222,7 @@ fn dyn_key_parts(key_val: Value) -> Result<(Option<String>, Option<usize>), VmEr
 
     match js_classify(key_val) {
         JSView::Str(ptr) => {
-            let key = unsafe { &*ptr }.data.clone();
+            let key = unsafe { &*ptr }.as_str().to_string();
             let index = key.parse::<usize>().ok();
             Ok((Some(key), index))
         }
@@ -478,6 +478,43 @@ impl<'a> Interpreter<'a> {
             .native_method_id_for_type_name(class_name, method_name)
     }
 
+    /// Decode a structural object (e.g. a `JSON.parse` result) into an
+    /// instance of a `//@@json` class, validating it against the class's
+    /// decode schema. `None` if the value is not a structural object or the
+    /// class has no schema.
+    fn decode_json_class_cast(
+        &self,
+        value: Value,
+        nominal_type_id: usize,
+    ) -> Option<Result<Value, VmError>> {
+        let obj_ptr = object_ptr_checked(value)?;
+        let obj = unsafe { &*obj_ptr.as_ptr() };
+        if obj.nominal_type_id_usize().is_some() {
+            return None;
+        }
+        let registry = self
+            .module_layouts
+            .read()
+            .values()
+            .find(|layout| {
+                (layout.nominal_type_base..layout.nominal_type_base + layout.nominal_type_len)
+                    .contains(&nominal_type_id)
+            })?
+            .json_schemas
+            .clone()?;
+        let schema = registry.class_schema(nominal_type_id)?;
+        let json = crate::vm::json::value_to_json_stack(value);
+        let mut gc = self.gc.lock();
+        Some(crate::vm::json::validate_cast_with_runtime_metadata(
+            &json,
+            &schema,
+            &registry,
+            &mut gc,
+            &mut |name| Some(self.intern_prop_key(name)),
+            &mut |layout_id| self.structural_layout_names(layout_id),
+        ))
+    }
+
     fn exec_nominal_cast(
         &self,
         stack: &mut Stack,
@@ -525,6 +562,13 @@ impl<'a> Interpreter<'a> {
             return OpcodeResult::Continue;
         }
 
+        if let Some(decoded) = self.decode_json_class_cast(obj_val, target_nominal_type_id) {
+            return match decoded.and_then(|value| stack.push(value)) {
+                Ok(()) => OpcodeResult::Continue,
+                Err(error) => OpcodeResult::Error(error),
+            };
+        }
+
         let target_name = {
             let classes = self.classes.read();
             classes
@@ -731,7 +775,7 @@ impl<'a> Interpreter<'a> {
                     JSView::Str(ptr) => {
                         let s = unsafe { &*ptr };
                         if let Some(index) = array_index {
-                            if let Some(ch) = s.data.chars().nth(index) {
+                            if let Some(ch) = s.as_str().chars().nth(index) {
                                 let gc_ptr = self.gc.lock().allocate(RayaString::new(ch.to_string()));
                                 unsafe {
                                     Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap())
@@ -913,6 +957,7 @@ impl<'a> Interpreter<'a> {
                             arr.elements.resize(index + 1, Value::null());
                         }
                         arr.elements[index] = value;
+                        write_barrier(value);
                     }
                     JSView::Struct { ptr, .. } => {
                         let actual_obj = crate::vm::reflect::unwrap_proxy_target(obj_val);
@@ -957,12 +1002,19 @@ impl<'a> Interpreter<'a> {
                                 key_str
                             )));
                         }
+                        if obj.is_frozen() {
+                            return OpcodeResult::Error(VmError::TypeError(format!(
+                                "Cannot assign to property '{}' of frozen object",
+                                key_str
+                            )));
+                        }
                         if let Some(index) = field_index {
                             let _ = obj.set_field(index, value);
                             self.sync_descriptor_value(actual_obj, &key_str, value);
                         } else {
                             let key = self.intern_prop_key(&key_str);
                             obj.ensure_dyn_map().insert(key, value);
+                            write_barrier(value);
                             self.sync_descriptor_value(actual_obj, &key_str, value);
                         }
                     }
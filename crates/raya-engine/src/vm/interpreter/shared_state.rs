@@ -7,20 +7,24 @@ use crate::compiler::Module;
 use crate::compiler::Opcode;
 use crate::vm::gc::GarbageCollector;
 use crate::vm::interpreter::{
-    ClassRegistry, ModuleRegistry, RuntimeLayoutRegistry, SafepointCoordinator,
+    ClassRegistry, FieldInlineCachePool, HandleLimits, ModuleRegistry, RuntimeLayoutRegistry,
+    SafepointCoordinator,
 };
+use crate::vm::json::TypeSchemaRegistry;
 use crate::vm::native_handler::{NativeHandler, NoopNativeHandler};
 use crate::vm::native_registry::{NativeFunctionRegistry, ResolvedNatives};
 use crate::vm::reflect::{ClassMetadata, ClassMetadataRegistry, MetadataStore};
 use crate::vm::scheduler::{IoSubmission, StackPool, Task, TaskId};
 use crate::vm::sync::{MutexRegistry, SemaphoreRegistry};
+use crate::vm::object::RayaString;
 use crate::vm::value::Value;
 use crossbeam::channel::Sender;
 use crossbeam_deque::Injector;
 use parking_lot::{Mutex, RwLock};
 use rustc_hash::{FxHashMap, FxHashSet};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 /// Promise-related microtasks processed by scheduler checkpoints.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,6 +53,23 @@ pub struct ModuleRuntimeLayout {
     pub resolved_natives: ResolvedNatives,
     /// Whether module-level init has been executed in this VM.
     pub initialized: bool,
+    /// Module-level init is deferred until the first call into the module.
+    pub lazy_init: bool,
+    /// Task running the deferred init, while it is in progress.
+    pub init_owner: Option<TaskId>,
+    /// Tasks that called into the module while another task was initializing it.
+    pub init_waiters: Vec<TaskId>,
+    /// Decode schemas of the module's `//@@json` types (class IDs rebased).
+    pub json_schemas: Option<Arc<TypeSchemaRegistry>>,
+}
+
+impl ModuleRuntimeLayout {
+    /// Mark the deferred init as done, returning the tasks waiting on it.
+    pub fn finish_init(&mut self) -> Vec<TaskId> {
+        self.initialized = true;
+        self.init_owner = None;
+        std::mem::take(&mut self.init_waiters)
+    }
 }
 
 /// Structural slot binding for cross-type field/method access.
@@ -194,6 +215,105 @@ impl PropertyKeyRegistry {
     }
 }
 
+/// VM-local interned string literals.
+///
+/// Each distinct literal content maps to one canonical `RayaString` carrying a
+/// unique intern id, so equality between two literals is an integer compare.
+/// Lookups by `(module checksum, constant index)` keep `ConstStr` from hashing
+/// the content on every execution.
+#[derive(Debug)]
+pub struct ConstantStringCache {
+    by_slot: FxHashMap<([u8; 32], usize), Value>,
+    by_content: FxHashMap<String, Value>,
+    next_id: AtomicU32,
+}
+
+impl Default for ConstantStringCache {
+    fn default() -> Self {
+        Self {
+            by_slot: FxHashMap::default(),
+            by_content: FxHashMap::default(),
+            next_id: AtomicU32::new(1),
+        }
+    }
+}
+
+impl ConstantStringCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Canonical string for a constant slot that has already been interned.
+    #[inline]
+    pub fn get(&self, module_checksum: [u8; 32], index: usize) -> Option<Value> {
+        self.by_slot.get(&(module_checksum, index)).copied()
+    }
+
+    /// Every canonical string (each is a GC root).
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.by_content.values()
+    }
+
+    pub fn clear(&mut self) {
+        self.by_slot.clear();
+        self.by_content.clear();
+    }
+
+    /// Resolve constant `index` of a module to its canonical interned string,
+    /// allocating it on first use of that content.
+    ///
+    /// Allocation happens outside the cache lock (collection reads the cache
+    /// for roots); the fresh string stays ephemerally rooted until published.
+    pub fn intern(
+        cache: &RwLock<Self>,
+        gc: &Mutex<GarbageCollector>,
+        ephemeral_gc_roots: &RwLock<Vec<Value>>,
+        module_checksum: [u8; 32],
+        index: usize,
+        content: &str,
+    ) -> Value {
+        let key = (module_checksum, index);
+        let existing = {
+            let cache = cache.read();
+            if let Some(&value) = cache.by_slot.get(&key) {
+                return value;
+            }
+            cache.by_content.get(content).copied()
+        };
+        if let Some(value) = existing {
+            cache.write().by_slot.insert(key, value);
+            return value;
+        }
+        let id = cache.read().next_id.fetch_add(1, Ordering::Relaxed);
+
+        let interned = {
+            let mut gc = gc.lock();
+            let gc_ptr = gc.allocate(RayaString::interned(content.to_owned(), id));
+            let value =
+                unsafe { Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap()) };
+            ephemeral_gc_roots.write().push(value);
+            value
+        };
+        let published = {
+            let mut cache = cache.write();
+            let published = *cache
+                .by_content
+                .entry(content.to_owned())
+                .or_insert(interned);
+            cache.by_slot.insert(key, published);
+            published
+        };
+        let mut ephemeral = ephemeral_gc_roots.write();
+        if let Some(position) = ephemeral
+            .iter()
+            .rposition(|candidate| *candidate == interned)
+        {
+            ephemeral.swap_remove(position);
+        }
+        published
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct TypeHandleKey {
     nominal_type_id: NominalTypeId,
@@ -339,8 +459,8 @@ pub struct SharedVmState {
     /// Maps builtin name -> global slot index.
     pub builtin_global_slots: RwLock<FxHashMap<String, usize>>,
 
-    /// VM-local interned string constants keyed by module checksum and constant index.
-    pub constant_string_cache: RwLock<FxHashMap<([u8; 32], usize), Value>>,
+    /// VM-local interned string constants, shared by every module.
+    pub constant_string_cache: RwLock<ConstantStringCache>,
 
     /// Freshly allocated values rooted only until they are published into a
     /// stable root set such as task state or a shared cache.
@@ -380,6 +500,9 @@ pub struct SharedVmState {
     /// Stack pool for reusing Stack allocations across task lifetimes
     pub stack_pool: StackPool,
 
+    /// Structural field inline caches, lent to each interpreter for one task slice
+    pub field_inline_caches: FieldInlineCachePool,
+
     /// IO submission sender (set by reactor on start, used by Interpreter for NativeCallResult::Suspend)
     pub io_submit_tx: Mutex<Option<Sender<IoSubmission>>>,
 
@@ -434,10 +557,40 @@ pub struct SharedVmState {
     /// Default: 10ms.
     pub preempt_threshold_ms: u64,
 
+    /// Open file/socket limits and counters enforced on native calls.
+    pub handle_limits: HandleLimits,
+
+    /// Deadline of the VM's execution timeout (None = no timeout).
+    /// Exposed to native calls so blocking host waits can be bounded by it.
+    pub execution_deadline: Mutex<Option<Instant>>,
+
+    /// Host-owned flag that cancels the running execution once set
+    /// (None = not cancellable).
+    pub cancel_flag: Mutex<Option<Arc<AtomicBool>>>,
+
+    /// Arguments passed to the running program, exposed via `process.argv()`.
+    pub program_args: RwLock<Vec<String>>,
+
+    /// Callbacks registered via `process.onExit()`, in registration order.
+    /// Kept rooted until `Vm::run_exit_hooks` drains them.
+    pub exit_hooks: Mutex<Vec<Value>>,
+
+    /// Policy consulted before every native call (None = all natives allowed).
+    /// Set by `Vm::set_native_policy()`, cloned by worker threads.
+    pub native_policy: Mutex<Option<crate::vm::NativeCallPolicy>>,
+
+    /// Canonicalize NaN results of float arithmetic, as the JIT does.
+    /// Set by `Vm::set_deterministic_float()`, read by worker threads.
+    pub deterministic_float: AtomicBool,
+
     /// CPU/wall-clock profiler — shared with interpreter threads for sampling.
     /// Set by `Vm::enable_profiling()`, cloned by worker threads.
     pub profiler: Mutex<Option<Arc<crate::profiler::Profiler>>>,
 
+    /// Line/branch coverage recorder — shared with interpreter threads.
+    /// Set by `Vm::enable_coverage()`, cloned by worker threads.
+    pub coverage: Mutex<Option<Arc<crate::coverage::CoverageRecorder>>>,
+
     /// Offline AOT profile collector populated from interpreter execution.
     pub aot_profile: RwLock<crate::aot_profile::AotProfileCollector>,
 
@@ -490,7 +643,7 @@ impl SharedVmState {
             globals: RwLock::new(FxHashMap::default()),
             globals_by_index: RwLock::new(Vec::new()),
             builtin_global_slots: RwLock::new(FxHashMap::default()),
-            constant_string_cache: RwLock::new(FxHashMap::default()),
+            constant_string_cache: RwLock::new(ConstantStringCache::new()),
             ephemeral_gc_roots: RwLock::new(Vec::new()),
             pinned_handles: RwLock::new(FxHashSet::default()),
             safepoint,
@@ -502,6 +655,7 @@ impl SharedVmState {
             mutex_registry: MutexRegistry::new(),
             semaphore_registry: SemaphoreRegistry::new(),
             stack_pool: StackPool::new(num_cpus::get() * 2),
+            field_inline_caches: FieldInlineCachePool::new(),
             io_submit_tx: Mutex::new(None),
             metadata: Mutex::new(MetadataStore::new()),
             class_metadata: RwLock::new(ClassMetadataRegistry::new()),
@@ -518,7 +672,15 @@ impl SharedVmState {
             debug_state: Mutex::new(None),
             max_preemptions: crate::vm::defaults::DEFAULT_MAX_PREEMPTIONS,
             preempt_threshold_ms: crate::vm::defaults::DEFAULT_PREEMPT_THRESHOLD_MS,
+            handle_limits: HandleLimits::default(),
+            execution_deadline: Mutex::new(None),
+            cancel_flag: Mutex::new(None),
+            program_args: RwLock::new(Vec::new()),
+            exit_hooks: Mutex::new(Vec::new()),
+            native_policy: Mutex::new(None),
+            deterministic_float: AtomicBool::new(false),
             profiler: Mutex::new(None),
+            coverage: Mutex::new(None),
             aot_profile: RwLock::new(crate::aot_profile::AotProfileCollector::default()),
             #[cfg(feature = "jit")]
             code_cache: Mutex::new(None),
@@ -567,6 +729,11 @@ impl SharedVmState {
             roots.extend(ephemeral.iter().copied().filter(|value| value.is_heap_allocated()));
         }
 
+        {
+            let hooks = self.exit_hooks.lock();
+            roots.extend(hooks.iter().copied().filter(|value| value.is_heap_allocated()));
+        }
+
         {
             let tasks = self.tasks.read();
             for task in tasks.values() {
@@ -581,21 +748,21 @@ impl SharedVmState {
 
     /// Intern a bytecode string constant once per VM and keep it rooted in shared state.
     pub fn intern_constant_string(&self, module: &Module, index: usize, value: &str) -> Value {
-        let key = (module.checksum, index);
-        let cached = {
-            let cache = self.constant_string_cache.read();
-            cache.get(&key).copied()
-        };
-        if let Some(cached) = cached {
-            return cached;
-        }
-
-        let interned = self.allocate_ephemerally_rooted_string(value.to_owned());
+        ConstantStringCache::intern(
+            &self.constant_string_cache,
+            &self.gc,
+            &self.ephemeral_gc_roots,
+            module.checksum,
+            index,
+            value,
+        )
+    }
 
-        let mut cache = self.constant_string_cache.write();
-        let published = *cache.entry(key).or_insert(interned);
-        self.release_ephemeral_gc_root(interned);
-        published
+    /// Intern every string literal in `module`'s constant pool.
+    pub fn intern_module_strings(&self, module: &Module) {
+        for (index, value) in module.constants.strings.iter().enumerate() {
+            self.intern_constant_string(module, index, value);
+        }
     }
 
     pub fn allocate_ephemerally_rooted_string(&self, value: String) -> Value {
@@ -1015,6 +1182,51 @@ impl SharedVmState {
         }
     }
 
+    /// Defer module top-level init until the first call into one of its functions.
+    ///
+    /// The interpreter runs the module's entry `main` on top of the callee frame
+    /// the first time a call crosses into the module.
+    pub fn mark_module_lazy(&self, module: &Module) {
+        if let Some(layout) = self.module_layouts.write().get_mut(&module.checksum) {
+            if !layout.initialized {
+                layout.lazy_init = true;
+            }
+        }
+    }
+
+    /// Check whether module top-level init is still waiting for first use.
+    pub fn is_module_init_pending(&self, module: &Module) -> bool {
+        self.module_layouts
+            .read()
+            .get(&module.checksum)
+            .map(|layout| layout.lazy_init)
+            .unwrap_or(false)
+    }
+
+    /// Finish every deferred module init owned by a task that failed.
+    ///
+    /// An uncaught exception out of a module's `main` never returns through the
+    /// init frame, so tasks waiting on the module are woken here instead.
+    pub fn abandon_module_inits(&self, task_id: TaskId) {
+        let waiters: Vec<TaskId> = self
+            .module_layouts
+            .write()
+            .values_mut()
+            .filter(|layout| layout.init_owner == Some(task_id))
+            .flat_map(|layout| layout.finish_init())
+            .collect();
+        if waiters.is_empty() {
+            return;
+        }
+        let tasks = self.tasks.read();
+        for waiter in waiters.iter().filter_map(|id| tasks.get(id)) {
+            if waiter.resume_if_pending() {
+                waiter.clear_suspend_reason();
+                self.injector.push(waiter.clone());
+            }
+        }
+    }
+
     /// Check whether module top-level init has executed.
     pub fn is_module_initialized(&self, module: &Module) -> bool {
         self.module_layouts
@@ -1024,6 +1236,36 @@ impl SharedVmState {
             .unwrap_or(false)
     }
 
+    /// Drop all per-run state while keeping registered modules and their layouts.
+    ///
+    /// Global slots keep their assigned indices (and the backing vector its
+    /// capacity) but are reset to null. With every root gone, a final collection
+    /// frees the whole heap. Callers must ensure no task is running.
+    pub fn reset_run_state(&self) {
+        self.tasks.write().clear();
+        while !self.injector.steal().is_empty() {}
+        self.promise_microtasks.lock().clear();
+
+        self.globals.write().clear();
+        self.globals_by_index.write().fill(Value::null());
+        self.constant_string_cache.write().clear();
+        self.ephemeral_gc_roots.write().clear();
+        self.pinned_handles.write().clear();
+
+        self.mutex_registry.clear();
+        self.semaphore_registry.clear();
+        *self.metadata.lock() = MetadataStore::new();
+
+        for layout in self.module_layouts.write().values_mut() {
+            layout.initialized = false;
+            layout.lazy_init = false;
+            layout.init_owner = None;
+            layout.init_waiters.clear();
+        }
+
+        self.gc.lock().collect();
+    }
+
     /// Register classes from a module
     pub fn register_classes(&self, module: &Arc<Module>, nominal_type_base: usize) {
         let mut classes = self.classes.write();
@@ -1189,6 +1431,10 @@ impl SharedVmState {
                 nominal_type_len,
                 resolved_natives: resolved_natives.clone(),
                 initialized: false,
+                lazy_init: false,
+                init_owner: None,
+                init_waiters: Vec::new(),
+                json_schemas: None,
             },
         );
 
@@ -1206,6 +1452,21 @@ impl SharedVmState {
         // Register classes from the module (rebased to global class IDs).
         self.register_classes(&module, nominal_type_base);
 
+        // Build the `//@@json` decode schemas once class layouts are known.
+        if !module.json_schemas.is_empty() {
+            let registry = TypeSchemaRegistry::from_module_schemas(
+                &module.json_schemas,
+                nominal_type_base,
+                |nominal_type_id| self.nominal_layout_id(nominal_type_id),
+            );
+            if let Some(layout) = self.module_layouts.write().get_mut(&module.checksum) {
+                layout.json_schemas = Some(Arc::new(registry));
+            }
+        }
+
+        // Intern string literals up front so literal equality is an id compare.
+        self.intern_module_strings(&module);
+
         Ok(())
     }
 }
@@ -1233,9 +1494,12 @@ fn reflect_type_name_to_id(type_name: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::{
-        PropertyKeyRegistry, RuntimeTypeHandleRegistry, ShapeAdapter, ShapeId,
+        ConstantStringCache, PropertyKeyRegistry, RuntimeTypeHandleRegistry, ShapeAdapter, ShapeId,
         StructuralSlotBinding,
     };
+    use crate::vm::gc::GarbageCollector;
+    use crate::vm::object::RayaString;
+    use parking_lot::{Mutex, RwLock};
 
     #[test]
     fn type_handle_registry_dedupes_equivalent_entries() {
@@ -1327,4 +1591,37 @@ mod tests {
         assert_eq!(registry.resolve(first), Some("name"));
         assert_eq!(registry.resolve(third), Some("other"));
     }
+
+    #[test]
+    fn constant_string_cache_shares_one_interned_string_per_literal() {
+        let cache = RwLock::new(ConstantStringCache::new());
+        let gc = Mutex::new(GarbageCollector::default());
+        let roots = RwLock::new(Vec::new());
+        let intern = |checksum: u8, index: usize, content: &str| {
+            ConstantStringCache::intern(&cache, &gc, &roots, [checksum; 32], index, content)
+        };
+
+        let first = intern(1, 0, "circle");
+        let same_module = intern(1, 3, "circle");
+        let other_module = intern(2, 0, "circle");
+        let different = intern(1, 1, "square");
+
+        assert_eq!(first, same_module);
+        assert_eq!(first, other_module);
+        assert_eq!(cache.read().get([2; 32], 0), Some(first));
+        assert!(
+            roots.read().is_empty(),
+            "published strings are not ephemeral"
+        );
+
+        let as_string = |value: crate::vm::value::Value| unsafe {
+            &*value.as_ptr::<RayaString>().unwrap().as_ptr()
+        };
+        let (a, b) = (as_string(first), as_string(different));
+        assert!(a.intern_id().is_some());
+        assert!(b.intern_id().is_some());
+        assert_ne!(a.intern_id(), b.intern_id());
+        assert!(a.content_eq(as_string(other_module)));
+        assert!(!a.content_eq(b));
+    }
 }
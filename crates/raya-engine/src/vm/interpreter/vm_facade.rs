@@ -136,7 +136,7 @@ fn serialize_heap(
             let string = unsafe { &*(value_ptr as *const RayaString) };
             SerializedHeapEntry::String {
                 object_id,
-                data: string.data.clone(),
+                data: string.as_str().to_string(),
             }
         } else if type_id == TypeId::of::<Closure>() {
             let closure = unsafe { &*(value_ptr as *const Closure) };
@@ -453,6 +453,10 @@ pub struct Vm {
     /// JIT configuration (kept for creating CompilationPolicy and module profiles)
     #[cfg(feature = "jit")]
     jit_config: Option<crate::jit::JitConfig>,
+    /// Execution timeout armed by `set_execution_timeout` (None = unlimited)
+    execution_timeout: Option<std::time::Duration>,
+    /// Module most recently run by `execute`; `call_function` searches it first
+    last_module: Option<Arc<Module>>,
 }
 
 impl Vm {
@@ -473,6 +477,8 @@ impl Vm {
             jit_engine: None,
             #[cfg(feature = "jit")]
             jit_config: None,
+            execution_timeout: None,
+            last_module: None,
         }
     }
 
@@ -490,6 +496,8 @@ impl Vm {
             jit_engine: None,
             #[cfg(feature = "jit")]
             jit_config: None,
+            execution_timeout: None,
+            last_module: None,
         }
     }
 
@@ -507,6 +515,29 @@ impl Vm {
             jit_engine: None,
             #[cfg(feature = "jit")]
             jit_config: None,
+            execution_timeout: None,
+            last_module: None,
+        }
+    }
+
+    /// Create a new VM with specified scheduler limits and native handler
+    pub fn with_limits_and_handler(
+        worker_count: usize,
+        limits: crate::vm::scheduler::SchedulerLimits,
+        native_handler: std::sync::Arc<dyn crate::vm::NativeHandler>,
+    ) -> Self {
+        let mut scheduler =
+            Scheduler::with_limits_and_handler(worker_count, limits, native_handler);
+        scheduler.start();
+
+        Self {
+            scheduler,
+            #[cfg(feature = "jit")]
+            jit_engine: None,
+            #[cfg(feature = "jit")]
+            jit_config: None,
+            execution_timeout: None,
+            last_module: None,
         }
     }
 
@@ -517,9 +548,52 @@ impl Vm {
             max_concurrent_tasks: options.limits.max_tasks,
             max_preemptions: options.limits.max_preemptions,
             preempt_threshold_ms: options.limits.preempt_threshold_ms,
+            max_stack_size: options.max_stack_size,
+            initial_stack_size: options.initial_stack_size,
+            stack_growth: options.stack_growth,
+            max_open_files: options.limits.max_open_files,
+            max_open_sockets: options.limits.max_open_sockets,
             ..Default::default()
         };
-        Self::with_scheduler_limits(1, limits)
+        let mut vm = Self::with_scheduler_limits(1, limits);
+        vm.set_deterministic_float(options.deterministic_float);
+        vm.set_native_policy(options.native_policy);
+        {
+            let mut gc = vm.scheduler.shared_state().gc.lock();
+            if let Some(allocator) = options.gc_allocator {
+                gc.set_allocator(allocator);
+            }
+            if options.gc_incremental {
+                gc.set_incremental(Some(options.gc_step_bytes));
+            }
+        }
+        vm
+    }
+
+    /// Create a new VM over modules shared read-only with other VMs.
+    ///
+    /// Each module is registered by reference, so code, constant pools and
+    /// debug info live in the shared `Arc`s and are never copied or mutated;
+    /// only per-VM state (heap, globals, tasks) is allocated for this instance.
+    /// Executing one of these modules later reuses the shared copy.
+    ///
+    /// Modules must carry an identity checksum (as decoded modules do).
+    pub fn from_shared_modules(modules: Arc<[Arc<Module>]>) -> VmResult<Self> {
+        let vm = Self::new();
+        for module in modules.iter() {
+            if module.checksum.iter().all(|byte| *byte == 0) {
+                return Err(VmError::RuntimeError(format!(
+                    "Shared module '{}' is missing module identity checksum",
+                    module.metadata.name
+                )));
+            }
+            module.validate().map_err(VmError::RuntimeError)?;
+            vm.scheduler
+                .shared_state()
+                .register_module(module.clone())
+                .map_err(VmError::RuntimeError)?;
+        }
+        Ok(vm)
     }
 
     /// Get the scheduler
@@ -591,12 +665,69 @@ impl Vm {
         }
     }
 
+    /// Snapshot of the shared collector's statistics
+    ///
+    /// Counters accumulate across every collection this VM has run.
+    pub fn gc_stats(&self) -> crate::vm::gc::GcStats {
+        self.scheduler.shared_state().gc.lock().stats().clone()
+    }
+
+    /// Snapshot of the shared heap's current size and allocation totals
+    pub fn heap_stats(&self) -> crate::vm::gc::HeapStats {
+        self.scheduler.shared_state().gc.lock().heap_stats()
+    }
+
+    /// Capture the current heap object graph for memory analysis.
+    ///
+    /// Class instances are named after their class. Serialize the result with
+    /// `HeapGraph::to_heapsnapshot_json` for Chrome DevTools.
+    pub fn heap_graph(&self) -> crate::vm::gc::HeapGraph {
+        let shared = self.scheduler.shared_state();
+        let gc = shared.gc.lock();
+        let mut graph = gc.heap_graph();
+        let classes = shared.classes.read();
+        for node in &mut graph.nodes {
+            if node.kind != crate::vm::gc::HeapNodeKind::Object || node.type_name != "Object" {
+                continue;
+            }
+            // The GC lock is still held, so the object cannot have been freed.
+            let object = unsafe { &*(node.address as *const Object) };
+            if let Some(class) = object
+                .nominal_type_id_usize()
+                .and_then(|id| classes.get_class(id))
+            {
+                node.type_name = class.name.clone();
+            }
+        }
+        graph
+    }
+
     /// Return a snapshot of JIT telemetry counters.
     #[cfg(feature = "jit")]
     pub fn get_jit_telemetry(&self) -> crate::vm::interpreter::JitTelemetrySnapshot {
         self.scheduler.shared_state().jit_telemetry.snapshot()
     }
 
+    /// Snapshot the functions that became hot during this VM's runs.
+    ///
+    /// Uses the adaptive-compilation counters, so the result is empty unless
+    /// JIT was enabled with `adaptive_compilation`. Write it out as a
+    /// `.rayaprof` and pass it back via `JitConfig::hot_profile` to compile the
+    /// same functions eagerly on the next run.
+    #[cfg(feature = "jit")]
+    pub fn jit_hot_profile(&self) -> crate::jit::profiling::hot_profile::HotProfile {
+        let shared = self.scheduler.shared_state();
+        let policy = shared.jit_compilation_policy.lock().clone();
+        let registry = shared.module_registry.read();
+        let mut hot_profile = crate::jit::profiling::hot_profile::HotProfile::new();
+        for (checksum, profile) in shared.module_profiles.read().iter() {
+            if let Some(module) = registry.get_by_checksum(checksum) {
+                hot_profile.record_module(module, profile, &policy);
+            }
+        }
+        hot_profile
+    }
+
     /// Terminate this VM and shut down the scheduler
     pub fn terminate(&mut self) {
         self.scheduler.shutdown();
@@ -624,15 +755,99 @@ impl Vm {
         gc.collect();
     }
 
+    /// Reset this VM so it can run again without being rebuilt.
+    ///
+    /// Persists: loaded modules and their global/class layouts, registered
+    /// classes and natives, the stack pool, JIT state, and the heap's capacity.
+    ///
+    /// Resets: heap contents, global values, tasks and scheduler queues, pending
+    /// microtasks, mutex/semaphore registries, reflection metadata, module
+    /// initialization flags, and the running execution timeout deadline.
+    ///
+    /// Call only after the previous run has finished (see [`Vm::wait_quiescent`]).
+    pub fn reset(&mut self) {
+        self.scheduler.shared_state().reset_run_state();
+        *self.scheduler.shared_state().execution_deadline.lock() = None;
+    }
+
+    /// Set (or clear, with `None`) the execution timeout.
+    ///
+    /// The deadline is armed by the first execution that starts afterwards
+    /// (`execute`, `execute_entry_only` or `call_function`), so compilation
+    /// and VM setup do not count against the budget. Later executions, such as
+    /// the dependency modules of one program, share that deadline until the
+    /// timeout is set again or the VM is [`reset`](Vm::reset). Once it passes,
+    /// the running execution returns [`VmError::Timeout`] and every task is
+    /// cancelled, including tasks parked on blocking native calls. The deadline is also exposed to natives via
+    /// `NativeContext::deadline` so host waits such as socket reads end with it.
+    pub fn set_execution_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.execution_timeout = timeout;
+        *self.scheduler.shared_state().execution_deadline.lock() = None;
+    }
+
+    /// Start the execution timeout clock unless it is already running.
+    fn arm_execution_deadline(&self) {
+        let mut deadline = self.scheduler.shared_state().execution_deadline.lock();
+        if deadline.is_none() {
+            *deadline = self
+                .execution_timeout
+                .map(|timeout| std::time::Instant::now() + timeout);
+        }
+    }
+
+    /// Install (or clear, with `None`) a host-owned cancellation flag.
+    ///
+    /// Once the flag is set, the interpreter stops at its next safepoint, every
+    /// task is cancelled, and the running execution returns
+    /// [`VmError::Cancelled`]. The flag can be set from any thread.
+    pub fn set_cancel_flag(&mut self, flag: Option<Arc<std::sync::atomic::AtomicBool>>) {
+        *self.scheduler.shared_state().cancel_flag.lock() = flag;
+    }
+
+    /// Set the arguments passed to the program, exposed to natives via
+    /// `NativeContext::program_args` (e.g. `process.argv()`).
+    pub fn set_program_args(&mut self, args: Vec<String>) {
+        *self.scheduler.shared_state().program_args.write() = args;
+    }
+
+    /// Install the policy consulted before every native call.
+    ///
+    /// Denied calls raise a catchable [`VmError::SecurityError`]. While a
+    /// policy is installed, functions run in the interpreter only, since
+    /// JIT-compiled code dispatches natives without consulting it.
+    pub fn set_native_policy(&self, policy: Option<crate::vm::NativeCallPolicy>) {
+        *self.scheduler.shared_state().native_policy.lock() = policy;
+    }
+
+    /// Make float arithmetic reproducible across the interpreter and the JIT.
+    ///
+    /// Interpreted `+ - * /` on floats return the canonical quiet NaN instead
+    /// of propagating NaN payloads, and JIT code is compiled without FMA
+    /// contraction and with NaN canonicalization. Call before `enable_jit`.
+    pub fn set_deterministic_float(&self, enabled: bool) {
+        self.scheduler
+            .shared_state()
+            .deterministic_float
+            .store(enabled, std::sync::atomic::Ordering::Relaxed);
+    }
+
     /// Enable CPU/wall-clock profiling with the given configuration.
     ///
     /// Creates a `Profiler` and stores it in shared state so that worker threads
-    /// pick it up when executing tasks. Sampling starts immediately.
+    /// pick it up when executing tasks. The profiler is installed paused;
+    /// sampling begins once the host starts it through [`Vm::profiler`].
     pub fn enable_profiling(&self, config: crate::profiler::ProfileConfig) {
         let profiler = Arc::new(crate::profiler::Profiler::new(config));
         *self.scheduler.shared_state().profiler.lock() = Some(profiler);
     }
 
+    /// The active profiler, if profiling is enabled.
+    ///
+    /// Lets hosts pause and resume sampling around the code they care about.
+    pub fn profiler(&self) -> Option<Arc<crate::profiler::Profiler>> {
+        self.scheduler.shared_state().profiler.lock().clone()
+    }
+
     /// Stop profiling and return the raw profile data.
     ///
     /// Removes the profiler from shared state (new interpreter runs won't sample)
@@ -643,6 +858,24 @@ impl Vm {
         Some(profiler.stop())
     }
 
+    /// Enable line/branch coverage collection.
+    ///
+    /// Installs a `CoverageRecorder` in shared state so worker threads record
+    /// executed instructions. JIT-compiled code is not instrumented, so worker
+    /// threads skip native dispatch while coverage is enabled.
+    pub fn enable_coverage(&self) {
+        let recorder = Arc::new(crate::coverage::CoverageRecorder::new());
+        *self.scheduler.shared_state().coverage.lock() = Some(recorder);
+    }
+
+    /// Stop collecting coverage and return the recorder.
+    ///
+    /// Call `CoverageRecorder::report(module)` to map the recorded bytecode
+    /// offsets to source lines.
+    pub fn stop_coverage(&self) -> Option<Arc<crate::coverage::CoverageRecorder>> {
+        self.scheduler.shared_state().coverage.lock().take()
+    }
+
     /// Snapshot the offline AOT profile collected by the interpreter.
     pub fn snapshot_aot_profile(&self) -> crate::aot_profile::AotProfileData {
         self.scheduler.shared_state().snapshot_aot_profile()
@@ -671,7 +904,15 @@ impl Vm {
 
     /// Enable JIT compilation with custom configuration.
     #[cfg(feature = "jit")]
-    pub fn enable_jit_with_config(&mut self, config: crate::jit::JitConfig) -> Result<(), String> {
+    pub fn enable_jit_with_config(
+        &mut self,
+        mut config: crate::jit::JitConfig,
+    ) -> Result<(), String> {
+        config.deterministic_float |= self
+            .scheduler
+            .shared_state()
+            .deterministic_float
+            .load(std::sync::atomic::Ordering::Relaxed);
         let engine = crate::jit::JitEngine::with_config(config.clone())
             .map_err(|e| format!("Failed to initialize JIT: {}", e))?;
         *self.scheduler.shared_state().code_cache.lock() = Some(engine.code_cache().clone());
@@ -702,14 +943,72 @@ impl Vm {
         self.execute_internal(module, false)
     }
 
+    /// Call a function of a loaded module by name and wait for its result.
+    ///
+    /// The module most recently run by `execute` is searched first; otherwise
+    /// the name must identify exactly one function across the loaded modules.
+    pub fn call_function(&mut self, name: &str, args: Vec<Value>) -> VmResult<Value> {
+        let (module, func_id) = self
+            .find_function(name)?
+            .ok_or_else(|| VmError::RuntimeError(format!("Function '{}' not found", name)))?;
+        let param_count = module.functions[func_id].param_count;
+        if args.len() != param_count {
+            return Err(VmError::RuntimeError(format!(
+                "Function '{}' expects {} argument(s), got {}",
+                name,
+                param_count,
+                args.len()
+            )));
+        }
+        let task = Arc::new(Task::with_args(func_id, module, None, args));
+        self.arm_execution_deadline();
+        self.run_task(task, "Function call")
+    }
+
+    /// Whether `call_function` can resolve `name` (ambiguous names count).
+    pub fn has_function(&self, name: &str) -> bool {
+        !matches!(self.find_function(name), Ok(None))
+    }
+
+    /// Resolve a function by name; an error means several modules define it.
+    fn find_function(&self, name: &str) -> VmResult<Option<(Arc<Module>, usize)>> {
+        let position = |module: &Module| module.functions.iter().position(|f| f.name == name);
+        if let Some(module) = &self.last_module {
+            if let Some(func_id) = position(module) {
+                return Ok(Some((module.clone(), func_id)));
+            }
+        }
+
+        let modules = self
+            .scheduler
+            .shared_state()
+            .module_registry
+            .read()
+            .all_modules();
+        let mut matches: Vec<_> = modules
+            .into_iter()
+            .filter_map(|module| position(&module).map(|func_id| (module, func_id)))
+            .take(2)
+            .collect();
+        if matches.len() > 1 {
+            return Err(VmError::RuntimeError(format!(
+                "Function '{}' is defined in more than one loaded module",
+                name
+            )));
+        }
+        Ok(matches.pop())
+    }
+
     fn execute_internal(
         &mut self,
         module: &Module,
         allow_user_main_fallback: bool,
     ) -> VmResult<Value> {
+        self.arm_execution_deadline();
+
         // Ensure module identity checksum is materialized for runtime bookkeeping
         // (module registry, per-frame snapshot identity, JIT module mapping).
-        let runtime_module = Self::ensure_runtime_module_identity(module)?;
+        let runtime_module = self.runtime_module(module)?;
         let module = runtime_module.as_ref();
 
         // Validate module
@@ -720,6 +1019,7 @@ impl Vm {
             .shared_state()
             .register_module(runtime_module.clone())
             .map_err(VmError::RuntimeError)?;
+        self.last_module = Some(runtime_module.clone());
 
         // JIT: start background thread and submit prewarm candidates (non-blocking)
         #[cfg(feature = "jit")]
@@ -743,7 +1043,21 @@ impl Vm {
                 *self.scheduler.shared_state().background_compiler.lock() =
                     Some(bg_compiler.clone());
 
-                let candidates = Self::collect_prewarm_candidates(module, config);
+                // Functions recorded hot by a previous run go first and are not
+                // subject to the heuristic prewarm limit.
+                let mut candidates = config
+                    .hot_profile
+                    .as_ref()
+                    .map(|profile| profile.hot_functions(module))
+                    .unwrap_or_default();
+                for func_index in Self::collect_prewarm_candidates(module, config)
+                    .into_iter()
+                    .take(config.max_prewarm_functions)
+                {
+                    if !candidates.contains(&func_index) {
+                        candidates.push(func_index);
+                    }
+                }
                 if !candidates.is_empty() {
                     let module_arc = runtime_module.clone();
                     let profile = self
@@ -759,7 +1073,7 @@ impl Vm {
                             ))
                         });
 
-                    for &func_index in candidates.iter().take(config.max_prewarm_functions) {
+                    for &func_index in &candidates {
                         if let Some(fp) = profile.get(func_index) {
                             if !fp.try_start_compile() {
                                 continue;
@@ -809,6 +1123,21 @@ impl Vm {
         Ok(result)
     }
 
+    /// Resolve the runtime copy of `module`, reusing an already-registered
+    /// (possibly shared) module with the same checksum instead of cloning it.
+    fn runtime_module(&self, module: &Module) -> VmResult<Arc<Module>> {
+        if let Some(registered) = self
+            .scheduler
+            .shared_state()
+            .module_registry
+            .read()
+            .get_by_checksum(&module.checksum)
+        {
+            return Ok(registered.clone());
+        }
+        Self::ensure_runtime_module_identity(module)
+    }
+
     fn ensure_runtime_module_identity(module: &Module) -> VmResult<Arc<Module>> {
         if module.checksum.iter().any(|byte| *byte != 0) {
             return Ok(Arc::new(module.clone()));
@@ -860,56 +1189,188 @@ impl Vm {
     }
 
     fn execute_main_task(&mut self, module: Arc<Module>, main_fn_id: usize) -> VmResult<Value> {
-        // Create main task
         let main_task = Arc::new(Task::new(main_fn_id, module, None));
-        let _task_id = main_task.id();
+        self.run_task(main_task, "Main task")
+    }
+
+    /// Spawn `main_task` and block until it finishes; `label` names it in errors.
+    fn run_task(&mut self, main_task: Arc<Task>, label: &str) -> VmResult<Value> {
+        main_task.replace_stack(self.scheduler.shared_state().stack_pool.acquire());
 
-        // Spawn main task
         if self.scheduler.spawn(main_task.clone()).is_none() {
-            return Err(VmError::RuntimeError(
-                "Failed to spawn main task".to_string(),
-            ));
+            return Err(VmError::RuntimeError(format!(
+                "Failed to spawn {}",
+                label.to_lowercase()
+            )));
         }
 
         // Block until main task completes using condvar (no busy-waiting)
-        let final_state = main_task.wait_completion();
+        let deadline = self
+            .scheduler
+            .shared_state()
+            .execution_deadline
+            .lock()
+            .zip(self.execution_timeout);
+        let cancel_flag = self.scheduler.shared_state().cancel_flag.lock().clone();
+        let cancel_poll =
+            std::time::Duration::from_millis(crate::vm::defaults::CANCEL_POLL_INTERVAL_MS);
+        let final_state = if deadline.is_none() && cancel_flag.is_none() {
+            main_task.wait_completion()
+        } else {
+            loop {
+                let wait = match deadline {
+                    Some((deadline, _)) => {
+                        let remaining =
+                            deadline.saturating_duration_since(std::time::Instant::now());
+                        if cancel_flag.is_some() {
+                            remaining.min(cancel_poll)
+                        } else {
+                            remaining
+                        }
+                    }
+                    None => cancel_poll,
+                };
+                let state = main_task.wait_completion_timeout(wait);
+                if cancel_flag
+                    .as_ref()
+                    .is_some_and(|flag| flag.load(std::sync::atomic::Ordering::Relaxed))
+                {
+                    self.scheduler.cancel_all();
+                    return Err(VmError::Cancelled);
+                }
+                if matches!(state, TaskState::Completed | TaskState::Failed) {
+                    break state;
+                }
+                if let Some((deadline, timeout)) = deadline {
+                    if std::time::Instant::now() >= deadline {
+                        self.scheduler.cancel_all();
+                        return Err(VmError::Timeout {
+                            timeout_ms: timeout.as_millis() as u64,
+                        });
+                    }
+                }
+            }
+        };
 
         match final_state {
             TaskState::Completed => Ok(main_task.result().unwrap_or_default()),
             TaskState::Failed => {
-                let msg = Self::extract_exception_message(&main_task);
-                Err(VmError::RuntimeError(msg))
+                let msg = Self::extract_exception_message(&main_task, &format!("{} failed", label));
+                let error = VmError::RuntimeError(msg);
+                Err(match main_task.failure_location() {
+                    Some(location) => error.with_location(location),
+                    None => error,
+                })
             }
             other => Err(VmError::RuntimeError(format!(
-                "Main task ended in unexpected state: {:?}",
-                other
+                "{} ended in unexpected state: {:?}",
+                label, other
             ))),
         }
     }
 
-    /// Extract a human-readable error message from a failed task's exception
-    fn extract_exception_message(task: &Task) -> String {
+    /// Run the callbacks registered via `process.onExit()`, most recent first.
+    ///
+    /// Each hook runs as its own task on the scheduler. Hooks registered while
+    /// hooks are running are picked up too. Once `budget` is spent the remaining
+    /// tasks are cancelled and [`VmError::Timeout`] is returned; a failing hook
+    /// does not stop later hooks, and the first failure is reported at the end.
+    pub fn run_exit_hooks(&mut self, budget: std::time::Duration) -> VmResult<()> {
+        let deadline = std::time::Instant::now() + budget;
+        let mut first_error = None;
+
+        loop {
+            let Some(hook) = self.scheduler.shared_state().exit_hooks.lock().pop() else {
+                break;
+            };
+            let task = Self::exit_hook_task(hook)?;
+            task.replace_stack(self.scheduler.shared_state().stack_pool.acquire());
+            if self.scheduler.spawn(task.clone()).is_none() {
+                return Err(VmError::RuntimeError(
+                    "Failed to spawn exit hook task".to_string(),
+                ));
+            }
+
+            let state = loop {
+                let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                let state = task.wait_completion_timeout(remaining);
+                if matches!(state, TaskState::Completed | TaskState::Failed) {
+                    break state;
+                }
+                if std::time::Instant::now() >= deadline {
+                    self.scheduler.cancel_all();
+                    self.scheduler.shared_state().exit_hooks.lock().clear();
+                    return Err(VmError::Timeout {
+                        timeout_ms: budget.as_millis() as u64,
+                    });
+                }
+            };
+
+            if state == TaskState::Failed && first_error.is_none() {
+                let msg = Self::extract_exception_message(&task, "Exit hook failed");
+                first_error = Some(VmError::RuntimeError(msg));
+            }
+        }
+
+        match first_error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Build the task that invokes an exit hook (a closure or bound method).
+    fn exit_hook_task(hook: Value) -> VmResult<Arc<Task>> {
+        use crate::vm::gc::header_ptr_from_value_ptr;
+        let not_callable = || VmError::TypeError("Exit hook is not a function".to_string());
+
+        let ptr = unsafe { hook.as_ptr::<u8>() }.ok_or_else(not_callable)?;
+        let header = unsafe { &*header_ptr_from_value_ptr(ptr.as_ptr()) };
+
+        if header.type_id() == TypeId::of::<Closure>() {
+            let closure = unsafe { &*ptr.cast::<Closure>().as_ptr() };
+            let module = closure.module().ok_or_else(not_callable)?;
+            let task = Arc::new(Task::new(closure.func_id, module, None));
+            // LoadCaptured reads captures through the task's closure stack.
+            task.push_closure(hook);
+            Ok(task)
+        } else if header.type_id() == TypeId::of::<BoundMethod>() {
+            let method = unsafe { &*ptr.cast::<BoundMethod>().as_ptr() };
+            let module = method.module.clone().ok_or_else(not_callable)?;
+            Ok(Arc::new(Task::with_args(
+                method.func_id,
+                module,
+                None,
+                vec![method.receiver],
+            )))
+        } else {
+            Err(not_callable())
+        }
+    }
+
+    /// Extract a human-readable error message from a failed task's exception,
+    /// prefixed with `context` (e.g. "Main task failed").
+    fn extract_exception_message(task: &Task, context: &str) -> String {
         use crate::vm::gc::header_ptr_from_value_ptr;
         let Some(exc) = task.current_exception() else {
-            return "Main task failed".to_string();
+            return context.to_string();
         };
 
         if exc.is_null() {
-            return "Main task failed".to_string();
+            return context.to_string();
         }
 
         if !exc.is_ptr() {
-            return format!("Main task failed: {:?}", exc);
+            return format!("{}: {:?}", context, exc);
         }
 
         let Some(ptr) = (unsafe { exc.as_ptr::<u8>() }) else {
-            return "Main task failed".to_string();
+            return context.to_string();
         };
         let header = unsafe { &*header_ptr_from_value_ptr(ptr.as_ptr()) };
 
         if header.type_id() == std::any::TypeId::of::<RayaString>() {
             let s = unsafe { &*ptr.cast::<RayaString>().as_ptr() };
-            return format!("Main task failed: {}", s.data);
+            return format!("{}: {}", context, s.as_str());
         }
 
         if header.type_id() == std::any::TypeId::of::<Object>() {
@@ -919,13 +1380,13 @@ impl Vm {
                     let msg_header = unsafe { &*header_ptr_from_value_ptr(msg_ptr.as_ptr()) };
                     if msg_header.type_id() == std::any::TypeId::of::<RayaString>() {
                         let s = unsafe { &*msg_ptr.cast::<RayaString>().as_ptr() };
-                        return format!("Main task failed: {}", s.data);
+                        return format!("{}: {}", context, s.as_str());
                     }
                 }
             }
         }
 
-        "Main task failed".to_string()
+        context.to_string()
     }
 
     /// Collect prewarm candidates from embedded JIT hints or runtime heuristics.
@@ -1110,6 +1571,20 @@ mod tests {
         let _vm = Vm::new();
     }
 
+    #[test]
+    fn test_enable_profiling_installs_paused_profiler() {
+        let vm = Vm::new();
+        assert!(vm.profiler().is_none());
+        vm.enable_profiling(crate::profiler::ProfileConfig::default());
+        let profiler = vm.profiler().expect("profiler installed");
+        assert!(
+            !profiler.is_enabled(),
+            "sampling waits for an explicit start"
+        );
+        profiler.start();
+        assert!(profiler.is_enabled());
+    }
+
     #[cfg(feature = "jit")]
     #[test]
     fn test_jit_telemetry_snapshot_includes_resume_counters() {
@@ -1163,6 +1638,24 @@ mod tests {
         assert_eq!(result, Value::bool(true));
     }
 
+    #[test]
+    fn test_execution_timeout_starts_with_execute() {
+        let mut module = Module::new("test".to_string());
+        module.functions.push(Function {
+            name: "main".to_string(),
+            param_count: 0,
+            local_count: 0,
+            code: vec![Opcode::ConstTrue as u8, Opcode::Return as u8],
+        });
+
+        let mut vm = Vm::new();
+        vm.set_execution_timeout(Some(std::time::Duration::from_millis(50)));
+        // Setup time before execute must not count against the budget
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let result = vm.execute(&module).unwrap();
+        assert_eq!(result, Value::bool(true));
+    }
+
     #[test]
     fn test_const_false() {
         let mut module = Module::new("test".to_string());
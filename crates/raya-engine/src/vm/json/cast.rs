@@ -41,7 +41,7 @@ const MAX_VALIDATION_DEPTH: usize = 100;
 /// - Type ID for runtime type tagging
 /// - Type kind (primitive, interface, array, union)
 /// - Field/element schemas for nested validation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TypeSchema {
     /// Unique type ID from the type registry
     pub type_id: usize,
@@ -51,7 +51,7 @@ pub struct TypeSchema {
 }
 
 /// Kind of type schema
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TypeKind {
     /// Null type (only allows null)
     Null,
@@ -65,10 +65,17 @@ pub enum TypeKind {
     /// String type
     String,
 
+    /// Any JSON value, kept as is (types with no JSON schema of their own)
+    Any,
+
+    /// Class field that is never read from JSON; decodes as null
+    Skipped,
+
     /// Interface/class type with named fields
     Interface {
-        /// Nominal type ID for runtime type tagging
-        nominal_type_id: usize,
+        /// Nominal type ID for runtime type tagging (`None` decodes to a
+        /// structural object)
+        nominal_type_id: Option<usize>,
         /// Physical layout ID for typed object allocation.
         layout_id: crate::vm::object::LayoutId,
         /// Field names and their type schema IDs
@@ -94,6 +101,7 @@ pub enum TypeKind {
 ///
 /// Stores all type schemas indexed by type ID. This is populated by the
 /// compiler during code generation and used during runtime validation.
+#[derive(Debug)]
 pub struct TypeSchemaRegistry {
     schemas: FxHashMap<usize, Arc<TypeSchema>>,
 }
@@ -120,6 +128,52 @@ impl TypeSchemaRegistry {
     pub fn contains(&self, type_id: usize) -> bool {
         self.schemas.contains_key(&type_id)
     }
+
+    /// Build a registry from the `//@@json` schemas of a compiled module
+    ///
+    /// Schema `i` is registered under type ID `i`. Module-local class indices
+    /// in `Interface` schemas are rebased by `nominal_type_base`, and class
+    /// schemas allocate with the layout `class_layout` reports for the class.
+    pub fn from_module_schemas<F>(
+        schemas: &[TypeSchema],
+        nominal_type_base: usize,
+        mut class_layout: F,
+    ) -> Self
+    where
+        F: FnMut(usize) -> Option<LayoutId>,
+    {
+        let mut registry = Self::new();
+        for (type_id, schema) in schemas.iter().enumerate() {
+            let mut schema = schema.clone();
+            schema.type_id = type_id;
+            if let TypeKind::Interface {
+                nominal_type_id: Some(nominal_type_id),
+                layout_id,
+                ..
+            } = &mut schema.kind
+            {
+                *nominal_type_id += nominal_type_base;
+                if let Some(class_layout_id) = class_layout(*nominal_type_id) {
+                    *layout_id = class_layout_id;
+                }
+            }
+            registry.register(type_id, schema);
+        }
+        registry
+    }
+
+    /// Get the schema that decodes the class with the given nominal type ID
+    pub fn class_schema(&self, nominal_type_id: usize) -> Option<Arc<TypeSchema>> {
+        self.schemas
+            .values()
+            .find(|schema| {
+                matches!(
+                    schema.kind,
+                    TypeKind::Interface { nominal_type_id: Some(id), .. } if id == nominal_type_id
+                )
+            })
+            .cloned()
+    }
 }
 
 impl Default for TypeSchemaRegistry {
@@ -210,7 +264,7 @@ where
 {
     // Check recursion depth
     if depth > MAX_VALIDATION_DEPTH {
-        return Err(VmError::StackOverflow);
+        return Err(VmError::StackOverflow { depth });
     }
 
     match &schema.kind {
@@ -222,6 +276,10 @@ where
 
         TypeKind::String => validate_string(json, gc),
 
+        TypeKind::Any => Ok(json.to_value()),
+
+        TypeKind::Skipped => Ok(Value::null()),
+
         TypeKind::Interface {
             nominal_type_id,
             layout_id,
@@ -319,7 +377,7 @@ fn validate_string(json: &JsonValue, _gc: &mut GarbageCollector) -> VmResult<Val
 /// Validate interface/class type
 fn validate_interface<FP, FL>(
     json: &JsonValue,
-    nominal_type_id: usize,
+    nominal_type_id: Option<usize>,
     layout_id: crate::vm::object::LayoutId,
     fields: &[(String, usize)],
     schema_registry: &TypeSchemaRegistry,
@@ -344,17 +402,21 @@ where
     let mut field_values = Vec::with_capacity(fields.len());
 
     for (field_name, field_type_id) in fields {
+        // Get field type schema
+        let field_schema = schema_registry
+            .get(*field_type_id)
+            .ok_or_else(|| VmError::TypeError(format!("Unknown type ID: {}", field_type_id)))?;
+        if field_schema.kind == TypeKind::Skipped {
+            field_values.push(Value::null());
+            continue;
+        }
+
         let field_json =
             json.get_property_with_runtime_metadata(field_name, resolve_prop_key, resolve_layout_names);
         if field_json.is_undefined() {
             return Err(VmError::TypeError(format!("Missing field: {}", field_name)));
         }
 
-        // Get field type schema
-        let field_schema = schema_registry
-            .get(*field_type_id)
-            .ok_or_else(|| VmError::TypeError(format!("Unknown type ID: {}", field_type_id)))?;
-
         // Recursively validate field
         let field_value = validate_cast_impl(
             &field_json,
@@ -370,7 +432,12 @@ where
     }
 
     // Create typed object
-    let mut obj = Object::new_nominal(layout_id, nominal_type_id as u32, field_values.len());
+    let mut obj = match nominal_type_id {
+        Some(nominal_type_id) => {
+            Object::new_nominal(layout_id, nominal_type_id as u32, field_values.len())
+        }
+        None => Object::new_structural(layout_id, field_values.len()),
+    };
     obj.fields = field_values;
 
     let obj_ptr = gc.allocate(obj);
@@ -471,7 +538,7 @@ where
         }
 
         let disc_str = match &disc_value {
-            JsonValue::String(s_ptr) => unsafe { &*s_ptr.as_ptr() }.data.clone(),
+            JsonValue::String(s_ptr) => unsafe { &*s_ptr.as_ptr() }.as_str().to_string(),
             _ => {
                 return Err(VmError::TypeError(format!(
                     "Discriminant must be string, got {}",
@@ -641,7 +708,7 @@ mod tests {
             &mut no_prop_keys,
             &mut no_layout_names,
         );
-        assert!(matches!(result, Err(VmError::StackOverflow)));
+        assert!(matches!(result, Err(VmError::StackOverflow { .. })));
     }
 
     fn make_string(gc: &mut GarbageCollector, s: &str) -> Value {
@@ -669,7 +736,7 @@ mod tests {
         let schema = TypeSchema {
             type_id: 3,
             kind: TypeKind::Interface {
-                nominal_type_id: 9,
+                nominal_type_id: Some(9),
                 layout_id: layout_id_from_ordered_names(&["name".to_string(), "age".to_string()]),
                 fields: vec![("name".to_string(), 1), ("age".to_string(), 2)],
             },
@@ -713,7 +780,7 @@ mod tests {
         let obj = unsafe { &*result.as_ptr::<Object>().unwrap().as_ptr() };
         assert_eq!(obj.field_count(), 2);
         let name = unsafe { &*obj.get_field(0).unwrap().as_ptr::<RayaString>().unwrap().as_ptr() };
-        assert_eq!(name.data, "Alice");
+        assert_eq!(name.as_str(), "Alice");
         assert_eq!(obj.get_field(1).unwrap().as_f64(), Some(30.0));
     }
 
@@ -748,7 +815,7 @@ mod tests {
 
         let obj = unsafe { &*result.as_ptr::<Object>().unwrap().as_ptr() };
         let name = unsafe { &*obj.get_field(0).unwrap().as_ptr::<RayaString>().unwrap().as_ptr() };
-        assert_eq!(name.data, "Bob");
+        assert_eq!(name.as_str(), "Bob");
         assert_eq!(obj.get_field(1).unwrap().as_f64(), Some(25.0));
     }
 }
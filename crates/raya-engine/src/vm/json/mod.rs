@@ -3,7 +3,9 @@
 //! # Design
 //!
 //! - `parser::parse()` produces native VM `Value` using `Object`/`Array`/`RayaString`
-//! - `stringify::stringify()` uses `js_classify()` for dispatch
+//! - `reader::parse_reader()` runs the same parser incrementally over any `io::Read`
+//! - `stringify::stringify()` uses `js_classify()` for dispatch;
+//!   `stringify::stringify_to_writer()` streams the same output to an `io::Write`
 //! - `JSView` / `js_classify()` are the single dispatch entry point for all type checks
 //! - `JsonValue` is kept as a **stack-only** internal type for the `cast` module
 //!   (never GC-heap-allocated)
@@ -11,9 +13,11 @@
 use crate::vm::gc::{GarbageCollector, GcPtr};
 use crate::vm::object::{global_layout_names, Array, LayoutId, PropKeyId, RayaString};
 use crate::vm::value::Value;
+use crate::vm::VmResult;
 
 pub mod cast;
 pub mod parser;
+pub mod reader;
 pub mod stringify;
 pub mod view;
 
@@ -100,6 +104,40 @@ impl JsonValue {
         }
     }
 
+    /// Parse one JSON document incrementally from `reader`.
+    ///
+    /// Input is pulled in fixed-size chunks, so large payloads (e.g. HTTP
+    /// bodies) never have to be buffered as a single string. Malformed input
+    /// is reported with the byte offset where parsing failed.
+    pub fn from_reader<R: std::io::Read>(
+        reader: R,
+        gc: &mut GarbageCollector,
+    ) -> VmResult<JsonValue> {
+        reader::parse_reader(reader, gc).map(value_to_json_stack)
+    }
+
+    /// Serialize this value as JSON to `writer` without building the whole
+    /// document in memory. `Undefined` is written as `null`.
+    pub fn to_writer<W: std::io::Write>(&self, writer: W) -> VmResult<()> {
+        stringify::stringify_to_writer(self.to_value(), writer)
+    }
+
+    /// The VM `Value` this JSON value refers to (no GC allocation)
+    fn to_value(&self) -> Value {
+        match self {
+            JsonValue::Null | JsonValue::Undefined => Value::null(),
+            JsonValue::Bool(b) => Value::bool(*b),
+            JsonValue::Number(n) => Value::f64(*n),
+            JsonValue::String(s_ptr) => unsafe {
+                Value::from_ptr(std::ptr::NonNull::new(s_ptr.as_ptr()).unwrap())
+            },
+            JsonValue::Array(arr_ptr) => unsafe {
+                Value::from_ptr(std::ptr::NonNull::new(arr_ptr.as_ptr()).unwrap())
+            },
+            JsonValue::Object(val) => *val,
+        }
+    }
+
     /// Get a property from a JSON object without runtime metadata.
     ///
     /// This supports standalone structural objects via the process-local layout
@@ -224,7 +262,7 @@ impl JsonValue {
             JsonValue::Number(n) => *n,
             JsonValue::String(s_ptr) => {
                 let s = unsafe { &*s_ptr.as_ptr() };
-                s.data.as_str().trim().parse::<f64>().unwrap_or(f64::NAN)
+                s.as_str().trim().parse::<f64>().unwrap_or(f64::NAN)
             }
             JsonValue::Array(_) | JsonValue::Object(_) => f64::NAN,
         }
@@ -247,7 +285,7 @@ impl PartialEq for JsonValue {
             (JsonValue::String(a), JsonValue::String(b)) => {
                 let a_str = unsafe { &*a.as_ptr() };
                 let b_str = unsafe { &*b.as_ptr() };
-                a_str.data == b_str.data
+                a_str.as_str() == b_str.as_str()
             }
             _ => false,
         }
@@ -286,19 +324,8 @@ pub fn value_to_json_stack(value: Value) -> JsonValue {
 /// Since `parser::parse()` already returns a native `Value`, this is now
 /// a no-op identity function kept for call-site compatibility.
 #[inline]
-pub fn json_to_value(json: &JsonValue, gc: &mut GarbageCollector) -> Value {
-    match json {
-        JsonValue::Null | JsonValue::Undefined => Value::null(),
-        JsonValue::Bool(b) => Value::bool(*b),
-        JsonValue::Number(n) => Value::f64(*n),
-        JsonValue::String(s_ptr) => unsafe {
-            Value::from_ptr(std::ptr::NonNull::new(s_ptr.as_ptr()).unwrap())
-        },
-        JsonValue::Array(arr_ptr) => unsafe {
-            Value::from_ptr(std::ptr::NonNull::new(arr_ptr.as_ptr()).unwrap())
-        },
-        JsonValue::Object(val) => *val,
-    }
+pub fn json_to_value(json: &JsonValue, _gc: &mut GarbageCollector) -> Value {
+    json.to_value()
 }
 
 /// Convert a VM `Value` to a `JsonValue` for use by `cast.rs`.
@@ -355,6 +382,39 @@ mod tests {
         assert_eq!(JsonValue::Number(42.5).to_number(), 42.5);
     }
 
+    #[test]
+    fn test_json_value_reader_writer_round_trip() {
+        let mut gc = GarbageCollector::default();
+        // Collect repeatedly while the document is only partly built.
+        gc.set_threshold(64 * 1024);
+        let mut json = String::from(r#"{"items":["#);
+        for i in 0..100_000 {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!(r#"{{"id":{},"name":"item\n{}"}}"#, i, i));
+        }
+        json.push_str(r#"],"total":100000}"#);
+        assert!(json.len() > 1024 * 1024);
+
+        let value = JsonValue::from_reader(std::io::Cursor::new(&json), &mut gc).unwrap();
+        assert!(gc.stats().collections > 0);
+        assert!(value.is_object());
+        assert_eq!(value.get_property("total").as_number(), Some(100000.0));
+        assert_eq!(value.get_property("items").array_len(), 100_000);
+
+        let mut written = Vec::new();
+        value.to_writer(&mut written).unwrap();
+        assert_eq!(String::from_utf8(written).unwrap(), json);
+    }
+
+    #[test]
+    fn test_json_value_from_reader_reports_offset() {
+        let mut gc = GarbageCollector::default();
+        let err = JsonValue::from_reader(&b"{\"a\": [1, 2}"[..], &mut gc).unwrap_err();
+        assert!(err.to_string().contains("at byte offset 11"), "{err}");
+    }
+
     #[test]
     fn test_json_value_equality() {
         assert_eq!(JsonValue::Null, JsonValue::Null);
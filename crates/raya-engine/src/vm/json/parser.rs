@@ -5,6 +5,10 @@
 //! - Minimal allocations
 //! - Direct GC allocation of native types (Object, Array, RayaString)
 //! - No intermediate representations
+//!
+//! The parser reads through a [`Source`], so the same code parses in-memory
+//! strings and `io::Read` streams (see [`super::reader`]). Errors report the
+//! byte offset where parsing failed.
 
 use crate::vm::gc::GarbageCollector;
 use crate::vm::object::{
@@ -24,16 +28,7 @@ use crate::vm::{VmError, VmResult};
 /// - array → `GcPtr<Array>` with elements as `Value` → `Value`
 /// - object → `GcPtr<Object>` with structural fields and/or dynamic props as `Value` → `Value`
 pub fn parse(input: &str, gc: &mut GarbageCollector) -> VmResult<Value> {
-    let mut parser = Parser::new(input, gc, None);
-    let val = parser.parse_value()?;
-    parser.skip_whitespace();
-    if parser.pos < parser.bytes.len() {
-        return Err(VmError::RuntimeError(format!(
-            "Unexpected trailing characters at position {}",
-            parser.pos
-        )));
-    }
-    Ok(val)
+    parse_source(SliceSource::new(input.as_bytes()), gc, None)
 }
 
 /// Parse JSON directly into unified `Object + dyn_map` carriers by interning
@@ -43,379 +38,347 @@ pub fn parse_with_prop_key_interner(
     gc: &mut GarbageCollector,
     intern_prop_key: &mut dyn FnMut(&str) -> PropKeyId,
 ) -> VmResult<Value> {
-    let mut parser = Parser::new(input, gc, Some(intern_prop_key));
-    let val = parser.parse_value()?;
-    parser.skip_whitespace();
-    if parser.pos < parser.bytes.len() {
-        return Err(VmError::RuntimeError(format!(
-            "Unexpected trailing characters at position {}",
-            parser.pos
-        )));
-    }
-    Ok(val)
+    parse_source(
+        SliceSource::new(input.as_bytes()),
+        gc,
+        Some(intern_prop_key),
+    )
 }
 
-/// JSON parser state
-struct Parser<'a> {
-    input: &'a str,
+/// Parse one JSON document from `source`; only whitespace may follow it.
+///
+/// Every value allocated while parsing stays rooted until the document is
+/// complete, so a collection triggered mid-parse cannot free the parts of
+/// the document that are not yet reachable from its root.
+pub(super) fn parse_source<'a, S: Source>(
+    source: S,
+    gc: &'a mut GarbageCollector,
+    intern_prop_key: Option<&'a mut dyn FnMut(&str) -> PropKeyId>,
+) -> VmResult<Value> {
+    let roots = gc.stack_root_count();
+    let mut parser = Parser {
+        source,
+        gc,
+        intern_prop_key,
+    };
+    let result = parser.parse_document();
+    parser.gc.truncate_stack_roots(roots);
+    result
+}
+
+/// Bytes of a JSON document, read one at a time
+pub(super) trait Source {
+    /// Look at the next byte without consuming it
+    fn peek(&mut self) -> VmResult<Option<u8>>;
+
+    /// Consume the byte `peek` returned
+    fn bump(&mut self);
+
+    /// Offset of the next unread byte from the start of the document
+    fn offset(&self) -> usize;
+}
+
+/// A document held in memory
+struct SliceSource<'a> {
     bytes: &'a [u8],
     pos: usize,
+}
+
+impl<'a> SliceSource<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+}
+
+impl Source for SliceSource<'_> {
+    fn peek(&mut self) -> VmResult<Option<u8>> {
+        Ok(self.bytes.get(self.pos).copied())
+    }
+
+    fn bump(&mut self) {
+        self.pos += 1;
+    }
+
+    fn offset(&self) -> usize {
+        self.pos
+    }
+}
+
+fn error_at(offset: usize, message: &str) -> VmError {
+    VmError::RuntimeError(format!("{} at byte offset {}", message, offset))
+}
+
+/// JSON parser state
+struct Parser<'a, S> {
+    source: S,
     gc: &'a mut GarbageCollector,
     intern_prop_key: Option<&'a mut dyn FnMut(&str) -> PropKeyId>,
 }
 
-impl<'a> Parser<'a> {
-    fn new(
-        input: &'a str,
-        gc: &'a mut GarbageCollector,
-        intern_prop_key: Option<&'a mut dyn FnMut(&str) -> PropKeyId>,
-    ) -> Self {
-        Self {
-            input,
-            bytes: input.as_bytes(),
-            pos: 0,
-            gc,
-            intern_prop_key,
-        }
+impl<S: Source> Parser<'_, S> {
+    fn error(&self, message: &str) -> VmError {
+        error_at(self.source.offset(), message)
     }
 
-    /// Parse a JSON value
-    fn parse_value(&mut self) -> VmResult<Value> {
-        self.skip_whitespace();
+    fn peek(&mut self) -> VmResult<Option<u8>> {
+        self.source.peek()
+    }
 
-        if self.pos >= self.bytes.len() {
-            return Err(VmError::RuntimeError("Unexpected end of JSON".to_string()));
+    fn next_byte(&mut self) -> VmResult<Option<u8>> {
+        let byte = self.source.peek()?;
+        if byte.is_some() {
+            self.source.bump();
         }
+        Ok(byte)
+    }
+
+    /// Allocate `value` on the GC heap and root it for the rest of the parse
+    fn allocate<T: 'static>(&mut self, value: T) -> Value {
+        let ptr = self.gc.allocate(value);
+        let value = unsafe { Value::from_ptr(std::ptr::NonNull::new(ptr.as_ptr()).unwrap()) };
+        self.gc.add_root(value);
+        value
+    }
 
-        match self.bytes[self.pos] {
-            b'n' => self.parse_null(),
-            b't' | b'f' => self.parse_bool(),
-            b'"' => self.parse_string_value(),
-            b'[' => self.parse_array(),
-            b'{' => self.parse_object(),
-            b'-' | b'0'..=b'9' => self.parse_number(),
-            c => Err(VmError::RuntimeError(format!(
-                "Unexpected character '{}' at position {}",
-                c as char, self.pos
-            ))),
+    fn parse_document(&mut self) -> VmResult<Value> {
+        let value = self.parse_value()?;
+        self.skip_whitespace()?;
+        if self.peek()?.is_some() {
+            return Err(self.error("Unexpected trailing characters"));
         }
+        Ok(value)
     }
 
-    fn parse_null(&mut self) -> VmResult<Value> {
-        if self.consume_literal("null") {
-            Ok(Value::null())
-        } else {
-            Err(VmError::RuntimeError(format!(
-                "Invalid null literal at position {}",
-                self.pos
-            )))
+    /// Parse a JSON value
+    fn parse_value(&mut self) -> VmResult<Value> {
+        self.skip_whitespace()?;
+        match self.peek()? {
+            None => Err(self.error("Unexpected end of JSON")),
+            Some(b'n') => self.parse_literal("null", Value::null()),
+            Some(b't') => self.parse_literal("true", Value::bool(true)),
+            Some(b'f') => self.parse_literal("false", Value::bool(false)),
+            Some(b'"') => {
+                let data = self.read_string()?;
+                Ok(self.allocate(RayaString::new(data)))
+            }
+            Some(b'[') => self.parse_array(),
+            Some(b'{') => self.parse_object(),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(c) => Err(self.error(&format!("Unexpected character '{}'", c as char))),
         }
     }
 
-    fn parse_bool(&mut self) -> VmResult<Value> {
-        if self.consume_literal("true") {
-            Ok(Value::bool(true))
-        } else if self.consume_literal("false") {
-            Ok(Value::bool(false))
-        } else {
-            Err(VmError::RuntimeError(format!(
-                "Invalid boolean literal at position {}",
-                self.pos
-            )))
+    fn parse_literal(&mut self, literal: &str, value: Value) -> VmResult<Value> {
+        let start = self.source.offset();
+        for &expected in literal.as_bytes() {
+            if self.next_byte()? != Some(expected) {
+                return Err(error_at(start, &format!("Invalid {} literal", literal)));
+            }
         }
+        Ok(value)
     }
 
     fn parse_number(&mut self) -> VmResult<Value> {
-        let start = self.pos;
-
-        if self.pos < self.bytes.len() && self.bytes[self.pos] == b'-' {
-            self.pos += 1;
-        }
+        let start = self.source.offset();
+        let mut text = String::new();
 
-        if self.pos >= self.bytes.len() {
-            return Err(VmError::RuntimeError(
-                "Unexpected end of number".to_string(),
-            ));
+        if self.peek()? == Some(b'-') {
+            self.source.bump();
+            text.push('-');
         }
 
-        if self.bytes[self.pos] == b'0' {
-            self.pos += 1;
-        } else if self.bytes[self.pos].is_ascii_digit() {
-            while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_digit() {
-                self.pos += 1;
+        match self.peek()? {
+            Some(b'0') => {
+                self.source.bump();
+                text.push('0');
             }
-        } else {
-            return Err(VmError::RuntimeError(format!(
-                "Invalid number at position {}",
-                self.pos
-            )));
+            Some(b'1'..=b'9') => self.take_digits(&mut text)?,
+            _ => return Err(self.error("Invalid number")),
         }
 
-        if self.pos < self.bytes.len() && self.bytes[self.pos] == b'.' {
-            self.pos += 1;
-            if self.pos >= self.bytes.len() || !self.bytes[self.pos].is_ascii_digit() {
-                return Err(VmError::RuntimeError(
-                    "Invalid number: digit expected after '.'".to_string(),
-                ));
-            }
-            while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_digit() {
-                self.pos += 1;
+        if self.peek()? == Some(b'.') {
+            self.source.bump();
+            text.push('.');
+            if !matches!(self.peek()?, Some(b'0'..=b'9')) {
+                return Err(self.error("Invalid number: digit expected after '.'"));
             }
+            self.take_digits(&mut text)?;
         }
 
-        if self.pos < self.bytes.len()
-            && (self.bytes[self.pos] == b'e' || self.bytes[self.pos] == b'E')
-        {
-            self.pos += 1;
-            if self.pos < self.bytes.len()
-                && (self.bytes[self.pos] == b'+' || self.bytes[self.pos] == b'-')
-            {
-                self.pos += 1;
+        if let Some(b'e' | b'E') = self.peek()? {
+            self.source.bump();
+            text.push('e');
+            if let Some(sign @ (b'+' | b'-')) = self.peek()? {
+                self.source.bump();
+                text.push(sign as char);
             }
-            if self.pos >= self.bytes.len() || !self.bytes[self.pos].is_ascii_digit() {
-                return Err(VmError::RuntimeError(
-                    "Invalid number: digit expected in exponent".to_string(),
-                ));
-            }
-            while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_digit() {
-                self.pos += 1;
+            if !matches!(self.peek()?, Some(b'0'..=b'9')) {
+                return Err(self.error("Invalid number: digit expected in exponent"));
             }
+            self.take_digits(&mut text)?;
         }
 
-        let num_str = &self.input[start..self.pos];
-        let n = num_str
-            .parse::<f64>()
-            .map_err(|_| VmError::RuntimeError(format!("Invalid number: {}", num_str)))?;
-        Ok(Value::f64(n))
+        text.parse::<f64>()
+            .map(Value::f64)
+            .map_err(|_| error_at(start, &format!("Invalid number {}", text)))
     }
 
-    /// Parse a JSON string and return it as a VM `Value` (allocates RayaString).
-    fn parse_string_value(&mut self) -> VmResult<Value> {
-        let data = self.read_string_data()?;
-        let raya_str = RayaString::new(data);
-        let gc_ptr = self.gc.allocate(raya_str);
-        Ok(unsafe { Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap()) })
+    fn take_digits(&mut self, text: &mut String) -> VmResult<()> {
+        while let Some(digit @ b'0'..=b'9') = self.peek()? {
+            self.source.bump();
+            text.push(digit as char);
+        }
+        Ok(())
     }
 
-    /// Parse a JSON string and return the raw Rust `String` (used for object keys).
-    fn read_string_data(&mut self) -> VmResult<String> {
-        if self.bytes[self.pos] != b'"' {
-            return Err(VmError::RuntimeError(format!(
-                "Expected '\"' at position {}",
-                self.pos
-            )));
-        }
-        self.pos += 1;
+    /// Read a string whose opening quote is the next byte (used for values
+    /// and object keys).
+    fn read_string(&mut self) -> VmResult<String> {
+        let start = self.source.offset();
+        self.source.bump();
 
-        let start = self.pos;
-        let mut has_escapes = false;
-
-        while self.pos < self.bytes.len() {
-            match self.bytes[self.pos] {
-                b'"' => {
-                    let end = self.pos;
-                    self.pos += 1;
-                    if has_escapes {
-                        return self.unescape_string(&self.input[start..end]);
-                    } else {
-                        return Ok(self.input[start..end].to_string());
-                    }
-                }
-                b'\\' => {
-                    has_escapes = true;
-                    self.pos += 1;
-                    if self.pos >= self.bytes.len() {
-                        return Err(VmError::RuntimeError(
-                            "Unexpected end of string escape".to_string(),
-                        ));
-                    }
-                    self.pos += 1;
-                }
-                b'\x00'..=b'\x1F' => {
-                    return Err(VmError::RuntimeError(format!(
-                        "Unescaped control character in string at position {}",
-                        self.pos
-                    )));
+        let mut bytes = Vec::new();
+        loop {
+            match self.peek()? {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    self.source.bump();
+                    self.read_escape(&mut bytes)?;
+                    continue;
                 }
-                _ => {
-                    self.pos += 1;
+                Some(0x00..=0x1F) => {
+                    return Err(self.error("Unescaped control character in string"))
                 }
+                Some(byte) => bytes.push(byte),
+                None => return Err(error_at(start, "Unterminated string starting")),
             }
+            self.source.bump();
         }
+        self.source.bump();
 
-        Err(VmError::RuntimeError("Unterminated string".to_string()))
+        String::from_utf8(bytes).map_err(|_| error_at(start, "Invalid UTF-8 in string starting"))
     }
 
-    fn unescape_string(&self, s: &str) -> VmResult<String> {
-        let mut result = String::with_capacity(s.len());
-        let mut chars = s.chars();
-
-        while let Some(ch) = chars.next() {
-            if ch == '\\' {
-                match chars.next() {
-                    Some('"') => result.push('"'),
-                    Some('\\') => result.push('\\'),
-                    Some('/') => result.push('/'),
-                    Some('b') => result.push('\x08'),
-                    Some('f') => result.push('\x0C'),
-                    Some('n') => result.push('\n'),
-                    Some('r') => result.push('\r'),
-                    Some('t') => result.push('\t'),
-                    Some('u') => {
-                        let hex: String = chars.by_ref().take(4).collect();
-                        if hex.len() != 4 {
-                            return Err(VmError::RuntimeError(
-                                "Invalid unicode escape".to_string(),
-                            ));
-                        }
-                        let code = u32::from_str_radix(&hex, 16).map_err(|_| {
-                            VmError::RuntimeError("Invalid unicode hex digits".to_string())
-                        })?;
-                        if let Some(unicode_char) = char::from_u32(code) {
-                            result.push(unicode_char);
-                        } else {
-                            return Err(VmError::RuntimeError(
-                                "Invalid unicode code point".to_string(),
-                            ));
-                        }
-                    }
-                    Some(c) => {
-                        return Err(VmError::RuntimeError(format!(
-                            "Invalid escape sequence: \\{}",
-                            c
-                        )))
-                    }
-                    None => {
-                        return Err(VmError::RuntimeError(
-                            "Unexpected end of string".to_string(),
-                        ))
-                    }
+    /// Decode the escape sequence following a consumed backslash
+    fn read_escape(&mut self, out: &mut Vec<u8>) -> VmResult<()> {
+        let start = self.source.offset() - 1;
+        let ch = match self.next_byte()? {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\x08',
+            Some(b'f') => '\x0C',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let mut code = 0;
+                for _ in 0..4 {
+                    let digit = self
+                        .next_byte()?
+                        .and_then(|byte| (byte as char).to_digit(16))
+                        .ok_or_else(|| error_at(start, "Invalid unicode escape"))?;
+                    code = code * 16 + digit;
                 }
-            } else {
-                result.push(ch);
+                char::from_u32(code).ok_or_else(|| error_at(start, "Invalid unicode code point"))?
             }
-        }
-
-        Ok(result)
+            Some(c) => {
+                return Err(error_at(
+                    start,
+                    &format!("Invalid escape sequence: \\{}", c as char),
+                ))
+            }
+            None => return Err(self.error("Unexpected end of string escape")),
+        };
+        let mut utf8 = [0; 4];
+        out.extend_from_slice(ch.encode_utf8(&mut utf8).as_bytes());
+        Ok(())
     }
 
     fn parse_array(&mut self) -> VmResult<Value> {
-        debug_assert_eq!(self.bytes[self.pos], b'[');
-        self.pos += 1;
-        self.skip_whitespace();
-
-        let mut elements: Vec<Value> = Vec::new();
-
-        if self.pos < self.bytes.len() && self.bytes[self.pos] == b']' {
-            self.pos += 1;
-            let arr = Array {
-                type_id: 0,
-                elements,
-            };
-            let arr_ptr = self.gc.allocate(arr);
-            return Ok(unsafe {
-                Value::from_ptr(std::ptr::NonNull::new(arr_ptr.as_ptr()).unwrap())
-            });
-        }
-
-        loop {
-            let elem = self.parse_value()?;
-            elements.push(elem);
-            self.skip_whitespace();
-
-            if self.pos >= self.bytes.len() {
-                return Err(VmError::RuntimeError("Unterminated array".to_string()));
-            }
+        self.source.bump();
+        self.skip_whitespace()?;
 
-            match self.bytes[self.pos] {
-                b',' => {
-                    self.pos += 1;
-                    self.skip_whitespace();
-                }
-                b']' => {
-                    self.pos += 1;
-                    let arr = Array {
-                        type_id: 0,
-                        elements,
-                    };
-                    let arr_ptr = self.gc.allocate(arr);
-                    return Ok(unsafe {
-                        Value::from_ptr(std::ptr::NonNull::new(arr_ptr.as_ptr()).unwrap())
-                    });
-                }
-                c => {
-                    return Err(VmError::RuntimeError(format!(
-                        "Expected ',' or ']' in array, got '{}' at position {}",
-                        c as char, self.pos
-                    )))
+        let mut elements = Vec::new();
+        if self.peek()? == Some(b']') {
+            self.source.bump();
+        } else {
+            loop {
+                elements.push(self.parse_value()?);
+                self.skip_whitespace()?;
+                match self.peek()? {
+                    Some(b',') => self.source.bump(),
+                    Some(b']') => {
+                        self.source.bump();
+                        break;
+                    }
+                    Some(c) => {
+                        return Err(self.error(&format!(
+                            "Expected ',' or ']' in array, got '{}'",
+                            c as char
+                        )))
+                    }
+                    None => return Err(self.error("Unterminated array")),
                 }
             }
         }
+
+        Ok(self.allocate(Array {
+            type_id: 0,
+            elements,
+        }))
     }
 
     fn parse_object(&mut self) -> VmResult<Value> {
-        debug_assert_eq!(self.bytes[self.pos], b'{');
-        self.pos += 1;
-        self.skip_whitespace();
+        self.source.bump();
+        self.skip_whitespace()?;
 
         let mut entries = Vec::new();
-
-        if self.pos < self.bytes.len() && self.bytes[self.pos] == b'}' {
-            self.pos += 1;
-            return self.allocate_object(entries);
+        if self.peek()? == Some(b'}') {
+            self.source.bump();
+            return Ok(self.allocate_object(entries));
         }
 
         loop {
-            self.skip_whitespace();
-            if self.pos >= self.bytes.len() || self.bytes[self.pos] != b'"' {
-                return Err(VmError::RuntimeError(format!(
-                    "Expected string key at position {}",
-                    self.pos
-                )));
+            self.skip_whitespace()?;
+            if self.peek()? != Some(b'"') {
+                return Err(self.error("Expected string key"));
             }
+            let key = self.read_string()?;
 
-            let key = self.read_string_data()?;
-
-            self.skip_whitespace();
-            if self.pos >= self.bytes.len() || self.bytes[self.pos] != b':' {
-                return Err(VmError::RuntimeError(format!(
-                    "Expected ':' after object key at position {}",
-                    self.pos
-                )));
+            self.skip_whitespace()?;
+            if self.peek()? != Some(b':') {
+                return Err(self.error("Expected ':' after object key"));
             }
-            self.pos += 1;
+            self.source.bump();
 
-            self.skip_whitespace();
             let value = self.parse_value()?;
             entries.push((key, value));
 
-            self.skip_whitespace();
-
-            if self.pos >= self.bytes.len() {
-                return Err(VmError::RuntimeError("Unterminated object".to_string()));
-            }
-
-            match self.bytes[self.pos] {
-                b',' => {
-                    self.pos += 1;
-                    self.skip_whitespace();
-                }
-                b'}' => {
-                    self.pos += 1;
-                    return self.allocate_object(entries);
+            self.skip_whitespace()?;
+            match self.peek()? {
+                Some(b',') => self.source.bump(),
+                Some(b'}') => {
+                    self.source.bump();
+                    return Ok(self.allocate_object(entries));
                 }
-                c => {
-                    return Err(VmError::RuntimeError(format!(
-                        "Expected ',' or '}}' in object, got '{}' at position {}",
-                        c as char, self.pos
+                Some(c) => {
+                    return Err(self.error(&format!(
+                        "Expected ',' or '}}' in object, got '{}'",
+                        c as char
                     )))
                 }
+                None => return Err(self.error("Unterminated object")),
             }
         }
     }
 
-    fn allocate_object(&mut self, entries: Vec<(String, Value)>) -> VmResult<Value> {
-        if let Some(intern_prop_key) = self.intern_prop_key.as_deref_mut() {
+    /// Allocate a parsed JSON object from its `(key, value)` entries.
+    ///
+    /// With a key interner the entries go into the dynamic property lane;
+    /// otherwise they become structural fields in sorted key order.
+    fn allocate_object(&mut self, entries: Vec<(String, Value)>) -> Value {
+        let obj = if let Some(intern_prop_key) = self.intern_prop_key.as_deref_mut() {
             let mut obj = Object::new_dynamic(layout_id_from_ordered_names(&[]), 0);
             {
                 let dyn_map = obj.ensure_dyn_map();
@@ -423,10 +386,12 @@ impl<'a> Parser<'a> {
                     dyn_map.insert(intern_prop_key(&key), value);
                 }
             }
-            let obj_ptr = self.gc.allocate(obj);
-            Ok(unsafe { Value::from_ptr(std::ptr::NonNull::new(obj_ptr.as_ptr()).unwrap()) })
+            obj
         } else {
-            let mut field_names = entries.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+            let mut field_names = entries
+                .iter()
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
             field_names.sort_unstable();
             field_names.dedup();
             let layout_id = layout_id_from_ordered_names(&field_names);
@@ -437,31 +402,16 @@ impl<'a> Parser<'a> {
                     let _ = obj.set_field(index, value);
                 }
             }
-            let obj_ptr = self.gc.allocate(obj);
-            Ok(unsafe { Value::from_ptr(std::ptr::NonNull::new(obj_ptr.as_ptr()).unwrap()) })
-        }
-    }
-
-    fn skip_whitespace(&mut self) {
-        while self.pos < self.bytes.len() {
-            match self.bytes[self.pos] {
-                b' ' | b'\t' | b'\n' | b'\r' => self.pos += 1,
-                _ => break,
-            }
-        }
+            obj
+        };
+        self.allocate(obj)
     }
 
-    fn consume_literal(&mut self, literal: &str) -> bool {
-        let lb = literal.as_bytes();
-        if self.pos + lb.len() > self.bytes.len() {
-            return false;
-        }
-        if &self.bytes[self.pos..self.pos + lb.len()] == lb {
-            self.pos += lb.len();
-            true
-        } else {
-            false
+    fn skip_whitespace(&mut self) -> VmResult<()> {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek()? {
+            self.source.bump();
         }
+        Ok(())
     }
 }
 
@@ -506,7 +456,7 @@ mod tests {
         let mut gc = GarbageCollector::default();
         let result = parse("\"hello\"", &mut gc).unwrap();
         match js_classify(result) {
-            JSView::Str(ptr) => assert_eq!(unsafe { &*ptr }.data, "hello"),
+            JSView::Str(ptr) => assert_eq!(unsafe { &*ptr }.as_str(), "hello"),
             _ => panic!("Expected string"),
         }
     }
@@ -516,7 +466,7 @@ mod tests {
         let mut gc = GarbageCollector::default();
         let result = parse("\"hello\\nworld\"", &mut gc).unwrap();
         match js_classify(result) {
-            JSView::Str(ptr) => assert_eq!(unsafe { &*ptr }.data, "hello\nworld"),
+            JSView::Str(ptr) => assert_eq!(unsafe { &*ptr }.as_str(), "hello\nworld"),
             _ => panic!("Expected string"),
         }
     }
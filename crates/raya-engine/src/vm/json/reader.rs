@@ -0,0 +1,152 @@
+//! Streaming JSON parser over `std::io::Read`
+//!
+//! Runs [`super::parser`] over a fixed-size buffer refilled from the reader
+//! instead of requiring the whole document as a `&str`. Errors report the
+//! absolute byte offset in the stream where parsing failed.
+
+use super::parser::{parse_source, Source};
+use crate::vm::gc::GarbageCollector;
+use crate::vm::value::Value;
+use crate::vm::{VmError, VmResult};
+use std::io::{ErrorKind, Read};
+
+/// Bytes pulled from the reader per refill
+const READ_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Parse one JSON document from `reader` into a native VM `Value`.
+///
+/// Only whitespace may follow the document. The reader is consumed in
+/// chunks; wrap it in a `BufReader` only if small reads are expensive.
+pub fn parse_reader<R: Read>(reader: R, gc: &mut GarbageCollector) -> VmResult<Value> {
+    parse_source(ReadSource::new(reader), gc, None)
+}
+
+/// A document pulled from a reader through a fixed-size buffer
+struct ReadSource<R> {
+    reader: R,
+    buffer: Box<[u8]>,
+    /// Number of valid bytes in `buffer`
+    len: usize,
+    /// Read position within `buffer`
+    pos: usize,
+    /// Stream offset of `buffer[0]`
+    base: usize,
+}
+
+impl<R: Read> ReadSource<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: vec![0; READ_CHUNK_BYTES].into_boxed_slice(),
+            len: 0,
+            pos: 0,
+            base: 0,
+        }
+    }
+}
+
+impl<R: Read> Source for ReadSource<R> {
+    /// Look at the next byte, refilling the buffer as needed
+    fn peek(&mut self) -> VmResult<Option<u8>> {
+        if self.pos == self.len {
+            self.base += self.len;
+            self.pos = 0;
+            self.len = loop {
+                match self.reader.read(&mut self.buffer) {
+                    Ok(n) => break n,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        return Err(VmError::IoError(format!(
+                            "{} at byte offset {}",
+                            e, self.base
+                        )))
+                    }
+                }
+            };
+            if self.len == 0 {
+                return Ok(None);
+            }
+        }
+        Ok(Some(self.buffer[self.pos]))
+    }
+
+    fn bump(&mut self) {
+        self.pos += 1;
+    }
+
+    fn offset(&self) -> usize {
+        self.base + self.pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::json::parser::parse;
+    use crate::vm::json::stringify::stringify;
+    use crate::vm::json::view::{js_classify, JSView};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_parse_reader_multi_megabyte_array() {
+        let count = 300_000;
+        let mut json = String::from("[");
+        for i in 0..count {
+            if i > 0 {
+                json.push_str(", ");
+            }
+            json.push_str(&format!("{}.5", i));
+        }
+        json.push(']');
+        assert!(json.len() > 2 * 1024 * 1024);
+
+        let mut gc = GarbageCollector::default();
+        let value = parse_reader(Cursor::new(json.into_bytes()), &mut gc).unwrap();
+        match js_classify(value) {
+            JSView::Arr(ptr) => {
+                let arr = unsafe { &*ptr };
+                assert_eq!(arr.len(), count);
+                assert_eq!(arr.get(0).and_then(|v| v.as_f64()), Some(0.5));
+                let last = arr.get(count - 1).and_then(|v| v.as_f64());
+                assert_eq!(last, Some(count as f64 - 0.5));
+            }
+            _ => panic!("Expected array"),
+        }
+    }
+
+    #[test]
+    fn test_parse_reader_matches_str_parser() {
+        let json = r#"{"name": "Alïce\n", "tags": ["a", "b"], "n": -1.5e3, "ok": true}"#;
+        let mut gc = GarbageCollector::default();
+        let streamed = parse_reader(Cursor::new(json), &mut gc).unwrap();
+        let parsed = parse(json, &mut gc).unwrap();
+        assert_eq!(stringify(streamed).unwrap(), stringify(parsed).unwrap());
+    }
+
+    #[test]
+    fn test_parse_reader_error_offsets() {
+        let mut gc = GarbageCollector::default();
+        let cases = [
+            ("[1, 2,, 3]", 6),
+            ("{\"a\" 1}", 5),
+            ("[1, 2", 5),
+            ("tru", 0),
+            ("[1] x", 4),
+        ];
+        for (json, offset) in cases {
+            let err = parse_reader(Cursor::new(json), &mut gc).unwrap_err();
+            let expected = format!("at byte offset {}", offset);
+            assert!(err.to_string().contains(&expected), "{json}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_parse_reader_error_offset_past_first_chunk() {
+        let mut json = " ".repeat(READ_CHUNK_BYTES + 10);
+        json.push('?');
+        let mut gc = GarbageCollector::default();
+        let err = parse_reader(Cursor::new(json), &mut gc).unwrap_err();
+        let expected = format!("at byte offset {}", READ_CHUNK_BYTES + 10);
+        assert!(err.to_string().contains(&expected), "{err}");
+    }
+}
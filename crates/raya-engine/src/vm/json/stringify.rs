@@ -2,6 +2,9 @@
 //!
 //! Converts a VM `Value` to a JSON string representation with proper escaping.
 //! Uses `js_classify()` as the single dispatch entry point.
+//!
+//! Output goes through a [`JsonSink`], so the same serializer either builds a
+//! `String` or streams fixed-size chunks to an `io::Write`.
 
 use super::view::{js_classify, JSView};
 use crate::vm::object::{global_layout_names, LayoutId, PropKeyId};
@@ -9,6 +12,86 @@ use crate::vm::value::Value;
 use crate::vm::{VmError, VmResult};
 use std::fmt::Write;
 
+/// Buffered output size before a streaming write flushes to its writer
+const WRITE_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Destination for serialized JSON text
+trait JsonSink: Write {
+    fn push(&mut self, ch: char);
+    fn push_str(&mut self, s: &str);
+}
+
+impl JsonSink for String {
+    fn push(&mut self, ch: char) {
+        String::push(self, ch);
+    }
+
+    fn push_str(&mut self, s: &str) {
+        String::push_str(self, s);
+    }
+}
+
+/// Sink that buffers output and writes it to an `io::Write` in chunks
+///
+/// The first I/O error is kept and reported once serialization finishes.
+struct IoSink<W: std::io::Write> {
+    writer: W,
+    buffer: String,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write> IoSink<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buffer: String::with_capacity(WRITE_CHUNK_BYTES),
+            error: None,
+        }
+    }
+
+    fn flush_buffer(&mut self) {
+        if self.error.is_none() {
+            if let Err(e) = self.writer.write_all(self.buffer.as_bytes()) {
+                self.error = Some(e);
+            }
+        }
+        self.buffer.clear();
+    }
+
+    fn finish(mut self) -> VmResult<()> {
+        self.flush_buffer();
+        if let Some(e) = self.error.take() {
+            return Err(VmError::IoError(e.to_string()));
+        }
+        self.writer
+            .flush()
+            .map_err(|e| VmError::IoError(e.to_string()))
+    }
+}
+
+impl<W: std::io::Write> Write for IoSink<W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        JsonSink::push_str(self, s);
+        Ok(())
+    }
+}
+
+impl<W: std::io::Write> JsonSink for IoSink<W> {
+    fn push(&mut self, ch: char) {
+        self.buffer.push(ch);
+        if self.buffer.len() >= WRITE_CHUNK_BYTES {
+            self.flush_buffer();
+        }
+    }
+
+    fn push_str(&mut self, s: &str) {
+        self.buffer.push_str(s);
+        if self.buffer.len() >= WRITE_CHUNK_BYTES {
+            self.flush_buffer();
+        }
+    }
+}
+
 /// Convert a VM `Value` to a JSON string.
 ///
 /// Class metadata for struct serialization is optional.  When not provided
@@ -41,23 +124,68 @@ where
     FL: FnMut(LayoutId) -> Option<Vec<String>>,
 {
     let mut output = String::new();
+    let mut ancestors = Vec::new();
     stringify_impl(
         value,
         &mut output,
+        &mut ancestors,
         &mut resolve_prop_key,
         &mut resolve_layout_names,
     )?;
     Ok(output)
 }
 
+/// Serialize a VM `Value` as JSON straight to `writer`.
+///
+/// Output is flushed in fixed-size chunks, so the full document is never
+/// held in memory. Uses the same metadata as [`stringify`].
+pub fn stringify_to_writer<W: std::io::Write>(value: Value, writer: W) -> VmResult<()> {
+    stringify_to_writer_with_runtime_metadata(
+        value,
+        writer,
+        |_| None,
+        |layout_id| global_layout_names(layout_id),
+    )
+}
+
+/// Streaming counterpart of [`stringify_with_runtime_metadata`].
+pub fn stringify_to_writer_with_runtime_metadata<W, FP, FL>(
+    value: Value,
+    writer: W,
+    mut resolve_prop_key: FP,
+    mut resolve_layout_names: FL,
+) -> VmResult<()>
+where
+    W: std::io::Write,
+    FP: FnMut(PropKeyId) -> Option<String>,
+    FL: FnMut(LayoutId) -> Option<Vec<String>>,
+{
+    let mut sink = IoSink::new(writer);
+    let mut ancestors = Vec::new();
+    stringify_impl(
+        value,
+        &mut sink,
+        &mut ancestors,
+        &mut resolve_prop_key,
+        &mut resolve_layout_names,
+    )?;
+    sink.finish()
+}
+
 /// Internal recursive stringification
-fn stringify_impl<FP, FL>(
+///
+/// `ancestors` holds the arrays/objects currently being serialized, so a
+/// container that (transitively) contains itself is reported instead of
+/// recursing forever.
+fn stringify_impl<S, FP, FL>(
     value: Value,
-    output: &mut String,
+    output: &mut S,
+    ancestors: &mut Vec<usize>,
     resolve_prop_key: &mut FP,
     resolve_layout_names: &mut FL,
 ) -> VmResult<()>
 where
+    S: JsonSink,
     FP: FnMut(PropKeyId) -> Option<String>,
     FL: FnMut(LayoutId) -> Option<Vec<String>>,
 {
@@ -86,23 +214,32 @@ where
         JSView::Str(ptr) => {
             let s = unsafe { &*ptr };
             output.push('"');
-            escape_string(&s.data, output);
+            escape_into(s.as_str(), output);
             output.push('"');
         }
 
         JSView::Arr(ptr) => {
+            enter_container(ancestors, ptr as usize)?;
             let arr = unsafe { &*ptr };
             output.push('[');
             for (i, elem) in arr.elements.iter().enumerate() {
                 if i > 0 {
                     output.push(',');
                 }
-                stringify_impl(*elem, output, resolve_prop_key, resolve_layout_names)?;
+                stringify_impl(
+                    *elem,
+                    output,
+                    ancestors,
+                    resolve_prop_key,
+                    resolve_layout_names,
+                )?;
             }
             output.push(']');
+            ancestors.pop();
         }
 
         JSView::Struct { ptr, layout_id, .. } => {
+            enter_container(ancestors, ptr as usize)?;
             let obj = unsafe { &*ptr };
             let fixed_names = resolve_layout_names(layout_id);
             if fixed_names.is_some() || obj.dyn_map().is_some() {
@@ -116,9 +253,15 @@ where
                     }
                     first = false;
                     output.push('"');
-                    escape_string(name, output);
+                    escape_into(name, output);
                     output.push_str("\":");
-                    stringify_impl(value, output, resolve_prop_key, resolve_layout_names)?;
+                    stringify_impl(
+                        value,
+                        output,
+                        ancestors,
+                        resolve_prop_key,
+                        resolve_layout_names,
+                    )?;
                 }
                 if let Some(dyn_map) = obj.dyn_map() {
                     for (key, val) in dyn_map {
@@ -133,9 +276,15 @@ where
                         }
                         first = false;
                         output.push('"');
-                        escape_string(&name, output);
+                        escape_into(&name, output);
                         output.push_str("\":");
-                        stringify_impl(*val, output, resolve_prop_key, resolve_layout_names)?;
+                        stringify_impl(
+                            *val,
+                            output,
+                            ancestors,
+                            resolve_prop_key,
+                            resolve_layout_names,
+                        )?;
                     }
                 }
                 output.push('}');
@@ -143,6 +292,7 @@ where
                 // Without any layout metadata we still cannot enumerate fixed slots.
                 output.push_str("null");
             }
+            ancestors.pop();
         }
 
         JSView::Other => {
@@ -153,8 +303,23 @@ where
     Ok(())
 }
 
+/// Push a container onto the ancestor stack, rejecting circular structures.
+fn enter_container(ancestors: &mut Vec<usize>, addr: usize) -> VmResult<()> {
+    if ancestors.contains(&addr) {
+        return Err(VmError::RuntimeError(
+            "Converting circular structure to JSON".to_string(),
+        ));
+    }
+    ancestors.push(addr);
+    Ok(())
+}
+
 /// Escape a string for JSON output.
 pub fn escape_string(s: &str, output: &mut String) {
+    escape_into(s, output);
+}
+
+fn escape_into<S: JsonSink>(s: &str, output: &mut S) {
     for ch in s.chars() {
         match ch {
             '"' => output.push_str("\\\""),
@@ -178,7 +343,7 @@ mod tests {
     use super::*;
     use crate::vm::gc::GarbageCollector;
     use crate::vm::json::parser;
-    use crate::vm::object::RayaString;
+    use crate::vm::object::{Array, RayaString};
 
     fn make_string(gc: &mut GarbageCollector, s: &str) -> Value {
         let raya_str = RayaString::new(s.to_string());
@@ -246,6 +411,26 @@ mod tests {
         assert!(stringify(Value::f64(f64::INFINITY)).is_err());
     }
 
+    #[test]
+    fn test_stringify_circular_array_error() {
+        let mut gc = GarbageCollector::default();
+        let arr = parser::parse("[1]", &mut gc).unwrap();
+        let ptr = unsafe { arr.as_ptr::<Array>() }.unwrap();
+        unsafe { (*ptr.as_ptr()).elements.push(arr) };
+        let err = stringify(arr).unwrap_err();
+        assert!(err.to_string().contains("circular structure"), "{err}");
+    }
+
+    #[test]
+    fn test_stringify_repeated_non_circular_reference() {
+        let mut gc = GarbageCollector::default();
+        let inner = parser::parse("[1]", &mut gc).unwrap();
+        let outer = parser::parse("[]", &mut gc).unwrap();
+        let ptr = unsafe { outer.as_ptr::<Array>() }.unwrap();
+        unsafe { (*ptr.as_ptr()).elements.extend([inner, inner]) };
+        assert_eq!(stringify(outer).unwrap(), "[[1],[1]]");
+    }
+
     #[test]
     fn test_round_trip() {
         let mut gc = GarbageCollector::default();
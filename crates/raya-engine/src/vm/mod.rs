@@ -19,6 +19,7 @@ pub mod interpreter;
 pub mod json;
 pub mod module;
 pub mod native_handler;
+pub mod native_policy;
 pub mod native_registry;
 pub mod object;
 pub mod reflect;
@@ -57,18 +58,20 @@ pub use abi::{
     task_spawn,
     value_to_native,
     EngineContext,
+    NativeHeapScope,
 };
 
 pub use interpreter::{
-    ClassRegistry, ContextRegistry, ResourceCounters, ResourceLimits, Vm, VmContext, VmContextId,
-    VmOptions, VmStats,
+    ClassRegistry, ContextRegistry, HandleLimits, ResourceCounters, ResourceLimits, Vm, VmContext,
+    VmContextId, VmOptions, VmStats,
 };
 pub use json::{validate_cast, JsonValue, TypeKind, TypeSchema, TypeSchemaRegistry};
+pub use native_policy::{NativeCallDecision, NativeCallInfo, NativeCallPolicy};
 pub use native_registry::{NativeFn, NativeFunctionRegistry, ResolvedNatives};
 pub use object::{Array, BoundMethod, Class, Object, RayaString, VTable};
 pub use scheduler::Scheduler;
 pub use snapshot::{SnapshotReader, SnapshotWriter};
-pub use stack::{CallFrame, Stack, StackStats};
+pub use stack::{CallFrame, Stack, StackConfig, StackGrowth, StackStats};
 pub use sync::{
     Mutex, MutexError, MutexId, MutexRegistry, Semaphore, SemaphoreError, SemaphoreId,
     SemaphoreRegistry,
@@ -80,8 +83,11 @@ pub use value::Value;
 #[derive(Debug, thiserror::Error)]
 pub enum VmError {
     /// Stack overflow
-    #[error("Stack overflow")]
-    StackOverflow,
+    #[error("Stack overflow (call depth {depth})")]
+    StackOverflow {
+        /// Call depth at the point the stack limit was hit
+        depth: usize,
+    },
 
     /// Stack underflow
     #[error("Stack underflow")]
@@ -131,6 +137,102 @@ pub enum VmError {
     /// Task suspended waiting for another task (yield to allow other tasks to run)
     #[error("Task suspended")]
     Suspended,
+
+    /// Execution exceeded the VM's configured timeout
+    #[error("Execution timed out after {timeout_ms}ms")]
+    Timeout {
+        /// Configured timeout in milliseconds
+        timeout_ms: u64,
+    },
+
+    /// Execution stopped because the host raised its cancellation flag
+    #[error("Execution cancelled")]
+    Cancelled,
+
+    /// Native call refused by the VM's native call policy
+    #[error("Security error: {0}")]
+    SecurityError(String),
+
+    /// Error raised at a known source location (resolved from `DebugInfo`)
+    #[error("{error} at {location}")]
+    Located {
+        /// The underlying error
+        error: Box<VmError>,
+        /// Source location of the faulting instruction
+        location: SourceLocation,
+    },
+}
+
+impl VmError {
+    /// Attach a source location to this error.
+    ///
+    /// Errors that already carry a location keep the original (innermost) one.
+    pub fn with_location(self, location: SourceLocation) -> Self {
+        match self {
+            located @ VmError::Located { .. } => located,
+            error => VmError::Located {
+                error: Box::new(error),
+                location,
+            },
+        }
+    }
+
+    /// Source location of the faulting instruction, if known
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            VmError::Located { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// The underlying error with any location wrapper removed
+    pub fn root(&self) -> &VmError {
+        match self {
+            VmError::Located { error, .. } => error.root(),
+            error => error,
+        }
+    }
+}
+
+/// Source position of a bytecode instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Source file path (None when the module carries no file table)
+    pub file: Option<String>,
+    /// Line number (1-indexed)
+    pub line: u32,
+    /// Column number (1-indexed)
+    pub column: u32,
+}
+
+impl SourceLocation {
+    /// Resolve the source location of `bytecode_offset` within a function
+    /// from the module's debug info.
+    pub fn resolve(
+        module: &crate::compiler::Module,
+        func_id: usize,
+        bytecode_offset: u32,
+    ) -> Option<Self> {
+        let debug_info = module.debug_info.as_ref()?;
+        let function = debug_info.functions.get(func_id)?;
+        let entry = function.lookup_location(bytecode_offset)?;
+        Some(Self {
+            file: debug_info
+                .get_source_file(function.source_file_index)
+                .map(str::to_string),
+            line: entry.line,
+            column: entry.column,
+        })
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file, self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
 }
 
 /// VM execution result
@@ -457,6 +457,7 @@ mod tests {
             checksum: [0; 32],
             native_functions: vec![],
             jit_hints: vec![],
+            json_schemas: vec![],
             reflection: None,
             debug_info: None,
         }
@@ -0,0 +1,248 @@
+//! Native call policy
+//!
+//! A `NativeCallPolicy` is consulted by the interpreter before every native
+//! dispatch (`NativeCall` and `ModuleNativeCall`). It sees the native's name,
+//! its builtin id (if any) and the call arguments, and decides whether the
+//! call runs. Denied calls raise `VmError::SecurityError`, which scripts can
+//! catch like any other runtime error.
+//!
+//! Presets cover the common sandboxing cases:
+//! - `NativeCallPolicy::allow_all()` - transparent, every call runs
+//! - `NativeCallPolicy::no_fs()` - deny filesystem natives
+//! - `NativeCallPolicy::no_net()` - deny network natives
+//! - `NativeCallPolicy::log_all()` - run every call and log it to stderr
+//!
+//! Calls a policy decides to `Log` go to its audit sink (`with_audit`); a
+//! policy without one logs nothing.
+//!
+//! Policies compose with `and`, e.g. `NativeCallPolicy::no_fs().and(NativeCallPolicy::no_net())`.
+
+use crate::vm::json::{js_classify, JSView};
+use crate::vm::value::Value;
+use std::fmt;
+use std::sync::Arc;
+
+/// Native name prefixes denied by `NativeCallPolicy::no_fs()`
+pub const FS_NATIVE_PREFIXES: &[&str] = &["fs.", "glob.", "archive.", "watch."];
+
+/// Native name prefixes denied by `NativeCallPolicy::no_net()`
+pub const NET_NATIVE_PREFIXES: &[&str] = &["net.", "http.", "http2.", "fetch.", "dns.", "ws."];
+
+/// A native call about to be dispatched
+#[derive(Debug, Clone, Copy)]
+pub struct NativeCallInfo<'a> {
+    /// Native name, e.g. `"fs.readFile"` or `"string.charAt"`
+    pub name: &'a str,
+    /// Builtin native id for `NativeCall`; `None` for module natives linked by name
+    pub id: Option<u16>,
+    /// Call arguments (receiver first for method natives)
+    pub args: &'a [Value],
+}
+
+impl NativeCallInfo<'_> {
+    /// Read argument `index` as a string, if it is one
+    pub fn string_arg(&self, index: usize) -> Option<String> {
+        let value = *self.args.get(index)?;
+        if !value.is_ptr() {
+            return None;
+        }
+        match js_classify(value) {
+            JSView::Str(ptr) => Some(unsafe { &*ptr }.as_str().to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// What the VM should do with a native call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NativeCallDecision {
+    /// Dispatch the call
+    Allow,
+    /// Dispatch the call and report it to the policy's audit sink
+    Log,
+    /// Refuse the call and raise a security error with this reason
+    Deny(String),
+}
+
+type PolicyFn = dyn Fn(&NativeCallInfo<'_>) -> NativeCallDecision + Send + Sync;
+type AuditFn = dyn Fn(&NativeCallInfo<'_>) + Send + Sync;
+
+/// Callback deciding whether native calls may run
+#[derive(Clone)]
+pub struct NativeCallPolicy {
+    check: Arc<PolicyFn>,
+    audit: Option<Arc<AuditFn>>,
+}
+
+impl NativeCallPolicy {
+    /// Create a policy from a callback
+    pub fn new<F>(check: F) -> Self
+    where
+        F: Fn(&NativeCallInfo<'_>) -> NativeCallDecision + Send + Sync + 'static,
+    {
+        Self {
+            check: Arc::new(check),
+            audit: None,
+        }
+    }
+
+    /// Report calls this policy decides to `Log` to `sink`
+    pub fn with_audit<F>(mut self, sink: F) -> Self
+    where
+        F: Fn(&NativeCallInfo<'_>) + Send + Sync + 'static,
+    {
+        self.audit = Some(Arc::new(sink));
+        self
+    }
+
+    /// Allow every native call
+    pub fn allow_all() -> Self {
+        Self::new(|_| NativeCallDecision::Allow)
+    }
+
+    /// Allow every native call and log it to stderr
+    pub fn log_all() -> Self {
+        Self::new(|_| NativeCallDecision::Log).with_audit(|call| {
+            eprintln!("[native-policy] {} ({} args)", call.name, call.args.len())
+        })
+    }
+
+    /// Deny filesystem natives (`fs.*`, `glob.*`, `archive.*`, `watch.*`)
+    pub fn no_fs() -> Self {
+        Self::deny_prefixes(FS_NATIVE_PREFIXES, "filesystem access is disabled")
+    }
+
+    /// Deny network natives (`net.*`, `http.*`, `http2.*`, `fetch.*`, `dns.*`, `ws.*`)
+    pub fn no_net() -> Self {
+        Self::deny_prefixes(NET_NATIVE_PREFIXES, "network access is disabled")
+    }
+
+    /// Deny every native whose name starts with one of `prefixes`
+    pub fn deny_prefixes(prefixes: &'static [&'static str], reason: &'static str) -> Self {
+        Self::new(move |call| {
+            if prefixes.iter().any(|prefix| call.name.starts_with(prefix)) {
+                NativeCallDecision::Deny(reason.to_string())
+            } else {
+                NativeCallDecision::Allow
+            }
+        })
+    }
+
+    /// Combine two policies: a call runs only if both allow it, and is
+    /// logged if either asks for logging. Logged calls go to the first
+    /// audit sink of the two.
+    pub fn and(self, other: NativeCallPolicy) -> Self {
+        let audit = self.audit.clone().or_else(|| other.audit.clone());
+        let mut combined = Self::new(move |call| match self.check(call) {
+            NativeCallDecision::Deny(reason) => NativeCallDecision::Deny(reason),
+            NativeCallDecision::Log => match other.check(call) {
+                NativeCallDecision::Deny(reason) => NativeCallDecision::Deny(reason),
+                _ => NativeCallDecision::Log,
+            },
+            NativeCallDecision::Allow => other.check(call),
+        });
+        combined.audit = audit;
+        combined
+    }
+
+    /// Decide what to do with `call`
+    #[inline]
+    pub fn check(&self, call: &NativeCallInfo<'_>) -> NativeCallDecision {
+        (self.check)(call)
+    }
+
+    /// Report a logged call to the audit sink, if there is one
+    pub fn audit(&self, call: &NativeCallInfo<'_>) {
+        if let Some(audit) = &self.audit {
+            audit(call);
+        }
+    }
+}
+
+impl fmt::Debug for NativeCallPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeCallPolicy").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(name: &str) -> NativeCallInfo<'_> {
+        NativeCallInfo {
+            name,
+            id: None,
+            args: &[],
+        }
+    }
+
+    #[test]
+    fn test_presets() {
+        let allow = NativeCallPolicy::allow_all();
+        assert_eq!(allow.check(&call("fs.readFile")), NativeCallDecision::Allow);
+
+        let no_fs = NativeCallPolicy::no_fs();
+        assert!(matches!(
+            no_fs.check(&call("fs.readFile")),
+            NativeCallDecision::Deny(_)
+        ));
+        assert_eq!(
+            no_fs.check(&call("fetch.request")),
+            NativeCallDecision::Allow
+        );
+        assert_eq!(no_fs.check(&call("math.abs")), NativeCallDecision::Allow);
+
+        let no_net = NativeCallPolicy::no_net();
+        assert!(matches!(
+            no_net.check(&call("net.tcpConnect")),
+            NativeCallDecision::Deny(_)
+        ));
+        assert_eq!(
+            no_net.check(&call("fs.readFile")),
+            NativeCallDecision::Allow
+        );
+    }
+
+    #[test]
+    fn test_and_combines_decisions() {
+        let policy = NativeCallPolicy::log_all()
+            .and(NativeCallPolicy::no_fs())
+            .and(NativeCallPolicy::no_net());
+        assert_eq!(policy.check(&call("math.abs")), NativeCallDecision::Log);
+        assert!(matches!(
+            policy.check(&call("fs.writeFile")),
+            NativeCallDecision::Deny(_)
+        ));
+        assert!(matches!(
+            policy.check(&call("http.serve")),
+            NativeCallDecision::Deny(_)
+        ));
+    }
+
+    #[test]
+    fn test_logged_calls_go_to_the_audit_sink() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let policy = NativeCallPolicy::new(|call| {
+            if call.name.starts_with("fs.") {
+                NativeCallDecision::Log
+            } else {
+                NativeCallDecision::Allow
+            }
+        })
+        .with_audit(move |call| sink.lock().unwrap().push(call.name.to_string()))
+        .and(NativeCallPolicy::no_net());
+
+        for name in ["fs.readFile", "math.abs"] {
+            let info = call(name);
+            if policy.check(&info) == NativeCallDecision::Log {
+                policy.audit(&info);
+            }
+        }
+        assert_eq!(*seen.lock().unwrap(), vec!["fs.readFile".to_string()]);
+
+        // Without a sink, logging is a no-op.
+        NativeCallPolicy::new(|_| NativeCallDecision::Log).audit(&call("fs.readFile"));
+    }
+}
@@ -59,7 +59,7 @@ impl ResolvedNatives {
         args: &[NativeValue],
     ) -> NativeCallResult {
         if let Some(handler) = self.handlers.get(local_idx as usize) {
-            handler(ctx, args)
+            call_native(handler, ctx, args)
         } else {
             NativeCallResult::Error(format!("Invalid native function index: {}", local_idx))
         }
@@ -76,9 +76,31 @@ impl ResolvedNatives {
     }
 }
 
+/// Call a native handler, turning an error value it returns
+/// (`NativeValue::error`) into `NativeCallResult::Error` so it is thrown
+/// rather than seen as data.
+pub fn call_native(
+    handler: &NativeFn,
+    ctx: &dyn NativeContext,
+    args: &[NativeValue],
+) -> NativeCallResult {
+    match handler(ctx, args) {
+        NativeCallResult::Value(value) if value.is_error() => {
+            NativeCallResult::Error(value.take_error_message().unwrap_or_default())
+        }
+        result => result,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vm::abi::EngineContext;
+    use crate::vm::gc::GarbageCollector;
+    use crate::vm::interpreter::{ClassRegistry, RuntimeLayoutRegistry};
+    use crate::vm::reflect::ClassMetadataRegistry;
+    use crate::vm::scheduler::TaskId;
+    use parking_lot::{Mutex, RwLock};
 
     #[test]
     fn test_registry_register_and_get() {
@@ -110,6 +132,33 @@ mod tests {
         assert!(result.unwrap_err().contains("math.unknown"));
     }
 
+    #[test]
+    fn test_resolved_natives_error_value_becomes_error() {
+        let mut registry = NativeFunctionRegistry::new();
+        registry.register("io.fail", |_ctx, _args| {
+            NativeCallResult::Value(NativeValue::error("disk full"))
+        });
+        registry.register("io.none", |_ctx, _args| {
+            NativeCallResult::Value(NativeValue::null())
+        });
+
+        let names = vec!["io.fail".to_string(), "io.none".to_string()];
+        let resolved = ResolvedNatives::link(&names, &registry).unwrap();
+        let gc = Mutex::new(GarbageCollector::default());
+        let classes = RwLock::new(ClassRegistry::default());
+        let layouts = RwLock::new(RuntimeLayoutRegistry::default());
+        let metadata = RwLock::new(ClassMetadataRegistry::default());
+        let ctx = EngineContext::new(&gc, &classes, &layouts, TaskId::from_u64(0), &metadata);
+        match resolved.call(0, &ctx, &[]) {
+            NativeCallResult::Error(message) => assert_eq!(message, "disk full"),
+            _ => panic!("expected an error result"),
+        }
+        match resolved.call(1, &ctx, &[]) {
+            NativeCallResult::Value(value) => assert!(value.is_null()),
+            _ => panic!("expected null to pass through"),
+        }
+    }
+
     #[test]
     fn test_resolved_natives_empty() {
         let resolved = ResolvedNatives::empty();
@@ -183,6 +183,16 @@ impl MetadataStore {
         })
     }
 
+    /// Check if any property of a target has metadata
+    pub fn has_any_property_metadata(&self, target: Value) -> bool {
+        let Some(id) = Self::target_id(target) else {
+            return false;
+        };
+        self.targets
+            .get(&id)
+            .is_some_and(|e| !e.properties.is_empty())
+    }
+
     /// Get all metadata keys on a property
     ///
     /// `Reflect.getMetadataKeys(target, propertyKey)`
@@ -231,7 +231,7 @@ impl SnapshotContext {
         // String
         if let Some(ptr) = unsafe { value.as_ptr::<RayaString>() } {
             let s = unsafe { &*ptr.as_ptr() };
-            return SnapshotValue::String(s.data.clone());
+            return SnapshotValue::String(s.as_str().to_string());
         }
 
         // Array
@@ -4,7 +4,7 @@
 //! values) is returned to the pool. The next spawned task acquires a recycled
 //! Stack, reusing the existing Vec capacity and avoiding re-allocation.
 
-use crate::vm::stack::Stack;
+use crate::vm::stack::{Stack, StackConfig};
 use parking_lot::Mutex;
 
 /// Pool of reusable Stack objects.
@@ -14,20 +14,42 @@ use parking_lot::Mutex;
 pub struct StackPool {
     stacks: Mutex<Vec<Stack>>,
     max_size: usize,
+    config: StackConfig,
 }
 
 impl StackPool {
     /// Create a new pool that holds up to `max_size` stacks.
     pub fn new(max_size: usize) -> Self {
+        Self::with_config(max_size, StackConfig::default())
+    }
+
+    /// Create a new pool whose freshly created stacks use `config`.
+    pub fn with_config(max_size: usize, config: StackConfig) -> Self {
         Self {
             stacks: Mutex::new(Vec::with_capacity(max_size)),
             max_size,
+            config,
         }
     }
 
+    /// Get the sizing configuration used for new stacks.
+    pub fn config(&self) -> StackConfig {
+        self.config
+    }
+
+    /// Replace the sizing configuration, dropping any pooled stacks that
+    /// were created under the previous one.
+    pub fn set_config(&mut self, config: StackConfig) {
+        self.config = config;
+        self.stacks.get_mut().clear();
+    }
+
     /// Get a stack from the pool, or create a new one.
     pub fn acquire(&self) -> Stack {
-        self.stacks.lock().pop().unwrap_or_default()
+        self.stacks
+            .lock()
+            .pop()
+            .unwrap_or_else(|| Stack::with_config(self.config))
     }
 
     /// Return a stack to the pool for reuse.
@@ -87,4 +109,18 @@ mod tests {
         let c = pool.acquire(); // pool empty — creates fresh
         assert_eq!(c.depth(), 0);
     }
+
+    #[test]
+    fn test_pool_uses_config_for_fresh_stacks() {
+        let config = StackConfig {
+            initial_size: 32,
+            max_size: 128,
+            ..Default::default()
+        };
+        let pool = StackPool::with_config(2, config);
+
+        let stack = pool.acquire();
+        assert_eq!(stack.max_size(), 128);
+        assert!(stack.stats().capacity >= 32);
+    }
 }
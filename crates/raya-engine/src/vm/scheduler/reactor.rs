@@ -10,6 +10,7 @@ use crate::vm::interpreter::{ExecutionResult, Interpreter, PromiseMicrotask, Sha
 use crate::vm::object::{Buffer, ChannelObject, Class, Object, RayaString};
 use crate::vm::scheduler::{SuspendReason, Task, TaskId, TaskState};
 use crate::vm::value::Value;
+use crate::vm::VmError;
 use crossbeam::channel::{self, Receiver, Sender, TryRecvError, TrySendError};
 use raya_sdk::{IoCompletion, IoRequest};
 use std::cmp::Ordering;
@@ -343,10 +344,19 @@ impl Reactor {
                 &state.stack_pool,
             );
 
+            let native_policy = state.native_policy.lock().clone();
+
             // Wire JIT code cache and profiling for native dispatch
             #[cfg(feature = "jit")]
             {
-                let cache = state.code_cache.lock().clone();
+                // JIT code dispatches natives without consulting the native
+                // call policy and records no coverage, so sandboxed and
+                // coverage-collecting VMs stay in the interpreter.
+                let cache = if native_policy.is_some() || state.coverage.lock().is_some() {
+                    None
+                } else {
+                    state.code_cache.lock().clone()
+                };
                 interpreter.set_code_cache(cache);
 
                 // Wire profiling for on-the-fly compilation
@@ -374,12 +384,31 @@ impl Reactor {
                 interpreter.set_profiler(Some(profiler.clone()));
             }
 
+            // Wire coverage recorder for line/branch coverage
+            if let Some(ref coverage) = *state.coverage.lock() {
+                interpreter.set_coverage(Some(coverage.clone()));
+            }
+
             // Wire debug state for debugger coordination
             if let Some(ref ds) = *state.debug_state.lock() {
                 interpreter.set_debug_state(Some(ds.clone()));
             }
 
+            interpreter.set_handle_limits(Some(&state.handle_limits));
+            interpreter.set_deadline(*state.execution_deadline.lock());
+            interpreter.set_cancel_flag(state.cancel_flag.lock().clone());
+            interpreter.set_program_args(Some(&state.program_args));
+            interpreter.set_exit_hooks(Some(&state.exit_hooks));
+            interpreter.set_native_policy(native_policy);
+            interpreter
+                .set_deterministic_float(state.deterministic_float.load(AtomicOrdering::Relaxed));
+            interpreter.set_field_inline_cache(state.field_inline_caches.acquire());
+
             let result = interpreter.run(&task);
+            interpreter.flush_coverage();
+            state
+                .field_inline_caches
+                .release(interpreter.take_field_inline_cache());
 
             // Signal debug state for terminal results (completion/failure)
             interpreter.signal_debug_result(&result);
@@ -470,8 +499,12 @@ impl Reactor {
             while let Some(entry) = timer_heap.peek() {
                 if entry.wake_at <= now {
                     let entry = timer_heap.pop().unwrap();
-                    if entry.task.state() == TaskState::Suspended {
-                        entry.task.set_state(TaskState::Resumed);
+                    // A sleeper woken early by `TaskCancel` leaves a stale entry behind.
+                    let still_sleeping = matches!(
+                        entry.task.suspend_reason(),
+                        Some(SuspendReason::Sleep { wake_at }) if wake_at == entry.wake_at
+                    );
+                    if still_sleeping && entry.task.resume_if_pending() {
                         entry.task.clear_suspend_reason();
                         ready_queue.push_back(entry.task);
                     }
@@ -736,6 +769,7 @@ impl Reactor {
                         | SuspendReason::MutexLockCall { .. }
                         | SuspendReason::SemaphoreAcquire { .. }
                         | SuspendReason::AwaitTask(_)
+                        | SuspendReason::ModuleInit
                 ) {
                     // These suspends can race with a wakeup performed by another
                     // worker. Park the task only if it is still Running, then
@@ -766,7 +800,8 @@ impl Reactor {
                                 }
                             }
                             SuspendReason::SemaphoreAcquire { .. }
-                            | SuspendReason::AwaitTask(_) => {}
+                            | SuspendReason::AwaitTask(_)
+                            | SuspendReason::ModuleInit => {}
                             _ => unreachable!(),
                         }
                     }
@@ -777,14 +812,24 @@ impl Reactor {
                             // Waiter registration already done by Interpreter
                         }
                         SuspendReason::Sleep { wake_at } => {
-                            timer_heap.push(SleepEntry {
-                                wake_at,
-                                task: vr.task,
-                            });
+                            // Cancelled sleepers unwind now rather than at their
+                            // deadline; task scopes wait for cancelled children.
+                            // Cancels that land after this point wake the task
+                            // from `TaskCancel` itself.
+                            if vr.task.is_cancelled() && vr.task.resume_if_pending() {
+                                vr.task.clear_suspend_reason();
+                                ready_queue.push_back(vr.task);
+                            } else {
+                                timer_heap.push(SleepEntry {
+                                    wake_at,
+                                    task: vr.task,
+                                });
+                            }
                         }
                         SuspendReason::MutexLock { .. } => unreachable!(),
                         SuspendReason::MutexLockCall { .. } => unreachable!(),
                         SuspendReason::SemaphoreAcquire { .. } => unreachable!(),
+                        SuspendReason::ModuleInit => unreachable!(),
                         SuspendReason::ChannelSend { channel_id, value } => {
                             channel_waiters.push(ChannelWaiter {
                                 task_id: vr.task.id(),
@@ -808,11 +853,23 @@ impl Reactor {
                 }
             }
             ExecutionResult::Failed(e) => {
-                let msg = e.to_string();
+                // The interpreter tracks calls outside `Stack`'s own frames,
+                // so report overflow depth from the task's call stack.
+                let e = match e {
+                    VmError::StackOverflow { .. } => VmError::StackOverflow {
+                        depth: vr.task.call_frame_count(),
+                    },
+                    other => other,
+                };
+                // Keep the location structured on the task rather than baking
+                // it into the exception message seen by awaiters.
+                vr.task.set_failure_location(e.location().cloned());
+                let msg = e.root().to_string();
                 let exc = shared_state.allocate_ephemerally_rooted_string(msg);
                 vr.task.set_exception(exc);
                 shared_state.release_ephemeral_gc_root(exc);
                 vr.task.fail();
+                shared_state.abandon_module_inits(vr.task.id());
                 Self::wake_waiters(shared_state, &vr.task, ready_queue);
                 Self::track_unhandled_rejection(shared_state, &vr.task);
                 // Return the stack to the pool for reuse by future tasks
@@ -996,12 +1053,59 @@ impl Reactor {
                     tx,
                 );
             }
+            IoRequest::FdReadable { fd } => {
+                Self::spawn_fd_readable(tokio_runtime, sub.task_id, fd, io_completion_tx.clone());
+            }
             // Sleep is dispatched as BlockingWork (thread::sleep on IO pool).
             // No special reactor handling needed — the IO pool returns the completion.
             IoRequest::Sleep { .. } => unreachable!("Sleep should be dispatched as BlockingWork"),
         }
     }
 
+    /// Complete `task_id` once `fd` becomes readable, using the tokio poller.
+    #[cfg(unix)]
+    fn spawn_fd_readable(
+        tokio_runtime: &Handle,
+        task_id: TaskId,
+        fd: i32,
+        completion_tx: Sender<IoPoolCompletion>,
+    ) {
+        /// Borrowed fd: registering it does not take ownership or close it.
+        struct WatchedFd(std::os::unix::io::RawFd);
+
+        impl std::os::unix::io::AsRawFd for WatchedFd {
+            fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+                self.0
+            }
+        }
+
+        tokio_runtime.spawn(async move {
+            let watched = tokio::io::unix::AsyncFd::with_interest(
+                WatchedFd(fd),
+                tokio::io::Interest::READABLE,
+            );
+            let result = match watched {
+                Ok(watched) => match watched.readable().await {
+                    Ok(_) => IoCompletion::Primitive(raya_sdk::NativeValue::null()),
+                    Err(e) => IoCompletion::Error(format!("fd {fd}: {e}")),
+                },
+                Err(e) => IoCompletion::Error(format!("fd {fd}: {e}")),
+            };
+            let _ = completion_tx.send(IoPoolCompletion { task_id, result });
+        });
+    }
+
+    #[cfg(not(unix))]
+    fn spawn_fd_readable(
+        _tokio_runtime: &Handle,
+        task_id: TaskId,
+        _fd: i32,
+        completion_tx: Sender<IoPoolCompletion>,
+    ) {
+        let result = IoCompletion::Error("fd readiness waits require a unix host".to_string());
+        let _ = completion_tx.send(IoPoolCompletion { task_id, result });
+    }
+
     fn spawn_blocking_io(
         tokio_runtime: &Handle,
         task_id: TaskId,
@@ -1,7 +1,9 @@
 //! Main task scheduler — wraps Reactor with the same public API
 
 use crate::vm::interpreter::SharedVmState;
-use crate::vm::scheduler::{Reactor, Task, TaskId, TaskState};
+use crate::vm::scheduler::{Reactor, SuspendReason, Task, TaskId, TaskState};
+use crate::vm::stack::{StackConfig, StackGrowth};
+use crate::vm::value::Value;
 use std::sync::Arc;
 
 /// Scheduler statistics
@@ -22,14 +24,22 @@ pub struct SchedulerLimits {
     pub max_workers: Option<usize>,
     /// Maximum concurrent running tasks (None = unlimited)
     pub max_concurrent_tasks: Option<usize>,
-    /// Maximum stack size per task in bytes (None = unlimited)
+    /// Maximum stack size per task in bytes (None = engine default)
     pub max_stack_size: Option<usize>,
+    /// Initial stack allocation per task in bytes (None = engine default)
+    pub initial_stack_size: Option<usize>,
+    /// Growth policy for task stacks between the initial and maximum size
+    pub stack_growth: StackGrowth,
     /// Maximum heap size in bytes (None = unlimited)
     pub max_heap_size: Option<usize>,
     /// Maximum consecutive preemptions before killing a task. Default: 1000.
     pub max_preemptions: u32,
     /// Preemption time slice in milliseconds. Default: 10ms.
     pub preempt_threshold_ms: u64,
+    /// Maximum open file handles held by native calls (None = unlimited)
+    pub max_open_files: Option<usize>,
+    /// Maximum open sockets held by native calls (None = unlimited)
+    pub max_open_sockets: Option<usize>,
 }
 
 impl Default for SchedulerLimits {
@@ -38,9 +48,13 @@ impl Default for SchedulerLimits {
             max_workers: None,
             max_concurrent_tasks: None,
             max_stack_size: None,
+            initial_stack_size: None,
+            stack_growth: StackGrowth::default(),
             max_heap_size: None,
             max_preemptions: crate::vm::defaults::DEFAULT_MAX_PREEMPTIONS,
             preempt_threshold_ms: crate::vm::defaults::DEFAULT_PREEMPT_THRESHOLD_MS,
+            max_open_files: None,
+            max_open_sockets: None,
         }
     }
 }
@@ -56,6 +70,27 @@ impl SchedulerLimits {
             ..Default::default()
         }
     }
+
+    /// Stack sizing for tasks spawned under these limits (sizes converted
+    /// from bytes to value slots).
+    pub fn stack_config(&self) -> StackConfig {
+        let slot_bytes = std::mem::size_of::<Value>();
+        let defaults = StackConfig::default();
+        let max_size = self
+            .max_stack_size
+            .map(|bytes| (bytes / slot_bytes).max(1))
+            .unwrap_or(defaults.max_size);
+        let initial_size = self
+            .initial_stack_size
+            .map(|bytes| bytes / slot_bytes)
+            .unwrap_or(defaults.initial_size)
+            .min(max_size);
+        StackConfig {
+            initial_size,
+            max_size,
+            growth: self.stack_growth,
+        }
+    }
 }
 
 /// Main task scheduler
@@ -125,6 +160,9 @@ impl Scheduler {
             SharedVmState::with_native_handler(safepoint, tasks, injector, native_handler);
         state.max_preemptions = limits.max_preemptions;
         state.preempt_threshold_ms = limits.preempt_threshold_ms;
+        state.stack_pool.set_config(limits.stack_config());
+        state.handle_limits =
+            crate::vm::HandleLimits::new(limits.max_open_files, limits.max_open_sockets);
         let shared_state = Arc::new(state);
         let gc_context_id = {
             let gc = shared_state.gc.lock();
@@ -331,6 +369,34 @@ impl Scheduler {
         }
     }
 
+    /// Cancel every live task and wake those parked on offloaded IO or sleeping.
+    ///
+    /// Tasks waiting on blocking work are resumed immediately so they fail at
+    /// their next safepoint instead of waiting for the host call to return;
+    /// the late IO completion is then discarded.
+    pub fn cancel_all(&self) {
+        let tasks = self.shared_state.tasks.read();
+        for task in tasks.values() {
+            task.cancel();
+            match task.suspend_reason() {
+                Some(SuspendReason::IoWait) => {
+                    task.set_resume_value(Value::null());
+                    if task.resume_if_pending() {
+                        task.clear_suspend_reason();
+                        self.shared_state.injector.push(task.clone());
+                    }
+                }
+                Some(SuspendReason::Sleep { .. }) => {
+                    if task.resume_if_pending() {
+                        task.clear_suspend_reason();
+                        self.shared_state.injector.push(task.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Block a Task on a mutex
     pub fn block_on_mutex(&self, task_id: TaskId, _mutex_id: crate::vm::sync::MutexId) {
         if let Some(task) = self.get_task(task_id) {
@@ -18,6 +18,7 @@ use crate::vm::snapshot::SerializedValue;
 use crate::vm::stack::Stack;
 use crate::vm::sync::{MutexId, SemaphoreId};
 use crate::vm::value::Value;
+use crate::vm::SourceLocation;
 use parking_lot::Condvar as ParkingCondvar;
 use parking_lot::Mutex as ParkingMutex;
 use parking_lot::RwLock as ParkingRwLock;
@@ -76,6 +77,9 @@ pub enum SuspendReason {
 
     /// Waiting for IO completion from the event loop (NativeCallResult::Suspend)
     IoWait,
+
+    /// Waiting for another task to finish a module's deferred top-level init
+    ModuleInit,
 }
 
 /// Unique identifier for a Task
@@ -164,6 +168,7 @@ struct ExceptionState {
     current_exception: Option<Value>,
     caught_exception: Option<Value>,
     exception_handlers: Vec<ExceptionHandler>,
+    failure_location: Option<SourceLocation>,
 }
 
 /// Call stack state (VM worker only)
@@ -293,6 +298,7 @@ impl Task {
                 current_exception: None,
                 caught_exception: None,
                 exception_handlers: Vec::new(),
+                failure_location: None,
             }),
 
             calls: ParkingMutex::new(CallState {
@@ -772,6 +778,16 @@ impl Task {
         self.exceptions.lock().current_exception.is_some()
     }
 
+    /// Record the source location of the runtime error that failed this task
+    pub fn set_failure_location(&self, location: Option<SourceLocation>) {
+        self.exceptions.lock().failure_location = location;
+    }
+
+    /// Source location of the runtime error that failed this task, if known
+    pub fn failure_location(&self) -> Option<SourceLocation> {
+        self.exceptions.lock().failure_location.clone()
+    }
+
     /// Get the caught exception (for Rethrow)
     pub fn caught_exception(&self) -> Option<Value> {
         self.exceptions.lock().caught_exception
@@ -1077,6 +1093,7 @@ impl Task {
                 BlockedReason::Other(format!("channel_recv:{}", channel_id))
             }
             SuspendReason::IoWait => BlockedReason::Other("io_wait".to_string()),
+            SuspendReason::ModuleInit => BlockedReason::Other("module_init".to_string()),
         });
 
         Ok(SerializedTask {
@@ -1250,6 +1267,7 @@ impl Task {
                 current_exception: None,
                 caught_exception: None,
                 exception_handlers: Vec::new(),
+                failure_location: None,
             }),
 
             calls: ParkingMutex::new(CallState {
@@ -30,7 +30,71 @@
 use crate::vm::{value::Value, VmError, VmResult};
 
 /// Default maximum stack size (in slots)
-const DEFAULT_MAX_STACK_SIZE: usize = 1024 * 64;
+pub const DEFAULT_MAX_STACK_SIZE: usize = 1024 * 64;
+
+/// Default initial stack size (in slots)
+///
+/// Stacks start empty and allocate on first push, so most short-lived
+/// tasks never pay for slots they do not use.
+pub const DEFAULT_INITIAL_STACK_SIZE: usize = 0;
+
+/// Growth policy applied when a stack's backing storage is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StackGrowth {
+    /// Double the allocation on each growth (amortized O(1) pushes)
+    #[default]
+    Double,
+
+    /// Grow the allocation by a fixed number of slots
+    Linear(usize),
+}
+
+/// Sizing configuration for a task stack
+///
+/// A stack starts with `initial_size` slots reserved and grows according to
+/// `growth` until it reaches `max_size`, at which point pushes fail with
+/// `VmError::StackOverflow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackConfig {
+    /// Slots reserved when the stack is created
+    pub initial_size: usize,
+
+    /// Maximum stack size (in slots)
+    pub max_size: usize,
+
+    /// How the backing storage grows when full
+    pub growth: StackGrowth,
+}
+
+impl Default for StackConfig {
+    fn default() -> Self {
+        Self {
+            initial_size: DEFAULT_INITIAL_STACK_SIZE,
+            max_size: DEFAULT_MAX_STACK_SIZE,
+            growth: StackGrowth::Double,
+        }
+    }
+}
+
+impl StackConfig {
+    /// Create a config with a specific maximum size and default growth
+    pub fn with_max_size(max_size: usize) -> Self {
+        Self {
+            max_size,
+            ..Default::default()
+        }
+    }
+
+    /// Compute the next allocation size (in slots) for a stack currently
+    /// holding `current` slots, clamped to `max_size`.
+    pub fn next_capacity(&self, current: usize) -> usize {
+        let grown = match self.growth {
+            StackGrowth::Double => current.saturating_mul(2).max(16),
+            StackGrowth::Linear(step) => current.saturating_add(step.max(1)),
+        };
+        grown.min(self.max_size)
+    }
+}
 
 /// Call frame for function invocation
 ///
@@ -133,6 +197,9 @@ pub struct Stack {
 
     /// Maximum stack size (in slots)
     max_size: usize,
+
+    /// Growth policy for the slot storage
+    growth: StackGrowth,
 }
 
 impl Stack {
@@ -146,15 +213,37 @@ impl Stack {
     /// The operand and frame vectors start empty and grow on demand,
     /// avoiding ~22KB of upfront allocation per task.
     pub fn with_capacity(max_size: usize) -> Self {
+        Self::with_config(StackConfig::with_max_size(max_size))
+    }
+
+    /// Create a stack from a sizing configuration
+    ///
+    /// Reserves `initial_size` slots up front (clamped to `max_size`); further
+    /// storage is allocated according to the configured growth policy.
+    pub fn with_config(config: StackConfig) -> Self {
         Self {
-            slots: Vec::new(),
+            slots: Vec::with_capacity(config.initial_size.min(config.max_size)),
             frames: Vec::new(),
             sp: 0,
             fp: 0,
-            max_size,
+            max_size: config.max_size,
+            growth: config.growth,
         }
     }
 
+    /// Grow the slot storage according to the growth policy
+    #[cold]
+    fn grow(&mut self) {
+        let config = StackConfig {
+            initial_size: 0,
+            max_size: self.max_size,
+            growth: self.growth,
+        };
+        let next = config.next_capacity(self.slots.capacity());
+        self.slots
+            .reserve_exact(next.saturating_sub(self.slots.len()));
+    }
+
     /// Reset the stack for reuse (keeps allocated capacity).
     ///
     /// This makes the stack logically empty while preserving the underlying
@@ -179,10 +268,15 @@ impl Stack {
     #[inline]
     pub fn push(&mut self, value: Value) -> VmResult<()> {
         if self.sp >= self.max_size {
-            return Err(VmError::StackOverflow);
+            return Err(VmError::StackOverflow {
+                depth: self.frames.len(),
+            });
         }
 
         if self.sp >= self.slots.len() {
+            if self.slots.len() == self.slots.capacity() {
+                self.grow();
+            }
             self.slots.push(value);
         } else {
             self.slots[self.sp] = value;
@@ -321,7 +415,9 @@ impl Stack {
 
         // Check if we have enough stack space for additional locals
         if self.sp + additional_locals > self.max_size {
-            return Err(VmError::StackOverflow);
+            return Err(VmError::StackOverflow {
+                depth: self.frames.len(),
+            });
         }
 
         // Create frame
@@ -632,7 +728,36 @@ mod tests {
         // This should fail
         let result = stack.push(Value::i32(3));
         assert!(result.is_err());
-        assert!(matches!(result, Err(VmError::StackOverflow)));
+        assert!(matches!(result, Err(VmError::StackOverflow { .. })));
+    }
+
+    #[test]
+    fn test_stack_grows_by_policy_up_to_max() {
+        let mut stack = Stack::with_config(StackConfig {
+            initial_size: 4,
+            max_size: 10,
+            growth: StackGrowth::Linear(4),
+        });
+        assert_eq!(stack.stats().capacity, 4);
+
+        for i in 0..10 {
+            stack.push(Value::i32(i)).unwrap();
+        }
+        assert_eq!(stack.stats().capacity, 10);
+
+        let result = stack.push(Value::i32(10));
+        assert!(matches!(result, Err(VmError::StackOverflow { .. })));
+    }
+
+    #[test]
+    fn test_stack_overflow_reports_frame_depth() {
+        let mut stack = Stack::with_capacity(8);
+        stack.push_frame(0, 0, 2, 0).unwrap();
+        stack.push_frame(1, 0, 2, 0).unwrap();
+        stack.push_frame(2, 0, 2, 0).unwrap();
+
+        let result = stack.push_frame(3, 0, 4, 0);
+        assert!(matches!(result, Err(VmError::StackOverflow { depth: 3 })));
     }
 
     #[test]
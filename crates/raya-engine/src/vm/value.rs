@@ -31,6 +31,10 @@
 //! - `number` (float) -> f64 (main float type, 64-bit IEEE 754)
 //! - Additional types for FFI and special cases: u32, f32, i64, u64
 
+use crate::vm::interpreter::{ClassRegistry, VmContext};
+use crate::vm::json::view::{js_classify, JSView};
+use crate::vm::object::Object;
+use rustc_hash::FxHashSet;
 use std::fmt;
 use std::ptr::NonNull;
 
@@ -406,6 +410,19 @@ impl Value {
         }
     }
 
+    /// Structural equality over primitives, strings, arrays and objects
+    ///
+    /// Arrays compare element-wise; objects compare their nominal type, layout
+    /// and every field (up to the layout's registered field count) plus any
+    /// dynamic properties. Integers and floats compare numerically. Other heap
+    /// values (closures, maps, channels, ...) are equal only when identical.
+    /// Cyclic structures terminate: a pair of objects already being compared is
+    /// assumed equal.
+    pub fn deep_equal(&self, other: &Value, ctx: &VmContext) -> bool {
+        let mut visited = FxHashSet::default();
+        deep_equal_inner(*self, *other, ctx.class_registry(), &mut visited)
+    }
+
     /// Encode value to binary format for snapshot serialization
     ///
     /// Note: Pointers are NOT serialized directly - they must be mapped
@@ -444,6 +461,83 @@ impl Value {
     }
 }
 
+fn deep_equal_inner(
+    a: Value,
+    b: Value,
+    classes: &ClassRegistry,
+    visited: &mut FxHashSet<(u64, u64)>,
+) -> bool {
+    if a == b {
+        return true;
+    }
+    match (js_classify(a), js_classify(b)) {
+        (JSView::Int(x), JSView::Int(y)) => x == y,
+        (JSView::Int(x), JSView::Number(y)) | (JSView::Number(y), JSView::Int(x)) => x as f64 == y,
+        (JSView::Number(x), JSView::Number(y)) => x == y,
+        (JSView::Str(x), JSView::Str(y)) => unsafe { (*x).as_str() == (*y).as_str() },
+        (JSView::Arr(x), JSView::Arr(y)) => {
+            if !visited.insert((a.0, b.0)) {
+                return true;
+            }
+            let (x, y) = unsafe { (&*x, &*y) };
+            x.len() == y.len()
+                && (0..x.len()).all(|i| match (x.get(i), y.get(i)) {
+                    (Some(l), Some(r)) => deep_equal_inner(l, r, classes, visited),
+                    _ => false,
+                })
+        }
+        (
+            JSView::Struct {
+                ptr: x,
+                layout_id: x_layout,
+                nominal_type_id: x_nominal,
+            },
+            JSView::Struct {
+                ptr: y,
+                layout_id: y_layout,
+                nominal_type_id: y_nominal,
+            },
+        ) => {
+            if x_layout != y_layout || x_nominal != y_nominal {
+                return false;
+            }
+            if !visited.insert((a.0, b.0)) {
+                return true;
+            }
+            let (x, y) = unsafe { (&*x, &*y) };
+            let field_count = classes
+                .get_layout(x_layout)
+                .map_or(x.fields.len().max(y.fields.len()), |layout| {
+                    layout.field_count
+                });
+            let fields_equal = (0..field_count).all(|i| {
+                let l = x.get_field(i).unwrap_or_else(Value::null);
+                let r = y.get_field(i).unwrap_or_else(Value::null);
+                deep_equal_inner(l, r, classes, visited)
+            });
+            fields_equal && dyn_props_equal(x, y, classes, visited)
+        }
+        _ => false,
+    }
+}
+
+fn dyn_props_equal(
+    x: &Object,
+    y: &Object,
+    classes: &ClassRegistry,
+    visited: &mut FxHashSet<(u64, u64)>,
+) -> bool {
+    let empty = Default::default();
+    let x_props = x.dyn_map().unwrap_or(&empty);
+    let y_props = y.dyn_map().unwrap_or(&empty);
+    x_props.len() == y_props.len()
+        && x_props.iter().all(|(key, &l)| {
+            y_props
+                .get(key)
+                .is_some_and(|&r| deep_equal_inner(l, r, classes, visited))
+        })
+}
+
 impl fmt::Debug for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.is_null() {
@@ -506,6 +600,7 @@ impl Default for Value {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vm::object::{Array, RayaString};
 
     #[test]
     fn test_value_null() {
@@ -616,6 +711,68 @@ mod tests {
         assert_ne!(Value::null(), Value::bool(false));
     }
 
+    fn alloc<T: 'static>(ctx: &mut VmContext, value: T) -> Value {
+        let ptr = ctx.gc_mut().allocate(value);
+        unsafe { Value::from_ptr(NonNull::new(ptr.as_ptr()).unwrap()) }
+    }
+
+    fn alloc_array(ctx: &mut VmContext, elements: &[Value]) -> Value {
+        let mut array = Array::new(0, 0);
+        for &element in elements {
+            array.push(element);
+        }
+        alloc(ctx, array)
+    }
+
+    fn alloc_point(ctx: &mut VmContext, x: Value, y: Value) -> Value {
+        let mut object = Object::new_structural(1, 2);
+        object.set_field(0, x).unwrap();
+        object.set_field(1, y).unwrap();
+        alloc(ctx, object)
+    }
+
+    #[test]
+    fn test_deep_equal_nested_arrays() {
+        let mut ctx = VmContext::new();
+        let hello = alloc(&mut ctx, RayaString::new("hi".to_string()));
+        let hello_copy = alloc(&mut ctx, RayaString::new("hi".to_string()));
+        let inner_a = alloc_array(&mut ctx, &[Value::i32(1), hello]);
+        let inner_b = alloc_array(&mut ctx, &[Value::f64(1.0), hello_copy]);
+        let a = alloc_array(&mut ctx, &[inner_a, Value::null()]);
+        let b = alloc_array(&mut ctx, &[inner_b, Value::null()]);
+
+        assert_ne!(a, b);
+        assert!(a.deep_equal(&b, &ctx));
+
+        let shorter = alloc_array(&mut ctx, &[inner_a]);
+        assert!(!a.deep_equal(&shorter, &ctx));
+    }
+
+    #[test]
+    fn test_deep_equal_objects_differing_in_one_field() {
+        let mut ctx = VmContext::new();
+        let a = alloc_point(&mut ctx, Value::i32(1), Value::i32(2));
+        let same = alloc_point(&mut ctx, Value::i32(1), Value::i32(2));
+        let different = alloc_point(&mut ctx, Value::i32(1), Value::i32(3));
+
+        assert!(a.deep_equal(&same, &ctx));
+        assert!(!a.deep_equal(&different, &ctx));
+    }
+
+    #[test]
+    fn test_deep_equal_self_referential() {
+        let mut ctx = VmContext::new();
+        let a = alloc_array(&mut ctx, &[Value::i32(1)]);
+        let b = alloc_array(&mut ctx, &[Value::i32(1)]);
+        for value in [a, b] {
+            let array = unsafe { &mut *value.as_ptr::<Array>().unwrap().as_ptr() };
+            array.push(value);
+        }
+
+        assert!(a.deep_equal(&b, &ctx));
+        assert!(a.deep_equal(&a, &ctx));
+    }
+
     #[test]
     fn test_value_pointer_aligned() {
         // Test that pointer encoding preserves alignment
@@ -92,6 +92,7 @@ fn test_function_decl_simple() {
     let add_name = intern(&mut interner, "add");
 
     let func = FunctionDecl {
+        annotations: vec![],
         name: Identifier::new(add_name, Span::new(9, 12, 1, 10)),
         type_params: None,
         params: vec![
@@ -146,6 +147,7 @@ fn test_function_decl_async() {
     let mut interner = Interner::new();
 
     let func = FunctionDecl {
+        annotations: vec![],
         name: ident(&mut interner, "fetch", Span::new(15, 20, 1, 16)),
         type_params: None,
         params: vec![],
@@ -303,15 +305,19 @@ fn test_switch_statement() {
                     value: 1,
                     span: Span::new(21, 22, 2, 10),
                 })),
+                guard: None,
                 consequent: vec![Statement::Break(BreakStatement {
                     label: None,
                     span: Span::new(24, 30, 2, 13),
                 })],
+                fallthrough: false,
                 span: Span::new(16, 31, 2, 5),
             },
             SwitchCase {
                 test: None, // default case
+                guard: None,
                 consequent: vec![],
+                fallthrough: false,
                 span: Span::new(36, 45, 3, 5),
             },
         ],
@@ -1,7 +1,8 @@
 //! Tests for expression parsing
 
 use raya_engine::parser::ast::*;
-use raya_engine::parser::parser::Parser;
+use raya_engine::parser::parser::{ParseErrorKind, Parser};
+use raya_engine::parser::Token;
 
 #[test]
 fn test_parse_number_literal() {
@@ -709,3 +710,78 @@ fn test_parse_right_associativity() {
         _ => panic!("Expected expression statement"),
     }
 }
+
+// ============================================================================
+// Standalone Expression Entry Point
+// ============================================================================
+
+#[test]
+fn test_parse_expression_binary() {
+    let (expr, _interner) = Parser::parse_expression("1 + 2").unwrap();
+    match expr {
+        Expression::Binary(bin) => {
+            assert!(matches!(bin.operator, BinaryOperator::Add));
+            assert!(matches!(*bin.left, Expression::IntLiteral(_)));
+            assert!(matches!(*bin.right, Expression::IntLiteral(_)));
+        }
+        other => panic!("Expected binary expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_expression_call() {
+    let (expr, interner) = Parser::parse_expression("add(1, x)").unwrap();
+    match expr {
+        Expression::Call(call) => {
+            match &*call.callee {
+                Expression::Identifier(id) => assert_eq!(interner.resolve(id.name), "add"),
+                other => panic!("Expected identifier callee, got {:?}", other),
+            }
+            assert_eq!(call.arguments.len(), 2);
+        }
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_expression_rejects_trailing_tokens() {
+    let err = Parser::parse_expression("1 + 2 ;;").unwrap_err();
+    assert!(matches!(
+        err.kind,
+        ParseErrorKind::UnexpectedToken { ref found, .. } if *found == Token::Semicolon
+    ));
+}
+
+#[test]
+fn test_parse_expression_satisfies() {
+    let (expr, _interner) = Parser::parse_expression("a + b satisfies number").unwrap();
+    match expr {
+        Expression::Satisfies(satisfies) => {
+            assert!(matches!(*satisfies.object, Expression::Binary(_)));
+            assert!(matches!(
+                satisfies.target_type.ty,
+                Type::Primitive(PrimitiveType::Number)
+            ));
+        }
+        other => panic!("Expected satisfies expression, got {:?}", other),
+    }
+
+    // `satisfies` stays usable as an ordinary identifier.
+    let (expr, _interner) = Parser::parse_expression("satisfies + 1").unwrap();
+    assert!(matches!(expr, Expression::Binary(_)));
+}
+
+#[test]
+fn test_parse_expression_as_const() {
+    let (expr, _interner) = Parser::parse_expression("[1, 2] as const").unwrap();
+    match expr {
+        Expression::ConstAssertion(assertion) => {
+            assert!(matches!(*assertion.object, Expression::Array(_)));
+        }
+        other => panic!("Expected const assertion, got {:?}", other),
+    }
+
+    // `as` followed by a type is still an ordinary cast.
+    let (expr, _interner) = Parser::parse_expression("x as number").unwrap();
+    assert!(matches!(expr, Expression::TypeCast(_)));
+}
@@ -5,8 +5,8 @@
 
 #![allow(unused_imports)]
 
-use raya_engine::compiler::{Function, Module, Opcode};
-use raya_engine::vm::gc::GarbageCollector;
+use raya_engine::compiler::{ClassDef, Function, Module, Opcode};
+use raya_engine::vm::gc::{GarbageCollector, GcAllocator};
 use raya_engine::vm::interpreter::{marshal, unmarshal, MarshalledValue};
 use raya_engine::vm::interpreter::{
     Capability, CapabilityError, CapabilityRegistry, ContextRegistry, ResourceLimits, VmContext,
@@ -14,6 +14,8 @@ use raya_engine::vm::interpreter::{
 };
 use raya_engine::vm::value::Value;
 use raya_engine::vm::Vm;
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 // ============================================================================
@@ -407,7 +409,7 @@ fn test_unmarshal_string() {
             .as_ptr::<raya_engine::vm::object::RayaString>()
             .unwrap();
         let string = &*str_ptr.as_ptr();
-        assert_eq!(string.data, "Test String");
+        assert_eq!(string.as_str(), "Test String");
     }
 }
 
@@ -591,3 +593,93 @@ fn test_multiple_vms_concurrent() {
     vm2.terminate();
     vm3.terminate();
 }
+
+// ============================================================================
+// Integration: Custom GC Allocator
+// ============================================================================
+
+#[derive(Default)]
+struct CountingAllocator {
+    allocs: AtomicUsize,
+    frees: AtomicUsize,
+}
+
+unsafe impl GcAllocator for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocs.fetch_add(1, Ordering::SeqCst);
+        std::alloc::alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.frees.fetch_add(1, Ordering::SeqCst);
+        std::alloc::dealloc(ptr, layout)
+    }
+}
+
+/// `main` allocates a two-field object, stores 42 in it and returns the field
+fn allocating_module() -> Module {
+    let mut module = Module::new("alloc_test".to_string());
+    module.classes.push(ClassDef {
+        name: "Box".to_string(),
+        field_count: 2,
+        parent_id: None,
+        methods: Vec::new(),
+    });
+    module.functions.push(Function {
+        name: "main".to_string(),
+        param_count: 0,
+        local_count: 1,
+        code: vec![
+            Opcode::NewType as u8,
+            0,
+            0,
+            Opcode::StoreLocal as u8,
+            0,
+            0,
+            Opcode::LoadLocal as u8,
+            0,
+            0,
+            Opcode::ConstI32 as u8,
+            42,
+            0,
+            0,
+            0,
+            Opcode::StoreFieldExact as u8,
+            0,
+            0,
+            Opcode::LoadLocal as u8,
+            0,
+            0,
+            Opcode::LoadFieldExact as u8,
+            0,
+            0,
+            Opcode::Return as u8,
+        ],
+    });
+    module
+}
+
+#[test]
+fn test_vm_with_custom_gc_allocator() {
+    let counter = Arc::new(CountingAllocator::default());
+    let mut vm = Vm::with_options(VmOptions {
+        gc_allocator: Some(counter.clone()),
+        ..Default::default()
+    });
+
+    let result = vm.execute(&allocating_module()).unwrap();
+    assert_eq!(result, Value::i32(42));
+    assert!(counter.allocs.load(Ordering::SeqCst) > 0);
+
+    // The object is unreachable once main returns, so collection hands its
+    // block back to the embedder's allocator
+    vm.collect_garbage();
+    assert!(counter.frees.load(Ordering::SeqCst) > 0);
+}
+
+#[test]
+fn test_vm_default_gc_allocator_unchanged() {
+    let mut vm = Vm::with_options(VmOptions::default());
+    let result = vm.execute(&allocating_module()).unwrap();
+    assert_eq!(result, Value::i32(42));
+}
@@ -480,3 +480,164 @@ fn test_float_arithmetic() {
 
     assert_eq!(result, Value::f64(6.0));
 }
+
+#[test]
+fn test_runtime_type_error_reports_source_location() {
+    use raya_engine::compiler::bytecode::FunctionDebugInfo;
+    use raya_engine::vm::SourceLocation;
+
+    // Bytecode: ARRAY_LEN on an integer
+    // CONST_I32 7   (offset 0, line 1)
+    // ARRAY_LEN     (offset 5, line 3 col 9) -> TypeError
+    // RETURN
+    let mut module = Module::new("test".to_string());
+    module.functions.push(Function {
+        name: "main".to_string(),
+        param_count: 0,
+        local_count: 0,
+        code: vec![
+            Opcode::ConstI32 as u8,
+            7,
+            0,
+            0,
+            0,
+            Opcode::ArrayLen as u8,
+            Opcode::Return as u8,
+        ],
+    });
+    let file_idx = module
+        .debug_info_mut()
+        .add_source_file("src/main.raya".to_string());
+    let mut func_debug = FunctionDebugInfo::new(file_idx, 1, 1, 3, 20);
+    func_debug.add_line_entry(0, 1, 1);
+    func_debug.add_line_entry(5, 3, 9);
+    module.debug_info_mut().functions.push(func_debug);
+
+    let mut vm = Vm::new();
+    let error = vm.execute(&module).unwrap_err();
+
+    assert_eq!(
+        error.location(),
+        Some(&SourceLocation {
+            file: Some("src/main.raya".to_string()),
+            line: 3,
+            column: 9,
+        })
+    );
+    let message = error.to_string();
+    assert!(message.contains("Expected array"), "got: {}", message);
+    assert!(
+        message.ends_with("at src/main.raya:3:9"),
+        "got: {}",
+        message
+    );
+}
+
+#[test]
+fn test_reset_clears_globals_but_keeps_modules_loaded() {
+    // Bytecode: return the previous value of global 0, then store 42 into it
+    // LOAD_GLOBAL 0
+    // CONST_I32 42
+    // STORE_GLOBAL 0
+    // RETURN
+    let mut module = Module::new("test".to_string());
+    module.functions.push(Function {
+        name: "main".to_string(),
+        param_count: 0,
+        local_count: 0,
+        code: vec![
+            Opcode::LoadGlobal as u8,
+            0,
+            0,
+            0,
+            0,
+            Opcode::ConstI32 as u8,
+            42,
+            0,
+            0,
+            0,
+            Opcode::StoreGlobal as u8,
+            0,
+            0,
+            0,
+            0,
+            Opcode::Return as u8,
+        ],
+    });
+
+    let mut vm = Vm::new();
+    assert_eq!(vm.execute(&module).unwrap(), Value::null());
+    assert_eq!(vm.execute(&module).unwrap(), Value::i32(42));
+
+    vm.reset();
+    assert_eq!(vm.shared_state().module_registry.read().module_count(), 1);
+    assert!(vm
+        .shared_state()
+        .globals_by_index
+        .read()
+        .iter()
+        .all(|value| value.is_null()));
+
+    assert_eq!(vm.execute(&module).unwrap(), Value::null());
+}
+
+#[test]
+fn test_shared_modules_isolate_globals_but_share_code() {
+    use std::sync::Arc;
+
+    // Same program as above: return the previous value of global 0, then store 42
+    let mut module = Module::new("shared".to_string());
+    module.functions.push(Function {
+        name: "main".to_string(),
+        param_count: 0,
+        local_count: 0,
+        code: vec![
+            Opcode::LoadGlobal as u8,
+            0,
+            0,
+            0,
+            0,
+            Opcode::ConstI32 as u8,
+            42,
+            0,
+            0,
+            0,
+            Opcode::StoreGlobal as u8,
+            0,
+            0,
+            0,
+            0,
+            Opcode::Return as u8,
+        ],
+    });
+    // Decode once to materialize the identity checksum, as a loaded .ryb would.
+    let module = Module::decode(&module.encode()).unwrap();
+    let shared: Arc<[Arc<Module>]> = Arc::from(vec![Arc::new(module)]);
+
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let shared = shared.clone();
+            std::thread::spawn(move || {
+                let mut vm = Vm::from_shared_modules(shared.clone()).unwrap();
+                let first = vm.execute(&shared[0]).unwrap();
+                let second = vm.execute(&shared[0]).unwrap();
+                let registered = vm
+                    .shared_state()
+                    .module_registry
+                    .read()
+                    .get_by_checksum(&shared[0].checksum)
+                    .cloned()
+                    .unwrap();
+                (first, second, Arc::ptr_eq(&registered, &shared[0]))
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let (first, second, shares_code) = handle.join().unwrap();
+        // Each VM starts with its own null global, regardless of the other VM.
+        assert_eq!(first, Value::null());
+        assert_eq!(second, Value::i32(42));
+        assert!(shares_code);
+    }
+}
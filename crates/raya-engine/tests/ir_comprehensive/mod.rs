@@ -1459,6 +1459,75 @@ mod type_annotations {
     }
 }
 
+// =============================================================================
+// INLINING
+// =============================================================================
+
+mod inlining {
+    use super::*;
+    use raya_engine::compiler::ir::IrInstr;
+    use raya_engine::compiler::optimize::Optimizer;
+
+    #[test]
+    fn test_leaf_function_inlined_in_loop() {
+        let source = r#"
+            function add(a: number, b: number): number {
+                return a + b;
+            }
+
+            let sum = 0;
+            for (let i = 0; i < 10; i = i + 1) {
+                sum = add(sum, i);
+            }
+        "#;
+        let mut ir = lower(source);
+        Optimizer::basic().optimize(&mut ir);
+
+        let main = ir.get_function_by_name("main").expect("main function");
+        let calls = main
+            .blocks()
+            .flat_map(|block| &block.instructions)
+            .filter(|instr| matches!(instr, IrInstr::Call { .. }))
+            .count();
+        assert_eq!(calls, 0, "add should be inlined:\n{}", ir.pretty_print());
+        // The callee itself is kept for other callers.
+        assert!(ir.get_function_by_name("add").is_some());
+    }
+}
+
+// =============================================================================
+// NATIVE CALLS
+// =============================================================================
+
+mod native_calls {
+    use raya_engine::compiler::{CompileError, Compiler};
+    use raya_engine::parser::{Parser, TypeContext};
+
+    fn compile_with_known_natives(source: &str) -> Result<(), CompileError> {
+        let parser = Parser::new(source).expect("lexer error");
+        let (module, interner) = parser.parse().expect("parse error");
+        let compiler = Compiler::new(TypeContext::new(), &interner)
+            .with_known_natives(["math.abs", "math.floor"]);
+        compiler.compile_via_ir(&module).map(|_| ())
+    }
+
+    #[test]
+    fn test_registered_native_name_compiles() {
+        let result = compile_with_known_natives(r#"let x = __NATIVE_CALL("math.abs", -1);"#);
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[test]
+    fn test_bogus_native_name_is_a_compile_error() {
+        match compile_with_known_natives(r#"let x = __NATIVE_CALL("math.absolute", -1);"#) {
+            Err(CompileError::UnresolvedNative { name, .. }) => {
+                assert_eq!(name, "math.absolute");
+            }
+            other => panic!("expected UnresolvedNative, got {:?}", other),
+        }
+    }
+}
+
 // =============================================================================
 // INTEGRATION TESTS
 // =============================================================================
@@ -187,6 +187,7 @@ fn make_module(code: Vec<u8>, param_count: usize, local_count: usize) -> Module
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     }
 }
 
@@ -220,6 +221,7 @@ fn make_vm_module(code: Vec<u8>, param_count: usize, local_count: usize) -> Modu
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     }
 }
 
@@ -247,6 +249,7 @@ fn make_custom_module(functions: Vec<Function>, classes: Vec<ClassDef>) -> Modul
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     }
 }
 
@@ -3648,6 +3651,7 @@ fn engine_prewarm_selects_hot() {
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     };
 
     let result = engine.prewarm(&module);
@@ -3720,6 +3724,69 @@ fn vm_enable_jit_with_config() {
     assert_eq!(result, raya_engine::Value::i32(100));
 }
 
+#[test]
+fn deterministic_float_jit_matches_interpreter() {
+    // 0.1 * 10.0 - 1.0 is 0.0 when each op rounds on its own, but a fused
+    // multiply-add would keep the product's rounding error (~5.55e-17).
+    let mut code = Vec::new();
+    emit_f64(&mut code, 0.1);
+    emit_store_local(&mut code, 0);
+    emit_f64(&mut code, 10.0);
+    emit_store_local(&mut code, 1);
+    emit_f64(&mut code, -1.0);
+    emit_store_local(&mut code, 2);
+    emit_load_local(&mut code, 0);
+    emit_load_local(&mut code, 1);
+    emit(&mut code, Opcode::Fmul);
+    emit_load_local(&mut code, 2);
+    emit(&mut code, Opcode::Fadd);
+    emit(&mut code, Opcode::Return);
+
+    let default_engine = JitEngine::new().unwrap();
+    assert!(!default_engine.is_deterministic_float());
+
+    let mut engine = JitEngine::with_config(JitConfig {
+        min_score: 1.0,
+        min_instruction_count: 2,
+        deterministic_float: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(engine.is_deterministic_float());
+    let module = make_module(code.clone(), 0, 3);
+    let summary = engine.prewarm(&module);
+    let jit_fn = engine
+        .code_cache()
+        .get(summary.module_id, 0)
+        .expect("function should be JIT-compiled");
+    let mut locals = vec![0u64; 3];
+    let mut exit = raya_engine::jit::runtime::trampoline::JitExitInfo::default();
+    let jit_raw = unsafe {
+        jit_fn(
+            ptr::null(),
+            0,
+            locals.as_mut_ptr(),
+            locals.len() as u32,
+            ptr::null_mut(),
+            &mut exit as *mut _,
+        )
+    };
+    let jit_val = decode_f64(jit_raw);
+
+    let mut vm = Vm::with_options(raya_engine::VmOptions {
+        deterministic_float: true,
+        ..Default::default()
+    });
+    let interp_val = vm
+        .execute(&make_vm_module(code, 0, 3))
+        .unwrap()
+        .as_f64()
+        .unwrap();
+
+    assert_eq!(interp_val, 0.0);
+    assert_eq!(jit_val.to_bits(), interp_val.to_bits());
+}
+
 // ============================================================================
 // Category 6: Adaptive (On-the-Fly) JIT Compilation
 // ============================================================================
@@ -3893,6 +3960,7 @@ fn background_compiler_processes_request() {
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     });
 
     let module_id = code_cache.register_module(module.checksum);
@@ -3990,6 +4058,7 @@ fn jit_hints_encode_decode_roundtrip() {
                 is_cpu_bound: false,
             },
         ],
+        json_schemas: vec![],
     };
 
     // Encode
@@ -4040,6 +4109,7 @@ fn jit_hints_absent_when_no_flag() {
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     };
 
     let bytes = module.encode();
@@ -4131,6 +4201,7 @@ fn prewarm_candidates_submitted_to_background() {
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     };
 
     let mut vm = raya_engine::Vm::new();
@@ -4156,3 +4227,94 @@ fn prewarm_candidates_submitted_to_background() {
         "Expected module profile for adaptive compilation"
     );
 }
+
+#[test]
+fn hot_profile_from_previous_run_compiles_function_eagerly() {
+    use raya_engine::jit::profiling::hot_profile::HotProfile;
+    use std::sync::Arc;
+
+    // main calls `hot_leaf` 32 times; hot_leaf is too small to be picked by
+    // the static prewarm heuristics.
+    let mut main_code = Vec::new();
+    for _ in 0..32 {
+        main_code.push(Opcode::Call as u8);
+        main_code.extend_from_slice(&1u32.to_le_bytes());
+        main_code.extend_from_slice(&0u16.to_le_bytes());
+        emit(&mut main_code, Opcode::Pop);
+    }
+    emit_i32(&mut main_code, 0);
+    emit(&mut main_code, Opcode::Return);
+
+    let mut leaf_code = Vec::new();
+    emit_i32(&mut leaf_code, 7);
+    emit(&mut leaf_code, Opcode::Return);
+
+    let module = make_custom_module(
+        vec![
+            Function {
+                name: "main".to_string(),
+                param_count: 0,
+                local_count: 0,
+                code: main_code,
+            },
+            Function {
+                name: "hot_leaf".to_string(),
+                param_count: 0,
+                local_count: 0,
+                code: leaf_code,
+            },
+        ],
+        vec![],
+    );
+
+    // Run 1: low threshold, so hot_leaf is recorded as hot.
+    let mut vm = Vm::new();
+    vm.enable_jit_with_config(JitConfig {
+        adaptive_compilation: true,
+        call_threshold: 16,
+        max_prewarm_functions: 0,
+        ..Default::default()
+    })
+    .unwrap();
+    vm.execute(&module).unwrap();
+    let recorded = vm.jit_hot_profile();
+    vm.terminate();
+
+    let profile = HotProfile::from_json(&recorded.to_json()).expect("profile round-trips");
+    assert_eq!(profile.modules.len(), 1);
+    let hot = &profile.modules[0].functions;
+    assert_eq!(hot.len(), 1, "only hot_leaf should be recorded: {:?}", hot);
+    assert_eq!(hot[0].name, "hot_leaf");
+    assert_eq!(hot[0].call_count, 32);
+
+    // Run 2: the threshold is out of reach, so only the profile can trigger
+    // compilation of hot_leaf.
+    let mut vm = Vm::new();
+    vm.enable_jit_with_config(JitConfig {
+        adaptive_compilation: true,
+        call_threshold: 1_000_000,
+        max_prewarm_functions: 0,
+        hot_profile: Some(Arc::new(profile)),
+        ..Default::default()
+    })
+    .unwrap();
+    vm.execute(&module).unwrap();
+
+    let module_profile = vm
+        .shared_state()
+        .module_profiles
+        .read()
+        .values()
+        .next()
+        .cloned()
+        .expect("module profile registered");
+    let start = std::time::Instant::now();
+    while !module_profile.get(1).unwrap().is_jit_available() {
+        assert!(
+            start.elapsed() < std::time::Duration::from_secs(5),
+            "hot_leaf was not compiled from the recorded profile"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(!module_profile.get(0).unwrap().is_jit_available());
+}
@@ -50,7 +50,7 @@ fn as_number(val: Value) -> Option<f64> {
 /// Returns the string data as a Rust String (clones the underlying data).
 fn get_string_data(val: Value) -> Option<String> {
     match js_classify(val) {
-        JSView::Str(ptr) => Some(unsafe { &*ptr }.data.clone()),
+        JSView::Str(ptr) => Some(unsafe { &*ptr }.as_str().to_string()),
         _ => None,
     }
 }
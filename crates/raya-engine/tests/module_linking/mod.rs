@@ -33,6 +33,7 @@ fn create_module(name: &str) -> Module {
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     }
 }
 
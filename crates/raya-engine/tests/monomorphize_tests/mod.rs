@@ -620,6 +620,74 @@ mod integration_tests {
         // identity_i32 and identity_string
     }
 
+    #[test]
+    fn test_instantiation_budget_falls_back_to_erased_specialization() {
+        let mut module = IrModule::new("test");
+
+        let identity_func = make_simple_function("identity", TypeId::new(100), TypeId::new(100));
+        let identity_id = module.add_function(identity_func);
+
+        // Call identity at 100 distinct argument types
+        let mut caller = IrFunction::new("main", vec![], TypeId::new(0));
+        let mut block = BasicBlock::new(BasicBlockId(0));
+        for i in 0..100 {
+            let ty = 1000 + i;
+            block.add_instr(IrInstr::Call {
+                dest: Some(make_reg(2 * i + 1, ty)),
+                func: identity_id,
+                args: vec![make_reg(2 * i, ty)],
+            });
+        }
+        block.set_terminator(Terminator::Return(None));
+        caller.add_block(block);
+        let main_id = module.add_function(caller);
+
+        let type_ctx = TypeContext::new();
+        let interner = Interner::new();
+        let mut mono = Monomorphizer::new(&type_ctx, &interner).with_instantiation_budget(10);
+        mono.register_generic_function(GenericFunctionInfo {
+            func_id: identity_id,
+            type_params: vec![TypeId::new(100)],
+            name: "identity".to_string(),
+        });
+
+        let result = mono.monomorphize(&mut module);
+
+        assert_eq!(result.functions_specialized, 10);
+        assert_eq!(result.functions_erased, 1);
+        assert_eq!(result.call_sites_rewritten, 100);
+        // identity + main + 10 specializations + 1 erased fallback
+        assert_eq!(module.function_count(), 13);
+
+        let erased = module
+            .functions()
+            .find(|f| f.name == "identity__mono_erased")
+            .expect("erased fallback should exist");
+        assert_eq!(
+            erased.params[0].ty,
+            TypeId::new(TypeContext::UNKNOWN_TYPE_ID)
+        );
+
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("identity"));
+
+        // Ten call sites get their own specialization; the other ninety share the fallback
+        let main = module.get_function(main_id).unwrap();
+        let mut call_counts: std::collections::HashMap<FunctionId, usize> =
+            std::collections::HashMap::new();
+        for instr in &main.blocks().next().unwrap().instructions {
+            if let IrInstr::Call { func, .. } = instr {
+                *call_counts.entry(*func).or_insert(0) += 1;
+            }
+        }
+        assert!(!call_counts.contains_key(&identity_id));
+        assert_eq!(call_counts.len(), 11);
+        let mut counts: Vec<usize> = call_counts.values().copied().collect();
+        counts.sort_unstable();
+        assert_eq!(counts[..10], [1; 10]);
+        assert_eq!(counts[10], 90);
+    }
+
     #[test]
     fn test_class_instantiation() {
         let mut module = IrModule::new("test");
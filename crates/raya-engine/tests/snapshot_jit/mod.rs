@@ -54,6 +54,7 @@ fn make_module(code: Vec<u8>, param_count: usize, local_count: usize) -> Module
         debug_info: None,
         native_functions: vec![],
         jit_hints: vec![],
+        json_schemas: vec![],
     }
 }
 
@@ -89,6 +89,55 @@ fn test_const_requires_initializer() {
     assert!(result.is_err(), "const without initializer should fail");
 }
 
+#[test]
+fn test_parse_using_declaration_wraps_rest_of_block() {
+    let source = "function f() { using r = open(); work(); }";
+    let parser = Parser::new(source).unwrap();
+    let (module, interner) = parser.parse().unwrap();
+
+    let Statement::FunctionDecl(func) = &module.statements[0] else {
+        panic!("Expected function declaration");
+    };
+    assert_eq!(func.body.statements.len(), 2);
+    match &func.body.statements[0] {
+        Statement::VariableDecl(decl) => {
+            assert!(matches!(decl.kind, VariableKind::Using));
+            match &decl.pattern {
+                Pattern::Identifier(id) => assert_eq!(interner.resolve(id.name), "r"),
+                _ => panic!("Expected identifier pattern"),
+            }
+        }
+        _ => panic!("Expected using declaration"),
+    }
+    match &func.body.statements[1] {
+        Statement::Try(try_stmt) => {
+            assert_eq!(try_stmt.body.statements.len(), 1);
+            assert!(try_stmt.catch_clause.is_some());
+            assert!(try_stmt.finally_clause.is_some());
+        }
+        _ => panic!("Expected the rest of the block inside a try statement"),
+    }
+}
+
+#[test]
+fn test_using_is_still_an_identifier() {
+    let source = "let using = 1; using = using + 1;";
+    let parser = Parser::new(source).unwrap();
+    let (module, _) = parser.parse().unwrap();
+
+    assert_eq!(module.statements.len(), 2);
+    assert!(matches!(module.statements[1], Statement::Expression(_)));
+}
+
+#[test]
+fn test_using_rejected_at_module_top_level() {
+    let source = "using r = open();";
+    let parser = Parser::new(source).unwrap();
+    let result = parser.parse();
+
+    assert!(result.is_err(), "top-level using must be rejected");
+}
+
 // ============================================================================
 // Function Declarations
 // ============================================================================
@@ -280,6 +329,43 @@ fn test_parse_if_else_if_statement() {
     }
 }
 
+// ============================================================================
+// Control Flow - Switch Statements
+// ============================================================================
+
+#[test]
+fn test_parse_switch_guard_and_fallthrough() {
+    let source = "switch (x) { case 1 when y > 0: a(); fallthrough; case 2: b(); }";
+    let parser = Parser::new(source).unwrap();
+    let (module, _interner) = parser.parse().unwrap();
+
+    let Statement::Switch(switch_stmt) = &module.statements[0] else {
+        panic!("Expected switch statement");
+    };
+    let first = &switch_stmt.cases[0];
+    assert!(matches!(first.guard, Some(Expression::Binary(_))));
+    assert_eq!(first.consequent.len(), 1);
+    assert!(first.fallthrough);
+    assert!(switch_stmt.cases[1].guard.is_none());
+    assert!(!switch_stmt.cases[1].fallthrough);
+    assert!(first.falls_through());
+    assert!(switch_stmt.cases[1].falls_through());
+}
+
+#[test]
+fn test_fallthrough_must_end_a_non_final_case() {
+    let not_last = "switch (x) { case 1: fallthrough; a(); case 2: b(); }";
+    assert!(Parser::new(not_last).unwrap().parse().is_err());
+
+    let final_case = "switch (x) { case 1: a(); fallthrough; }";
+    assert!(Parser::new(final_case).unwrap().parse().is_err());
+
+    // Outside that position `fallthrough` is an ordinary identifier.
+    let source = "let fallthrough = 1; fallthrough = fallthrough + 1;";
+    let (module, _) = Parser::new(source).unwrap().parse().unwrap();
+    assert_eq!(module.statements.len(), 2);
+}
+
 // ============================================================================
 // Control Flow - While Statements
 // ============================================================================
@@ -1044,6 +1130,39 @@ fn test_parse_export_default_new_expression() {
     }
 }
 
+#[test]
+fn test_parse_export_default_class_declaration() {
+    let source = "export default class Foo {}";
+    let parser = Parser::new(source).unwrap();
+    let (module, interner) = parser.parse().unwrap();
+
+    assert_eq!(module.statements.len(), 2);
+    assert!(matches!(&module.statements[0], Statement::ClassDecl(_)));
+    match &module.statements[1] {
+        Statement::ExportDecl(ExportDecl::Default { expression, .. }) => {
+            match expression.as_ref() {
+                Expression::Identifier(ident) => assert_eq!(interner.resolve(ident.name), "Foo"),
+                _ => panic!("Expected default export of the class name"),
+            }
+        }
+        _ => panic!("Expected export default declaration"),
+    }
+}
+
+#[test]
+fn test_parse_export_default_function_declaration() {
+    let source = "export default function greet(): string { return \"hi\"; }";
+    let parser = Parser::new(source).unwrap();
+    let (module, _interner) = parser.parse().unwrap();
+
+    assert_eq!(module.statements.len(), 2);
+    assert!(matches!(&module.statements[0], Statement::FunctionDecl(_)));
+    assert!(matches!(
+        &module.statements[1],
+        Statement::ExportDecl(ExportDecl::Default { .. })
+    ));
+}
+
 // ============================================================================
 // Mixed Statements
 // ============================================================================
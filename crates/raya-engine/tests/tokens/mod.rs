@@ -181,6 +181,31 @@ fn test_integer_literals() {
     assert_eq!(token, Token::IntLiteral(123));
 }
 
+#[test]
+fn test_bigint_literals() {
+    let (token, interner) = lex_single("0n");
+    match token {
+        Token::BigIntLiteral(sym) => assert_eq!(interner.resolve(sym), "0"),
+        other => panic!("Expected bigint literal, got {:?}", other),
+    }
+
+    let (tokens, interner) = lex_tokens("-5n");
+    assert_eq!(tokens[0].0, Token::Minus);
+    match &tokens[1].0 {
+        Token::BigIntLiteral(sym) => assert_eq!(interner.resolve(*sym), "5"),
+        other => panic!("Expected bigint literal, got {:?}", other),
+    }
+
+    // Too large for an IntLiteral; the digits are kept as text
+    let (token, interner) = lex_single("123_456_789_012_345_678_901_234_567_890n");
+    match token {
+        Token::BigIntLiteral(sym) => {
+            assert_eq!(interner.resolve(sym), "123456789012345678901234567890")
+        }
+        other => panic!("Expected bigint literal, got {:?}", other),
+    }
+}
+
 #[test]
 fn test_hex_literals() {
     let (token, _) = lex_single("0x1F");
@@ -948,3 +973,70 @@ const y = $;
         panic!("Expected errors");
     }
 }
+
+// ============================================================================
+// Span Column Tests (UTF-16)
+// ============================================================================
+
+/// Span of the first identifier named `name`.
+fn identifier_span(source: &str, name: &str) -> raya_engine::parser::Span {
+    let (tokens, interner) = lex_tokens(source);
+    tokens
+        .iter()
+        .find(|(t, _)| matches!(t, Token::Identifier(sym) if interner.resolve(*sym) == name))
+        .map(|(_, span)| *span)
+        .expect("identifier not found")
+}
+
+#[test]
+fn test_span_column_counts_surrogate_pairs() {
+    // The emoji is one char but two UTF-16 code units.
+    let source = r#"let s = "😀"; let x = 1;"#;
+    let span = identifier_span(source, "x");
+    assert_eq!(span.line, 1);
+    assert_eq!(span.column, 19);
+    assert_eq!(&source[span.start..span.end], "x");
+}
+
+#[test]
+fn test_span_column_counts_cjk_as_single_units() {
+    let source = r#"let s = "日本" + y;"#;
+    let span = identifier_span(source, "y");
+    assert_eq!(span.column, 16);
+    assert_eq!(span.start, 19);
+}
+
+#[test]
+fn test_span_column_after_multibyte_comments() {
+    let source = "/* 🎉 */ a // ünïcode\n  b";
+    let a = identifier_span(source, "a");
+    assert_eq!((a.line, a.column), (1, 10));
+    let b = identifier_span(source, "b");
+    assert_eq!((b.line, b.column), (2, 3));
+}
+
+#[test]
+fn test_utf16_position_conversions() {
+    use raya_engine::parser::{byte_offset_from_utf16, utf16_position};
+
+    let source = "let s = \"😀\";\nlet t = \"é\" + 1;";
+    let span = identifier_span(source, "s");
+    assert_eq!(utf16_position(source, span.start), (1, span.column));
+
+    let x_offset = source.find('=').unwrap();
+    assert_eq!(utf16_position(source, x_offset), (1, 7));
+    assert_eq!(byte_offset_from_utf16(source, 1, 7), Some(x_offset));
+
+    // The semicolon after the emoji string sits at UTF-16 column 13.
+    let semi = source.find(';').unwrap();
+    assert_eq!(utf16_position(source, semi), (1, 13));
+    assert_eq!(byte_offset_from_utf16(source, 1, 13), Some(semi));
+
+    // Column 11 is the second half of the surrogate pair.
+    assert_eq!(byte_offset_from_utf16(source, 1, 11), None);
+
+    let eq2 = source.rfind('=').unwrap();
+    assert_eq!(utf16_position(source, eq2), (2, 7));
+    assert_eq!(byte_offset_from_utf16(source, 2, 7), Some(eq2));
+    assert_eq!(byte_offset_from_utf16(source, 3, 1), None);
+}
@@ -83,6 +83,27 @@ fn test_parse_tuple_type() {
     }
 }
 
+#[test]
+fn test_parse_readonly_array_and_tuple_types() {
+    let source = "let x: readonly number[]; let y: readonly [number, string]; let z: number[];";
+    let parser = Parser::new(source).unwrap();
+    let (module, _interner) = parser.parse().unwrap();
+
+    let readonly_flags: Vec<bool> = module
+        .statements
+        .iter()
+        .map(|stmt| match stmt {
+            Statement::VariableDecl(decl) => match &decl.type_annotation.as_ref().unwrap().ty {
+                Type::Array(array_ty) => array_ty.readonly,
+                Type::Tuple(tuple_ty) => tuple_ty.readonly,
+                _ => panic!("Expected array or tuple type"),
+            },
+            _ => panic!("Expected variable declaration"),
+        })
+        .collect();
+    assert_eq!(readonly_flags, vec![true, true, false]);
+}
+
 #[test]
 fn test_parse_function_type() {
     let source = "let x: (x: number) => string;";
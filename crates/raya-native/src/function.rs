@@ -130,6 +130,8 @@ pub fn expand_function(func: ItemFn) -> Result<TokenStream> {
                 }
             }
             syn::ReturnType::Type(_, ty) => {
+                // `Result::Err` converts to `NativeValue::error`, which the
+                // VM's native dispatch throws instead of returning.
                 quote! {
                     <#ty as raya_sdk::ToRaya>::to_raya(result)
                 }
@@ -21,8 +21,9 @@ pub mod url;
 pub use cache::{Cache, CacheError, ModuleMetadata};
 pub use lockfile::{LockedPackage, Lockfile, LockfileError, Source};
 pub use manifest::{
-    AssetsConfig, BundleConfig, Dependency, ManifestError, PackageInfo, PackageManifest,
-    RegistryConfig,
+    AssetsConfig, BundleConfig, Dependency, InitConfig, LintManifestConfig, LintRuleSetting,
+    ManifestError, PackageInfo, PackageManifest, PermissionsConfig, RegistryConfig,
+    TestManifestConfig,
 };
 pub use path::{find_project_root, PathError, PathResolver};
 pub use semver::{Constraint, SemverError, Version};
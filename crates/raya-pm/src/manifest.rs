@@ -68,6 +68,18 @@ pub struct PackageManifest {
     /// Lint configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lint: Option<LintManifestConfig>,
+
+    /// Test runner configuration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<TestManifestConfig>,
+
+    /// Capability grants (optional — omit to leave natives unrestricted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<PermissionsConfig>,
+
+    /// Module initialization settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init: Option<InitConfig>,
 }
 
 /// Registry configuration
@@ -187,6 +199,11 @@ pub struct BuildConfig {
     /// Default: false
     #[serde(default)]
     pub embed_source: bool,
+
+    /// Features enabled for `//@@if(feature)` conditional compilation.
+    /// Default: none
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
 }
 
 fn default_true() -> bool {
@@ -198,6 +215,7 @@ impl Default for BuildConfig {
         Self {
             sourcemap: true,
             embed_source: false,
+            features: Vec::new(),
         }
     }
 }
@@ -205,9 +223,154 @@ impl Default for BuildConfig {
 /// Lint configuration for `[lint]` section in raya.toml
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct LintManifestConfig {
-    /// Per-rule severity overrides: "off" | "warn" | "error"
+    /// Per-rule settings, keyed by rule name
     #[serde(default)]
-    pub rules: HashMap<String, String>,
+    pub rules: HashMap<String, LintRuleSetting>,
+}
+
+/// Setting for a single lint rule
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum LintRuleSetting {
+    /// Severity shorthand: "off" | "warn" | "error"
+    Severity(String),
+
+    /// Detailed setting: `{ enabled = true, severity = "error" }`
+    Detailed {
+        /// Enable or disable the rule (enabled rules keep their default severity)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        enabled: Option<bool>,
+
+        /// Severity override: "off" | "warn" | "error"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        severity: Option<String>,
+    },
+}
+
+/// Test configuration for `[test]` section in raya.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TestManifestConfig {
+    /// Minimum line and branch coverage percentage for `raya test --coverage`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_coverage: Option<f64>,
+
+    /// Minimum line coverage percentage (overrides `min_coverage`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_line_coverage: Option<f64>,
+
+    /// Minimum branch coverage percentage (overrides `min_coverage`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_branch_coverage: Option<f64>,
+}
+
+/// Module initialization configuration for `[init]` section in raya.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InitConfig {
+    /// Defer a dependency module's top-level code until the first call into it (default: true)
+    #[serde(default = "default_true")]
+    pub lazy: bool,
+
+    /// Modules whose top-level code always runs at load: package names or
+    /// source paths relative to the manifest
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub eager: Vec<String>,
+}
+
+impl Default for InitConfig {
+    fn default() -> Self {
+        Self {
+            lazy: true,
+            eager: Vec::new(),
+        }
+    }
+}
+
+/// Capability grants for `[permissions]` section in raya.toml
+///
+/// When the section is present, the runtime denies native calls that need a
+/// capability not listed here. Only the root project's manifest is read: the
+/// application grants capabilities for all code it runs, so a dependency's own
+/// `[permissions]` section cannot widen them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PermissionsConfig {
+    /// Filesystem grants: "read" | "write"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fs: Vec<String>,
+
+    /// Network grants: "connect" (any host) | "connect:<host>" | "listen"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub net: Vec<String>,
+
+    /// Process grants: "exec" (run subprocesses)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub process: Vec<String>,
+}
+
+impl PermissionsConfig {
+    /// Whether filesystem `access` ("read" or "write") is granted
+    pub fn allows_fs(&self, access: &str) -> bool {
+        self.fs.iter().any(|grant| grant == access)
+    }
+
+    /// Whether outgoing connections to `host` are granted
+    pub fn allows_connect(&self, host: &str) -> bool {
+        self.net
+            .iter()
+            .any(|grant| match grant.strip_prefix("connect") {
+                Some("") => true,
+                Some(rest) => rest
+                    .strip_prefix(':')
+                    .is_some_and(|granted| granted.eq_ignore_ascii_case(host)),
+                None => false,
+            })
+    }
+
+    /// Whether any outgoing connection is granted
+    pub fn allows_any_connect(&self) -> bool {
+        self.net.iter().any(|grant| grant.starts_with("connect"))
+    }
+
+    /// Whether listening for incoming connections is granted
+    pub fn allows_listen(&self) -> bool {
+        self.net.iter().any(|grant| grant == "listen")
+    }
+
+    /// Whether running subprocesses is granted
+    pub fn allows_exec(&self) -> bool {
+        self.process.iter().any(|grant| grant == "exec")
+    }
+
+    fn validate(&self) -> Result<(), ManifestError> {
+        for grant in &self.fs {
+            if grant != "read" && grant != "write" {
+                return Err(ManifestError::ValidationError(format!(
+                    "Invalid fs permission: {}. Must be \"read\" or \"write\"",
+                    grant
+                )));
+            }
+        }
+        for grant in &self.net {
+            let valid = match grant.strip_prefix("connect:") {
+                Some(host) => !host.is_empty(),
+                None => grant == "connect" || grant == "listen",
+            };
+            if !valid {
+                return Err(ManifestError::ValidationError(format!(
+                    "Invalid net permission: {}. Must be \"connect\", \"connect:<host>\" or \"listen\"",
+                    grant
+                )));
+            }
+        }
+        for grant in &self.process {
+            if grant != "exec" {
+                return Err(ManifestError::ValidationError(format!(
+                    "Invalid process permission: {}. Must be \"exec\"",
+                    grant
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Package information
@@ -328,6 +491,10 @@ impl PackageManifest {
             validate_dependency(name, dep)?;
         }
 
+        if let Some(permissions) = &self.permissions {
+            permissions.validate()?;
+        }
+
         Ok(())
     }
 
@@ -564,6 +731,60 @@ lib = { git = "https://github.com/user/repo", tag = "v1.0.0" }
         assert_eq!(lib_dep.git(), Some("https://github.com/user/repo"));
     }
 
+    #[test]
+    fn test_parse_lint_rules() {
+        let toml = r#"
+[package]
+name = "main"
+version = "1.0.0"
+
+[lint.rules]
+prefer-const = "error"
+no-shadow = { enabled = false }
+no-implicit-any = { enabled = true, severity = "warn" }
+"#;
+
+        let manifest = PackageManifest::from_str(toml).unwrap();
+        let rules = manifest.lint.unwrap().rules;
+        assert_eq!(
+            rules["prefer-const"],
+            LintRuleSetting::Severity("error".to_string())
+        );
+        assert_eq!(
+            rules["no-shadow"],
+            LintRuleSetting::Detailed {
+                enabled: Some(false),
+                severity: None,
+            }
+        );
+        assert_eq!(
+            rules["no-implicit-any"],
+            LintRuleSetting::Detailed {
+                enabled: Some(true),
+                severity: Some("warn".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_test_coverage_thresholds() {
+        let toml = r#"
+[package]
+name = "main"
+version = "1.0.0"
+
+[test]
+min_coverage = 80
+min_branch_coverage = 60.5
+"#;
+
+        let manifest = PackageManifest::from_str(toml).unwrap();
+        let test = manifest.test.unwrap();
+        assert_eq!(test.min_coverage, Some(80.0));
+        assert_eq!(test.min_line_coverage, None);
+        assert_eq!(test.min_branch_coverage, Some(60.5));
+    }
+
     #[test]
     fn test_validate_package_name() {
         assert!(is_valid_package_name("my-package"));
@@ -827,12 +1048,14 @@ version = "1.0.0"
 [build]
 sourcemap = false
 embed_source = true
+features = ["linux", "tls"]
 "#;
 
         let manifest = PackageManifest::from_str(toml).unwrap();
         let build = manifest.build.unwrap();
         assert!(!build.sourcemap);
         assert!(build.embed_source);
+        assert_eq!(build.features, vec!["linux".to_string(), "tls".to_string()]);
     }
 
     #[test]
@@ -849,6 +1072,7 @@ version = "1.0.0"
         let build = manifest.build.unwrap();
         assert!(build.sourcemap);
         assert!(!build.embed_source);
+        assert!(build.features.is_empty());
     }
 
     #[test]
@@ -863,6 +1087,109 @@ version = "1.0.0"
         assert!(manifest.build.is_none());
     }
 
+    #[test]
+    fn test_parse_permissions() {
+        let toml = r#"
+[package]
+name = "my-app"
+version = "1.0.0"
+
+[permissions]
+fs = ["read"]
+net = ["connect:api.example.com"]
+"#;
+
+        let manifest = PackageManifest::from_str(toml).unwrap();
+        let permissions = manifest.permissions.unwrap();
+        assert!(permissions.allows_fs("read"));
+        assert!(!permissions.allows_fs("write"));
+        assert!(permissions.allows_connect("api.example.com"));
+        assert!(permissions.allows_connect("API.example.com"));
+        assert!(!permissions.allows_connect("evil.example.com"));
+        assert!(permissions.allows_any_connect());
+        assert!(!permissions.allows_listen());
+        assert!(!permissions.allows_exec());
+    }
+
+    #[test]
+    fn test_parse_no_permissions() {
+        let toml = r#"
+[package]
+name = "my-app"
+version = "1.0.0"
+"#;
+
+        let manifest = PackageManifest::from_str(toml).unwrap();
+        assert!(manifest.permissions.is_none());
+    }
+
+    #[test]
+    fn test_invalid_permissions() {
+        let bad_fs = r#"
+[package]
+name = "my-app"
+version = "1.0.0"
+
+[permissions]
+fs = ["execute"]
+"#;
+        assert!(PackageManifest::from_str(bad_fs).is_err());
+
+        let bad_net = r#"
+[package]
+name = "my-app"
+version = "1.0.0"
+
+[permissions]
+net = ["connect:"]
+"#;
+        assert!(PackageManifest::from_str(bad_net).is_err());
+
+        let bad_process = r#"
+[package]
+name = "my-app"
+version = "1.0.0"
+
+[permissions]
+process = ["spawn"]
+"#;
+        assert!(PackageManifest::from_str(bad_process).is_err());
+    }
+
+    #[test]
+    fn test_parse_init_config() {
+        let toml = r#"
+[package]
+name = "my-app"
+version = "1.0.0"
+
+[init]
+eager = ["src/polyfills.raya", "logger"]
+"#;
+
+        let manifest = PackageManifest::from_str(toml).unwrap();
+        let init = manifest.init.unwrap();
+        assert!(init.lazy);
+        assert_eq!(init.eager, vec!["src/polyfills.raya", "logger"]);
+    }
+
+    #[test]
+    fn test_parse_init_config_disable_lazy() {
+        let toml = r#"
+[package]
+name = "my-app"
+version = "1.0.0"
+
+[init]
+lazy = false
+"#;
+
+        let manifest = PackageManifest::from_str(toml).unwrap();
+        let init = manifest.init.unwrap();
+        assert!(!init.lazy);
+        assert!(init.eager.is_empty());
+    }
+
     #[test]
     fn test_jsx_config_round_trip() {
         let config = JsxConfig {
@@ -891,6 +1218,9 @@ version = "1.0.0"
             build: None,
             bundle: None,
             lint: None,
+            test: None,
+            permissions: None,
+            init: None,
         };
 
         let serialized = toml::to_string_pretty(&manifest).unwrap();
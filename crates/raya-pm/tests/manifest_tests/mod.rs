@@ -329,6 +329,9 @@ fn test_roundtrip_complex() {
         build: None,
         bundle: None,
         lint: None,
+        test: None,
+        permissions: None,
+        init: None,
     };
 
     manifest
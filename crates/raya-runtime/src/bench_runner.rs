@@ -0,0 +1,283 @@
+//! Bench runner — compiles and executes benchmark files, collecting timings.
+//!
+//! The bench runner:
+//! 1. Reads the benchmark file source
+//! 2. Appends `__runBenchmarks();` to the bench harness + user source
+//! 3. Compiles using the standard binary-link pipeline (with a source map
+//!    when profiling, so samples resolve to benchmark lines)
+//! 4. Creates a VM with stdlib + bench handlers
+//! 5. Executes the module and returns collected timings and the profile
+
+use raya_engine::profiler::{ProfileConfig, ResolvedProfileData};
+use raya_engine::vm::Vm;
+use raya_stdlib::bench::{self, BenchConfig, ProfileHook, SharedBenchResults};
+use raya_stdlib::StdNativeHandler;
+use std::path::Path;
+use std::sync::Arc;
+
+// Re-export types for consumers (e.g., raya-cli)
+pub use raya_stdlib::bench::{BenchResult, BenchResults};
+
+use crate::compile;
+use crate::error::RuntimeError;
+use crate::RuntimeOptions;
+
+/// The benchmark harness source, embedded at compile time.
+const BENCH_SOURCE: &str = include_str!("../../raya-stdlib/raya/bench.raya");
+
+/// Which benchmarks to run and how.
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    /// Only run benchmarks whose name contains this pattern.
+    pub filter: Option<String>,
+    /// Unmeasured iterations run before timing starts.
+    pub warmup: usize,
+    /// Measured iterations.
+    pub iterations: usize,
+    /// Run only the benchmark with this name, sampling its measured
+    /// iterations with the CPU profiler.
+    pub profile: Option<String>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            filter: None,
+            warmup: 10,
+            iterations: 100,
+            profile: None,
+        }
+    }
+}
+
+/// Run a single benchmark file and return its results.
+pub fn run_bench_file(
+    path: &Path,
+    bench_options: &BenchOptions,
+    options: &RuntimeOptions,
+) -> Result<BenchFileResult, RuntimeError> {
+    let source = std::fs::read_to_string(path)?;
+    run_bench_source(&source, path, bench_options, options)
+}
+
+/// Run benchmark source code and return results.
+///
+/// With `bench_options.profile` set, the profiler samples only the measured
+/// iterations of that benchmark; the resolved profile maps frames in the
+/// benchmark source back to `file_path`.
+pub fn run_bench_source(
+    source: &str,
+    file_path: &Path,
+    bench_options: &BenchOptions,
+    options: &RuntimeOptions,
+) -> Result<BenchFileResult, RuntimeError> {
+    // Build bench source: harness + user code + runner invocation
+    let full_bench_source = format!("{}\n{}\n__runBenchmarks();\n", BENCH_SOURCE, source);
+
+    // Compile through the binary module pipeline.
+    let profiling = bench_options.profile.is_some();
+    let (module, _interner) = if profiling {
+        compile::compile_source_with_sourcemap(&full_bench_source)?
+    } else {
+        compile::compile_source(&full_bench_source)?
+    };
+
+    // Create VM with bench handlers
+    let results = bench::new_results();
+    let mut vm = create_bench_vm(options, bench_options, results.clone());
+
+    // Execute
+    let exec_result = vm.execute(&module);
+
+    // Collect results regardless of VM outcome
+    let bench_results = results.lock().clone();
+    let profile = vm.stop_profiling().map(|data| {
+        let mut resolved = data.resolve(&module);
+        map_profile_to_user_source(&mut resolved, source, file_path);
+        resolved
+    });
+
+    let error = match exec_result {
+        Ok(_) => None,
+        Err(e) => Some(format!("{}", e)),
+    };
+
+    Ok(BenchFileResult {
+        file: file_path.to_path_buf(),
+        results: bench_results,
+        execution_error: error,
+        profile,
+    })
+}
+
+/// Result of running a single benchmark file.
+#[derive(Debug)]
+pub struct BenchFileResult {
+    /// Path to the benchmark file.
+    pub file: std::path::PathBuf,
+    /// Collected benchmark timings.
+    pub results: BenchResults,
+    /// Execution-level error (e.g., compilation failure, VM crash).
+    pub execution_error: Option<String>,
+    /// Resolved CPU profile of the profiled benchmark, when one was requested.
+    pub profile: Option<ResolvedProfileData>,
+}
+
+impl BenchFileResult {
+    /// Whether the file had any failures (benchmark or execution).
+    pub fn has_failures(&self) -> bool {
+        self.results.failed() > 0 || self.execution_error.is_some()
+    }
+}
+
+// ── Internal ─────────────────────────────────────────────────────────────
+
+/// Create a VM configured for benchmarking (stdlib + bench handlers).
+///
+/// When profiling, the profiler is installed paused; the bench handlers
+/// start it only around the measured iterations of the profiled benchmark.
+fn create_bench_vm(
+    options: &RuntimeOptions,
+    bench_options: &BenchOptions,
+    results: SharedBenchResults,
+) -> Vm {
+    let threads = if options.threads == 0 {
+        num_cpus::get()
+    } else {
+        options.threads
+    };
+
+    let vm = Vm::with_native_handler(threads, Arc::new(StdNativeHandler));
+
+    let mut profile_hook: Option<ProfileHook> = None;
+    if bench_options.profile.is_some() {
+        vm.enable_profiling(ProfileConfig {
+            interval_us: options.prof_interval_us,
+            ..Default::default()
+        });
+        if let Some(profiler) = vm.profiler() {
+            profile_hook = Some(Arc::new(move |measuring: bool| {
+                if measuring {
+                    profiler.start();
+                } else {
+                    profiler.pause();
+                }
+            }));
+        }
+    }
+
+    let config = BenchConfig {
+        filter: bench_options.filter.clone(),
+        warmup: bench_options.warmup,
+        iterations: bench_options.iterations,
+        profile: bench_options.profile.clone(),
+    };
+
+    // Register all stdlib + bench native functions
+    {
+        let mut registry = vm.native_registry().write();
+        raya_stdlib::register_stdlib(&mut registry);
+        raya_stdlib_posix::register_posix(&mut registry);
+        bench::register_bench(&mut registry, results, config, profile_hook);
+    }
+
+    vm
+}
+
+/// Point frames inside the benchmark source at `file_path` with file-relative
+/// line numbers; frames in the harness are attributed to `std:bench`.
+fn map_profile_to_user_source(profile: &mut ResolvedProfileData, source: &str, file_path: &Path) {
+    let line_offset = BENCH_SOURCE.matches('\n').count() as u32 + 1;
+    let user_lines = (line_offset + 1)..=(line_offset + source.lines().count() as u32);
+    let path = file_path.display().to_string();
+
+    for frame in profile.samples.iter_mut().flat_map(|s| s.frames.iter_mut()) {
+        if user_lines.contains(&frame.line_number) {
+            frame.line_number -= line_offset;
+            frame.source_file = path.clone();
+        } else if frame.line_number != 0 {
+            frame.source_file = "std:bench".to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUM_BENCH: &str = r#"bench("sum", () => {
+    let total: number = 0;
+    for (let i: number = 0; i < 2000; i = i + 1) {
+        total = total + i;
+    }
+});
+bench("noop", () => {});
+"#;
+
+    #[test]
+    fn test_run_benchmarks_records_timings() {
+        let bench_options = BenchOptions {
+            warmup: 1,
+            iterations: 5,
+            ..Default::default()
+        };
+        let r = run_bench_source(
+            SUM_BENCH,
+            Path::new("sum.bench.raya"),
+            &bench_options,
+            &RuntimeOptions::default(),
+        )
+        .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        let names: Vec<&str> = r.results.results.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["sum", "noop"]);
+        assert!(r.results.results.iter().all(|b| b.iterations == 5));
+        assert!(r.profile.is_none());
+    }
+
+    #[test]
+    fn test_profile_runs_named_benchmark_and_resolves_body() {
+        let bench_options = BenchOptions {
+            warmup: 2,
+            iterations: 200,
+            profile: Some("sum".to_string()),
+            ..Default::default()
+        };
+        let options = RuntimeOptions {
+            prof_interval_us: 50,
+            ..Default::default()
+        };
+        let r = run_bench_source(
+            SUM_BENCH,
+            Path::new("sum.bench.raya"),
+            &bench_options,
+            &options,
+        )
+        .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(
+            r.results.results.len(),
+            1,
+            "only the profiled benchmark runs"
+        );
+        assert_eq!(r.results.results[0].name, "sum");
+
+        let profile = r.profile.expect("profile collected");
+        assert!(!profile.samples.is_empty(), "profile has samples");
+        let hits_body = profile.samples.iter().any(|sample| {
+            sample.frames.iter().any(|frame| {
+                frame.source_file == "sum.bench.raya" && (2..=5).contains(&frame.line_number)
+            })
+        });
+        assert!(hits_body, "a sample resolves into the benchmark body");
+        assert!(profile.to_cpuprofile_json().contains("sum.bench.raya"));
+    }
+}
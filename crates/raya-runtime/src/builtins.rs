@@ -22,6 +22,10 @@ fn strict_builtin_source_modules() -> &'static [(&'static str, &'static str)] {
     &[
         ("strict/array.raya", include_str!("../../raya-engine/builtins/strict/array.raya")),
         ("strict/regexp.raya", include_str!("../../raya-engine/builtins/strict/regexp.raya")),
+        (
+            "strict/regexp_match.raya",
+            include_str!("../../raya-engine/builtins/strict/regexp_match.raya"),
+        ),
         ("strict/object.raya", include_str!("../../raya-engine/builtins/strict/object.raya")),
         ("strict/error.raya", include_str!("../../raya-engine/builtins/strict/error.raya")),
         ("strict/symbol.raya", include_str!("../../raya-engine/builtins/strict/symbol.raya")),
@@ -35,7 +39,15 @@ fn strict_builtin_source_modules() -> &'static [(&'static str, &'static str)] {
             "strict/buffer.raya",
             include_str!("../../raya-engine/builtins/strict/buffer.raya"),
         ),
+        (
+            "strict/bigint.raya",
+            include_str!("../../raya-engine/builtins/strict/bigint.raya"),
+        ),
         ("strict/date.raya", include_str!("../../raya-engine/builtins/strict/date.raya")),
+        (
+            "strict/random.raya",
+            include_str!("../../raya-engine/builtins/strict/random.raya"),
+        ),
         (
             "strict/channel.raya",
             include_str!("../../raya-engine/builtins/strict/channel.raya"),
@@ -64,6 +76,10 @@ fn node_compat_builtin_source_modules() -> &'static [(&'static str, &'static str
     &[
         ("strict/array.raya", include_str!("../../raya-engine/builtins/strict/array.raya")),
         ("strict/regexp.raya", include_str!("../../raya-engine/builtins/strict/regexp.raya")),
+        (
+            "strict/regexp_match.raya",
+            include_str!("../../raya-engine/builtins/strict/regexp_match.raya"),
+        ),
         (
             "node_compat/object.raya",
             include_str!("../../raya-engine/builtins/node_compat/object.raya"),
@@ -92,6 +108,10 @@ fn node_compat_builtin_source_modules() -> &'static [(&'static str, &'static str
             "node_compat/buffer.raya",
             include_str!("../../raya-engine/builtins/node_compat/buffer.raya"),
         ),
+        (
+            "strict/bigint.raya",
+            include_str!("../../raya-engine/builtins/strict/bigint.raya"),
+        ),
         (
             "node_compat/date.raya",
             include_str!("../../raya-engine/builtins/node_compat/date.raya"),
@@ -148,6 +168,10 @@ fn node_compat_builtin_source_modules() -> &'static [(&'static str, &'static str
             "node_compat/intl.raya",
             include_str!("../../raya-engine/builtins/node_compat/intl.raya"),
         ),
+        (
+            "node_compat/weak_map.raya",
+            include_str!("../../raya-engine/builtins/node_compat/weak_map.raya"),
+        ),
         (
             "node_compat/weak_collections.raya",
             include_str!("../../raya-engine/builtins/node_compat/weak_collections.raya"),
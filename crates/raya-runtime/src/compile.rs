@@ -2,8 +2,9 @@
 //!
 //! Parse → Bind → TypeCheck → Compile to bytecode.
 
-use raya_engine::compiler::{Compiler, Module, MonomorphizationMode};
-use raya_engine::parser::ast::Statement;
+use raya_engine::compiler::ir::PrettyPrint;
+use raya_engine::compiler::{CompileResult, Compiler, Module, MonomorphizationMode};
+use raya_engine::parser::ast::{self, Statement};
 use raya_engine::parser::checker::{
     BindError, Binder, CheckError, CheckWarning, CheckerPolicy, ScopeId, TsTypeFlags, TypeChecker,
     TypeSystemMode,
@@ -18,6 +19,7 @@ use std::path::Path;
 use crate::builtin_manifest;
 use crate::builtins;
 use crate::error::RuntimeError;
+use crate::module_system::{CompiledProgram, ProgramCompiler};
 use crate::BuiltinMode;
 
 /// Checker behavior mode, independent from builtin API surface.
@@ -118,6 +120,8 @@ pub struct CompileOptions {
     pub emit_generic_templates: bool,
     /// Monomorphization strategy for generic specialization.
     pub monomorphization_mode: MonomorphizationMode,
+    /// Compile calls to the builtin `assert` to nothing (`--no-assertions`).
+    pub strip_assertions: bool,
 }
 
 /// Diagnostics returned from a check-only pass (no codegen).
@@ -171,19 +175,48 @@ pub fn compile_source_with_modes_and_ts_options(
     type_mode: TypeMode,
     ts_options: Option<&TsCompilerOptions>,
 ) -> Result<(Module, Interner), RuntimeError> {
-    validate_mode_constraints(builtin_mode, type_mode, ts_options)?;
-    precheck_user_top_level_duplicates(source)?;
-    precheck_node_compat_symbol_usage(source, builtin_mode)?;
+    compile_inline_program(source, builtin_mode, type_mode, ts_options, false)
+}
+
+/// Compile Raya source code to a bytecode module carrying a source map
+/// (bytecode offset → source line), as needed to attribute coverage to lines.
+pub fn compile_source_with_sourcemap(source: &str) -> Result<(Module, Interner), RuntimeError> {
+    let builtin_mode = BuiltinMode::RayaStrict;
+    let type_mode = default_type_mode_for_builtin(builtin_mode);
+    compile_inline_program(source, builtin_mode, type_mode, None, true)
+}
+
+/// Compile Raya source as the entry of a program graph at `entry_path`, so
+/// relative imports resolve next to it; `entry_path` need not exist on disk.
+///
+/// With `sourcemap`, every module of the graph carries a source map
+/// (bytecode offset → source line), as needed to attribute coverage to lines.
+pub fn compile_program_source_at(
+    source: &str,
+    entry_path: &Path,
+    sourcemap: bool,
+) -> Result<CompiledProgram, RuntimeError> {
+    let builtin_mode = BuiltinMode::RayaStrict;
+    let type_mode = default_type_mode_for_builtin(builtin_mode);
+    compile_inline_program_graph(source, entry_path, builtin_mode, type_mode, None, sourcemap)
+}
 
-    use crate::module_system::ProgramCompiler;
+fn compile_inline_program(
+    source: &str,
+    builtin_mode: BuiltinMode,
+    type_mode: TypeMode,
+    ts_options: Option<&TsCompilerOptions>,
+    sourcemap: bool,
+) -> Result<(Module, Interner), RuntimeError> {
     let virtual_entry = Path::new("<inline>.raya");
-    let compiler = ProgramCompiler {
+    let program = compile_inline_program_graph(
+        source,
+        virtual_entry,
         builtin_mode,
         type_mode,
-        ts_options: ts_options.cloned(),
-        compile_options: None,
-    };
-    let program = compiler.compile_program_source(source, &virtual_entry)?;
+        ts_options,
+        sourcemap,
+    )?;
     let module = program.entry.module;
     let interner = program
         .entry
@@ -192,6 +225,28 @@ pub fn compile_source_with_modes_and_ts_options(
     Ok((module, interner))
 }
 
+fn compile_inline_program_graph(
+    source: &str,
+    entry_path: &Path,
+    builtin_mode: BuiltinMode,
+    type_mode: TypeMode,
+    ts_options: Option<&TsCompilerOptions>,
+    sourcemap: bool,
+) -> Result<CompiledProgram, RuntimeError> {
+    validate_mode_constraints(builtin_mode, type_mode, ts_options)?;
+    precheck_user_top_level_duplicates(source)?;
+    precheck_node_compat_symbol_usage(source, builtin_mode)?;
+
+    let compiler = ProgramCompiler {
+        builtin_mode,
+        type_mode,
+        ts_options: ts_options.cloned(),
+        sourcemap,
+        ..Default::default()
+    };
+    compiler.compile_program_source(source, entry_path)
+}
+
 /// Compile Raya source code to a bytecode module with options.
 ///
 /// Same as `compile_source` but allows controlling compilation output
@@ -282,6 +337,54 @@ pub fn compile_graph_source_with_options_and_modes_and_ts_options(
     type_mode: TypeMode,
     ts_options: Option<&TsCompilerOptions>,
 ) -> Result<(Module, Interner), RuntimeError> {
+    compile_graph_source_with(
+        source,
+        options,
+        builtin_mode,
+        type_mode,
+        ts_options,
+        |compiler, ast| compiler.compile_via_ir(ast),
+    )
+}
+
+/// Lower linked module-graph source to optimized IR and render it as text.
+///
+/// Runs the same front end as bytecode compilation, then stops after the
+/// IR optimization pipeline and returns the pretty-printed `.rir` form.
+pub fn compile_graph_source_to_ir_with_options_and_modes_and_ts_options(
+    source: &str,
+    options: &CompileOptions,
+    builtin_mode: BuiltinMode,
+    type_mode: TypeMode,
+    ts_options: Option<&TsCompilerOptions>,
+) -> Result<String, RuntimeError> {
+    let (ir_text, _) = compile_graph_source_with(
+        source,
+        options,
+        builtin_mode,
+        type_mode,
+        ts_options,
+        |compiler, ast| {
+            compiler
+                .compile_to_optimized_ir(ast)
+                .map(|ir_module| ir_module.pretty_print())
+        },
+    )?;
+    Ok(ir_text)
+}
+
+/// Shared Parse → Bind → TypeCheck front end for linked graph source.
+///
+/// `emit` receives the configured compiler and parsed AST and produces the
+/// requested output (bytecode, IR, ...).
+fn compile_graph_source_with<T>(
+    source: &str,
+    options: &CompileOptions,
+    builtin_mode: BuiltinMode,
+    type_mode: TypeMode,
+    ts_options: Option<&TsCompilerOptions>,
+    emit: impl FnOnce(&Compiler, &ast::Module) -> CompileResult<T>,
+) -> Result<(T, Interner), RuntimeError> {
     validate_mode_constraints(builtin_mode, type_mode, ts_options)?;
     precheck_user_top_level_duplicates(source)?;
     precheck_node_compat_symbol_usage(source, builtin_mode)?;
@@ -304,13 +407,11 @@ pub fn compile_graph_source_with_options_and_modes_and_ts_options(
         .map_err(|errors| RuntimeError::Parse(format_parse_errors(&errors, prefix_lines)))?;
 
     // Bind (creates symbol table)
-    let mut type_ctx = TypeContext::new();
+    let mut type_ctx = TypeContext::with_builtins();
     let policy = checker_policy_for_mode(type_mode, ts_options);
-    let mut binder = Binder::new(&mut type_ctx, &interner)
+    let binder = Binder::with_builtins(&mut type_ctx, &interner)
         .with_mode(type_system_mode(type_mode))
         .with_policy(policy);
-    let builtin_sigs = raya_engine::builtins::to_checker_signatures();
-    binder.register_builtins(&builtin_sigs);
 
     let mut symbols = binder.bind_module(&ast).map_err(|errors| {
         RuntimeError::TypeCheck(
@@ -349,12 +450,13 @@ pub fn compile_graph_source_with_options_and_modes_and_ts_options(
         .with_sourcemap(options.sourcemap)
         .with_emit_generic_templates(options.emit_generic_templates)
         .with_monomorphization_mode(options.monomorphization_mode)
+        .with_strip_assertions(options.strip_assertions)
         .with_js_this_binding_compat(true)
         .with_allow_unresolved_runtime_fallback(allow_unresolved_runtime_fallback)
         .with_source_text(full_source.clone());
-    let bytecode = compiler.compile_via_ir(&ast)?;
+    let output = emit(&compiler, &ast)?;
 
-    Ok((bytecode, interner))
+    Ok((output, interner))
 }
 
 /// Type-check Raya source code without generating bytecode.
@@ -392,17 +494,19 @@ pub fn check_source_with_modes_and_ts_options(
     type_mode: TypeMode,
     ts_options: Option<&TsCompilerOptions>,
 ) -> Result<CheckDiagnostics, RuntimeError> {
-    check_graph_source_with_modes_and_ts_options(source, builtin_mode, type_mode, ts_options)
+    check_graph_source_with_modes_and_ts_options(source, builtin_mode, type_mode, ts_options, false)
 }
 
 /// Type-check already-linked module-graph source (Module System V2 path).
 ///
 /// Linked source is type-checked directly with binder-level builtin registration.
+/// `strict_unknown` additionally reports inferred `unknown`/`any` leaks.
 pub fn check_graph_source_with_modes_and_ts_options(
     source: &str,
     builtin_mode: BuiltinMode,
     type_mode: TypeMode,
     ts_options: Option<&TsCompilerOptions>,
+    strict_unknown: bool,
 ) -> Result<CheckDiagnostics, RuntimeError> {
     validate_mode_constraints(builtin_mode, type_mode, ts_options)?;
     precheck_user_top_level_duplicates(source)?;
@@ -423,13 +527,11 @@ pub fn check_graph_source_with_modes_and_ts_options(
         .map_err(|errors| RuntimeError::Parse(format_parse_errors(&errors, prefix_lines)))?;
 
     // Bind
-    let mut type_ctx = TypeContext::new();
+    let mut type_ctx = TypeContext::with_builtins();
     let policy = checker_policy_for_mode(type_mode, ts_options);
-    let mut binder = Binder::new(&mut type_ctx, &interner)
+    let binder = Binder::with_builtins(&mut type_ctx, &interner)
         .with_mode(type_system_mode(type_mode))
         .with_policy(policy);
-    let builtin_sigs = raya_engine::builtins::to_checker_signatures();
-    binder.register_builtins(&builtin_sigs);
 
     let bind_result = binder.bind_module(&ast);
 
@@ -438,7 +540,8 @@ pub fn check_graph_source_with_modes_and_ts_options(
         Ok(symbols) => {
             let checker = TypeChecker::new(&mut type_ctx, &symbols, &interner)
                 .with_mode(type_system_mode(type_mode))
-                .with_policy(policy);
+                .with_policy(policy)
+                .with_strict_unknown(strict_unknown);
             match checker.check_module(&ast) {
                 Ok(mut result) => {
                     if matches!(type_mode, TypeMode::Ts) {
@@ -739,6 +842,134 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_check_unused_import_warning() {
+        let diag =
+            check_source("import { used, unused } from \"./lib\";\nexport { used };\n").unwrap();
+        let unused_imports: Vec<_> = diag
+            .warnings
+            .iter()
+            .filter_map(|w| match w {
+                CheckWarning::UnusedImport { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            unused_imports,
+            vec!["unused"],
+            "warnings: {:?}",
+            diag.warnings
+        );
+    }
+
+    #[test]
+    fn test_check_deprecated_method_call_warning() {
+        let source = r#"
+class Greeter {
+    //@@deprecated("use greet() instead")
+    hello(): string { return "hi"; }
+    greet(): string { return "hi"; }
+}
+const greeter = new Greeter();
+greeter.greet();
+greeter.hello();
+"#;
+        let diag = check_source(source).unwrap();
+        let deprecated: Vec<_> = diag
+            .warnings
+            .iter()
+            .filter_map(|w| match w {
+                CheckWarning::Deprecated { name, message, .. } => Some((name, message)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(deprecated.len(), 1, "warnings: {:?}", diag.warnings);
+        assert_eq!(deprecated[0].0, "Greeter.hello");
+        assert_eq!(deprecated[0].1, "use greet() instead");
+    }
+
+    #[test]
+    fn test_check_suspicious_equality_warning() {
+        let source = r#"
+const n: number = 1;
+const s: string = "1";
+const mixed = n == s;
+const same = n == 2;
+const differs = 1 != "1";
+"#;
+        let diag = check_source(source).unwrap();
+        let suspicious: Vec<_> = diag
+            .warnings
+            .iter()
+            .filter_map(|w| match w {
+                CheckWarning::SuspiciousEquality {
+                    left,
+                    right,
+                    always_true,
+                    span,
+                } => Some((left.as_str(), right.as_str(), *always_true, span.line)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            suspicious,
+            vec![
+                ("number", "string", false, 4),
+                ("number", "string", true, 6)
+            ],
+            "warnings: {:?}",
+            diag.warnings
+        );
+    }
+
+    #[test]
+    fn test_check_strict_unknown_warns_on_unannotated_parameter() {
+        let source = r#"
+function identity(value) {
+    return value;
+}
+function annotated(value: unknown): unknown {
+    return value;
+}
+identity(1);
+annotated(2);
+"#;
+        let ts_options = permissive_ts_options();
+        let check = |strict_unknown: bool| {
+            check_graph_source_with_modes_and_ts_options(
+                source,
+                BuiltinMode::NodeCompat,
+                TypeMode::Ts,
+                Some(&ts_options),
+                strict_unknown,
+            )
+            .unwrap()
+        };
+        let inferred_unknown = |diag: &CheckDiagnostics| {
+            diag.warnings
+                .iter()
+                .filter_map(|w| match w {
+                    CheckWarning::InferredUnknown { position, span, .. } => {
+                        Some((position.clone(), span.line))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let diag = check(true);
+        // Only the un-annotated parameter leaks; the explicit `unknown` does not.
+        assert_eq!(
+            inferred_unknown(&diag),
+            vec![("return value".to_string(), 3)],
+            "warnings: {:?}",
+            diag.warnings
+        );
+
+        let diag = check(false);
+        assert!(inferred_unknown(&diag).is_empty(), "{:?}", diag.warnings);
+    }
+
     #[test]
     fn test_check_source_returns_original_source() {
         let diag = check_source("let x = 1;").unwrap();
@@ -759,6 +990,36 @@ mod tests {
         assert!(result.is_ok(), "compile_source should still work");
     }
 
+    #[test]
+    fn test_constant_division_by_zero_traps_at_source_site() {
+        let options = CompileOptions {
+            sourcemap: true,
+            ..CompileOptions::default()
+        };
+        let (module, _) = compile_graph_source_with_options_and_modes_and_ts_options(
+            "let a = 6 / 3;\nlet b = 1 / 0;\nreturn a + b;\n",
+            &options,
+            BuiltinMode::RayaStrict,
+            TypeMode::Raya,
+            None,
+        )
+        .expect("source should compile");
+
+        let mut vm = raya_engine::vm::Vm::new();
+        let error = vm
+            .execute(&module)
+            .expect_err("1 / 0 should trap at runtime, not be folded away");
+        assert!(
+            error.root().to_string().contains("division by zero"),
+            "got: {}",
+            error
+        );
+        let location = error
+            .location()
+            .expect("trap should carry the source location");
+        assert_eq!(location.line, 2, "got: {}", error);
+    }
+
     #[test]
     fn test_node_path_import_is_supported() {
         let result = compile_source_with_mode(
@@ -1639,7 +1900,7 @@ mod tests {
             let n = Number("42");
             let s = String(42);
             let a = new Array<number>(2);
-            return b && n == 42 && s == "42" && a.length == 2;
+            return b && n == 42 && s == "42" && a.length == 0;
             "#,
         );
         assert!(
@@ -1745,7 +2006,7 @@ mod tests {
     fn test_weakmap_not_available_in_strict_mode_with_explicit_error_code() {
         let result = compile_source(
             r#"
-            let w = new WeakMap<number>();
+            let w = new WeakMap<Object, number>();
             let k = new Object();
             w.set(k, 1);
             return w.has(k);
@@ -1770,7 +2031,7 @@ mod tests {
     fn test_weakmap_available_in_node_compat_mode() {
         let result = compile_source_with_mode(
             r#"
-            let w = new WeakMap<number>();
+            let w = new WeakMap<Object, number>();
             let k = new Object();
             w.set(k, 7);
             return w.has(k);
@@ -2,7 +2,7 @@
 
 use raya_pm::{Dependency, Lockfile, PackageManifest};
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 use crate::error::RuntimeError;
@@ -16,14 +16,20 @@ use crate::TypeMode;
 /// - Path dependencies: compiled from source or loaded as .ryb
 /// - URL/git dependencies: loaded from cache (must be pre-installed)
 /// - Registry packages: loaded from .raya/packages/ (preferred) or raya_packages/ (legacy)
+///
+/// Dependencies named in `skip` were already supplied elsewhere and are not loaded.
 pub fn load_dependencies(
     manifest: &PackageManifest,
     manifest_dir: &Path,
+    skip: &HashSet<String>,
 ) -> Result<Vec<CompiledModule>, RuntimeError> {
     let mut deps = Vec::new();
     let lock_modes = load_lockfile_type_modes(manifest_dir);
 
     for (name, dep) in &manifest.dependencies {
+        if skip.contains(name) {
+            continue;
+        }
         let module = load_dependency(name, dep, manifest_dir, lock_modes.get(name).copied())?;
         deps.push(module);
     }
@@ -32,8 +38,11 @@ pub fn load_dependencies(
 }
 
 /// Load dependencies declared in package.json (dependencies only).
+///
+/// Dependencies named in `skip` were already supplied elsewhere and are not loaded.
 pub fn load_dependencies_from_package_json(
     manifest_dir: &Path,
+    skip: &HashSet<String>,
 ) -> Result<Vec<CompiledModule>, RuntimeError> {
     let package_json_path = manifest_dir.join("package.json");
     let content = std::fs::read_to_string(&package_json_path).map_err(|e| {
@@ -54,7 +63,7 @@ pub fn load_dependencies_from_package_json(
     let mut deps = Vec::new();
     let lock_modes = load_lockfile_type_modes(manifest_dir);
     if let Some(obj) = value.get("dependencies").and_then(|v| v.as_object()) {
-        for name in obj.keys() {
+        for name in obj.keys().filter(|name| !skip.contains(*name)) {
             deps.push(load_registry_dep(
                 name,
                 manifest_dir,
@@ -37,4 +37,8 @@ pub enum RuntimeError {
     /// Dependency resolution error
     #[error("{0}")]
     Dependency(String),
+
+    /// Execution stopped by the caller's cancellation flag
+    #[error("Execution cancelled")]
+    Cancelled,
 }
@@ -26,12 +26,14 @@
 
 mod builtin_manifest;
 mod builtins;
+pub mod bench_runner;
 pub mod bundle;
 pub mod compile;
 pub mod deps;
 pub mod error;
 pub mod loader;
 pub mod module_system;
+pub mod permissions;
 pub mod session;
 pub mod test_runner;
 mod vm_setup;
@@ -47,9 +49,11 @@ pub use raya_stdlib::StdNativeHandler;
 pub use raya_stdlib_posix::register_posix;
 
 pub use error::RuntimeError;
+pub use loader::ModuleResolver;
 pub use module_system::{CompiledProgram, ProgramDiagnostics};
 pub use session::Session;
 
+use raya_engine::compiler::lower::LoweringCache;
 use raya_engine::compiler::module::{
     builtin_global_exports, BuiltinSurfaceMode, LateLinkRequirement, LateLinkSymbolRequirement,
 };
@@ -81,8 +85,9 @@ use raya_engine::vm::scheduler::{Task, TaskState};
 use raya_engine::vm::VmError;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::Path;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 
 const IMPORTED_CLASS_TYPE_HANDLE_KEY: &str = "__raya_type_handle__";
 
@@ -113,16 +118,33 @@ pub struct RuntimeOptions {
     pub heap_limit: usize,
     /// Execution timeout in milliseconds (0 = unlimited).
     pub timeout: u64,
+    /// Maximum stack size per task in bytes (0 = engine default).
+    pub stack_size: usize,
+    /// Maximum open file handles held by native calls (0 = unlimited).
+    pub max_open_files: usize,
+    /// Maximum open sockets held by native calls (0 = unlimited).
+    pub max_open_sockets: usize,
     /// Disable JIT compilation (interpreter only).
     pub no_jit: bool,
     /// JIT adaptive compilation call threshold.
     pub jit_threshold: u32,
+    /// Make float results bit-identical between the interpreter and the JIT:
+    /// no FMA contraction, and canonical NaNs from float arithmetic.
+    pub deterministic_float: bool,
     /// Enable CPU profiling and write output to this path.
     /// None = profiling disabled. Format is inferred from extension:
     /// `.cpuprofile` → Chrome DevTools JSON, anything else → folded stacks.
     pub cpu_prof: Option<std::path::PathBuf>,
     /// Profiling sample interval in microseconds (default: 10_000 = 10ms / 100Hz).
     pub prof_interval_us: u64,
+    /// Load a `.rayaprof` hot-function profile and JIT-compile the recorded
+    /// functions at module load.
+    pub profile_use: Option<std::path::PathBuf>,
+    /// Record hot functions during the run and write them to this `.rayaprof`.
+    pub profile_gen: Option<std::path::PathBuf>,
+    /// Write a Chrome DevTools `.heapsnapshot` of the GC heap to this path
+    /// when the program exits.
+    pub heap_snapshot: Option<std::path::PathBuf>,
     /// Builtin API mode (strict Raya vs node-compat surface).
     pub builtin_mode: BuiltinMode,
     /// Optional type-system mode override.
@@ -130,6 +152,27 @@ pub struct RuntimeOptions {
     pub type_mode: Option<TypeMode>,
     /// Optional TS compiler options payload for `TypeMode::Ts`.
     pub ts_options: Option<TsCompilerOptions>,
+    /// Features enabled for `//@@if(feature)` conditional compilation.
+    pub features: Vec<String>,
+    /// Compile calls to the builtin `assert` to nothing.
+    pub strip_assertions: bool,
+    /// Warn where type inference produces `unknown`/`any` when checking.
+    pub strict_unknown: bool,
+    /// Arguments passed to the program, exposed as `process.argv()`.
+    pub program_args: Vec<String>,
+    /// Policy consulted before every native call.
+    /// None = loaded from the `[permissions]` section of `raya.toml` when
+    /// running a file, otherwise unrestricted.
+    pub native_policy: Option<raya_engine::vm::NativeCallPolicy>,
+    /// Defer a dependency module's top-level init until the first call into it.
+    /// Only modules whose importers use nothing but exported functions are deferred.
+    pub lazy_module_init: bool,
+    /// Modules initialized at load even with lazy init on, by module name
+    /// (package name or source path).
+    pub eager_modules: Vec<String>,
+    /// Resolver consulted for every import before manifest dependencies and
+    /// the filesystem. None = imports resolve from `raya.toml` and adjacent files only.
+    pub module_resolver: Option<ModuleResolver>,
 }
 
 impl Default for RuntimeOptions {
@@ -138,13 +181,28 @@ impl Default for RuntimeOptions {
             threads: 0,
             heap_limit: 0,
             timeout: 0,
+            stack_size: 0,
+            max_open_files: 0,
+            max_open_sockets: 0,
             no_jit: false,
             jit_threshold: 1000,
+            deterministic_float: false,
             cpu_prof: None,
             prof_interval_us: 10_000,
+            profile_use: None,
+            profile_gen: None,
+            heap_snapshot: None,
             builtin_mode: BuiltinMode::RayaStrict,
             type_mode: None,
             ts_options: None,
+            features: Vec::new(),
+            strip_assertions: false,
+            strict_unknown: false,
+            program_args: Vec::new(),
+            native_policy: None,
+            lazy_module_init: true,
+            eager_modules: Vec::new(),
+            module_resolver: None,
         }
     }
 }
@@ -158,6 +216,10 @@ pub struct CompiledModule {
     pub(crate) module: Module,
     /// String interner (present when compiled from source, None for .ryb loads).
     pub(crate) interner: Option<Interner>,
+    /// Lowered functions of this module (present when compiled via
+    /// `Runtime::compile_file` or `Runtime::compile_incremental`), reused by
+    /// the next incremental compile.
+    pub(crate) lowering_cache: Option<LoweringCache>,
 }
 
 impl CompiledModule {
@@ -175,6 +237,50 @@ impl CompiledModule {
     pub fn module(&self) -> &Module {
         &self.module
     }
+
+    /// Top-level functions the compile that produced this module had to lower
+    /// (the rest were reused). `None` unless it came from
+    /// `Runtime::compile_file` or `Runtime::compile_incremental`.
+    pub fn relowered_functions(&self) -> Option<&[String]> {
+        self.lowering_cache
+            .as_ref()
+            .map(LoweringCache::lowered_functions)
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Garbage collector statistics from the most recent execution.
+///
+/// Every byte the heap handed out is either still allocated or was reclaimed,
+/// so `total_allocated_bytes == live_bytes + bytes_freed`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GcStats {
+    /// Bytes allocated over the whole execution, including reclaimed objects.
+    pub total_allocated_bytes: usize,
+    /// Bytes still allocated when execution finished.
+    pub live_bytes: usize,
+    /// Number of collections that ran.
+    pub collections: usize,
+    /// Bytes reclaimed by those collections.
+    pub bytes_freed: usize,
+    /// Pause time of the last collection, in microseconds (0 if none ran).
+    pub last_pause_us: u64,
+}
+
+impl GcStats {
+    /// Read the current counters of `vm`, for hosts that manage their own VM.
+    pub fn from_vm(vm: &raya_engine::vm::Vm) -> Self {
+        let gc = vm.gc_stats();
+        let heap = vm.heap_stats();
+        Self {
+            total_allocated_bytes: heap.total_allocated_bytes,
+            live_bytes: heap.allocated_bytes,
+            collections: gc.collections,
+            bytes_freed: gc.bytes_freed,
+            last_pause_us: gc.last_pause_time.as_micros() as u64,
+        }
+    }
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -189,6 +295,7 @@ impl CompiledModule {
 /// ```
 pub struct Runtime {
     options: RuntimeOptions,
+    last_gc_stats: Mutex<Option<GcStats>>,
 }
 
 impl Default for Runtime {
@@ -202,6 +309,7 @@ impl Runtime {
         &self,
         source: &str,
         virtual_entry: &Path,
+        host_globals: &[(String, String)],
     ) -> Result<CompiledProgram, RuntimeError> {
         let type_mode = self
             .options
@@ -213,8 +321,11 @@ impl Runtime {
             type_mode,
             ts_options,
             compile_options: None,
+            features: self.options.features.clone(),
+            strip_assertions: self.options.strip_assertions,
+            ..Default::default()
         };
-        compiler.compile_program_source(source, virtual_entry)
+        compiler.compile_program_source_with_host_globals(source, virtual_entry, host_globals)
     }
 
     fn resolve_ts_options_for_inline(&self) -> Result<Option<TsCompilerOptions>, RuntimeError> {
@@ -253,14 +364,15 @@ impl Runtime {
 
     /// Create a runtime with default options.
     pub fn new() -> Self {
-        Self {
-            options: RuntimeOptions::default(),
-        }
+        Self::with_options(RuntimeOptions::default())
     }
 
     /// Create a runtime with custom options.
     pub fn with_options(options: RuntimeOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            last_gc_stats: Mutex::new(None),
+        }
     }
 
     /// Access the runtime options.
@@ -268,6 +380,13 @@ impl Runtime {
         &self.options
     }
 
+    /// GC statistics from the most recent execution on this runtime.
+    ///
+    /// `None` until an execution completes.
+    pub fn gc_stats(&self) -> Option<GcStats> {
+        *self.last_gc_stats.lock().unwrap()
+    }
+
     // ── Compilation ──────────────────────────────────────────────────────
 
     /// Compile a Raya source string to a bytecode module.
@@ -289,6 +408,7 @@ impl Runtime {
         Ok(CompiledModule {
             module,
             interner: Some(interner),
+            lowering_cache: None,
         })
     }
 
@@ -296,12 +416,41 @@ impl Runtime {
     ///
     /// Returns the entry module plus all compiled dependencies and late-link metadata.
     pub fn compile_program_source(&self, source: &str) -> Result<CompiledProgram, RuntimeError> {
-        self.compile_program_source_with_virtual_entry(source, Path::new("<inline>.raya"))
+        self.compile_program_source_with_virtual_entry(source, Path::new("<inline>.raya"), &[])
     }
 
     /// Compile a .raya source file to a bytecode module.
+    ///
+    /// The result keeps the lowered IR of its top-level functions so it can
+    /// be passed to [`Runtime::compile_incremental`].
     pub fn compile_file(&self, path: &Path) -> Result<CompiledModule, RuntimeError> {
-        Ok(self.compile_program_file(path)?.entry)
+        let mut compiler = self.program_compiler(path)?;
+        compiler.lowering_cache = Some(LoweringCache::new());
+        Ok(compiler.compile_program_file(path)?.entry)
+    }
+
+    /// Recompile a .raya file, re-lowering only the functions that changed
+    /// since `previous`.
+    ///
+    /// Meant for watch-mode hosts: pass the module returned by `compile_file`
+    /// or by the previous call. Only lowering is incremental: the file is
+    /// parsed and type-checked again (with its imports), and bytecode is
+    /// generated for the whole module, but a top-level function whose source
+    /// text and checker types are unchanged reuses its lowered IR from
+    /// `previous`, so its bytecode comes out identical. Moving it, or editing
+    /// whitespace and comments around it, does not count as a change. Classes
+    /// and top-level statements are always lowered, and a change to anything
+    /// a function can see — another declaration, a signature, an import —
+    /// lowers every function again. A `previous` loaded from bytecode or
+    /// compiled from a source string reuses nothing.
+    pub fn compile_incremental(
+        &self,
+        path: &Path,
+        previous: &CompiledModule,
+    ) -> Result<CompiledModule, RuntimeError> {
+        let mut compiler = self.program_compiler(path)?;
+        compiler.lowering_cache = Some(previous.lowering_cache.clone().unwrap_or_default());
+        Ok(compiler.compile_program_file(path)?.entry)
     }
 
     /// Compile a Raya source string with options (e.g., source map).
@@ -325,6 +474,7 @@ impl Runtime {
         Ok(CompiledModule {
             module,
             interner: Some(interner),
+            lowering_cache: None,
         })
     }
 
@@ -337,6 +487,20 @@ impl Runtime {
         Ok(self.compile_program_file_with_options(path, options)?.entry)
     }
 
+    /// Lower a .raya source file to optimized IR and return its textual form.
+    ///
+    /// The file is compiled with the same options as `compile_file_with_options`
+    /// (features, assertion stripping, imports); only the entry module's IR is
+    /// returned.
+    pub fn compile_file_to_ir(
+        &self,
+        path: &Path,
+        options: &compile::CompileOptions,
+    ) -> Result<String, RuntimeError> {
+        self.program_compiler_with_options(path, options)?
+            .compile_program_file_to_ir(path)
+    }
+
     // ── Checking ─────────────────────────────────────────────────────────
 
     /// Type-check a Raya source string without generating bytecode.
@@ -348,11 +512,12 @@ impl Runtime {
             .type_mode
             .unwrap_or_else(|| compile::default_type_mode_for_builtin(self.options.builtin_mode));
         let ts_options = self.resolve_ts_options_for_inline()?;
-        compile::check_source_with_modes_and_ts_options(
+        compile::check_graph_source_with_modes_and_ts_options(
             source,
             self.options.builtin_mode,
             type_mode,
             ts_options.as_ref(),
+            self.options.strict_unknown,
         )
     }
 
@@ -363,19 +528,28 @@ impl Runtime {
 
     /// Compile a full file program (entry + resolved local module graph).
     pub fn compile_program_file(&self, path: &Path) -> Result<CompiledProgram, RuntimeError> {
+        self.program_compiler(path)?.compile_program_file(path)
+    }
+
+    fn program_compiler(
+        &self,
+        path: &Path,
+    ) -> Result<module_system::ProgramCompiler, RuntimeError> {
         let type_mode = self
             .options
             .type_mode
             .unwrap_or_else(|| compile::default_type_mode_for_builtin(self.options.builtin_mode));
         let ts_options = self.resolve_ts_options_for_path(path)?;
 
-        let compiler = module_system::ProgramCompiler {
+        Ok(module_system::ProgramCompiler {
             builtin_mode: self.options.builtin_mode,
             type_mode,
             ts_options,
             compile_options: None,
-        };
-        compiler.compile_program_file(path)
+            features: self.options.features.clone(),
+            strip_assertions: self.options.strip_assertions,
+            ..Default::default()
+        })
     }
 
     /// Compile a full file program (entry + resolved local module graph) with options.
@@ -384,13 +558,22 @@ impl Runtime {
         path: &Path,
         options: &compile::CompileOptions,
     ) -> Result<CompiledProgram, RuntimeError> {
+        self.program_compiler_with_options(path, options)?
+            .compile_program_file(path)
+    }
+
+    fn program_compiler_with_options(
+        &self,
+        path: &Path,
+        options: &compile::CompileOptions,
+    ) -> Result<module_system::ProgramCompiler, RuntimeError> {
         let type_mode = self
             .options
             .type_mode
             .unwrap_or_else(|| compile::default_type_mode_for_builtin(self.options.builtin_mode));
         let ts_options = self.resolve_ts_options_for_path(path)?;
 
-        let compiler = module_system::ProgramCompiler {
+        Ok(module_system::ProgramCompiler {
             builtin_mode: self.options.builtin_mode,
             type_mode,
             ts_options,
@@ -404,8 +587,10 @@ impl Runtime {
             } else {
                 None
             },
-        };
-        compiler.compile_program_file(path)
+            features: self.options.features.clone(),
+            strip_assertions: options.strip_assertions || self.options.strip_assertions,
+            ..Default::default()
+        })
     }
 
     /// Type-check a full file program (entry + resolved local module graph).
@@ -421,6 +606,9 @@ impl Runtime {
             type_mode,
             ts_options,
             compile_options: None,
+            features: self.options.features.clone(),
+            strip_assertions: self.options.strip_assertions,
+            ..Default::default()
         };
         compiler.check_program_file(path)
     }
@@ -442,19 +630,53 @@ impl Runtime {
     /// Execute a compiled module and return the VM result value.
     pub fn execute(&self, module: &CompiledModule) -> Result<Value, RuntimeError> {
         let mut vm = vm_setup::create_vm(&self.options);
-        self.ensure_ambient_builtin_globals_seeded(&mut vm)?;
+        self.execute_in_vm(&mut vm, module)
+    }
+
+    /// Execute a compiled module that can be stopped from another thread.
+    ///
+    /// Setting `cancel` makes the VM unwind at its next safepoint, and this
+    /// call returns [`RuntimeError::Cancelled`]. `RuntimeOptions::timeout`
+    /// still applies.
+    pub fn execute_cancellable(
+        &self,
+        module: &CompiledModule,
+        cancel: Arc<AtomicBool>,
+    ) -> Result<Value, RuntimeError> {
+        let mut vm = vm_setup::create_vm(&self.options);
+        vm.set_cancel_flag(Some(cancel));
+        match self.execute_in_vm(&mut vm, module) {
+            Err(RuntimeError::Vm(error)) if matches!(error.root(), VmError::Cancelled) => {
+                Err(RuntimeError::Cancelled)
+            }
+            result => result,
+        }
+    }
+
+    /// Execute `module` on a freshly created `vm`, then write any requested
+    /// profiles/snapshots.
+    fn execute_in_vm(
+        &self,
+        vm: &mut raya_engine::vm::Vm,
+        module: &CompiledModule,
+    ) -> Result<Value, RuntimeError> {
+        self.ensure_ambient_builtin_globals_seeded(vm)?;
         vm.shared_state()
             .register_module(Arc::new(module.module.clone()))
             .map_err(RuntimeError::Dependency)?;
-        self.maybe_enable_jit(&mut vm);
-        self.maybe_enable_profiling(&vm);
+        self.maybe_enable_jit(vm);
+        self.maybe_enable_profiling(vm);
         let result = if self.options.builtin_mode == BuiltinMode::RayaStrict {
             vm.execute_entry_only(&module.module)?
         } else {
             vm.execute(&module.module)?
         };
-        self.maybe_write_profile(&vm, &module.module);
-        self.maybe_emit_jit_telemetry(&vm);
+        Self::run_exit_hooks(vm)?;
+        self.maybe_write_profile(vm, &module.module);
+        self.maybe_write_hot_profile(vm);
+        self.maybe_write_heap_snapshot(vm);
+        self.maybe_emit_jit_telemetry(vm);
+        self.record_gc_stats(vm);
         Ok(result)
     }
 
@@ -470,16 +692,31 @@ impl Runtime {
         let mut vm = vm_setup::create_vm(&self.options);
         let result = self.execute_with_deps_in_vm(&mut vm, module, deps)?;
         self.maybe_write_profile(&vm, &module.module);
+        self.maybe_write_hot_profile(&vm);
+        self.maybe_write_heap_snapshot(&vm);
         self.maybe_emit_jit_telemetry(&vm);
+        self.record_gc_stats(&vm);
         Ok(result)
     }
 
     /// Execute a compiled program graph, resolving declaration-backed late links at runtime.
     pub fn execute_program(&self, program: &CompiledProgram) -> Result<Value, RuntimeError> {
         let mut vm = vm_setup::create_vm(&self.options);
-        let result = self.execute_program_with_vm(program, &mut vm)?;
-        self.maybe_write_profile(&vm, &program.entry.module);
-        self.maybe_emit_jit_telemetry(&vm);
+        self.execute_program_and_report(program, &mut vm)
+    }
+
+    /// Execute `program` on `vm`, then write any requested profiles/snapshots.
+    fn execute_program_and_report(
+        &self,
+        program: &CompiledProgram,
+        vm: &mut raya_engine::vm::Vm,
+    ) -> Result<Value, RuntimeError> {
+        let result = self.execute_program_with_vm(program, vm)?;
+        self.maybe_write_profile(vm, &program.entry.module);
+        self.maybe_write_hot_profile(vm);
+        self.maybe_write_heap_snapshot(vm);
+        self.maybe_emit_jit_telemetry(vm);
+        self.record_gc_stats(vm);
         Ok(result)
     }
 
@@ -506,10 +743,94 @@ impl Runtime {
     /// ```
     pub fn eval(&self, code: &str) -> Result<Value, RuntimeError> {
         let program =
-            self.compile_program_source_with_virtual_entry(code, Path::new("<eval>.raya"))?;
+            self.compile_program_source_with_virtual_entry(code, Path::new("<eval>.raya"), &[])?;
         self.execute_program(&program)
     }
 
+    /// Compile and execute a source string with host values bound to names.
+    ///
+    /// Each binding is a read-only global of the snippet; a top-level
+    /// declaration with the same name is an error. Values may be null,
+    /// booleans, numbers, or strings (copied into the evaluating VM).
+    ///
+    /// ```rust,ignore
+    /// let rt = Runtime::new();
+    /// let value = rt.eval_with_bindings(
+    ///     "return x + y;",
+    ///     &[("x", Value::i32(1)), ("y", Value::i32(2))],
+    /// )?;
+    /// ```
+    pub fn eval_with_bindings(
+        &self,
+        code: &str,
+        bindings: &[(&str, Value)],
+    ) -> Result<Value, RuntimeError> {
+        let mut host_globals = Vec::with_capacity(bindings.len());
+        for (index, &(name, value)) in bindings.iter().enumerate() {
+            if bindings[..index].iter().any(|&(other, _)| other == name) {
+                return Err(RuntimeError::TypeCheck(format!(
+                    "Host binding '{}' is bound more than once",
+                    name
+                )));
+            }
+            let signature = Self::host_binding_signature(name, value)?;
+            host_globals.push((name.to_string(), signature.to_string()));
+        }
+        let program = self.compile_program_source_with_virtual_entry(
+            code,
+            Path::new("<eval>.raya"),
+            &host_globals,
+        )?;
+
+        let mut vm = vm_setup::create_vm(&self.options);
+        self.ensure_ambient_builtin_globals_seeded(&mut vm)?;
+        for &(name, value) in bindings {
+            let value = match Self::host_binding_string(value) {
+                Some(string) => {
+                    let gc_ptr = vm
+                        .shared_state()
+                        .gc
+                        .lock()
+                        .allocate(RayaString::new(string));
+                    unsafe { Value::from_ptr(std::ptr::NonNull::new(gc_ptr.as_ptr()).unwrap()) }
+                }
+                None => value,
+            };
+            vm.shared_state().set_builtin_global(name, value);
+        }
+        self.execute_program_and_report(&program, &mut vm)
+    }
+
+    /// Type signature the checker sees for a host binding.
+    fn host_binding_signature(name: &str, value: Value) -> Result<&'static str, RuntimeError> {
+        if value.is_null() {
+            Ok("null")
+        } else if value.is_bool() {
+            Ok("boolean")
+        } else if value.is_i32() {
+            Ok("int")
+        } else if value.is_f64() {
+            Ok("number")
+        } else if Self::host_binding_string(value).is_some() {
+            Ok("string")
+        } else {
+            Err(RuntimeError::TypeCheck(format!(
+                "Host binding '{}' must be null, a boolean, a number, or a string",
+                name
+            )))
+        }
+    }
+
+    fn host_binding_string(value: Value) -> Option<String> {
+        if !value.is_ptr() {
+            return None;
+        }
+        match raya_engine::vm::json::js_classify(value) {
+            JSView::Str(ptr) => Some(unsafe { &*ptr }.as_str().to_string()),
+            _ => None,
+        }
+    }
+
     /// Run a file (.raya or .ryb), auto-detecting format by extension.
     ///
     /// - `.raya` files are compiled from source then executed.
@@ -526,6 +847,10 @@ impl Runtime {
             path.to_path_buf()
         };
 
+        if let Some(options) = self.project_run_options(&path)? {
+            return Runtime::with_options(options).run_file(&path);
+        }
+
         if path.extension().and_then(|e| e.to_str()) == Some("raya")
             && self.can_use_binary_program_execution()
         {
@@ -560,7 +885,7 @@ impl Runtime {
 
         // Try to load dependencies from raya.toml or adjacent .ryb files
         let file_dir = path.parent().unwrap_or(Path::new("."));
-        let dep_modules = self.resolve_deps_for_file(&path, file_dir)?;
+        let dep_modules = self.resolve_deps_for_file(&module, &path, file_dir)?;
 
         let result = if dep_modules.is_empty() {
             self.execute(&module)
@@ -578,6 +903,25 @@ impl Runtime {
         }
     }
 
+    /// Run source text that has no file location (e.g. piped on stdin).
+    ///
+    /// Relative imports resolve against the current directory and manifest
+    /// dependency resolution is skipped. Returns the process exit code like
+    /// [`Runtime::run_file`].
+    pub fn run_source(&self, source: &str) -> Result<i32, RuntimeError> {
+        let virtual_entry = std::env::current_dir()?.join("<stdin>.raya");
+        let program =
+            self.compile_program_source_with_virtual_entry(source, &virtual_entry, &[])?;
+        match self.execute_program(&program) {
+            Ok(_) => Ok(0),
+            Err(RuntimeError::Vm(e)) => {
+                eprintln!("Runtime error: {}", e);
+                Ok(1)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     #[cfg(feature = "aot")]
     fn run_bundle_file(&self, path: &Path) -> Result<i32, RuntimeError> {
         let payload = bundle::loader::detect_bundle_at(path).ok_or_else(|| {
@@ -587,7 +931,10 @@ impl Runtime {
         let mut vm = vm_setup::create_vm(&self.options);
         let result = self.execute_bundle_with_vm(&mut vm, &module, &payload);
         self.maybe_write_profile(&vm, &module.module);
+        self.maybe_write_hot_profile(&vm);
+        self.maybe_write_heap_snapshot(&vm);
         self.maybe_emit_jit_telemetry(&vm);
+        self.record_gc_stats(&vm);
         match result {
             Ok(_) => Ok(0),
             Err(RuntimeError::Vm(e)) => {
@@ -694,6 +1041,52 @@ impl Runtime {
         Ok(ordered)
     }
 
+    /// Modules in `init_order` whose top-level init can wait for the first
+    /// call into them.
+    ///
+    /// A module qualifies only if every import of it is a function: calls are
+    /// where the VM runs a deferred init, while constants, classes and
+    /// namespaces are materialized from module state at link time.
+    fn compute_lazy_init_modules(
+        &self,
+        linker: &ModuleLinker,
+        init_order: &[Arc<Module>],
+        entry_module: &Arc<Module>,
+    ) -> Result<HashSet<String>, RuntimeError> {
+        if !self.options.lazy_module_init {
+            return Ok(HashSet::new());
+        }
+
+        let mut function_only = HashMap::new();
+        for module in init_order {
+            if module.imports.is_empty() {
+                continue;
+            }
+            let resolved = linker.link_module(module).map_err(|error| {
+                RuntimeError::Dependency(format!(
+                    "Runtime module link validation failed for '{}': {}",
+                    module.metadata.name, error
+                ))
+            })?;
+            for (import, resolved_symbol) in module.imports.iter().zip(resolved.iter()) {
+                let is_function = import.symbol != "*"
+                    && matches!(resolved_symbol.export.symbol_type, SymbolType::Function);
+                *function_only
+                    .entry(resolved_symbol.module.metadata.name.clone())
+                    .or_insert(true) &= is_function;
+            }
+        }
+
+        Ok(init_order
+            .iter()
+            .filter(|module| module.metadata.name != entry_module.metadata.name)
+            .filter(|module| function_only.get(&module.metadata.name) == Some(&true))
+            .filter(|module| module.functions.iter().any(|f| f.name == "main"))
+            .filter(|module| !self.is_eager_module(module))
+            .map(|module| module.metadata.name.clone())
+            .collect())
+    }
+
     fn collect_program_dependencies(
         &self,
         program: &CompiledProgram,
@@ -704,6 +1097,7 @@ impl Runtime {
             .map(|dep| CompiledModule {
                 module: dep.module.clone(),
                 interner: None,
+                lowering_cache: None,
             })
             .collect::<Vec<_>>();
 
@@ -1239,6 +1633,7 @@ impl Runtime {
                 ))
             })?;
         let init_order = self.compute_module_init_order(&linker, &entry_module)?;
+        let lazy_modules = self.compute_lazy_init_modules(&linker, &init_order, &entry_module)?;
 
         let mut entry_result = None;
         for current_module in init_order {
@@ -1258,6 +1653,10 @@ impl Runtime {
                     vm.execute(&current_module)?
                 };
                 entry_result = Some(result);
+            } else if lazy_modules.contains(&current_module.metadata.name) {
+                // Top-level init runs on the first call into the module.
+                vm.shared_state().mark_module_lazy(&current_module);
+                continue;
             } else {
                 // Dependency modules must execute once to materialize module-level state
                 // (default export objects, initialized globals, static setup).
@@ -1274,6 +1673,10 @@ impl Runtime {
             vm.shared_state().mark_module_initialized(&current_module);
         }
 
+        if entry_result.is_some() {
+            Self::run_exit_hooks(vm)?;
+        }
+
         entry_result.ok_or_else(|| {
             RuntimeError::Dependency(format!(
                 "Entry module '{}' was not executed during dependency initialization",
@@ -1955,9 +2358,19 @@ impl Runtime {
                 return;
             }
 
+            let hot_profile = self.options.profile_use.as_ref().and_then(|path| {
+                match raya_engine::jit::profiling::hot_profile::HotProfile::read_from_file(path) {
+                    Ok(profile) => Some(Arc::new(profile)),
+                    Err(e) => {
+                        eprintln!("Warning: ignoring --profile-use: {e}");
+                        None
+                    }
+                }
+            });
             let config = raya_engine::jit::JitConfig {
                 adaptive_compilation: true,
                 call_threshold: self.options.jit_threshold,
+                hot_profile,
                 ..Default::default()
             };
 
@@ -1979,6 +2392,9 @@ impl Runtime {
                 ..Default::default()
             };
             vm.enable_profiling(config);
+            if let Some(profiler) = vm.profiler() {
+                profiler.start();
+            }
         }
     }
 
@@ -2013,6 +2429,69 @@ impl Runtime {
         }
     }
 
+    fn maybe_write_hot_profile(&self, vm: &raya_engine::vm::Vm) {
+        let Some(ref path) = self.options.profile_gen else {
+            return;
+        };
+
+        #[cfg(feature = "jit")]
+        {
+            if self.options.no_jit {
+                eprintln!("Warning: --profile-gen has no effect with --no-jit");
+                return;
+            }
+            if let Err(e) = vm.jit_hot_profile().write_to_file(path) {
+                eprintln!(
+                    "Warning: failed to write hot profile to {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+
+        #[cfg(not(feature = "jit"))]
+        {
+            let _ = vm;
+            eprintln!(
+                "Warning: --profile-gen requires JIT support; {} was not written",
+                path.display()
+            );
+        }
+    }
+
+    /// Run `process.onExit` hooks after a normal exit, within the default budget.
+    fn run_exit_hooks(vm: &mut raya_engine::vm::Vm) -> Result<(), RuntimeError> {
+        vm.run_exit_hooks(std::time::Duration::from_millis(
+            raya_engine::vm::defaults::DEFAULT_EXIT_HOOK_BUDGET_MS,
+        ))?;
+        Ok(())
+    }
+
+    fn maybe_write_heap_snapshot(&self, vm: &raya_engine::vm::Vm) {
+        let Some(ref path) = self.options.heap_snapshot else {
+            return;
+        };
+        let graph = vm.heap_graph();
+        if let Err(e) = std::fs::write(path, graph.to_heapsnapshot_json()) {
+            eprintln!(
+                "Warning: failed to write heap snapshot to {}: {}",
+                path.display(),
+                e
+            );
+        } else {
+            eprintln!(
+                "Heap snapshot written to {} ({} objects, {} bytes)",
+                path.display(),
+                graph.nodes.len(),
+                graph.total_size()
+            );
+        }
+    }
+
+    fn record_gc_stats(&self, vm: &raya_engine::vm::Vm) {
+        *self.last_gc_stats.lock().unwrap() = Some(GcStats::from_vm(vm));
+    }
+
     fn maybe_emit_jit_telemetry(&self, vm: &raya_engine::vm::Vm) {
         #[cfg(feature = "jit")]
         {
@@ -2335,38 +2814,114 @@ impl Runtime {
 
     // ── Internal helpers ─────────────────────────────────────────────────
 
-    /// Resolve dependencies for a file, checking both raya.toml and adjacent .ryb files.
+    /// Options with the execution settings from the project's `raya.toml`
+    /// (`[permissions]`, `[build].features`, `[init]`) applied, or `None` if
+    /// they are already in effect.
+    fn project_run_options(&self, path: &Path) -> Result<Option<RuntimeOptions>, RuntimeError> {
+        let Some(manifest_dir) = deps::find_manifest_dir(path) else {
+            return Ok(None);
+        };
+        let manifest_path = manifest_dir.join("raya.toml");
+        if !manifest_path.exists() {
+            return Ok(None);
+        }
+        let manifest = raya_pm::PackageManifest::from_file(&manifest_path).map_err(|e| {
+            RuntimeError::Dependency(format!("Failed to load {}: {}", manifest_path.display(), e))
+        })?;
+
+        let mut options = self.options.clone();
+        let mut changed = false;
+        if options.native_policy.is_none() {
+            if let Some(grants) = &manifest.permissions {
+                options.native_policy = Some(permissions::policy_from_permissions(grants));
+                changed = true;
+            }
+        }
+        // `[build].features` add to the features enabled by the caller
+        if let Some(build) = &manifest.build {
+            for feature in &build.features {
+                if !options.features.contains(feature) {
+                    options.features.push(feature.clone());
+                    changed = true;
+                }
+            }
+        }
+        if let Some(init) = &manifest.init {
+            if options.lazy_module_init && !init.lazy {
+                options.lazy_module_init = false;
+                changed = true;
+            }
+            for eager in &init.eager {
+                // Source paths are relative to the manifest; anything else is a package name.
+                let source_path = manifest_dir.join(eager);
+                let name = if source_path.is_file() {
+                    source_path.to_string_lossy().to_string()
+                } else {
+                    eager.clone()
+                };
+                if !options.eager_modules.contains(&name) {
+                    options.eager_modules.push(name);
+                    changed = true;
+                }
+            }
+        }
+        Ok(changed.then_some(options))
+    }
+
+    /// Whether `module` must run its top-level init at load.
+    fn is_eager_module(&self, module: &Module) -> bool {
+        let name = &module.metadata.name;
+        self.options.eager_modules.iter().any(|eager| {
+            eager == name
+                || matches!(
+                    (std::fs::canonicalize(eager), std::fs::canonicalize(name)),
+                    (Ok(a), Ok(b)) if a == b
+                )
+        })
+    }
+
+    /// Resolve dependencies for a file: the module resolver first, then
+    /// raya.toml/package.json or adjacent .ryb files for what it declines.
     fn resolve_deps_for_file(
         &self,
+        entry: &CompiledModule,
         file_path: &Path,
         file_dir: &Path,
     ) -> Result<Vec<CompiledModule>, RuntimeError> {
+        // 0. An embedder-supplied resolver answers first for every import
+        let (mut modules, resolved) = match self.options.module_resolver.as_ref() {
+            Some(resolver) => loader::resolve_imports_with(entry, resolver)?,
+            None => (Vec::new(), HashSet::new()),
+        };
+
         // 1. Check for package.json/raya.toml project
         if let Some(manifest_dir) = deps::find_manifest_dir(file_path) {
             let package_json_path = manifest_dir.join("package.json");
             if package_json_path.exists() {
-                let package_json_deps = deps::load_dependencies_from_package_json(&manifest_dir)?;
+                let package_json_deps =
+                    deps::load_dependencies_from_package_json(&manifest_dir, &resolved)?;
                 if !package_json_deps.is_empty() {
-                    return Ok(package_json_deps);
+                    modules.extend(package_json_deps);
+                    return Ok(modules);
                 }
             }
             let manifest_path = manifest_dir.join("raya.toml");
             if let Ok(manifest) = raya_pm::PackageManifest::from_file(&manifest_path) {
                 if !manifest.dependencies.is_empty() {
-                    return deps::load_dependencies(&manifest, &manifest_dir);
+                    let manifest_deps =
+                        deps::load_dependencies(&manifest, &manifest_dir, &resolved)?;
+                    modules.extend(manifest_deps);
+                    return Ok(modules);
                 }
             }
         }
 
-        // 2. For .ryb files, auto-resolve imports from adjacent files
+        // 2. For .ryb files, auto-resolve the remaining imports from adjacent files
         if file_path.extension().and_then(|e| e.to_str()) == Some("ryb") {
-            let module = self.load_bytecode(file_path)?;
-            if !module.module.imports.is_empty() {
-                return loader::resolve_ryb_deps(&module, file_dir);
-            }
+            modules.extend(loader::resolve_ryb_deps(entry, file_dir, &resolved)?);
         }
 
-        Ok(Vec::new())
+        Ok(modules)
     }
 
     fn load_bundle_entry_module(&self, path: &Path) -> Result<CompiledModule, RuntimeError> {
@@ -2532,12 +3087,12 @@ impl Runtime {
         };
         if exc.is_ptr() {
             if let Some(s) = unsafe { exc.as_ptr::<RayaString>() } {
-                return unsafe { &*s.as_ptr() }.data.clone();
+                return unsafe { &*s.as_ptr() }.as_str().to_string();
             }
             if let Some(obj) = unsafe { exc.as_ptr::<Object>() } {
                 if let Some(msg_val) = unsafe { &*obj.as_ptr() }.get_field(0) {
                     if let Some(s) = unsafe { msg_val.as_ptr::<RayaString>() } {
-                        return unsafe { &*s.as_ptr() }.data.clone();
+                        return unsafe { &*s.as_ptr() }.as_str().to_string();
                     }
                 }
             }
@@ -2,7 +2,10 @@
 
 use raya_engine::compiler::Module;
 use serde_json::Value as JsonValue;
+use std::collections::HashSet;
+use std::fmt;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::error::RuntimeError;
 use crate::CompiledModule;
@@ -20,12 +23,88 @@ pub fn load_bytecode_bytes(bytes: &[u8]) -> Result<CompiledModule, RuntimeError>
     Ok(CompiledModule {
         module,
         interner: None,
+        lowering_cache: None,
     })
 }
 
+type ResolveFn = dyn Fn(&str) -> Option<Vec<u8>> + Send + Sync;
+
+/// Embedder hook mapping an import specifier to `.ryb` bytecode.
+///
+/// Lets modules live in a database or virtual filesystem instead of on disk.
+/// Returning `None` falls back to the normal filesystem search.
+#[derive(Clone)]
+pub struct ModuleResolver {
+    resolve: Arc<ResolveFn>,
+}
+
+impl ModuleResolver {
+    /// Create a resolver from a callback.
+    pub fn new<F>(resolve: F) -> Self
+    where
+        F: Fn(&str) -> Option<Vec<u8>> + Send + Sync + 'static,
+    {
+        Self {
+            resolve: Arc::new(resolve),
+        }
+    }
+
+    /// Bytecode for `specifier`, if the resolver knows it.
+    pub fn resolve(&self, specifier: &str) -> Option<Vec<u8>> {
+        (self.resolve)(specifier)
+    }
+}
+
+impl fmt::Debug for ModuleResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModuleResolver").finish_non_exhaustive()
+    }
+}
+
+/// Non-`std:` import specifiers of `module`, each listed once.
+fn import_specifiers(module: &CompiledModule) -> impl Iterator<Item = &str> {
+    let mut seen = HashSet::new();
+    module
+        .module
+        .imports
+        .iter()
+        .map(|import| import.module_specifier.as_str())
+        // Skip std: imports — they're compiled into the source
+        .filter(move |specifier| !specifier.starts_with("std:") && seen.insert(*specifier))
+}
+
+/// Resolve every import of `module` through an embedder-supplied resolver.
+///
+/// Returns the modules the resolver supplied and the specifiers it supplied
+/// them for; the specifiers it declines are left to the manifest or
+/// filesystem search.
+pub fn resolve_imports_with(
+    module: &CompiledModule,
+    resolver: &ModuleResolver,
+) -> Result<(Vec<CompiledModule>, HashSet<String>), RuntimeError> {
+    let mut deps = Vec::new();
+    let mut resolved = HashSet::new();
+
+    for specifier in import_specifiers(module) {
+        let Some(bytes) = resolver.resolve(specifier) else {
+            continue;
+        };
+        let dep = load_bytecode_bytes(&bytes).map_err(|e| {
+            RuntimeError::Dependency(format!(
+                "Module resolver returned invalid bytecode for '{}': {}",
+                specifier, e
+            ))
+        })?;
+        deps.push(dep);
+        resolved.insert(specifier.to_string());
+    }
+
+    Ok((deps, resolved))
+}
+
 /// Resolve import dependencies for a .ryb module by searching nearby directories.
 ///
-/// Search order for each import:
+/// Imports in `skip` were already supplied elsewhere. Search order for the rest:
 /// 1. Same directory as the .ryb file
 /// 2. `.raya/packages/` relative to the .ryb file
 /// 3. `raya_packages/` (legacy) relative to the .ryb file
@@ -33,19 +112,12 @@ pub fn load_bytecode_bytes(bytes: &[u8]) -> Result<CompiledModule, RuntimeError>
 pub fn resolve_ryb_deps(
     module: &CompiledModule,
     ryb_dir: &Path,
+    skip: &HashSet<String>,
 ) -> Result<Vec<CompiledModule>, RuntimeError> {
     let mut deps = Vec::new();
 
-    for import in &module.module.imports {
-        let specifier = &import.module_specifier;
-
-        // Skip std: imports — they're compiled into the source
-        if specifier.starts_with("std:") {
-            continue;
-        }
-
-        let dep = find_library(specifier, ryb_dir)?;
-        deps.push(dep);
+    for specifier in import_specifiers(module).filter(|specifier| !skip.contains(*specifier)) {
+        deps.push(find_library(specifier, ryb_dir)?);
     }
 
     Ok(deps)
@@ -67,6 +139,7 @@ fn find_library(name: &str, base_dir: &Path) -> Result<CompiledModule, RuntimeEr
         return Ok(CompiledModule {
             module,
             interner: Some(interner),
+            lowering_cache: None,
         });
     }
 
@@ -224,6 +297,7 @@ fn load_entry_point_with_mode(
             Ok(CompiledModule {
                 module,
                 interner: Some(interner),
+                lowering_cache: None,
             })
         }
         _ => Err(RuntimeError::Dependency(format!(
@@ -294,3 +368,82 @@ pub fn load_ts_compiler_options(path: &Path) -> Result<compile::TsCompilerOption
         ))
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raya_engine::compiler::{module_id_from_name, Import, SymbolScope};
+    use std::collections::HashMap;
+
+    fn entry_importing(specifier: &str) -> CompiledModule {
+        let (mut module, interner) = compile::compile_source("return 1;").unwrap();
+        module.imports.push(Import {
+            module_specifier: specifier.to_string(),
+            symbol: "add".to_string(),
+            alias: None,
+            module_id: module_id_from_name(specifier),
+            symbol_id: 0,
+            scope: SymbolScope::Module,
+            signature_hash: 0,
+            type_signature: None,
+            runtime_global_slot: None,
+        });
+        CompiledModule {
+            module,
+            interner: Some(interner),
+            lowering_cache: None,
+        }
+    }
+
+    fn missing_dir() -> PathBuf {
+        std::env::temp_dir().join("raya-loader-tests-no-such-dir")
+    }
+
+    #[test]
+    fn resolve_ryb_deps_uses_in_memory_resolver() {
+        let (library, _) = compile::compile_source(
+            "export function add(a: number, b: number): number { return a + b; }",
+        )
+        .unwrap();
+        let modules: HashMap<String, Vec<u8>> =
+            HashMap::from([("mathlib".to_string(), library.encode())]);
+        let resolver = ModuleResolver::new(move |specifier| modules.get(specifier).cloned());
+
+        let entry = entry_importing("mathlib");
+        let (deps, resolved) = resolve_imports_with(&entry, &resolver).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert!(deps[0].module.functions.iter().any(|f| f.name == "add"));
+        assert!(resolved.contains("mathlib"));
+
+        // Nothing is left for the filesystem search.
+        assert!(resolve_ryb_deps(&entry, &missing_dir(), &resolved)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn resolve_ryb_deps_falls_back_to_filesystem_when_resolver_declines() {
+        let resolver = ModuleResolver::new(|_| None);
+        let entry = entry_importing("mathlib");
+        let (deps, resolved) = resolve_imports_with(&entry, &resolver).unwrap();
+        assert!(deps.is_empty() && resolved.is_empty());
+        let err = resolve_ryb_deps(&entry, &missing_dir(), &resolved)
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string().contains("Cannot find module 'mathlib'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn resolve_ryb_deps_rejects_invalid_resolver_bytecode() {
+        let resolver = ModuleResolver::new(|_| Some(b"not bytecode".to_vec()));
+        let entry = entry_importing("mathlib");
+        let err = resolve_imports_with(&entry, &resolver).err().unwrap();
+        assert!(
+            err.to_string().contains("invalid bytecode for 'mathlib'"),
+            "{err}"
+        );
+    }
+}
@@ -7,6 +7,10 @@ use std::path::{Path, PathBuf};
 use super::loader::ModuleLoaderV2;
 use super::resolver::{ImportResolution, ModuleKey, ModuleResolverV2, ModuleSpecifierKind};
 
+/// Suggested fix appended to import-cycle errors
+pub(crate) const CYCLE_FIX_HINT: &str =
+    "Move the declarations these modules share into a separate module that each of them imports.";
+
 #[derive(Debug, Clone)]
 pub struct ProgramGraphNode {
     pub key: ModuleKey,
@@ -101,8 +105,9 @@ impl ProgramGraphBuilder {
                 .collect::<Vec<_>>();
             cycle.push(key.display_name());
             return Err(RuntimeError::Dependency(format!(
-                "Circular module dependency detected: {}",
-                cycle.join(" -> ")
+                "Circular module dependency detected: {}\n{}",
+                cycle.join(" -> "),
+                CYCLE_FIX_HINT
             )));
         }
 
@@ -279,5 +284,10 @@ mod tests {
             msg.contains("Circular module dependency detected"),
             "expected cycle error, got: {msg}"
         );
+        assert!(
+            msg.contains("a.raya") && msg.contains("b.raya"),
+            "expected both modules in cycle path, got: {msg}"
+        );
+        assert!(msg.contains(" -> "), "expected cycle path, got: {msg}");
     }
 }
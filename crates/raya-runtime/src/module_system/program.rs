@@ -2,6 +2,7 @@ use crate::compile;
 use crate::compile::{TsCompilerOptions, TypeMode};
 use crate::error::RuntimeError;
 use crate::BuiltinMode;
+use raya_engine::compiler::lower::LoweringCache;
 use raya_engine::compiler::module::{
     specialization_template_from_symbol, BuiltinSurfaceMode, LateLinkRequirement,
 };
@@ -10,10 +11,13 @@ use raya_engine::compiler::{module_id_from_name, SymbolType};
 use raya_engine::parser::checker::{CheckerPolicy, TsTypeFlags, TypeSystemMode};
 use raya_engine::parser::{Interner, Parser};
 use raya_engine::vm::module::ModuleLinker;
+use raya_engine::vm::NativeFunctionRegistry;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+
+use super::graph::CYCLE_FIX_HINT;
 
 pub struct CompiledProgram {
     pub entry_path: PathBuf,
@@ -23,6 +27,8 @@ pub struct CompiledProgram {
     pub dependencies: Vec<crate::CompiledModule>,
     /// Unresolved declaration-backed module requirements to satisfy at runtime.
     pub late_link_requirements: Vec<LateLinkRequirement>,
+    /// Non-fatal compiler warnings, each prefixed with its module path.
+    pub warnings: Vec<String>,
 }
 
 pub struct ProgramDiagnostics {
@@ -32,12 +38,32 @@ pub struct ProgramDiagnostics {
     pub diagnostics: compile::CheckDiagnostics,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ProgramCompiler {
     pub builtin_mode: BuiltinMode,
     pub type_mode: TypeMode,
     pub ts_options: Option<TsCompilerOptions>,
     pub compile_options: Option<compile::CompileOptions>,
+    /// Features enabled for `//@@if(feature)` conditional compilation.
+    pub features: Vec<String>,
+    /// Compile calls to the builtin `assert` to nothing.
+    pub strip_assertions: bool,
+    /// Emit a source map (bytecode offset → source location) into each module.
+    pub sourcemap: bool,
+    /// Lowered functions of the entry module to reuse (incremental compiles);
+    /// the updated cache is returned on the compiled entry.
+    pub lowering_cache: Option<LoweringCache>,
+}
+
+/// Names the runtime registers for `ModuleNativeCall` dispatch (see `vm_setup`).
+fn registered_native_names() -> &'static [String] {
+    static NAMES: OnceLock<Vec<String>> = OnceLock::new();
+    NAMES.get_or_init(|| {
+        let mut registry = NativeFunctionRegistry::new();
+        raya_stdlib::register_stdlib(&mut registry);
+        raya_stdlib_posix::register_posix(&mut registry);
+        registry.names().map(str::to_string).collect()
+    })
 }
 
 impl ProgramCompiler {
@@ -51,6 +77,49 @@ impl ProgramCompiler {
             .map_err(map_module_compile_error)
     }
 
+    /// Lower the entry file to textual optimized IR.
+    ///
+    /// The whole module graph is compiled with the same options as
+    /// `compile_program_file`, so imports resolve; only the entry module's IR
+    /// is returned.
+    pub fn compile_program_file_to_ir(&self, path: &Path) -> Result<String, RuntimeError> {
+        if !self.can_use_binary_module_pipeline() {
+            return Err(RuntimeError::Dependency(
+                "compile_program_file_to_ir requires the binary module pipeline; disable unsupported compile options or type-mode overrides".to_string(),
+            ));
+        }
+        let entry_path = path.canonicalize()?;
+        let mut compiler = self.module_compiler(&entry_path).with_entry_ir(true);
+        compiler
+            .compile(&entry_path)
+            .map_err(map_module_compile_error)?;
+        compiler.entry_ir().map(str::to_string).ok_or_else(|| {
+            RuntimeError::Dependency(format!(
+                "No IR was produced for entry module {}",
+                entry_path.display()
+            ))
+        })
+    }
+
+    fn module_compiler(&self, entry_path: &Path) -> BinaryModuleCompiler {
+        let project_root = entry_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let compiler = BinaryModuleCompiler::new(project_root)
+            .with_checker_mode(self.type_system_mode())
+            .with_checker_policy(self.checker_policy())
+            .with_builtin_surface_mode(self.builtin_surface_mode())
+            .with_features(self.features.clone())
+            .with_strip_assertions(self.strip_assertions)
+            .with_sourcemap(self.sourcemap)
+            .with_known_natives(registered_native_names().iter().cloned());
+        match &self.lowering_cache {
+            Some(cache) => compiler.with_entry_lowering_cache(cache.clone()),
+            None => compiler,
+        }
+    }
+
     fn compile_program_file_binary(
         &self,
         path: &Path,
@@ -61,15 +130,7 @@ impl ProgramCompiler {
                 path: path.to_path_buf(),
                 message: e.to_string(),
             })?;
-        let project_root = entry_path
-            .parent()
-            .map(Path::to_path_buf)
-            .unwrap_or_else(|| PathBuf::from("."));
-
-        let mut compiler = BinaryModuleCompiler::new(project_root)
-            .with_checker_mode(self.type_system_mode())
-            .with_checker_policy(self.checker_policy())
-            .with_builtin_surface_mode(self.builtin_surface_mode());
+        let mut compiler = self.module_compiler(&entry_path);
         let mut compiled_modules = compiler.compile(&entry_path)?;
         if std::env::var("RAYA_DEBUG_MODULE_NATIVES").is_ok() {
             for compiled in &compiled_modules {
@@ -95,15 +156,24 @@ impl ProgramCompiler {
 
         let mut entry = None;
         let mut dependencies = Vec::new();
+        let mut warnings = Vec::new();
         for compiled in compiled_modules {
             if compiled.declaration_only {
                 continue;
             }
-            let runtime_module = crate::CompiledModule {
+            warnings.extend(
+                compiled
+                    .warnings
+                    .iter()
+                    .map(|warning| format!("{}: {}", compiled.path.display(), warning)),
+            );
+            let mut runtime_module = crate::CompiledModule {
                 module: compiled.bytecode,
                 interner: None,
+                lowering_cache: None,
             };
             if compiled.path == entry_path {
+                runtime_module.lowering_cache = compiler.take_entry_lowering_cache();
                 entry = Some(runtime_module);
             } else {
                 dependencies.push(runtime_module);
@@ -122,6 +192,7 @@ impl ProgramCompiler {
             entry,
             dependencies,
             late_link_requirements,
+            warnings,
         })
     }
 
@@ -129,17 +200,12 @@ impl ProgramCompiler {
         &self,
         source: &str,
         virtual_entry_path: &Path,
+        host_globals: &[(String, String)],
     ) -> Result<CompiledProgram, ModuleCompileError> {
         let entry_path = virtual_entry_path.to_path_buf();
-        let project_root = entry_path
-            .parent()
-            .map(Path::to_path_buf)
-            .unwrap_or_else(|| PathBuf::from("."));
-
-        let mut compiler = BinaryModuleCompiler::new(project_root)
-            .with_checker_mode(self.type_system_mode())
-            .with_checker_policy(self.checker_policy())
-            .with_builtin_surface_mode(self.builtin_surface_mode());
+        let mut compiler = self
+            .module_compiler(&entry_path)
+            .with_host_globals(host_globals.to_vec());
         let mut compiled_modules =
             compiler.compile_with_virtual_entry_source(&entry_path, source.to_string())?;
         if std::env::var("RAYA_DEBUG_MODULE_NATIVES").is_ok() {
@@ -166,13 +232,21 @@ impl ProgramCompiler {
 
         let mut entry = None;
         let mut dependencies = Vec::new();
+        let mut warnings = Vec::new();
         for compiled in compiled_modules {
             if compiled.declaration_only {
                 continue;
             }
+            warnings.extend(
+                compiled
+                    .warnings
+                    .iter()
+                    .map(|warning| format!("{}: {}", compiled.path.display(), warning)),
+            );
             let runtime_module = crate::CompiledModule {
                 module: compiled.bytecode,
                 interner: None,
+                lowering_cache: None,
             };
             if compiled.path == entry_path {
                 entry = Some(runtime_module);
@@ -193,6 +267,7 @@ impl ProgramCompiler {
             entry,
             dependencies,
             late_link_requirements,
+            warnings,
         })
     }
 
@@ -424,6 +499,19 @@ impl ProgramCompiler {
         &self,
         source: &str,
         virtual_entry_path: &Path,
+    ) -> Result<CompiledProgram, RuntimeError> {
+        self.compile_program_source_with_host_globals(source, virtual_entry_path, &[])
+    }
+
+    /// Compile source with host-provided globals predeclared in the entry.
+    ///
+    /// Each global is a name and the type signature of its value; the host
+    /// must seed the values as ambient globals before executing the program.
+    pub fn compile_program_source_with_host_globals(
+        &self,
+        source: &str,
+        virtual_entry_path: &Path,
+        host_globals: &[(String, String)],
     ) -> Result<CompiledProgram, RuntimeError> {
         if !self.can_use_binary_module_pipeline() {
             return Err(RuntimeError::Dependency(
@@ -433,7 +521,7 @@ impl ProgramCompiler {
         self.enforce_dynamic_import_policy(source)?;
 
         let mut program = self
-            .compile_program_source_binary(source, virtual_entry_path)
+            .compile_program_source_binary(source, virtual_entry_path, host_globals)
             .map_err(map_module_compile_error)?;
 
         let entry_name = virtual_entry_path.to_string_lossy().to_string();
@@ -518,7 +606,7 @@ fn map_module_compile_error(error: ModuleCompileError) -> RuntimeError {
             RuntimeError::Dependency(format!("Module resolution error: {error}"))
         }
         ModuleCompileError::CircularDependency(error) => {
-            RuntimeError::Dependency(format!("Circular dependency: {error}"))
+            RuntimeError::Dependency(format!("Circular dependency: {error}\n{CYCLE_FIX_HINT}"))
         }
         ModuleCompileError::IoError { path, message } => RuntimeError::Io(std::io::Error::other(
             format!("{}: {}", path.display(), message),
@@ -557,6 +645,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let err = compiler
             .enforce_dynamic_import_policy(r#"const x = import("std:path");"#)
@@ -572,6 +661,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let result = compiler.compile_program_source(
@@ -616,6 +706,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let program = compiler
@@ -671,6 +762,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let program = compiler
@@ -716,6 +808,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let result = compiler.compile_program_file(&main_path);
@@ -753,6 +846,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let program = compiler
@@ -784,6 +878,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let program = compiler
@@ -815,6 +910,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let program = compiler
@@ -859,6 +955,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let error = match compiler.compile_program_file(&main_path) {
@@ -900,6 +997,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
 
         let program = compiler
@@ -939,6 +1037,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -990,6 +1089,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1041,6 +1141,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1098,6 +1199,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1156,6 +1258,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1203,6 +1306,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1241,6 +1345,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1274,6 +1379,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1316,6 +1422,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1369,6 +1476,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1426,6 +1534,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1491,6 +1600,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1544,6 +1654,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -1588,6 +1699,7 @@ mod tests {
             type_mode: TypeMode::Raya,
             ts_options: None,
             compile_options: None,
+            ..Default::default()
         };
         let program = compiler
             .compile_program_file(&main_path)
@@ -0,0 +1,123 @@
+//! Manifest capability permissions.
+//!
+//! Maps the `[permissions]` section of `raya.toml` onto a `NativeCallPolicy`.
+//! Filesystem natives need `fs:read` or `fs:write`; natives that open outgoing
+//! connections need `net:connect` (or `net:connect:<host>` for the target
+//! host); natives that bind sockets need `net:listen`; natives that start
+//! subprocesses need `process:exec`. Natives that only use an existing handle
+//! are allowed, since the handle was gated when opened.
+//!
+//! Grants come from the root project's manifest only and cover every module
+//! the program runs, dependencies included.
+
+use raya_engine::vm::{NativeCallDecision, NativeCallInfo, NativeCallPolicy};
+use raya_pm::PermissionsConfig;
+
+/// Capability a native call needs
+enum Capability {
+    FsRead,
+    FsWrite,
+    Connect(Option<String>),
+    Listen,
+    Exec,
+}
+
+/// Build a policy that denies natives outside the granted permissions.
+pub fn policy_from_permissions(permissions: &PermissionsConfig) -> NativeCallPolicy {
+    let permissions = permissions.clone();
+    NativeCallPolicy::new(move |call| match required_capability(call) {
+        None => NativeCallDecision::Allow,
+        Some(capability) => match missing_permission(&permissions, &capability) {
+            None => NativeCallDecision::Allow,
+            Some(missing) => NativeCallDecision::Deny(format!("missing permission {}", missing)),
+        },
+    })
+}
+
+fn missing_permission(permissions: &PermissionsConfig, capability: &Capability) -> Option<String> {
+    match capability {
+        Capability::FsRead if !permissions.allows_fs("read") => Some("fs:read".to_string()),
+        Capability::FsWrite if !permissions.allows_fs("write") => Some("fs:write".to_string()),
+        Capability::Connect(Some(host)) if !permissions.allows_connect(host) => {
+            Some(format!("net:connect:{}", host))
+        }
+        Capability::Connect(None) if !permissions.allows_any_connect() => {
+            Some("net:connect".to_string())
+        }
+        Capability::Listen if !permissions.allows_listen() => Some("net:listen".to_string()),
+        Capability::Exec if !permissions.allows_exec() => Some("process:exec".to_string()),
+        _ => None,
+    }
+}
+
+fn required_capability(call: &NativeCallInfo<'_>) -> Option<Capability> {
+    let (family, name) = call.name.split_once('.')?;
+    match family {
+        "fs" => Some(match name {
+            "readFile" | "readTextFile" | "exists" | "isFile" | "isDir" | "isSymlink"
+            | "fileSize" | "lastModified" | "stat" | "readDir" | "readlink" | "realpath" => {
+                Capability::FsRead
+            }
+            _ => Capability::FsWrite,
+        }),
+        "glob" | "watch" => Some(Capability::FsRead),
+        "archive" if name.ends_with("List") => Some(Capability::FsRead),
+        "archive" => Some(Capability::FsWrite),
+        "net" => match name {
+            "tcpConnect" | "tlsConnect" | "tlsConnectWithCa" => Some(connect_to(call, 0)),
+            "udpSendTo" | "udpSendText" => Some(connect_to(call, 2)),
+            "tcpListen" | "udpBind" => Some(Capability::Listen),
+            _ => None,
+        },
+        "http" | "http2" | "ws" if name.starts_with("serverCreate") => Some(Capability::Listen),
+        "http2" if name == "clientConnect" => Some(connect_to(call, 0)),
+        "ws" if name.starts_with("connect") => Some(connect_to(call, 0)),
+        "fetch" if name == "request" => Some(connect_to(call, 1)),
+        "dns" if name == "reverse" => Some(Capability::Connect(None)),
+        "dns" => Some(connect_to(call, 0)),
+        "process" if name == "exec" || name.starts_with("spawn") => Some(Capability::Exec),
+        _ => None,
+    }
+}
+
+/// Outgoing connection to the host named by string argument `index`
+fn connect_to(call: &NativeCallInfo<'_>, index: usize) -> Capability {
+    Capability::Connect(
+        call.string_arg(index)
+            .map(|target| host_of(&target).to_string()),
+    )
+}
+
+/// Extract the host from a URL, `host:port` pair or bare host name.
+fn host_of(target: &str) -> &str {
+    let rest = target.split_once("://").map_or(target, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    if let Some(bracketed) = host_port.strip_prefix('[') {
+        return bracketed.split(']').next().unwrap_or(bracketed);
+    }
+    match host_port.split_once(':') {
+        Some((host, port)) if !port.contains(':') => host,
+        _ => host_port,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_of() {
+        assert_eq!(host_of("api.example.com"), "api.example.com");
+        assert_eq!(host_of("api.example.com:443"), "api.example.com");
+        assert_eq!(host_of("https://api.example.com/v1?q=1"), "api.example.com");
+        assert_eq!(
+            host_of("wss://user:pw@api.example.com:8443/ws"),
+            "api.example.com"
+        );
+        assert_eq!(host_of("http://[::1]:8080/"), "::1");
+        assert_eq!(host_of("::1"), "::1");
+    }
+}
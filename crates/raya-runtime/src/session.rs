@@ -18,6 +18,8 @@
 use std::any::TypeId;
 
 use raya_engine::vm::gc::{header_ptr_from_value_ptr, GcHeader};
+use raya_engine::vm::json::stringify::{stringify, stringify_with_runtime_metadata};
+use raya_engine::vm::json::{js_classify, JSView};
 use raya_engine::vm::object::{
     Array, BoundMethod, Buffer, ChannelObject, Closure, DateObject, MapObject, RegExpObject,
     SetObject,
@@ -126,6 +128,31 @@ impl Session {
         format!("{:?}", value)
     }
 
+    /// Serialize a Value to JSON through the same path as `JSON.stringify`.
+    ///
+    /// Functions (including ones nested in arrays and objects) and circular
+    /// structures cannot be represented and are reported as errors rather
+    /// than silently printed as `null`.
+    pub fn format_value_json(&self, value: &Value) -> Result<String, RuntimeError> {
+        if contains_function(*value, &mut Vec::new()) {
+            return Err(RuntimeError::Vm(VmError::RuntimeError(
+                "Cannot serialize a function to JSON".to_string(),
+            )));
+        }
+        let result = match &self.last_vm {
+            Some(vm) => {
+                let shared = vm.shared_state();
+                stringify_with_runtime_metadata(
+                    *value,
+                    |key| shared.prop_key_name(key),
+                    |layout_id| shared.structural_layout_names(layout_id),
+                )
+            }
+            None => stringify(*value),
+        };
+        result.map_err(RuntimeError::Vm)
+    }
+
     /// Reset the session (discards all accumulated state).
     pub fn reset(&mut self, options: &RuntimeOptions) {
         self.options = options.clone();
@@ -145,6 +172,47 @@ fn is_declaration(code: &str) -> bool {
         || trimmed.starts_with("export ")
 }
 
+/// Whether a function is reachable from `value` through arrays and object fields.
+///
+/// `visiting` holds the containers on the current path; cycles are left for
+/// the stringifier to report.
+fn contains_function(value: Value, visiting: &mut Vec<usize>) -> bool {
+    match js_classify(value) {
+        JSView::Arr(ptr) => {
+            if visiting.contains(&(ptr as usize)) {
+                return false;
+            }
+            visiting.push(ptr as usize);
+            let arr = unsafe { &*ptr };
+            let found = arr
+                .elements
+                .iter()
+                .any(|elem| contains_function(*elem, visiting));
+            visiting.pop();
+            found
+        }
+        JSView::Struct { ptr, .. } => {
+            if visiting.contains(&(ptr as usize)) {
+                return false;
+            }
+            visiting.push(ptr as usize);
+            let obj = unsafe { &*ptr };
+            let found = obj
+                .fields
+                .iter()
+                .chain(obj.dyn_map().into_iter().flat_map(|map| map.values()))
+                .any(|field| contains_function(*field, visiting));
+            visiting.pop();
+            found
+        }
+        JSView::Other => unsafe { read_gc_header(&value) }.is_some_and(|header| {
+            let tid = header.type_id();
+            tid == TypeId::of::<Closure>() || tid == TypeId::of::<BoundMethod>()
+        }),
+        _ => false,
+    }
+}
+
 /// Read the GcHeader for a heap-allocated Value.
 ///
 /// # Safety
@@ -246,5 +314,5 @@ fn format_heap_value(value: &Value, vm: &Vm) -> String {
 fn try_read_string(value: &Value) -> Option<String> {
     let ptr = unsafe { value.as_ptr::<RayaString>() }?;
     let s = unsafe { &*ptr.as_ptr() };
-    Some(s.data.clone())
+    Some(s.as_str().to_string())
 }
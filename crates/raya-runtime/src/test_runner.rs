@@ -7,6 +7,7 @@
 //! 4. Creates a VM with stdlib + test result handlers
 //! 5. Executes the module and returns collected results
 
+use raya_engine::coverage::CoverageRecorder;
 use raya_engine::vm::Vm;
 use raya_stdlib::test::{self, SharedTestResults};
 use raya_stdlib::StdNativeHandler;
@@ -14,11 +15,13 @@ use std::path::Path;
 use std::sync::Arc;
 
 // Re-export types for consumers (e.g., raya-cli)
+pub use raya_engine::coverage::{CoverageReport, FileCoverage};
 pub use raya_stdlib::test::{TestResult, TestResults};
 
 use crate::compile;
 use crate::error::RuntimeError;
-use crate::RuntimeOptions;
+use crate::module_system::CompiledProgram;
+use crate::{Runtime, RuntimeOptions};
 
 /// The std:test source, embedded at compile time.
 const TEST_SOURCE: &str = include_str!("../../raya-stdlib/raya/test.raya");
@@ -32,41 +35,37 @@ pub fn run_test_file(
     run_test_source(&source, path, options)
 }
 
+/// Run a single test file with line and branch coverage collection.
+pub fn run_test_file_with_coverage(
+    path: &Path,
+    options: &RuntimeOptions,
+) -> Result<TestFileResult, RuntimeError> {
+    let source = std::fs::read_to_string(path)?;
+    run_test_source_with_coverage(&source, path, options)
+}
+
 /// Run test source code and return results.
 ///
 /// Compiles the std:test harness plus user source as one entry module, then
-/// executes it on a VM with test result collection handlers.
+/// executes it and its imports on a VM with test result collection handlers.
 pub fn run_test_source(
     source: &str,
     file_path: &Path,
     options: &RuntimeOptions,
 ) -> Result<TestFileResult, RuntimeError> {
-    // Build test source: test framework + user code + runner invocation
-    let full_test_source = format!("{}\n{}\n__runTests();\n", TEST_SOURCE, source);
-
-    // Compile through the binary module pipeline.
-    let (module, _interner) = compile::compile_source(&full_test_source)?;
-
-    // Create VM with test handlers
-    let results = test::new_results();
-    let mut vm = create_test_vm(options, results.clone());
-
-    // Execute
-    let exec_result = vm.execute(&module);
-
-    // Collect results regardless of VM outcome
-    let test_results = results.lock().clone();
-
-    let error = match exec_result {
-        Ok(_) => None,
-        Err(e) => Some(format!("{}", e)),
-    };
+    execute_test_source(source, file_path, options, false)
+}
 
-    Ok(TestFileResult {
-        file: file_path.to_path_buf(),
-        results: test_results,
-        execution_error: error,
-    })
+/// Run test source code with coverage collection.
+///
+/// Like [`run_test_source`], but compiles with a source map and records which
+/// lines and branches of the user source and its imported modules executed.
+pub fn run_test_source_with_coverage(
+    source: &str,
+    file_path: &Path,
+    options: &RuntimeOptions,
+) -> Result<TestFileResult, RuntimeError> {
+    execute_test_source(source, file_path, options, true)
 }
 
 /// Result of running a single test file.
@@ -79,6 +78,9 @@ pub struct TestFileResult {
     /// Execution-level error (e.g., compilation failure, VM crash).
     /// Individual test failures are in `results`, not here.
     pub execution_error: Option<String>,
+    /// Coverage of the test file and the source files it imports, when run
+    /// with coverage enabled. The test file comes first.
+    pub coverage: Option<CoverageReport>,
 }
 
 impl TestFileResult {
@@ -110,6 +112,104 @@ impl TestFileResult {
 
 // ── Internal ─────────────────────────────────────────────────────────────
 
+fn execute_test_source(
+    source: &str,
+    file_path: &Path,
+    options: &RuntimeOptions,
+    coverage: bool,
+) -> Result<TestFileResult, RuntimeError> {
+    // Build test source: test framework + user code + runner invocation
+    let full_test_source = format!("{}\n{}\n__runTests();\n", TEST_SOURCE, source);
+
+    // Compile through the binary module pipeline, rooted at the test file so
+    // its relative imports resolve.
+    let program = compile::compile_program_source_at(&full_test_source, file_path, coverage)?;
+
+    // Create VM with test handlers
+    let results = test::new_results();
+    let mut vm = create_test_vm(options, results.clone());
+    if coverage {
+        vm.enable_coverage();
+    }
+
+    // Execute the test module after the modules it imports.
+    let runtime = Runtime::with_options(RuntimeOptions {
+        no_jit: true,
+        ..options.clone()
+    });
+    let exec_result = runtime.execute_program_with_vm(&program, &mut vm);
+
+    // Collect results regardless of VM outcome
+    let test_results = results.lock().clone();
+    let coverage = vm
+        .stop_coverage()
+        .map(|recorder| program_coverage(&recorder, &program, source, file_path));
+
+    let error = match exec_result {
+        Ok(_) => None,
+        Err(e) => Some(format!("{}", e)),
+    };
+
+    Ok(TestFileResult {
+        file: file_path.to_path_buf(),
+        results: test_results,
+        execution_error: error,
+        coverage,
+    })
+}
+
+/// Coverage of the test file followed by the source files it imports.
+///
+/// std modules are compiled from virtual paths and are left out.
+fn program_coverage(
+    recorder: &CoverageRecorder,
+    program: &CompiledProgram,
+    source: &str,
+    file_path: &Path,
+) -> CoverageReport {
+    let mut files = vec![user_coverage(
+        &recorder.report(&program.entry.module),
+        source,
+        file_path,
+    )];
+    for dep in &program.dependencies {
+        if Path::new(&dep.module.metadata.name).is_file() {
+            files.extend(recorder.report(&dep.module).files);
+        }
+    }
+    CoverageReport { files }
+}
+
+/// Restrict a coverage report to the user's source, dropping the std:test
+/// harness prepended to it and renumbering lines to match the test file.
+fn user_coverage(report: &CoverageReport, source: &str, file_path: &Path) -> FileCoverage {
+    let line_offset = TEST_SOURCE.matches('\n').count() as u32 + 1;
+    let user_lines = (line_offset + 1)..=(line_offset + source.lines().count() as u32);
+
+    let mut coverage = FileCoverage {
+        path: file_path.display().to_string(),
+        ..Default::default()
+    };
+    for file in &report.files {
+        for (line, hit) in &file.lines {
+            if user_lines.contains(line) {
+                *coverage.lines.entry(line - line_offset).or_insert(false) |= *hit;
+            }
+        }
+        for (line, (covered, total)) in &file.branches {
+            if user_lines.contains(line) {
+                let counts = coverage
+                    .branches
+                    .entry(line - line_offset)
+                    .or_insert((0, 0));
+                counts.0 += covered;
+                counts.1 += total;
+            }
+        }
+    }
+    coverage
+}
+
 /// Create a VM configured for test execution (stdlib + test handlers).
 fn create_test_vm(options: &RuntimeOptions, results: SharedTestResults) -> Vm {
     let threads = if options.threads == 0 {
@@ -291,4 +391,231 @@ test("second", () => { expect(count).toBe(2); });
         assert_eq!(r.total(), 2);
         assert_eq!(r.passed(), 2);
     }
+
+    #[test]
+    fn test_hook_order_with_nested_describe() {
+        let source = r#"
+let log: string = "";
+describe("outer", () => {
+    beforeAll(() => { log = log + "oBA;"; });
+    beforeEach(() => { log = log + "oBE;"; });
+    afterEach(() => { log = log + "oAE;"; });
+    describe("inner", () => {
+        beforeAll(() => { log = log + "iBA;"; });
+        beforeEach(() => { log = log + "iBE;"; });
+        afterEach(() => { log = log + "iAE;"; });
+        afterAll(() => { log = log + "iAA;"; });
+        test("first", () => { log = log + "T1;"; });
+        test("second", () => { log = log + "T2;"; });
+    });
+    test("check", () => {
+        expect(log).toBe("oBA;iBA;oBE;iBE;T1;iAE;oAE;oBE;iBE;T2;iAE;oAE;iAA;oBE;");
+    });
+});
+"#;
+        let options = RuntimeOptions::default();
+        let r = run_test_source(source, Path::new("order.test.raya"), &options)
+            .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(r.total(), 3);
+        assert_eq!(r.passed(), 3, "results: {:?}", r.results.results);
+    }
+
+    #[test]
+    fn test_after_each_runs_after_failing_test() {
+        let source = r#"
+let cleanups: number = 0;
+afterEach(() => { cleanups = cleanups + 1; });
+test("throws", () => { throw new Error("boom"); });
+test("sees cleanup", () => { expect(cleanups).toBe(1); });
+"#;
+        let options = RuntimeOptions::default();
+        let r = run_test_source(source, Path::new("cleanup.test.raya"), &options)
+            .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(r.total(), 2);
+        assert_eq!(r.passed(), 1);
+        assert_eq!(r.failed(), 1);
+    }
+
+    #[test]
+    fn test_to_equal_diff_points_at_changed_path() {
+        let source = r#"
+test("nested", () => {
+    let actual = { user: { name: "ada", address: { city: "Lyon", zip: 69000 } } };
+    expect(actual).toEqual({ user: { name: "ada", address: { city: "Paris", zip: 69000 } } });
+});
+"#;
+        let options = RuntimeOptions::default();
+        let r = run_test_source(source, Path::new("diff.test.raya"), &options)
+            .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(r.failed(), 1);
+        let message = r.results.results[0]
+            .error_message
+            .clone()
+            .unwrap_or_default();
+        assert!(message.contains("at user.address.city"), "{}", message);
+        assert!(message.contains("Expected: \"Paris\""), "{}", message);
+        assert!(message.contains("Received: \"Lyon\""), "{}", message);
+        assert!(!message.contains("zip"), "{}", message);
+    }
+
+    #[test]
+    fn test_to_throw_matches_message_substring() {
+        let source = r#"
+let boom = (): void => { throw new Error("connection refused by host"); };
+test("matches substring", () => { expect(boom).toThrow("refused"); });
+test("rejects other message", () => { expect(boom).toThrow("timed out"); });
+test("not.toThrow other message", () => { expect(boom).not.toThrow("timed out"); });
+"#;
+        let options = RuntimeOptions::default();
+        let r = run_test_source(source, Path::new("throws.test.raya"), &options)
+            .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(r.total(), 3);
+        assert_eq!(r.passed(), 2, "results: {:?}", r.results.results);
+        let failure = &r.results.results[1];
+        assert!(!failure.passed);
+        assert!(
+            failure
+                .error_message
+                .as_deref()
+                .is_some_and(|m| m.contains("connection refused by host")),
+            "{:?}",
+            failure.error_message
+        );
+    }
+
+    #[test]
+    fn test_custom_matcher_and_close_to() {
+        let source = r#"
+defineMatcher("toBeEven", (actual: unknown, _expected: unknown): string => {
+    if ((actual as number) % 2 == 0) {
+        return "";
+    }
+    return "Expected an even number";
+});
+test("even", () => { expect(4).toSatisfy("toBeEven"); });
+test("odd", () => { expect(3).not.toSatisfy("toBeEven"); });
+test("close", () => { expect(0.1 + 0.2).toBeCloseTo(0.3, 5); });
+test("fails", () => { expect(3).toSatisfy("toBeEven"); });
+"#;
+        let options = RuntimeOptions::default();
+        let r = run_test_source(source, Path::new("matchers.test.raya"), &options)
+            .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(r.passed(), 3, "results: {:?}", r.results.results);
+        assert_eq!(
+            r.results.results[3].error_message.as_deref(),
+            Some("Expected an even number")
+        );
+    }
+
+    #[test]
+    fn test_coverage_reports_uncovered_lines_and_branches() {
+        let source = r#"function classify(n: number): string {
+    if (n > 0) {
+        return "positive";
+    }
+    return "non-positive";
+}
+
+test("positive", () => { expect(classify(1)).toBe("positive"); });
+"#;
+        let options = RuntimeOptions::default();
+        let r = run_test_source_with_coverage(source, Path::new("cov.test.raya"), &options)
+            .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(r.passed(), 1);
+
+        let report = r.coverage.expect("coverage collected");
+        assert_eq!(report.files.len(), 1, "std modules are not reported");
+        let coverage = &report.files[0];
+        assert_eq!(coverage.path, "cov.test.raya");
+        let uncovered = coverage.uncovered_lines();
+        assert!(uncovered.contains(&5), "uncovered: {:?}", uncovered);
+        assert!(!uncovered.contains(&3), "uncovered: {:?}", uncovered);
+        assert_eq!(coverage.lines.get(&3), Some(&true));
+        assert!(coverage.line_percent() > 0.0 && coverage.line_percent() < 100.0);
+        assert!(coverage.branches_total() >= 2);
+        assert!(coverage.branches_covered() < coverage.branches_total());
+    }
+
+    #[test]
+    fn test_coverage_includes_imported_modules() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("sign.raya"),
+            r#"export function sign(n: number): number {
+    if (n < 0) {
+        return -1;
+    }
+    return 1;
+}
+"#,
+        )
+        .unwrap();
+        let source = r#"import { sign } from "./sign.raya";
+
+test("sign", () => { expect(sign(2)).toBe(1); });
+"#;
+        let test_path = dir.path().join("sign.test.raya");
+        std::fs::write(&test_path, source).unwrap();
+
+        let r = run_test_file_with_coverage(&test_path, &RuntimeOptions::default())
+            .expect("should compile and run");
+        assert!(
+            r.execution_error.is_none(),
+            "no execution error: {:?}",
+            r.execution_error
+        );
+        assert_eq!(r.passed(), 1);
+
+        let report = r.coverage.expect("coverage collected");
+        let imported = report
+            .files
+            .iter()
+            .find(|file| file.path.ends_with("sign.raya"))
+            .expect("imported module is reported");
+        assert_eq!(imported.lines.get(&5), Some(&true));
+        assert_eq!(imported.lines.get(&3), Some(&false));
+        assert!(imported.branches_covered() < imported.branches_total());
+    }
+
+    #[test]
+    fn test_coverage_is_off_by_default() {
+        let options = RuntimeOptions::default();
+        let r = run_test_source(
+            "test(\"simple\", () => { expect(1).toBe(1); });\n",
+            Path::new("simple.test.raya"),
+            &options,
+        )
+        .expect("should compile and run");
+        assert!(r.coverage.is_none());
+    }
 }
@@ -4,6 +4,7 @@ use raya_engine::vm::scheduler::SchedulerLimits;
 use raya_engine::vm::Vm;
 use raya_stdlib::StdNativeHandler;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::RuntimeOptions;
 
@@ -21,16 +22,31 @@ pub fn create_vm(options: &RuntimeOptions) -> Vm {
         } else {
             None
         },
+        max_stack_size: if options.stack_size > 0 {
+            Some(options.stack_size)
+        } else {
+            None
+        },
+        max_open_files: if options.max_open_files > 0 {
+            Some(options.max_open_files)
+        } else {
+            None
+        },
+        max_open_sockets: if options.max_open_sockets > 0 {
+            Some(options.max_open_sockets)
+        } else {
+            None
+        },
         ..Default::default()
     };
 
-    let mut vm = Vm::with_native_handler(threads, Arc::new(StdNativeHandler));
-
-    // Apply scheduler limits if any were set
-    if options.heap_limit > 0 {
-        // Recreate with limits
-        vm = create_vm_with_limits(threads, limits);
+    let mut vm = Vm::with_limits_and_handler(threads, limits, Arc::new(StdNativeHandler));
+    if options.timeout > 0 {
+        vm.set_execution_timeout(Some(Duration::from_millis(options.timeout)));
     }
+    vm.set_program_args(options.program_args.clone());
+    vm.set_native_policy(options.native_policy.clone());
+    vm.set_deterministic_float(options.deterministic_float);
 
     // Register symbolic native functions for ModuleNativeCall dispatch
     {
@@ -41,15 +57,3 @@ pub fn create_vm(options: &RuntimeOptions) -> Vm {
 
     vm
 }
-
-fn create_vm_with_limits(threads: usize, limits: SchedulerLimits) -> Vm {
-    // When we have limits, we need to use the scheduler_limits constructor
-    // and also set the native handler. Since with_scheduler_limits doesn't
-    // take a native handler, we use with_native_handler then apply limits
-    // through the scheduler. For now, use with_native_handler which is the
-    // primary path.
-    let vm = Vm::with_native_handler(threads, Arc::new(StdNativeHandler));
-    // TODO: Apply SchedulerLimits when Vm API supports both limits + handler
-    let _ = limits;
-    vm
-}
@@ -641,3 +641,47 @@ fn test_string_array_param_index_dispatch() {
         "opt",
     );
 }
+
+#[test]
+fn test_array_with_capacity_starts_empty() {
+    expect_i32(
+        "let xs: number[] = Array.withCapacity<number>(100);
+         return xs.length;",
+        0,
+    );
+}
+
+#[test]
+fn test_array_with_capacity_push_fills_in_order() {
+    expect_i32(
+        "let xs: number[] = Array.withCapacity<number>(8);
+         for (let i = 0; i < 20; i = i + 1) {
+             xs.push(i * 2);
+         }
+         return xs.length * 100 + xs[19];",
+        2038,
+    );
+}
+
+#[test]
+fn test_new_array_with_numeric_arg_reserves_capacity() {
+    expect_i32(
+        "let n = 64;
+         let xs = new Array<number>(n);
+         let empty = xs.length;
+         for (let i = 0; i < n; i = i + 1) {
+             xs.push(i);
+         }
+         return empty * 1000 + xs.length + xs[63];",
+        127,
+    );
+}
+
+#[test]
+fn test_new_array_with_numeric_literal_keeps_length_in_node_compat() {
+    expect_i32_runtime_node_compat(
+        "let xs = new Array(3);
+         return xs.length;",
+        3,
+    );
+}
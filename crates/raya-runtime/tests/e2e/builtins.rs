@@ -10,9 +10,10 @@
 //! are added to the VM. The tests verify compilation succeeds.
 
 use super::harness::{
-    expect_bool, expect_bool_runtime, expect_bool_runtime_node_compat, expect_bool_with_builtins,
-    expect_i32, expect_i32_runtime_node_compat, expect_i32_with_builtins, expect_string,
-    expect_string_runtime_node_compat, expect_string_with_builtins,
+    compile_with_builtins, expect_bool, expect_bool_runtime, expect_bool_runtime_node_compat,
+    expect_bool_with_builtins, expect_compile_error, expect_i32, expect_i32_runtime_node_compat,
+    expect_i32_with_builtins, expect_string, expect_string_runtime_node_compat,
+    expect_string_with_builtins,
 };
 
 // ============================================================================
@@ -480,6 +481,183 @@ fn test_buffer_get_set_float64() {
     );
 }
 
+#[test]
+fn test_buffer_int32_big_and_little_endian() {
+    expect_bool_with_builtins(
+        r#"
+        let buf = new Buffer(8);
+        buf.setInt32BE(0, 16909060);
+        buf.setInt32LE(4, 16909060);
+        return buf.getByte(0) == 1 && buf.getByte(3) == 4
+            && buf.getByte(4) == 4 && buf.getByte(7) == 1
+            && buf.getInt32BE(0) == 16909060 && buf.getInt32LE(4) == 16909060
+            && buf.getInt32(4) == 16909060 && buf.getInt32LE(0) == 67305985;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_buffer_int16_uint16_and_uint8() {
+    expect_bool_with_builtins(
+        r#"
+        let buf = new Buffer(4);
+        buf.setUint16BE(0, 65534);
+        buf.setInt16LE(2, -2);
+        return buf.getUint8(0) == 255 && buf.getUint16BE(0) == 65534
+            && buf.getInt16BE(0) == -2 && buf.getInt16LE(2) == -2
+            && buf.getUint16LE(2) == 65534;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_buffer_float64_big_endian_round_trip() {
+    expect_bool_with_builtins(
+        r#"
+        let buf = new Buffer(8);
+        buf.setFloat64BE(0, 1.5);
+        return buf.getByte(0) == 63 && buf.getFloat64BE(0) == 1.5 && buf.getFloat64LE(0) != 1.5;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_buffer_out_of_bounds_read_is_catchable() {
+    expect_string_with_builtins(
+        r#"
+        let buf = new Buffer(8);
+        try {
+            buf.getInt32BE(6);
+            return "no error";
+        } catch (e) {
+            return "caught";
+        }
+    "#,
+        "caught",
+    );
+}
+
+#[test]
+fn test_buffer_accessor_accepts_integral_number_offset() {
+    expect_bool_with_builtins(
+        r#"
+        let buf = new Buffer(8);
+        let offset: number = 8 / 2;
+        buf.setInt32BE(offset, 7);
+        return buf.getByte(7) == 7 && buf.getInt32BE(offset) == 7;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_buffer_accessor_invalid_offsets_are_catchable() {
+    expect_i32_with_builtins(
+        r#"
+        let buf = new Buffer(8);
+        let offsets: number[] = [1.5, -1, 1e20];
+        let caught = 0;
+        for (const offset of offsets) {
+            try {
+                buf.getUint8(offset);
+            } catch (e) {
+                caught = caught + 1;
+            }
+        }
+        return caught;
+    "#,
+        3,
+    );
+}
+
+#[test]
+fn test_utf8_round_trip_ascii_and_emoji() {
+    expect_bool_with_builtins(
+        r#"
+        let ascii = encodeUtf8("hello");
+        let emoji = encodeUtf8("hi 😀");
+        return ascii.length == 5 && decodeUtf8(ascii) == "hello"
+            && emoji.length == 7 && emoji.getByte(3) == 0xF0
+            && decodeUtf8(emoji, true) == "hi 😀";
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_utf16le_round_trip() {
+    expect_bool_with_builtins(
+        r#"
+        let buf = encodeUtf16LE("A😀");
+        return buf.length == 6 && buf.getByte(0) == 0x41 && buf.getByte(1) == 0
+            && decodeUtf16LE(buf, true) == "A😀";
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_decode_invalid_bytes_lenient_uses_replacement_character() {
+    expect_bool_with_builtins(
+        r#"
+        let buf = new Buffer(3);
+        buf.setByte(0, 0x61);
+        buf.setByte(1, 0xFF);
+        buf.setByte(2, 0x62);
+        let text = decodeUtf8(buf);
+        let odd = decodeUtf16LE(buf);
+        return text.charCodeAt(0) == 0x61 && text.charCodeAt(1) == 65533
+            && odd.charCodeAt(1) == 65533;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_decode_invalid_bytes_fatal_throws() {
+    expect_string_with_builtins(
+        r#"
+        let buf = new Buffer(1);
+        buf.setByte(0, 0xC3);
+        let result = "";
+        try {
+            decodeUtf8(buf, true);
+            result = "no error";
+        } catch (e) {
+            result = "utf8";
+        }
+        try {
+            decodeUtf16LE(buf, true);
+            result = result + ",no error";
+        } catch (e) {
+            result = result + ",utf16";
+        }
+        return result;
+    "#,
+        "utf8,utf16",
+    );
+}
+
+#[test]
+fn test_unknown_encoding_throws_type_error() {
+    expect_string_with_builtins(
+        r#"
+        let result = "";
+        try {
+            encodeUtf8("hi").toString("latin2");
+            result = "no error";
+        } catch (e) {
+            result = String(e);
+        }
+        return result;
+    "#,
+        "Type error: Unknown encoding: latin2",
+    );
+}
+
 // ============================================================================
 // TypedArray / DataView tests
 // ============================================================================
@@ -623,19 +801,19 @@ fn test_dataview_out_of_range_error_code() {
 }
 
 #[test]
-fn test_dataview_big_endian_unimplemented_behavior_error_code() {
-    expect_string_runtime_node_compat(
+fn test_dataview_big_endian_round_trip() {
+    expect_bool_runtime_node_compat(
         r#"
-        let ab = new ArrayBuffer(8);
+        let ab = new ArrayBuffer(16);
         let view = new DataView(ab);
-        try {
-            view.getInt32(0, false);
-            return "NO_ERR";
-        } catch (e) {
-            return e.code;
-        }
+        view.setInt32(0, 16909060, false);
+        view.setFloat64(8, 1.5, false);
+        return ab.getByte(0) == 1 && ab.getByte(3) == 4
+            && view.getInt32(0, false) == 16909060
+            && view.getInt32(0, true) == 67305985
+            && ab.getByte(8) == 63 && view.getFloat64(8, false) == 1.5;
     "#,
-        "E_UNIMPLEMENTED_BUILTIN_BEHAVIOR",
+        true,
     );
 }
 
@@ -1155,18 +1333,44 @@ fn test_string_match_all_basic() {
 
 #[test]
 fn test_string_match_all_with_index() {
-    // matchAll returns array of [match, index, ...groups]
     expect_i32(
         r#"
         let re = new RegExp("o", "g");
         let results = "hello world".matchAll(re);
         // First match at index 4
-        return results[0][1];
+        return results[0].index;
     "#,
         4,
     );
 }
 
+#[test]
+fn test_string_match_all_indices() {
+    expect_i32(
+        r#"
+        let results = "a1 b22 c3".matchAll(new RegExp("\\d+", "g"));
+        return results.length * 1000
+            + results[0].index * 100
+            + results[1].index * 10
+            + results[2].index;
+    "#,
+        3148,
+    );
+}
+
+#[test]
+fn test_string_match_all_capture_groups() {
+    expect_string(
+        r#"
+        let results: RegExpMatch[] = "k1=v1; k2=v2".matchAll(new RegExp("(\\w+)=(\\w+)", "g"));
+        let second = results[1];
+        return (results[0].group(1) as string) + "," + second.match + "," + second.groups[1]
+            + "," + second.groupCount() + "," + (second.group(3) == null ? "none" : "some") + "," + second.input;
+    "#,
+        "k1,k2=v2,v2,2,none,k1=v1; k2=v2",
+    );
+}
+
 #[test]
 fn test_string_search_found() {
     expect_i32(
@@ -1339,6 +1543,40 @@ fn test_string_split_regexp_pattern_content() {
     );
 }
 
+#[test]
+fn test_string_split_limit_drops_the_rest() {
+    expect_string(
+        r#"
+        let re = new RegExp(",", "");
+        return "a,b,c".split(",", 2).join("|") + ";" + "a,b,c,d,e".split(re, 3).join("|");
+    "#,
+        "a|b;a|b|c",
+    );
+}
+
+#[test]
+fn test_string_split_empty_separator_matches_for_string_and_regexp() {
+    expect_string(
+        r#"
+        let byString = "abc".split("").join("|");
+        let byRegExp = "abc".split(new RegExp("", "")).join("|");
+        return byString + ";" + byRegExp + ";" + "".split("").length;
+    "#,
+        "a|b|c;a|b|c;0",
+    );
+}
+
+#[test]
+fn test_string_split_union_typed_regexp_separator() {
+    expect_string(
+        r#"
+        let sep: string | RegExp = new RegExp("\\s*;\\s*", "");
+        return "x ; y;z".split(sep).join("|");
+    "#,
+        "x|y|z",
+    );
+}
+
 // ============================================================================
 // String replaceWith (callback-based replacement)
 // ============================================================================
@@ -1977,6 +2215,21 @@ fn test_map_values() {
     );
 }
 
+#[test]
+fn test_map_from_entries_keeps_insertion_order() {
+    // A repeated key keeps its first position but takes the last value.
+    expect_string_with_builtins(
+        r#"
+        let m = new Map<string, number>([["b", 2], ["a", 1], ["c", 3], ["a", 10]]);
+        if (m.size != 3) {
+            return "wrong size";
+        }
+        return m.keys().join(",") + "|" + m.values().join(",");
+    "#,
+        "b,a,c|2,10,3",
+    );
+}
+
 // ============================================================================
 // Set.values / Set.union / Set.intersection / Set.difference
 // ============================================================================
@@ -1996,6 +2249,20 @@ fn test_set_values() {
     );
 }
 
+#[test]
+fn test_set_from_array_deduplicates() {
+    expect_string_with_builtins(
+        r#"
+        let s = new Set<number>([3, 1, 3, 2, 1]);
+        if (s.size != 3) {
+            return "wrong size";
+        }
+        return s.values().join(",");
+    "#,
+        "3,1,2",
+    );
+}
+
 #[test]
 fn test_set_intersection() {
     expect_i32_with_builtins(
@@ -2444,6 +2711,87 @@ fn test_iterator_to_array_remaining_values() {
     );
 }
 
+#[test]
+fn test_iterator_to_array_does_not_consume() {
+    expect_i32_with_builtins(
+        r#"
+        let it = Iterator.fromArray<number>([1, 2, 3]);
+        let first = it.toArray();
+        let second = it.toArray();
+        let drained = it.collect();
+        let after = it.toArray();
+        return first.length * 1000 + second.length * 100 + drained.length * 10 + after.length;
+    "#,
+        3330,
+    );
+}
+
+#[test]
+fn test_iterator_lazy_take_pulls_only_needed_values() {
+    expect_i32_with_builtins(
+        r#"
+        let pulled = 0;
+        let firstEvens = Iterator.range(0, 1000000)
+            .map((n: number): number => {
+                pulled = pulled + 1;
+                return n;
+            })
+            .filter((n: number): boolean => n % 2 == 0)
+            .take(3)
+            .collect();
+        return pulled * 100 + firstEvens.length;
+    "#,
+        503,
+    );
+}
+
+#[test]
+fn test_iterator_lazy_chain_collects_expected_values() {
+    expect_string_with_builtins(
+        r#"
+        let out = Iterator.fromArray<number>([1, 2, 3, 4, 5, 6])
+            .map((n: number): number => n * 10)
+            .filter((n: number): boolean => n > 20)
+            .take(3)
+            .collect();
+        return out.join(",");
+    "#,
+        "30,40,50",
+    );
+}
+
+#[test]
+fn test_iterator_combinator_chain_type_checks() {
+    let result = compile_with_builtins(
+        r#"
+        let labels: string[] = Iterator.range(0, 5)
+            .map((n: number): string => "n" + n)
+            .filter((s: string): boolean => s != "n2")
+            .take(2)
+            .collect();
+        let pulled: Iterator<number> = Iterator.fromNext<number>(
+            (): IteratorResult<number> => new IteratorResult<number>(null, true),
+        );
+        return labels.length + pulled.collect().length;
+    "#,
+    );
+    assert!(
+        result.is_ok(),
+        "iterator chain should type-check: {:?}",
+        result.err()
+    );
+
+    expect_compile_error(
+        r#"
+        let wrong: number[] = Iterator.range(0, 5)
+            .map((n: number): string => "n" + n)
+            .collect();
+        return wrong.length;
+    "#,
+        "TypeMismatch",
+    );
+}
+
 #[test]
 fn test_node_compat_function_constructor_unimplemented_behavior_error_code() {
     expect_string_runtime_node_compat(
@@ -2604,10 +2952,11 @@ fn test_constructor_globals_strict_surface() {
         let n = Number("42");
         let s = String(42);
         let a = new Array<number>(2);
-        a[0] = 7;
-        a[1] = 8;
+        let reserved = a.length;
+        a.push(7);
+        a.push(8);
         let b2 = new Array<number>(1, 2);
-        return b && n == 42 && s == "42" && a.length == 2 && (a[0] + a[1]) == 15 && b2.length == 2;
+        return b && n == 42 && s == "42" && reserved == 0 && (a[0] + a[1]) == 15 && b2.length == 2;
     "#,
         true,
     );
@@ -2632,7 +2981,7 @@ fn test_node_compat_eval_unimplemented_behavior_error_code() {
 fn test_node_compat_weakmap_basic_object_key_roundtrip() {
     expect_i32_runtime_node_compat(
         r#"
-        let wm = new WeakMap<number>();
+        let wm = new WeakMap<Object, number>();
         let k = new Object();
         wm.set(k, 42);
         let v = wm.get(k);
@@ -2645,6 +2994,20 @@ fn test_node_compat_weakmap_basic_object_key_roundtrip() {
     );
 }
 
+#[test]
+fn test_node_compat_weakmap_has_and_delete() {
+    expect_bool_runtime_node_compat(
+        r#"
+        let wm = new WeakMap<Object, string>();
+        let a = new Object();
+        let b = new Object();
+        wm.set(a, "a");
+        return wm.has(a) && !wm.has(b) && wm.delete(a) && !wm.has(a) && wm.get(a) == null;
+    "#,
+        true,
+    );
+}
+
 #[test]
 fn test_node_compat_weakset_basic_identity_membership() {
     expect_bool_runtime_node_compat(
@@ -2785,3 +3148,380 @@ fn test_event_emitter_remove_all_listeners() {
         true,
     );
 }
+
+// ============================================================================
+// Object.keys / Object.values / Object.entries
+// ============================================================================
+
+#[test]
+fn test_object_keys_in_declaration_order_without_methods() {
+    expect_string_with_builtins(
+        r#"
+        class Person {
+            name: string;
+            age: number;
+            constructor(name: string, age: number) {
+                this.name = name;
+                this.age = age;
+            }
+            greet(): string {
+                return "hi " + this.name;
+            }
+        }
+        let p = new Person("Ada", 36);
+        return Object.keys(p).join(",");
+    "#,
+        "name,age",
+    );
+}
+
+#[test]
+fn test_object_values_in_declaration_order() {
+    expect_bool_with_builtins(
+        r#"
+        class Person {
+            name: string;
+            age: number;
+            constructor(name: string, age: number) {
+                this.name = name;
+                this.age = age;
+            }
+            greet(): string {
+                return "hi " + this.name;
+            }
+        }
+        let values = Object.values(new Person("Ada", 36));
+        return values.length == 2
+            && (values[0] as string) == "Ada"
+            && (values[1] as number) == 36;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_object_entries_pairs_keys_with_values() {
+    expect_bool_with_builtins(
+        r#"
+        class Person {
+            name: string;
+            age: number;
+            constructor(name: string, age: number) {
+                this.name = name;
+                this.age = age;
+            }
+            greet(): string {
+                return "hi " + this.name;
+            }
+        }
+        let entries = Object.entries(new Person("Ada", 36));
+        let first = entries[0];
+        let second = entries[1];
+        return entries.length == 2
+            && first[0] == "name" && (first[1] as string) == "Ada"
+            && second[0] == "age" && (second[1] as number) == 36;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_object_keys_lists_dynamic_properties_in_insertion_order() {
+    // The first parse interns "b" before "a"; the second object's keys must
+    // still come back in the order they were added.
+    expect_string_with_builtins(
+        r#"
+        let seed = JSON.parse('{"b": 1, "a": 2}');
+        let obj = JSON.parse('{"a": 3, "b": 4}');
+        let entries = Object.entries(obj);
+        return Object.keys(seed).join(",") + "|" + Object.keys(obj).join(",")
+            + "|" + entries[0][0] + "," + entries[1][0];
+    "#,
+        "b,a|a,b|a,b",
+    );
+}
+
+// ============================================================================
+// Object.freeze / Object.isFrozen
+// ============================================================================
+
+#[test]
+fn test_object_freeze_rejects_field_assignment() {
+    expect_bool_with_builtins(
+        r#"
+        class Point {
+            x: number;
+            y: number;
+            constructor(x: number, y: number) {
+                this.x = x;
+                this.y = y;
+            }
+        }
+        let p = Object.freeze(new Point(1, 2));
+        let threw = false;
+        try {
+            p.x = 10;
+        } catch (e) {
+            threw = true;
+        }
+        return threw && p.x == 1 && p.y == 2;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_object_freeze_rejects_dynamic_property_assignment_with_type_error() {
+    expect_bool_with_builtins(
+        r#"
+        let user = Object.freeze(JSON.parse('{"name":"Alice"}')) as { name: string };
+        let message = "";
+        try {
+            user.name = "Bob";
+        } catch (e) {
+            message = String(e);
+        }
+        return message.startsWith("Type error") && user.name == "Alice";
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_object_is_frozen_before_and_after_freeze() {
+    expect_bool_with_builtins(
+        r#"
+        class Point {
+            x: number;
+            constructor(x: number) {
+                this.x = x;
+            }
+        }
+        let p = new Point(1);
+        let before = Object.isFrozen(p);
+        p.x = 2;
+        Object.freeze(p);
+        return !before && Object.isFrozen(p) && p.x == 2;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_object_freeze_is_shallow_and_deep_freeze_is_not() {
+    expect_bool_with_builtins(
+        r#"
+        class Inner {
+            value: number;
+            constructor(value: number) {
+                this.value = value;
+            }
+        }
+        class Outer {
+            inner: Inner;
+            constructor(inner: Inner) {
+                this.inner = inner;
+            }
+        }
+        let shallow = Object.freeze(new Outer(new Inner(1)));
+        shallow.inner.value = 2;
+        let deep = Object.deepFreeze(new Outer(new Inner(1)));
+        let threw = false;
+        try {
+            deep.inner.value = 2;
+        } catch (e) {
+            threw = true;
+        }
+        return shallow.inner.value == 2
+            && !Object.isFrozen(shallow.inner)
+            && threw
+            && Object.isFrozen(deep.inner)
+            && deep.inner.value == 1;
+    "#,
+        true,
+    );
+}
+
+// ============================================================================
+// Random tests
+// ============================================================================
+
+#[test]
+fn test_random_same_seed_same_sequence() {
+    expect_bool_with_builtins(
+        r#"
+        let a = new Random(1234);
+        let b = new Random(1234);
+        for (let i = 0; i < 50; i++) {
+            if (a.nextInt(1000) != b.nextInt(1000)) {
+                return false;
+            }
+            if (a.nextFloat() != b.nextFloat()) {
+                return false;
+            }
+        }
+        let xs: number[] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let ys: number[] = [1, 2, 3, 4, 5, 6, 7, 8];
+        a.shuffle(xs);
+        b.shuffle(ys);
+        for (let i = 0; i < xs.length; i++) {
+            if (xs[i] != ys[i]) {
+                return false;
+            }
+        }
+        return a.choice(xs) == b.choice(ys);
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_random_different_seeds_diverge() {
+    expect_bool_with_builtins(
+        r#"
+        let a = new Random(1);
+        let b = new Random(2);
+        let same = 0;
+        for (let i = 0; i < 20; i++) {
+            if (a.nextInt(1000000) == b.nextInt(1000000)) {
+                same = same + 1;
+            }
+        }
+        return same < 20;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_random_ranges() {
+    expect_bool_with_builtins(
+        r#"
+        let rng = new Random(99);
+        for (let i = 0; i < 200; i++) {
+            let n = rng.nextInt(6);
+            let f = rng.nextFloat();
+            if (n < 0 || n >= 6 || n % 1 != 0 || f < 0 || f >= 1) {
+                return false;
+            }
+        }
+        return true;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_random_shuffle_is_permutation() {
+    expect_bool_with_builtins(
+        r#"
+        let rng = new Random(7);
+        let xs: number[] = [];
+        for (let i = 0; i < 20; i++) {
+            xs.push(i);
+        }
+        let shuffled = rng.shuffle(xs);
+        if (shuffled.length != 20) {
+            return false;
+        }
+        let seen: boolean[] = [];
+        for (let i = 0; i < 20; i++) {
+            seen.push(false);
+        }
+        for (let i = 0; i < shuffled.length; i++) {
+            let v = shuffled[i];
+            if (seen[v]) {
+                return false;
+            }
+            seen[v] = true;
+        }
+        for (let i = 0; i < 20; i++) {
+            if (!seen[i]) {
+                return false;
+            }
+        }
+        return true;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_random_choice_returns_element() {
+    expect_bool_with_builtins(
+        r#"
+        let rng = new Random(3);
+        let xs: string[] = ["a", "b", "c"];
+        for (let i = 0; i < 30; i++) {
+            let c = rng.choice(xs);
+            if (c != "a" && c != "b" && c != "c") {
+                return false;
+            }
+        }
+        return true;
+    "#,
+        true,
+    );
+}
+
+#[test]
+fn test_random_choice_empty_throws() {
+    expect_bool_with_builtins(
+        r#"
+        let rng = new Random(3);
+        let xs: number[] = [];
+        try {
+            rng.choice(xs);
+        } catch (e) {
+            return true;
+        }
+        return false;
+    "#,
+        true,
+    );
+}
+
+// ============================================================================
+// BigInt tests
+// ============================================================================
+
+#[test]
+fn test_bigint_literal_arithmetic_beyond_f64_precision() {
+    expect_string_with_builtins(
+        r#"
+        let a = 9007199254740993n;
+        let b = new BigInt("-123456789012345678901234567890");
+        let c = a.mul(b).add(1n);
+        return c.toString() + "|" + a.sub(a).toString() + "|" + 2n.pow(100).toString();
+    "#,
+        "-1111999897984716012249948601223882860224514769|0|1267650600228229401496703205376",
+    );
+}
+
+#[test]
+fn test_bigint_div_mod_and_compare() {
+    expect_string_with_builtins(
+        r#"
+        let q = new BigInt("-7").div(2n);
+        let r = new BigInt("-7").mod(2n);
+        return q.toString() + "," + r.toString() + "," + 10n.compareTo(9n) + ","
+            + new BigInt("-10").compareTo(2n) + "," + 5n.compareTo(5n);
+    "#,
+        "-3,-1,1,-1,0",
+    );
+}
+
+#[test]
+fn test_bigint_division_by_zero_is_catchable() {
+    expect_bool_with_builtins(
+        r#"
+        try {
+            1n.div(0n);
+        } catch (e) {
+            return String(e).indexOf("Division by zero") >= 0;
+        }
+        return false;
+    "#,
+        true,
+    );
+}
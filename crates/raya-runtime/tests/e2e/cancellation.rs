@@ -0,0 +1,63 @@
+//! E2E tests for cancelling a running execution from another thread
+
+use raya_runtime::{Runtime, RuntimeError, RuntimeOptions};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const LONG_LOOP: &str = "\
+let n = 0;
+for (let i = 0; i < 1000000000; i = i + 1) {
+    n = n + 1;
+}
+return n;
+";
+
+fn interpreter_runtime() -> Runtime {
+    Runtime::with_options(RuntimeOptions {
+        no_jit: true,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_execute_cancellable_stops_long_loop_from_another_thread() {
+    let rt = interpreter_runtime();
+    let module = rt.compile(LONG_LOOP).expect("compiles");
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    let canceller = {
+        let cancel = cancel.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            cancel.store(true, Ordering::Relaxed);
+        })
+    };
+
+    let start = Instant::now();
+    let result = rt.execute_cancellable(&module, cancel);
+    let elapsed = start.elapsed();
+    canceller.join().unwrap();
+
+    assert!(
+        matches!(result, Err(RuntimeError::Cancelled)),
+        "expected cancellation, got {:?}",
+        result
+    );
+    assert!(
+        elapsed < Duration::from_secs(2),
+        "cancellation took {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn test_execute_cancellable_without_cancel_runs_to_completion() {
+    let rt = interpreter_runtime();
+    let module = rt.compile("return 6 * 7;").expect("compiles");
+    let value = rt
+        .execute_cancellable(&module, Arc::new(AtomicBool::new(false)))
+        .expect("runs");
+    assert_eq!(value.as_i32(), Some(42));
+}
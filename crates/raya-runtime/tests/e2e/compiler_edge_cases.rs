@@ -156,6 +156,34 @@ fn test_locals_with_complex_init() {
     );
 }
 
+#[test]
+fn test_short_lived_temporaries_share_local_slots() {
+    // Every `n * k` term and partial sum is a fresh temporary in the IR;
+    // coalescing lets them share a handful of slots
+    let source = "function manyTemps(n: int): int {
+             return n * 1 + n * 2 + n * 3 + n * 4 + n * 5 + n * 6
+                  + n * 7 + n * 8 + n * 9 + n * 10 + n * 11 + n * 12
+                  + n * 13 + n * 14 + n * 15 + n * 16 + n * 17 + n * 18
+                  + n * 19 + n * 20 + n * 21 + n * 22 + n * 23 + n * 24
+                  + n * 25 + n * 26 + n * 27 + n * 28 + n * 29 + n * 30;
+         }
+         return manyTemps(2);";
+
+    let (module, _) = compile(source).expect("compile failed");
+    let func = module
+        .functions
+        .iter()
+        .find(|f| f.name.contains("manyTemps"))
+        .expect("manyTemps not found");
+    assert!(
+        func.local_count <= 12,
+        "expected coalesced slots, got local_count = {}",
+        func.local_count
+    );
+
+    expect_i32(source, 930);
+}
+
 #[test]
 fn test_many_function_parameters() {
     expect_i32(
@@ -388,6 +416,132 @@ fn test_switch_no_match_falls_to_default() {
     );
 }
 
+#[test]
+fn test_switch_when_guard_matches_only_when_guard_holds() {
+    expect_i32(
+        "function classify(x: int, limit: int): int {
+             switch (x) {
+                 case 5 when limit > 10: return 1;
+                 case 5: return 2;
+                 default: return 3;
+             }
+         }
+         return classify(5, 20) * 100 + classify(5, 0) * 10 + classify(6, 20);",
+        123,
+    );
+}
+
+#[test]
+fn test_switch_string_when_guard() {
+    expect_i32(
+        "function score(kind: string, n: int): int {
+             switch (kind) {
+                 case \"big\" when n > 100: return 2;
+                 case \"big\": return 1;
+             }
+             return 0;
+         }
+         return score(\"big\", 500) * 10 + score(\"big\", 5);",
+        21,
+    );
+}
+
+#[test]
+fn test_switch_fallthrough_reaches_next_case() {
+    expect_i32(
+        "function test(x: int): int {
+             let result = 0;
+             switch (x) {
+                 case 1:
+                     result = result + 10;
+                     fallthrough;
+                 case 2:
+                     result = result + 32;
+                     break;
+                 case 3:
+                     result = result + 1000;
+             }
+             return result;
+         }
+         return test(1);",
+        42,
+    );
+}
+
+#[test]
+fn test_switch_without_fallthrough_breaks_in_explicit_switch() {
+    expect_i32(
+        "function test(x: int): int {
+             let result = 0;
+             switch (x) {
+                 case 1 when result == 0:
+                     result = 42;
+                 case 2:
+                     result = 1000;
+             }
+             return result;
+         }
+         return test(1);",
+        42,
+    );
+}
+
+#[test]
+fn test_switch_guard_does_not_disable_fallthrough_in_other_cases() {
+    expect_i32(
+        "function test(x: int): int {
+             let result = 0;
+             switch (x) {
+                 case 1:
+                     result = result + 2;
+                 case 2:
+                     result = result + 40;
+                     break;
+                 case 3 when result == 0:
+                     result = 1000;
+             }
+             return result;
+         }
+         return test(1);",
+        42,
+    );
+}
+
+#[test]
+fn test_switch_guard_narrows_on_direct_entry() {
+    expect_i32(
+        "function test(x: string | int, k: int): int {
+             switch (k) {
+                 case 1 when typeof x === \"string\":
+                     return x.length;
+                 case 2:
+                     return 0;
+             }
+             return 40;
+         }
+         return test(\"hi\", 1) + test(1, 1);",
+        42,
+    );
+}
+
+#[test]
+fn test_switch_guard_does_not_narrow_on_fallthrough_entry() {
+    expect_compile_error(
+        "function test(x: string | int, k: int): int {
+             switch (k) {
+                 case 1:
+                     k = 2;
+                 case 2 when typeof x === \"int\":
+                     let n: int = x;
+                     return n;
+             }
+             return 0;
+         }
+         return test(1, 1);",
+        "TypeMismatch",
+    );
+}
+
 // ============================================================================
 // 7. Exception Handling Compilation
 // ============================================================================
@@ -628,3 +782,14 @@ fn test_string_in_conditional() {
         42,
     );
 }
+
+#[test]
+fn test_inlined_leaf_function_in_loop() {
+    expect_i32(
+        "function add(a: number, b: number): number { return a + b; }
+         let sum = 0;
+         for (let i = 0; i < 10; i = i + 1) { sum = add(sum, i); }
+         return sum;",
+        45,
+    );
+}
@@ -28,6 +28,99 @@ fn test_task_cancel() {
     );
 }
 
+// ============================================================================
+// Task Scopes (structured concurrency)
+// ============================================================================
+
+#[test]
+fn test_task_scope_waits_for_all_children() {
+    expect_i32_with_builtins(
+        "let done: number[] = [];
+         let result = taskScope((scope: TaskScope): number => {
+             scope.spawn(async (): Promise<void> => {
+                 sleep(20);
+                 done.push(1);
+             });
+             scope.spawn(async (): Promise<void> => {
+                 sleep(5);
+                 done.push(2);
+             });
+             return 7;
+         });
+         return result * 100 + done.length * 10 + done[0];",
+        722,
+    );
+}
+
+#[test]
+fn test_task_scope_early_exit_cancels_running_children() {
+    expect_i32_with_builtins(
+        "let progress: number[] = [];
+         let children: Promise<void>[] = [];
+         let caught = 0;
+         try {
+             taskScope((scope: TaskScope): number => {
+                 children.push(scope.spawn(async (): Promise<void> => {
+                     sleep(10000);
+                     progress.push(1);
+                 }));
+                 throw new Error('leaving early');
+             });
+         } catch (e) {
+             caught = 1;
+         }
+         let child = children[0];
+         let cancelled = child.isCancelled() && child.isDone() ? 1 : 0;
+         return caught * 100 + cancelled * 10 + progress.length;",
+        110,
+    );
+}
+
+#[test]
+fn test_task_scope_child_failure_cancels_siblings() {
+    expect_i32_with_builtins(
+        "let progress: number[] = [];
+         let caught = 0;
+         try {
+             taskScope((scope: TaskScope): number => {
+                 scope.spawn(async (): Promise<void> => {
+                     sleep(10000);
+                     progress.push(1);
+                 });
+                 scope.spawn(async (): Promise<void> => {
+                     throw new Error('child failed');
+                 });
+                 return 0;
+             });
+         } catch (e) {
+             caught = 1;
+         }
+         return caught * 10 + progress.length;",
+        10,
+    );
+}
+
+#[test]
+fn test_task_scope_waits_for_child_blocked_on_channel() {
+    expect_i32_with_builtins(
+        "let ch = new Channel<number>(1);
+         let got: number[] = [];
+         let feed = async (): Promise<void> => {
+             sleep(20);
+             ch.send(5);
+         };
+         let result = taskScope((scope: TaskScope): number => {
+             scope.spawn(async (): Promise<void> => {
+                 got.push(ch.receive());
+             });
+             feed();
+             return 1;
+         });
+         return result * 10 + got[0];",
+        15,
+    );
+}
+
 // ============================================================================
 // Promise with Shared State
 // ============================================================================
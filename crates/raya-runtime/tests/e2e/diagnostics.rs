@@ -331,3 +331,21 @@ fn test_error_assign_to_wrong_class() {
         "TypeMismatch",
     );
 }
+
+#[test]
+fn test_error_unregistered_native_name() {
+    expect_compile_error(
+        "let x = __NATIVE_CALL<number>(\"math.absolute\", -1);
+         return x;",
+        "Unresolved native function 'math.absolute'",
+    );
+}
+
+#[test]
+fn test_error_unknown_native_id() {
+    expect_compile_error(
+        "let x = __NATIVE_CALL<number>(0x7000, 1);
+         return x;",
+        "native id 0x7000",
+    );
+}
@@ -0,0 +1,83 @@
+//! E2E tests for evaluating snippets with host-provided bindings
+
+use raya_runtime::{Runtime, RuntimeOptions, Session, Value};
+
+fn as_number(value: Value) -> f64 {
+    value
+        .as_i32()
+        .map(f64::from)
+        .or_else(|| value.as_f64())
+        .expect("numeric result")
+}
+
+#[test]
+fn test_eval_with_bindings_reads_numbers() {
+    let rt = Runtime::new();
+    let value = rt
+        .eval_with_bindings(
+            "return x + y;",
+            &[("x", Value::i32(1)), ("y", Value::i32(2))],
+        )
+        .expect("eval with bindings");
+    assert_eq!(as_number(value), 3.0);
+
+    let value = rt
+        .eval_with_bindings(
+            "function scale(n: number): number { return n * factor; }\nreturn scale(4);",
+            &[("factor", Value::f64(2.5))],
+        )
+        .expect("binding visible inside functions");
+    assert_eq!(as_number(value), 10.0);
+}
+
+#[test]
+fn test_eval_with_bindings_threads_string_through() {
+    // Strings come from a live VM; the binding is copied into the evaluating VM.
+    let mut session = Session::new(&RuntimeOptions::default());
+    let name = session.eval("return \"raya\";").expect("string value");
+
+    let rt = Runtime::new();
+    let value = rt
+        .eval_with_bindings(
+            "let greeting = \"hello \" + name; return greeting.length;",
+            &[("name", name)],
+        )
+        .expect("eval with string binding");
+    assert_eq!(as_number(value), 10.0);
+
+    let value = rt
+        .eval_with_bindings(
+            "return name == \"raya\" && enabled;",
+            &[("name", name), ("enabled", Value::bool(true))],
+        )
+        .expect("eval with string and bool bindings");
+    assert_eq!(value.as_bool(), Some(true));
+}
+
+#[test]
+fn test_eval_with_bindings_rejects_colliding_declaration() {
+    let rt = Runtime::new();
+    let err = rt
+        .eval_with_bindings("let x = 5; return x;", &[("x", Value::i32(1))])
+        .expect_err("declaration shadows binding");
+    assert!(
+        err.to_string().contains("Host binding 'x' conflicts"),
+        "unexpected error: {}",
+        err
+    );
+
+    let err = rt
+        .eval_with_bindings("return x;", &[("x", Value::i32(1)), ("x", Value::i32(2))])
+        .expect_err("duplicate binding");
+    assert!(err.to_string().contains("bound more than once"), "{}", err);
+
+    let err = rt
+        .eval_with_bindings("return 1;", &[("Map", Value::i32(1))])
+        .expect_err("binding shadows a builtin");
+    assert!(
+        err.to_string()
+            .contains("Host binding 'Map' conflicts with a builtin global"),
+        "{}",
+        err
+    );
+}
@@ -352,6 +352,49 @@ fn test_error_to_string() {
     );
 }
 
+#[test]
+fn test_assert_failure_throws_assertion_error() {
+    expect_string_with_builtins(
+        "let str = '';
+         try {
+             assert(1 > 2, 'math is broken');
+         } catch (e) {
+             const err = e as AssertionError;
+             str = err.toString();
+         }
+         return str;",
+        "AssertionError: math is broken",
+    );
+}
+
+#[test]
+fn test_assert_failure_captures_stack() {
+    expect_bool_with_builtins(
+        "function check(n: number): void {
+             assert(n > 0);
+         }
+         let stack = '';
+         try {
+             check(0);
+         } catch (e) {
+             const err = e as AssertionError;
+             stack = err.stack;
+         }
+         return stack.includes('AssertionError: assertion failed') && stack.includes('check');",
+        true,
+    );
+}
+
+#[test]
+fn test_assert_success_is_noop() {
+    expect_i32_with_builtins(
+        "let x = 1;
+         assert(x == 1, 'unreachable');
+         return x + 1;",
+        2,
+    );
+}
+
 #[test]
 fn test_error_from_function_simple() {
     // Simplified test: throw Error from function (no if statement)
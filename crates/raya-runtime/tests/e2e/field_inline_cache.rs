@@ -0,0 +1,38 @@
+//! E2E tests for structural field inline caches surviving across tasks
+
+use raya_engine::vm::Vm;
+use raya_runtime::Runtime;
+
+#[test]
+fn test_field_inline_cache_hits_across_tasks() {
+    // Each `async` block runs `norm1` in a new task, and so in a new
+    // interpreter; only a cache that outlives the interpreter can hit.
+    let rt = Runtime::new();
+    let program = rt
+        .compile_program_source(
+            "type Point = { x: number; y: number; };
+             function norm1(p: Point): number { return p.x + p.y; }
+             let a: Point = { y: 2, x: 1 };
+             let total = 0;
+             for (let i = 0; i < 50; i = i + 1) {
+                 let t = async { return norm1(a); };
+                 total = total + await t;
+             }
+             return total;",
+        )
+        .expect("compiles");
+    let mut vm = Vm::with_worker_count(1);
+    let value = rt
+        .execute_program_with_vm(&program, &mut vm)
+        .expect("executes");
+    let total = value
+        .as_i32()
+        .map(f64::from)
+        .or_else(|| value.as_f64())
+        .expect("numeric result");
+    assert_eq!(total, 150.0);
+
+    let (hits, misses) = vm.shared_state().field_inline_caches.stats();
+    assert!(hits >= 90, "hits: {hits}, misses: {misses}");
+    assert!(misses < hits, "hits: {hits}, misses: {misses}");
+}
@@ -1,6 +1,7 @@
 //! E2E tests for std:fs module
 
 use super::harness::*;
+use raya_engine::vm::scheduler::SchedulerLimits;
 
 #[test]
 fn test_fs_temp_dir() {
@@ -289,3 +290,65 @@ fn test_fs_realpath() {
         true,
     );
 }
+
+#[test]
+fn test_fs_open_write_and_read_text() {
+    expect_string_with_builtins(
+        r#"
+        import fs from "std:fs";
+        const fp: string = fs.tempFile("raya_test_open_rw_");
+        const out = fs.open(fp, "w");
+        out.writeText("hello ");
+        out.writeText("raya");
+        out.close();
+        const input = fs.open(fp, "r");
+        const txt: string = input.readText();
+        input.close();
+        fs.remove(fp);
+        return txt;
+    "#,
+        "hello raya",
+    );
+}
+
+#[test]
+fn test_fs_open_file_limit_counts_held_files() {
+    let limits = SchedulerLimits {
+        max_open_files: Some(2),
+        ..Default::default()
+    };
+
+    // Files held open keep their slots until closed; closing one frees a slot.
+    let value = compile_and_run_with_builtins_and_limits(
+        r#"
+        import fs from "std:fs";
+
+        function main(): boolean {
+            const a: string = fs.tempFile("raya_test_limit_a_");
+            const b: string = fs.tempFile("raya_test_limit_b_");
+            const c: string = fs.tempFile("raya_test_limit_c_");
+            const first = fs.open(a, "r");
+            const second = fs.open(b, "r");
+            let rejected = false;
+            try {
+                fs.open(c, "r");
+            } catch (_e) {
+                rejected = true;
+            }
+            first.close();
+            const reopened = fs.open(c, "r");
+            reopened.close();
+            second.close();
+            fs.remove(a);
+            fs.remove(b);
+            fs.remove(c);
+            return rejected;
+        }
+
+        return main();
+        "#,
+        limits,
+    )
+    .expect("file limit program should run");
+    assert_eq!(value.as_bool(), Some(true));
+}
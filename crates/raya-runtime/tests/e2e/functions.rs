@@ -161,6 +161,61 @@ fn test_sum_recursive() {
     );
 }
 
+const DEEP_RECURSION_SOURCE: &str = "function sumTo(n: number): number {
+         if (n <= 0) { return 0; }
+         return n + sumTo(n - 1);
+     }
+     return sumTo(50000);";
+
+#[test]
+fn test_deep_recursion_overflows_default_stack() {
+    expect_runtime_error(DEEP_RECURSION_SOURCE, "Stack overflow");
+}
+
+#[test]
+fn test_deep_recursion_succeeds_with_larger_stack() {
+    let limits = raya_engine::vm::scheduler::SchedulerLimits {
+        max_stack_size: Some(8 * 1024 * 1024),
+        ..Default::default()
+    };
+    let value = compile_and_run_with_limits(DEEP_RECURSION_SOURCE, limits)
+        .expect("deep recursion should fit in an 8MB stack");
+    let actual = value
+        .as_i32()
+        .map(|v| v as f64)
+        .or_else(|| value.as_f64())
+        .expect("expected numeric result");
+    assert_eq!(actual, 1_250_025_000.0);
+}
+
+#[test]
+fn test_unbounded_recursion_reports_overflow_depth() {
+    let limits = raya_engine::vm::scheduler::SchedulerLimits {
+        max_stack_size: Some(64 * 1024),
+        ..Default::default()
+    };
+    let error = compile_and_run_with_limits(
+        "function down(n: number): number { return down(n + 1); }
+         return down(0);",
+        limits,
+    )
+    .expect_err("unbounded recursion should overflow");
+    let message = error.to_string();
+    let depth: usize = message
+        .split("call depth ")
+        .nth(1)
+        .and_then(|rest| rest.split(')').next())
+        .and_then(|n| n.trim().parse().ok())
+        .unwrap_or_else(|| panic!("missing call depth in: {}", message));
+    // 64KB holds 8192 slots; each frame needs at least one.
+    assert!(
+        depth > 1000 && depth <= 8192,
+        "unexpected overflow depth {} in: {}",
+        depth,
+        message
+    );
+}
+
 // ============================================================================
 // Functions with Local Variables
 // ============================================================================
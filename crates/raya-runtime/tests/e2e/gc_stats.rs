@@ -0,0 +1,54 @@
+//! E2E tests for reading GC statistics through the Runtime API
+
+use raya_engine::vm::Vm;
+use raya_runtime::{GcStats, Runtime};
+
+const ALLOCATING: &str = "\
+let kept: number[][] = [];
+for (let i = 0; i < 2000; i = i + 1) {
+    let garbage: number[] = [i, i + 1, i + 2, i + 3];
+    if (i % 10 == 0) {
+        kept.push(garbage);
+    }
+}
+return kept.length;
+";
+
+fn assert_consistent(stats: &GcStats) {
+    assert!(stats.total_allocated_bytes > 0);
+    assert_eq!(
+        stats.total_allocated_bytes,
+        stats.live_bytes + stats.bytes_freed
+    );
+}
+
+#[test]
+fn test_runtime_records_gc_stats_after_execution() {
+    let rt = Runtime::new();
+    assert!(rt.gc_stats().is_none());
+
+    let value = rt.eval(ALLOCATING).expect("runtime.eval should succeed");
+    assert_eq!(value.as_i32(), Some(200));
+
+    let stats = rt.gc_stats().expect("execution records GC stats");
+    assert_consistent(&stats);
+}
+
+#[test]
+fn test_gc_stats_after_forced_collection() {
+    let rt = Runtime::new();
+    let program = rt.compile_program_source(ALLOCATING).expect("compiles");
+    let mut vm = Vm::new();
+    rt.execute_program_with_vm(&program, &mut vm)
+        .expect("executes");
+
+    let before = GcStats::from_vm(&vm);
+    vm.collect_garbage();
+    let after = GcStats::from_vm(&vm);
+
+    assert_consistent(&after);
+    assert_eq!(after.collections, before.collections + 1);
+    assert!(after.bytes_freed > before.bytes_freed);
+    assert!(after.live_bytes < before.live_bytes);
+    assert_eq!(after.total_allocated_bytes, before.total_allocated_bytes);
+}
@@ -7,7 +7,7 @@ use raya_engine::parser::checker::{Binder, TypeChecker, TypeSystemMode};
 use raya_engine::parser::{Interner, Parser, TypeContext};
 use raya_engine::vm::gc::header_ptr_from_value_ptr;
 use raya_engine::vm::scheduler::SchedulerLimits;
-use raya_engine::vm::{Array, Object, RayaString, Value, Vm, VmError};
+use raya_engine::vm::{Array, NativeCallPolicy, Object, RayaString, Value, Vm, VmError};
 use raya_runtime::{BuiltinMode, StdNativeHandler};
 use std::fs::{self, File, OpenOptions};
 use std::path::PathBuf;
@@ -57,7 +57,7 @@ fn extract_live_string(value: &Value, source: &str) -> String {
                 );
             }
             let raya_str = unsafe { &*value.as_ptr::<RayaString>().unwrap().as_ptr() };
-            raya_str.data.clone()
+            raya_str.as_str().to_string()
         } else {
             panic!(
                 "Failed to extract string pointer from value {:?}\nSource:\n{}",
@@ -283,13 +283,49 @@ pub(crate) fn compile_and_run_isolated(source: &str) -> E2EResult<Value> {
 ///
 /// Use this for tests that use Map, Set, Buffer, Date, Channel, Logger, etc.
 pub fn compile_and_run_with_builtins(source: &str) -> E2EResult<Value> {
+    compile_and_run_with_builtins_and_limits(source, SchedulerLimits::default())
+}
+
+/// Compile and execute with builtins included under custom scheduler limits
+#[allow(dead_code)]
+pub fn compile_and_run_with_builtins_and_limits(
+    source: &str,
+    limits: SchedulerLimits,
+) -> E2EResult<Value> {
+    run_with_builtins_vm(source, limits, None, None)
+}
+
+/// Compile and execute with builtins included under an execution timeout
+#[allow(dead_code)]
+pub fn compile_and_run_with_builtins_and_timeout(
+    source: &str,
+    timeout: Duration,
+) -> E2EResult<Value> {
+    run_with_builtins_vm(source, SchedulerLimits::default(), Some(timeout), None)
+}
+
+/// Compile and execute with builtins included under a native call policy
+#[allow(dead_code)]
+pub fn compile_and_run_with_builtins_and_policy(
+    source: &str,
+    policy: NativeCallPolicy,
+) -> E2EResult<Value> {
+    run_with_builtins_vm(source, SchedulerLimits::default(), None, Some(policy))
+}
+
+fn run_with_builtins_vm(
+    source: &str,
+    limits: SchedulerLimits,
+    timeout: Option<Duration>,
+    policy: Option<NativeCallPolicy>,
+) -> E2EResult<Value> {
     let owned = source.to_string();
     run_joined("raya-e2e-builtins", move || {
         with_harness_vm_lock(|| {
             let (runtime, program) = compile_program_with_mode(&owned, BuiltinMode::RayaStrict)?;
 
             // Use single worker with StdNativeHandler for stdlib support (logger, etc.).
-            let mut vm = Vm::with_native_handler(1, Arc::new(StdNativeHandler));
+            let mut vm = Vm::with_limits_and_handler(1, limits, Arc::new(StdNativeHandler));
 
             // Register symbolic native functions for ModuleNativeCall dispatch.
             {
@@ -297,6 +333,8 @@ pub fn compile_and_run_with_builtins(source: &str) -> E2EResult<Value> {
                 raya_stdlib::register_stdlib(&mut registry);
                 raya_stdlib_posix::register_posix(&mut registry);
             }
+            vm.set_execution_timeout(timeout);
+            vm.set_native_policy(policy);
 
             match runtime.execute_program_with_vm(&program, &mut vm) {
                 Ok(value) => {
@@ -1173,6 +1211,30 @@ fn compile_and_run_multiworker_with_timeout(
     })
 }
 
+/// Compile and execute on a single-worker VM with custom scheduler limits
+///
+/// Use this for tests that exercise per-task limits such as stack sizing.
+#[allow(dead_code)]
+pub fn compile_and_run_with_limits(source: &str, limits: SchedulerLimits) -> E2EResult<Value> {
+    let owned = source.to_string();
+    run_joined("raya-e2e-limits", move || {
+        with_harness_vm_lock(|| {
+            let (runtime, program) = compile_program_with_mode(&owned, BuiltinMode::RayaStrict)?;
+            let mut vm = Vm::with_scheduler_limits(1, limits);
+            match runtime.execute_program_with_vm(&program, &mut vm) {
+                Ok(value) => {
+                    finalize_vm_after_result(&value, vm);
+                    Ok(value)
+                }
+                Err(error) => {
+                    finalize_vm_after_error(vm);
+                    Err(map_runtime_error(error))
+                }
+            }
+        })
+    })
+}
+
 fn compile_and_run_multiworker_with_builtins_timeout(
     source: &str,
     worker_count: usize,
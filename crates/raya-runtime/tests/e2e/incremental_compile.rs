@@ -0,0 +1,96 @@
+//! E2E tests for incremental recompilation of a watched file
+
+use raya_runtime::{CompiledModule, Runtime};
+
+const ORIGINAL: &str = "\
+function double(n: number): number { return n * 2; }
+function triple(n: number): number { return n * 3; }
+function quadruple(n: number): number { return n * 4; }
+return double(1) + triple(1) + quadruple(1);
+";
+
+/// Bytecode of every function, by name.
+fn function_codes(compiled: &CompiledModule) -> Vec<(String, Vec<u8>)> {
+    compiled
+        .module()
+        .functions
+        .iter()
+        .map(|function| (function.name.clone(), function.code.clone()))
+        .collect()
+}
+
+fn numeric_result(rt: &Runtime, compiled: &CompiledModule) -> f64 {
+    let value = rt.execute(compiled).expect("module runs");
+    value
+        .as_i32()
+        .map(f64::from)
+        .or_else(|| value.as_f64())
+        .expect("numeric result")
+}
+
+#[test]
+fn test_compile_incremental_relowers_only_the_edited_function() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("main.raya");
+    std::fs::write(&path, ORIGINAL).unwrap();
+
+    let rt = Runtime::new();
+    let initial = rt.compile_file(&path).expect("initial compile");
+    assert_eq!(
+        initial.relowered_functions(),
+        Some(&["double", "triple", "quadruple"].map(String::from)[..])
+    );
+
+    std::fs::write(&path, ORIGINAL.replace("n * 3", "n + n + n")).unwrap();
+    let second = rt
+        .compile_incremental(&path, &initial)
+        .expect("recompile after edit");
+    assert_eq!(
+        second.relowered_functions(),
+        Some(&["triple".to_string()][..])
+    );
+
+    // Reused functions must produce what a full compile produces.
+    let fresh = rt.compile_file(&path).expect("full compile after edit");
+    assert_eq!(function_codes(&second), function_codes(&fresh));
+    assert_eq!(numeric_result(&rt, &second), 9.0);
+}
+
+#[test]
+fn test_compile_incremental_reuses_moved_functions() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("main.raya");
+    std::fs::write(&path, ORIGINAL).unwrap();
+
+    let rt = Runtime::new();
+    let first = rt.compile_file(&path).expect("initial compile");
+
+    // Blank lines and comments shift every declaration without changing it.
+    std::fs::write(&path, format!("// header\n\n{ORIGINAL}// touched\n")).unwrap();
+    let second = rt
+        .compile_incremental(&path, &first)
+        .expect("incremental compile of moved functions");
+    assert_eq!(second.relowered_functions(), Some(&[][..]));
+    assert_eq!(function_codes(&first), function_codes(&second));
+    assert_eq!(numeric_result(&rt, &second), 9.0);
+}
+
+#[test]
+fn test_compile_incremental_relowers_everything_when_a_signature_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("main.raya");
+    std::fs::write(&path, ORIGINAL).unwrap();
+
+    let rt = Runtime::new();
+    let first = rt.compile_file(&path).expect("initial compile");
+
+    let edited = ORIGINAL
+        .replace("triple(n: number)", "triple(n: number, m: number = 3)")
+        .replace("n * 3", "n * m");
+    std::fs::write(&path, edited).unwrap();
+    let second = rt
+        .compile_incremental(&path, &first)
+        .expect("recompile after signature change");
+    assert_eq!(second.relowered_functions().map(<[String]>::len), Some(3));
+    assert_eq!(numeric_result(&rt, &second), 9.0);
+}
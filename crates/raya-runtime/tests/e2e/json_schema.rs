@@ -0,0 +1,183 @@
+//! E2E tests for the JSON decode schemas emitted for `//@@json` types
+
+use super::harness::*;
+use raya_engine::compiler::Module;
+use raya_engine::vm::json::{TypeKind, TypeSchema};
+use raya_runtime::Runtime;
+
+const SOURCE: &str = "\
+//@@json
+class Address {
+    city: string;
+    zip: number;
+}
+
+//@@json
+class User {
+    //@@json user_name
+    name: string;
+    age: int;
+    active: boolean;
+    tags: string[];
+    address: Address;
+    nickname: string | null;
+    //@@json -
+    password: string;
+}
+
+return 0;
+";
+
+fn compile_module(source: &str) -> Module {
+    Runtime::new()
+        .compile(source)
+        .expect("compile should succeed")
+        .module()
+        .clone()
+}
+
+/// Schema of the class named `name`, as `(key, field kind)` pairs
+fn class_fields(module: &Module, name: &str) -> Vec<(String, TypeKind)> {
+    let class_index = module
+        .classes
+        .iter()
+        .position(|class| class.name == name)
+        .expect("class compiled");
+    let schema = module
+        .json_schemas
+        .iter()
+        .find(|schema| {
+            matches!(
+                schema.kind,
+                TypeKind::Interface { nominal_type_id: Some(id), .. } if id == class_index
+            )
+        })
+        .expect("class schema emitted");
+    let TypeKind::Interface { fields, .. } = &schema.kind else {
+        unreachable!()
+    };
+    fields
+        .iter()
+        .map(|(key, type_id)| (key.clone(), kind(module, *type_id)))
+        .collect()
+}
+
+fn kind(module: &Module, type_id: usize) -> TypeKind {
+    let TypeSchema { kind, .. } = &module.json_schemas[type_id];
+    kind.clone()
+}
+
+#[test]
+fn test_json_class_schema_uses_declared_field_types() {
+    let module = compile_module(SOURCE);
+    let fields = class_fields(&module, "User");
+    let keys: Vec<&str> = fields.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(
+        keys,
+        [
+            "user_name",
+            "age",
+            "active",
+            "tags",
+            "address",
+            "nickname",
+            "password"
+        ]
+    );
+    assert_eq!(fields[0].1, TypeKind::String);
+    assert_eq!(fields[1].1, TypeKind::Number);
+    assert_eq!(fields[2].1, TypeKind::Bool);
+    let TypeKind::Array { element_type_id } = fields[3].1 else {
+        panic!("expected an array, got {:?}", fields[3].1);
+    };
+    assert_eq!(kind(&module, element_type_id), TypeKind::String);
+    assert!(matches!(
+        fields[4].1,
+        TypeKind::Interface {
+            nominal_type_id: Some(_),
+            ..
+        }
+    ));
+    let variant_type_ids = match &fields[5].1 {
+        TypeKind::Union {
+            variant_type_ids, ..
+        } => variant_type_ids,
+        other => panic!("expected a union, got {other:?}"),
+    };
+    let variants: Vec<TypeKind> = variant_type_ids
+        .iter()
+        .map(|&type_id| kind(&module, type_id))
+        .collect();
+    assert!(variants.contains(&TypeKind::String));
+    assert!(variants.contains(&TypeKind::Null));
+    assert_eq!(fields[6].1, TypeKind::Skipped);
+}
+
+#[test]
+fn test_json_schema_accepts_any_value_for_types_without_a_schema() {
+    let module = compile_module(
+        "\
+class Plain {
+    x: number;
+}
+
+//@@json
+class Circle {
+    kind: \"circle\";
+    data: json;
+    scores: Map<string, number>;
+    plain: Plain;
+}
+
+return 0;
+",
+    );
+    let fields = class_fields(&module, "Circle");
+    for (key, kind) in fields {
+        assert_eq!(kind, TypeKind::Any, "field {key}");
+    }
+}
+
+const CAST_SOURCE: &str = r#"
+//@@json
+class Address {
+    city: string;
+    zip: number;
+}
+
+//@@json
+class User {
+    //@@json user_name
+    name: string;
+    age: number;
+    address: Address;
+    //@@json -
+    password: string | null;
+}
+
+const user = JSON.parse('INPUT') as User;
+return user.age + user.address.zip;
+"#;
+
+const VALID_USER: &str =
+    r#"{"user_name": "ada", "age": 36, "address": {"city": "Oslo", "zip": 150}}"#;
+
+#[test]
+fn test_json_class_cast_decodes_through_the_schema() {
+    expect_f64(&CAST_SOURCE.replace("INPUT", VALID_USER), 186.0);
+}
+
+#[test]
+fn test_json_class_cast_rejects_wrong_field_type() {
+    let wrong_age = VALID_USER.replace(r#""age": 36"#, r#""age": "36""#);
+    expect_runtime_error(
+        &CAST_SOURCE.replace("INPUT", &wrong_age),
+        "Expected number, got string",
+    );
+
+    let wrong_zip = VALID_USER.replace(r#""zip": 150"#, r#""zip": false"#);
+    expect_runtime_error(
+        &CAST_SOURCE.replace("INPUT", &wrong_zip),
+        "Expected number, got boolean",
+    );
+}
@@ -8,6 +8,7 @@ mod args;
 mod arrays;
 mod async_await;
 mod builtins;
+mod cancellation;
 mod classes;
 mod closure_captures;
 mod closures;
@@ -19,34 +20,42 @@ mod crypto;
 mod decorators;
 mod dns;
 mod edge_cases;
+mod eval_bindings;
 mod encoding;
 mod env;
 mod exceptions;
 mod fetch;
+mod field_inline_cache;
 mod fs;
 mod functions;
 mod fundamentals;
+mod gc_stats;
 mod glob;
 mod hardening;
 mod harness;
 mod http;
+mod incremental_compile;
 mod inheritance;
 mod io;
 mod js_syntax_conformance;
 mod json;
+mod json_schema;
 #[cfg(feature = "jit")]
 mod jit_runtime;
 mod literals;
 mod logger;
 mod loops;
 mod math;
+mod module_init;
 mod module_vars;
 mod narrowing;
+mod native_policy;
 mod net;
 mod node_stdlib;
 mod operators;
 mod os;
 mod path;
+mod permissions;
 mod process;
 mod reflect;
 mod rest_params;
@@ -61,6 +70,7 @@ mod template;
 mod time;
 mod type_checker;
 mod url;
+mod using_declarations;
 mod variables;
 
 // TypeScript conformance test adaptations
@@ -0,0 +1,190 @@
+//! E2E tests for lazy (deferred) module initialization
+
+use raya_runtime::{Runtime, RuntimeOptions};
+use std::path::Path;
+
+/// Counts how many times `setup.raya` has run its top-level code.
+const FLAG_MODULE: &str = r#"
+let count = 0;
+export function markInitialized(): void { count = count + 1; }
+export function initCount(): number { return count; }
+"#;
+
+/// Top-level code marks the flag; `double` is the only export.
+const SETUP_MODULE: &str = r#"
+import { markInitialized } from "./flag";
+markInitialized();
+const factor = 2;
+export function double(x: number): number { return x * factor; }
+"#;
+
+fn write_project(dir: &Path, main: &str) -> std::path::PathBuf {
+    std::fs::write(dir.join("flag.raya"), FLAG_MODULE).unwrap();
+    std::fs::write(dir.join("setup.raya"), SETUP_MODULE).unwrap();
+    let main_path = dir.join("main.raya");
+    std::fs::write(&main_path, main).unwrap();
+    main_path
+}
+
+fn run_number(runtime: &Runtime, main_path: &Path) -> f64 {
+    let program = runtime
+        .compile_program_file(main_path)
+        .expect("program compiles");
+    let value = runtime.execute_program(&program).expect("program runs");
+    value
+        .as_i32()
+        .map(f64::from)
+        .or_else(|| value.as_f64())
+        .expect("numeric result")
+}
+
+const FIRST_USE_MAIN: &str = r#"
+import { initCount } from "./flag";
+import { double } from "./setup";
+const before = initCount();
+const first = double(5);
+const second = double(6);
+return before * 1000 + initCount() * 100 + first + second;
+"#;
+
+#[test]
+fn test_module_init_deferred_until_first_export_use() {
+    let dir = tempfile::tempdir().unwrap();
+    let main_path = write_project(dir.path(), FIRST_USE_MAIN);
+
+    // Not initialized before `double` is called, then initialized exactly once.
+    assert_eq!(run_number(&Runtime::new(), &main_path), 122.0);
+}
+
+#[test]
+fn test_eager_module_opt_out_initializes_at_load() {
+    let dir = tempfile::tempdir().unwrap();
+    let main_path = write_project(dir.path(), FIRST_USE_MAIN);
+
+    let eager = Runtime::with_options(RuntimeOptions {
+        eager_modules: vec![dir.path().join("setup.raya").to_string_lossy().to_string()],
+        ..Default::default()
+    });
+    assert_eq!(run_number(&eager, &main_path), 1122.0);
+
+    let no_lazy = Runtime::with_options(RuntimeOptions {
+        lazy_module_init: false,
+        ..Default::default()
+    });
+    assert_eq!(run_number(&no_lazy, &main_path), 1122.0);
+}
+
+#[test]
+fn test_manifest_eager_flag_opts_module_out() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("raya.toml"),
+        "[package]\nname = \"app\"\nversion = \"1.0.0\"\n\n[init]\neager = [\"setup.raya\"]\n",
+    )
+    .unwrap();
+    let main_path = write_project(
+        dir.path(),
+        r#"
+import { initCount } from "./flag";
+import { double } from "./setup";
+if (initCount() != 1) {
+    throw new Error("setup.raya should initialize at load");
+}
+double(1);
+"#,
+    );
+
+    let exit_code = Runtime::new().run_file(&main_path).expect("runs");
+    assert_eq!(exit_code, 0);
+}
+
+#[test]
+fn test_concurrent_first_use_waits_for_module_init() {
+    let dir = tempfile::tempdir().unwrap();
+    // The init suspends mid-way, so the second task reaches `value` while the
+    // first is still running it.
+    std::fs::write(
+        dir.path().join("slow.raya"),
+        r#"
+let ready = 0;
+sleep(20);
+ready = 1;
+export function value(): number { return ready * 21; }
+"#,
+    )
+    .unwrap();
+    let main_path = dir.path().join("main.raya");
+    std::fs::write(
+        &main_path,
+        r#"
+import { value } from "./slow";
+async function read(): Promise<number> { return value(); }
+const a = read();
+const b = read();
+return (await a) * 100 + (await b);
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(run_number(&Runtime::new(), &main_path), 2121.0);
+}
+
+#[test]
+fn test_value_import_cycle_is_rejected_with_both_modules() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("a.raya"),
+        r#"
+import { b } from "./b";
+export const a: number = 1;
+return a + b;
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("b.raya"),
+        r#"
+import { a } from "./a";
+export const b: number = a + 1;
+"#,
+    )
+    .unwrap();
+
+    let error = match Runtime::new().compile_program_file(&dir.path().join("a.raya")) {
+        Ok(_) => panic!("value cycle should be rejected"),
+        Err(error) => error.to_string(),
+    };
+    assert!(error.contains("Circular dependency:"), "{error}");
+    assert!(
+        error.contains("a.raya -> b.raya -> a.raya")
+            || error.contains("b.raya -> a.raya -> b.raya"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_type_only_import_cycle_runs() {
+    let dir = tempfile::tempdir().unwrap();
+    // `shape` only takes the `Point` alias back from `main`, so nothing in
+    // the cycle depends on initialization order.
+    std::fs::write(
+        dir.path().join("shape.raya"),
+        r#"
+import { Point } from "./main";
+export function area(p: Point): number { return p.x * p.y; }
+"#,
+    )
+    .unwrap();
+    let main_path = dir.path().join("main.raya");
+    std::fs::write(
+        &main_path,
+        r#"
+import { area } from "./shape";
+export type Point = { x: number; y: number };
+return area({ x: 6, y: 7 });
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(run_number(&Runtime::new(), &main_path), 42.0);
+}
@@ -0,0 +1,92 @@
+//! E2E tests for native call policies (sandboxing native access)
+
+use super::harness::*;
+use raya_engine::vm::{NativeCallDecision, NativeCallPolicy, Value, VmError};
+use std::sync::{Arc, Mutex};
+
+const READ_TEMP_FILE: &str = r#"
+    import fs from "std:fs";
+    let sum = 0;
+    for (let i = 1; i <= 10; i = i + 1) {
+        sum = sum + i;
+    }
+    const fp: string = fs.tempFile("raya_test_policy_");
+    fs.writeTextFile(fp, "hello raya");
+    const txt: string = fs.readTextFile(fp);
+    fs.remove(fp);
+    return sum * 100 + txt.length;
+"#;
+
+/// Numeric result as f64 (numbers may come back as i32 or f64)
+fn number(value: Value) -> f64 {
+    value
+        .as_i32()
+        .map(f64::from)
+        .or_else(|| value.as_f64())
+        .expect("numeric result")
+}
+
+#[test]
+fn test_no_fs_policy_denies_fs_with_catchable_security_error() {
+    let result = compile_and_run_with_builtins_and_policy(
+        r#"
+        import fs from "std:fs";
+        let sum = 0;
+        for (let i = 1; i <= 10; i = i + 1) {
+            sum = sum + i;
+        }
+        let denied = 0;
+        try {
+            fs.readTextFile("raya_policy_probe.txt");
+        } catch (e) {
+            if (String(e).startsWith("Security error")) {
+                denied = 1;
+            }
+        }
+        return sum * 10 + denied;
+    "#,
+        NativeCallPolicy::no_fs(),
+    )
+    .expect("pure computation should still run");
+    assert_eq!(number(result), 551.0);
+}
+
+#[test]
+fn test_no_fs_policy_uncaught_denial_is_security_error() {
+    let error = compile_and_run_with_builtins_and_policy(READ_TEMP_FILE, NativeCallPolicy::no_fs())
+        .expect_err("fs access should be denied");
+    match error {
+        E2EError::Vm(vm_error) => assert!(
+            matches!(vm_error.root(), VmError::SecurityError(_)),
+            "expected a security error, got {}",
+            vm_error
+        ),
+        other => panic!("expected a VM error, got {}", other),
+    }
+}
+
+#[test]
+fn test_allow_all_policy_is_transparent() {
+    let unrestricted = compile_and_run_with_builtins(READ_TEMP_FILE).expect("runs unrestricted");
+    let allowed =
+        compile_and_run_with_builtins_and_policy(READ_TEMP_FILE, NativeCallPolicy::allow_all())
+            .expect("runs under allow-all");
+    assert_eq!(number(unrestricted), 5510.0);
+    assert_eq!(number(allowed), number(unrestricted));
+}
+
+#[test]
+fn test_custom_policy_sees_native_names() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorder = seen.clone();
+    let policy = NativeCallPolicy::new(move |call| {
+        recorder.lock().unwrap().push(call.name.to_string());
+        NativeCallDecision::Allow
+    });
+    let result = compile_and_run_with_builtins_and_policy(READ_TEMP_FILE, policy)
+        .expect("runs under a recording policy");
+    assert_eq!(number(result), 5510.0);
+    let seen = seen.lock().unwrap();
+    assert!(seen.iter().any(|name| name == "fs.readTextFile"));
+    assert!(seen.iter().any(|name| name == "fs.writeTextFile"));
+}
@@ -2,7 +2,12 @@
 //!
 //! Covers TCP listener/stream round-trips and UDP send/receive helpers.
 
-use super::harness::expect_bool_with_builtins;
+use super::harness::{
+    compile_and_run_with_builtins_and_limits, compile_and_run_with_builtins_and_timeout,
+    expect_bool_with_builtins,
+};
+use raya_engine::vm::scheduler::SchedulerLimits;
+use std::time::{Duration, Instant};
 
 #[test]
 fn test_net_tcp_echo_round_trip() {
@@ -124,3 +129,82 @@ fn test_net_udp_send_receive() {
         true,
     );
 }
+
+#[test]
+fn test_net_socket_limit_rejects_extra_open() {
+    let limits = SchedulerLimits {
+        max_open_sockets: Some(2),
+        ..Default::default()
+    };
+
+    // Opening past the limit throws a catchable error; closing frees a slot.
+    let value = compile_and_run_with_builtins_and_limits(
+        r#"
+        import net from "std:net";
+
+        function main(): boolean {
+            const first = net.bindUdp("localhost", 0);
+            const second = net.bindUdp("localhost", 0);
+            let rejected = false;
+            try {
+                net.bindUdp("localhost", 0);
+            } catch (_e) {
+                rejected = true;
+            }
+            first.close();
+            const reopened = net.bindUdp("localhost", 0);
+            reopened.close();
+            second.close();
+            return rejected;
+        }
+
+        return main();
+        "#,
+        limits.clone(),
+    )
+    .expect("socket limit program should run");
+    assert_eq!(value.as_bool(), Some(true));
+
+    let error = compile_and_run_with_builtins_and_limits(
+        r#"
+        import net from "std:net";
+
+        const first = net.bindUdp("localhost", 0);
+        const second = net.bindUdp("localhost", 0);
+        net.bindUdp("localhost", 0);
+        return true;
+        "#,
+        limits,
+    )
+    .expect_err("third socket should exceed the limit");
+    assert!(
+        error.to_string().contains("fd limit exceeded"),
+        "got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_net_execution_timeout_interrupts_blocked_receive() {
+    // Nothing is ever sent to the socket, so only the timeout can end the read.
+    let started = Instant::now();
+    let error = compile_and_run_with_builtins_and_timeout(
+        r#"
+        import net from "std:net";
+
+        const socket = net.bindUdp("localhost", 0);
+        socket.receive(64);
+        return true;
+        "#,
+        Duration::from_millis(200),
+    )
+    .expect_err("blocked receive should time out");
+    let elapsed = started.elapsed();
+
+    assert!(error.to_string().contains("timed out"), "got: {}", error);
+    assert!(
+        elapsed < Duration::from_secs(5),
+        "timeout took {:?} to abort the blocked receive",
+        elapsed
+    );
+}
@@ -0,0 +1,184 @@
+//! E2E tests for `[permissions]` capability grants from raya.toml
+
+use super::harness::*;
+use raya_engine::vm::{NativeCallPolicy, Value, VmError};
+use raya_runtime::permissions::policy_from_permissions;
+use raya_runtime::Runtime;
+
+/// Numeric result as f64 (numbers may come back as i32 or f64)
+fn number(value: Value) -> f64 {
+    value
+        .as_i32()
+        .map(f64::from)
+        .or_else(|| value.as_f64())
+        .expect("numeric result")
+}
+
+fn policy_from_manifest(permissions: &str) -> NativeCallPolicy {
+    let manifest = raya_pm::PackageManifest::from_str(&format!(
+        "[package]\nname = \"app\"\nversion = \"1.0.0\"\n\n[permissions]\n{}",
+        permissions
+    ))
+    .expect("valid manifest");
+    policy_from_permissions(&manifest.permissions.expect("permissions section"))
+}
+
+#[test]
+fn test_fs_read_permission_allows_read_and_denies_write() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.txt");
+    std::fs::write(&path, "hello raya").unwrap();
+
+    let source = r#"
+        import fs from "std:fs";
+        const txt: string = fs.readTextFile("__PATH__");
+        let denied = 0;
+        try {
+            fs.writeTextFile("__PATH__", "overwritten");
+        } catch (e) {
+            if (String(e).includes("missing permission fs:write")) {
+                denied = 1;
+            }
+        }
+        return txt.length * 10 + denied;
+    "#
+    .replace("__PATH__", &path.to_string_lossy());
+
+    let result =
+        compile_and_run_with_builtins_and_policy(&source, policy_from_manifest("fs = [\"read\"]"))
+            .expect("reads are granted");
+    assert_eq!(number(result), 101.0);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "hello raya");
+}
+
+#[test]
+fn test_net_connect_to_ungranted_host_is_denied() {
+    let error = compile_and_run_with_builtins_and_policy(
+        r#"
+        import net from "std:net";
+        const stream = net.connect("evil.example.com", 443);
+        stream.close();
+        return 1;
+    "#,
+        policy_from_manifest("net = [\"connect:api.example.com\"]"),
+    )
+    .expect_err("connect to an ungranted host should be denied");
+    match error {
+        E2EError::Vm(vm_error) => {
+            assert!(
+                matches!(vm_error.root(), VmError::SecurityError(_)),
+                "expected a security error, got {}",
+                vm_error
+            );
+            assert!(
+                vm_error
+                    .to_string()
+                    .contains("missing permission net:connect:evil.example.com"),
+                "error should name the missing permission, got {}",
+                vm_error
+            );
+        }
+        other => panic!("expected a VM error, got {}", other),
+    }
+}
+
+#[test]
+fn test_run_file_enforces_manifest_permissions() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("raya.toml"),
+        "[package]\nname = \"app\"\nversion = \"1.0.0\"\n\n[permissions]\nfs = [\"read\"]\n",
+    )
+    .unwrap();
+    let output = dir.path().join("out.txt");
+    let main = dir.path().join("main.raya");
+    std::fs::write(
+        &main,
+        format!(
+            "import fs from \"std:fs\";\nfs.writeTextFile(\"{}\", \"written\");\n",
+            output.to_string_lossy()
+        ),
+    )
+    .unwrap();
+
+    let exit_code = Runtime::new().run_file(&main).expect("runs");
+    assert_eq!(exit_code, 1);
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_subprocess_needs_process_exec_permission() {
+    let source = r#"
+        import process from "std:process";
+        let denied = 0;
+        try {
+            process.exec("echo hi");
+        } catch (e) {
+            if (String(e).includes("missing permission process:exec")) {
+                denied = 1;
+            }
+        }
+        try {
+            process.spawn("echo hi");
+        } catch (e) {
+            if (String(e).includes("missing permission process:exec")) {
+                denied = denied + 1;
+            }
+        }
+        return denied;
+    "#;
+    let result =
+        compile_and_run_with_builtins_and_policy(source, policy_from_manifest("fs = [\"read\"]"))
+            .expect("denials are catchable");
+    assert_eq!(number(result), 2.0);
+
+    let granted = compile_and_run_with_builtins_and_policy(
+        r#"
+        import process from "std:process";
+        const handle = process.exec("echo hi");
+        const code = process.execGetCode(handle);
+        process.execRelease(handle);
+        return code;
+    "#,
+        policy_from_manifest("process = [\"exec\"]"),
+    )
+    .expect("exec is granted");
+    assert_eq!(number(granted), 0.0);
+}
+
+#[test]
+fn test_dependency_manifest_cannot_widen_root_permissions() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("raya.toml"),
+        "[package]\nname = \"app\"\nversion = \"1.0.0\"\n\n[permissions]\nfs = [\"read\"]\n",
+    )
+    .unwrap();
+    // A vendored package asking for more than the application grants.
+    let vendor = dir.path().join("vendor").join("writer");
+    std::fs::create_dir_all(&vendor).unwrap();
+    std::fs::write(
+        vendor.join("raya.toml"),
+        "[package]\nname = \"writer\"\nversion = \"1.0.0\"\n\n[permissions]\nfs = [\"read\", \"write\"]\n",
+    )
+    .unwrap();
+    let output = dir.path().join("out.txt");
+    std::fs::write(
+        vendor.join("writer.raya"),
+        format!(
+            "import fs from \"std:fs\";\nexport function write(): void {{\n    fs.writeTextFile(\"{}\", \"written\");\n}}\n",
+            output.to_string_lossy()
+        ),
+    )
+    .unwrap();
+    let main = dir.path().join("main.raya");
+    std::fs::write(
+        &main,
+        "import { write } from \"./vendor/writer/writer.raya\";\nwrite();\n",
+    )
+    .unwrap();
+
+    let exit_code = Runtime::new().run_file(&main).expect("runs");
+    assert_eq!(exit_code, 1);
+    assert!(!output.exists());
+}
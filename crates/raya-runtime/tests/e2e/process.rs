@@ -68,13 +68,36 @@ fn test_process_run() {
 
 #[test]
 fn test_process_argv() {
-    // argv should return an array (possibly empty in test context)
-    compile_and_run_with_builtins(
+    // No program arguments were passed, so argv is empty
+    expect_i32_with_builtins(
         r#"
         import process from "std:process";
         const argvValues: string[] = process.argv();
+        return argvValues.length;
+    "#,
+        0,
+    );
+}
+
+#[test]
+fn test_process_on_exit_hooks_run_after_main_in_lifo_order() {
+    let sentinel = std::env::temp_dir().join(format!("raya_on_exit_{}.txt", std::process::id()));
+    let _ = std::fs::remove_file(&sentinel);
+    let source = format!(
+        r#"
+        import fs from "std:fs";
+        import process from "std:process";
+        const sentinel: string = "{}";
+        process.onExit(() => {{ fs.appendFile(sentinel, "first;"); }});
+        process.onExit(() => {{ fs.appendFile(sentinel, "second;"); }});
+        fs.writeTextFile(sentinel, "main;");
         return 1;
     "#,
-    )
-    .unwrap();
+        sentinel.to_string_lossy().replace('\\', "/")
+    );
+    compile_and_run_with_builtins(&source).unwrap();
+
+    let written = std::fs::read_to_string(&sentinel).expect("exit hooks should write sentinel");
+    let _ = std::fs::remove_file(&sentinel);
+    assert_eq!(written, "main;second;first;");
 }
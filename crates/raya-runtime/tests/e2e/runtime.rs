@@ -7,6 +7,8 @@ use super::harness::{
     compile_and_run_with_builtins, expect_bool_with_builtins, expect_i32_with_builtins,
     expect_string_contains_with_builtins,
 };
+use raya_engine::compiler::verify_module;
+use raya_runtime::Runtime;
 
 // ============================================================================
 // Compiler.compile + Compiler.execute
@@ -247,6 +249,73 @@ fn test_bytecode_validate() {
     );
 }
 
+#[test]
+fn test_verify_accepts_compiled_control_flow() {
+    // Every block join in real compiler output must see one stack depth
+    let source = r#"
+        function classify(n: number): string {
+            return n > 10 ? "big" : n > 0 ? "small" : "none";
+        }
+
+        function makeCounter(start: number): () => number {
+            let count = start;
+            return (): number => {
+                count = count + 1;
+                return count;
+            };
+        }
+
+        function guarded(n: number): number {
+            let result = 0;
+            try {
+                if (n < 0) {
+                    throw new Error("negative");
+                }
+                result = n;
+            } catch (e) {
+                result = -1;
+            } finally {
+                result = result + 1;
+            }
+            try {
+                result = result * 2;
+            } finally {
+                result = result + 1;
+            }
+            return result;
+        }
+
+        let total = 0;
+        for (let i = 0; i < 5; i = i + 1) {
+            if (i == 3) {
+                continue;
+            }
+            total = total + (i % 2 == 0 ? i : -i);
+        }
+        let j = 0;
+        while (true) {
+            j = j + 1;
+            if (j > 3) {
+                break;
+            }
+        }
+        let words: string[] = ["a", "bb"];
+        for (const word of words) {
+            total = total + word.length;
+        }
+        let pair: [number, string] = [total, classify(total)];
+        let counter = makeCounter(pair[0]);
+        counter();
+        return guarded(counter()) + j + pair[1].length;
+    "#;
+    let compiled = Runtime::new()
+        .compile(source)
+        .expect("compile should succeed");
+    if let Err(e) = verify_module(compiled.module()) {
+        panic!("compiled module failed verification: {e}");
+    }
+}
+
 #[test]
 fn test_bytecode_disassemble() {
     // disassemble should return a string containing bytecode listing
@@ -787,6 +856,27 @@ fn test_vm_gc_stats_non_negative() {
     );
 }
 
+#[test]
+fn test_vm_memory_usage_reflects_allocations() {
+    // heapUsed grows while freshly allocated data is kept alive
+    expect_bool_with_builtins(
+        r#"
+        import vm, { Vm } from "std:runtime";
+        let before = vm.memoryUsage();
+        let kept: number[][] = [];
+        for (let i = 0; i < 1000; i = i + 1) {
+            kept.push([i, i + 1, i + 2, i + 3]);
+        }
+        let after = vm.memoryUsage();
+        return kept.length == 1000
+            && after.heapUsed > before.heapUsed
+            && after.heapTotal >= after.heapUsed
+            && after.gcCount >= before.gcCount;
+    "#,
+        true,
+    );
+}
+
 #[test]
 fn test_vm_gc_collect_no_error() {
     // gcCollect() should not error; return a value after calling it
@@ -75,6 +75,42 @@ fn test_string_inequality() {
     expect_bool("return \"abc\" != \"def\";", true);
 }
 
+#[test]
+fn test_string_switch_matches_literal_and_built_tags() {
+    expect_i32(
+        r#"
+        function code(tag: string): int {
+            switch (tag) {
+                case "user.created": return 1;
+                case "user.updated": return 2;
+                case "user.deleted": return 3;
+            }
+            return 0;
+        }
+        // Built at run time from parameters and a loop, so they are not
+        // constant-folded into interned literals.
+        function tag(scope: string, action: string): string {
+            return scope + "." + action;
+        }
+        let actions: string[] = ["created", "updated", "deleted"];
+        let built = tag("user", actions[1]);
+        let word = "user.deleted";
+        let looped = "";
+        for (let i = 0; i < word.length; i++) {
+            looped = looped + word.charAt(i);
+        }
+        let other = "user.";
+        for (let i = 0; i < actions.length; i++) {
+            other = other + actions[i].charAt(0);
+        }
+        let same = built == "user.updated" ? 1 : 0;
+        return code("user.created") * 10000 + code(built) * 1000 + code(looped) * 100
+            + code(other) * 10 + same;
+        "#,
+        12301,
+    );
+}
+
 #[test]
 fn test_string_less_than() {
     expect_bool("return \"abc\" < \"abd\";", true);
@@ -525,3 +525,46 @@ fn test_structural_instanceof_alias_returns_false_when_slot_missing() {
         0,
     );
 }
+
+#[test]
+fn test_structural_field_access_monomorphic_loop() {
+    // One access site sees the same reordered layout on every iteration,
+    // so after the first access the slot comes from the site's inline cache.
+    expect_i32(
+        "type Point = {
+             x: number;
+             y: number;
+         };
+         function readX(p: Point): number { return p.x; }
+         function readY(p: Point): number { return p.y; }
+         let p: Point = { y: 2, x: 1 };
+         let total = 0;
+         for (let i = 0; i < 100; i = i + 1) {
+             total = total + readX(p) * 10 + readY(p);
+         }
+         return total;",
+        1200,
+    );
+}
+
+#[test]
+fn test_structural_field_access_site_survives_layout_changes() {
+    expect_i32(
+        "type Point = {
+             x: number;
+             y: number;
+         };
+         function bump(p: Point): number {
+             p.x = p.x + 1;
+             return p.x * 10 + p.y;
+         }
+         let a: Point = { x: 1, y: 2 };
+         let b: Point = { y: 4, x: 3 };
+         let total = 0;
+         for (let i = 0; i < 4; i = i + 1) {
+             total = total + bump(a) + bump(a) + bump(b);
+         }
+         return total + a.x * 1000 + b.x * 100000;",
+        709692,
+    );
+}
@@ -0,0 +1,133 @@
+//! Tests for `using` declarations (scoped resource disposal)
+//!
+//! A `using` binding's `dispose()` method runs when its block exits.
+
+use super::harness::*;
+
+const RESOURCE: &str = "
+class Resource {
+    log: number[];
+    id: number;
+    constructor(log: number[], id: number) {
+        this.log = log;
+        this.id = id;
+    }
+    dispose(): void {
+        this.log.push(this.id);
+    }
+}
+let log: number[] = [];
+";
+
+fn with_resource(body: &str) -> String {
+    format!("{}{}", RESOURCE, body)
+}
+
+#[test]
+fn test_using_disposed_at_scope_end() {
+    expect_i32(
+        &with_resource(
+            "function work(): void {
+                 using r = new Resource(log, 1);
+                 log.push(0);
+             }
+             work();
+             return log.length * 10 + log[1];",
+        ),
+        21,
+    );
+}
+
+#[test]
+fn test_using_disposed_at_nested_block_end() {
+    expect_i32(
+        &with_resource(
+            "function work(): number {
+                 if (log.length == 0) {
+                     using r = new Resource(log, 4);
+                     log.push(0);
+                 }
+                 log.push(9);
+                 return log[0] * 100 + log[1] * 10 + log[2];
+             }
+             return work();",
+        ),
+        49,
+    );
+}
+
+#[test]
+fn test_using_disposed_on_early_return() {
+    expect_i32(
+        &with_resource(
+            "function find(early: boolean): number {
+                 using r = new Resource(log, 7);
+                 if (early) {
+                     return 1;
+                 }
+                 log.push(0);
+                 return 2;
+             }
+             let a = find(true);
+             let b = find(false);
+             return a * 1000 + b * 100 + log.length * 10 + log[0];",
+        ),
+        1237,
+    );
+}
+
+#[test]
+fn test_using_disposed_in_reverse_declaration_order() {
+    expect_i32(
+        &with_resource(
+            "function work(): void {
+                 using a = new Resource(log, 1);
+                 using b = new Resource(log, 2);
+                 using c = new Resource(log, 3);
+                 log.push(0);
+             }
+             work();
+             return log[0] * 1000 + log[1] * 100 + log[2] * 10 + log[3];",
+        ),
+        321,
+    );
+}
+
+#[test]
+fn test_using_disposed_on_throw_and_rethrows() {
+    expect_i32(
+        &with_resource(
+            "function fail(): void {
+                 using r = new Resource(log, 5);
+                 throw 'boom';
+             }
+             let caught = 0;
+             try {
+                 fail();
+             } catch (e) {
+                 caught = 1;
+             }
+             return caught * 100 + log.length * 10 + log[0];",
+        ),
+        115,
+    );
+}
+
+#[test]
+fn test_using_disposed_on_break() {
+    expect_i32(
+        &with_resource(
+            "function work(): number {
+                 for (let i = 0; i < 3; i = i + 1) {
+                     using r = new Resource(log, i + 1);
+                     if (i == 1) {
+                         break;
+                     }
+                 }
+                 return log.length * 100 + log[0] * 10 + log[1];
+             }
+             return work();",
+        ),
+        212,
+    );
+}
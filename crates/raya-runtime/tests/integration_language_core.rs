@@ -13,10 +13,14 @@ mod conditionals;
 mod decorators;
 #[path = "e2e/edge_cases.rs"]
 mod edge_cases;
+#[path = "e2e/eval_bindings.rs"]
+mod eval_bindings;
 #[path = "e2e/functions.rs"]
 mod functions;
 #[path = "e2e/fundamentals.rs"]
 mod fundamentals;
+#[path = "e2e/incremental_compile.rs"]
+mod incremental_compile;
 #[path = "e2e/js_syntax_conformance.rs"]
 mod js_syntax_conformance;
 #[path = "e2e/literals.rs"]
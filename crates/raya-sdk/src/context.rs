@@ -86,6 +86,15 @@ pub trait NativeContext {
     /// Get object's class ID
     fn object_nominal_type_id(&self, val: NativeValue) -> AbiResult<usize>;
 
+    /// Get an object's named fields and their values, in layout order.
+    ///
+    /// Fails for values that are not objects (including strings and arrays).
+    /// The default implementation does not support field inspection.
+    fn object_entries(&self, val: NativeValue) -> AbiResult<Vec<(String, NativeValue)>> {
+        let _ = val;
+        Err("object field inspection is not supported by this host".into())
+    }
+
     // ========================================================================
     // Class Operations
     // ========================================================================
@@ -158,4 +167,106 @@ pub trait NativeContext {
 
     /// Check if a channel is closed
     fn channel_is_closed(&self, channel: NativeValue) -> bool;
+
+    // ========================================================================
+    // Host Resource Accounting
+    // ========================================================================
+
+    /// Reserve a slot for a host handle of `kind` before opening it.
+    ///
+    /// Fails when the VM's open-handle limit for `kind` is reached. The default
+    /// implementation enforces no limit.
+    fn reserve_handle(&self, kind: HandleKind) -> AbiResult<HandleReservation> {
+        let _ = kind;
+        Ok(HandleReservation::unlimited())
+    }
+
+    /// Release the slot of a handle kept via [`HandleReservation::keep`] once it is closed.
+    fn release_handle(&self, kind: HandleKind) {
+        let _ = kind;
+    }
+
+    /// Instant at which the VM's execution timeout expires, if one is set.
+    ///
+    /// Blocking natives should bound host waits (e.g. socket reads) by it so
+    /// offloaded work does not outlive the timed-out program.
+    fn deadline(&self) -> Option<std::time::Instant> {
+        None
+    }
+
+    // ========================================================================
+    // Program Environment
+    // ========================================================================
+
+    /// Arguments passed to the running program (excluding the runtime binary
+    /// and entry file), as exposed by `process.argv()`.
+    fn program_args(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Register a callback the host runs when the program exits.
+    ///
+    /// The default implementation rejects registration.
+    fn register_exit_hook(&self, hook: NativeValue) -> AbiResult<()> {
+        let _ = hook;
+        Err("exit hooks are not supported by this host".into())
+    }
+
+    /// Remove and return the registered exit hooks, in registration order.
+    fn take_exit_hooks(&self) -> Vec<NativeValue> {
+        Vec::new()
+    }
+}
+
+/// Kind of host OS handle counted against a VM's resource limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandleKind {
+    /// Open file descriptor
+    File,
+    /// Network socket (listener, stream or datagram)
+    Socket,
+}
+
+/// Slot reserved for a host handle by [`NativeContext::reserve_handle`].
+///
+/// Dropping the reservation releases the slot, so failed opens need no cleanup.
+/// Once the handle is open and registered, call [`keep`](Self::keep) and pair
+/// the eventual close with [`NativeContext::release_handle`].
+pub struct HandleReservation {
+    release: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl HandleReservation {
+    /// Create a reservation that runs `release` unless kept
+    pub fn new(release: impl FnOnce() + Send + 'static) -> Self {
+        Self {
+            release: Some(Box::new(release)),
+        }
+    }
+
+    /// Create a reservation that is not counted against any limit
+    pub fn unlimited() -> Self {
+        Self { release: None }
+    }
+
+    /// Keep the slot reserved after the handle was opened successfully
+    pub fn keep(mut self) {
+        self.release = None;
+    }
+}
+
+impl Drop for HandleReservation {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            release();
+        }
+    }
+}
+
+impl std::fmt::Debug for HandleReservation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandleReservation")
+            .field("counted", &self.release.is_some())
+            .finish()
+    }
 }
@@ -63,6 +63,13 @@ pub enum IoRequest {
         /// Duration in nanoseconds (supports both ms and μs precision)
         duration_nanos: u64,
     },
+    /// Wait until a file descriptor is readable — the reactor registers it with
+    /// its poller, so no pool thread is held. Completes with `null` without
+    /// reading from the fd.
+    FdReadable {
+        /// Raw file descriptor; the caller keeps it open until the wait completes
+        fd: i32,
+    },
 }
 
 /// Result of a completed IO operation from a pool thread.
@@ -201,6 +208,11 @@ impl NativeFunctionRegistry {
         self.handlers.contains_key(name)
     }
 
+    /// Iterate over the registered names (e.g. to verify natives at compile time)
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.handlers.keys().map(String::as_str)
+    }
+
     /// Get the number of registered handlers
     pub fn len(&self) -> usize {
         self.handlers.len()
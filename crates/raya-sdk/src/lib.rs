@@ -43,7 +43,7 @@ mod types;
 mod value;
 
 // Re-export core types
-pub use context::{ClassInfo, NativeContext};
+pub use context::{ClassInfo, HandleKind, HandleReservation, NativeContext};
 pub use error::{AbiResult, NativeError};
 pub use handler::{
     IoCompletion, IoRequest, NativeCallResult, NativeFunctionRegistry, NativeHandler,
@@ -65,6 +65,7 @@ pub use convert::{FromNativeObject, ToNativeObject};
 // ============================================================================
 
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
 /// Native function signature for third-party FFI modules.
 ///
@@ -212,12 +213,142 @@ impl ToRaya for () {
     }
 }
 
+/// `null` maps to `None`; anything else must convert to `T`.
+///
+/// Raya has a single `null`, so nested options collapse: `Option<Option<T>>`
+/// decodes `null` as the outer `None`, and `Some(None)` encodes as `null`.
+impl<T: FromRaya> FromRaya for Option<T> {
+    fn from_raya(value: NativeValue) -> Result<Self, NativeError> {
+        if value.is_null() {
+            Ok(None)
+        } else {
+            T::from_raya(value).map(Some)
+        }
+    }
+}
+
+/// `None` maps to `null`, matching Raya's `T | null` convention.
+impl<T: ToRaya> ToRaya for Option<T> {
+    fn to_raya(self) -> NativeValue {
+        match self {
+            Some(value) => value.to_raya(),
+            None => NativeValue::null(),
+        }
+    }
+}
+
+/// Heap access the engine installs so `String` and `Vec` conversions work
+/// without a `NativeContext` (the SDK cannot see the engine's object layouts).
+#[derive(Debug, Clone, Copy)]
+pub struct HeapHooks {
+    /// Copy out a heap string's contents; `None` if the pointer is not a string.
+    pub read_string: fn(NativeValue) -> Option<String>,
+    /// Allocate a heap string in the VM whose native call is in progress;
+    /// `None` when no native call is in progress on this thread.
+    pub create_string: fn(&str) -> Option<NativeValue>,
+    /// Pin an array, pass its elements to the visitor, then unpin it.
+    /// Returns `false` without visiting if the pointer is not an array.
+    pub visit_array: fn(NativeValue, &mut dyn FnMut(&[NativeValue])) -> bool,
+    /// Allocate an array in the VM whose native call is in progress;
+    /// `None` when no native call is in progress on this thread.
+    pub create_array: fn(&[NativeValue]) -> Option<NativeValue>,
+}
+
+static HEAP_HOOKS: OnceLock<HeapHooks> = OnceLock::new();
+
+/// Install the engine's heap hooks. The first installation wins.
+pub fn install_heap_hooks(hooks: HeapHooks) {
+    let _ = HEAP_HOOKS.set(hooks);
+}
+
+/// Error value for a heap allocation attempted outside a native call.
+fn no_heap_error(what: &str) -> NativeValue {
+    NativeValue::error(format!("cannot allocate {} outside a native call", what))
+}
+
+/// Hooks for decoding a heap value expected to be `expected`.
+fn heap_hooks_for(value: NativeValue, expected: &str) -> Result<&'static HeapHooks, NativeError> {
+    if !value.is_ptr() {
+        return Err(NativeError::TypeMismatch {
+            expected: expected.to_string(),
+            got: value.type_name().to_string(),
+        });
+    }
+    HEAP_HOOKS
+        .get()
+        .ok_or_else(|| NativeError::AbiError("no Raya engine installed".to_string()))
+}
+
+impl FromRaya for String {
+    fn from_raya(value: NativeValue) -> Result<Self, NativeError> {
+        let hooks = heap_hooks_for(value, "string")?;
+        (hooks.read_string)(value).ok_or_else(|| NativeError::TypeMismatch {
+            expected: "string".to_string(),
+            got: "object".to_string(),
+        })
+    }
+}
+
+/// Outside a native call there is no VM heap to allocate in, so the result
+/// is an error value.
+impl ToRaya for &str {
+    fn to_raya(self) -> NativeValue {
+        HEAP_HOOKS
+            .get()
+            .and_then(|hooks| (hooks.create_string)(self))
+            .unwrap_or_else(|| no_heap_error("a string"))
+    }
+}
+
+impl ToRaya for String {
+    fn to_raya(self) -> NativeValue {
+        self.as_str().to_raya()
+    }
+}
+
+/// Elements are decoded while the array is pinned; the first element that
+/// does not convert to `T` fails the whole conversion.
+impl<T: FromRaya> FromRaya for Vec<T> {
+    fn from_raya(value: NativeValue) -> Result<Self, NativeError> {
+        let hooks = heap_hooks_for(value, "array")?;
+        let mut decoded = Ok(Vec::new());
+        let is_array = (hooks.visit_array)(value, &mut |items| {
+            decoded = items.iter().map(|item| T::from_raya(*item)).collect();
+        });
+        if !is_array {
+            return Err(NativeError::TypeMismatch {
+                expected: "array".to_string(),
+                got: "object".to_string(),
+            });
+        }
+        decoded
+    }
+}
+
+/// The first element that converts to an error value becomes the result, and
+/// outside a native call the result is an error value.
+impl<T: ToRaya> ToRaya for Vec<T> {
+    fn to_raya(self) -> NativeValue {
+        let mut items = Vec::with_capacity(self.len());
+        for item in self {
+            let value = item.to_raya();
+            if value.is_error() {
+                return value;
+            }
+            items.push(value);
+        }
+        HEAP_HOOKS
+            .get()
+            .and_then(|hooks| (hooks.create_array)(&items))
+            .unwrap_or_else(|| no_heap_error("an array"))
+    }
+}
+
 impl<T: ToRaya, E: ToString> ToRaya for Result<T, E> {
     fn to_raya(self) -> NativeValue {
         match self {
             Ok(value) => value.to_raya(),
-            // Error returns null (TODO: proper error propagation)
-            Err(_) => NativeValue::null(),
+            Err(e) => NativeValue::error(e.to_string()),
         }
     }
 }
@@ -285,4 +416,84 @@ mod tests {
         assert_eq!(true.to_raya().as_bool(), Some(true));
         assert!(().to_raya().is_null());
     }
+
+    #[test]
+    fn test_result_err_to_raya_is_error() {
+        let ok: Result<i32, String> = Ok(3);
+        assert_eq!(ok.to_raya().as_i32(), Some(3));
+
+        let err: Result<i32, String> = Err("bad input".to_string());
+        let value = err.to_raya();
+        assert!(value.is_error());
+        assert_eq!(value.take_error_message(), Some("bad input".to_string()));
+    }
+
+    #[test]
+    fn test_option_from_raya() {
+        assert_eq!(Option::<i32>::from_raya(NativeValue::null()).unwrap(), None);
+        assert_eq!(
+            Option::<i32>::from_raya(NativeValue::i32(5)).unwrap(),
+            Some(5)
+        );
+        assert!(Option::<i32>::from_raya(NativeValue::bool(true)).is_err());
+
+        // Nested options collapse onto the single null
+        assert_eq!(
+            Option::<Option<i32>>::from_raya(NativeValue::null()).unwrap(),
+            None
+        );
+        assert_eq!(
+            Option::<Option<i32>>::from_raya(NativeValue::i32(5)).unwrap(),
+            Some(Some(5))
+        );
+    }
+
+    #[test]
+    fn test_option_to_raya() {
+        assert_eq!(Some(7i32).to_raya().as_i32(), Some(7));
+        assert!(None::<i32>.to_raya().is_null());
+        assert!(Some(None::<i32>).to_raya().is_null());
+        assert_eq!(Some(Some(true)).to_raya().as_bool(), Some(true));
+    }
+
+    #[test]
+    fn test_string_to_raya_without_engine_is_error() {
+        let value = "no heap".to_raya();
+        assert!(value.is_error());
+        assert_eq!(
+            value.take_error_message(),
+            Some("cannot allocate a string outside a native call".to_string())
+        );
+    }
+
+    #[test]
+    fn test_vec_to_raya_propagates_element_errors() {
+        let items: Vec<Result<i32, String>> = vec![Ok(1), Err("bad item".to_string())];
+        let value = items.to_raya();
+        assert!(value.is_error());
+        assert_eq!(value.take_error_message(), Some("bad item".to_string()));
+
+        let value = vec![1, 2].to_raya();
+        assert_eq!(
+            value.take_error_message(),
+            Some("cannot allocate an array outside a native call".to_string())
+        );
+    }
+
+    #[test]
+    fn test_string_from_raya_rejects_non_pointers() {
+        for value in [
+            NativeValue::i32(7),
+            NativeValue::null(),
+            NativeValue::f64(1.5),
+        ] {
+            match String::from_raya(value) {
+                Err(NativeError::TypeMismatch { expected, got }) => {
+                    assert_eq!(expected, "string");
+                    assert_eq!(got, value.type_name());
+                }
+                other => panic!("expected TypeMismatch, got {:?}", other),
+            }
+        }
+    }
 }
@@ -16,9 +16,14 @@
 //!   - f32:       0xFFF8004000000000 | (f32.to_bits() as u64)    [tag=100]
 //!   - i64:       0xFFF8005000000000 | (i64 as u64 & 0xFFFFFFFF) [tag=101]
 //!   - null:      0xFFF8006000000000                             [tag=110]
+//!   - error:     0xFFF8006000000001                             [tag=110, payload=1]
 //!   - u64:       0xFFF8007000000000 | (u64 & 0xFFFFFFFFFFFF)    [tag=111]
 //! ```
+//!
+//! An error value carries no pointer: its message is owned by a per-thread
+//! slot until the native call dispatch takes it.
 
+use std::cell::RefCell;
 use std::ptr::NonNull;
 
 /// NaN-boxed 64-bit value — identical bit layout to the engine's `Value`.
@@ -44,11 +49,20 @@ const TAG_F32: u64 = 0x4 << TAG_SHIFT;
 const TAG_I64: u64 = 0x5 << TAG_SHIFT;
 const TAG_NULL: u64 = 0x6 << TAG_SHIFT;
 const TAG_U64: u64 = 0x7 << TAG_SHIFT;
+/// Errors share null's tag (all eight tags are taken and must stay identical
+/// to the engine's `Value`) and are told apart by a non-zero payload.
+const TAG_ERROR: u64 = TAG_NULL;
 
 const NULL_BITS: u64 = NAN_BOX_BASE | TAG_NULL;
+const ERROR_BITS: u64 = NAN_BOX_BASE | TAG_ERROR | 1;
 const TRUE_BITS: u64 = NAN_BOX_BASE | TAG_BOOL | 1;
 const FALSE_BITS: u64 = NAN_BOX_BASE | TAG_BOOL;
 
+thread_local! {
+    /// Message of the last error value created on this thread and not yet taken
+    static PENDING_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
 unsafe impl Send for NativeValue {}
 unsafe impl Sync for NativeValue {}
 
@@ -121,6 +135,16 @@ impl NativeValue {
         Self(NAN_BOX_BASE | TAG_U64 | (u & PAYLOAD_MASK))
     }
 
+    /// Create an error value carrying `message`
+    ///
+    /// The message is held by this thread until the VM's native call dispatch
+    /// takes it and throws it. Native calls are synchronous, so the dispatch
+    /// runs on the same thread; a newer error replaces an untaken one.
+    pub fn error(message: impl Into<String>) -> Self {
+        PENDING_ERROR.with(|slot| *slot.borrow_mut() = Some(message.into()));
+        Self(ERROR_BITS)
+    }
+
     /// Create from an opaque pointer
     ///
     /// # Safety
@@ -151,6 +175,12 @@ impl NativeValue {
         self.0 == NULL_BITS
     }
 
+    /// Check if value is an error created by `NativeValue::error`
+    #[inline]
+    pub const fn is_error(&self) -> bool {
+        self.0 == ERROR_BITS
+    }
+
     /// Check if value is a boolean
     #[inline]
     pub const fn is_bool(&self) -> bool {
@@ -290,6 +320,29 @@ impl NativeValue {
         }
     }
 
+    /// Copy out the pending message of an error value
+    ///
+    /// Returns `None` for non-errors and once the message has been taken.
+    pub fn error_message(&self) -> Option<String> {
+        if self.is_error() {
+            PENDING_ERROR.with(|slot| slot.borrow().clone())
+        } else {
+            None
+        }
+    }
+
+    /// Take the pending message of an error value
+    ///
+    /// Returns `None` for non-errors and once the message has been taken, so
+    /// taking it through a copy of the value is harmless.
+    pub fn take_error_message(self) -> Option<String> {
+        if self.is_error() {
+            PENDING_ERROR.with(|slot| slot.borrow_mut().take())
+        } else {
+            None
+        }
+    }
+
     /// Get type name for debugging
     pub const fn type_name(&self) -> &'static str {
         if !self.is_nan_boxed() {
@@ -302,6 +355,7 @@ impl NativeValue {
                 3 => "u32",
                 4 => "f32",
                 5 => "i64",
+                6 if self.is_error() => "error",
                 6 => "null",
                 7 => "u64",
                 _ => "unknown",
@@ -340,6 +394,7 @@ impl std::fmt::Debug for NativeValue {
                     "NativeValue::I64({})",
                     (self.0 & PAYLOAD_MASK_32) as i32 as i64
                 ),
+                6 if self.is_error() => write!(f, "NativeValue::Error"),
                 6 => write!(f, "NativeValue::Null"),
                 7 => write!(f, "NativeValue::U64({})", self.0 & PAYLOAD_MASK),
                 _ => write!(f, "NativeValue::Unknown({:#x})", self.0),
@@ -361,6 +416,26 @@ mod tests {
         assert!(!v.is_ptr());
     }
 
+    #[test]
+    fn test_error() {
+        let v = NativeValue::error("disk full");
+        assert!(v.is_error());
+        assert!(!v.is_null());
+        assert!(!v.is_ptr());
+        assert_eq!(v.type_name(), "error");
+        assert_eq!(v.error_message(), Some("disk full".to_string()));
+        assert_eq!(v.take_error_message(), Some("disk full".to_string()));
+
+        // The message is owned by the thread, so a copy can't take it twice.
+        let copy = v;
+        assert!(copy.is_error());
+        assert_eq!(copy.take_error_message(), None);
+
+        assert!(!NativeValue::null().is_error());
+        assert!(!NativeValue::from_bits(NULL_BITS | 2).is_error());
+        assert_eq!(NativeValue::i32(1).error_message(), None);
+    }
+
     #[test]
     fn test_bool() {
         let t = NativeValue::bool(true);
@@ -1,19 +1,39 @@
 //! std:fs — Filesystem operations
 
-use raya_sdk::{IoCompletion, IoRequest, NativeCallResult, NativeContext, NativeValue};
+use crate::handles::HandleRegistry;
+use raya_sdk::{HandleKind, IoCompletion, IoRequest, NativeCallResult, NativeContext, NativeValue};
+use std::sync::LazyLock;
 use std::time::UNIX_EPOCH;
 
+/// Files opened with `fs.open`, kept open until `fs.fileClose`
+static OPEN_FILES: LazyLock<HandleRegistry<std::fs::File>> = LazyLock::new(HandleRegistry::new);
+
+/// Run blocking file work on the IO pool while holding an open-file slot
+fn file_work(
+    ctx: &dyn NativeContext,
+    op: &str,
+    work: impl FnOnce() -> IoCompletion + Send + 'static,
+) -> NativeCallResult {
+    match ctx.reserve_handle(HandleKind::File) {
+        Ok(slot) => NativeCallResult::Suspend(IoRequest::BlockingWork {
+            work: Box::new(move || {
+                let _slot = slot;
+                work()
+            }),
+        }),
+        Err(e) => NativeCallResult::Error(format!("{}: {}", op, e)),
+    }
+}
+
 /// Read file as binary Buffer
 pub fn read_file(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let path = match ctx.read_string(args[0]) {
         Ok(s) => s,
         Err(e) => return NativeCallResult::Error(format!("fs.readFile: {}", e)),
     };
-    NativeCallResult::Suspend(IoRequest::BlockingWork {
-        work: Box::new(move || match std::fs::read(&path) {
-            Ok(data) => IoCompletion::Bytes(data),
-            Err(e) => IoCompletion::Error(format!("fs.readFile: {}", e)),
-        }),
+    file_work(ctx, "fs.readFile", move || match std::fs::read(&path) {
+        Ok(data) => IoCompletion::Bytes(data),
+        Err(e) => IoCompletion::Error(format!("fs.readFile: {}", e)),
     })
 }
 
@@ -23,12 +43,14 @@ pub fn read_text_file(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCa
         Ok(s) => s,
         Err(e) => return NativeCallResult::Error(format!("fs.readTextFile: {}", e)),
     };
-    NativeCallResult::Suspend(IoRequest::BlockingWork {
-        work: Box::new(move || match std::fs::read_to_string(&path) {
+    file_work(
+        ctx,
+        "fs.readTextFile",
+        move || match std::fs::read_to_string(&path) {
             Ok(data) => IoCompletion::String(data),
             Err(e) => IoCompletion::Error(format!("fs.readTextFile: {}", e)),
-        }),
-    })
+        },
+    )
 }
 
 /// Write binary Buffer to file
@@ -41,11 +63,11 @@ pub fn write_file(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallRe
         Ok(d) => d,
         Err(e) => return NativeCallResult::Error(format!("fs.writeFile: {}", e)),
     };
-    NativeCallResult::Suspend(IoRequest::BlockingWork {
-        work: Box::new(move || match std::fs::write(&path, &data) {
+    file_work(ctx, "fs.writeFile", move || {
+        match std::fs::write(&path, &data) {
             Ok(_) => IoCompletion::Primitive(NativeValue::null()),
             Err(e) => IoCompletion::Error(format!("fs.writeFile: {}", e)),
-        }),
+        }
     })
 }
 
@@ -59,11 +81,11 @@ pub fn write_text_file(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeC
         Ok(s) => s,
         Err(e) => return NativeCallResult::Error(format!("fs.writeTextFile: {}", e)),
     };
-    NativeCallResult::Suspend(IoRequest::BlockingWork {
-        work: Box::new(move || match std::fs::write(&path, data.as_bytes()) {
+    file_work(ctx, "fs.writeTextFile", move || {
+        match std::fs::write(&path, data.as_bytes()) {
             Ok(_) => IoCompletion::Primitive(NativeValue::null()),
             Err(e) => IoCompletion::Error(format!("fs.writeTextFile: {}", e)),
-        }),
+        }
     })
 }
 
@@ -77,21 +99,19 @@ pub fn append_file(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
         Ok(s) => s,
         Err(e) => return NativeCallResult::Error(format!("fs.appendFile: {}", e)),
     };
-    NativeCallResult::Suspend(IoRequest::BlockingWork {
-        work: Box::new(move || {
-            use std::io::Write;
-            match std::fs::OpenOptions::new()
-                .append(true)
-                .create(true)
-                .open(&path)
-            {
-                Ok(mut file) => match file.write_all(data.as_bytes()) {
-                    Ok(_) => IoCompletion::Primitive(NativeValue::null()),
-                    Err(e) => IoCompletion::Error(format!("fs.appendFile: {}", e)),
-                },
+    file_work(ctx, "fs.appendFile", move || {
+        use std::io::Write;
+        match std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&path)
+        {
+            Ok(mut file) => match file.write_all(data.as_bytes()) {
+                Ok(_) => IoCompletion::Primitive(NativeValue::null()),
                 Err(e) => IoCompletion::Error(format!("fs.appendFile: {}", e)),
-            }
-        }),
+            },
+            Err(e) => IoCompletion::Error(format!("fs.appendFile: {}", e)),
+        }
     })
 }
 
@@ -338,11 +358,9 @@ pub fn copy(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
         Ok(s) => s,
         Err(e) => return NativeCallResult::Error(format!("fs.copy: {}", e)),
     };
-    NativeCallResult::Suspend(IoRequest::BlockingWork {
-        work: Box::new(move || match std::fs::copy(&from, &to) {
-            Ok(_) => IoCompletion::Primitive(NativeValue::null()),
-            Err(e) => IoCompletion::Error(format!("fs.copy: {}", e)),
-        }),
+    file_work(ctx, "fs.copy", move || match std::fs::copy(&from, &to) {
+        Ok(_) => IoCompletion::Primitive(NativeValue::null()),
+        Err(e) => IoCompletion::Error(format!("fs.copy: {}", e)),
     })
 }
 
@@ -442,15 +460,106 @@ pub fn temp_file(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallRes
         Ok(s) => s,
         Err(e) => return NativeCallResult::Error(format!("fs.tempFile: {}", e)),
     };
+    file_work(ctx, "fs.tempFile", move || {
+        let dir = std::env::temp_dir();
+        let name = format!("{}{}", prefix, std::process::id());
+        let path = dir.join(name);
+        match std::fs::File::create(&path) {
+            Ok(_) => IoCompletion::String(path.to_string_lossy().into_owned()),
+            Err(e) => IoCompletion::Error(format!("fs.tempFile: {}", e)),
+        }
+    })
+}
+
+/// Open a file and keep it open (blocking → IO pool)
+///
+/// Mode is "r" (read), "w" (truncate or create) or "a" (append or create).
+/// The file holds an open-file slot until it is closed.
+pub fn open(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let path = match ctx.read_string(args[0]) {
+        Ok(s) => s,
+        Err(e) => return NativeCallResult::Error(format!("fs.open: {}", e)),
+    };
+    let mode = match ctx.read_string(args[1]) {
+        Ok(s) => s,
+        Err(e) => return NativeCallResult::Error(format!("fs.open: {}", e)),
+    };
+    let mut options = std::fs::OpenOptions::new();
+    match mode.as_str() {
+        "r" => options.read(true),
+        "w" => options.write(true).create(true).truncate(true),
+        "a" => options.append(true).create(true),
+        _ => return NativeCallResult::Error(format!("fs.open: invalid mode '{}'", mode)),
+    };
+    let slot = match ctx.reserve_handle(HandleKind::File) {
+        Ok(slot) => slot,
+        Err(e) => return NativeCallResult::Error(format!("fs.open: {}", e)),
+    };
+    NativeCallResult::Suspend(IoRequest::BlockingWork {
+        work: Box::new(move || match options.open(&path) {
+            Ok(file) => {
+                slot.keep();
+                let handle = OPEN_FILES.insert(file);
+                IoCompletion::Primitive(NativeValue::f64(handle as f64))
+            }
+            Err(e) => IoCompletion::Error(format!("fs.open: {}", e)),
+        }),
+    })
+}
+
+/// Read the rest of an open file as UTF-8 text (blocking → IO pool)
+pub fn file_read_text(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let handle = args
+        .first()
+        .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
+        .unwrap_or(0.0) as u64;
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || {
-            let dir = std::env::temp_dir();
-            let name = format!("{}{}", prefix, std::process::id());
-            let path = dir.join(name);
-            match std::fs::File::create(&path) {
-                Ok(_) => IoCompletion::String(path.to_string_lossy().into_owned()),
-                Err(e) => IoCompletion::Error(format!("fs.tempFile: {}", e)),
+            use std::io::Read;
+            let Some(mut file) = OPEN_FILES.get_mut(handle) else {
+                return IoCompletion::Error(format!("fs.fileReadText: invalid handle {}", handle));
+            };
+            let mut text = String::new();
+            match file.read_to_string(&mut text) {
+                Ok(_) => IoCompletion::String(text),
+                Err(e) => IoCompletion::Error(format!("fs.fileReadText: {}", e)),
+            }
+        }),
+    })
+}
+
+/// Write text to an open file (blocking → IO pool)
+pub fn file_write_text(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let handle = args
+        .first()
+        .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
+        .unwrap_or(0.0) as u64;
+    let data = match ctx.read_string(args[1]) {
+        Ok(s) => s,
+        Err(e) => return NativeCallResult::Error(format!("fs.fileWriteText: {}", e)),
+    };
+    NativeCallResult::Suspend(IoRequest::BlockingWork {
+        work: Box::new(move || {
+            use std::io::Write;
+            let Some(mut file) = OPEN_FILES.get_mut(handle) else {
+                return IoCompletion::Error(format!("fs.fileWriteText: invalid handle {}", handle));
+            };
+            match file.write_all(data.as_bytes()) {
+                Ok(_) => IoCompletion::Primitive(NativeValue::null()),
+                Err(e) => IoCompletion::Error(format!("fs.fileWriteText: {}", e)),
             }
         }),
     })
 }
+
+/// Close an open file and release its open-file slot
+pub fn file_close(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let handle = args
+        .first()
+        .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
+        .unwrap_or(0.0) as u64;
+    if OPEN_FILES.remove(handle).is_some() {
+        ctx.release_handle(HandleKind::File);
+    }
+    NativeCallResult::null()
+}
@@ -3,12 +3,13 @@
 use crate::handles::HandleRegistry;
 use crate::tls;
 use dashmap::{DashMap, DashSet};
-use raya_sdk::{IoCompletion, IoRequest, NativeCallResult, NativeContext, NativeValue};
+use raya_sdk::{HandleKind, IoCompletion, IoRequest, NativeCallResult, NativeContext, NativeValue};
 use std::io::{Read, Write};
 use std::net;
 use std::net::ToSocketAddrs;
 use std::os::fd::AsRawFd;
 use std::sync::LazyLock;
+use std::time::{Duration, Instant};
 
 static TCP_LISTENERS: LazyLock<HandleRegistry<net::TcpListener>> =
     LazyLock::new(HandleRegistry::new);
@@ -19,6 +20,15 @@ static UDP_SOCKETS: LazyLock<HandleRegistry<net::UdpSocket>> = LazyLock::new(Han
 static TLS_STREAMS: LazyLock<HandleRegistry<tls::ClientTlsStream>> =
     LazyLock::new(HandleRegistry::new);
 
+/// Socket read timeout that ends a blocking read at the VM's execution deadline.
+fn deadline_read_timeout(deadline: Option<Instant>) -> Option<Duration> {
+    deadline.map(|deadline| {
+        deadline
+            .saturating_duration_since(Instant::now())
+            .max(Duration::from_millis(1))
+    })
+}
+
 fn normalize_connect_host(host: &str) -> String {
     let trimmed = host.trim();
     if trimmed.is_empty() {
@@ -82,9 +92,14 @@ pub fn tcp_listen(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallRe
         .get(1)
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u16;
+    let slot = match ctx.reserve_handle(HandleKind::Socket) {
+        Ok(slot) => slot,
+        Err(e) => return NativeCallResult::Error(format!("net.tcpListen: {}", e)),
+    };
     let addr = format!("{}:{}", host, port);
     match net::TcpListener::bind(&addr) {
         Ok(listener) => {
+            slot.keep();
             let handle = TCP_LISTENERS.insert(listener);
             TCP_LISTENER_DISPLAY_HOST.insert(handle, normalize_connect_host(&host));
             CLOSED_TCP_LISTENERS.remove(&handle);
@@ -95,11 +110,15 @@ pub fn tcp_listen(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallRe
 }
 
 /// Accept a TCP connection (blocking → IO pool)
-pub fn tcp_accept(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn tcp_accept(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u64;
+    let slot = match ctx.reserve_handle(HandleKind::Socket) {
+        Ok(slot) => slot,
+        Err(e) => return NativeCallResult::Error(format!("net.tcpAccept: {}", e)),
+    };
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || {
             if CLOSED_TCP_LISTENERS.contains(&handle) {
@@ -127,6 +146,7 @@ pub fn tcp_accept(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
                         // Listener was closed while accept completed; report graceful shutdown.
                         IoCompletion::Primitive(NativeValue::null())
                     } else {
+                        slot.keep();
                         let stream_handle = TCP_STREAMS.insert(stream);
                         IoCompletion::Primitive(NativeValue::f64(stream_handle as f64))
                     }
@@ -144,7 +164,7 @@ pub fn tcp_accept(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
 }
 
 /// Close TCP listener
-pub fn tcp_listener_close(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn tcp_listener_close(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
@@ -152,6 +172,7 @@ pub fn tcp_listener_close(_ctx: &dyn NativeContext, args: &[NativeValue]) -> Nat
     CLOSED_TCP_LISTENERS.insert(handle);
     TCP_LISTENER_DISPLAY_HOST.remove(&handle);
     if let Some((_id, listener)) = TCP_LISTENERS.remove(handle) {
+        ctx.release_handle(HandleKind::Socket);
         // Wake any concurrent blocking accept() calls immediately.
         let _ = unsafe { libc::shutdown(listener.as_raw_fd(), libc::SHUT_RDWR) };
     }
@@ -192,6 +213,10 @@ pub fn tcp_connect(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
         .get(1)
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u16;
+    let slot = match ctx.reserve_handle(HandleKind::Socket) {
+        Ok(slot) => slot,
+        Err(e) => return NativeCallResult::Error(format!("net.tcpConnect: {}", e)),
+    };
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || {
             let addrs = match resolve_connect_addrs(&host, port) {
@@ -200,6 +225,7 @@ pub fn tcp_connect(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
             };
             match connect_first_resolved(&addrs) {
                 Ok(stream) => {
+                    slot.keep();
                     let handle = TCP_STREAMS.insert(stream);
                     IoCompletion::Primitive(NativeValue::f64(handle as f64))
                 }
@@ -210,7 +236,7 @@ pub fn tcp_connect(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
 }
 
 /// Read up to N bytes from TCP stream (blocking → IO pool)
-pub fn tcp_read(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn tcp_read(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
@@ -219,9 +245,13 @@ pub fn tcp_read(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallRes
         .get(1)
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(4096.0) as usize;
+    let timeout = deadline_read_timeout(ctx.deadline());
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || match TCP_STREAMS.get_mut(handle) {
             Some(mut stream) => {
+                if timeout.is_some() {
+                    let _ = stream.set_read_timeout(timeout);
+                }
                 let mut buf = vec![0u8; size];
                 match stream.read(&mut buf) {
                     Ok(n) => {
@@ -237,14 +267,18 @@ pub fn tcp_read(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallRes
 }
 
 /// Read all bytes from TCP stream until EOF (blocking → IO pool)
-pub fn tcp_read_all(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn tcp_read_all(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u64;
+    let timeout = deadline_read_timeout(ctx.deadline());
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || match TCP_STREAMS.get_mut(handle) {
             Some(mut stream) => {
+                if timeout.is_some() {
+                    let _ = stream.set_read_timeout(timeout);
+                }
                 let mut buf = Vec::new();
                 match stream.read_to_end(&mut buf) {
                     Ok(_) => IoCompletion::Bytes(buf),
@@ -257,14 +291,18 @@ pub fn tcp_read_all(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCal
 }
 
 /// Read a line from TCP stream (blocking → IO pool)
-pub fn tcp_read_line(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn tcp_read_line(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u64;
+    let timeout = deadline_read_timeout(ctx.deadline());
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || match TCP_STREAMS.get_mut(handle) {
             Some(mut stream) => {
+                if timeout.is_some() {
+                    let _ = stream.set_read_timeout(timeout);
+                }
                 // Read byte-by-byte until newline.
                 //
                 // Important: do not use a temporary BufReader over a cloned stream.
@@ -338,12 +376,14 @@ pub fn tcp_write_text(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCa
 }
 
 /// Close TCP stream
-pub fn tcp_stream_close(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn tcp_stream_close(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u64;
-    TCP_STREAMS.remove(handle);
+    if TCP_STREAMS.remove(handle).is_some() {
+        ctx.release_handle(HandleKind::Socket);
+    }
     NativeCallResult::null()
 }
 
@@ -389,9 +429,14 @@ pub fn udp_bind(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResu
         .get(1)
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u16;
+    let slot = match ctx.reserve_handle(HandleKind::Socket) {
+        Ok(slot) => slot,
+        Err(e) => return NativeCallResult::Error(format!("net.udpBind: {}", e)),
+    };
     let addr = format!("{}:{}", host, port);
     match net::UdpSocket::bind(&addr) {
         Ok(socket) => {
+            slot.keep();
             let handle = UDP_SOCKETS.insert(socket);
             NativeCallResult::f64(handle as f64)
         }
@@ -450,7 +495,7 @@ pub fn udp_send_text(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCal
 }
 
 /// Receive data from UDP socket (blocking → IO pool)
-pub fn udp_receive(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn udp_receive(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
@@ -459,9 +504,13 @@ pub fn udp_receive(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCall
         .get(1)
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(4096.0) as usize;
+    let timeout = deadline_read_timeout(ctx.deadline());
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || match UDP_SOCKETS.get(handle) {
             Some(socket) => {
+                if timeout.is_some() {
+                    let _ = socket.set_read_timeout(timeout);
+                }
                 let mut buf = vec![0u8; size];
                 match socket.recv_from(&mut buf) {
                     Ok((n, _addr)) => {
@@ -477,12 +526,14 @@ pub fn udp_receive(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCall
 }
 
 /// Close UDP socket
-pub fn udp_close(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn udp_close(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u64;
-    UDP_SOCKETS.remove(handle);
+    if UDP_SOCKETS.remove(handle).is_some() {
+        ctx.release_handle(HandleKind::Socket);
+    }
     NativeCallResult::null()
 }
 
@@ -513,6 +564,10 @@ pub fn tls_connect(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
         .get(1)
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(443.0) as u16;
+    let slot = match ctx.reserve_handle(HandleKind::Socket) {
+        Ok(slot) => slot,
+        Err(e) => return NativeCallResult::Error(format!("net.tlsConnect: {}", e)),
+    };
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || {
             let addrs = match resolve_connect_addrs(&host, port) {
@@ -525,6 +580,7 @@ pub fn tls_connect(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
                     let config = tls::default_client_config();
                     match tls::connect_tls(stream, &sni_host, config) {
                         Ok(tls_stream) => {
+                            slot.keep();
                             let handle = TLS_STREAMS.insert(tls_stream);
                             IoCompletion::Primitive(NativeValue::f64(handle as f64))
                         }
@@ -551,6 +607,10 @@ pub fn tls_connect_with_ca(ctx: &dyn NativeContext, args: &[NativeValue]) -> Nat
         Ok(s) => s,
         Err(e) => return NativeCallResult::Error(format!("net.tlsConnectWithCa: {}", e)),
     };
+    let slot = match ctx.reserve_handle(HandleKind::Socket) {
+        Ok(slot) => slot,
+        Err(e) => return NativeCallResult::Error(format!("net.tlsConnectWithCa: {}", e)),
+    };
     NativeCallResult::Suspend(IoRequest::BlockingWork {
         work: Box::new(move || {
             let config = match tls::client_config_with_ca(&ca_pem) {
@@ -565,6 +625,7 @@ pub fn tls_connect_with_ca(ctx: &dyn NativeContext, args: &[NativeValue]) -> Nat
             match connect_first_resolved(&addrs) {
                 Ok(stream) => match tls::connect_tls(stream, &sni_host, config) {
                     Ok(tls_stream) => {
+                        slot.keep();
                         let handle = TLS_STREAMS.insert(tls_stream);
                         IoCompletion::Primitive(NativeValue::f64(handle as f64))
                     }
@@ -706,12 +767,14 @@ pub fn tls_write_text(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCa
 }
 
 /// Close TLS stream
-pub fn tls_close(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+pub fn tls_close(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
     let handle = args
         .first()
         .and_then(|v| v.as_f64().or_else(|| v.as_i32().map(|i| i as f64)))
         .unwrap_or(0.0) as u64;
-    TLS_STREAMS.remove(handle);
+    if TLS_STREAMS.remove(handle).is_some() {
+        ctx.release_handle(HandleKind::Socket);
+    }
     NativeCallResult::null()
 }
 
@@ -36,14 +36,35 @@ pub fn exit(_ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult
     std::process::exit(code);
 }
 
+/// Register a callback to run when the program exits
+pub fn on_exit(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let Some(&hook) = args.first() else {
+        return NativeCallResult::Error("process.onExit: missing callback".to_string());
+    };
+    match ctx.register_exit_hook(hook) {
+        Ok(()) => NativeCallResult::null(),
+        Err(e) => NativeCallResult::Error(format!("process.onExit: {}", e)),
+    }
+}
+
+/// Remove and return the registered exit hooks (registration order)
+pub fn take_exit_hooks(ctx: &dyn NativeContext, _args: &[NativeValue]) -> NativeCallResult {
+    let hooks = ctx.take_exit_hooks();
+    NativeCallResult::Value(ctx.create_array(&hooks))
+}
+
 /// Get current process ID
 pub fn pid(_ctx: &dyn NativeContext, _args: &[NativeValue]) -> NativeCallResult {
     NativeCallResult::f64(std::process::id() as f64)
 }
 
-/// Get command-line arguments
+/// Get the arguments passed to the running program
 pub fn argv(ctx: &dyn NativeContext, _args: &[NativeValue]) -> NativeCallResult {
-    let args: Vec<NativeValue> = std::env::args().map(|a| ctx.create_string(&a)).collect();
+    let args: Vec<NativeValue> = ctx
+        .program_args()
+        .iter()
+        .map(|a| ctx.create_string(a))
+        .collect();
     NativeCallResult::Value(ctx.create_array(&args))
 }
 
@@ -302,7 +323,7 @@ pub fn heap_total(_ctx: &dyn NativeContext, _args: &[NativeValue]) -> NativeCall
 
 // ── Signal handling ──
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 
 /// Signal flags — indexed by signal number. Max signal number on most Unix is 64.
 #[allow(clippy::declare_interior_mutable_const)] // Const used to initialize static array
@@ -318,6 +339,71 @@ static SIGNAL_TRAPPED: [AtomicBool; 64] = {
     [INIT; 64]
 };
 
+/// Self-pipe per trapped signal: the handler writes a byte to the write end so
+/// waiters can block on the read end through the reactor (-1 = not created).
+#[allow(clippy::declare_interior_mutable_const)] // Const used to initialize static array
+static SIGNAL_PIPE_READ: [AtomicI32; 64] = {
+    const INIT: AtomicI32 = AtomicI32::new(-1);
+    [INIT; 64]
+};
+
+#[allow(clippy::declare_interior_mutable_const)] // Const used to initialize static array
+static SIGNAL_PIPE_WRITE: [AtomicI32; 64] = {
+    const INIT: AtomicI32 = AtomicI32::new(-1);
+    [INIT; 64]
+};
+
+/// Serializes self-pipe creation across concurrent `trapSignal` calls.
+static SIGNAL_PIPE_INIT: Mutex<()> = Mutex::new(());
+
+/// Create the non-blocking self-pipe for `sig` if it does not exist yet.
+fn ensure_signal_pipe(sig: usize) -> Result<(), String> {
+    let _guard = SIGNAL_PIPE_INIT.lock().unwrap_or_else(|e| e.into_inner());
+    if SIGNAL_PIPE_READ[sig].load(Ordering::SeqCst) >= 0 {
+        return Ok(());
+    }
+    let mut fds = [0 as libc::c_int; 2];
+    // SAFETY: `fds` is a valid two-element buffer for pipe(2).
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(std::io::Error::last_os_error().to_string());
+    }
+    for fd in fds {
+        // SAFETY: `fd` was just returned by pipe(2).
+        unsafe {
+            let flags = libc::fcntl(fd, libc::F_GETFL);
+            libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+            libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+        }
+    }
+    SIGNAL_PIPE_READ[sig].store(fds[0], Ordering::SeqCst);
+    SIGNAL_PIPE_WRITE[sig].store(fds[1], Ordering::SeqCst);
+    Ok(())
+}
+
+/// Write one byte to the self-pipe of `sig` (async-signal-safe).
+fn wake_signal_pipe(sig: usize) {
+    let fd = SIGNAL_PIPE_WRITE[sig].load(Ordering::SeqCst);
+    if fd >= 0 {
+        let byte = 1u8;
+        // SAFETY: write(2) is async-signal-safe; a full pipe (EAGAIN) already
+        // guarantees a pending wake-up, so the result is ignored.
+        unsafe {
+            libc::write(fd, &byte as *const u8 as *const libc::c_void, 1);
+        }
+    }
+}
+
+/// Discard every pending byte in the self-pipe of `sig`.
+fn drain_signal_pipe(sig: usize) {
+    let fd = SIGNAL_PIPE_READ[sig].load(Ordering::SeqCst);
+    if fd < 0 {
+        return;
+    }
+    let mut buf = [0u8; 64];
+    // SAFETY: the read end is non-blocking and `buf` is a valid buffer.
+    while unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) } > 0 {}
+}
+
 fn signal_name_to_number(name: &str) -> Option<i32> {
     match name.to_uppercase().as_str() {
         "SIGINT" | "INT" => Some(libc::SIGINT),
@@ -340,11 +426,12 @@ fn signal_number_to_name(sig: usize) -> &'static str {
     }
 }
 
-/// C-compatible signal handler — sets atomic flag
+/// C-compatible signal handler — sets atomic flag and wakes the self-pipe
 extern "C" fn signal_handler(sig: libc::c_int) {
     let sig = sig as usize;
     if sig < SIGNAL_FLAGS.len() {
         SIGNAL_FLAGS[sig].store(true, Ordering::SeqCst);
+        wake_signal_pipe(sig);
     }
 }
 
@@ -369,6 +456,9 @@ pub fn trap_signal(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallR
             "process.trapSignal: signal number out of range".to_string(),
         );
     }
+    if let Err(e) = ensure_signal_pipe(sig_usize) {
+        return NativeCallResult::Error(format!("process.trapSignal: {}", e));
+    }
     // Mark as trapped and install handler
     SIGNAL_TRAPPED[sig_usize].store(true, Ordering::SeqCst);
     SIGNAL_FLAGS[sig_usize].store(false, Ordering::SeqCst);
@@ -406,6 +496,8 @@ pub fn untrap_signal(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCal
     unsafe {
         libc::signal(sig, libc::SIG_DFL);
     }
+    // Wake `waitSignalReady` callers so they observe the untrap
+    wake_signal_pipe(sig_usize);
     NativeCallResult::null()
 }
 
@@ -463,6 +555,65 @@ pub fn wait_signal(_ctx: &dyn NativeContext, _args: &[NativeValue]) -> NativeCal
     })
 }
 
+/// Consume a received trapped signal. Returns true (and clears its flag) if
+/// the signal arrived since the last call.
+pub fn take_signal(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let sig = match trapped_signal_index(ctx, args, "process.takeSignal") {
+        Ok(sig) => sig,
+        Err(e) => return NativeCallResult::Error(e),
+    };
+    // Drain before swapping: a signal landing in between leaves its byte in
+    // the pipe, so the next wait returns at once instead of missing it.
+    drain_signal_pipe(sig);
+    NativeCallResult::bool(SIGNAL_FLAGS[sig].swap(false, Ordering::SeqCst))
+}
+
+/// Suspend until the signal is received or untrapped. The wait is registered
+/// with the reactor's poller on the signal's self-pipe, so no thread is held;
+/// call `takeSignal` afterwards to consume the signal.
+pub fn wait_signal_ready(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let sig = match trapped_signal_index(ctx, args, "process.waitSignalReady") {
+        Ok(sig) => sig,
+        Err(e) => return NativeCallResult::Error(e),
+    };
+    let fd = SIGNAL_PIPE_READ[sig].load(Ordering::SeqCst);
+    if fd < 0
+        || !SIGNAL_TRAPPED[sig].load(Ordering::SeqCst)
+        || SIGNAL_FLAGS[sig].load(Ordering::SeqCst)
+    {
+        return NativeCallResult::null();
+    }
+    NativeCallResult::Suspend(IoRequest::FdReadable { fd })
+}
+
+/// Resolve the signal-name argument to an index into the signal tables.
+fn trapped_signal_index(
+    ctx: &dyn NativeContext,
+    args: &[NativeValue],
+    op: &str,
+) -> Result<usize, String> {
+    let name = ctx
+        .read_string(args[0])
+        .map_err(|e| format!("{}: {}", op, e))?;
+    match signal_name_to_number(&name) {
+        Some(s) if (s as usize) < SIGNAL_FLAGS.len() => Ok(s as usize),
+        _ => Err(format!("{}: unknown signal '{}'", op, name)),
+    }
+}
+
+/// Check whether a signal is currently trapped
+pub fn is_signal_trapped(ctx: &dyn NativeContext, args: &[NativeValue]) -> NativeCallResult {
+    let name = match ctx.read_string(args[0]) {
+        Ok(s) => s,
+        Err(e) => return NativeCallResult::Error(format!("process.isSignalTrapped: {}", e)),
+    };
+    let trapped = signal_name_to_number(&name)
+        .map(|sig| sig as usize)
+        .filter(|&sig| sig < SIGNAL_TRAPPED.len())
+        .is_some_and(|sig| SIGNAL_TRAPPED[sig].load(Ordering::SeqCst));
+    NativeCallResult::bool(trapped)
+}
+
 // ── Platform-specific helpers ──
 
 fn get_memory_usage() -> u64 {
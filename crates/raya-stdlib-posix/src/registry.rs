@@ -123,10 +123,24 @@ fn register_fs(registry: &mut NativeFunctionRegistry) {
     registry.register("fs.realpath", |ctx, args| crate::fs::realpath(ctx, args));
     registry.register("fs.tempDir", |ctx, args| crate::fs::temp_dir(ctx, args));
     registry.register("fs.tempFile", |ctx, args| crate::fs::temp_file(ctx, args));
+    registry.register("fs.open", |ctx, args| crate::fs::open(ctx, args));
+    registry.register("fs.fileReadText", |ctx, args| {
+        crate::fs::file_read_text(ctx, args)
+    });
+    registry.register("fs.fileWriteText", |ctx, args| {
+        crate::fs::file_write_text(ctx, args)
+    });
+    registry.register("fs.fileClose", |ctx, args| crate::fs::file_close(ctx, args));
 }
 
 fn register_process(registry: &mut NativeFunctionRegistry) {
     registry.register("process.exit", |ctx, args| crate::process::exit(ctx, args));
+    registry.register("process.onExit", |ctx, args| {
+        crate::process::on_exit(ctx, args)
+    });
+    registry.register("process.takeExitHooks", |ctx, args| {
+        crate::process::take_exit_hooks(ctx, args)
+    });
     registry.register("process.pid", |ctx, args| crate::process::pid(ctx, args));
     registry.register("process.argv", |ctx, args| crate::process::argv(ctx, args));
     registry.register("process.execPath", |ctx, args| {
@@ -203,6 +217,15 @@ fn register_process(registry: &mut NativeFunctionRegistry) {
     registry.register("process.waitSignal", |ctx, args| {
         crate::process::wait_signal(ctx, args)
     });
+    registry.register("process.takeSignal", |ctx, args| {
+        crate::process::take_signal(ctx, args)
+    });
+    registry.register("process.waitSignalReady", |ctx, args| {
+        crate::process::wait_signal_ready(ctx, args)
+    });
+    registry.register("process.isSignalTrapped", |ctx, args| {
+        crate::process::is_signal_trapped(ctx, args)
+    });
     // Child process (subprocess spawning)
     registry.register("process.spawn", |ctx, args| {
         crate::process::process_spawn(ctx, args)
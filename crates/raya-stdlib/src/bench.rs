@@ -0,0 +1,212 @@
+//! Benchmark module native implementations.
+//!
+//! Provides native functions for the `raya bench` harness:
+//! - Run configuration (name filter, warmup and measured iteration counts)
+//! - Timing of each benchmark's measured iterations
+//! - Profiler hooks around the measured iterations of the profiled benchmark
+
+use parking_lot::Mutex;
+use raya_sdk::{NativeCallResult, NativeFunctionRegistry};
+use std::sync::Arc;
+use std::time::Instant;
+
+// ============================================================================
+// Benchmark Result Types
+// ============================================================================
+
+/// Result of a single benchmark.
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    /// Benchmark name
+    pub name: String,
+    /// Number of measured iterations (warmup excluded)
+    pub iterations: usize,
+    /// Wall-clock time of the measured iterations in milliseconds
+    pub total_ms: f64,
+    /// Error message if the benchmark threw
+    pub error_message: Option<String>,
+}
+
+impl BenchResult {
+    /// Mean time per measured iteration in milliseconds.
+    pub fn mean_ms(&self) -> f64 {
+        if self.iterations == 0 {
+            0.0
+        } else {
+            self.total_ms / self.iterations as f64
+        }
+    }
+}
+
+/// Collected results from a benchmark file execution.
+#[derive(Debug, Clone, Default)]
+pub struct BenchResults {
+    /// Individual benchmark results in execution order
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchResults {
+    /// Number of benchmarks that threw.
+    pub fn failed(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| r.error_message.is_some())
+            .count()
+    }
+}
+
+/// Shared handle for collecting benchmark results across native calls.
+pub type SharedBenchResults = Arc<Mutex<BenchResults>>;
+
+/// Create a new shared benchmark results collector.
+pub fn new_results() -> SharedBenchResults {
+    Arc::new(Mutex::new(BenchResults::default()))
+}
+
+/// Which benchmarks to run and how many iterations of each.
+#[derive(Debug, Clone, Default)]
+pub struct BenchConfig {
+    /// Only run benchmarks whose name contains this pattern
+    pub filter: Option<String>,
+    /// Unmeasured iterations run before timing starts
+    pub warmup: usize,
+    /// Measured iterations
+    pub iterations: usize,
+    /// Run only the benchmark with this exact name, under the profiler
+    pub profile: Option<String>,
+}
+
+/// Called with `true` when the measured iterations of the profiled benchmark
+/// begin and `false` when they end.
+pub type ProfileHook = Arc<dyn Fn(bool) + Send + Sync>;
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+/// Register benchmark native functions into the given registry.
+///
+/// The `results` handle is shared with the caller (typically the bench runner),
+/// which reads it after module execution completes. `profile_hook` is invoked
+/// around the measured iterations of `config.profile`, so warmup iterations
+/// are never sampled.
+pub fn register_bench(
+    registry: &mut NativeFunctionRegistry,
+    results: SharedBenchResults,
+    config: BenchConfig,
+    profile_hook: Option<ProfileHook>,
+) {
+    let config = Arc::new(config);
+    // Benchmark currently being measured and when its measured phase started.
+    let measuring: Arc<Mutex<Option<(String, Instant)>>> = Arc::new(Mutex::new(None));
+
+    // bench.warmupIterations() -> number
+    let c = config.clone();
+    registry.register("bench.warmupIterations", move |_ctx, _args| {
+        NativeCallResult::i32(c.warmup as i32)
+    });
+
+    // bench.iterations() -> number
+    let c = config.clone();
+    registry.register("bench.iterations", move |_ctx, _args| {
+        NativeCallResult::i32(c.iterations as i32)
+    });
+
+    // bench.shouldRun(name: string) -> boolean
+    let c = config.clone();
+    registry.register("bench.shouldRun", move |ctx, args| {
+        let name = args
+            .first()
+            .and_then(|v| ctx.read_string(*v).ok())
+            .unwrap_or_default();
+        NativeCallResult::bool(should_run(&c, &name))
+    });
+
+    // bench.measureStart(name: string)
+    let c = config.clone();
+    let m = measuring.clone();
+    let hook = profile_hook.clone();
+    registry.register("bench.measureStart", move |ctx, args| {
+        let name = args
+            .first()
+            .and_then(|v| ctx.read_string(*v).ok())
+            .unwrap_or_default();
+        if let Some(ref hook) = hook {
+            if c.profile.as_deref() == Some(name.as_str()) {
+                hook(true);
+            }
+        }
+        *m.lock() = Some((name, Instant::now()));
+        NativeCallResult::null()
+    });
+
+    // bench.measureEnd(name: string)
+    let c = config.clone();
+    let m = measuring.clone();
+    let r = results.clone();
+    let hook = profile_hook.clone();
+    registry.register("bench.measureEnd", move |_ctx, _args| {
+        let Some((name, started)) = m.lock().take() else {
+            return NativeCallResult::null();
+        };
+        let total_ms = started.elapsed().as_secs_f64() * 1000.0;
+        stop_profiling(&c, &name, hook.as_ref());
+        r.lock().results.push(BenchResult {
+            name,
+            iterations: c.iterations,
+            total_ms,
+            error_message: None,
+        });
+        NativeCallResult::null()
+    });
+
+    // bench.reportError(name: string, error: unknown)
+    let c = config;
+    let m = measuring;
+    let r = results;
+    let hook = profile_hook;
+    registry.register("bench.reportError", move |ctx, args| {
+        let name = args
+            .first()
+            .and_then(|v| ctx.read_string(*v).ok())
+            .unwrap_or_default();
+        if m.lock().take().is_some() {
+            stop_profiling(&c, &name, hook.as_ref());
+        }
+        let message = args
+            .get(1)
+            .and_then(|v| crate::test::error_message(ctx, *v))
+            .unwrap_or_else(|| "benchmark threw".to_string());
+        r.lock().results.push(BenchResult {
+            name,
+            iterations: 0,
+            total_ms: 0.0,
+            error_message: Some(message),
+        });
+        NativeCallResult::null()
+    });
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Whether the benchmark `name` is selected by the filter and profile target.
+fn should_run(config: &BenchConfig, name: &str) -> bool {
+    if let Some(ref profile) = config.profile {
+        return profile == name;
+    }
+    config
+        .filter
+        .as_deref()
+        .map(|pattern| name.contains(pattern))
+        .unwrap_or(true)
+}
+
+fn stop_profiling(config: &BenchConfig, name: &str, hook: Option<&ProfileHook>) {
+    if let Some(hook) = hook {
+        if config.profile.as_deref() == Some(name) {
+            hook(false);
+        }
+    }
+}
@@ -6,6 +6,7 @@
 
 #![warn(missing_docs)]
 
+pub mod bench;
 pub mod compress;
 pub mod crypto;
 pub mod encoding;
@@ -2,7 +2,8 @@
 //!
 //! Provides native functions for the `std:test` module:
 //! - Result reporting (pass/fail/skip)
-//! - Deep equality comparison
+//! - Strict and deep equality comparison
+//! - Structured expected-vs-actual diffs
 //! - String matching
 //! - Contains check
 
@@ -128,6 +129,24 @@ pub fn register_test(registry: &mut NativeFunctionRegistry, results: SharedTestR
         NativeCallResult::bool(equal)
     });
 
+    // test.strictEqual(a: any, b: any) -> boolean
+    // Primitives and strings compare by value, everything else by reference.
+    registry.register("test.strictEqual", |ctx, args| {
+        let a = args.first().copied().unwrap_or_else(NativeValue::null);
+        let b = args.get(1).copied().unwrap_or_else(NativeValue::null);
+        NativeCallResult::bool(strict_equal(ctx, a, b))
+    });
+
+    // test.diff(expected: any, actual: any) -> string
+    // Lists each path where the values differ, one entry per line.
+    registry.register("test.diff", |ctx, args| {
+        let expected = args.first().copied().unwrap_or_else(NativeValue::null);
+        let actual = args.get(1).copied().unwrap_or_else(NativeValue::null);
+        let mut lines = Vec::new();
+        diff_values(ctx, expected, actual, "", &mut lines);
+        NativeCallResult::Value(ctx.create_string(&lines.join("\n")))
+    });
+
     // test.contains(haystack: any, needle: any) -> boolean
     registry.register("test.contains", |ctx, args| {
         let haystack = args.first().copied().unwrap_or_else(NativeValue::null);
@@ -166,20 +185,12 @@ pub fn register_test(registry: &mut NativeFunctionRegistry, results: SharedTestR
     });
 
     // test.getErrorMessage(error: any) -> string
-    // Reads the `message` field (index 0) from an Error object.
+    // Reads the `message` field from an Error object; thrown strings are
+    // returned as-is.
     registry.register("test.getErrorMessage", |ctx, args| {
         let err = args.first().copied().unwrap_or_else(NativeValue::null);
-        if err.is_null() {
-            return NativeCallResult::Value(ctx.create_string("unknown error"));
-        }
-        // Error.message is field 0
-        match ctx.object_get_field(err, 0) {
-            Ok(msg_val) => match ctx.read_string(msg_val) {
-                Ok(s) => NativeCallResult::Value(ctx.create_string(&s)),
-                Err(_) => NativeCallResult::Value(ctx.create_string("unknown error")),
-            },
-            Err(_) => NativeCallResult::Value(ctx.create_string("unknown error")),
-        }
+        let message = error_message(ctx, err).unwrap_or_else(|| "unknown error".to_string());
+        NativeCallResult::Value(ctx.create_string(&message))
     });
 
     // test.isNull(value) -> boolean
@@ -240,6 +251,13 @@ fn stringify_value(ctx: &dyn NativeContext, val: NativeValue) -> String {
     if let Some(f) = val.as_f64() {
         return format!("{}", f);
     }
+    if let Ok(fields) = ctx.object_entries(val) {
+        let parts: Vec<String> = fields
+            .into_iter()
+            .map(|(name, field)| format!("{}: {}", name, stringify_value(ctx, field)))
+            .collect();
+        return format!("{{ {} }}", parts.join(", "));
+    }
     if let Ok(s) = ctx.read_string(val) {
         return format!("\"{}\"", s);
     }
@@ -258,6 +276,19 @@ fn stringify_value(ctx: &dyn NativeContext, val: NativeValue) -> String {
     "[object]".to_string()
 }
 
+/// Read the message of a thrown value: an Error's `message` field, or the
+/// value itself when a string was thrown.
+pub(crate) fn error_message(ctx: &dyn NativeContext, err: NativeValue) -> Option<String> {
+    if err.is_null() {
+        return None;
+    }
+    if let Ok(fields) = ctx.object_entries(err) {
+        let (_, message) = fields.into_iter().find(|(name, _)| name == "message")?;
+        return ctx.read_string(message).ok();
+    }
+    ctx.read_string(err).ok()
+}
+
 /// Deep equality check for two NativeValues.
 fn deep_equal(ctx: &dyn NativeContext, a: NativeValue, b: NativeValue) -> bool {
     // Both null
@@ -280,6 +311,22 @@ fn deep_equal(ctx: &dyn NativeContext, a: NativeValue, b: NativeValue) -> bool {
         return (af - bf).abs() < f64::EPSILON;
     }
 
+    // Objects: same field names, pairwise deep-equal values
+    match (ctx.object_entries(a), ctx.object_entries(b)) {
+        (Ok(fa), Ok(fb)) => {
+            if fa.len() != fb.len() {
+                return false;
+            }
+            return fa.iter().all(|(name, va)| {
+                fb.iter()
+                    .find(|(other, _)| other == name)
+                    .is_some_and(|(_, vb)| deep_equal(ctx, *va, *vb))
+            });
+        }
+        (Ok(_), Err(_)) | (Err(_), Ok(_)) => return false,
+        (Err(_), Err(_)) => {}
+    }
+
     // Strings
     if let (Ok(sa), Ok(sb)) = (ctx.read_string(a), ctx.read_string(b)) {
         return sa == sb;
@@ -304,6 +351,98 @@ fn deep_equal(ctx: &dyn NativeContext, a: NativeValue, b: NativeValue) -> bool {
     a == b
 }
 
+/// Strict equality: primitives and strings by value, heap values by reference.
+fn strict_equal(ctx: &dyn NativeContext, a: NativeValue, b: NativeValue) -> bool {
+    let a_num = a.as_f64().or_else(|| a.as_i32().map(|i| i as f64));
+    let b_num = b.as_f64().or_else(|| b.as_i32().map(|i| i as f64));
+    if let (Some(af), Some(bf)) = (a_num, b_num) {
+        return af == bf;
+    }
+    if let (Ok(sa), Ok(sb)) = (ctx.read_string(a), ctx.read_string(b)) {
+        return sa == sb;
+    }
+    a == b
+}
+
+/// Record every path at which `actual` differs from `expected`.
+///
+/// Objects and arrays are compared field by field so a failure points at the
+/// innermost changed value instead of the whole structure.
+fn diff_values(
+    ctx: &dyn NativeContext,
+    expected: NativeValue,
+    actual: NativeValue,
+    path: &str,
+    out: &mut Vec<String>,
+) {
+    if deep_equal(ctx, expected, actual) {
+        return;
+    }
+    let at = if path.is_empty() { "(root)" } else { path };
+
+    if let (Ok(fe), Ok(fa)) = (ctx.object_entries(expected), ctx.object_entries(actual)) {
+        for (name, ve) in &fe {
+            let child = field_path(path, name);
+            match fa.iter().find(|(other, _)| other == name) {
+                Some((_, va)) => diff_values(ctx, *ve, *va, &child, out),
+                None => out.push(format!(
+                    "  at {}\n    Expected: {}\n    Received: (missing)",
+                    child,
+                    stringify_value(ctx, *ve)
+                )),
+            }
+        }
+        for (name, va) in &fa {
+            if !fe.iter().any(|(other, _)| other == name) {
+                let child = field_path(path, name);
+                out.push(format!(
+                    "  at {}\n    Expected: (missing)\n    Received: {}",
+                    child,
+                    stringify_value(ctx, *va)
+                ));
+            }
+        }
+        return;
+    }
+
+    if let (Ok(le), Ok(la)) = (ctx.array_len(expected), ctx.array_len(actual)) {
+        if le != la {
+            out.push(format!(
+                "  at {}\n    Expected: {}\n    Received: {}",
+                field_path(path, "length"),
+                le,
+                la
+            ));
+        }
+        for i in 0..le.min(la) {
+            let ve = ctx
+                .array_get(expected, i)
+                .unwrap_or_else(|_| NativeValue::null());
+            let va = ctx
+                .array_get(actual, i)
+                .unwrap_or_else(|_| NativeValue::null());
+            diff_values(ctx, ve, va, &format!("{}[{}]", path, i), out);
+        }
+        return;
+    }
+
+    out.push(format!(
+        "  at {}\n    Expected: {}\n    Received: {}",
+        at,
+        stringify_value(ctx, expected),
+        stringify_value(ctx, actual)
+    ));
+}
+
+/// Path of the field `name` under `path` (the root is the empty path).
+fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
 /// Check if a value is truthy.
 fn is_truthy(ctx: &dyn NativeContext, val: NativeValue) -> bool {
     if val.is_null() {